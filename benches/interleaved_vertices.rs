@@ -0,0 +1,82 @@
+//! Benchmark of `Reader::read_vertices` against zipping the attribute readers.
+//!
+//! Generates one triangle list of 999 999 vertices with positions, normals, and
+//! texture co-ordinates, and reports the time taken to build a `Vec` of
+//! `StandardVertex` either way. Run with
+//! `cargo bench --bench interleaved_vertices`.
+
+use std::time::{Duration, Instant};
+
+use gltf::mesh::util::vertices::StandardVertex;
+
+const VERTICES: usize = 999_999;
+const RUNS: usize = 5;
+
+/// Returns glTF with one primitive of `VERTICES` vertices and its buffer.
+fn generate() -> (String, Vec<u8>) {
+    let positions = 12 * VERTICES;
+    let normals = 12 * VERTICES;
+    let tex_coords = 8 * VERTICES;
+    let json = format!(
+        r#"{{"asset":{{"version":"2.0"}},"buffers":[{{"byteLength":{length}}}],
+        "bufferViews":[{{"buffer":0,"byteLength":{positions}}},
+            {{"buffer":0,"byteOffset":{positions},"byteLength":{normals}}},
+            {{"buffer":0,"byteOffset":{tex_offset},"byteLength":{tex_coords}}}],
+        "accessors":[{{"bufferView":0,"componentType":5126,"count":{count},"type":"VEC3","min":[0,0,0],"max":[1,1,1]}},
+            {{"bufferView":1,"componentType":5126,"count":{count},"type":"VEC3"}},
+            {{"bufferView":2,"componentType":5126,"count":{count},"type":"VEC2"}}],
+        "meshes":[{{"primitives":[{{"attributes":{{"POSITION":0,"NORMAL":1,"TEXCOORD_0":2}}}}]}}]}}"#,
+        length = positions + normals + tex_coords,
+        positions = positions,
+        normals = normals,
+        tex_offset = positions + normals,
+        tex_coords = tex_coords,
+        count = VERTICES,
+    );
+    let mut data = Vec::with_capacity(positions + normals + tex_coords);
+    for (components, value) in [(3, 0.5f32), (3, 1.0), (2, 0.25)].iter() {
+        for _ in 0..components * VERTICES {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+    }
+    (json, data)
+}
+
+/// Returns the fastest of `RUNS` runs of `f`.
+fn fastest<T>(mut f: impl FnMut() -> T) -> (Duration, T) {
+    let mut best = None::<(Duration, T)>;
+    for _ in 0..RUNS {
+        let start = Instant::now();
+        let value = f();
+        let elapsed = start.elapsed();
+        if best.as_ref().is_none_or(|(x, _)| elapsed < *x) {
+            best = Some((elapsed, value));
+        }
+    }
+    best.unwrap()
+}
+
+fn main() {
+    let (json, data) = generate();
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).expect("failed to load asset");
+    let primitive = gltf.meshes().next().unwrap().primitives().next().unwrap();
+    let reader = primitive.reader(|_| Some(&data));
+
+    let (zip, naive) = fastest(|| {
+        let positions = reader.read_positions().unwrap();
+        let normals = reader.read_normals().unwrap();
+        let tex_coords = reader.read_tex_coords(0).unwrap().into_f32();
+        positions
+            .zip(normals)
+            .zip(tex_coords)
+            .map(|((position, normal), tex_coord)| StandardVertex { position, normal, tex_coord })
+            .collect::<Vec<_>>()
+    });
+    println!("zip: {:?} for {} vertices", zip, naive.len());
+
+    let (interleaved, vertices) = fastest(|| {
+        reader.read_vertices::<StandardVertex>().unwrap().collect::<Vec<_>>()
+    });
+    assert_eq!(vertices, naive);
+    println!("read_vertices: {:?} for {} vertices", interleaved, vertices.len());
+}
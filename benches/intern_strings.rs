@@ -0,0 +1,71 @@
+//! Heap benchmark for `ParseOptions::intern_strings`.
+//!
+//! Loads a document of 100 000 nodes named alike, as written by tiling tools,
+//! and 1 000 images with the same URI, with and without interning, and prints
+//! the allocations and heap bytes held by the loaded document. Run with
+//! `cargo bench --bench intern_strings`.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use gltf::{Gltf, ParseOptions};
+
+const NODES: usize = 100_000;
+const IMAGES: usize = 1_000;
+
+/// Counts the live allocations and heap bytes of the process.
+struct Counter;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counter {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        ALLOCATIONS.fetch_sub(1, Ordering::Relaxed);
+        BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counter = Counter;
+
+fn generate() -> Vec<u8> {
+    let mut json = String::from(r#"{"asset":{"version":"2.0"},"nodes":["#);
+    for i in 0..NODES {
+        if i > 0 {
+            json.push(',');
+        }
+        json.push_str(r#"{"name":"Tile"}"#);
+    }
+    json.push_str(r#"],"images":["#);
+    for i in 0..IMAGES {
+        if i > 0 {
+            json.push(',');
+        }
+        json.push_str(r#"{"name":"Tile","uri":"textures/tile.png"}"#);
+    }
+    json.push_str("]}");
+    json.into_bytes()
+}
+
+fn load(label: &str, data: &[u8], options: &ParseOptions) {
+    let (allocations, bytes) = (ALLOCATIONS.load(Ordering::Relaxed), BYTES.load(Ordering::Relaxed));
+    let gltf = Gltf::from_slice_with_options(data, options).expect("failed to load asset");
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - allocations;
+    let bytes = BYTES.load(Ordering::Relaxed) - bytes;
+    assert_eq!(gltf.nodes().count(), NODES);
+    println!("{}: {} allocations, {} KiB", label, allocations, bytes / 1024);
+}
+
+fn main() {
+    let data = generate();
+    load("default", &data, &ParseOptions::default());
+    load("intern_strings", &data, &ParseOptions::default().intern_strings(true));
+}
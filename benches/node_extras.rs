@@ -0,0 +1,70 @@
+//! Parse benchmark for documents with large extras on every node.
+//!
+//! Generates a document of 20 000 nodes, each with extras resembling the
+//! metadata of a CAD export, and compares `Gltf::from_slice`, which keeps
+//! extras as raw JSON, with also materializing every extras object as a
+//! `Value`, as an eager representation would. Reading the extras of a single
+//! node on demand, which parses them on the first read only, is timed as
+//! well. Run with `cargo bench --bench node_extras --features extras`.
+
+use std::time::{Duration, Instant};
+
+use gltf::json::extras::ParseExtras;
+
+const NODES: usize = 20_000;
+const PROPERTIES: usize = 40;
+const RUNS: usize = 5;
+
+/// Returns glTF with `NODES` nodes of `PROPERTIES` extras properties each.
+fn generate() -> String {
+    let mut json = String::from(r#"{"asset":{"version":"2.0"},"nodes":["#);
+    for i in 0..NODES {
+        if i > 0 {
+            json.push(',');
+        }
+        json.push_str(r#"{"extras":{"#);
+        for j in 0..PROPERTIES {
+            if j > 0 {
+                json.push(',');
+            }
+            json.push_str(&format!(
+                r#""property_{}":{{"part":"P-{}-{}","tolerance":[0.01,0.02],"revised":true}}"#,
+                j, i, j,
+            ));
+        }
+        json.push_str("}}");
+    }
+    json.push_str("]}");
+    json
+}
+
+/// Returns the fastest of `RUNS` runs of `f`.
+fn fastest<T>(mut f: impl FnMut() -> T) -> (Duration, T) {
+    let mut best = None::<(Duration, T)>;
+    for _ in 0..RUNS {
+        let start = Instant::now();
+        let value = f();
+        let elapsed = start.elapsed();
+        if best.as_ref().is_none_or(|(x, _)| elapsed < *x) {
+            best = Some((elapsed, value));
+        }
+    }
+    best.unwrap()
+}
+
+fn main() {
+    let json = generate();
+    let (deferred, gltf) = fastest(|| gltf::Gltf::from_slice(json.as_bytes()).expect("failed to load asset"));
+    println!("parse, extras deferred: {:?} for {} bytes", deferred, json.len());
+
+    let (eager, count) = fastest(|| {
+        let gltf = gltf::Gltf::from_slice(json.as_bytes()).expect("failed to load asset");
+        gltf.nodes().filter_map(|node| node.extras().as_value()).count()
+    });
+    assert_eq!(count, NODES);
+    println!("parse, extras materialized: {:?}", eager);
+
+    let (on_demand, value) = fastest(|| gltf.nodes().nth(NODES / 2).and_then(|node| node.extras().as_value()));
+    assert_eq!(value.unwrap().as_object().map(|x| x.len()), Some(PROPERTIES));
+    println!("extras of one node: {:?}", on_demand);
+}
@@ -0,0 +1,66 @@
+//! Parse and iteration benchmark for the attributes of many primitives.
+//!
+//! Generates a document with 100 000 primitives of five attributes each and
+//! reports the time taken by `Gltf::from_slice` and by iterating over every
+//! attribute. Run with `cargo bench --bench primitive_attributes`.
+
+use std::time::{Duration, Instant};
+
+const PRIMITIVES: usize = 100_000;
+const RUNS: usize = 5;
+
+/// Returns glTF with one mesh of `PRIMITIVES` primitives sharing accessors.
+fn generate() -> String {
+    let mut json = String::from(r#"{"asset":{"version":"2.0"},"buffers":[{"byteLength":1024}],"#);
+    json.push_str(r#""bufferViews":[{"buffer":0,"byteLength":256},{"buffer":0,"byteOffset":256,"byteLength":256},"#);
+    json.push_str(r#"{"buffer":0,"byteOffset":512,"byteLength":256},{"buffer":0,"byteOffset":768,"byteLength":256}],"#);
+    json.push_str(r#""accessors":["#);
+    let accessors = [
+        r#"{"bufferView":0,"componentType":5126,"count":3,"type":"VEC3","min":[0,0,0],"max":[1,1,1]}"#,
+        r#"{"bufferView":1,"componentType":5126,"count":3,"type":"VEC3"}"#,
+        r#"{"bufferView":2,"componentType":5126,"count":3,"type":"VEC4"}"#,
+        r#"{"bufferView":3,"componentType":5126,"count":3,"type":"VEC2"}"#,
+    ];
+    json.push_str(&accessors.join(","));
+    json.push_str(r#"],"meshes":[{"primitives":["#);
+    for i in 0..PRIMITIVES {
+        if i > 0 {
+            json.push(',');
+        }
+        json.push_str(
+            r#"{"attributes":{"TEXCOORD_1":3,"POSITION":0,"TANGENT":2,"NORMAL":1,"TEXCOORD_0":3}}"#,
+        );
+    }
+    json.push_str("]}]}");
+    json
+}
+
+/// Returns the fastest of `RUNS` runs of `f`.
+fn fastest<T>(mut f: impl FnMut() -> T) -> (Duration, T) {
+    let mut best = None::<(Duration, T)>;
+    for _ in 0..RUNS {
+        let start = Instant::now();
+        let value = f();
+        let elapsed = start.elapsed();
+        if best.as_ref().is_none_or(|(x, _)| elapsed < *x) {
+            best = Some((elapsed, value));
+        }
+    }
+    best.unwrap()
+}
+
+fn main() {
+    let json = generate();
+    let (parse, gltf) = fastest(|| gltf::Gltf::from_slice(json.as_bytes()).expect("failed to load asset"));
+    println!("parse: {:?} for {} bytes", parse, json.len());
+
+    let (iterate, sum) = fastest(|| {
+        gltf.meshes()
+            .flat_map(|mesh| mesh.primitives())
+            .flat_map(|primitive| primitive.attributes())
+            .map(|(_, accessor)| accessor.index())
+            .sum::<usize>()
+    });
+    assert_eq!(sum, 9 * PRIMITIVES);
+    println!("iterate: {:?} for {} attributes", iterate, 5 * PRIMITIVES);
+}
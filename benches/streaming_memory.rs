@@ -0,0 +1,86 @@
+//! Peak memory benchmark for `Gltf::from_reader` and
+//! `Gltf::from_reader_streaming`.
+//!
+//! Each loader runs in a child process so that the peak resident set size
+//! (`VmHWM`, Linux only) of one does not mask the other. Run with
+//! `cargo bench --bench streaming_memory`.
+
+use std::io::Write;
+use std::{env, fs, io, process};
+
+const NODES: usize = 100_000;
+const BIN_LENGTH: usize = 16 << 20;
+
+/// Writes binary glTF with a large JSON chunk and a large `BIN` chunk.
+fn generate(path: &std::path::Path) -> io::Result<()> {
+    let mut json = String::from(r#"{"asset":{"version":"2.0"},"buffers":[{"byteLength":"#);
+    json.push_str(&format!("{}}}],\"nodes\":[", BIN_LENGTH));
+    for i in 0..NODES {
+        if i > 0 {
+            json.push(',');
+        }
+        json.push_str(&format!(
+            r#"{{"name":"node{}","translation":[{}.0,0.5,0.25],"scale":[1.0,1.0,1.0]}}"#,
+            i, i
+        ));
+    }
+    json.push_str("]}");
+    while json.len() % 4 != 0 {
+        json.push(' ');
+    }
+    let length = 12 + 8 + json.len() + 8 + BIN_LENGTH;
+    let mut file = io::BufWriter::new(fs::File::create(path)?);
+    file.write_all(b"glTF")?;
+    file.write_all(&2u32.to_le_bytes())?;
+    file.write_all(&(length as u32).to_le_bytes())?;
+    file.write_all(&(json.len() as u32).to_le_bytes())?;
+    file.write_all(b"JSON")?;
+    file.write_all(json.as_bytes())?;
+    file.write_all(&(BIN_LENGTH as u32).to_le_bytes())?;
+    file.write_all(b"BIN\0")?;
+    file.write_all(&vec![0x55; BIN_LENGTH])?;
+    file.flush()
+}
+
+/// Returns the peak resident set size of this process in kilobytes.
+fn peak_memory() -> Option<u64> {
+    let status = fs::read_to_string("/proc/self/status").ok()?;
+    let line = status.lines().find(|line| line.starts_with("VmHWM:"))?;
+    line.split_whitespace().nth(1)?.parse().ok()
+}
+
+fn load(loader: &str, path: &str) {
+    let reader = io::BufReader::new(fs::File::open(path).expect("failed to open asset"));
+    let gltf = match loader {
+        "from_reader" => gltf::Gltf::from_reader(reader),
+        "from_reader_streaming" => gltf::Gltf::from_reader_streaming(reader),
+        _ => panic!("unknown loader {}", loader),
+    }
+    .expect("failed to load asset");
+    assert_eq!(gltf.nodes().count(), NODES);
+    match peak_memory() {
+        Some(kb) => println!("{}: peak memory {} KiB", loader, kb),
+        None => println!("{}: peak memory unavailable", loader),
+    }
+}
+
+fn main() {
+    let args = env::args().collect::<Vec<_>>();
+    if args.len() == 4 && args[1] == "--load" {
+        load(&args[2], &args[3]);
+        return;
+    }
+
+    let path = env::temp_dir().join(format!("gltf-streaming-memory-{}.glb", process::id()));
+    generate(&path).expect("failed to generate asset");
+    for loader in &["from_reader", "from_reader_streaming"] {
+        let status = process::Command::new(env::current_exe().unwrap())
+            .arg("--load")
+            .arg(loader)
+            .arg(&path)
+            .status()
+            .expect("failed to run loader");
+        assert!(status.success());
+    }
+    let _ = fs::remove_file(&path);
+}
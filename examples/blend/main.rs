@@ -0,0 +1,44 @@
+use std::boxed::Box;
+use std::error::Error as StdError;
+
+use gltf::animation::{self, SampledPose};
+
+/// The number of crossfade steps printed.
+const STEPS: u32 = 4;
+
+fn run(path: &str, time: f32) -> Result<(), Box<dyn StdError>> {
+    let (gltf, buffers, _) = gltf::import(path)?;
+    let animations = gltf.animations().collect::<Vec<_>>();
+    if animations.len() < 2 {
+        return Err("the asset needs at least two animations".into());
+    }
+    let root = gltf
+        .default_scene()
+        .or_else(|| gltf.scenes().next())
+        .and_then(|scene| scene.nodes().next())
+        .ok_or("the asset has no scene with nodes")?;
+
+    let get = |buffer: gltf::Buffer| buffers.get(buffer.index()).map(|x| &**x);
+    let a = SampledPose::sample(&animations[0], time, get);
+    let b = SampledPose::sample(&animations[1], time, get);
+    for step in 0..=STEPS {
+        let t = step as f32 / STEPS as f32;
+        let pose = animation::blend(&a, &b, t);
+        let translation = match pose.transforms.get(&root.index()) {
+            Some(transform) => transform.translation,
+            None => root.transform().decomposed().0,
+        };
+        println!("t = {:.2}: node {} translation {:?}", t, root.index(), translation);
+    }
+    Ok(())
+}
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    if let Some(path) = args.next() {
+        let time = args.next().and_then(|x| x.parse().ok()).unwrap_or(0.0);
+        run(&path, time).expect("runtime error");
+    } else {
+        println!("usage: gltf-blend <FILE> [TIME]");
+    }
+}
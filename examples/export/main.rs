@@ -1,4 +1,4 @@
-use gltf_json as json;
+use gltf::json;
 
 use std::{fs, mem};
 
@@ -103,7 +103,7 @@ fn export(output: Output) {
 
     let primitive = json::mesh::Primitive {
         attributes: {
-            let mut map = std::collections::HashMap::new();
+            let mut map = json::mesh::Attributes::default();
             map.insert(Valid(json::mesh::Semantic::Positions), json::Index::new(0));
             map.insert(Valid(json::mesh::Semantic::Colors(0)), json::Index::new(1));
             map
@@ -180,6 +180,7 @@ fn export(output: Output) {
                 },
                 bin: Some(Cow::Owned(to_padded_byte_vector(triangle_vertices))),
                 json: Cow::Owned(json_string.into_bytes()),
+                extra_chunks: Vec::new(),
             };
             let writer = std::fs::File::create("triangle.glb").expect("I/O error");
             glb.to_writer(writer).expect("glTF binary output error");
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+#[path = "../../tests/fuzz/exercise.rs"]
+mod exercise;
+
+fuzz_target!(|data: &[u8]| exercise::exercise(data));
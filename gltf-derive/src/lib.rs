@@ -25,10 +25,10 @@ fn expand(ast: &DeriveInput) -> proc_macro2::TokenStream {
     let ident = &ast.ident;
     let validations: Vec<TokenStream> = fields
         .iter()
-        .map(|f| f.ident.as_ref().unwrap())
-        .map(|ident| {
+        .map(|f| {
             use inflections::Inflect;
-            let field = ident.to_string().to_camel_case();
+            let ident = f.ident.as_ref().unwrap();
+            let field = serde_rename(f).unwrap_or_else(|| ident.to_string().to_camel_case());
             quote!(
                 self.#ident.validate(
                     _root,
@@ -59,3 +59,25 @@ fn expand(ast: &DeriveInput) -> proc_macro2::TokenStream {
         }
     )
 }
+
+/// Returns the name given to a field by `#[serde(rename = "...")]`.
+fn serde_rename(field: &syn::Field) -> Option<String> {
+    field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("serde"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(syn::Meta::List(list)) => Some(list.nested),
+            _ => None,
+        })
+        .flatten()
+        .find_map(|nested| match nested {
+            syn::NestedMeta::Meta(syn::Meta::NameValue(ref name_value)) if name_value.path.is_ident("rename") => {
+                match name_value.lit {
+                    syn::Lit::Str(ref name) => Some(name.value()),
+                    _ => None,
+                }
+            },
+            _ => None,
+        })
+}
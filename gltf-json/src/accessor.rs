@@ -128,7 +128,7 @@ pub mod sparse {
 
         /// Optional application specific data.
         #[serde(default)]
-        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "crate::extras::is_empty"))]
         pub extras: Extras,
     }
 
@@ -157,7 +157,7 @@ pub mod sparse {
 
         /// Optional application specific data.
         #[serde(default)]
-        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "crate::extras::is_empty"))]
         pub extras: Extras,
     }
 
@@ -182,7 +182,7 @@ pub mod sparse {
 
         /// Optional application specific data.
         #[serde(default)]
-        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "crate::extras::is_empty"))]
         pub extras: Extras,
     }
 }
@@ -215,7 +215,7 @@ pub struct Accessor {
 
     /// Optional application specific data.
     #[serde(default)]
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "crate::extras::is_empty"))]
     pub extras: Extras,
 
     /// Specifies if the attribute is a scalar, vector, or matrix.
@@ -233,7 +233,7 @@ pub struct Accessor {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
-    pub name: Option<String>,
+    pub name: Option<crate::Str>,
 
     /// Specifies whether integer data values should be normalized.
     #[serde(default, skip_serializing_if = "is_normalized_default")]
@@ -246,6 +246,159 @@ pub struct Accessor {
     pub sparse: Option<sparse::Sparse>,
 }
 
+/// Returns the number of bytes of a buffer view spanned by `count` elements
+/// of `element_size` bytes placed `stride` bytes apart, starting at
+/// `byte_offset`.
+///
+/// This is `byteOffset + stride * (count - 1) + elementSize`, which must not
+/// exceed the `byteLength` of the buffer view. The padding after the last
+/// element is not required, so data ending with the last element fits even
+/// when the stride is larger than the element size.
+///
+/// ```
+/// # use gltf_json::accessor::required_length;
+/// // Three 12 byte elements, interleaved with a stride of 24 bytes.
+/// assert_eq!(required_length(0, 24, 3, 12), 60);
+/// assert_eq!(required_length(8, 12, 1, 12), 20);
+/// assert_eq!(required_length(8, 12, 0, 12), 8);
+/// ```
+pub fn required_length(byte_offset: u64, stride: u64, count: u64, element_size: u64) -> u64 {
+    match count {
+        0 => byte_offset,
+        _ => stride.saturating_mul(count - 1).saturating_add(element_size).saturating_add(byte_offset),
+    }
+}
+
+/// Accessor data encoded by [`sparsify`].
+///
+/// [`sparsify`]: fn.sparsify.html
+#[derive(Clone, Debug, PartialEq)]
+pub enum Sparsified {
+    /// The modified elements, stored as sparse substitutions of the base data.
+    Sparse {
+        /// The number of modified elements, which is zero if the data is
+        /// unchanged.
+        count: u32,
+
+        /// The smallest unsigned component type holding every index.
+        index_type: ComponentType,
+
+        /// The indices of the modified elements in increasing order, as
+        /// little-endian integers of `index_type`.
+        indices: Vec<u8>,
+
+        /// The modified elements, tightly packed.
+        values: Vec<u8>,
+    },
+
+    /// Every element of the modified data, tightly packed.
+    Dense(Vec<u8>),
+}
+
+/// Encodes the elements of `modified` that differ from `base` as sparse
+/// storage, or falls back to the whole of `modified` if the sparse indices
+/// and values together would exceed `threshold` times its length.
+///
+/// Both slices hold tightly packed elements of `element_size` bytes, such as
+/// returned by [`Type::element_size`]. Elements of `modified` beyond the end
+/// of `base` are treated as modified. A `threshold` of `0.5` therefore stores
+/// the data sparsely when that takes at most half the bytes of a dense
+/// rewrite, ignoring the padding between the indices and the values.
+///
+/// ```
+/// # use gltf_json::accessor::{sparsify, ComponentType, Sparsified};
+/// let base = [0u8, 0, 0, 0, 0, 0, 0, 0];
+/// let modified = [0u8, 0, 7, 0, 0, 0, 0, 0];
+/// let sparse = Sparsified::Sparse {
+///     count: 1,
+///     index_type: ComponentType::U8,
+///     indices: vec![1],
+///     values: vec![7, 0],
+/// };
+/// assert_eq!(sparsify(&base, &modified, 2, 0.5), sparse);
+/// assert_eq!(sparsify(&base, &modified, 2, 0.25), Sparsified::Dense(modified.to_vec()));
+/// ```
+///
+/// [`Type::element_size`]: enum.Type.html#method.element_size
+pub fn sparsify(base: &[u8], modified: &[u8], element_size: usize, threshold: f32) -> Sparsified {
+    let changed = modified
+        .chunks(element_size.max(1))
+        .enumerate()
+        .filter(|&(i, element)| base.get(i * element_size..i * element_size + element.len()) != Some(element))
+        .collect::<Vec<_>>();
+    let index_type = match changed.last() {
+        Some(&(last, _)) if last > u16::MAX as usize => ComponentType::U32,
+        Some(&(last, _)) if last > u8::MAX as usize => ComponentType::U16,
+        _ => ComponentType::U8,
+    };
+    let size = changed.len() * index_type.size() + changed.iter().map(|x| x.1.len()).sum::<usize>();
+    if size as f32 > threshold * modified.len() as f32 {
+        return Sparsified::Dense(modified.to_vec());
+    }
+
+    let mut indices = Vec::with_capacity(changed.len() * index_type.size());
+    let mut values = Vec::with_capacity(changed.len() * element_size);
+    for &(i, element) in &changed {
+        match index_type {
+            ComponentType::U8 => indices.push(i as u8),
+            ComponentType::U16 => indices.extend_from_slice(&(i as u16).to_le_bytes()),
+            _ => indices.extend_from_slice(&(i as u32).to_le_bytes()),
+        }
+        values.extend_from_slice(element);
+    }
+    Sparsified::Sparse { count: changed.len() as u32, index_type, indices, values }
+}
+
+impl Accessor {
+    /// Returns the number of bytes of its buffer view covered by the accessor,
+    /// as computed by [`required_length`].
+    ///
+    /// Returns `None` if the accessor has no buffer view, or if the buffer
+    /// view or the types of the accessor are invalid.
+    ///
+    /// [`required_length`]: fn.required_length.html
+    pub fn required_view_length(&self, root: &Root) -> Option<u64> {
+        let view = root.get(self.buffer_view?)?;
+        let (component_type, type_) = match (self.component_type, self.type_) {
+            (Checked::Valid(component_type), Checked::Valid(type_)) => (component_type.0, type_),
+            _ => return None,
+        };
+        let element_size = type_.element_size(component_type) as u64;
+        let stride = view.byte_stride.filter(|&x| x != 0).map_or(element_size, u64::from);
+        Some(required_length(self.byte_offset.into(), stride, self.count.into(), element_size))
+    }
+
+    /// Returns whether the accessor can be used as the indices of a
+    /// primitive, that is whether it is `SCALAR` with unsigned byte, short,
+    /// or int components, is not normalized, and is not in a buffer view with
+    /// a `byteStride`.
+    ///
+    /// Returns `false` if the types of the accessor are invalid.
+    pub fn is_index_compatible(&self, root: &Root) -> bool {
+        let component_type = match (self.component_type, self.type_) {
+            (Checked::Valid(component_type), Checked::Valid(Type::Scalar)) => component_type.0,
+            _ => return false,
+        };
+        let strided = self.buffer_view
+            .and_then(|index| root.get(index))
+            .and_then(|view| view.byte_stride)
+            .is_some_and(|stride| stride != 0);
+        let unsigned = matches!(component_type, ComponentType::U8 | ComponentType::U16 | ComponentType::U32);
+        unsigned && !self.normalized && !strided
+    }
+}
+
+/// Reports `Error::AccessorTooLong` if `required` bytes exceed the length of
+/// `view`.
+fn validate_length<P, R>(required: u64, view: &buffer::View, path: P, report: &mut R)
+    where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+{
+    let available = u64::from(view.byte_length);
+    if required > available {
+        report(&path, Error::AccessorTooLong { required, available });
+    }
+}
+
 impl Validate for Accessor {
     fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
         where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
@@ -255,6 +408,67 @@ impl Validate for Accessor {
             // missing since it is the more common one to require.
             report(&|| path().field("bufferView"), Error::Missing);
         }
+        if self.buffer_view.is_none() && self.byte_offset != 0 {
+            // Without a buffer view the base values are zero and there is
+            // nothing to offset into.
+            report(&|| path().field("byteOffset"), Error::Invalid);
+        }
+
+        if let Some(stride) = self.buffer_view
+            .and_then(|index| root.get(index))
+            .and_then(|view| view.byte_stride)
+            .filter(|&stride| stride != 0)
+        {
+            if let (Checked::Valid(component_type), Checked::Valid(type_)) = (self.component_type, self.type_) {
+                // Elements must not overlap.
+                if (stride as usize) < type_.element_size(component_type.0) {
+                    report(&|| path().field("bufferView"), Error::Invalid);
+                }
+            }
+        }
+
+        if let (Some(required), Some(view)) = (
+            self.required_view_length(root),
+            self.buffer_view.and_then(|index| root.get(index)),
+        ) {
+            validate_length(required, view, &path, report);
+        }
+        if let Some(sparse) = self.sparse.as_ref() {
+            let count = u64::from(sparse.count);
+            if let (Checked::Valid(component_type), Some(view)) =
+                (sparse.indices.component_type, root.get(sparse.indices.buffer_view))
+            {
+                let size = component_type.0.size() as u64;
+                let required = required_length(sparse.indices.byte_offset.into(), size, count, size);
+                validate_length(required, view, || path().field("sparse").field("indices"), report);
+            }
+            let strided = root.get(sparse.indices.buffer_view)
+                .and_then(|view| view.byte_stride)
+                .is_some_and(|stride| stride != 0);
+            if strided {
+                report(&|| path().field("sparse").field("indices").field("bufferView"), Error::InvalidIndexAccessor);
+            }
+            if let (Checked::Valid(component_type), Checked::Valid(type_), Some(view)) =
+                (self.component_type, self.type_, root.get(sparse.values.buffer_view))
+            {
+                let size = type_.element_size(component_type.0) as u64;
+                let required = required_length(sparse.values.byte_offset.into(), size, count, size);
+                validate_length(required, view, || path().field("sparse").field("values"), report);
+            }
+        }
+
+        if let Checked::Valid(GenericComponentType(ComponentType::F32)) = self.component_type {
+            // Bounds beyond the range of `f32`, such as `1e39`, overflow to
+            // infinity when read.
+            for (name, bound) in [("min", &self.min), ("max", &self.max)] {
+                let values = bound.as_ref().and_then(|x| x.as_array()).map(Vec::as_slice).unwrap_or(&[]);
+                for (i, value) in values.iter().enumerate() {
+                    if value.as_f64().is_some_and(|x| !(x as f32).is_finite()) {
+                        report(&|| path().field(name).index(i), Error::NonFinite);
+                    }
+                }
+            }
+        }
 
         self.buffer_view.validate(root, || path().field("bufferView"), report);
         self.byte_offset.validate(root, || path().field("byteOffset"), report);
@@ -440,4 +654,23 @@ impl Type {
             Mat4 => 16,
         }
     }
+
+    /// Returns the size in bytes of one element of this type, including the
+    /// column padding of matrices with one or two byte components.
+    ///
+    /// This is the stride between the elements of tightly packed data.
+    ///
+    /// ```
+    /// # use gltf_json::accessor::{ComponentType, Type};
+    /// assert_eq!(Type::Vec3.element_size(ComponentType::F32), 12);
+    /// assert_eq!(Type::Mat3.element_size(ComponentType::I8), 12);
+    /// ```
+    pub fn element_size(&self, component_type: ComponentType) -> usize {
+        match (component_type.size(), *self) {
+            (1, Type::Mat2) => 8,
+            (1, Type::Mat3) => 12,
+            (2, Type::Mat3) => 24,
+            (size, type_) => size * type_.multiplicity(),
+        }
+    }
 }
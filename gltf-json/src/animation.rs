@@ -1,6 +1,7 @@
 use gltf_derive::Validate;
 use serde_derive::{Serialize, Deserialize};
 use serde::{de, ser};
+use std::collections::HashSet;
 use std::fmt;
 use crate::validation::{Checked, Error, Validate};
 use crate::{accessor, extensions, scene, Extras, Index, Path, Root};
@@ -18,6 +19,8 @@ pub const VALID_PROPERTIES: &'static [&'static str] = &[
     "rotation",
     "scale",
     "weights",
+    #[cfg(feature = "KHR_animation_pointer")]
+    "pointer",
 ];
 
 /// Specifies an interpolation algorithm.
@@ -49,7 +52,7 @@ pub enum Interpolation {
 }
 
 /// Specifies a property to animate.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Deserialize)]
 pub enum Property {
     /// XYZ translation vector.
     Translation = 1,
@@ -62,6 +65,11 @@ pub enum Property {
 
     /// Weights of morph targets.
     MorphTargetWeights,
+
+    /// The property referenced by the JSON pointer of the
+    /// `KHR_animation_pointer` extension.
+    #[cfg(feature = "KHR_animation_pointer")]
+    Pointer,
 }
 
 /// A keyframe animation.
@@ -73,7 +81,7 @@ pub struct Animation {
     
     /// Optional application specific data.
     #[serde(default)]
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "crate::extras::is_empty"))]
     pub extras: Extras,
     
     /// An array of channels, each of which targets an animation's sampler at a
@@ -86,7 +94,7 @@ pub struct Animation {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
-    pub name: Option<String>,
+    pub name: Option<crate::Str>,
     
     /// An array of samplers that combine input and output accessors with an
     /// interpolation algorithm to define a keyframe graph (but not its target).
@@ -110,7 +118,7 @@ pub struct Channel {
     
     /// Optional application specific data.
     #[serde(default)]
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "crate::extras::is_empty"))]
     pub extras: Extras,
 }
 
@@ -123,11 +131,15 @@ pub struct Target {
     
     /// Optional application specific data.
     #[serde(default)]
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "crate::extras::is_empty"))]
     pub extras: Extras,
     
     /// The index of the node to target.
-    pub node: Index<scene::Node>,
+    ///
+    /// Only absent when `path` is `pointer`, in which case the target is
+    /// defined by the `KHR_animation_pointer` extension.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub node: Option<Index<scene::Node>>,
     
     /// The name of the node's property to modify or the 'weights' of the
     /// morph targets it instantiates.
@@ -143,7 +155,7 @@ pub struct Sampler {
     
     /// Optional application specific data.
     #[serde(default)]
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "crate::extras::is_empty"))]
     pub extras: Extras,
     
     /// The index of an accessor containing keyframe input values, e.g., time.
@@ -164,11 +176,68 @@ impl Validate for Animation {
         R: FnMut(&dyn Fn() -> Path, Error),
     {
         self.samplers.validate(root, || path().field("samplers"), report);
+        self.extensions.validate(root, || path().field("extensions"), report);
         for (index, channel) in self.channels.iter().enumerate() {
             if channel.sampler.value() as usize >= self.samplers.len() {
                 let path = || path().field("channels").index(index).field("sampler");
                 report(&path, Error::IndexOutOfBounds);
             }
+            let path = || path().field("channels").index(index).field("target");
+            channel.target.validate(root, path, report);
+        }
+
+        let mut targets = HashSet::new();
+        for (index, channel) in self.channels.iter().enumerate() {
+            let path = || path().field("channels").index(index).field("target");
+            let target = &channel.target;
+            let pointer = target.pointer();
+            match target.path {
+                #[cfg(feature = "KHR_animation_pointer")]
+                Checked::Valid(Property::Pointer) if pointer.is_none() => {
+                    let path = || path().field("extensions").field("KHR_animation_pointer");
+                    report(&path, Error::Missing);
+                    continue;
+                },
+                #[cfg(feature = "KHR_animation_pointer")]
+                Checked::Valid(Property::Pointer) => {},
+                Checked::Valid(_) if target.node.is_none() => {
+                    report(&|| path().field("node"), Error::Missing);
+                    continue;
+                },
+                Checked::Valid(_) => {},
+                Checked::Invalid => continue,
+            }
+            if !targets.insert((target.node.map(|x| x.value()), target.path, pointer)) {
+                report(&path, Error::Duplicate);
+            }
+            if let (Some(node), Checked::Valid(property)) = (target.node, target.path) {
+                let animates_transform = matches!(
+                    property,
+                    Property::Translation | Property::Rotation | Property::Scale
+                );
+                let has_matrix = root.get(node).is_some_and(|x| x.matrix.is_some());
+                if animates_transform && has_matrix {
+                    report(&|| path().field("node"), Error::MatrixAnimated { node: node.value() as u32 });
+                }
+            }
+        }
+    }
+}
+
+impl Target {
+    /// Returns the JSON pointer of the `KHR_animation_pointer` extension, if
+    /// present.
+    pub fn pointer(&self) -> Option<&str> {
+        #[cfg(feature = "KHR_animation_pointer")]
+        {
+            self.extensions
+                .as_ref()
+                .and_then(|x| x.khr_animation_pointer.as_ref())
+                .map(|x| x.pointer.as_str())
+        }
+        #[cfg(not(feature = "KHR_animation_pointer"))]
+        {
+            None
         }
     }
 }
@@ -243,6 +312,8 @@ impl<'de> de::Deserialize<'de> for Checked<Property> {
                     "rotation" => Valid(Rotation),
                     "scale" => Valid(Scale),
                     "weights" => Valid(MorphTargetWeights),
+                    #[cfg(feature = "KHR_animation_pointer")]
+                    "pointer" => Valid(Pointer),
                     _ => Invalid,
                 })
             }
@@ -261,6 +332,8 @@ impl ser::Serialize for Property {
             Property::Rotation => "rotation",
             Property::Scale => "scale",
             Property::MorphTargetWeights => "weights",
+            #[cfg(feature = "KHR_animation_pointer")]
+            Property::Pointer => "pointer",
         })
     }
 }
@@ -15,7 +15,7 @@ pub struct Asset {
 
     /// Optional application specific data.
     #[serde(default)]
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "crate::extras::is_empty"))]
     pub extras: Extras,
 
     /// Tool that generated this glTF model.
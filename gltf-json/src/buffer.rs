@@ -54,12 +54,12 @@ pub struct Buffer {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
-    pub name: Option<String>,
+    pub name: Option<crate::Str>,
 
     /// The uri of the buffer.  Relative paths are relative to the .gltf file.
     /// Instead of referencing an external file, the uri can also be a data-uri.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub uri: Option<String>,
+    pub uri: Option<crate::Str>,
 
     /// Extension specific data.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -67,7 +67,7 @@ pub struct Buffer {
 
     /// Optional application specific data.
     #[serde(default)]
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "crate::extras::is_empty"))]
     pub extras: Extras,
 }
 
@@ -98,7 +98,7 @@ pub struct View {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
-    pub name: Option<String>,
+    pub name: Option<crate::Str>,
 
     /// Optional target the buffer should be bound to.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -110,7 +110,7 @@ pub struct View {
 
     /// Optional application specific data.
     #[serde(default)]
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "crate::extras::is_empty"))]
     pub extras: Extras,
 }
 
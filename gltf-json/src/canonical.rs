@@ -0,0 +1,211 @@
+use std::io;
+
+use serde::ser::Error as _;
+
+use crate::{Error, Root};
+
+/// Options for serializing a `Root` with [`Root::to_vec_with`] and related
+/// methods.
+///
+/// The default options write the same compact JSON as [`Root::to_vec`].
+///
+/// ```
+/// use gltf_json::canonical::SerializeOptions;
+/// use gltf_json::Root;
+///
+/// let json = r#"{"asset": {"version": "2.0"}, "extras": {"b": 1.50, "a": "A"}}"#;
+/// let root = Root::from_str(json).unwrap();
+/// let options = SerializeOptions { canonical: true, ..Default::default() };
+/// let canonical = root.to_string_with(&options).unwrap();
+/// # #[cfg(feature = "extras")]
+/// assert_eq!(canonical, r#"{"asset":{"version":"2.0"},"extras":{"b":1.5,"a":"A"}}"#);
+/// ```
+///
+/// [`Root::to_vec_with`]: ../struct.Root.html#method.to_vec_with
+/// [`Root::to_vec`]: ../struct.Root.html#method.to_vec
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct SerializeOptions {
+    /// Write byte-identical output for equal documents, for example to hash
+    /// the output for caching.
+    ///
+    /// Object keys keep the order of the glTF schema, or of the input for
+    /// free-form objects such as extras. Whitespace, string escapes, and
+    /// numbers within extras are normalized the way the rest of the document
+    /// is written, floating point numbers use their shortest round-trip
+    /// representation, and `extensionsUsed` and `extensionsRequired` are
+    /// sorted.
+    pub canonical: bool,
+
+    /// Indent the output with two spaces, as [`Root::to_vec_pretty`] does.
+    ///
+    /// [`Root::to_vec_pretty`]: ../struct.Root.html#method.to_vec_pretty
+    pub pretty: bool,
+
+    /// Round floating point numbers to this number of decimal places.
+    ///
+    /// Integers are not affected.
+    pub float_precision: Option<u8>,
+}
+
+impl Root {
+    /// Serialize as a JSON byte vector with the given options.
+    pub fn to_vec_with(&self, options: &SerializeOptions) -> Result<Vec<u8>, Error> {
+        let sorted;
+        let root = if options.canonical {
+            let mut root = self.clone();
+            for names in [&mut root.extensions_used, &mut root.extensions_required] {
+                names.sort();
+                names.dedup();
+            }
+            sorted = root;
+            &sorted
+        } else {
+            self
+        };
+        match (options.canonical || options.float_precision.is_some(), options.pretty) {
+            (false, false) => root.to_vec(),
+            (false, true) => root.to_vec_pretty(),
+            (true, _) => {
+                let input = root.to_vec()?;
+                let mut emitter = Emitter { input: &input, position: 0, options, output: Vec::new() };
+                emitter.value(0)?;
+                Ok(emitter.output)
+            },
+        }
+    }
+
+    /// Serialize as a `String` of JSON with the given options.
+    pub fn to_string_with(&self, options: &SerializeOptions) -> Result<String, Error> {
+        let vec = self.to_vec_with(options)?;
+        String::from_utf8(vec).map_err(Error::custom)
+    }
+
+    /// Serialize as a JSON byte writer with the given options.
+    pub fn to_writer_with<W>(&self, mut writer: W, options: &SerializeOptions) -> Result<(), Error>
+        where W: io::Write,
+    {
+        let vec = self.to_vec_with(options)?;
+        writer.write_all(&vec).map_err(Error::io)
+    }
+}
+
+/// Rewrites JSON written by `serde_json`, whose extras may contain arbitrary
+/// whitespace and number forms, according to the options.
+struct Emitter<'a> {
+    input: &'a [u8],
+    position: usize,
+    options: &'a SerializeOptions,
+    output: Vec<u8>,
+}
+
+impl<'a> Emitter<'a> {
+    fn skip_whitespace(&mut self) {
+        while self.input.get(self.position).is_some_and(|x| x.is_ascii_whitespace()) {
+            self.position += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_whitespace();
+        self.input.get(self.position).copied()
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), Error> {
+        if self.peek() == Some(byte) {
+            self.position += 1;
+            Ok(())
+        } else {
+            Err(Error::custom(format!("expected `{}` at byte {}", byte as char, self.position)))
+        }
+    }
+
+    fn newline(&mut self, depth: usize) {
+        if self.options.pretty {
+            self.output.push(b'\n');
+            self.output.extend(std::iter::repeat_n(b' ', 2 * depth));
+        }
+    }
+
+    fn value(&mut self, depth: usize) -> Result<(), Error> {
+        match self.peek() {
+            Some(b'{') => self.container(depth, b'{', b'}', true),
+            Some(b'[') => self.container(depth, b'[', b']', false),
+            Some(b'"') => self.string(),
+            Some(_) => self.scalar(),
+            None => Err(Error::custom("unexpected end of JSON")),
+        }
+    }
+
+    fn container(&mut self, depth: usize, open: u8, close: u8, object: bool) -> Result<(), Error> {
+        self.expect(open)?;
+        self.output.push(open);
+        if self.peek() == Some(close) {
+            self.position += 1;
+            self.output.push(close);
+            return Ok(());
+        }
+        loop {
+            self.newline(depth + 1);
+            if object {
+                self.string()?;
+                self.expect(b':')?;
+                self.output.push(b':');
+                if self.options.pretty {
+                    self.output.push(b' ');
+                }
+            }
+            self.value(depth + 1)?;
+            if self.peek() == Some(b',') {
+                self.position += 1;
+                self.output.push(b',');
+            } else {
+                break;
+            }
+        }
+        self.newline(depth);
+        self.expect(close)?;
+        self.output.push(close);
+        Ok(())
+    }
+
+    fn string(&mut self) -> Result<(), Error> {
+        self.expect(b'"')?;
+        let start = self.position - 1;
+        loop {
+            match self.input.get(self.position) {
+                Some(b'\\') => self.position += 2,
+                Some(b'"') => break,
+                Some(_) => self.position += 1,
+                None => return Err(Error::custom("unterminated string")),
+            }
+        }
+        self.position += 1;
+        let raw = &self.input[start..self.position];
+        if self.options.canonical {
+            let string: String = serde_json::from_slice(raw)?;
+            serde_json::to_writer(&mut self.output, &string)?;
+        } else {
+            self.output.extend_from_slice(raw);
+        }
+        Ok(())
+    }
+
+    /// Writes a number or a literal.
+    fn scalar(&mut self) -> Result<(), Error> {
+        let start = self.position;
+        while self.input.get(self.position).is_some_and(|x| x.is_ascii_alphanumeric() || b"+-.".contains(x)) {
+            self.position += 1;
+        }
+        let raw = &self.input[start..self.position];
+        let float = raw.iter().any(|x| b".eE".contains(x)) && !raw[0].is_ascii_alphabetic();
+        if !float || !(self.options.canonical || self.options.float_precision.is_some()) {
+            self.output.extend_from_slice(raw);
+            return Ok(());
+        }
+        let mut value: f64 = serde_json::from_slice(raw)?;
+        if let Some(precision) = self.options.float_precision {
+            value = format!("{:.*}", precision as usize, value).parse().map_err(Error::custom)?;
+        }
+        serde_json::to_writer(&mut self.output, &value)
+    }
+}
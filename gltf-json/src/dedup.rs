@@ -0,0 +1,387 @@
+use std::collections::{BTreeSet, HashMap};
+use std::hash::Hash;
+
+use crate::buffer::View;
+use crate::identity::{Kind, Remap};
+use crate::validation::Checked;
+use crate::{Accessor, Index, Root};
+
+/// Summary of the changes made by [`Root::deduplicate_accessors`].
+///
+/// [`Root::deduplicate_accessors`]: ../struct.Root.html#method.deduplicate_accessors
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Report {
+    /// The original indices of the removed accessors.
+    pub removed_accessors: Vec<usize>,
+
+    /// The original indices of the buffer views that were removed because
+    /// only removed accessors referred to them.
+    pub removed_views: Vec<usize>,
+
+    /// The number of buffer bytes no longer covered by any buffer view.
+    ///
+    /// These bytes remain in the buffers until they are removed with
+    /// [`Root::repack_buffers`].
+    ///
+    /// [`Root::repack_buffers`]: ../struct.Root.html#method.repack_buffers
+    pub bytes_saved: usize,
+
+    /// The new index of every original accessor and buffer view. Removed
+    /// accessors map to the accessor replacing them.
+    pub remap: Remap,
+}
+
+/// Returns the groups of two or more items with equal keys, each in
+/// ascending order, ordered by their first item.
+fn groups<K, T, I>(keys: I) -> Vec<Vec<Index<T>>>
+where
+    K: Eq + Hash,
+    I: Iterator<Item = K>,
+{
+    let mut groups = HashMap::<K, Vec<usize>>::new();
+    for (index, key) in keys.enumerate() {
+        groups.entry(key).or_default().push(index);
+    }
+    let mut groups = groups.into_values().filter(|x| x.len() > 1).collect::<Vec<_>>();
+    groups.sort();
+    groups
+        .into_iter()
+        .map(|group| group.into_iter().map(|x| Index::new(x as u32)).collect())
+        .collect()
+}
+
+/// Returns the JSON of an accessor without its name and extras.
+fn accessor_key(accessor: &Accessor) -> String {
+    let mut accessor = accessor.clone();
+    #[cfg(feature = "names")]
+    {
+        accessor.name = None;
+    }
+    accessor.extras = Default::default();
+    serde_json::to_string(&accessor).unwrap_or_default()
+}
+
+/// Returns the JSON of a buffer view without its name and extras.
+fn view_key(view: &View) -> String {
+    let mut view = view.clone();
+    #[cfg(feature = "names")]
+    {
+        view.name = None;
+    }
+    view.extras = Default::default();
+    serde_json::to_string(&view).unwrap_or_default()
+}
+
+/// Returns the sum of the lengths of the union of byte ranges.
+fn covered(mut ranges: Vec<(usize, usize, usize)>) -> usize {
+    ranges.sort();
+    let mut total = 0;
+    let mut current = None::<(usize, usize, usize)>;
+    for (buffer, start, end) in ranges {
+        match current {
+            Some((b, s, e)) if b == buffer && start <= e => current = Some((b, s, e.max(end))),
+            _ => {
+                total += current.map_or(0, |(_, s, e)| e - s);
+                current = Some((buffer, start, end));
+            },
+        }
+    }
+    total + current.map_or(0, |(_, s, e)| e - s)
+}
+
+impl Root {
+    /// Returns the groups of accessors that are declared identically, that
+    /// is with the same buffer view, byte offset, component type, type,
+    /// count, bounds, and sparse storage.
+    ///
+    /// Names and extras are ignored. Each group is in ascending order and the
+    /// groups are ordered by their first accessor.
+    pub fn duplicate_accessors(&self) -> Vec<Vec<Index<Accessor>>> {
+        groups(self.accessors.iter().map(accessor_key))
+    }
+
+    /// Returns the groups of accessors that are declared identically, as
+    /// with [`duplicate_accessors`], or that hold identical elements in
+    /// different bytes of `buffers`.
+    ///
+    /// Accessors with sparse storage, and accessors whose data is not in
+    /// `buffers`, are only compared by declaration.
+    ///
+    /// [`duplicate_accessors`]: #method.duplicate_accessors
+    pub fn duplicate_accessors_in<B: AsRef<[u8]>>(&self, buffers: &[B]) -> Vec<Vec<Index<Accessor>>> {
+        groups(self.accessors.iter().map(|accessor| match self.accessor_elements(accessor, buffers) {
+            Some(elements) => {
+                let mut declaration = accessor.clone();
+                declaration.buffer_view = None;
+                declaration.byte_offset = 0;
+                (accessor_key(&declaration), Some(elements))
+            },
+            None => (accessor_key(accessor), None),
+        }))
+    }
+
+    /// Returns the groups of buffer views that are declared identically, that
+    /// is with the same buffer, byte range, stride, and target.
+    ///
+    /// Names and extras are ignored. Each group is in ascending order and the
+    /// groups are ordered by their first buffer view.
+    pub fn duplicate_views(&self) -> Vec<Vec<Index<View>>> {
+        groups(self.buffer_views.iter().map(view_key))
+    }
+
+    /// Returns the groups of buffer views that are declared identically, as
+    /// with [`duplicate_views`], or that have the same length, stride, and
+    /// target and identical bytes in `buffers`.
+    ///
+    /// [`duplicate_views`]: #method.duplicate_views
+    pub fn duplicate_views_in<B: AsRef<[u8]>>(&self, buffers: &[B]) -> Vec<Vec<Index<View>>> {
+        groups(self.buffer_views.iter().map(|view| match view_bytes(view, buffers) {
+            Some(bytes) => {
+                let mut declaration = view.clone();
+                declaration.buffer = Index::new(0);
+                declaration.byte_offset = None;
+                (view_key(&declaration), Some(bytes))
+            },
+            None => (view_key(view), None),
+        }))
+    }
+
+    /// Collapses every group of [`duplicate_accessors`] into its first
+    /// accessor and removes the others.
+    ///
+    /// References from mesh attributes, indices, and morph targets, from
+    /// skins, and from animation samplers are remapped. Buffer views that only
+    /// removed accessors referred to are removed as well. References in
+    /// extensions that are not supported by this crate are not remapped.
+    ///
+    /// [`duplicate_accessors`]: #method.duplicate_accessors
+    pub fn deduplicate_accessors(&mut self) -> Report {
+        let groups = self.duplicate_accessors();
+        self.collapse_accessors(&groups)
+    }
+
+    /// Collapses every group of [`duplicate_accessors_in`] into its first
+    /// accessor and removes the others, as [`deduplicate_accessors`] does.
+    ///
+    /// The bytes of the removed buffer views are reported in `bytes_saved`
+    /// and may then be removed with [`repack_buffers`].
+    ///
+    /// ```
+    /// # use gltf_json::Root;
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut root = Root::from_str(r#"{
+    ///     "asset": {"version": "2.0"},
+    ///     "buffers": [{"byteLength": 8}],
+    ///     "bufferViews": [
+    ///         {"buffer": 0, "byteLength": 4},
+    ///         {"buffer": 0, "byteOffset": 4, "byteLength": 4}
+    ///     ],
+    ///     "accessors": [
+    ///         {"bufferView": 0, "componentType": 5126, "count": 1, "type": "SCALAR"},
+    ///         {"bufferView": 1, "componentType": 5126, "count": 1, "type": "SCALAR"}
+    ///     ]
+    /// }"#)?;
+    /// let buffers = vec![[1.0f32.to_le_bytes(), 1.0f32.to_le_bytes()].concat()];
+    /// let report = root.deduplicate_accessors_in(&buffers);
+    /// assert_eq!(report.removed_accessors, vec![1]);
+    /// assert_eq!(report.bytes_saved, 4);
+    /// assert_eq!(root.buffer_views.len(), 1);
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    ///
+    /// [`duplicate_accessors_in`]: #method.duplicate_accessors_in
+    /// [`deduplicate_accessors`]: #method.deduplicate_accessors
+    /// [`repack_buffers`]: #method.repack_buffers
+    pub fn deduplicate_accessors_in<B: AsRef<[u8]>>(&mut self, buffers: &[B]) -> Report {
+        let groups = self.duplicate_accessors_in(buffers);
+        self.collapse_accessors(&groups)
+    }
+
+    /// Returns the elements of a dense accessor, tightly packed, or `None`
+    /// if the accessor is sparse, invalid, or its data is not in `buffers`.
+    fn accessor_elements<B: AsRef<[u8]>>(&self, accessor: &Accessor, buffers: &[B]) -> Option<Vec<u8>> {
+        if accessor.sparse.is_some() {
+            return None;
+        }
+        let size = match (accessor.component_type, accessor.type_) {
+            (Checked::Valid(component_type), Checked::Valid(type_)) => type_.element_size(component_type.0),
+            _ => return None,
+        };
+        let count = accessor.count as usize;
+        let length = count.checked_mul(size)?;
+        let view = match accessor.buffer_view {
+            Some(view) => self.buffer_views.get(view.value())?,
+            // The elements of accessors without a buffer view are zeros. They
+            // cannot equal the elements of a view if longer than the buffers.
+            None if length <= buffers.iter().map(|x| x.as_ref().len()).sum() => return Some(vec![0; length]),
+            None => return None,
+        };
+        let bytes = view_bytes(view, buffers)?;
+        let stride = view.byte_stride.map_or(size, |x| x as usize).max(1);
+        let mut elements = Vec::with_capacity(length.min(bytes.len()));
+        for i in 0..count {
+            let start = (accessor.byte_offset as usize).checked_add(i.checked_mul(stride)?)?;
+            elements.extend_from_slice(bytes.get(start..start.checked_add(size)?)?);
+        }
+        Some(elements)
+    }
+
+    /// Removes every accessor of `groups` except the first of each group and
+    /// remaps the references to them.
+    fn collapse_accessors(&mut self, groups: &[Vec<Index<Accessor>>]) -> Report {
+        let mut report = Report::default();
+        let covered_before = covered(self.view_ranges());
+
+        // Maps every accessor to the accessor replacing it, then to the index
+        // of the replacement once the removed accessors are gone.
+        let mut replacement = (0..self.accessors.len()).collect::<Vec<_>>();
+        for group in groups {
+            for duplicate in &group[1..] {
+                replacement[duplicate.value()] = group[0].value();
+                report.removed_accessors.push(duplicate.value());
+            }
+        }
+        report.removed_accessors.sort();
+        let mut new_index = vec![0; self.accessors.len()];
+        let mut next = 0;
+        for (index, new) in new_index.iter_mut().enumerate() {
+            if replacement[index] == index {
+                *new = next;
+                next += 1;
+            }
+        }
+        let remap = |index: &mut Index<Accessor>| {
+            if let Some(&old) = replacement.get(index.value()) {
+                *index = Index::new(new_index[old] as u32);
+            }
+        };
+
+        for primitive in self.meshes.iter_mut().flat_map(|mesh| mesh.primitives.iter_mut()) {
+            primitive.attributes.accessors_mut().for_each(remap);
+            primitive.indices.iter_mut().for_each(remap);
+            for target in primitive.targets.iter_mut().flatten() {
+                target.positions.iter_mut().for_each(remap);
+                target.normals.iter_mut().for_each(remap);
+                target.tangents.iter_mut().for_each(remap);
+            }
+        }
+        for skin in &mut self.skins {
+            skin.inverse_bind_matrices.iter_mut().for_each(remap);
+        }
+        #[cfg(feature = "EXT_mesh_gpu_instancing")]
+        for node in &mut self.nodes {
+            let instancing = node.extensions.as_mut().and_then(|x| x.ext_mesh_gpu_instancing.as_mut());
+            instancing.into_iter().flat_map(|x| x.attributes.values_mut()).for_each(remap);
+        }
+        for sampler in self.animations.iter_mut().flat_map(|animation| animation.samplers.iter_mut()) {
+            remap(&mut sampler.input);
+            remap(&mut sampler.output);
+        }
+
+        // The buffer views of the removed accessors that nothing else uses.
+        let mut orphaned = BTreeSet::new();
+        for &index in &report.removed_accessors {
+            let accessor = &self.accessors[index];
+            orphaned.extend(accessor.buffer_view.map(|x| x.value()));
+            if let Some(sparse) = accessor.sparse.as_ref() {
+                orphaned.insert(sparse.indices.buffer_view.value());
+                orphaned.insert(sparse.values.buffer_view.value());
+            }
+        }
+        let mut index = 0;
+        self.accessors.retain(|_| {
+            index += 1;
+            replacement[index - 1] == index - 1
+        });
+        for accessor in &self.accessors {
+            if let Some(view) = accessor.buffer_view {
+                orphaned.remove(&view.value());
+            }
+            if let Some(sparse) = accessor.sparse.as_ref() {
+                orphaned.remove(&sparse.indices.buffer_view.value());
+                orphaned.remove(&sparse.values.buffer_view.value());
+            }
+        }
+        for image in &self.images {
+            if let Some(view) = image.buffer_view {
+                orphaned.remove(&view.value());
+            }
+        }
+        orphaned.retain(|&x| x < self.buffer_views.len());
+        let mut next = 0;
+        let views = (0..self.buffer_views.len())
+            .map(|index| {
+                if orphaned.contains(&index) {
+                    return None;
+                }
+                next += 1;
+                Some(next - 1)
+            })
+            .collect();
+        report.remap.insert(Kind::Accessor, replacement.iter().map(|&x| Some(new_index[x])).collect());
+        report.remap.insert(Kind::BufferView, views);
+        self.remove_views(&orphaned);
+        report.removed_views = orphaned.into_iter().collect();
+
+        report.bytes_saved = covered_before - covered(self.view_ranges());
+        report
+    }
+
+    /// Removes the given buffer views and remaps the references to the
+    /// others.
+    fn remove_views(&mut self, removed: &BTreeSet<usize>) {
+        if removed.is_empty() {
+            return;
+        }
+        let mut new_index = Vec::with_capacity(self.buffer_views.len());
+        let mut next = 0;
+        for index in 0..self.buffer_views.len() {
+            new_index.push(next);
+            if !removed.contains(&index) {
+                next += 1;
+            }
+        }
+        let remap = |view: &mut Index<View>| {
+            if let Some(&new) = new_index.get(view.value()) {
+                *view = Index::new(new as u32);
+            }
+        };
+        for accessor in &mut self.accessors {
+            accessor.buffer_view.iter_mut().for_each(remap);
+            if let Some(sparse) = accessor.sparse.as_mut() {
+                remap(&mut sparse.indices.buffer_view);
+                remap(&mut sparse.values.buffer_view);
+            }
+        }
+        for image in &mut self.images {
+            image.buffer_view.iter_mut().for_each(remap);
+        }
+        let mut index = 0;
+        self.buffer_views.retain(|_| {
+            index += 1;
+            !removed.contains(&(index - 1))
+        });
+    }
+
+    /// Returns the buffer, start, and end of every buffer view.
+    fn view_ranges(&self) -> Vec<(usize, usize, usize)> {
+        self.buffer_views
+            .iter()
+            .map(|view| {
+                let start = view.byte_offset.unwrap_or(0) as usize;
+                (view.buffer.value(), start, start + view.byte_length as usize)
+            })
+            .collect()
+    }
+}
+
+/// Returns the bytes of a buffer view, or `None` if they are not in
+/// `buffers`.
+fn view_bytes<'a, B: AsRef<[u8]>>(view: &View, buffers: &'a [B]) -> Option<&'a [u8]> {
+    let start = view.byte_offset.unwrap_or(0) as usize;
+    buffers.get(view.buffer.value())?.as_ref().get(start..start + view.byte_length as usize)
+}
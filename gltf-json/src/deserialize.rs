@@ -0,0 +1,134 @@
+pub use serde_json::{from_reader, from_slice, from_str, from_value};
+
+use serde::de::{self, Deserialize, DeserializeOwned, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor};
+use std::collections::HashSet;
+use std::{fmt, io};
+
+use crate::Path;
+
+/// Deserializes a `T` from the JSON text at the start of `data`, and returns
+/// it with the length of that text.
+///
+/// Unlike `from_slice`, content after the JSON value is not an error, so that
+/// callers can inspect it.
+///
+/// ```
+/// # use gltf_json::deserialize::from_slice_prefix;
+/// let (value, length) = from_slice_prefix::<Vec<u32>>(b"[1, 2] trailing").unwrap();
+/// assert_eq!((value, length), (vec![1, 2], 6));
+/// ```
+pub fn from_slice_prefix<'a, T>(data: &'a [u8]) -> Result<(T, usize), serde_json::Error>
+where
+    T: Deserialize<'a>,
+{
+    let mut stream = serde_json::Deserializer::from_slice(data).into_iter::<T>();
+    match stream.next() {
+        Some(result) => result.map(|value| (value, stream.byte_offset())),
+        // Reports the end of the input.
+        None => from_slice(data).map(|value| (value, data.len())),
+    }
+}
+
+/// Deserializes a `T` from the JSON text at the start of a reader.
+///
+/// Unlike `from_reader`, content after the JSON value is not an error and is
+/// not read, so that callers can inspect it. The reader is read one byte at a
+/// time and should be buffered.
+pub fn from_reader_prefix<R, T>(reader: R) -> Result<T, serde_json::Error>
+where
+    R: io::Read,
+    T: DeserializeOwned,
+{
+    T::deserialize(&mut serde_json::Deserializer::from_reader(reader))
+}
+
+/// Returns the path of every object member whose key repeats an earlier key
+/// of the same object, in document order.
+///
+/// When deserializing `Root`, repeated fields of glTF objects are rejected
+/// without a path, and the last of the repeated keys of maps such as
+/// `attributes` silently replaces the others. Members of objects are named
+/// with `Path::field` and array elements with `Path::index`. Fails if `data`
+/// is not valid JSON; content after the JSON value is ignored.
+///
+/// ```
+/// # use gltf_json::deserialize::duplicate_keys;
+/// let json = br#"{"nodes": [{"name": "a", "name": "b"}], "asset": {"version": "2.0"}}"#;
+/// let paths = duplicate_keys(json).unwrap();
+/// assert_eq!(paths.iter().map(|x| x.as_str()).collect::<Vec<_>>(), ["nodes[0].name"]);
+/// ```
+pub fn duplicate_keys(data: &[u8]) -> Result<Vec<Path>, serde_json::Error> {
+    let mut duplicates = Vec::new();
+    let mut deserializer = serde_json::Deserializer::from_slice(data);
+    Keys { path: Path::new(), duplicates: &mut duplicates }.deserialize(&mut deserializer)?;
+    Ok(duplicates)
+}
+
+/// Visits a JSON value, recording the paths of repeated object keys.
+struct Keys<'a> {
+    path: Path,
+    duplicates: &'a mut Vec<Path>,
+}
+
+impl<'de, 'a> DeserializeSeed<'de> for Keys<'a> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de, 'a> Visitor<'de> for Keys<'a> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "any JSON value")
+    }
+
+    fn visit_bool<E: de::Error>(self, _: bool) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_i64<E: de::Error>(self, _: i64) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_u64<E: de::Error>(self, _: u64) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_f64<E: de::Error>(self, _: f64) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_str<E: de::Error>(self, _: &str) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        let mut index = 0;
+        loop {
+            let element = Keys { path: self.path.index(index), duplicates: &mut *self.duplicates };
+            if seq.next_element_seed(element)?.is_none() {
+                return Ok(());
+            }
+            index += 1;
+        }
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        let mut keys = HashSet::new();
+        while let Some(key) = map.next_key::<String>()? {
+            let path = self.path.field(&key);
+            if !keys.insert(key) {
+                self.duplicates.push(path.clone());
+            }
+            map.next_value_seed(Keys { path, duplicates: &mut *self.duplicates })?;
+        }
+        Ok(())
+    }
+}
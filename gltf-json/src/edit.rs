@@ -0,0 +1,183 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::{Index, Mesh, Node, Path, Root};
+
+/// Represents an error that occured while editing a glTF document or
+/// repacking its buffers.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    /// An index was found to be out of bounds.
+    IndexOutOfBounds(Path),
+
+    /// The edit would introduce a cycle in the node hierarchy.
+    Cycle,
+
+    /// The node already has a parent or is the root node of a scene.
+    AlreadyParented,
+
+    /// A transform without a finite decomposition was given for a node
+    /// targeted by an animation, which cannot have a `matrix`.
+    AnimatedMatrix,
+
+    /// A buffer view or accessor refers to bytes outside of the buffer data.
+    OutOfRange(Path),
+
+    /// The document is invalid, for example an accessor has an unknown
+    /// component type.
+    Invalid(Path),
+}
+
+impl Root {
+    /// Appends a node to the document and returns its index.
+    ///
+    /// The node is not validated, therefore it should be added to the node
+    /// hierarchy with [`add_child`] rather than by populating its `children`.
+    ///
+    /// # Examples
+    ///
+    /// Building a two-node scene that references an existing mesh.
+    ///
+    /// ```rust
+    /// # use gltf_json::{Index, Node, Root, Scene};
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut root = Root::from_str(r#"{
+    ///     "asset": { "version": "2.0" },
+    ///     "meshes": [{ "primitives": [{ "attributes": {} }] }]
+    /// }"#)?;
+    /// let parent = root.push_node(Node::default());
+    /// let child = root.push_node(Node::default());
+    /// root.add_child(parent, child)?;
+    /// # assert_eq!(root.add_child(child, parent), Err(gltf_json::edit::Error::Cycle));
+    /// # assert_eq!(root.add_child(parent, child), Err(gltf_json::edit::Error::AlreadyParented));
+    /// root.attach_mesh(child, Index::new(0))?;
+    /// root.nodes[child.value()].translation = Some([0.0, 1.0, 0.0]);
+    /// root.scenes.push(Scene { nodes: vec![parent], ..Default::default() });
+    /// root.scene = Some(Index::new(0));
+    /// let json = root.to_string()?;
+    /// # assert!(json.contains(r#""children":[1]"#));
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    ///
+    /// [`add_child`]: #method.add_child
+    pub fn push_node(&mut self, node: Node) -> Index<Node> {
+        self.nodes.push(node);
+        Index::new(self.nodes.len() as u32 - 1)
+    }
+
+    /// Adds `child` to the children of `parent`.
+    ///
+    /// Fails if `child` already has a parent, is the root node of a scene, or is
+    /// an ancestor of `parent`. Use [`reparent_node`] to move a node that is
+    /// already part of the hierarchy.
+    ///
+    /// [`reparent_node`]: #method.reparent_node
+    pub fn add_child(&mut self, parent: Index<Node>, child: Index<Node>) -> Result<(), Error> {
+        self.check_child(parent, child)?;
+        if self.is_parented(child) {
+            return Err(Error::AlreadyParented);
+        }
+        self.push_child(parent, child);
+        Ok(())
+    }
+
+    /// Moves `child` to the children of `parent`, removing it from its previous
+    /// parent and from the root nodes of every scene.
+    ///
+    /// Fails if `child` is an ancestor of `parent`.
+    pub fn reparent_node(&mut self, parent: Index<Node>, child: Index<Node>) -> Result<(), Error> {
+        self.check_child(parent, child)?;
+        let is_child = |index: &Index<Node>| index.value() == child.value();
+        for node in &mut self.nodes {
+            if let Some(children) = node.children.as_mut() {
+                children.retain(|index| !is_child(index));
+            }
+        }
+        for scene in &mut self.scenes {
+            scene.nodes.retain(|index| !is_child(index));
+        }
+        self.push_child(parent, child);
+        Ok(())
+    }
+
+    /// Sets the mesh instantiated by a node.
+    pub fn attach_mesh(&mut self, node: Index<Node>, mesh: Index<Mesh>) -> Result<(), Error> {
+        self.check_node(node)?;
+        if mesh.value() >= self.meshes.len() {
+            return Err(Error::IndexOutOfBounds(Path::new().field("meshes").index(mesh.value())));
+        }
+        self.nodes[node.value()].mesh = Some(mesh);
+        Ok(())
+    }
+
+    fn check_node(&self, node: Index<Node>) -> Result<(), Error> {
+        if node.value() < self.nodes.len() {
+            Ok(())
+        } else {
+            Err(Error::IndexOutOfBounds(Path::new().field("nodes").index(node.value())))
+        }
+    }
+
+    fn check_child(&self, parent: Index<Node>, child: Index<Node>) -> Result<(), Error> {
+        self.check_node(parent)?;
+        self.check_node(child)?;
+        if self.is_ancestor(child, parent) {
+            Err(Error::Cycle)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns `true` if `node` is `ancestor` or one of its descendants.
+    fn is_ancestor(&self, ancestor: Index<Node>, node: Index<Node>) -> bool {
+        let mut visited = HashSet::new();
+        let mut stack = vec![ancestor.value()];
+        while let Some(index) = stack.pop() {
+            if index == node.value() {
+                return true;
+            }
+            if !visited.insert(index) {
+                continue;
+            }
+            if let Some(children) = self.nodes.get(index).and_then(|x| x.children.as_ref()) {
+                stack.extend(children.iter().map(Index::value));
+            }
+        }
+        false
+    }
+
+    fn is_parented(&self, node: Index<Node>) -> bool {
+        let is_node = |index: &Index<Node>| index.value() == node.value();
+        self.nodes
+            .iter()
+            .filter_map(|x| x.children.as_ref())
+            .any(|children| children.iter().any(is_node))
+            || self.scenes.iter().any(|scene| scene.nodes.iter().any(is_node))
+    }
+
+    fn push_child(&mut self, parent: Index<Node>, child: Index<Node>) {
+        self.nodes[parent.value()]
+            .children
+            .get_or_insert_with(Vec::new)
+            .push(child);
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::IndexOutOfBounds(ref path) => write!(f, "{}: index out of bounds", path),
+            Error::Cycle => write!(f, "node hierarchy would contain a cycle"),
+            Error::AlreadyParented => write!(f, "node already has a parent"),
+            Error::AnimatedMatrix => write!(f, "animated node cannot have a matrix"),
+            Error::OutOfRange(ref path) => write!(f, "{}: out of range of the buffer data", path),
+            Error::Invalid(ref path) => write!(f, "{}: invalid value", path),
+        }
+    }
+}
@@ -2,8 +2,12 @@ use gltf_derive::Validate;
 use serde_derive::{Serialize, Deserialize};
 
 /// A keyframe animation.
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct Animation {}
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+pub struct Animation {
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[serde(default, rename = "KHR_xmp_json_ld", skip_serializing_if = "Option::is_none")]
+    pub khr_xmp_json_ld: Option<crate::extensions::root::khr_xmp_json_ld::KhrXmpJsonLd>,
+}
 
 /// Targets an animation's sampler at a node's property.
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -11,7 +15,20 @@ pub struct Channel {}
 
 /// The index of the node and TRS property that an animation channel targets.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Target {}
+pub struct Target {
+    #[cfg(feature = "KHR_animation_pointer")]
+    #[serde(default, rename = "KHR_animation_pointer", skip_serializing_if = "Option::is_none")]
+    pub khr_animation_pointer: Option<AnimationPointer>,
+}
+
+/// Targets an arbitrary property of the document with a JSON pointer.
+#[cfg(feature = "KHR_animation_pointer")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+pub struct AnimationPointer {
+    /// A JSON pointer to the animated property, for example
+    /// `/materials/0/pbrMetallicRoughness/baseColorFactor`.
+    pub pointer: String,
+}
 
 /// Defines a keyframe graph but not its target.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
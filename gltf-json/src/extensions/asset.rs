@@ -3,4 +3,8 @@ use serde_derive::{Serialize, Deserialize};
 
 /// Metadata about the glTF asset.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Asset {}
+pub struct Asset {
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[serde(default, rename = "KHR_xmp_json_ld", skip_serializing_if = "Option::is_none")]
+    pub khr_xmp_json_ld: Option<crate::extensions::root::khr_xmp_json_ld::KhrXmpJsonLd>,
+}
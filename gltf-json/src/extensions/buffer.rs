@@ -3,8 +3,237 @@ use serde_derive::{Serialize, Deserialize};
 
 /// A buffer points to binary data representing geometry, animations, or skins.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Buffer {}
+pub struct Buffer {
+    #[cfg(feature = "EXT_meshopt_compression")]
+    #[serde(default, rename = "EXT_meshopt_compression", skip_serializing_if = "Option::is_none")]
+    pub ext_meshopt_compression: Option<ext_meshopt_compression::Buffer>,
+}
 
 /// A view into a buffer generally representing a subset of the buffer.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct View {}
+pub struct View {
+    #[cfg(feature = "EXT_meshopt_compression")]
+    #[serde(default, rename = "EXT_meshopt_compression", skip_serializing_if = "Option::is_none")]
+    pub ext_meshopt_compression: Option<ext_meshopt_compression::View>,
+}
+
+#[cfg(feature = "EXT_meshopt_compression")]
+pub mod ext_meshopt_compression {
+    use crate::validation::{Checked, Error, Validate};
+    use crate::{buffer, Index, Path, Root};
+    use gltf_derive::Validate;
+    use serde::{de, ser};
+    use serde_derive::{Deserialize, Serialize};
+    use std::fmt;
+
+    /// All valid compression modes.
+    pub const VALID_MODES: &[&str] = &[
+        "ATTRIBUTES",
+        "TRIANGLES",
+        "INDICES",
+    ];
+
+    /// All valid filters.
+    pub const VALID_FILTERS: &[&str] = &[
+        "NONE",
+        "OCTAHEDRAL",
+        "QUATERNION",
+        "EXPONENTIAL",
+    ];
+
+    /// Marks a buffer whose data is only needed by clients that do not
+    /// support the extension.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    pub struct Buffer {
+        /// `true` if the buffer may have no `uri`, since every view of it is
+        /// decompressed from another buffer.
+        #[serde(default)]
+        pub fallback: bool,
+    }
+
+    /// The compressed data of a buffer view, which decompresses to `count`
+    /// elements of `byte_stride` bytes.
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    pub struct View {
+        /// The buffer containing the compressed data.
+        pub buffer: Index<buffer::Buffer>,
+
+        /// The offset of the compressed data in the buffer, in bytes.
+        #[serde(default, rename = "byteOffset")]
+        pub byte_offset: u32,
+
+        /// The length of the compressed data, in bytes.
+        #[serde(rename = "byteLength")]
+        pub byte_length: u32,
+
+        /// The stride of the decompressed elements, in bytes.
+        #[serde(rename = "byteStride")]
+        pub byte_stride: u32,
+
+        /// The number of decompressed elements.
+        pub count: u32,
+
+        /// The compression mode.
+        pub mode: Checked<Mode>,
+
+        /// The filter applied to decompressed attributes.
+        #[serde(default = "filter_default", skip_serializing_if = "is_filter_default")]
+        pub filter: Checked<Filter>,
+    }
+
+    /// Specifies how the data of a buffer view is compressed.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub enum Mode {
+        /// Vertex attributes, or other elements of a multiple of 4 bytes.
+        Attributes = 1,
+
+        /// Triangle list indices of 2 or 4 bytes.
+        Triangles,
+
+        /// Other indices of 2 or 4 bytes.
+        Indices,
+    }
+
+    /// Specifies how decompressed attributes are transformed.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub enum Filter {
+        /// The attributes are used as is.
+        None = 1,
+
+        /// Octahedral encoding of unit vectors into 8 or 16 bit components.
+        Octahedral,
+
+        /// Encoding of unit quaternions into four 16 bit components.
+        Quaternion,
+
+        /// Encoding of floats with a shared exponent.
+        Exponential,
+    }
+
+    fn filter_default() -> Checked<Filter> {
+        Checked::Valid(Filter::None)
+    }
+
+    fn is_filter_default(filter: &Checked<Filter>) -> bool {
+        *filter == Checked::Valid(Filter::None)
+    }
+
+    impl View {
+        /// Returns `true` if `byte_stride` and `count` are valid for the mode
+        /// and filter.
+        fn is_layout_valid(&self, mode: Mode, filter: Filter) -> bool {
+            let stride = self.byte_stride;
+            let mode_valid = match mode {
+                Mode::Attributes => stride.is_multiple_of(4) && stride > 0 && stride <= 256,
+                Mode::Triangles => (stride == 2 || stride == 4) && self.count.is_multiple_of(3),
+                Mode::Indices => stride == 2 || stride == 4,
+            };
+            let filter_valid = match filter {
+                Filter::None => true,
+                Filter::Octahedral => mode == Mode::Attributes && (stride == 4 || stride == 8),
+                Filter::Quaternion => mode == Mode::Attributes && stride == 8,
+                Filter::Exponential => mode == Mode::Attributes,
+            };
+            mode_valid && filter_valid
+        }
+    }
+
+    impl Validate for View {
+        fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+            where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+        {
+            self.buffer.validate(root, || path().field("buffer"), report);
+            self.mode.validate(root, || path().field("mode"), report);
+            self.filter.validate(root, || path().field("filter"), report);
+            if let (Checked::Valid(mode), Checked::Valid(filter)) = (self.mode, self.filter) {
+                if !self.is_layout_valid(mode, filter) {
+                    report(&|| path().field("byteStride"), Error::Invalid);
+                }
+            }
+        }
+    }
+
+    impl<'de> de::Deserialize<'de> for Checked<Mode> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where D: de::Deserializer<'de>
+        {
+            struct Visitor;
+            impl<'de> de::Visitor<'de> for Visitor {
+                type Value = Checked<Mode>;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "any of: {:?}", VALID_MODES)
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                    where E: de::Error
+                {
+                    use self::Mode::*;
+                    use crate::validation::Checked::*;
+                    Ok(match value {
+                        "ATTRIBUTES" => Valid(Attributes),
+                        "TRIANGLES" => Valid(Triangles),
+                        "INDICES" => Valid(Indices),
+                        _ => Invalid,
+                    })
+                }
+            }
+            deserializer.deserialize_str(Visitor)
+        }
+    }
+
+    impl ser::Serialize for Mode {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where S: ser::Serializer
+        {
+            serializer.serialize_str(match *self {
+                Mode::Attributes => "ATTRIBUTES",
+                Mode::Triangles => "TRIANGLES",
+                Mode::Indices => "INDICES",
+            })
+        }
+    }
+
+    impl<'de> de::Deserialize<'de> for Checked<Filter> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where D: de::Deserializer<'de>
+        {
+            struct Visitor;
+            impl<'de> de::Visitor<'de> for Visitor {
+                type Value = Checked<Filter>;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "any of: {:?}", VALID_FILTERS)
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                    where E: de::Error
+                {
+                    use self::Filter::*;
+                    use crate::validation::Checked::*;
+                    Ok(match value {
+                        "NONE" => Valid(None),
+                        "OCTAHEDRAL" => Valid(Octahedral),
+                        "QUATERNION" => Valid(Quaternion),
+                        "EXPONENTIAL" => Valid(Exponential),
+                        _ => Invalid,
+                    })
+                }
+            }
+            deserializer.deserialize_str(Visitor)
+        }
+    }
+
+    impl ser::Serialize for Filter {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where S: ser::Serializer
+        {
+            serializer.serialize_str(match *self {
+                Filter::None => "NONE",
+                Filter::Octahedral => "OCTAHEDRAL",
+                Filter::Quaternion => "QUATERNION",
+                Filter::Exponential => "EXPONENTIAL",
+            })
+        }
+    }
+}
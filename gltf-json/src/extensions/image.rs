@@ -3,4 +3,8 @@ use serde_derive::{Serialize, Deserialize};
 
 /// Image data used to create a texture.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Image {}
+pub struct Image {
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[serde(default, rename = "KHR_xmp_json_ld", skip_serializing_if = "Option::is_none")]
+    pub khr_xmp_json_ld: Option<crate::extensions::root::khr_xmp_json_ld::KhrXmpJsonLd>,
+}
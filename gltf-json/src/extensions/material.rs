@@ -4,7 +4,11 @@ use serde_derive::{Serialize, Deserialize};
 use crate::{Extras, texture, validation::Validate, material::StrengthFactor};
 
 /// The material appearance of a primitive.
+///
+/// New fields are added to this struct as extensions become supported. Prefer
+/// the accessor methods of `gltf::Material` over matching on its fields.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[non_exhaustive]
 pub struct Material {
     #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
     #[serde(default, rename = "KHR_materials_pbrSpecularGlossiness", skip_serializing_if = "Option::is_none")]
@@ -13,11 +17,16 @@ pub struct Material {
     #[cfg(feature = "KHR_materials_unlit")]
     #[serde(default, rename = "KHR_materials_unlit", skip_serializing_if = "Option::is_none")]
     pub unlit: Option<Unlit>,
+
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[serde(default, rename = "KHR_xmp_json_ld", skip_serializing_if = "Option::is_none")]
+    pub khr_xmp_json_ld: Option<crate::extensions::root::khr_xmp_json_ld::KhrXmpJsonLd>,
 }
 
 /// A set of parameter values that are used to define the metallic-roughness
 /// material model from Physically-Based Rendering (PBR) methodology.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[non_exhaustive]
 pub struct PbrMetallicRoughness {}
 
 /// A set of parameter values that are used to define the specular-glossiness
@@ -67,23 +76,46 @@ pub struct PbrSpecularGlossiness {
     pub specular_glossiness_texture: Option<texture::Info>,
 
     /// Optional application specific data.
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "crate::extras::is_empty"))]
     pub extras: Extras,
 }
 
 /// Defines the normal texture of a material.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct NormalTexture {}
+#[non_exhaustive]
+pub struct NormalTexture {
+    #[cfg(feature = "KHR_texture_transform")]
+    #[serde(default, rename = "KHR_texture_transform", skip_serializing_if = "Option::is_none")]
+    pub texture_transform: Option<crate::extensions::texture::TextureTransform>,
+}
 
 /// Defines the occlusion texture of a material.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct OcclusionTexture {}
+#[non_exhaustive]
+pub struct OcclusionTexture {
+    #[cfg(feature = "KHR_texture_transform")]
+    #[serde(default, rename = "KHR_texture_transform", skip_serializing_if = "Option::is_none")]
+    pub texture_transform: Option<crate::extensions::texture::TextureTransform>,
+}
 
 /// The diffuse factor of a material.
 #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
 pub struct PbrDiffuseFactor(pub [f32; 4]);
 
+#[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+impl PbrDiffuseFactor {
+    /// Creates a diffuse factor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a component is outside the range [0.0, 1.0].
+    pub fn new(value: [f32; 4]) -> Self {
+        assert!(value.iter().all(|x| (0.0..=1.0).contains(x)), "diffuse factor out of range: {:?}", value);
+        PbrDiffuseFactor(value)
+    }
+}
+
 #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
 impl Default for PbrDiffuseFactor {
     fn default() -> Self {
@@ -99,6 +131,19 @@ impl Validate for PbrDiffuseFactor {}
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
 pub struct PbrSpecularFactor(pub [f32; 3]);
 
+#[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+impl PbrSpecularFactor {
+    /// Creates a specular factor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a component is outside the range [0.0, 1.0].
+    pub fn new(value: [f32; 3]) -> Self {
+        assert!(value.iter().all(|x| (0.0..=1.0).contains(x)), "specular factor out of range: {:?}", value);
+        PbrSpecularFactor(value)
+    }
+}
+
 #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
 impl Default for PbrSpecularFactor {
     fn default() -> Self {
@@ -6,7 +6,11 @@ use serde_derive::{Serialize, Deserialize};
 /// A node can contain one or more meshes and its transform places the meshes in
 /// the scene.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Mesh {}
+pub struct Mesh {
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[serde(default, rename = "KHR_xmp_json_ld", skip_serializing_if = "Option::is_none")]
+    pub khr_xmp_json_ld: Option<crate::extensions::root::khr_xmp_json_ld::KhrXmpJsonLd>,
+}
 
 /// Geometry to be rendered with the given material.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
@@ -36,19 +36,43 @@ pub mod texture;
 
 pub use self::root::Root;
 
-/// Names of glTF 2.0 extensions enabled by the user.
+/// Names of glTF 2.0 extensions enabled by the user, sorted by name.
 pub const ENABLED_EXTENSIONS: &'static [&'static str] = &[
+    #[cfg(feature = "EXT_mesh_gpu_instancing")]
+    "EXT_mesh_gpu_instancing",
+    #[cfg(feature = "EXT_meshopt_compression")]
+    "EXT_meshopt_compression",
+    #[cfg(feature = "EXT_texture_webp")]
+    "EXT_texture_webp",
+    #[cfg(feature = "KHR_animation_pointer")]
+    "KHR_animation_pointer",
     #[cfg(feature = "KHR_lights_punctual")]
     "KHR_lights_punctual",
     #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
     "KHR_materials_pbrSpecularGlossiness",
     #[cfg(feature = "KHR_materials_unlit")]
     "KHR_materials_unlit",
+    #[cfg(feature = "KHR_node_visibility")]
+    "KHR_node_visibility",
+    #[cfg(feature = "KHR_texture_transform")]
+    "KHR_texture_transform",
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    "KHR_xmp_json_ld",
+    #[cfg(feature = "MSFT_texture_dds")]
+    "MSFT_texture_dds",
 ];
 
-/// Names of glTF 2.0 extensions supported by the library.
+/// Names of glTF 2.0 extensions supported by the library, sorted by name.
 pub const SUPPORTED_EXTENSIONS: &'static [&'static str] = &[
+    "EXT_mesh_gpu_instancing",
+    "EXT_meshopt_compression",
+    "EXT_texture_webp",
+    "KHR_animation_pointer",
     "KHR_lights_punctual",
     "KHR_materials_pbrSpecularGlossiness",
     "KHR_materials_unlit",
+    "KHR_node_visibility",
+    "KHR_texture_transform",
+    "KHR_xmp_json_ld",
+    "MSFT_texture_dds",
 ];
@@ -1,12 +1,115 @@
 use gltf_derive::Validate;
+use serde::{de, ser};
+#[cfg(any(feature = "KHR_lights_punctual", feature = "KHR_xmp_json_ld"))]
+use serde::de::DeserializeOwned;
+use serde::ser::SerializeMap;
+use serde_json::value::RawValue;
+#[cfg(any(feature = "KHR_lights_punctual", feature = "KHR_xmp_json_ld"))]
 use serde_derive::{Serialize, Deserialize};
+use std::collections::BTreeMap;
 
 /// The root object of a glTF 2.0 asset.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+///
+/// Extensions that are not recognized, or whose crate feature is disabled,
+/// are kept in `others` and written back unchanged.
+#[derive(Clone, Debug, Default, Validate)]
 pub struct Root {
     #[cfg(feature = "KHR_lights_punctual")]
-    #[serde(default, rename = "KHR_lights_punctual", skip_serializing_if = "Option::is_none")]
     pub khr_lights_punctual: Option<KhrLightsPunctual>,
+
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    pub khr_xmp_json_ld: Option<KhrXmpJsonLd>,
+
+    /// The raw JSON of every other root extension, keyed by extension name.
+    pub others: BTreeMap<String, Box<RawValue>>,
+}
+
+impl Root {
+    /// Returns the JSON of the named extension, whether it is parsed by an
+    /// enabled crate feature or kept in `others`.
+    pub fn value(&self, name: &str) -> Option<serde_json::Value> {
+        match name {
+            #[cfg(feature = "KHR_lights_punctual")]
+            "KHR_lights_punctual" => self.khr_lights_punctual.as_ref().and_then(|x| serde_json::to_value(x).ok()),
+            #[cfg(feature = "KHR_xmp_json_ld")]
+            "KHR_xmp_json_ld" => self.khr_xmp_json_ld.as_ref().and_then(|x| serde_json::to_value(x).ok()),
+            _ => self.others.get(name).and_then(|x| serde_json::from_str(x.get()).ok()),
+        }
+    }
+
+    /// Returns the names of the extensions, whether they are parsed by an
+    /// enabled crate feature or kept in `others`, sorted by name.
+    pub fn names(&self) -> Vec<&str> {
+        #[allow(unused_mut)]
+        let mut names = self.others.keys().map(String::as_str).collect::<Vec<_>>();
+        #[cfg(feature = "KHR_lights_punctual")]
+        {
+            if self.khr_lights_punctual.is_some() {
+                names.push("KHR_lights_punctual");
+            }
+        }
+        #[cfg(feature = "KHR_xmp_json_ld")]
+        {
+            if self.khr_xmp_json_ld.is_some() {
+                names.push("KHR_xmp_json_ld");
+            }
+        }
+        names.sort_unstable();
+        names
+    }
+}
+
+/// Removes and parses the named extension.
+#[cfg(any(feature = "KHR_lights_punctual", feature = "KHR_xmp_json_ld"))]
+fn take<T, E>(extensions: &mut BTreeMap<String, Box<RawValue>>, name: &str) -> Result<Option<T>, E>
+where
+    T: DeserializeOwned,
+    E: de::Error,
+{
+    extensions
+        .remove(name)
+        .map(|raw| serde_json::from_str(raw.get()).map_err(E::custom))
+        .transpose()
+}
+
+impl<'de> de::Deserialize<'de> for Root {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: de::Deserializer<'de>
+    {
+        #[allow(unused_mut)]
+        let mut others = BTreeMap::<String, Box<RawValue>>::deserialize(deserializer)?;
+        Ok(Root {
+            #[cfg(feature = "KHR_lights_punctual")]
+            khr_lights_punctual: take(&mut others, "KHR_lights_punctual")?,
+            #[cfg(feature = "KHR_xmp_json_ld")]
+            khr_xmp_json_ld: take(&mut others, "KHR_xmp_json_ld")?,
+            others,
+        })
+    }
+}
+
+impl ser::Serialize for Root {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: ser::Serializer
+    {
+        let mut map = serializer.serialize_map(None)?;
+        #[cfg(feature = "KHR_lights_punctual")]
+        {
+            if let Some(khr_lights_punctual) = self.khr_lights_punctual.as_ref() {
+                map.serialize_entry("KHR_lights_punctual", khr_lights_punctual)?;
+            }
+        }
+        #[cfg(feature = "KHR_xmp_json_ld")]
+        {
+            if let Some(khr_xmp_json_ld) = self.khr_xmp_json_ld.as_ref() {
+                map.serialize_entry("KHR_xmp_json_ld", khr_xmp_json_ld)?;
+            }
+        }
+        for (name, value) in &self.others {
+            map.serialize_entry(name, value)?;
+        }
+        map.end()
+    }
 }
 
 #[cfg(feature = "KHR_lights_punctual")]
@@ -32,3 +135,46 @@ impl crate::root::Get<crate::extensions::scene::khr_lights_punctual::Light> for
         }
     }
 }
+
+/// The XMP metadata packets of the asset.
+#[cfg(feature = "KHR_xmp_json_ld")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+pub struct KhrXmpJsonLd {
+    /// Packets of XMP metadata referenced by the objects of the asset.
+    pub packets: Vec<khr_xmp_json_ld::Packet>,
+}
+
+#[cfg(feature = "KHR_xmp_json_ld")]
+impl crate::root::Get<khr_xmp_json_ld::Packet> for crate::Root {
+    fn get(&self, id: crate::Index<khr_xmp_json_ld::Packet>) -> Option<&khr_xmp_json_ld::Packet> {
+        self.extensions
+            .as_ref()
+            .and_then(|extensions| extensions.khr_xmp_json_ld.as_ref())
+            .and_then(|khr_xmp_json_ld| khr_xmp_json_ld.packets.get(id.value()))
+    }
+}
+
+#[cfg(feature = "KHR_xmp_json_ld")]
+pub mod khr_xmp_json_ld {
+    use crate::Index;
+    use crate::validation::Validate;
+    use gltf_derive::Validate;
+    use serde_derive::{Deserialize, Serialize};
+
+    /// A packet of XMP metadata, stored as a JSON-LD object.
+    ///
+    /// The object usually has an `@context` key mapping namespace prefixes
+    /// such as `dc` to their URIs, followed by the metadata properties.
+    #[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+    #[serde(transparent)]
+    pub struct Packet(pub serde_json::Map<String, serde_json::Value>);
+
+    impl Validate for Packet {}
+
+    /// References the XMP metadata packet of an object.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    pub struct KhrXmpJsonLd {
+        /// The index of the packet in the root `KHR_xmp_json_ld` extension.
+        pub packet: Index<Packet>,
+    }
+}
@@ -13,9 +13,59 @@ use serde_derive::{Serialize, Deserialize};
 /// be present.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
 pub struct Node {
+    #[cfg(feature = "EXT_mesh_gpu_instancing")]
+    #[serde(default, rename = "EXT_mesh_gpu_instancing", skip_serializing_if = "Option::is_none")]
+    pub ext_mesh_gpu_instancing: Option<ext_mesh_gpu_instancing::ExtMeshGpuInstancing>,
+
     #[cfg(feature = "KHR_lights_punctual")]
     #[serde(default, rename = "KHR_lights_punctual", skip_serializing_if = "Option::is_none")]
     pub khr_lights_punctual: Option<khr_lights_punctual::KhrLightsPunctual>,
+
+    #[cfg(feature = "KHR_node_visibility")]
+    #[serde(default, rename = "KHR_node_visibility", skip_serializing_if = "Option::is_none")]
+    pub khr_node_visibility: Option<khr_node_visibility::KhrNodeVisibility>,
+
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[serde(default, rename = "KHR_xmp_json_ld", skip_serializing_if = "Option::is_none")]
+    pub khr_xmp_json_ld: Option<crate::extensions::root::khr_xmp_json_ld::KhrXmpJsonLd>,
+}
+
+#[cfg(feature = "EXT_mesh_gpu_instancing")]
+pub mod ext_mesh_gpu_instancing {
+    use crate::validation::{Checked, Error, Validate};
+    use crate::{accessor, Index, Path, Root};
+    use serde_derive::{Deserialize, Serialize};
+    use std::collections::BTreeMap;
+
+    /// Draws the mesh of a node once per instance, transformed by the
+    /// per-instance attributes.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize)]
+    pub struct ExtMeshGpuInstancing {
+        /// Maps attribute names, such as `TRANSLATION`, `ROTATION`, `SCALE`,
+        /// or application-specific names starting with an underscore, to the
+        /// accessors containing one element per instance.
+        pub attributes: BTreeMap<String, Index<accessor::Accessor>>,
+    }
+
+    impl Validate for ExtMeshGpuInstancing {
+        fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+            where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+        {
+            use crate::accessor::Type::{Vec3, Vec4};
+            self.attributes.validate(root, || path().field("attributes"), report);
+            for (name, index) in &self.attributes {
+                let expected = match name.as_str() {
+                    "TRANSLATION" | "SCALE" => Vec3,
+                    "ROTATION" => Vec4,
+                    _ => continue,
+                };
+                let type_ = root.accessors.get(index.value()).map(|x| x.type_);
+                if type_.is_some_and(|x| x != Checked::Valid(expected)) {
+                    report(&|| path().field("attributes").key(name), Error::Invalid);
+                }
+            }
+        }
+    }
 }
 
 #[cfg(feature = "KHR_lights_punctual")]
@@ -82,7 +132,7 @@ pub mod khr_lights_punctual {
 
         /// Optional application specific data.
         #[serde(default)]
-        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "crate::extras::is_empty"))]
         pub extras: Extras,
 
         /// Intensity of the light source. `point` and `spot` lights use luminous intensity
@@ -93,7 +143,7 @@ pub mod khr_lights_punctual {
         /// Optional user-defined name for this object.
         #[cfg(feature = "names")]
         #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
-        pub name: Option<String>,
+        pub name: Option<crate::Str>,
 
         /// A distance cutoff at which the light's intensity may be considered to have reached
         /// zero.
@@ -193,6 +243,34 @@ pub mod khr_lights_punctual {
     }
 }
 
+#[cfg(feature = "KHR_node_visibility")]
+pub mod khr_node_visibility {
+    use gltf_derive::Validate;
+    use serde_derive::{Deserialize, Serialize};
+
+    /// Marks a node and its descendants as hidden.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    pub struct KhrNodeVisibility {
+        /// `false` if the node and all of its descendants are hidden.
+        #[serde(default = "visible_default")]
+        pub visible: bool,
+    }
+
+    impl Default for KhrNodeVisibility {
+        fn default() -> Self {
+            KhrNodeVisibility { visible: true }
+        }
+    }
+
+    fn visible_default() -> bool {
+        true
+    }
+}
+
 /// The root `Node`s of a scene.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Scene {}
+pub struct Scene {
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[serde(default, rename = "KHR_xmp_json_ld", skip_serializing_if = "Option::is_none")]
+    pub khr_xmp_json_ld: Option<crate::extensions::root::khr_xmp_json_ld::KhrXmpJsonLd>,
+}
@@ -1,14 +1,112 @@
 use gltf_derive::Validate;
 use serde_derive::{Serialize, Deserialize};
+#[cfg(any(feature = "EXT_texture_webp", feature = "MSFT_texture_dds"))]
+use crate::{image, Index};
+#[cfg(feature = "KHR_texture_transform")]
+use crate::Extras;
 
 /// Texture sampler properties for filtering and wrapping modes.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[non_exhaustive]
 pub struct Sampler {}
 
 /// A texture and its sampler.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Texture {}
+#[non_exhaustive]
+pub struct Texture {
+    #[cfg(feature = "EXT_texture_webp")]
+    #[serde(default, rename = "EXT_texture_webp", skip_serializing_if = "Option::is_none")]
+    pub texture_webp: Option<TextureWebp>,
+
+    #[cfg(feature = "MSFT_texture_dds")]
+    #[serde(default, rename = "MSFT_texture_dds", skip_serializing_if = "Option::is_none")]
+    pub texture_dds: Option<TextureDds>,
+}
+
+/// A WebP image used in place of the core source of a texture.
+#[cfg(feature = "EXT_texture_webp")]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+pub struct TextureWebp {
+    /// The index of the image with the `image/webp` MIME type.
+    pub source: Index<image::Image>,
+}
+
+/// A DDS image used in place of the core source of a texture.
+#[cfg(feature = "MSFT_texture_dds")]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+pub struct TextureDds {
+    /// The index of the image with the `image/vnd-ms.dds` MIME type.
+    pub source: Index<image::Image>,
+}
 
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
 /// Reference to a `Texture`.
-pub struct Info {}
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[non_exhaustive]
+pub struct Info {
+    #[cfg(feature = "KHR_texture_transform")]
+    #[serde(default, rename = "KHR_texture_transform", skip_serializing_if = "Option::is_none")]
+    pub texture_transform: Option<TextureTransform>,
+}
+
+/// Transforms the texture coordinates of a texture reference.
+///
+/// The coordinates are scaled, then rotated, then offset.
+#[cfg(feature = "KHR_texture_transform")]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+pub struct TextureTransform {
+    /// The offset of the UV coordinate origin as a factor of the texture
+    /// dimensions.
+    #[serde(default, skip_serializing_if = "is_offset_default")]
+    pub offset: [f32; 2],
+
+    /// The counter-clockwise rotation of the UV coordinates around their
+    /// origin, in radians.
+    #[serde(default, skip_serializing_if = "is_rotation_default")]
+    pub rotation: f32,
+
+    /// The scale factors of the UV coordinates.
+    #[serde(default = "scale_default", skip_serializing_if = "is_scale_default")]
+    pub scale: [f32; 2],
+
+    /// Overrides the `texCoord` set of the texture reference, if set.
+    #[serde(default, rename = "texCoord", skip_serializing_if = "Option::is_none")]
+    pub tex_coord: Option<u32>,
+
+    /// Optional application specific data.
+    #[serde(default)]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "crate::extras::is_empty"))]
+    pub extras: Extras,
+}
+
+#[cfg(feature = "KHR_texture_transform")]
+fn is_offset_default(offset: &[f32; 2]) -> bool {
+    *offset == [0.0, 0.0]
+}
+
+#[cfg(feature = "KHR_texture_transform")]
+fn is_rotation_default(rotation: &f32) -> bool {
+    *rotation == 0.0
+}
+
+#[cfg(feature = "KHR_texture_transform")]
+fn scale_default() -> [f32; 2] {
+    [1.0, 1.0]
+}
+
+#[cfg(feature = "KHR_texture_transform")]
+fn is_scale_default(scale: &[f32; 2]) -> bool {
+    *scale == scale_default()
+}
+
+#[cfg(feature = "KHR_texture_transform")]
+impl Default for TextureTransform {
+    fn default() -> Self {
+        TextureTransform {
+            offset: [0.0, 0.0],
+            rotation: 0.0,
+            scale: scale_default(),
+            tex_coord: None,
+            extras: Default::default(),
+        }
+    }
+}
@@ -0,0 +1,564 @@
+use std::collections::BTreeSet;
+
+use crate::edit::Error;
+use crate::identity::{self, Kind};
+use crate::{Index, Path, Root, Scene};
+
+/// Summary of the items removed by [`Root::extract_scene`].
+///
+/// [`Root::extract_scene`]: ../struct.Root.html#method.extract_scene
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Report {
+    /// The original indices of the animations that were removed because none
+    /// of their channels target a retained node.
+    pub removed_animations: Vec<usize>,
+
+    /// The original paths of the channels that were removed from retained
+    /// animations because they target removed nodes.
+    pub removed_channels: Vec<Path>,
+
+    /// The original indices of the retained buffers, in their new order.
+    ///
+    /// The data of the extracted document is `buffers[i]` of the original
+    /// document for every `i` in this list.
+    pub buffers: Vec<usize>,
+
+    /// The index in the extracted document of every item of the original
+    /// document, or `None` if the item was not retained.
+    pub remap: identity::Remap,
+}
+
+/// Maps the indices of retained items to their new indices.
+struct Remap(Vec<Option<u32>>);
+
+impl Remap {
+    fn new(retained: &BTreeSet<usize>, len: usize) -> Self {
+        let mut map = vec![None; len];
+        for (new, &old) in retained.iter().enumerate() {
+            map[old] = Some(new as u32);
+        }
+        Remap(map)
+    }
+
+    /// Returns the new index of a retained item.
+    ///
+    /// Every referenced item is retained, so `None` indicates an index out of
+    /// bounds.
+    fn apply<T>(&self, index: Index<T>, path: &dyn Fn() -> Path) -> Result<Index<T>, Error> {
+        self.0
+            .get(index.value())
+            .and_then(|x| *x)
+            .map(Index::new)
+            .ok_or_else(|| Error::IndexOutOfBounds(path()))
+    }
+
+    fn apply_option<T>(
+        &self,
+        index: &mut Option<Index<T>>,
+        path: &dyn Fn() -> Path,
+    ) -> Result<(), Error> {
+        if let Some(x) = index.as_mut() {
+            *x = self.apply(*x, path)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the elements of `items` whose indices are in `retained`.
+fn retain<T: Clone>(items: &[T], retained: &BTreeSet<usize>) -> Vec<T> {
+    retained.iter().filter_map(|&index| items.get(index).cloned()).collect()
+}
+
+/// Splits a JSON pointer such as `/nodes/3/translation` into the name of the
+/// array, the index, and the remaining path.
+#[cfg(feature = "KHR_animation_pointer")]
+fn split_pointer(pointer: &str) -> Option<(&str, usize, &str)> {
+    const LIGHTS: &str = "/extensions/KHR_lights_punctual/lights/";
+    let (array, rest) = if let Some(rest) = pointer.strip_prefix(LIGHTS) {
+        ("lights", rest)
+    } else {
+        let rest = pointer.strip_prefix('/')?;
+        let end = rest.find('/')?;
+        (&rest[..end], &rest[end + 1..])
+    };
+    let end = rest.find('/').unwrap_or(rest.len());
+    let index = rest[..end].parse().ok()?;
+    Some((array, index, &rest[end..]))
+}
+
+/// The retained items of each kind, as original indices.
+#[derive(Default)]
+struct Retained {
+    accessors: BTreeSet<usize>,
+    animations: BTreeSet<usize>,
+    buffers: BTreeSet<usize>,
+    cameras: BTreeSet<usize>,
+    images: BTreeSet<usize>,
+    lights: BTreeSet<usize>,
+    materials: BTreeSet<usize>,
+    meshes: BTreeSet<usize>,
+    nodes: BTreeSet<usize>,
+    samplers: BTreeSet<usize>,
+    skins: BTreeSet<usize>,
+    textures: BTreeSet<usize>,
+    views: BTreeSet<usize>,
+}
+
+/// The new indices of the retained items of each kind.
+struct Remaps {
+    accessors: Remap,
+    buffers: Remap,
+    cameras: Remap,
+    images: Remap,
+    #[cfg_attr(not(feature = "KHR_lights_punctual"), allow(dead_code))]
+    lights: Remap,
+    materials: Remap,
+    meshes: Remap,
+    nodes: Remap,
+    samplers: Remap,
+    skins: Remap,
+    textures: Remap,
+    views: Remap,
+}
+
+impl Root {
+    /// Returns a document containing only the given scene, the nodes
+    /// reachable from it, and everything these nodes reference.
+    ///
+    /// The retained nodes are the descendants of the root nodes of the scene,
+    /// together with the joints and skeletons of their skins and the
+    /// descendants of these. Meshes, cameras, lights, skins, materials,
+    /// textures, samplers, images, accessors, buffer views, and buffers are
+    /// retained if they are referenced by retained items. Items keep their
+    /// relative order and all indices are remapped. The extracted scene
+    /// becomes the only and default scene.
+    ///
+    /// Animation channels targeting removed nodes are removed, and so are
+    /// animations left without channels and samplers left without channels.
+    /// Channels of the `KHR_animation_pointer` extension are kept if their
+    /// pointer refers to a retained node, mesh, material, camera, or light.
+    /// The removals are listed in the returned report.
+    ///
+    /// Buffers are retained as a whole. Their unused bytes may be removed with
+    /// [`repack_buffers`]:
+    ///
+    /// ```
+    /// # use gltf_json::{repack::Layout, Index, Root};
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let root = Root::from_str(r#"{"asset": {"version": "2.0"}, "scenes": [{"nodes": []}]}"#)?;
+    /// # let buffers: Vec<Vec<u8>> = Vec::new();
+    /// let (mut extracted, report) = root.extract_scene(Index::new(0))?;
+    /// let mut data = report.buffers.iter().map(|&i| buffers[i].clone()).collect();
+    /// extracted.repack_buffers(&mut data, Layout::Separate)?;
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    ///
+    /// The document is expected to be valid. Other root extensions and the
+    /// lists of used and required extensions are copied unchanged.
+    ///
+    /// [`repack_buffers`]: #method.repack_buffers
+    pub fn extract_scene(&self, scene: Index<Scene>) -> Result<(Root, Report), Error> {
+        let scene_path = || Path::new().field("scenes").index(scene.value());
+        let source_scene = self.scenes.get(scene.value()).ok_or_else(|| Error::IndexOutOfBounds(scene_path()))?;
+        let mut report = Report::default();
+        let mut retained = Retained::default();
+
+        self.retain_nodes(source_scene, &mut retained);
+        for &index in &retained.nodes {
+            let node = &self.nodes[index];
+            retained.cameras.extend(node.camera.map(|x| x.value()));
+            retained.meshes.extend(node.mesh.map(|x| x.value()));
+            #[cfg(feature = "KHR_lights_punctual")]
+            {
+                let light = node.extensions.as_ref().and_then(|x| x.khr_lights_punctual.as_ref());
+                retained.lights.extend(light.map(|x| x.light.value()));
+            }
+            #[cfg(feature = "EXT_mesh_gpu_instancing")]
+            {
+                let instancing = node.extensions.as_ref().and_then(|x| x.ext_mesh_gpu_instancing.as_ref());
+                retained.accessors.extend(instancing.iter().flat_map(|x| x.attributes.values()).map(|x| x.value()));
+            }
+        }
+        for mesh in retained.meshes.iter().filter_map(|&index| self.meshes.get(index)) {
+            for primitive in &mesh.primitives {
+                retained.materials.extend(primitive.material.map(|x| x.value()));
+                retained.accessors.extend(primitive.attributes.accessors().map(|x| x.value()));
+                retained.accessors.extend(primitive.indices.map(|x| x.value()));
+                for target in primitive.targets.iter().flatten() {
+                    let accessors = [target.positions, target.normals, target.tangents];
+                    retained.accessors.extend(accessors.iter().flatten().map(|x| x.value()));
+                }
+            }
+        }
+        for skin in retained.skins.iter().filter_map(|&index| self.skins.get(index)) {
+            retained.accessors.extend(skin.inverse_bind_matrices.map(|x| x.value()));
+        }
+        for material in retained.materials.iter().filter_map(|&index| self.materials.get(index)) {
+            let pbr = &material.pbr_metallic_roughness;
+            let infos = [&pbr.base_color_texture, &pbr.metallic_roughness_texture, &material.emissive_texture];
+            retained.textures.extend(infos.iter().filter_map(|x| x.as_ref()).map(|x| x.index.value()));
+            retained.textures.extend(material.normal_texture.as_ref().map(|x| x.index.value()));
+            retained.textures.extend(material.occlusion_texture.as_ref().map(|x| x.index.value()));
+            #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+            {
+                let specular = material.extensions.as_ref().and_then(|x| x.pbr_specular_glossiness.as_ref());
+                if let Some(specular) = specular {
+                    let infos = [&specular.diffuse_texture, &specular.specular_glossiness_texture];
+                    retained.textures.extend(infos.iter().filter_map(|x| x.as_ref()).map(|x| x.index.value()));
+                }
+            }
+        }
+        for texture in retained.textures.iter().filter_map(|&index| self.textures.get(index)) {
+            retained.samplers.extend(texture.sampler.map(|x| x.value()));
+            retained.images.extend(texture.source.map(|x| x.value()));
+            #[cfg(feature = "EXT_texture_webp")]
+            retained.images.extend(texture.webp_source().map(|x| x.value()));
+            #[cfg(feature = "MSFT_texture_dds")]
+            retained.images.extend(texture.dds_source().map(|x| x.value()));
+        }
+
+        // The channels of every animation, before remapping.
+        let mut channels = Vec::with_capacity(self.animations.len());
+        for (index, animation) in self.animations.iter().enumerate() {
+            let mut kept = Vec::new();
+            let mut removed = Vec::new();
+            for (channel_index, channel) in animation.channels.iter().enumerate() {
+                if self.is_retained_target(&channel.target, &retained) {
+                    kept.push(channel_index);
+                } else {
+                    removed.push(Path::new().field("animations").index(index).field("channels").index(channel_index));
+                }
+            }
+            if kept.is_empty() {
+                report.removed_animations.push(index);
+            } else {
+                report.removed_channels.extend(removed);
+                retained.animations.insert(index);
+                for &channel_index in &kept {
+                    let sampler = animation.channels[channel_index].sampler.value();
+                    if let Some(sampler) = animation.samplers.get(sampler) {
+                        retained.accessors.insert(sampler.input.value());
+                        retained.accessors.insert(sampler.output.value());
+                    }
+                }
+            }
+            channels.push(kept);
+        }
+
+        for accessor in retained.accessors.iter().filter_map(|&index| self.accessors.get(index)) {
+            retained.views.extend(accessor.buffer_view.map(|x| x.value()));
+            if let Some(sparse) = accessor.sparse.as_ref() {
+                retained.views.insert(sparse.indices.buffer_view.value());
+                retained.views.insert(sparse.values.buffer_view.value());
+            }
+        }
+        for image in retained.images.iter().filter_map(|&index| self.images.get(index)) {
+            retained.views.extend(image.buffer_view.map(|x| x.value()));
+        }
+        for view in retained.views.iter().filter_map(|&index| self.buffer_views.get(index)) {
+            retained.buffers.insert(view.buffer.value());
+        }
+
+        #[cfg(feature = "KHR_lights_punctual")]
+        let light_count = self.extensions.as_ref()
+            .and_then(|x| x.khr_lights_punctual.as_ref())
+            .map_or(0, |x| x.lights.len());
+        #[cfg(not(feature = "KHR_lights_punctual"))]
+        let light_count = 0;
+        let remaps = Remaps {
+            accessors: Remap::new(&retained.accessors, self.accessors.len()),
+            buffers: Remap::new(&retained.buffers, self.buffers.len()),
+            cameras: Remap::new(&retained.cameras, self.cameras.len()),
+            images: Remap::new(&retained.images, self.images.len()),
+            lights: Remap::new(&retained.lights, light_count),
+            materials: Remap::new(&retained.materials, self.materials.len()),
+            meshes: Remap::new(&retained.meshes, self.meshes.len()),
+            nodes: Remap::new(&retained.nodes, self.nodes.len()),
+            samplers: Remap::new(&retained.samplers, self.samplers.len()),
+            skins: Remap::new(&retained.skins, self.skins.len()),
+            textures: Remap::new(&retained.textures, self.textures.len()),
+            views: Remap::new(&retained.views, self.buffer_views.len()),
+        };
+        report.buffers = retained.buffers.iter().cloned().filter(|&x| x < self.buffers.len()).collect();
+        let kinds = [
+            (Kind::Accessor, &remaps.accessors),
+            (Kind::Animation, &Remap::new(&retained.animations, self.animations.len())),
+            (Kind::Buffer, &remaps.buffers),
+            (Kind::BufferView, &remaps.views),
+            (Kind::Camera, &remaps.cameras),
+            (Kind::Image, &remaps.images),
+            (Kind::Material, &remaps.materials),
+            (Kind::Mesh, &remaps.meshes),
+            (Kind::Node, &remaps.nodes),
+            (Kind::Sampler, &remaps.samplers),
+            (Kind::Scene, &Remap::new(&std::iter::once(scene.value()).collect(), self.scenes.len())),
+            (Kind::Skin, &remaps.skins),
+            (Kind::Texture, &remaps.textures),
+        ];
+        for (kind, remap) in kinds {
+            report.remap.insert(kind, remap.0.iter().map(|x| x.map(|x| x as usize)).collect());
+        }
+
+        let mut root = Root {
+            accessors: retain(&self.accessors, &retained.accessors),
+            animations: Vec::new(),
+            asset: self.asset.clone(),
+            buffers: retain(&self.buffers, &retained.buffers),
+            buffer_views: retain(&self.buffer_views, &retained.views),
+            scene: Some(Index::new(0)),
+            extensions: self.extensions.clone(),
+            extras: self.extras.clone(),
+            extensions_used: self.extensions_used.clone(),
+            extensions_required: self.extensions_required.clone(),
+            cameras: retain(&self.cameras, &retained.cameras),
+            images: retain(&self.images, &retained.images),
+            materials: retain(&self.materials, &retained.materials),
+            meshes: retain(&self.meshes, &retained.meshes),
+            nodes: retain(&self.nodes, &retained.nodes),
+            samplers: retain(&self.samplers, &retained.samplers),
+            scenes: vec![source_scene.clone()],
+            skins: retain(&self.skins, &retained.skins),
+            textures: retain(&self.textures, &retained.textures),
+        };
+        #[cfg(feature = "KHR_lights_punctual")]
+        {
+            let lights = root.extensions.as_mut().and_then(|x| x.khr_lights_punctual.as_mut());
+            if let Some(lights) = lights {
+                lights.lights = retain(&lights.lights, &retained.lights);
+            }
+        }
+
+        for node in &mut root.scenes[0].nodes {
+            *node = remaps.nodes.apply(*node, &|| scene_path().field("nodes"))?;
+        }
+        for (node, &index) in root.nodes.iter_mut().zip(&retained.nodes) {
+            let path = || Path::new().field("nodes").index(index);
+            remaps.cameras.apply_option(&mut node.camera, &|| path().field("camera"))?;
+            remaps.meshes.apply_option(&mut node.mesh, &|| path().field("mesh"))?;
+            remaps.skins.apply_option(&mut node.skin, &|| path().field("skin"))?;
+            for child in node.children.iter_mut().flatten() {
+                *child = remaps.nodes.apply(*child, &|| path().field("children"))?;
+            }
+            #[cfg(feature = "KHR_lights_punctual")]
+            {
+                let light = node.extensions.as_mut().and_then(|x| x.khr_lights_punctual.as_mut());
+                if let Some(light) = light {
+                    light.light = remaps.lights.apply(light.light, &|| path().field("extensions"))?;
+                }
+            }
+            #[cfg(feature = "EXT_mesh_gpu_instancing")]
+            {
+                let instancing = node.extensions.as_mut().and_then(|x| x.ext_mesh_gpu_instancing.as_mut());
+                for accessor in instancing.into_iter().flat_map(|x| x.attributes.values_mut()) {
+                    *accessor = remaps.accessors.apply(*accessor, &|| path().field("extensions"))?;
+                }
+            }
+        }
+        for (mesh, &index) in root.meshes.iter_mut().zip(&retained.meshes) {
+            for (primitive_index, primitive) in mesh.primitives.iter_mut().enumerate() {
+                let path = || Path::new().field("meshes").index(index).field("primitives").index(primitive_index);
+                for accessor in primitive.attributes.accessors_mut() {
+                    *accessor = remaps.accessors.apply(*accessor, &|| path().field("attributes"))?;
+                }
+                remaps.accessors.apply_option(&mut primitive.indices, &|| path().field("indices"))?;
+                remaps.materials.apply_option(&mut primitive.material, &|| path().field("material"))?;
+                for target in primitive.targets.iter_mut().flatten() {
+                    let path = || path().field("targets");
+                    remaps.accessors.apply_option(&mut target.positions, &path)?;
+                    remaps.accessors.apply_option(&mut target.normals, &path)?;
+                    remaps.accessors.apply_option(&mut target.tangents, &path)?;
+                }
+            }
+        }
+        for (skin, &index) in root.skins.iter_mut().zip(&retained.skins) {
+            let path = || Path::new().field("skins").index(index);
+            remaps.accessors.apply_option(&mut skin.inverse_bind_matrices, &|| path().field("inverseBindMatrices"))?;
+            remaps.nodes.apply_option(&mut skin.skeleton, &|| path().field("skeleton"))?;
+            for joint in &mut skin.joints {
+                *joint = remaps.nodes.apply(*joint, &|| path().field("joints"))?;
+            }
+        }
+        for (material, &index) in root.materials.iter_mut().zip(&retained.materials) {
+            let path = || Path::new().field("materials").index(index);
+            let pbr = &mut material.pbr_metallic_roughness;
+            let mut infos = [
+                &mut pbr.base_color_texture,
+                &mut pbr.metallic_roughness_texture,
+                &mut material.emissive_texture,
+            ];
+            for info in infos.iter_mut().filter_map(|x| x.as_mut()) {
+                info.index = remaps.textures.apply(info.index, &path)?;
+            }
+            if let Some(normal) = material.normal_texture.as_mut() {
+                normal.index = remaps.textures.apply(normal.index, &path)?;
+            }
+            if let Some(occlusion) = material.occlusion_texture.as_mut() {
+                occlusion.index = remaps.textures.apply(occlusion.index, &path)?;
+            }
+            #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+            {
+                let specular = material.extensions.as_mut().and_then(|x| x.pbr_specular_glossiness.as_mut());
+                if let Some(specular) = specular {
+                    let mut infos = [&mut specular.diffuse_texture, &mut specular.specular_glossiness_texture];
+                    for info in infos.iter_mut().filter_map(|x| x.as_mut()) {
+                        info.index = remaps.textures.apply(info.index, &path)?;
+                    }
+                }
+            }
+        }
+        for (texture, &index) in root.textures.iter_mut().zip(&retained.textures) {
+            let path = || Path::new().field("textures").index(index);
+            remaps.samplers.apply_option(&mut texture.sampler, &|| path().field("sampler"))?;
+            remaps.images.apply_option(&mut texture.source, &|| path().field("source"))?;
+            #[cfg(feature = "EXT_texture_webp")]
+            {
+                let webp = texture.extensions.as_mut().and_then(|x| x.texture_webp.as_mut());
+                if let Some(webp) = webp {
+                    webp.source = remaps.images.apply(webp.source, &|| path().field("extensions"))?;
+                }
+            }
+            #[cfg(feature = "MSFT_texture_dds")]
+            {
+                let dds = texture.extensions.as_mut().and_then(|x| x.texture_dds.as_mut());
+                if let Some(dds) = dds {
+                    dds.source = remaps.images.apply(dds.source, &|| path().field("extensions"))?;
+                }
+            }
+        }
+        for (image, &index) in root.images.iter_mut().zip(&retained.images) {
+            let path = || Path::new().field("images").index(index).field("bufferView");
+            remaps.views.apply_option(&mut image.buffer_view, &path)?;
+        }
+        for (accessor, &index) in root.accessors.iter_mut().zip(&retained.accessors) {
+            let path = || Path::new().field("accessors").index(index);
+            remaps.views.apply_option(&mut accessor.buffer_view, &|| path().field("bufferView"))?;
+            if let Some(sparse) = accessor.sparse.as_mut() {
+                let path = || path().field("sparse");
+                sparse.indices.buffer_view = remaps.views.apply(sparse.indices.buffer_view, &path)?;
+                sparse.values.buffer_view = remaps.views.apply(sparse.values.buffer_view, &path)?;
+            }
+        }
+        for (view, &index) in root.buffer_views.iter_mut().zip(&retained.views) {
+            let path = || Path::new().field("bufferViews").index(index).field("buffer");
+            view.buffer = remaps.buffers.apply(view.buffer, &path)?;
+        }
+
+        for &index in &retained.animations {
+            root.animations.push(self.extract_animation(index, &channels[index], &remaps)?);
+        }
+        Ok((root, report))
+    }
+
+    /// Adds the nodes of a scene, their descendants, and the joints and
+    /// skeletons of their skins to `retained`.
+    fn retain_nodes(&self, scene: &Scene, retained: &mut Retained) {
+        let mut stack = scene.nodes.iter().map(|x| x.value()).collect::<Vec<_>>();
+        while let Some(index) = stack.pop() {
+            let node = match self.nodes.get(index) {
+                Some(node) if retained.nodes.insert(index) => node,
+                _ => continue,
+            };
+            stack.extend(node.children.iter().flatten().map(|x| x.value()));
+            if let Some(skin) = node.skin {
+                if retained.skins.insert(skin.value()) {
+                    if let Some(skin) = self.skins.get(skin.value()) {
+                        stack.extend(skin.joints.iter().map(|x| x.value()));
+                        stack.extend(skin.skeleton.map(|x| x.value()));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns `true` if the target of an animation channel is retained.
+    #[cfg_attr(not(feature = "KHR_animation_pointer"), allow(unused_variables))]
+    fn is_retained_target(&self, target: &crate::animation::Target, retained: &Retained) -> bool {
+        if let Some(node) = target.node {
+            return retained.nodes.contains(&node.value());
+        }
+        #[cfg(feature = "KHR_animation_pointer")]
+        {
+            let split = target.pointer().and_then(split_pointer);
+            let retained = match split {
+                Some(("nodes", ..)) => &retained.nodes,
+                Some(("meshes", ..)) => &retained.meshes,
+                Some(("materials", ..)) => &retained.materials,
+                Some(("cameras", ..)) => &retained.cameras,
+                Some(("lights", ..)) => &retained.lights,
+                _ => return false,
+            };
+            split.is_some_and(|(_, index, _)| retained.contains(&index))
+        }
+        #[cfg(not(feature = "KHR_animation_pointer"))]
+        {
+            false
+        }
+    }
+
+    /// Returns animation `index` with only the given channels and the samplers
+    /// they use, remapped into the extracted document.
+    fn extract_animation(
+        &self,
+        index: usize,
+        channels: &[usize],
+        remaps: &Remaps,
+    ) -> Result<crate::Animation, Error> {
+        let source = &self.animations[index];
+        let path = || Path::new().field("animations").index(index);
+        let mut samplers = channels
+            .iter()
+            .map(|&channel| source.channels[channel].sampler.value())
+            .collect::<BTreeSet<_>>();
+        samplers.retain(|&sampler| sampler < source.samplers.len());
+        let sampler_remap = Remap::new(&samplers, source.samplers.len());
+
+        let mut animation = source.clone();
+        animation.samplers = retain(&source.samplers, &samplers);
+        for (sampler, &sampler_index) in animation.samplers.iter_mut().zip(&samplers) {
+            let path = || path().field("samplers").index(sampler_index);
+            sampler.input = remaps.accessors.apply(sampler.input, &|| path().field("input"))?;
+            sampler.output = remaps.accessors.apply(sampler.output, &|| path().field("output"))?;
+        }
+        animation.channels = Vec::with_capacity(channels.len());
+        for &channel_index in channels {
+            let path = || path().field("channels").index(channel_index);
+            let mut channel = source.channels[channel_index].clone();
+            channel.sampler = sampler_remap.apply(channel.sampler, &|| path().field("sampler"))?;
+            remaps.nodes.apply_option(&mut channel.target.node, &|| path().field("target").field("node"))?;
+            #[cfg(feature = "KHR_animation_pointer")]
+            {
+                let pointer = channel.target.extensions.as_mut().and_then(|x| x.khr_animation_pointer.as_mut());
+                if let Some(pointer) = pointer {
+                    pointer.pointer = remap_pointer(&pointer.pointer, remaps)
+                        .ok_or_else(|| Error::Invalid(path().field("target").field("extensions")))?;
+                }
+            }
+            animation.channels.push(channel);
+        }
+        Ok(animation)
+    }
+}
+
+/// Rewrites the index of a JSON pointer into the extracted document.
+#[cfg(feature = "KHR_animation_pointer")]
+fn remap_pointer(pointer: &str, remaps: &Remaps) -> Option<String> {
+    let (array, index, rest) = split_pointer(pointer)?;
+    let remap = match array {
+        "nodes" => &remaps.nodes,
+        "meshes" => &remaps.meshes,
+        "materials" => &remaps.materials,
+        "cameras" => &remaps.cameras,
+        "lights" => &remaps.lights,
+        _ => return None,
+    };
+    let new = remap.0.get(index).and_then(|x| *x)?;
+    Some(if array == "lights" {
+        format!("/extensions/KHR_lights_punctual/lights/{}{}", new, rest)
+    } else {
+        format!("/{}/{}{}", array, new, rest)
+    })
+}
@@ -1,18 +1,163 @@
 use gltf_derive::Validate;
+use serde::de::DeserializeOwned;
 use serde_derive::{Serialize, Deserialize};
+use serde_json::Value;
 use std::fmt;
 
 #[cfg(feature = "extras")]
 pub use serde_json::value::RawValue;
 
+#[cfg(feature = "extras")]
+use std::{ops, sync::OnceLock};
+
 /// Data type of the `extras` attribute on all glTF objects.
+///
+/// The extras are kept as raw JSON text, which the type dereferences to, and
+/// parsed the first time they are read through [`ParseExtras`].
 #[cfg(feature = "extras")]
-pub type Extras = Option<::std::boxed::Box<RawValue>>;
+#[derive(Clone, Default)]
+pub struct Extras {
+    raw: Option<Box<RawValue>>,
+    value: OnceLock<Option<Value>>,
+}
 
 /// Data type of the `extras` attribute on all glTF objects.
 #[cfg(not(feature = "extras"))]
 pub type Extras = Void;
 
+#[cfg(feature = "extras")]
+impl Extras {
+    /// Returns the extras parsed as a generic JSON value, parsing them on the
+    /// first call only.
+    fn value(&self) -> Option<&Value> {
+        self.value
+            .get_or_init(|| self.raw.as_ref().and_then(|x| serde_json::from_str(x.get()).ok()))
+            .as_ref()
+    }
+}
+
+#[cfg(feature = "extras")]
+impl ops::Deref for Extras {
+    type Target = Option<Box<RawValue>>;
+    fn deref(&self) -> &Self::Target {
+        &self.raw
+    }
+}
+
+#[cfg(feature = "extras")]
+impl From<Option<Box<RawValue>>> for Extras {
+    fn from(raw: Option<Box<RawValue>>) -> Self {
+        Extras { raw, value: OnceLock::new() }
+    }
+}
+
+#[cfg(feature = "extras")]
+impl From<Extras> for Option<Box<RawValue>> {
+    fn from(extras: Extras) -> Self {
+        extras.raw
+    }
+}
+
+#[cfg(feature = "extras")]
+impl fmt::Debug for Extras {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.raw, f)
+    }
+}
+
+#[cfg(feature = "extras")]
+impl serde::Serialize for Extras {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde::Serialize::serialize(&self.raw, serializer)
+    }
+}
+
+#[cfg(feature = "extras")]
+impl<'de> serde::Deserialize<'de> for Extras {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        <Option<Box<RawValue>> as serde::Deserialize>::deserialize(deserializer).map(Extras::from)
+    }
+}
+
+#[cfg(feature = "extras")]
+impl crate::validation::Validate for Extras {}
+
+/// Returns `true` if no application specific data is present.
+#[cfg(feature = "extras")]
+pub(crate) fn is_empty(extras: &Extras) -> bool {
+    extras.raw.is_none()
+}
+
+/// Returns `true` if no application specific data is present.
+#[cfg(not(feature = "extras"))]
+pub(crate) fn is_empty(_: &Extras) -> bool {
+    true
+}
+
+/// On-demand access to the application specific data of an object.
+///
+/// With the `extras` feature, which is enabled by default, extras are kept as
+/// raw JSON text when a document is parsed, so that large extras cost no more
+/// than a copy of their bytes until they are read. The first read parses them
+/// once and later reads reuse the result. Without the feature they are
+/// discarded and both functions return `None`.
+///
+/// ```
+/// use gltf_json::extras::ParseExtras;
+///
+/// let node: gltf_json::Node = gltf_json::deserialize::from_str(
+///     r#"{"extras": {"part": "A-113", "mass": 2.5}}"#,
+/// ).unwrap();
+/// # #[cfg(feature = "extras")]
+/// # {
+/// #[derive(serde_derive::Deserialize)]
+/// struct Part {
+///     part: String,
+/// }
+/// let part = node.extras.parse::<Part>().unwrap().unwrap();
+/// assert_eq!(part.part, "A-113");
+/// assert_eq!(node.extras.as_value().unwrap()["mass"], 2.5);
+/// # }
+/// # #[cfg(not(feature = "extras"))]
+/// assert!(node.extras.as_value().is_none());
+/// ```
+pub trait ParseExtras {
+    /// Deserializes the extras as `T`, or returns `None` if there are none.
+    fn parse<T: DeserializeOwned>(&self) -> Option<Result<T, serde_json::Error>>;
+
+    /// Returns the extras as a generic JSON value, or `None` if there are
+    /// none.
+    fn as_value(&self) -> Option<&Value>;
+}
+
+#[cfg(feature = "extras")]
+impl ParseExtras for Extras {
+    fn parse<T: DeserializeOwned>(&self) -> Option<Result<T, serde_json::Error>> {
+        self.value().map(T::deserialize)
+    }
+
+    fn as_value(&self) -> Option<&Value> {
+        self.value()
+    }
+}
+
+#[cfg(not(feature = "extras"))]
+impl ParseExtras for Extras {
+    fn parse<T: DeserializeOwned>(&self) -> Option<Result<T, serde_json::Error>> {
+        None
+    }
+
+    fn as_value(&self) -> Option<&Value> {
+        None
+    }
+}
+
 /// Type representing no user-defined data.
 #[derive(Clone, Default, Serialize, Deserialize, Validate)]
 pub struct Void {
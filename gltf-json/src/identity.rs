@@ -0,0 +1,231 @@
+use std::collections::BTreeMap;
+
+use crate::Root;
+
+/// A stable identity of an object of a document, which is kept while edits
+/// move the object to other indices.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Id(u64);
+
+impl Id {
+    /// Returns the numeric value of the ID.
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+}
+
+/// The top-level arrays of a document whose objects are tracked.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Kind {
+    /// `accessors`.
+    Accessor,
+
+    /// `animations`.
+    Animation,
+
+    /// `buffers`.
+    Buffer,
+
+    /// `bufferViews`.
+    BufferView,
+
+    /// `cameras`.
+    Camera,
+
+    /// `images`.
+    Image,
+
+    /// `materials`.
+    Material,
+
+    /// `meshes`.
+    Mesh,
+
+    /// `nodes`.
+    Node,
+
+    /// `samplers`.
+    Sampler,
+
+    /// `scenes`.
+    Scene,
+
+    /// `skins`.
+    Skin,
+
+    /// `textures`.
+    Texture,
+}
+
+impl Kind {
+    /// Every kind, in document order.
+    pub const ALL: [Kind; 13] = [
+        Kind::Accessor,
+        Kind::Animation,
+        Kind::Buffer,
+        Kind::BufferView,
+        Kind::Camera,
+        Kind::Image,
+        Kind::Material,
+        Kind::Mesh,
+        Kind::Node,
+        Kind::Sampler,
+        Kind::Scene,
+        Kind::Skin,
+        Kind::Texture,
+    ];
+
+    /// Returns the number of objects of this kind in a document.
+    pub fn count(&self, root: &Root) -> usize {
+        match self {
+            Kind::Accessor => root.accessors.len(),
+            Kind::Animation => root.animations.len(),
+            Kind::Buffer => root.buffers.len(),
+            Kind::BufferView => root.buffer_views.len(),
+            Kind::Camera => root.cameras.len(),
+            Kind::Image => root.images.len(),
+            Kind::Material => root.materials.len(),
+            Kind::Mesh => root.meshes.len(),
+            Kind::Node => root.nodes.len(),
+            Kind::Sampler => root.samplers.len(),
+            Kind::Scene => root.scenes.len(),
+            Kind::Skin => root.skins.len(),
+            Kind::Texture => root.textures.len(),
+        }
+    }
+}
+
+/// The new indices of the objects of a document after an edit that removes
+/// or reorders them, as reported by [`Root::deduplicate_accessors`],
+/// [`Root::extract_scene`], and [`Root::repack_buffers`].
+///
+/// [`Root::deduplicate_accessors`]: ../struct.Root.html#method.deduplicate_accessors
+/// [`Root::extract_scene`]: ../struct.Root.html#method.extract_scene
+/// [`Root::repack_buffers`]: ../struct.Root.html#method.repack_buffers
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Remap(BTreeMap<Kind, Vec<Option<usize>>>);
+
+impl Remap {
+    /// Returns the new index of an object, or `None` if it was removed.
+    ///
+    /// A removed object that was merged into another one, such as a duplicate
+    /// accessor, maps to the index of the object replacing it. Objects of
+    /// kinds that the edit did not change keep their index.
+    pub fn get(&self, kind: Kind, index: usize) -> Option<usize> {
+        match self.0.get(&kind) {
+            Some(map) => map.get(index).copied().flatten(),
+            None => Some(index),
+        }
+    }
+
+    /// Records the new index of every object of a kind, by original index.
+    pub(crate) fn insert(&mut self, kind: Kind, map: Vec<Option<usize>>) {
+        self.0.insert(kind, map);
+    }
+}
+
+/// A side table assigning an [`Id`] to every object of a document.
+///
+/// IDs are assigned in document order when the table is created, and to
+/// objects appended later by [`sync`]. Edits that remove or reorder objects
+/// report a [`Remap`], which [`apply`] uses to carry the IDs along.
+///
+/// ```
+/// use gltf_json::identity::{Identities, Kind};
+/// use gltf_json::Root;
+///
+/// let json = r#"{
+///     "asset": {"version": "2.0"},
+///     "accessors": [
+///         {"componentType": 5126, "count": 1, "type": "SCALAR"},
+///         {"componentType": 5126, "count": 1, "type": "SCALAR"},
+///         {"componentType": 5126, "count": 1, "type": "VEC2"}
+///     ]
+/// }"#;
+/// let mut root = Root::from_str(json).unwrap();
+/// let mut identities = Identities::new(&root);
+/// let id = identities.get(Kind::Accessor, 2).unwrap();
+///
+/// let report = root.deduplicate_accessors();
+/// let moved = identities.apply(&report.remap, &root);
+/// assert_eq!(moved[&id], Some(1));
+/// assert_eq!(identities.find(id), Some((Kind::Accessor, 1)));
+/// ```
+///
+/// [`Id`]: struct.Id.html
+/// [`Remap`]: struct.Remap.html
+/// [`sync`]: #method.sync
+/// [`apply`]: #method.apply
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Identities {
+    ids: BTreeMap<Kind, Vec<Id>>,
+    next: u64,
+}
+
+impl Identities {
+    /// Assigns an ID to every object of a document.
+    pub fn new(root: &Root) -> Self {
+        let mut identities = Identities::default();
+        identities.sync(root);
+        identities
+    }
+
+    fn allocate(&mut self) -> Id {
+        self.next += 1;
+        Id(self.next - 1)
+    }
+
+    /// Assigns new IDs to the objects appended to the document since the
+    /// table was last updated, and forgets the IDs of objects past the end of
+    /// their arrays.
+    pub fn sync(&mut self, root: &Root) {
+        for kind in Kind::ALL {
+            let len = kind.count(root);
+            let mut ids = self.ids.remove(&kind).unwrap_or_default();
+            ids.truncate(len);
+            while ids.len() < len {
+                ids.push(self.allocate());
+            }
+            self.ids.insert(kind, ids);
+        }
+    }
+
+    /// Returns the ID of an object, or `None` if the index is out of range.
+    pub fn get(&self, kind: Kind, index: usize) -> Option<Id> {
+        self.ids.get(&kind).and_then(|x| x.get(index)).copied()
+    }
+
+    /// Returns the kind and current index of the object with an ID, or `None`
+    /// if the object was removed.
+    pub fn find(&self, id: Id) -> Option<(Kind, usize)> {
+        self.ids
+            .iter()
+            .find_map(|(&kind, ids)| ids.iter().position(|&x| x == id).map(|index| (kind, index)))
+    }
+
+    /// Updates the table after an edit of `root`, which is the edited
+    /// document, and returns the new index of every previously tracked ID.
+    ///
+    /// Objects keep their IDs at their new indices. The ID of a removed object
+    /// maps to `None`, or to the index of the object it was merged into, and
+    /// is forgotten. New objects receive new IDs, as [`sync`] does.
+    ///
+    /// [`sync`]: #method.sync
+    pub fn apply(&mut self, remap: &Remap, root: &Root) -> BTreeMap<Id, Option<usize>> {
+        let mut moved = BTreeMap::new();
+        for kind in Kind::ALL {
+            let mut ids = vec![None; kind.count(root)];
+            for (index, &id) in self.ids.get(&kind).into_iter().flatten().enumerate() {
+                let new = remap.get(kind, index);
+                moved.insert(id, new);
+                if let Some(slot) = new.and_then(|x| ids.get_mut(x)) {
+                    // Objects merged into another one follow it, whose ID is kept.
+                    slot.get_or_insert(id);
+                }
+            }
+            let ids = ids.into_iter().map(|x| x.unwrap_or_else(|| self.allocate())).collect();
+            self.ids.insert(kind, ids);
+        }
+        moved
+    }
+}
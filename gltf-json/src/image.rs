@@ -1,16 +1,21 @@
-use gltf_derive::Validate;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{de, ser};
 use serde_derive::{Serialize, Deserialize};
-use crate::validation::Validate;
-use crate::{buffer, extensions, Extras, Index};
+use crate::validation::{Error, Validate};
+use crate::{buffer, extensions, Extras, Index, Path, Root};
 
 /// All valid MIME types.
 pub const VALID_MIME_TYPES: &'static [&'static str] = &[
     "image/jpeg",
     "image/png",
+    "image/webp",
+    "image/vnd-ms.dds",
 ];
 
 /// Image data used to create a texture.
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct Image {
     /// The index of the buffer view that contains the image. Use this instead of
     /// the image's uri property.
@@ -26,13 +31,13 @@ pub struct Image {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
-    pub name: Option<String>,
+    pub name: Option<crate::Str>,
 
     /// The uri of the image.  Relative paths are relative to the .gltf file.
     /// Instead of referencing an external file, the uri can also be a data-uri.
     /// The image format must be jpg or png.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub uri: Option<String>,
+    pub uri: Option<crate::Str>,
 
     /// Extension specific data.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -40,12 +45,133 @@ pub struct Image {
 
     /// Optional application specific data.
     #[serde(default)]
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "crate::extras::is_empty"))]
     pub extras: Extras,
 }
 
+impl Validate for Image {
+    fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+    {
+        // spec: images **must** define either `uri` or `bufferView`, and
+        // `mimeType` with the latter.
+        if self.buffer_view.is_none() && self.uri.is_none() {
+            report(&|| path().field("uri"), Error::Missing);
+        }
+        if self.buffer_view.is_some() && self.mime_type.is_none() {
+            report(&|| path().field("mimeType"), Error::Missing);
+        }
+
+        self.buffer_view.validate(root, || path().field("bufferView"), report);
+        self.mime_type.validate(root, || path().field("mimeType"), report);
+        self.uri.validate(root, || path().field("uri"), report);
+        self.extensions.validate(root, || path().field("extensions"), report);
+        self.extras.validate(root, || path().field("extras"), report);
+    }
+}
+
 /// An image MIME type.
-#[derive(Clone, Debug, Deserialize, Serialize)]
-pub struct MimeType(pub String);
+///
+/// Types that the library does not know are kept as `Other` rather than
+/// rejected, since extensions may define new image formats.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum MimeType {
+    /// `image/jpeg`.
+    Jpeg,
+
+    /// `image/png`.
+    Png,
+
+    /// `image/webp`, defined by `EXT_texture_webp`.
+    Webp,
+
+    /// `image/ktx2`, defined by `KHR_texture_basisu`.
+    Ktx2,
+
+    /// `image/vnd-ms.dds`, defined by `MSFT_texture_dds`.
+    Dds,
+
+    /// Any other MIME type.
+    Other(String),
+}
+
+impl MimeType {
+    /// Returns the MIME type as a string, for example `"image/png"`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            MimeType::Jpeg => "image/jpeg",
+            MimeType::Png => "image/png",
+            MimeType::Webp => "image/webp",
+            MimeType::Ktx2 => "image/ktx2",
+            MimeType::Dds => "image/vnd-ms.dds",
+            MimeType::Other(mime_type) => mime_type,
+        }
+    }
+
+    /// Returns the name of the extension defining this MIME type, or `None`
+    /// for the types of the core specification and unknown types.
+    pub fn extension(&self) -> Option<&'static str> {
+        match self {
+            MimeType::Webp => Some("EXT_texture_webp"),
+            MimeType::Ktx2 => Some("KHR_texture_basisu"),
+            MimeType::Dds => Some("MSFT_texture_dds"),
+            _ => None,
+        }
+    }
+}
 
-impl Validate for MimeType {}
+impl From<&str> for MimeType {
+    fn from(mime_type: &str) -> Self {
+        match mime_type {
+            "image/jpeg" => MimeType::Jpeg,
+            "image/png" => MimeType::Png,
+            "image/webp" => MimeType::Webp,
+            "image/ktx2" => MimeType::Ktx2,
+            "image/vnd-ms.dds" => MimeType::Dds,
+            _ => MimeType::Other(mime_type.to_string()),
+        }
+    }
+}
+
+impl FromStr for MimeType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(mime_type: &str) -> Result<Self, Self::Err> {
+        Ok(MimeType::from(mime_type))
+    }
+}
+
+impl fmt::Display for MimeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl ser::Serialize for MimeType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: ser::Serializer
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> de::Deserialize<'de> for MimeType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: de::Deserializer<'de>
+    {
+        let mime_type = <String as de::Deserialize>::deserialize(deserializer)?;
+        Ok(MimeType::from(mime_type.as_str()))
+    }
+}
+
+impl Validate for MimeType {
+    fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+    {
+        if let Some(extension) = self.extension() {
+            if !root.extensions_used.iter().any(|x| x == extension) {
+                report(&path, Error::UndeclaredExtension);
+            }
+        }
+    }
+}
@@ -0,0 +1,196 @@
+use std::borrow::Borrow;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::{fmt, ops};
+
+use serde::{de, ser};
+
+use crate::validation::Validate;
+use crate::Root;
+
+/// An immutable string whose allocation may be shared by its clones.
+///
+/// The names and URIs of glTF objects are stored as `Str`, so that equal
+/// strings can share one allocation after [`Root::intern_strings`]. A `Str`
+/// dereferences to `str` and is serialized as a JSON string.
+///
+/// [`Root::intern_strings`]: ../struct.Root.html#method.intern_strings
+#[derive(Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Str(Arc<str>);
+
+impl Str {
+    /// Returns `true` if both strings share one allocation.
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        Arc::ptr_eq(&this.0, &other.0)
+    }
+
+    /// Returns the string as a `&str`.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl ops::Deref for Str {
+    type Target = str;
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Str {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for Str {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for Str {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&*self.0, f)
+    }
+}
+
+impl fmt::Display for Str {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&*self.0, f)
+    }
+}
+
+impl From<&str> for Str {
+    fn from(value: &str) -> Self {
+        Str(value.into())
+    }
+}
+
+impl From<String> for Str {
+    fn from(value: String) -> Self {
+        Str(value.into())
+    }
+}
+
+impl From<Str> for String {
+    fn from(value: Str) -> Self {
+        value.0.to_string()
+    }
+}
+
+impl PartialEq<str> for Str {
+    fn eq(&self, other: &str) -> bool {
+        &*self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Str {
+    fn eq(&self, other: &&str) -> bool {
+        &*self.0 == *other
+    }
+}
+
+impl PartialEq<String> for Str {
+    fn eq(&self, other: &String) -> bool {
+        *self.0 == **other
+    }
+}
+
+impl ser::Serialize for Str {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> de::Deserialize<'de> for Str {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(Str::from)
+    }
+}
+
+impl Validate for Str {}
+
+/// The set of distinct strings seen by [`Root::intern_strings`].
+#[derive(Default)]
+struct Interner {
+    strings: HashSet<Str>,
+    shared: usize,
+}
+
+impl Interner {
+    /// Replaces `value` by an equal string seen before, if any.
+    fn intern(&mut self, value: &mut Option<Str>) {
+        if let Some(value) = value {
+            match self.strings.get(value.as_str()) {
+                Some(seen) if !Str::ptr_eq(seen, value) => {
+                    *value = seen.clone();
+                    self.shared += 1;
+                },
+                Some(_) => {},
+                None => {
+                    self.strings.insert(value.clone());
+                },
+            }
+        }
+    }
+}
+
+impl Root {
+    /// Makes equal names and URIs of the document share one allocation, and
+    /// returns the number of strings whose allocation was released.
+    ///
+    /// Documents written by tiling tools, for example, name thousands of
+    /// nodes alike and reference the same images many times. The strings are
+    /// unchanged.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut root = gltf_json::Root::from_str(r#"{
+    ///     "asset": {"version": "2.0"},
+    ///     "images": [{"uri": "tile.png"}, {"uri": "tile.png"}]
+    /// }"#)?;
+    /// assert_eq!(root.intern_strings(), 1);
+    /// let (a, b) = (root.images[0].uri.as_ref().unwrap(), root.images[1].uri.as_ref().unwrap());
+    /// assert!(gltf_json::Str::ptr_eq(a, b));
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    pub fn intern_strings(&mut self) -> usize {
+        let mut interner = Interner::default();
+        for buffer in &mut self.buffers {
+            interner.intern(&mut buffer.uri);
+        }
+        for image in &mut self.images {
+            interner.intern(&mut image.uri);
+        }
+        #[cfg(feature = "names")]
+        {
+            macro_rules! names {
+                ($($items:expr),*) => {
+                    $(for item in $items.iter_mut() {
+                        interner.intern(&mut item.name);
+                    })*
+                };
+            }
+            names!(
+                self.accessors, self.animations, self.buffers, self.buffer_views, self.cameras, self.images,
+                self.materials, self.meshes, self.nodes, self.samplers, self.scenes, self.skins, self.textures
+            );
+            #[cfg(feature = "KHR_lights_punctual")]
+            if let Some(lights) = self.extensions.as_mut().and_then(|x| x.khr_lights_punctual.as_mut()) {
+                names!(lights.lights);
+            }
+        }
+        interner.shared
+    }
+}
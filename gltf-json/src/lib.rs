@@ -13,16 +13,36 @@ pub mod buffer;
 /// Contains `Camera` and other related data structures.
 pub mod camera;
 
+/// Contains `SerializeOptions` and `Root` methods for canonical JSON output.
+pub mod canonical;
+
+/// Contains `Root` methods for finding and removing duplicate accessors.
+pub mod dedup;
+
+/// Contains `Root` methods for editing the node hierarchy.
+pub mod edit;
+
 /// Contains extension specific data structures and the names of all
 /// 2.0 extensions supported by the library.
 pub mod extensions;
 
-/// Contains `Extras`.
+/// Contains `Root` methods for extracting a single scene.
+pub mod extract;
+
+/// Contains `Extras` and the `ParseExtras` trait for reading them on demand.
 pub mod extras;
 
+/// Contains `Identities`, which assigns stable IDs to the objects of a
+/// document.
+pub mod identity;
+
 /// Contains `Image` and other related data structures.
 pub mod image;
 
+/// Contains `Str`, the shared string type of names and URIs, and the `Root`
+/// method for interning them.
+pub mod intern;
+
 /// Contains `Material` and other related data structures.
 pub mod material;
 
@@ -32,6 +52,10 @@ pub mod mesh;
 /// Contains `Path`.
 pub mod path;
 
+/// Contains `Root` methods for repacking buffer data and rewriting accessor
+/// data.
+pub mod repack;
+
 /// Contains `Root`.
 pub mod root;
 
@@ -41,6 +65,10 @@ pub mod scene;
 /// Contains `Skin` and other related data structures.
 pub mod skin;
 
+/// Contains `StripOptions` and the `Root` method for removing names, extras,
+/// and extensions.
+pub mod strip;
+
 /// Contains `Texture`, `Sampler`, and other related data structures.
 pub mod texture;
 
@@ -75,6 +103,8 @@ pub use texture::Texture;
 #[doc(inline)]
 pub use self::extras::Extras;
 #[doc(inline)]
+pub use self::intern::Str;
+#[doc(inline)]
 pub use self::path::Path;
 #[doc(inline)]
 pub use self::root::Index;
@@ -90,10 +120,10 @@ pub use serde_json::Value;
 ///
 /// This module re-exports the generic serde deserialization functions
 /// so that one can deserialize data structures other than `Root` without
-/// being bound to a specific version of `serde_json`.
-pub mod deserialize {
-    pub use serde_json::{from_reader, from_slice, from_str, from_value};
-}
+/// being bound to a specific version of `serde_json`, together with
+/// functions for inspecting JSON text that `serde_json` would accept or
+/// reject as a whole.
+pub mod deserialize;
 
 /// Re-exports of `serde_json` serialization functions.
 ///
@@ -102,4 +132,15 @@ pub mod deserialize {
 /// being bound to a specific version of `serde_json`.
 pub mod serialize {
     pub use serde_json::{to_string, to_string_pretty, to_value, to_vec, to_vec_pretty, to_writer, to_writer_pretty};
+    pub use serde_json::ser::Formatter;
+
+    /// Serializes `value` to a writer, with the output shaped by `formatter`.
+    pub fn to_writer_with_formatter<W, F, T>(writer: W, formatter: F, value: &T) -> serde_json::Result<()>
+    where
+        W: std::io::Write,
+        F: Formatter,
+        T: ?Sized + serde::Serialize,
+    {
+        value.serialize(&mut serde_json::Serializer::with_formatter(writer, formatter))
+    }
 }
@@ -2,8 +2,8 @@ use gltf_derive::Validate;
 use serde_derive::{Serialize, Deserialize};
 use serde::{de, ser};
 use std::fmt;
-use crate::validation::{Checked, Validate};
-use crate::{extensions, texture, Extras, Index};
+use crate::validation::{Checked, Error, Validate};
+use crate::{extensions, texture, Extras, Index, Path, Root};
 
 /// All valid alpha modes.
 pub const VALID_ALPHA_MODES: &'static [&'static str] = &[
@@ -12,6 +12,41 @@ pub const VALID_ALPHA_MODES: &'static [&'static str] = &[
     "BLEND",
 ];
 
+/// The default values of material properties, as defined by the
+/// specification.
+///
+/// Properties equal to their default value are omitted when serializing.
+pub mod defaults {
+    use super::AlphaMode;
+
+    /// The default `alphaCutoff` of a material.
+    pub const ALPHA_CUTOFF: f32 = 0.5;
+
+    /// The default `alphaMode` of a material.
+    pub const ALPHA_MODE: AlphaMode = AlphaMode::Opaque;
+
+    /// The default `doubleSided` of a material.
+    pub const DOUBLE_SIDED: bool = false;
+
+    /// The default `emissiveFactor` of a material.
+    pub const EMISSIVE_FACTOR: [f32; 3] = [0.0, 0.0, 0.0];
+
+    /// The default `baseColorFactor` of `pbrMetallicRoughness`.
+    pub const BASE_COLOR_FACTOR: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+
+    /// The default `metallicFactor` of `pbrMetallicRoughness`.
+    pub const METALLIC_FACTOR: f32 = 1.0;
+
+    /// The default `roughnessFactor` of `pbrMetallicRoughness`.
+    pub const ROUGHNESS_FACTOR: f32 = 1.0;
+
+    /// The default `scale` of a normal texture.
+    pub const NORMAL_SCALE: f32 = 1.0;
+
+    /// The default `strength` of an occlusion texture.
+    pub const OCCLUSION_STRENGTH: f32 = 1.0;
+}
+
 /// The alpha rendering mode of a material.
 #[derive(Clone, Copy, Eq, PartialEq, Debug)]
 pub enum AlphaMode {
@@ -44,7 +79,7 @@ impl ser::Serialize for AlphaMode {
 #[serde(default)]
 pub struct Material {
     /// The alpha cutoff value of the material.
-    #[serde(rename = "alphaCutoff")]
+    #[serde(rename = "alphaCutoff", skip_serializing_if = "is_alpha_cutoff_default")]
     pub alpha_cutoff: AlphaCutoff,
     
     /// The alpha rendering mode of the material.
@@ -63,7 +98,7 @@ pub struct Material {
     ///   destination areas and the rendered output is combined with the
     ///   background using the normal painting operation (i.e. the Porter and
     ///   Duff over operator).
-    #[serde(rename = "alphaMode")]
+    #[serde(rename = "alphaMode", skip_serializing_if = "is_alpha_mode_default")]
     pub alpha_mode: Checked<AlphaMode>,
 
     /// Specifies whether the material is double-sided.
@@ -75,18 +110,19 @@ pub struct Material {
     ///
     /// The back-face must have its normals reversed before the lighting
     /// equation is evaluated.
-    #[serde(rename = "doubleSided")]
+    #[serde(rename = "doubleSided", skip_serializing_if = "is_double_sided_default")]
     pub double_sided: bool,
 
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
-    pub name: Option<String>,
+    pub name: Option<crate::Str>,
 
     /// A set of parameter values that are used to define the metallic-roughness
     /// material model from Physically-Based Rendering (PBR) methodology. When not
     /// specified, all the default values of `pbrMetallicRoughness` apply.
     #[serde(default, rename = "pbrMetallicRoughness")]
+    #[serde(skip_serializing_if = "PbrMetallicRoughness::is_default")]
     pub pbr_metallic_roughness: PbrMetallicRoughness,
 
     /// A tangent space normal map. The texture contains RGB components in linear
@@ -116,7 +152,7 @@ pub struct Material {
     pub emissive_texture: Option<texture::Info>,
 
     /// The emissive color of the material.
-    #[serde(rename = "emissiveFactor")]
+    #[serde(rename = "emissiveFactor", skip_serializing_if = "is_emissive_factor_default")]
     pub emissive_factor: EmissiveFactor,
 
     /// Extension specific data.
@@ -124,7 +160,7 @@ pub struct Material {
     pub extensions: Option<extensions::material::Material>,
 
     /// Optional application specific data.
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "crate::extras::is_empty"))]
     pub extras: Extras,
 }
 
@@ -134,7 +170,7 @@ pub struct Material {
 #[serde(default)]
 pub struct PbrMetallicRoughness {
     /// The material's base color factor.
-    #[serde(rename = "baseColorFactor")]
+    #[serde(rename = "baseColorFactor", skip_serializing_if = "is_base_color_factor_default")]
     pub base_color_factor: PbrBaseColorFactor,
 
     /// The base color texture.
@@ -143,14 +179,14 @@ pub struct PbrMetallicRoughness {
     pub base_color_texture: Option<texture::Info>,
 
     /// The metalness of the material.
-    #[serde(rename = "metallicFactor")]
+    #[serde(rename = "metallicFactor", skip_serializing_if = "is_metallic_factor_default")]
     pub metallic_factor: StrengthFactor,
 
     /// The roughness of the material.
     ///
     /// * A value of 1.0 means the material is completely rough.
     /// * A value of 0.0 means the material is completely smooth.
-    #[serde(rename = "roughnessFactor")]
+    #[serde(rename = "roughnessFactor", skip_serializing_if = "is_roughness_factor_default")]
     pub roughness_factor: StrengthFactor,
 
     /// The metallic-roughness texture.
@@ -170,10 +206,86 @@ pub struct PbrMetallicRoughness {
     pub extensions: Option<extensions::material::PbrMetallicRoughness>,
 
     /// Optional application specific data.
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "crate::extras::is_empty"))]
     pub extras: Extras,
 }
 
+impl Material {
+    /// Returns the `TEXCOORD` set every texture of the material is sampled
+    /// with, after any `KHR_texture_transform` override, sorted and without
+    /// duplicates.
+    pub fn tex_coords(&self) -> Vec<u32> {
+        let pbr = &self.pbr_metallic_roughness;
+        let mut tex_coords = [&pbr.base_color_texture, &pbr.metallic_roughness_texture, &self.emissive_texture]
+            .iter()
+            .filter_map(|info| info.as_ref().map(texture::Info::effective_tex_coord))
+            .chain(self.normal_texture.as_ref().map(NormalTexture::effective_tex_coord))
+            .chain(self.occlusion_texture.as_ref().map(OcclusionTexture::effective_tex_coord))
+            .collect::<Vec<_>>();
+        #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+        {
+            let extension = self.extensions.as_ref().and_then(|x| x.pbr_specular_glossiness.as_ref());
+            if let Some(extension) = extension {
+                let infos = [&extension.diffuse_texture, &extension.specular_glossiness_texture];
+                tex_coords.extend(infos.iter().filter_map(|info| info.as_ref().map(texture::Info::effective_tex_coord)));
+            }
+        }
+        tex_coords.sort_unstable();
+        tex_coords.dedup();
+        tex_coords
+    }
+}
+
+impl PbrMetallicRoughness {
+    /// Returns `true` if every property has its default value, in which case
+    /// `pbrMetallicRoughness` is omitted when serializing.
+    fn is_default(&self) -> bool {
+        is_base_color_factor_default(&self.base_color_factor)
+            && is_metallic_factor_default(&self.metallic_factor)
+            && is_roughness_factor_default(&self.roughness_factor)
+            && self.base_color_texture.is_none()
+            && self.metallic_roughness_texture.is_none()
+            && self.extensions.is_none()
+            && crate::extras::is_empty(&self.extras)
+    }
+}
+
+fn is_alpha_cutoff_default(cutoff: &AlphaCutoff) -> bool {
+    cutoff.0 == defaults::ALPHA_CUTOFF
+}
+
+fn is_alpha_mode_default(mode: &Checked<AlphaMode>) -> bool {
+    *mode == Checked::Valid(defaults::ALPHA_MODE)
+}
+
+fn is_double_sided_default(double_sided: &bool) -> bool {
+    *double_sided == defaults::DOUBLE_SIDED
+}
+
+fn is_emissive_factor_default(factor: &EmissiveFactor) -> bool {
+    factor.0 == defaults::EMISSIVE_FACTOR
+}
+
+fn is_base_color_factor_default(factor: &PbrBaseColorFactor) -> bool {
+    factor.0 == defaults::BASE_COLOR_FACTOR
+}
+
+fn is_metallic_factor_default(factor: &StrengthFactor) -> bool {
+    factor.0 == defaults::METALLIC_FACTOR
+}
+
+fn is_roughness_factor_default(factor: &StrengthFactor) -> bool {
+    factor.0 == defaults::ROUGHNESS_FACTOR
+}
+
+fn is_normal_scale_default(scale: &f32) -> bool {
+    *scale == defaults::NORMAL_SCALE
+}
+
+fn is_occlusion_strength_default(strength: &StrengthFactor) -> bool {
+    strength.0 == defaults::OCCLUSION_STRENGTH
+}
+
 /// Defines the normal texture of a material.
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
 pub struct NormalTexture {
@@ -184,10 +296,11 @@ pub struct NormalTexture {
     ///
     /// This value is ignored if normalTexture is not specified.
     #[serde(default = "material_normal_texture_scale_default")]
+    #[serde(skip_serializing_if = "is_normal_scale_default")]
     pub scale: f32,
 
     /// The set index of the texture's `TEXCOORD` attribute.
-    #[serde(default, rename = "texCoord")]
+    #[serde(default, rename = "texCoord", skip_serializing_if = "texture::is_tex_coord_default")]
     pub tex_coord: u32,
 
     /// Extension specific data.
@@ -196,12 +309,12 @@ pub struct NormalTexture {
 
     /// Optional application specific data.
     #[serde(default)]
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "crate::extras::is_empty"))]
     pub extras: Extras,
 }
 
 fn material_normal_texture_scale_default() -> f32 {
-    1.0
+    defaults::NORMAL_SCALE
 }
 
 /// Defines the occlusion texture of a material.
@@ -211,11 +324,11 @@ pub struct OcclusionTexture {
     pub index: Index<texture::Texture>,
 
     /// The scalar multiplier controlling the amount of occlusion applied.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "is_occlusion_strength_default")]
     pub strength: StrengthFactor,
 
     /// The set index of the texture's `TEXCOORD` attribute.
-    #[serde(default, rename = "texCoord")]
+    #[serde(default, rename = "texCoord", skip_serializing_if = "texture::is_tex_coord_default")]
     pub tex_coord: u32,
 
     /// Extension specific data.
@@ -224,16 +337,70 @@ pub struct OcclusionTexture {
 
     /// Optional application specific data.
     #[serde(default)]
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "crate::extras::is_empty"))]
     pub extras: Extras,
 }
 
+impl NormalTexture {
+    /// Creates a reference to the normal texture `index`, with the default
+    /// scale and the first `TEXCOORD` set.
+    pub fn new(index: Index<texture::Texture>) -> Self {
+        NormalTexture {
+            index,
+            scale: defaults::NORMAL_SCALE,
+            tex_coord: texture::defaults::TEX_COORD,
+            extensions: None,
+            extras: Default::default(),
+        }
+    }
+
+    /// Returns the set index of the `TEXCOORD` attribute the texture is
+    /// sampled with, see `texture::Info::effective_tex_coord`.
+    pub fn effective_tex_coord(&self) -> u32 {
+        #[cfg(feature = "KHR_texture_transform")]
+        {
+            let transform = self.extensions.as_ref().and_then(|x| x.texture_transform.as_ref());
+            if let Some(tex_coord) = transform.and_then(|x| x.tex_coord) {
+                return tex_coord;
+            }
+        }
+        self.tex_coord
+    }
+}
+
+impl OcclusionTexture {
+    /// Creates a reference to the occlusion texture `index`, with the default
+    /// strength and the first `TEXCOORD` set.
+    pub fn new(index: Index<texture::Texture>) -> Self {
+        OcclusionTexture {
+            index,
+            strength: StrengthFactor::default(),
+            tex_coord: texture::defaults::TEX_COORD,
+            extensions: None,
+            extras: Default::default(),
+        }
+    }
+
+    /// Returns the set index of the `TEXCOORD` attribute the texture is
+    /// sampled with, see `texture::Info::effective_tex_coord`.
+    pub fn effective_tex_coord(&self) -> u32 {
+        #[cfg(feature = "KHR_texture_transform")]
+        {
+            let transform = self.extensions.as_ref().and_then(|x| x.texture_transform.as_ref());
+            if let Some(tex_coord) = transform.and_then(|x| x.tex_coord) {
+                return tex_coord;
+            }
+        }
+        self.tex_coord
+    }
+}
+
 /// The alpha cutoff value of a material.
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
 pub struct AlphaCutoff(pub f32);
 
 /// The emissive color of a material.
-#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
 pub struct EmissiveFactor(pub [f32; 3]);
 
 /// The base color factor of a material.
@@ -244,17 +411,35 @@ pub struct PbrBaseColorFactor(pub [f32; 4]);
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
 pub struct StrengthFactor(pub f32);
 
+impl AlphaCutoff {
+    /// Creates an alpha cutoff value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is negative or not finite.
+    pub fn new(value: f32) -> Self {
+        assert!(value.is_finite() && value >= 0.0, "alpha cutoff out of range: {}", value);
+        AlphaCutoff(value)
+    }
+}
+
 impl Default for AlphaCutoff {
     fn default() -> Self {
-        AlphaCutoff(0.5)
+        AlphaCutoff(defaults::ALPHA_CUTOFF)
     }
 }
 
-impl Validate for AlphaCutoff {}
+impl Validate for AlphaCutoff {
+    fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+    {
+        self.0.validate(root, path, report);
+    }
+}
 
 impl Default for AlphaMode {
     fn default() -> Self {
-        AlphaMode::Opaque
+        defaults::ALPHA_MODE
     }
 }
 
@@ -287,20 +472,81 @@ impl<'de> de::Deserialize<'de> for Checked<AlphaMode> {
     }
 }
 
-impl Validate for EmissiveFactor {}
+impl EmissiveFactor {
+    /// Creates an emissive color.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a component is outside the range [0.0, 1.0].
+    pub fn new(value: [f32; 3]) -> Self {
+        assert!(value.iter().all(|x| (0.0..=1.0).contains(x)), "emissive factor out of range: {:?}", value);
+        EmissiveFactor(value)
+    }
+}
+
+impl Default for EmissiveFactor {
+    fn default() -> Self {
+        EmissiveFactor(defaults::EMISSIVE_FACTOR)
+    }
+}
+
+impl Validate for EmissiveFactor {
+    fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+    {
+        self.0.validate(root, path, report);
+    }
+}
+
+impl PbrBaseColorFactor {
+    /// Creates a base color factor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a component is outside the range [0.0, 1.0].
+    pub fn new(value: [f32; 4]) -> Self {
+        assert!(value.iter().all(|x| (0.0..=1.0).contains(x)), "base color factor out of range: {:?}", value);
+        PbrBaseColorFactor(value)
+    }
+}
 
 impl Default for PbrBaseColorFactor {
     fn default() -> Self {
-        PbrBaseColorFactor([1.0, 1.0, 1.0, 1.0])
+        PbrBaseColorFactor(defaults::BASE_COLOR_FACTOR)
     }
 }
 
-impl Validate for PbrBaseColorFactor {}
+impl Validate for PbrBaseColorFactor {
+    fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+    {
+        self.0.validate(root, path, report);
+    }
+}
+
+impl StrengthFactor {
+    /// Creates a strength factor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is outside the range [0.0, 1.0].
+    pub fn new(value: f32) -> Self {
+        assert!((0.0..=1.0).contains(&value), "strength factor out of range: {}", value);
+        StrengthFactor(value)
+    }
+}
 
 impl Default for StrengthFactor {
+    /// Returns 1.0, the default of every strength factor of the specification.
     fn default() -> Self {
-        StrengthFactor(1.0)
+        StrengthFactor(defaults::OCCLUSION_STRENGTH)
     }
 }
 
-impl Validate for StrengthFactor {}
+impl Validate for StrengthFactor {
+    fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+    {
+        self.0.validate(root, path, report);
+    }
+}
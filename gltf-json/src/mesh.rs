@@ -2,8 +2,11 @@ use gltf_derive::Validate;
 use serde_derive::{Serialize, Deserialize};
 use serde::{de, ser};
 use serde_json::from_value;
-use std::collections::HashMap;
+use std::cmp::Ordering;
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
 use std::fmt;
+use std::str::FromStr;
 use crate::validation::{Checked, Error, Validate};
 use crate::{accessor, extensions, material, Extras, Index};
 
@@ -83,13 +86,13 @@ pub struct Mesh {
 
     /// Optional application specific data.
     #[serde(default)]
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "crate::extras::is_empty"))]
     pub extras: Extras,
 
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
-    pub name: Option<String>,
+    pub name: Option<crate::Str>,
 
     /// Defines the geometry to be renderered with a material.
     #[serde(skip_serializing_if = "Vec::is_empty")]
@@ -105,7 +108,10 @@ pub struct Mesh {
 pub struct Primitive {
     /// Maps attribute semantic names to the `Accessor`s containing the
     /// corresponding attribute data.
-    pub attributes: HashMap<Checked<Semantic>, Index<accessor::Accessor>>,
+    ///
+    /// The attributes are ordered by semantic, see the `Ord` implementation
+    /// of `Semantic`.
+    pub attributes: Attributes,
 
     /// Extension specific data.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -113,7 +119,7 @@ pub struct Primitive {
 
     /// Optional application specific data.
     #[serde(default)]
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "crate::extras::is_empty"))]
     pub extras: Extras,
 
     /// The index of the accessor that contains the indices.
@@ -156,29 +162,231 @@ impl Validate for Primitive {
 
         // Custom part
         let position_path = &|| path().field("attributes").key("POSITION");
-        if let Some(pos_accessor_index) = self.attributes.get(&Checked::Valid(Semantic::Positions)) {
-            // spec: POSITION accessor **must** have `min` and `max` properties defined.
-            let pos_accessor = &root.accessors[pos_accessor_index.value()];
-
-            let min_path = &|| position_path().field("min");
-            if let Some(ref min) = pos_accessor.min {
-                if from_value::<[f32; 3]>(min.clone()).is_err() {
-                    report(min_path, Error::Invalid);
+        let position_accessor = self.attributes
+            .get(&Checked::Valid(Semantic::Positions))
+            .map(|index| root.accessors.get(index.value()));
+        match position_accessor {
+            Some(Some(pos_accessor)) => {
+                // spec: POSITION accessor **must** have `min` and `max` properties defined.
+                let min_path = &|| position_path().field("min");
+                if let Some(ref min) = pos_accessor.min {
+                    if from_value::<[f32; 3]>(min.clone()).is_err() {
+                        report(min_path, Error::Invalid);
+                    }
+                } else {
+                    report(min_path, Error::Missing);
                 }
-            } else {
-                report(min_path, Error::Missing);
+
+                let max_path = &|| position_path().field("max");
+                if let Some(ref max) = pos_accessor.max {
+                    if from_value::<[f32; 3]>(max.clone()).is_err() {
+                        report(max_path, Error::Invalid);
+                    }
+                } else {
+                    report(max_path, Error::Missing);
+                }
+            },
+            // The out of bounds index has already been reported.
+            Some(None) => {},
+            None => report(position_path, Error::Missing),
+        }
+
+        let mut unbounded = self.attributes
+            .iter()
+            .filter(|&(semantic, _)| *semantic != Checked::Valid(Semantic::Positions))
+            .filter(|&(_, index)| {
+                root.accessors
+                    .get(index.value())
+                    .is_some_and(|accessor| accessor.min.is_none() || accessor.max.is_none())
+            })
+            .map(|(semantic, _)| semantic.to_string())
+            .collect::<Vec<_>>();
+        unbounded.sort();
+        for semantic in unbounded {
+            report(&|| path().field("attributes").key(&semantic), Error::MissingBounds);
+        }
+
+        // Accessors with invalid types have already been reported.
+        let indices = self.indices.as_ref().and_then(|index| root.accessors.get(index.value()));
+        if let Some(accessor) = indices {
+            let checked = matches!((accessor.component_type, accessor.type_), (Checked::Valid(_), Checked::Valid(_)));
+            if checked && !accessor.is_index_compatible(root) {
+                report(&|| path().field("indices"), Error::InvalidIndexAccessor);
+            }
+        }
+
+        // Every texture of the material should be sampled with a `TEXCOORD`
+        // set of the primitive.
+        let material = self.material.as_ref().and_then(|index| root.materials.get(index.value()));
+        for set in material.map(material::Material::tex_coords).unwrap_or_default() {
+            if !self.attributes.contains_key(&Checked::Valid(Semantic::TexCoords(set))) {
+                report(&|| path().field("attributes").key(&format!("TEXCOORD_{}", set)), Error::TexCoordMissing);
+            }
+        }
+
+        self.validate_formats(root, &path, report);
+        self.validate_strides(root, &path, report);
+        self.validate_counts(root, path, report);
+    }
+}
+
+impl Primitive {
+    /// Checks that the accessors of attributes with a restricted format use
+    /// one of the permitted types and component types.
+    ///
+    /// `COLOR_n` must be `VEC3` or `VEC4` of floats, or of normalized unsigned
+    /// bytes or shorts.
+    fn validate_formats<P, R>(&self, root: &crate::Root, path: P, report: &mut R)
+        where
+        P: Fn() -> crate::Path,
+        R: FnMut(&dyn Fn() -> crate::Path, crate::validation::Error),
+    {
+        use crate::accessor::{ComponentType::*, Type::*};
+        let mut attributes = self.attributes
+            .iter()
+            .filter_map(|(semantic, index)| match semantic {
+                Checked::Valid(Semantic::Colors(_)) => Some((semantic.to_string(), root.accessors.get(index.value())?)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        attributes.sort_by(|a, b| a.0.cmp(&b.0));
+        for (semantic, accessor) in attributes {
+            let valid = match (accessor.component_type, accessor.type_) {
+                (Checked::Valid(component_type), Checked::Valid(Vec3))
+                | (Checked::Valid(component_type), Checked::Valid(Vec4)) => match component_type.0 {
+                    F32 => true,
+                    U8 | U16 => accessor.normalized,
+                    _ => false,
+                },
+                (Checked::Valid(_), Checked::Valid(_)) => false,
+                // The invalid type has already been reported.
+                _ => true,
+            };
+            if !valid {
+                report(&|| path().field("attributes").key(&semantic), Error::Invalid);
+            }
+        }
+    }
+
+    /// Checks that attribute data shared with accessors of a different
+    /// element size is accompanied by an explicit `byteStride`, since the
+    /// stride cannot be inferred from the element size.
+    fn validate_strides<P, R>(&self, root: &crate::Root, path: P, report: &mut R)
+        where
+        P: Fn() -> crate::Path,
+        R: FnMut(&dyn Fn() -> crate::Path, crate::validation::Error),
+    {
+        let element_size = |accessor: &accessor::Accessor| match (accessor.component_type, accessor.type_) {
+            (Checked::Valid(component_type), Checked::Valid(type_)) => Some(type_.element_size(component_type.0)),
+            _ => None,
+        };
+        let mut attributes = self.attributes
+            .iter()
+            .map(|(semantic, index)| (semantic.to_string(), index))
+            .collect::<Vec<_>>();
+        attributes.sort_by(|a, b| a.0.cmp(&b.0));
+        for (semantic, index) in attributes {
+            let accessor = match root.accessors.get(index.value()) {
+                Some(accessor) => accessor,
+                None => continue,
+            };
+            let view = match accessor.buffer_view {
+                Some(view) => view,
+                None => continue,
+            };
+            let stride = root.buffer_views.get(view.value()).and_then(|view| view.byte_stride);
+            if stride.is_some_and(|stride| stride != 0) {
+                continue;
+            }
+            let size = element_size(accessor);
+            let conflict = root.accessors
+                .iter()
+                .filter(|other| other.buffer_view.map(|x| x.value()) == Some(view.value()))
+                .any(|other| element_size(other) != size);
+            if conflict {
+                report(&|| path().field("attributes").key(&semantic), Error::Invalid);
+            }
+        }
+    }
+
+    /// Checks that every attribute accessor and morph target accessor has the
+    /// same count, and that the number of vertices is valid for the primitive
+    /// mode.
+    fn validate_counts<P, R>(&self, root: &crate::Root, path: P, report: &mut R)
+        where
+        P: Fn() -> crate::Path,
+        R: FnMut(&dyn Fn() -> crate::Path, crate::validation::Error),
+    {
+        let count = |index: &Index<accessor::Accessor>| {
+            root.accessors.get(index.value()).map(|accessor| accessor.count)
+        };
+        let mut attributes = self.attributes
+            .iter()
+            .filter_map(|(semantic, index)| Some((semantic.to_string(), count(index)?)))
+            .collect::<Vec<_>>();
+        attributes.sort();
+        let expected = match self.attributes.get(&Checked::Valid(Semantic::Positions)) {
+            Some(index) => count(index),
+            None => attributes.first().map(|&(_, count)| count),
+        };
+        let expected = match expected {
+            Some(expected) => expected,
+            None => return,
+        };
+
+        // The declared maximum index must refer to an existing vertex.
+        let max_index = self.indices
+            .as_ref()
+            .and_then(|index| root.accessors.get(index.value()))
+            .and_then(|accessor| accessor.max.as_ref())
+            .and_then(|max| max.get(0))
+            .and_then(|max| max.as_f64());
+        if max_index.is_some_and(|max| max >= f64::from(expected)) {
+            report(&|| path().field("indices"), Error::IndexOutOfBounds);
+        }
+
+        for (semantic, actual) in attributes {
+            if actual != expected {
+                report(
+                    &|| path().field("attributes").key(&semantic),
+                    Error::CountMismatch { expected, actual },
+                );
             }
+        }
 
-            let max_path = &|| position_path().field("max");
-            if let Some(ref max) = pos_accessor.max {
-                if from_value::<[f32; 3]>(max.clone()).is_err() {
-                    report(max_path, Error::Invalid);
+        for (i, target) in self.targets.iter().flatten().enumerate() {
+            let semantics = [
+                ("POSITION", target.positions.as_ref()),
+                ("NORMAL", target.normals.as_ref()),
+                ("TANGENT", target.tangents.as_ref()),
+            ];
+            for &(semantic, index) in semantics.iter() {
+                match index.and_then(count) {
+                    Some(actual) if actual != expected => report(
+                        &|| path().field("targets").index(i).key(semantic),
+                        Error::CountMismatch { expected, actual },
+                    ),
+                    _ => {},
                 }
-            } else {
-                report(max_path, Error::Missing);
             }
-        } else {
-            report(position_path, Error::Missing);
+        }
+
+        let (count_path, count): (&dyn Fn() -> crate::Path, u32) = match self.indices {
+            Some(ref index) => match count(index) {
+                Some(count) => (&|| path().field("indices"), count),
+                None => return,
+            },
+            None => (&|| path().field("attributes").key("POSITION"), expected),
+        };
+        let valid = match self.mode {
+            Checked::Valid(Mode::Points) | Checked::Invalid => true,
+            Checked::Valid(Mode::Lines) => count % 2 == 0,
+            Checked::Valid(Mode::LineLoop) | Checked::Valid(Mode::LineStrip) => count >= 2,
+            Checked::Valid(Mode::Triangles) => count % 3 == 0,
+            Checked::Valid(Mode::TriangleStrip) | Checked::Valid(Mode::TriangleFan) => count >= 3,
+        };
+        if !valid {
+            report(count_path, Error::Invalid);
         }
     }
 }
@@ -202,11 +410,29 @@ pub struct MorphTarget {
     pub tangents: Option<Index<accessor::Accessor>>,
 }
 
+/// The attributes of a primitive.
+///
+/// Dereferences to the map of semantics to accessors. Set indices written
+/// with leading zeros, such as `TEXCOORD_01`, are read as the set they denote
+/// and reported as `Error::NonCanonicalSemantic`, a warning. Attributes whose
+/// names are not semantics, or that repeat the semantic of another
+/// attribute, are kept by name in `invalid` and reported as errors. Every
+/// attribute is written back with the name it was read with.
+#[derive(Clone, Debug, Default)]
+pub struct Attributes {
+    map: BTreeMap<Checked<Semantic>, Index<accessor::Accessor>>,
+
+    /// The names of the attributes in `map` that are not canonical.
+    names: BTreeMap<Semantic, String>,
+
+    /// The attributes that could not be read as a semantic, keyed by name.
+    pub invalid: BTreeMap<String, Index<accessor::Accessor>>,
+}
+
 /// Vertex attribute semantic name.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum Semantic {
-    /// Extra attribute name.
-    #[cfg(feature = "extras")]
+    /// Application-specific attribute, named without its leading underscore.
     Extras(String),
 
     /// XYZ vertex positions.
@@ -296,44 +522,237 @@ impl ser::Serialize for Mode {
 }
 
 impl Semantic {
+    /// Returns the position of the semantic in the order of the
+    /// specification and its set index.
+    fn rank(&self) -> (u8, u32) {
+        use self::Semantic::*;
+        match *self {
+            Positions => (0, 0),
+            Normals => (1, 0),
+            Tangents => (2, 0),
+            TexCoords(set) => (3, set),
+            Colors(set) => (4, set),
+            Joints(set) => (5, set),
+            Weights(set) => (6, set),
+            Extras(_) => (7, 0),
+        }
+    }
+
     fn checked(s: &str) -> Checked<Self> {
+        match s.parse() {
+            Ok(semantic) => Checked::Valid(semantic),
+            Err(_) => Checked::Invalid,
+        }
+    }
+
+    /// Parses a semantic name, also accepting set indices with leading zeros
+    /// if `lenient` is `true`.
+    fn parse_name(s: &str, lenient: bool) -> Option<Self> {
         use self::Semantic::*;
-        use crate::validation::Checked::*;
+        let set = |prefix: &str| s.strip_prefix(prefix).and_then(|digits| parse_set(digits, lenient));
         match s {
-            "NORMAL" => Valid(Normals),
-            "POSITION" => Valid(Positions),
-            "TANGENT" => Valid(Tangents),
-            #[cfg(feature = "extras")]
-            _ if s.starts_with("_") => Valid(Extras(s[1..].to_string())),
-            _ if s.starts_with("COLOR_") => {
-                match s["COLOR_".len()..].parse() {
-                    Ok(set) => Valid(Colors(set)),
-                    Err(_) => Invalid,
-                }
+            "NORMAL" => Some(Normals),
+            "POSITION" => Some(Positions),
+            "TANGENT" => Some(Tangents),
+            _ if s.starts_with('_') => Some(Extras(s[1..].to_string())),
+            _ if s.starts_with("COLOR_") => set("COLOR_").map(Colors),
+            _ if s.starts_with("TEXCOORD_") => set("TEXCOORD_").map(TexCoords),
+            _ if s.starts_with("JOINTS_") => set("JOINTS_").map(Joints),
+            _ if s.starts_with("WEIGHTS_") => set("WEIGHTS_").map(Weights),
+            _ => None,
+        }
+    }
+}
+
+/// Parses the set index of a semantic such as `TEXCOORD_1`, which must be
+/// written in decimal without a sign, and without leading zeros unless
+/// `lenient` is `true`.
+fn parse_set(digits: &str, lenient: bool) -> Option<u32> {
+    let valid = match digits.as_bytes() {
+        [] => false,
+        [b'0', _, ..] if !lenient => false,
+        bytes => bytes.iter().all(u8::is_ascii_digit),
+    };
+    if valid {
+        digits.parse().ok()
+    } else {
+        None
+    }
+}
+
+impl Attributes {
+    /// Returns the accessors of every attribute, including the invalid ones.
+    pub fn accessors(&self) -> impl Iterator<Item = &Index<accessor::Accessor>> {
+        self.map.values().chain(self.invalid.values())
+    }
+
+    /// Returns the accessors of every attribute, including the invalid ones.
+    pub fn accessors_mut(&mut self) -> impl Iterator<Item = &mut Index<accessor::Accessor>> {
+        self.map.values_mut().chain(self.invalid.values_mut())
+    }
+
+    /// Adds an attribute read from a document.
+    fn insert_name(&mut self, name: String, index: Index<accessor::Accessor>) {
+        let semantic = match Semantic::parse_name(&name, true) {
+            Some(semantic) => semantic,
+            None => {
+                self.invalid.insert(name, index);
+                return;
             },
-            _ if s.starts_with("TEXCOORD_") => {
-                match s["TEXCOORD_".len()..].parse() {
-                    Ok(set) => Valid(TexCoords(set)),
-                    Err(_) => Invalid,
+        };
+        let canonical = semantic.to_string() == name;
+        match self.map.entry(Checked::Valid(semantic.clone())) {
+            Entry::Vacant(entry) => {
+                entry.insert(index);
+                if !canonical {
+                    self.names.insert(semantic, name);
                 }
             },
-            _ if s.starts_with("JOINTS_") => {
-                match s["JOINTS_".len()..].parse() {
-                    Ok(set) => Valid(Joints(set)),
-                    Err(_) => Invalid,
+            // The canonical name takes precedence over a non-canonical one.
+            Entry::Occupied(mut entry) if canonical => {
+                let previous = entry.insert(index);
+                if let Some(previous_name) = self.names.remove(&semantic) {
+                    self.invalid.insert(previous_name, previous);
                 }
             },
-            _ if s.starts_with("WEIGHTS_") => {
-                match s["WEIGHTS_".len()..].parse() {
-                    Ok(set) => Valid(Weights(set)),
-                    Err(_) => Invalid,
-                }
+            Entry::Occupied(_) => {
+                self.invalid.insert(name, index);
             },
-            _ => Invalid,
         }
     }
 }
 
+impl std::ops::Deref for Attributes {
+    type Target = BTreeMap<Checked<Semantic>, Index<accessor::Accessor>>;
+    fn deref(&self) -> &Self::Target {
+        &self.map
+    }
+}
+
+impl std::ops::DerefMut for Attributes {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.map
+    }
+}
+
+impl From<BTreeMap<Checked<Semantic>, Index<accessor::Accessor>>> for Attributes {
+    fn from(map: BTreeMap<Checked<Semantic>, Index<accessor::Accessor>>) -> Self {
+        Attributes { map, names: BTreeMap::new(), invalid: BTreeMap::new() }
+    }
+}
+
+impl<'de> de::Deserialize<'de> for Attributes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: de::Deserializer<'de>
+    {
+        let mut attributes = Attributes::default();
+        for (name, index) in BTreeMap::<String, Index<accessor::Accessor>>::deserialize(deserializer)? {
+            attributes.insert_name(name, index);
+        }
+        Ok(attributes)
+    }
+}
+
+impl ser::Serialize for Attributes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: ser::Serializer
+    {
+        use serde::ser::SerializeMap;
+        let mut map = serializer.serialize_map(Some(self.map.len() + self.invalid.len()))?;
+        for (semantic, index) in &self.map {
+            match semantic {
+                Checked::Valid(semantic) => match self.names.get(semantic) {
+                    Some(name) => map.serialize_entry(name, index)?,
+                    None => map.serialize_entry(&semantic.to_string(), index)?,
+                },
+                Checked::Invalid => return Err(ser::Error::custom("invalid item")),
+            }
+        }
+        for (name, index) in &self.invalid {
+            map.serialize_entry(name, index)?;
+        }
+        map.end()
+    }
+}
+
+impl Validate for Attributes {
+    fn validate<P, R>(&self, root: &crate::Root, path: P, report: &mut R)
+        where
+        P: Fn() -> crate::Path,
+        R: FnMut(&dyn Fn() -> crate::Path, crate::validation::Error),
+    {
+        self.map.validate(root, &path, report);
+        for (semantic, name) in &self.names {
+            if self.map.contains_key(&Checked::Valid(semantic.clone())) {
+                report(&|| path().key(name), Error::NonCanonicalSemantic);
+            }
+        }
+        for (name, index) in &self.invalid {
+            let error = match Semantic::parse_name(name, true) {
+                Some(_) => Error::Duplicate,
+                None => Error::Invalid,
+            };
+            report(&|| path().key(name), error);
+            index.validate(root, || path().key(name), report);
+        }
+    }
+}
+
+/// The error returned when parsing a string that is not a valid semantic
+/// name.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseSemanticError(pub String);
+
+impl fmt::Display for ParseSemanticError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid semantic name: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseSemanticError {}
+
+/// Parses the canonical names written by `Display`.
+///
+/// Set indices must be written in decimal without a sign or leading zeros,
+/// so `TEXCOORD_` and `TEXCOORD_00` are rejected, and every parsed semantic
+/// is written back as it was read. The attributes of documents are read more
+/// leniently, see `Attributes`.
+///
+/// ```
+/// use gltf_json::mesh::Semantic;
+///
+/// assert_eq!("TEXCOORD_10".parse(), Ok(Semantic::TexCoords(10)));
+/// assert_eq!("_CUSTOM".parse(), Ok(Semantic::Extras("CUSTOM".to_string())));
+/// assert!("TEXCOORD_00".parse::<Semantic>().is_err());
+/// assert_eq!(Semantic::Colors(1).to_string(), "COLOR_1");
+/// ```
+impl FromStr for Semantic {
+    type Err = ParseSemanticError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Semantic::parse_name(s, false).ok_or_else(|| ParseSemanticError(s.to_string()))
+    }
+}
+
+/// Semantics are ordered as listed by the specification: `POSITION`,
+/// `NORMAL`, `TANGENT`, `TEXCOORD_n`, `COLOR_n`, `JOINTS_n`, and `WEIGHTS_n`,
+/// by ascending set index, followed by application-specific semantics by
+/// name.
+impl Ord for Semantic {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Semantic::Extras(a), Semantic::Extras(b)) => a.cmp(b),
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+}
+
+impl PartialOrd for Semantic {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl ser::Serialize for Semantic {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
         where S: ser::Serializer
@@ -342,28 +761,36 @@ impl ser::Serialize for Semantic {
     }
 }
 
-impl ToString for Semantic {
-    fn to_string(&self) -> String {
+impl<'de> de::Deserialize<'de> for Semantic {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: de::Deserializer<'de>
+    {
+        let name = <std::borrow::Cow<str> as de::Deserialize>::deserialize(deserializer)?;
+        name.parse().map_err(de::Error::custom)
+    }
+}
+
+impl fmt::Display for Semantic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use self::Semantic::*;
         match *self {
-            Positions => "POSITION".into(),
-            Normals => "NORMAL".into(),
-            Tangents => "TANGENT".into(),
-            Colors(set) => format!("COLOR_{}", set),
-            TexCoords(set) => format!("TEXCOORD_{}", set),
-            Joints(set) => format!("JOINTS_{}", set),
-            Weights(set) => format!("WEIGHTS_{}", set),
-            #[cfg(feature = "extras")]
-            Extras(ref name) => format!("_{}", name),
+            Positions => f.write_str("POSITION"),
+            Normals => f.write_str("NORMAL"),
+            Tangents => f.write_str("TANGENT"),
+            Colors(set) => write!(f, "COLOR_{}", set),
+            TexCoords(set) => write!(f, "TEXCOORD_{}", set),
+            Joints(set) => write!(f, "JOINTS_{}", set),
+            Weights(set) => write!(f, "WEIGHTS_{}", set),
+            Extras(ref name) => write!(f, "_{}", name),
         }
     }
 }
 
-impl ToString for Checked<Semantic> {
-    fn to_string(&self) -> String {
+impl fmt::Display for Checked<Semantic> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            Checked::Valid(ref semantic) => semantic.to_string(),
-            Checked::Invalid => "<invalid semantic name>".into(),
+            Checked::Valid(ref semantic) => semantic.fmt(f),
+            Checked::Invalid => f.write_str("<invalid semantic name>"),
         }
     }
 }
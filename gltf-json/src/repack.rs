@@ -0,0 +1,598 @@
+use std::collections::BTreeSet;
+
+use serde_json::Value;
+
+use crate::accessor::sparse::{self, Sparse};
+use crate::accessor::{sparsify, ComponentType, IndexComponentType, Sparsified, Type};
+use crate::buffer::{Target, View};
+use crate::edit::Error;
+use crate::identity::{Kind, Remap};
+use crate::validation::Checked;
+use crate::{Accessor, Index, Path, Root};
+
+/// Layout of the accessor data written by [`Root::repack_buffers`].
+///
+/// [`Root::repack_buffers`]: ../struct.Root.html#method.repack_buffers
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Layout {
+    /// Every accessor is given its own tightly packed buffer view.
+    ///
+    /// Vertex attribute elements whose size is not a multiple of four bytes
+    /// are padded, as required by the specification.
+    Separate,
+
+    /// The vertex attributes of a primitive are interleaved in one buffer view.
+    ///
+    /// Morph targets, indices, and all other accessors are laid out as in
+    /// `Separate`. Attributes already interleaved for an earlier primitive, and
+    /// primitives whose interleaved stride would exceed 252 bytes, are also
+    /// laid out as in `Separate`.
+    InterleavedPerPrimitive,
+}
+
+/// Summary of the changes made by [`Root::repack_buffers`].
+///
+/// [`Root::repack_buffers`]: ../struct.Root.html#method.repack_buffers
+#[derive(Clone, Debug, Default)]
+pub struct Report {
+    /// The total byte length of all buffers before repacking.
+    pub bytes_before: usize,
+
+    /// The total byte length of all buffers after repacking.
+    pub bytes_after: usize,
+
+    /// The new buffer views whose contents were copied unchanged.
+    ///
+    /// These hold data that is not described by an accessor, namely image
+    /// data and the indices and values of sparse accessors.
+    pub copied_views: Vec<Index<View>>,
+
+    /// The number of buffer views that were not referenced and were removed.
+    pub removed_views: usize,
+
+    /// The original indices of buffers that became empty and were removed.
+    pub removed_buffers: Vec<usize>,
+
+    /// The new index of every original buffer and buffer view.
+    ///
+    /// Only the copied buffer views keep an identity; the others map to
+    /// `None`, since the data of their accessors moved to new buffer views.
+    pub remap: Remap,
+}
+
+/// How an accessor is referenced, which determines its buffer view target.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Role {
+    Unused,
+    Vertex,
+    Index,
+    Other,
+}
+
+fn align_to_multiple_of_four(n: usize) -> usize {
+    (n + 3) & !3
+}
+
+/// Pads `data` to a multiple of four bytes and returns the new length.
+fn align(data: &mut Vec<u8>) -> usize {
+    let length = align_to_multiple_of_four(data.len());
+    data.resize(length, 0);
+    length
+}
+
+/// The source location of the elements of an accessor.
+struct Source {
+    buffer: usize,
+    start: usize,
+    stride: usize,
+    element_size: usize,
+    count: usize,
+}
+
+impl Source {
+    fn element<'a>(&self, buffers: &'a [Vec<u8>], index: usize) -> &'a [u8] {
+        let start = self.start + index * self.stride;
+        &buffers[self.buffer][start..start + self.element_size]
+    }
+}
+
+impl Root {
+    /// Rewrites the buffer views and accessors of the document, and the data
+    /// of its buffers, so that no unused bytes remain and the accessor data
+    /// follows the given layout.
+    ///
+    /// `buffers` contains the data of every buffer in the document and is
+    /// replaced with the repacked data. Every accessor is kept, including
+    /// those that are not referenced, and the data of animation samplers and
+    /// skins is repacked like any other accessor. Buffer views referenced by
+    /// images or by the sparse storage of accessors are copied unchanged and
+    /// listed in the returned report. Buffer views that are not referenced are
+    /// removed. Buffers left without any buffer views are removed, unless
+    /// they are buffer 0 of binary glTF.
+    ///
+    /// The document is expected to be valid. Buffer views referenced only by
+    /// unsupported extensions are treated as unreferenced.
+    pub fn repack_buffers(
+        &mut self,
+        buffers: &mut Vec<Vec<u8>>,
+        layout: Layout,
+    ) -> Result<Report, Error> {
+        self.check_buffer_data(buffers)?;
+        let mut report = Report {
+            bytes_before: self.buffers.iter().map(|x| x.byte_length as usize).sum(),
+            ..Default::default()
+        };
+
+        let roles = self.accessor_roles();
+        let mut sources = Vec::with_capacity(self.accessors.len());
+        for index in 0..self.accessors.len() {
+            sources.push(self.accessor_source(index, buffers)?);
+        }
+
+        let mut referenced = BTreeSet::new();
+        referenced.extend(self.accessors.iter().filter_map(|x| x.buffer_view).map(|x| x.value()));
+        let copied = self.copied_views()?;
+        referenced.extend(copied.iter().cloned());
+        report.removed_views = self.buffer_views.len().saturating_sub(referenced.len());
+
+        let mut data = vec![Vec::new(); buffers.len()];
+        let mut views = Vec::new();
+        let mut placed = vec![false; self.accessors.len()];
+
+        if layout == Layout::InterleavedPerPrimitive {
+            for group in self.interleaved_groups() {
+                let group = group
+                    .into_iter()
+                    .filter(|&index| !placed[index] && sources[index].is_some())
+                    .collect::<Vec<_>>();
+                if group.len() < 2 {
+                    continue;
+                }
+                let sizes = group
+                    .iter()
+                    .map(|&index| align_to_multiple_of_four(sources[index].as_ref().unwrap().element_size))
+                    .collect::<Vec<_>>();
+                let stride = sizes.iter().sum::<usize>();
+                let count = sources[group[0]].as_ref().unwrap().count;
+                let same_count = group.iter().all(|&index| sources[index].as_ref().unwrap().count == count);
+                if stride > 252 || !same_count {
+                    continue;
+                }
+
+                let buffer = sources[group[0]].as_ref().unwrap().buffer;
+                let output = &mut data[buffer];
+                let offset = align(output);
+                for element in 0..count {
+                    for (&index, &size) in group.iter().zip(&sizes) {
+                        let bytes = sources[index].as_ref().unwrap().element(buffers, element);
+                        output.extend_from_slice(bytes);
+                        output.resize(output.len() + size - bytes.len(), 0);
+                    }
+                }
+                let view = Index::new(views.len() as u32);
+                views.push(new_view(buffer, offset, output.len() - offset, Some(stride), Some(Target::ArrayBuffer)));
+                let mut byte_offset = 0;
+                for (&index, &size) in group.iter().zip(&sizes) {
+                    self.accessors[index].buffer_view = Some(view);
+                    self.accessors[index].byte_offset = byte_offset as u32;
+                    placed[index] = true;
+                    byte_offset += size;
+                }
+            }
+        }
+
+        for index in 0..self.accessors.len() {
+            let source = match sources[index] {
+                Some(ref source) if !placed[index] => source,
+                _ => continue,
+            };
+            let target = match roles[index] {
+                Role::Vertex => Some(Target::ArrayBuffer),
+                Role::Index => Some(Target::ElementArrayBuffer),
+                Role::Other => None,
+                Role::Unused => {
+                    let view = &self.buffer_views[self.accessors[index].buffer_view.unwrap().value()];
+                    match view.target {
+                        Some(Checked::Valid(target)) => Some(target),
+                        _ => None,
+                    }
+                },
+            };
+            let stride = match target {
+                Some(Target::ArrayBuffer) if source.element_size % 4 != 0 => {
+                    Some(align_to_multiple_of_four(source.element_size))
+                },
+                _ => None,
+            };
+            let output = &mut data[source.buffer];
+            let offset = align(output);
+            for element in 0..source.count {
+                output.extend_from_slice(source.element(buffers, element));
+                if let Some(stride) = stride {
+                    output.resize(offset + (element + 1) * stride, 0);
+                }
+            }
+            let view = Index::new(views.len() as u32);
+            views.push(new_view(source.buffer, offset, output.len() - offset, stride, target));
+            self.accessors[index].buffer_view = Some(view);
+            self.accessors[index].byte_offset = 0;
+        }
+
+        let mut copied_views = Vec::with_capacity(copied.len());
+        for &index in &copied {
+            let mut view = self.buffer_views[index].clone();
+            let buffer = view.buffer.value();
+            let start = view.byte_offset.unwrap_or(0) as usize;
+            let end = start + view.byte_length as usize;
+            let output = &mut data[buffer];
+            let offset = align(output);
+            output.extend_from_slice(&buffers[buffer][start..end]);
+            view.byte_offset = Some(offset as u32);
+            copied_views.push((index, Index::new(views.len() as u32)));
+            views.push(view);
+        }
+        let copied_view = |index: Index<View>| {
+            copied_views.iter().find(|x| x.0 == index.value()).map(|x| x.1).unwrap()
+        };
+        for image in &mut self.images {
+            image.buffer_view = image.buffer_view.map(copied_view);
+        }
+        for sparse in self.accessors.iter_mut().filter_map(|x| x.sparse.as_mut()) {
+            sparse.indices.buffer_view = copied_view(sparse.indices.buffer_view);
+            sparse.values.buffer_view = copied_view(sparse.values.buffer_view);
+        }
+        report.copied_views = copied_views.iter().map(|x| x.1).collect();
+        let mut views_remap = vec![None; self.buffer_views.len()];
+        for &(old, new) in &copied_views {
+            views_remap[old] = Some(new.value());
+        }
+        report.remap.insert(Kind::BufferView, views_remap);
+
+        // Remove buffers without views, except the `BIN` chunk of binary glTF.
+        let mut remap = Vec::with_capacity(data.len());
+        let mut kept = 0;
+        for (index, buffer) in self.buffers.iter().enumerate() {
+            let used = views.iter().any(|view| view.buffer.value() == index);
+            if used || buffer.uri.is_none() {
+                remap.push(Some(kept));
+                kept += 1;
+            } else {
+                remap.push(None);
+                report.removed_buffers.push(index);
+            }
+        }
+        for view in &mut views {
+            view.buffer = Index::new(remap[view.buffer.value()].unwrap() as u32);
+        }
+        report.remap.insert(Kind::Buffer, remap.clone());
+        let mut index = 0;
+        self.buffers.retain(|_| {
+            index += 1;
+            remap[index - 1].is_some()
+        });
+        let mut data = data
+            .into_iter()
+            .zip(&remap)
+            .filter(|x| x.1.is_some())
+            .map(|x| x.0)
+            .collect::<Vec<_>>();
+        for (buffer, data) in self.buffers.iter_mut().zip(&mut data) {
+            buffer.byte_length = align(data) as u32;
+        }
+
+        report.bytes_after = data.iter().map(Vec::len).sum();
+        self.buffer_views = views;
+        *buffers = data;
+        Ok(report)
+    }
+
+    /// Replaces the elements of an accessor with `data`, appending either
+    /// sparse substitutions or a dense copy to the buffer of the accessor, as
+    /// chosen by [`sparsify`] with the given `threshold`.
+    ///
+    /// `buffers` contains the data of every buffer in the document. `data`
+    /// holds `count` tightly packed elements and is compared with the data of
+    /// the buffer view of the accessor, or with zeros if it has none, so any
+    /// previous sparse storage is replaced. The accessor keeps its buffer view
+    /// when stored sparsely, and the new buffer views are appended to the
+    /// buffer of that view, or to buffer 0. The `min` and `max` of the
+    /// accessor are recomputed if present. Every reference to the accessor
+    /// sees the new data.
+    ///
+    /// Returns whether the data was stored sparsely. The previous data is
+    /// left in place; use [`repack_buffers`] to remove it.
+    ///
+    /// [`sparsify`]: accessor/fn.sparsify.html
+    /// [`repack_buffers`]: #method.repack_buffers
+    pub fn set_accessor_data(
+        &mut self,
+        accessor: Index<Accessor>,
+        buffers: &mut [Vec<u8>],
+        data: &[u8],
+        threshold: f32,
+    ) -> Result<bool, Error> {
+        let index = accessor.value();
+        let path = || Path::new().field("accessors").index(index);
+        let json = self.accessors.get(index).ok_or_else(|| Error::IndexOutOfBounds(path()))?;
+        self.check_buffer_data(buffers)?;
+        let (component_type, type_) = match (json.component_type, json.type_) {
+            (Checked::Valid(component_type), Checked::Valid(type_)) => (component_type.0, type_),
+            _ => return Err(Error::Invalid(path())),
+        };
+        let element_size = type_.element_size(component_type);
+        if data.len() != json.count as usize * element_size {
+            return Err(Error::Invalid(path()));
+        }
+        let base = match self.accessor_source(index, buffers)? {
+            Some(source) => (0..source.count).flat_map(|i| source.element(buffers, i)).copied().collect(),
+            None => vec![0; data.len()],
+        };
+        let view = json.buffer_view.map(|x| &self.buffer_views[x.value()]);
+        let buffer = view.map_or(0, |x| x.buffer.value());
+        if buffer >= self.buffers.len() {
+            return Err(Error::OutOfRange(Path::new().field("buffers").index(buffer)));
+        }
+        let target = view.and_then(|x| match x.target {
+            Some(Checked::Valid(target)) => Some(target),
+            _ => None,
+        });
+
+        let output = &mut buffers[buffer];
+        let sparse = match sparsify(&base, data, element_size, threshold) {
+            Sparsified::Sparse { count: 0, .. } => {
+                self.accessors[index].sparse = None;
+                true
+            },
+            Sparsified::Sparse { count, index_type, indices, values } => {
+                let offset = align(output);
+                output.extend_from_slice(&indices);
+                self.buffer_views.push(new_view(buffer, offset, indices.len(), None, None));
+                let offset = align(output);
+                output.extend_from_slice(&values);
+                self.buffer_views.push(new_view(buffer, offset, values.len(), None, None));
+                let views = self.buffer_views.len() as u32;
+                self.accessors[index].sparse = Some(Sparse {
+                    count,
+                    indices: sparse::Indices {
+                        buffer_view: Index::new(views - 2),
+                        byte_offset: 0,
+                        component_type: Checked::Valid(IndexComponentType(index_type)),
+                        extensions: None,
+                        extras: Default::default(),
+                    },
+                    values: sparse::Values {
+                        buffer_view: Index::new(views - 1),
+                        byte_offset: 0,
+                        extensions: None,
+                        extras: Default::default(),
+                    },
+                    extensions: None,
+                    extras: Default::default(),
+                });
+                true
+            },
+            Sparsified::Dense(data) => {
+                let stride = match target {
+                    Some(Target::ArrayBuffer) if element_size % 4 != 0 => {
+                        Some(align_to_multiple_of_four(element_size))
+                    },
+                    _ => None,
+                };
+                let offset = align(output);
+                for (i, element) in data.chunks(element_size).enumerate() {
+                    output.extend_from_slice(element);
+                    if let Some(stride) = stride {
+                        output.resize(offset + (i + 1) * stride, 0);
+                    }
+                }
+                self.buffer_views.push(new_view(buffer, offset, output.len() - offset, stride, target));
+                let json = &mut self.accessors[index];
+                json.buffer_view = Some(Index::new(self.buffer_views.len() as u32 - 1));
+                json.byte_offset = 0;
+                json.sparse = None;
+                false
+            },
+        };
+        self.buffers[buffer].byte_length = align(output) as u32;
+
+        let json = &mut self.accessors[index];
+        if json.min.is_some() || json.max.is_some() {
+            if let Some((min, max)) = bounds(data, component_type, type_) {
+                json.min = json.min.as_ref().map(|_| min);
+                json.max = json.max.as_ref().map(|_| max);
+            }
+        }
+        Ok(sparse)
+    }
+
+    /// Checks that every buffer view lies within the data of its buffer.
+    fn check_buffer_data(&self, buffers: &[Vec<u8>]) -> Result<(), Error> {
+        for (index, buffer) in self.buffers.iter().enumerate() {
+            match buffers.get(index) {
+                Some(data) if data.len() >= buffer.byte_length as usize => {},
+                _ => return Err(Error::OutOfRange(Path::new().field("buffers").index(index))),
+            }
+        }
+        for (index, view) in self.buffer_views.iter().enumerate() {
+            let end = view.byte_offset.unwrap_or(0) as u64 + view.byte_length as u64;
+            let length = self.buffers.get(view.buffer.value()).map(|x| x.byte_length as u64);
+            if length.is_none_or(|length| end > length) {
+                return Err(Error::OutOfRange(Path::new().field("bufferViews").index(index)));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the buffer views referenced by images and by the sparse storage
+    /// of accessors, whose contents are copied unchanged by `repack_buffers`.
+    fn copied_views(&self) -> Result<BTreeSet<usize>, Error> {
+        let mut copied = BTreeSet::new();
+        let mut insert = |view: Index<View>, path: &dyn Fn() -> Path| {
+            if view.value() >= self.buffer_views.len() {
+                return Err(Error::IndexOutOfBounds(path()));
+            }
+            copied.insert(view.value());
+            Ok(())
+        };
+        for (index, image) in self.images.iter().enumerate() {
+            if let Some(view) = image.buffer_view {
+                insert(view, &|| Path::new().field("images").index(index).field("bufferView"))?;
+            }
+        }
+        for (index, accessor) in self.accessors.iter().enumerate() {
+            if let Some(sparse) = accessor.sparse.as_ref() {
+                let path = || Path::new().field("accessors").index(index).field("sparse");
+                insert(sparse.indices.buffer_view, &|| path().field("indices").field("bufferView"))?;
+                insert(sparse.values.buffer_view, &|| path().field("values").field("bufferView"))?;
+            }
+        }
+        Ok(copied)
+    }
+
+    /// Returns the location of the elements of an accessor, or `None` if the
+    /// accessor has no buffer view.
+    fn accessor_source(&self, index: usize, buffers: &[Vec<u8>]) -> Result<Option<Source>, Error> {
+        let path = || Path::new().field("accessors").index(index);
+        let accessor = &self.accessors[index];
+        let view = match accessor.buffer_view {
+            Some(view) => self.buffer_views.get(view.value()).ok_or_else(|| Error::IndexOutOfBounds(path()))?,
+            None => return Ok(None),
+        };
+        let element_size = match (accessor.component_type, accessor.type_) {
+            (Checked::Valid(component_type), Checked::Valid(type_)) => type_.element_size(component_type.0),
+            _ => return Err(Error::Invalid(path())),
+        };
+        let stride = match view.byte_stride {
+            Some(stride) if stride > 0 => stride as usize,
+            _ => element_size,
+        };
+        let source = Source {
+            buffer: view.buffer.value(),
+            start: view.byte_offset.unwrap_or(0) as usize + accessor.byte_offset as usize,
+            stride,
+            element_size,
+            count: accessor.count as usize,
+        };
+        let view_end = view.byte_offset.unwrap_or(0) as usize + view.byte_length as usize;
+        if source.count > 0 {
+            let end = source.start + (source.count - 1) * stride + element_size;
+            if end > view_end || end > buffers[source.buffer].len() {
+                return Err(Error::OutOfRange(path()));
+            }
+        }
+        Ok(Some(source))
+    }
+
+    /// Returns how each accessor is referenced.
+    ///
+    /// An accessor referenced in more than one way takes the role of its first
+    /// reference.
+    fn accessor_roles(&self) -> Vec<Role> {
+        let mut roles = vec![Role::Unused; self.accessors.len()];
+        let mut assign = |index: usize, role: Role| {
+            if let Some(x) = roles.get_mut(index) {
+                if *x == Role::Unused {
+                    *x = role;
+                }
+            }
+        };
+        for primitive in self.meshes.iter().flat_map(|x| x.primitives.iter()) {
+            for accessor in primitive.attributes.accessors() {
+                assign(accessor.value(), Role::Vertex);
+            }
+            for target in primitive.targets.iter().flatten() {
+                for accessor in target.positions.iter().chain(&target.normals).chain(&target.tangents) {
+                    assign(accessor.value(), Role::Vertex);
+                }
+            }
+            if let Some(accessor) = primitive.indices {
+                assign(accessor.value(), Role::Index);
+            }
+        }
+        #[cfg(feature = "EXT_mesh_gpu_instancing")]
+        for node in &self.nodes {
+            let instancing = node.extensions.as_ref().and_then(|x| x.ext_mesh_gpu_instancing.as_ref());
+            for accessor in instancing.iter().flat_map(|x| x.attributes.values()) {
+                assign(accessor.value(), Role::Other);
+            }
+        }
+        for accessor in self.skins.iter().filter_map(|x| x.inverse_bind_matrices) {
+            assign(accessor.value(), Role::Other);
+        }
+        for sampler in self.animations.iter().flat_map(|x| x.samplers.iter()) {
+            assign(sampler.input.value(), Role::Other);
+            assign(sampler.output.value(), Role::Other);
+        }
+        roles
+    }
+
+    /// Returns the attribute accessors of every primitive, sorted by index.
+    fn interleaved_groups(&self) -> Vec<Vec<usize>> {
+        self.meshes
+            .iter()
+            .flat_map(|x| x.primitives.iter())
+            .map(|primitive| {
+                let mut group = primitive
+                    .attributes
+                    .values()
+                    .map(|x| x.value())
+                    .filter(|&x| x < self.accessors.len())
+                    .collect::<Vec<_>>();
+                group.sort();
+                group.dedup();
+                group
+            })
+            .collect()
+    }
+}
+
+/// Returns the per-component minimum and maximum of tightly packed elements,
+/// or `None` if there are none or the elements have padded matrix columns.
+fn bounds(data: &[u8], component_type: ComponentType, type_: Type) -> Option<(Value, Value)> {
+    let size = component_type.size();
+    let components = type_.multiplicity();
+    if data.is_empty() || type_.element_size(component_type) != size * components {
+        return None;
+    }
+    let value = |bytes: &[u8]| match component_type {
+        ComponentType::I8 => f64::from(bytes[0] as i8),
+        ComponentType::U8 => f64::from(bytes[0]),
+        ComponentType::I16 => f64::from(i16::from_le_bytes([bytes[0], bytes[1]])),
+        ComponentType::U16 => f64::from(u16::from_le_bytes([bytes[0], bytes[1]])),
+        ComponentType::U32 => f64::from(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])),
+        ComponentType::F32 => f64::from(f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])),
+    };
+    let mut min = vec![f64::INFINITY; components];
+    let mut max = vec![f64::NEG_INFINITY; components];
+    for element in data.chunks(size * components) {
+        for (i, bytes) in element.chunks(size).enumerate() {
+            min[i] = min[i].min(value(bytes));
+            max[i] = max[i].max(value(bytes));
+        }
+    }
+    let to_json = |values: Vec<f64>| match component_type {
+        ComponentType::F32 => Value::from(values),
+        _ => Value::from(values.into_iter().map(|x| x as i64).collect::<Vec<_>>()),
+    };
+    Some((to_json(min), to_json(max)))
+}
+
+fn new_view(
+    buffer: usize,
+    offset: usize,
+    length: usize,
+    stride: Option<usize>,
+    target: Option<Target>,
+) -> View {
+    View {
+        buffer: Index::new(buffer as u32),
+        byte_length: length as u32,
+        byte_offset: Some(offset as u32),
+        byte_stride: stride.map(|x| x as u32),
+        #[cfg(feature = "names")]
+        name: None,
+        target: target.map(Checked::Valid),
+        extensions: None,
+        extras: Default::default(),
+    }
+}
@@ -55,7 +55,7 @@ pub struct Root {
 
     /// Optional application specific data.
     #[serde(default)]
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "crate::extras::is_empty"))]
     pub extras: Extras,
 
     /// Names of glTF extensions used somewhere in this asset.
@@ -179,6 +179,19 @@ impl Root {
     }
 }
 
+/// Deserializes from a generic JSON value.
+///
+/// Extras and unknown extensions are kept as raw JSON, which is written
+/// anew from the value, so their original whitespace is lost and the keys of
+/// their objects are in the order of the map of the value.
+impl std::convert::TryFrom<Value> for Root {
+    type Error = Error;
+
+    fn try_from(value: Value) -> Result<Self, Error> {
+        serde_json::from_value(value)
+    }
+}
+
 impl<T> Index<T> {
     /// Creates a new `Index` representing an offset into an array containing `T`.
     pub fn new(value: u32) -> Self {
@@ -1,7 +1,7 @@
 use gltf_derive::Validate;
 use serde_derive::{Serialize, Deserialize};
-use crate::validation::Validate;
-use crate::{camera, extensions, mesh, scene, skin, Extras, Index};
+use crate::validation::{Error, Validate, UNIT_LENGTH_TOLERANCE};
+use crate::{camera, extensions, mesh, scene, skin, Extras, Index, Path, Root};
 
 /// A node in the node hierarchy.  When the node contains `skin`, all
 /// `mesh.primitives` must contain `JOINTS_0` and `WEIGHTS_0` attributes.
@@ -13,7 +13,7 @@ use crate::{camera, extensions, mesh, scene, skin, Extras, Index};
 /// identity. When a node is targeted for animation (referenced by an
 /// animation.channel.target), only TRS properties may be present; `matrix` will not
 /// be present.
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
 pub struct Node {
     /// The index of the camera referenced by this node.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -29,7 +29,7 @@ pub struct Node {
 
     /// Optional application specific data.
     #[serde(default)]
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "crate::extras::is_empty"))]
     pub extras: Extras,
 
     /// 4x4 column-major transformation matrix.
@@ -52,7 +52,7 @@ pub struct Node {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
-    pub name: Option<String>,
+    pub name: Option<crate::Str>,
 
     /// The node's unit quaternion rotation in the order (x, y, z, w), where w is
     /// the scalar.
@@ -78,7 +78,7 @@ pub struct Node {
 }
 
 /// The root `Node`s of a scene.
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
 pub struct Scene {
     /// Extension specific data.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -86,13 +86,13 @@ pub struct Scene {
 
     /// Optional application specific data.
     #[serde(default)]
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "crate::extras::is_empty"))]
     pub extras: Extras,
 
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
-    pub name: Option<String>,
+    pub name: Option<crate::Str>,
 
     /// The indices of each root node.
     #[serde(skip_serializing_if = "Vec::is_empty")]
@@ -109,4 +109,20 @@ impl Default for UnitQuaternion {
     }
 }
 
-impl Validate for UnitQuaternion {}
+impl Validate for UnitQuaternion {
+    fn validate<P, R>(&self, _root: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+    {
+        let q = self.0;
+        if q.iter().any(|x| !x.is_finite()) {
+            report(&path, Error::NonFinite);
+            return;
+        }
+        let magnitude = (q[0] * q[0] + q[1] * q[1] + q[2] * q[2] + q[3] * q[3]).sqrt();
+        if magnitude == 0.0 {
+            report(&path, Error::ZeroLength);
+        } else if (magnitude - 1.0).abs() > UNIT_LENGTH_TOLERANCE {
+            report(&path, Error::Denormalized);
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use std::collections::BTreeSet;
+use std::convert::TryFrom;
+
+use serde_json::{Map, Value};
+
+use crate::edit::Error;
+use crate::extensions::ENABLED_EXTENSIONS;
+use crate::validation::{self, Severity, Validate};
+use crate::{Path, Root};
+
+/// Selects the data removed by [`Root::strip`].
+///
+/// The default options remove nothing.
+///
+/// [`Root::strip`]: ../struct.Root.html#method.strip
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct StripOptions {
+    /// Remove the user-defined `name` of every object.
+    pub names: bool,
+
+    /// Remove the `extras` of every object.
+    pub extras: bool,
+
+    /// Remove every extension that is not parsed by an enabled crate feature,
+    /// see `extensions::ENABLED_EXTENSIONS`.
+    pub unknown_extensions: bool,
+
+    /// Remove the named extensions, whether or not they are parsed.
+    pub specific_extensions: Vec<String>,
+}
+
+/// Summary of the changes made by [`Root::strip`].
+///
+/// [`Root::strip`]: ../struct.Root.html#method.strip
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Report {
+    /// The names of the removed extensions, sorted by name.
+    pub removed_extensions: Vec<String>,
+
+    /// The length of the compact JSON of the document before stripping.
+    pub bytes_before: usize,
+
+    /// The length of the compact JSON of the document after stripping.
+    pub bytes_after: usize,
+}
+
+impl Report {
+    /// Returns the number of bytes of compact JSON saved by stripping.
+    pub fn bytes_saved(&self) -> usize {
+        self.bytes_before.saturating_sub(self.bytes_after)
+    }
+}
+
+/// Removes the selected data from a JSON value of the document.
+struct Stripper<'a> {
+    options: &'a StripOptions,
+    removed: BTreeSet<String>,
+}
+
+impl Stripper<'_> {
+    fn removes(&self, extension: &str) -> bool {
+        self.options.specific_extensions.iter().any(|x| x == extension)
+            || (self.options.unknown_extensions && !ENABLED_EXTENSIONS.contains(&extension))
+    }
+
+    fn value(&mut self, value: &mut Value) {
+        match value {
+            Value::Object(map) => self.object(map),
+            Value::Array(items) => items.iter_mut().for_each(|x| self.value(x)),
+            _ => {},
+        }
+    }
+
+    /// Strips a glTF object and the objects nested in it.
+    ///
+    /// Extras and the extensions that are not parsed are free-form JSON, so
+    /// their contents are kept as they are. The packets of `KHR_xmp_json_ld`
+    /// are free-form as well.
+    fn object(&mut self, map: &mut Map<String, Value>) {
+        if self.options.names {
+            map.remove("name");
+        }
+        if self.options.extras {
+            map.remove("extras");
+        }
+        if let Some(Value::Object(extensions)) = map.get_mut("extensions") {
+            let names = extensions.keys().filter(|x| self.removes(x)).cloned().collect::<Vec<_>>();
+            let emptied = !names.is_empty() && names.len() == extensions.len();
+            for name in names {
+                extensions.remove(&name);
+                self.removed.insert(name);
+            }
+            for (name, value) in extensions.iter_mut() {
+                if ENABLED_EXTENSIONS.contains(&name.as_str()) && name != "KHR_xmp_json_ld" {
+                    self.value(value);
+                }
+            }
+            if emptied {
+                map.remove("extensions");
+            }
+        }
+        for (key, value) in map.iter_mut() {
+            if key != "extras" && key != "extensions" {
+                self.value(value);
+            }
+        }
+    }
+}
+
+/// Returns the validation errors of a document, excluding warnings.
+fn errors(root: &Root) -> Vec<(Path, validation::Error)> {
+    let mut errors = Vec::new();
+    root.validate(root, Path::new, &mut |path, error| {
+        if error.severity() == Severity::Error {
+            errors.push((path(), error));
+        }
+    });
+    errors
+}
+
+impl Root {
+    /// Removes names, extras, and extensions from every object of the
+    /// document, as selected by `options`, for example to scrub authoring
+    /// metadata before shipping.
+    ///
+    /// Nested objects such as texture references, primitives, samplers, and
+    /// animation channels are stripped as well. Removed extensions are also
+    /// removed from `extensionsUsed` and `extensionsRequired`.
+    ///
+    /// Fails with `Error::Invalid` and leaves the document unchanged if
+    /// stripping would introduce a validation error, for example when an
+    /// `EXT_texture_webp` texture has no other source, or if the document
+    /// cannot be serialized.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// use gltf_json::strip::StripOptions;
+    ///
+    /// let mut root = gltf_json::Root::from_str(r#"{
+    ///     "asset": {"version": "2.0", "generator": "exporter"},
+    ///     "extensionsUsed": ["EXT_authoring_notes"],
+    ///     "extensions": {"EXT_authoring_notes": {"author": "someone"}},
+    ///     "nodes": [{"name": "Root"}]
+    /// }"#)?;
+    /// let options = StripOptions { names: true, extras: true, unknown_extensions: true, ..Default::default() };
+    /// let report = root.strip(&options)?;
+    /// assert_eq!(report.removed_extensions, vec!["EXT_authoring_notes".to_string()]);
+    /// assert!(report.bytes_saved() > 0);
+    /// assert!(root.extensions_used.is_empty());
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    pub fn strip(&mut self, options: &StripOptions) -> Result<Report, Error> {
+        let invalid = |_| Error::Invalid(Path::new());
+        let bytes_before = self.to_vec().map_err(invalid)?.len();
+        let mut value = self.to_value().map_err(invalid)?;
+        let mut stripper = Stripper { options, removed: BTreeSet::new() };
+        stripper.value(&mut value);
+        let mut root = Root::try_from(value).map_err(invalid)?;
+        for names in [&mut root.extensions_used, &mut root.extensions_required] {
+            names.retain(|name| {
+                let removed = stripper.removes(name);
+                if removed {
+                    stripper.removed.insert(name.clone());
+                }
+                !removed
+            });
+        }
+
+        let before = errors(self);
+        if let Some((path, _)) = errors(&root).into_iter().find(|x| !before.contains(x)) {
+            return Err(Error::Invalid(path));
+        }
+        let bytes_after = root.to_vec().map_err(invalid)?.len();
+        *self = root;
+        Ok(Report {
+            removed_extensions: stripper.removed.into_iter().collect(),
+            bytes_before,
+            bytes_after,
+        })
+    }
+}
@@ -2,8 +2,8 @@ use gltf_derive::Validate;
 use serde_derive::{Serialize, Deserialize};
 use serde::{de, ser};
 use std::fmt;
-use crate::validation::Checked;
-use crate::{extensions, image, Extras, Index};
+use crate::validation::{Checked, Error, Validate};
+use crate::{extensions, image, Extras, Index, Path, Root};
 
 /// Corresponds to `GL_NEAREST`.
 pub const NEAREST: u32 = 9728;
@@ -32,6 +32,20 @@ pub const MIRRORED_REPEAT: u32 = 33_648;
 /// Corresponds to `GL_REPEAT`.
 pub const REPEAT: u32 = 10_497;
 
+/// The default values of texture properties, as defined by the
+/// specification.
+///
+/// Properties equal to their default value are omitted when serializing.
+pub mod defaults {
+    use super::WrappingMode;
+
+    /// The default `wrapS` and `wrapT` of a sampler.
+    pub const WRAPPING_MODE: WrappingMode = WrappingMode::Repeat;
+
+    /// The default `texCoord` of a texture reference.
+    pub const TEX_COORD: u32 = 0;
+}
+
 /// All valid magnification filters.
 pub const VALID_MAG_FILTERS: &'static [u32] = &[
     NEAREST,
@@ -109,6 +123,11 @@ impl MinFilter {
             MinFilter::LinearMipmapLinear => LINEAR_MIPMAP_LINEAR,
         }
     }
+
+    /// Returns `true` if the filter samples from a mipmap chain.
+    pub fn uses_mipmaps(&self) -> bool {
+        !matches!(*self, MinFilter::Nearest | MinFilter::Linear)
+    }
 }
 
 /// Texture co-ordinate wrapping mode.
@@ -152,14 +171,14 @@ pub struct Sampler {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
-    pub name: Option<String>,
+    pub name: Option<crate::Str>,
 
     /// `s` wrapping mode.
-    #[serde(default, rename = "wrapS")]
+    #[serde(default, rename = "wrapS", skip_serializing_if = "is_wrapping_mode_default")]
     pub wrap_s: Checked<WrappingMode>,
 
     /// `t` wrapping mode.
-    #[serde(default, rename = "wrapT")]
+    #[serde(default, rename = "wrapT", skip_serializing_if = "is_wrapping_mode_default")]
     pub wrap_t: Checked<WrappingMode>,
 
     /// Extension specific data.
@@ -168,24 +187,36 @@ pub struct Sampler {
 
     /// Optional application specific data.
     #[serde(default)]
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "crate::extras::is_empty"))]
     pub extras: Extras,
 }
 
+fn is_wrapping_mode_default(mode: &Checked<WrappingMode>) -> bool {
+    *mode == Checked::Valid(defaults::WRAPPING_MODE)
+}
+
+pub(crate) fn is_tex_coord_default(tex_coord: &u32) -> bool {
+    *tex_coord == defaults::TEX_COORD
+}
+
 /// A texture and its sampler.
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct Texture {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
-    pub name: Option<String>,
+    pub name: Option<crate::Str>,
 
     /// The index of the sampler used by this texture.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sampler: Option<Index<Sampler>>,
 
     /// The index of the image used by this texture.
-    pub source: Index<image::Image>,
+    ///
+    /// May be omitted when an extension such as `EXT_texture_webp` or
+    /// `MSFT_texture_dds` provides the image instead.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<Index<image::Image>>,
 
     /// Extension specific data.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -193,10 +224,57 @@ pub struct Texture {
 
     /// Optional application specific data.
     #[serde(default)]
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "crate::extras::is_empty"))]
     pub extras: Extras,
 }
 
+impl Texture {
+    /// Returns the image index of the `EXT_texture_webp` extension, if any.
+    #[cfg(feature = "EXT_texture_webp")]
+    pub fn webp_source(&self) -> Option<Index<image::Image>> {
+        self.extensions
+            .as_ref()
+            .and_then(|x| x.texture_webp.as_ref())
+            .map(|x| x.source)
+    }
+
+    /// Returns the image index of the `MSFT_texture_dds` extension, if any.
+    #[cfg(feature = "MSFT_texture_dds")]
+    pub fn dds_source(&self) -> Option<Index<image::Image>> {
+        self.extensions
+            .as_ref()
+            .and_then(|x| x.texture_dds.as_ref())
+            .map(|x| x.source)
+    }
+}
+
+impl Validate for Texture {
+    fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+    where
+        P: Fn() -> Path,
+        R: FnMut(&dyn Fn() -> Path, Error),
+    {
+        self.sampler.validate(root, || path().field("sampler"), report);
+        self.source.validate(root, || path().field("source"), report);
+        self.extensions.validate(root, || path().field("extensions"), report);
+        self.extras.validate(root, || path().field("extras"), report);
+
+        #[allow(unused_mut)]
+        let mut has_source = self.source.is_some();
+        #[cfg(feature = "EXT_texture_webp")]
+        {
+            has_source |= self.webp_source().is_some();
+        }
+        #[cfg(feature = "MSFT_texture_dds")]
+        {
+            has_source |= self.dds_source().is_some();
+        }
+        if !has_source {
+            report(&|| path().field("source"), Error::Missing);
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
 /// Reference to a `Texture`.
 pub struct Info {
@@ -204,7 +282,7 @@ pub struct Info {
     pub index: Index<Texture>,
 
     /// The set index of the texture's `TEXCOORD` attribute.
-    #[serde(default, rename = "texCoord")]
+    #[serde(default, rename = "texCoord", skip_serializing_if = "is_tex_coord_default")]
     pub tex_coord: u32,
 
     /// Extension specific data.
@@ -213,10 +291,37 @@ pub struct Info {
 
     /// Optional application specific data.
     #[serde(default)]
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "crate::extras::is_empty"))]
     pub extras: Extras,
 }
 
+impl Info {
+    /// Creates a reference to the texture `index` that is sampled with the
+    /// first `TEXCOORD` set.
+    pub fn new(index: Index<Texture>) -> Self {
+        Info {
+            index,
+            tex_coord: defaults::TEX_COORD,
+            extensions: None,
+            extras: Default::default(),
+        }
+    }
+
+    /// Returns the set index of the `TEXCOORD` attribute the texture is
+    /// sampled with, which is the `texCoord` of the `KHR_texture_transform`
+    /// extension if it overrides that of the reference.
+    pub fn effective_tex_coord(&self) -> u32 {
+        #[cfg(feature = "KHR_texture_transform")]
+        {
+            let transform = self.extensions.as_ref().and_then(|x| x.texture_transform.as_ref());
+            if let Some(tex_coord) = transform.and_then(|x| x.tex_coord) {
+                return tex_coord;
+            }
+        }
+        self.tex_coord
+    }
+}
+
 impl<'de> de::Deserialize<'de> for Checked<MagFilter> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
         where D: de::Deserializer<'de>
@@ -326,7 +431,7 @@ impl ser::Serialize for MagFilter {
 
 impl Default for WrappingMode {
     fn default() -> Self {
-        WrappingMode::Repeat
+        defaults::WRAPPING_MODE
     }
 }
 
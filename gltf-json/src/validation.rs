@@ -1,5 +1,6 @@
 use serde::{ser, Serialize, Serializer};
-use std::collections::HashMap;
+use serde_derive::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 use std::hash::Hash;
 
 use crate::{Path, Root};
@@ -27,10 +28,242 @@ pub enum Error {
 
     /// Some required data has been omitted.
     Missing,
+
+    /// A unit quaternion deviates from unit length by more than
+    /// `UNIT_LENGTH_TOLERANCE`.
+    Denormalized,
+
+    /// A unit quaternion has zero length and cannot be renormalized.
+    ZeroLength,
+
+    /// The number of elements differs from that of related data.
+    CountMismatch {
+        /// The expected number of elements.
+        expected: u32,
+
+        /// The actual number of elements.
+        actual: u32,
+    },
+
+    /// An item duplicates a preceding item where duplicates are not allowed.
+    Duplicate,
+
+    /// An accessor used as a vertex attribute other than `POSITION` does not
+    /// declare `min` and `max`, which the specification does not require but
+    /// tools may rely on.
+    MissingBounds,
+
+    /// An animation channel targets the translation, rotation, or scale of a
+    /// node whose transform is defined by `matrix`, which the specification
+    /// forbids.
+    MatrixAnimated {
+        /// The index of the targeted node.
+        node: u32,
+    },
+
+    /// The `matrix` of an animated node was decomposed into translation,
+    /// rotation, and scale when loading so the animation can apply.
+    ///
+    /// Matrices with shear or non-uniform scale along rotated axes are only
+    /// approximated by the decomposition.
+    MatrixDecomposed,
+
+    /// Binary glTF has a `BIN` chunk but no buffers refer to it.
+    BinChunkUnused,
+
+    /// The first buffer of binary glTF with a `BIN` chunk defines a `uri`,
+    /// which the specification forbids. The `uri` is used instead of the
+    /// chunk.
+    BinChunkShadowed,
+
+    /// A buffer does not define a `uri` although it is not the first buffer
+    /// of binary glTF, which is the only buffer that may refer to the `BIN`
+    /// chunk.
+    BufferUriMissing,
+
+    /// A number is NaN or infinite. Numbers that overflow `f32` when
+    /// deserialized, such as `1e39`, are infinite.
+    NonFinite,
+
+    /// A NaN or infinite number was replaced with a safe default when
+    /// loading, such as the identity scale of a node or the nearest bound of
+    /// a material factor.
+    NonFiniteReplaced,
+
+    /// An extension listed in `extensionsRequired` is not supported, either
+    /// because the library does not implement it or because its cargo
+    /// feature is disabled.
+    UnsupportedExtension,
+
+    /// A value defined by an extension, such as the `image/ktx2` MIME type
+    /// of `KHR_texture_basisu`, is used without listing the extension in
+    /// `extensionsUsed`.
+    UndeclaredExtension,
+
+    /// The elements of an accessor extend past the end of its buffer view.
+    AccessorTooLong {
+        /// The number of bytes spanned by the elements.
+        required: u64,
+
+        /// The `byteLength` of the buffer view.
+        available: u64,
+    },
+
+    /// An accessor used as the indices of a primitive, or the indices of a
+    /// sparse accessor, is not `SCALAR`, does not have unsigned byte, short,
+    /// or int components, is normalized, or is in a buffer view with a
+    /// `byteStride`.
+    InvalidIndexAccessor,
+
+    /// A texture of the material of a primitive is sampled with a `TEXCOORD`
+    /// set that the primitive does not define, taking the override of
+    /// `KHR_texture_transform` into account.
+    TexCoordMissing,
+
+    /// An object repeats a key, of which only the last member is kept.
+    DuplicateKey,
+
+    /// An attribute semantic writes its set index with leading zeros, such
+    /// as `TEXCOORD_01`. The attribute is read as the set it denotes.
+    NonCanonicalSemantic,
+}
+
+/// Specifies how severe a validation error is.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// The data is valid, but could be improved.
+    Info,
+
+    /// The data can be loaded but should be corrected.
+    Warning,
+
+    /// The data cannot be loaded safely.
+    Error,
+}
+
+/// The maximum deviation from unit length of a unit quaternion before it is
+/// reported as `Error::Denormalized`.
+pub const UNIT_LENGTH_TOLERANCE: f32 = 1.0e-3;
+
+impl Error {
+    /// Returns the severity of this error.
+    ///
+    /// Errors with `Severity::Warning` only cause validation to fail in strict
+    /// mode, and errors with `Severity::Info` never do.
+    pub fn severity(&self) -> Severity {
+        match *self {
+            Error::IndexOutOfBounds | Error::Invalid | Error::Missing => Severity::Error,
+            Error::NonFinite => Severity::Error,
+            Error::UnsupportedExtension => Severity::Error,
+            Error::AccessorTooLong { .. } => Severity::Error,
+            Error::InvalidIndexAccessor => Severity::Error,
+            Error::DuplicateKey => Severity::Error,
+            Error::Duplicate => Severity::Error,
+            Error::CountMismatch { .. } => Severity::Error,
+            Error::MatrixAnimated { .. } => Severity::Error,
+            Error::Denormalized | Error::ZeroLength => Severity::Warning,
+            Error::MatrixDecomposed => Severity::Warning,
+            Error::BinChunkUnused | Error::BinChunkShadowed => Severity::Warning,
+            Error::BufferUriMissing => Severity::Warning,
+            Error::NonFiniteReplaced => Severity::Warning,
+            Error::UndeclaredExtension => Severity::Warning,
+            Error::TexCoordMissing => Severity::Warning,
+            Error::NonCanonicalSemantic => Severity::Warning,
+            Error::MissingBounds => Severity::Info,
+        }
+    }
+
+    /// Returns a stable, machine-readable code identifying the kind of error,
+    /// for example `"INDEX_OUT_OF_BOUNDS"`.
+    ///
+    /// Codes are never changed or reused, so they may be stored in
+    /// suppression lists together with the path of the error.
+    pub fn code(&self) -> &'static str {
+        match *self {
+            Error::IndexOutOfBounds => "INDEX_OUT_OF_BOUNDS",
+            Error::Invalid => "INVALID_VALUE",
+            Error::Missing => "MISSING_VALUE",
+            Error::Denormalized => "DENORMALIZED_QUATERNION",
+            Error::ZeroLength => "ZERO_LENGTH_QUATERNION",
+            Error::CountMismatch { .. } => "COUNT_MISMATCH",
+            Error::Duplicate => "DUPLICATE_ITEM",
+            Error::MissingBounds => "ACCESSOR_BOUNDS_MISSING",
+            Error::MatrixAnimated { .. } => "NODE_MATRIX_ANIMATED",
+            Error::MatrixDecomposed => "NODE_MATRIX_DECOMPOSED",
+            Error::BinChunkUnused => "GLB_BIN_CHUNK_UNUSED",
+            Error::BinChunkShadowed => "GLB_BIN_CHUNK_SHADOWED",
+            Error::BufferUriMissing => "BUFFER_URI_MISSING",
+            Error::NonFinite => "NON_FINITE_VALUE",
+            Error::NonFiniteReplaced => "NON_FINITE_VALUE_REPLACED",
+            Error::UnsupportedExtension => "UNSUPPORTED_EXTENSION",
+            Error::UndeclaredExtension => "UNDECLARED_EXTENSION",
+            Error::AccessorTooLong { .. } => "ACCESSOR_TOO_LONG",
+            Error::InvalidIndexAccessor => "INVALID_INDEX_ACCESSOR",
+            Error::TexCoordMissing => "TEXCOORD_MISSING",
+            Error::DuplicateKey => "DUPLICATE_KEY",
+            Error::NonCanonicalSemantic => "NON_CANONICAL_SEMANTIC",
+        }
+    }
+}
+
+/// Rules and parts of a document whose validation errors are suppressed.
+///
+/// Suppressed errors do not cause validation to fail, but are still listed in
+/// validation reports. The options can be stored in configuration files, as
+/// they serialize to an object with a `codes` and a `pathPrefixes` array.
+///
+/// ```
+/// # use gltf_json::validation::{Error, ValidationOptions};
+/// # use gltf_json::Path;
+/// let options = ValidationOptions::default()
+///     .suppress("DENORMALIZED_QUATERNION")
+///     .suppress_path_prefix("materials[2]");
+/// let path = Path::new().field("materials").index(2).field("alphaCutoff");
+/// assert!(options.is_suppressed(&path, &Error::Invalid));
+/// assert!(!options.is_suppressed(&Path::new().field("materials").index(20), &Error::Invalid));
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ValidationOptions {
+    /// The codes of the suppressed rules, as returned by `Error::code`.
+    #[serde(default)]
+    pub codes: Vec<String>,
+
+    /// The paths of the suppressed objects and properties.
+    #[serde(default, rename = "pathPrefixes")]
+    pub path_prefixes: Vec<String>,
+}
+
+impl ValidationOptions {
+    /// Suppresses the errors with the given code, such as `"INVALID_VALUE"`.
+    pub fn suppress(mut self, code: &str) -> Self {
+        self.codes.push(code.to_string());
+        self
+    }
+
+    /// Suppresses the errors at the given path and below it.
+    ///
+    /// The prefix `materials[0]` suppresses errors at `materials[0]` and
+    /// `materials[0].alphaCutoff`, but not at `materials[01]`.
+    pub fn suppress_path_prefix(mut self, path: &str) -> Self {
+        self.path_prefixes.push(path.to_string());
+        self
+    }
+
+    /// Returns `true` if the error at the given path is suppressed.
+    pub fn is_suppressed(&self, path: &Path, error: &Error) -> bool {
+        let path = path.as_str();
+        let below = |prefix: &String| match path.strip_prefix(prefix.as_str()) {
+            Some(rest) => rest.is_empty() || rest.starts_with('.') || rest.starts_with('['),
+            None => false,
+        };
+        self.codes.iter().any(|code| code == error.code()) || self.path_prefixes.iter().any(below)
+    }
 }
 
 /// Specifies a type that has been pre-validated during deserialization or otherwise.
-#[derive(Debug, Eq, Hash, PartialEq)]
+///
+/// Valid items are ordered before invalid items.
+#[derive(Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum Checked<T> {
     /// The item is valid.
     Valid(T),
@@ -48,6 +281,15 @@ impl<T> Checked<T> {
         }
     }
 
+    /// Converts from `Checked<T>` to `Option<T>`, discarding an `Invalid`
+    /// item.
+    pub fn valid(self) -> Option<T> {
+        match self {
+            Checked::Valid(item) => Some(item),
+            Checked::Invalid => None,
+        }
+    }
+
     /// Takes ownership of the contained item if it is `Valid`.
     ///
     /// # Panics
@@ -100,7 +342,22 @@ impl<T> Validate for Checked<T> {
     }
 }
 
+/// Entries are validated in the order of their keys as strings, so errors
+/// are reported in the same order for every instance of the map.
 impl<K: Eq + Hash + ToString + Validate, V: Validate> Validate for HashMap<K, V> {
+    fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+    {
+        let mut entries = self.iter().collect::<Vec<_>>();
+        entries.sort_by_cached_key(|(key, _)| key.to_string());
+        for (key, value) in entries {
+            key.validate(root, || path().key(&key.to_string()), report);
+            value.validate(root, || path().key(&key.to_string()), report);
+        }
+    }
+}
+
+impl<K: Ord + ToString + Validate, V: Validate> Validate for BTreeMap<K, V> {
     fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
         where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
     {
@@ -145,14 +402,70 @@ impl std::error::Error for Error {
             Error::IndexOutOfBounds => "Index out of bounds",
             Error::Invalid => "Invalid value",
             Error::Missing => "Missing data",
+            Error::Denormalized => "Denormalized quaternion",
+            Error::ZeroLength => "Zero-length quaternion",
+            Error::CountMismatch { .. } => "Count mismatch",
+            Error::Duplicate => "Duplicate item",
+            Error::MissingBounds => "Missing accessor bounds",
+            Error::MatrixAnimated { .. } => "Animated node defines a matrix",
+            Error::MatrixDecomposed => "Matrix decomposed for animation",
+            Error::BinChunkUnused => "BIN chunk is not used by any buffer",
+            Error::BinChunkShadowed => "Buffer defines a uri instead of using the BIN chunk",
+            Error::BufferUriMissing => "Buffer does not define a uri and cannot use the BIN chunk",
+            Error::NonFinite => "Number is NaN or infinite",
+            Error::NonFiniteReplaced => "Non-finite number replaced with a default",
+            Error::UnsupportedExtension => "Required extension is not supported",
+            Error::UndeclaredExtension => "Extension is used but not listed in extensionsUsed",
+            Error::AccessorTooLong { .. } => "Accessor extends past the end of its buffer view",
+            Error::InvalidIndexAccessor => "Accessor cannot be used as indices",
+            Error::TexCoordMissing => "Texture is sampled with a TEXCOORD set the primitive does not define",
+            Error::DuplicateKey => "Duplicate object key",
+            Error::NonCanonicalSemantic => "Attribute semantic has a set index with leading zeros",
         }
     }
 }
 
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        use std::error::Error;
-        write!(f, "{}", self.description())
+        use std::error::Error as StdError;
+        match *self {
+            Error::CountMismatch { expected, actual } => {
+                write!(f, "Count mismatch: expected {} but found {}", expected, actual)
+            },
+            Error::MatrixAnimated { node } => {
+                write!(f, "Animated node nodes[{}] defines a matrix", node)
+            },
+            Error::AccessorTooLong { required, available } => {
+                write!(f, "Accessor requires {} bytes but its buffer view has {}", required, available)
+            },
+            _ => write!(f, "{}", self.description()),
+        }
+    }
+}
+
+impl Validate for f32 {
+    fn validate<P, R>(&self, _root: &Root, path: P, report: &mut R)
+    where
+        P: Fn() -> Path,
+        R: FnMut(&dyn Fn() -> Path, Error),
+    {
+        if !self.is_finite() {
+            report(&path, Error::NonFinite);
+        }
+    }
+}
+
+impl<const N: usize> Validate for [f32; N] {
+    fn validate<P, R>(&self, _root: &Root, path: P, report: &mut R)
+    where
+        P: Fn() -> Path,
+        R: FnMut(&dyn Fn() -> Path, Error),
+    {
+        // A single error for the array, since the values are usually replaced
+        // together.
+        if self.iter().any(|x| !x.is_finite()) {
+            report(&path, Error::NonFinite);
+        }
     }
 }
 
@@ -160,10 +473,6 @@ impl std::fmt::Display for Error {
 impl Validate for bool {}
 impl Validate for u32 {}
 impl Validate for i32 {}
-impl Validate for f32 {}
-impl Validate for [f32; 3] {}
-impl Validate for [f32; 4] {}
-impl Validate for [f32; 16] {}
 impl Validate for () {}
 impl Validate for String {}
 impl Validate for serde_json::Value {}
@@ -0,0 +1,39 @@
+use gltf_json::mesh::{Primitive, Semantic};
+use gltf_json::validation::Checked;
+use gltf_json::{deserialize, serialize};
+
+#[test]
+fn test_attribute_order() {
+    let json = r#"{"attributes": {
+        "_ID": 7, "WEIGHTS_0": 6, "TEXCOORD_1": 4, "COLOR_0": 5, "_BATCH": 8,
+        "TEXCOORD_0": 3, "JOINTS_0": 2, "POSITION": 0, "NORMAL": 1
+    }}"#;
+    let primitive: Primitive = deserialize::from_str(json).unwrap();
+    let semantics = primitive.attributes
+        .keys()
+        .map(|x| match x {
+            Checked::Valid(semantic) => semantic.clone(),
+            Checked::Invalid => panic!("invalid semantic"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        semantics,
+        vec![
+            Semantic::Positions,
+            Semantic::Normals,
+            Semantic::TexCoords(0),
+            Semantic::TexCoords(1),
+            Semantic::Colors(0),
+            Semantic::Joints(0),
+            Semantic::Weights(0),
+            Semantic::Extras("BATCH".to_string()),
+            Semantic::Extras("ID".to_string()),
+        ]
+    );
+
+    // The attributes are still written as a JSON object.
+    assert_eq!(
+        serialize::to_string(&primitive.attributes).unwrap(),
+        r#"{"POSITION":0,"NORMAL":1,"TEXCOORD_0":3,"TEXCOORD_1":4,"COLOR_0":5,"JOINTS_0":2,"WEIGHTS_0":6,"_BATCH":8,"_ID":7}"#,
+    );
+}
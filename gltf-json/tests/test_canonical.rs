@@ -0,0 +1,66 @@
+use gltf_json::canonical::SerializeOptions;
+use gltf_json::Root;
+
+/// A document with unsorted extension names, floats written with excess
+/// digits, and unnormalized extras.
+const DOCUMENT: &str = r#"{
+    "asset": {"version": "2.0", "generator": "café"},
+    "extensionsUsed": ["KHR_texture_transform", "KHR_materials_unlit"],
+    "nodes": [
+        {"translation": [0.10000000149011612, 2, -3.25], "scale": [1e0, 1, 1]},
+        {"rotation": [0, 0.7071068, 0, 0.7071068], "extras": {"z": [1.500, 2E3],  "a": "A"}}
+    ],
+    "scenes": [{"nodes": [0, 1]}]
+}"#;
+
+const CANONICAL: SerializeOptions = SerializeOptions { canonical: true, pretty: false, float_precision: None };
+
+#[test]
+fn test_default_options_match_plain_output() {
+    let root = Root::from_str(DOCUMENT).unwrap();
+    assert_eq!(root.to_vec_with(&SerializeOptions::default()).unwrap(), root.to_vec().unwrap());
+    let pretty = SerializeOptions { pretty: true, ..Default::default() };
+    assert_eq!(root.to_vec_with(&pretty).unwrap(), root.to_vec_pretty().unwrap());
+}
+
+#[test]
+fn test_canonical_output_is_reproducible() {
+    for options in [CANONICAL, SerializeOptions { pretty: true, ..CANONICAL }] {
+        let root = Root::from_str(DOCUMENT).unwrap();
+        let first = root.to_string_with(&options).unwrap();
+        assert_eq!(root.to_string_with(&options).unwrap(), first);
+
+        let reparsed = Root::from_str(&first).unwrap();
+        assert_eq!(reparsed.to_string_with(&options).unwrap(), first);
+    }
+}
+
+#[test]
+fn test_canonical_normalization() {
+    let root = Root::from_str(DOCUMENT).unwrap();
+    let json = root.to_string_with(&CANONICAL).unwrap();
+    assert!(json.contains(r#""extensionsUsed":["KHR_materials_unlit","KHR_texture_transform"]"#));
+    assert!(json.contains(r#""translation":[0.1,2.0,-3.25]"#));
+    assert!(json.contains(r#""generator":"café""#));
+    #[cfg(feature = "extras")]
+    assert!(json.contains(r#""extras":{"z":[1.5,2000.0],"a":"A"}"#));
+
+    // Pretty output is laid out as `to_vec_pretty` does, and extras are
+    // indented too.
+    let pretty = root.to_string_with(&SerializeOptions { pretty: true, ..CANONICAL }).unwrap();
+    #[cfg(not(feature = "extras"))]
+    assert_eq!(pretty, Root::from_str(&json).unwrap().to_string_pretty().unwrap());
+    #[cfg(feature = "extras")]
+    assert!(pretty.contains("\"extras\": {\n        \"z\": [\n          1.5,"));
+}
+
+#[test]
+fn test_float_precision() {
+    let root = Root::from_str(DOCUMENT).unwrap();
+    let options = SerializeOptions { float_precision: Some(2), ..Default::default() };
+    let json = root.to_string_with(&options).unwrap();
+    assert!(json.contains(r#""rotation":[0.0,0.71,0.0,0.71]"#));
+    assert!(json.contains(r#""translation":[0.1,2.0,-3.25]"#));
+    let reparsed = Root::from_str(&json).unwrap();
+    assert_eq!(reparsed.to_string_with(&options).unwrap(), json);
+}
@@ -0,0 +1,84 @@
+use gltf_json::material::{self, defaults, AlphaCutoff, Material, NormalTexture, OcclusionTexture};
+use gltf_json::texture::{self, Info, Sampler};
+use gltf_json::validation::Checked;
+use gltf_json::{deserialize, serialize, Index, Value};
+
+/// Serializes `value`, ignoring the `extras` which are always written unless
+/// the `extras` feature is enabled.
+fn to_value<T: serde::Serialize>(value: &T) -> Value {
+    let mut value = serialize::to_value(value).unwrap();
+    strip_extras(&mut value);
+    value
+}
+
+fn strip_extras(value: &mut Value) {
+    if let Value::Object(map) = value {
+        map.remove("extras");
+        map.values_mut().for_each(strip_extras);
+    }
+}
+
+#[test]
+fn test_defaults_are_not_serialized() {
+    let empty = Value::Object(Default::default());
+    assert_eq!(to_value(&Material::default()), empty);
+    assert_eq!(to_value(&Sampler::default()), empty);
+
+    let info: Info = deserialize::from_str(r#"{"index": 0}"#).unwrap();
+    assert_eq!(to_value(&info), serde_json::json!({"index": 0}));
+    let normal: NormalTexture = deserialize::from_str(r#"{"index": 0}"#).unwrap();
+    assert_eq!(to_value(&normal), serde_json::json!({"index": 0}));
+    let occlusion: OcclusionTexture = deserialize::from_str(r#"{"index": 0}"#).unwrap();
+    assert_eq!(to_value(&occlusion), serde_json::json!({"index": 0}));
+}
+
+#[test]
+fn test_defaults_match_deserialized_values() {
+    let material: Material = deserialize::from_str("{}").unwrap();
+    assert_eq!(material.alpha_cutoff.0, defaults::ALPHA_CUTOFF);
+    assert_eq!(material.alpha_mode, Checked::Valid(defaults::ALPHA_MODE));
+    assert_eq!(material.double_sided, defaults::DOUBLE_SIDED);
+    assert_eq!(material.emissive_factor.0, defaults::EMISSIVE_FACTOR);
+    let pbr = &material.pbr_metallic_roughness;
+    assert_eq!(pbr.base_color_factor.0, defaults::BASE_COLOR_FACTOR);
+    assert_eq!(pbr.metallic_factor.0, defaults::METALLIC_FACTOR);
+    assert_eq!(pbr.roughness_factor.0, defaults::ROUGHNESS_FACTOR);
+
+    let normal: NormalTexture = deserialize::from_str(r#"{"index": 0}"#).unwrap();
+    assert_eq!(normal.scale, defaults::NORMAL_SCALE);
+    assert_eq!(normal.tex_coord, texture::defaults::TEX_COORD);
+    let occlusion: OcclusionTexture = deserialize::from_str(r#"{"index": 0}"#).unwrap();
+    assert_eq!(occlusion.strength.0, defaults::OCCLUSION_STRENGTH);
+
+    let sampler: Sampler = deserialize::from_str("{}").unwrap();
+    assert_eq!(sampler.wrap_s, Checked::Valid(texture::defaults::WRAPPING_MODE));
+    assert_eq!(sampler.wrap_t, Checked::Valid(texture::defaults::WRAPPING_MODE));
+}
+
+#[test]
+fn test_non_default_values_are_serialized() {
+    let mut material = Material::default();
+    material.alpha_cutoff = AlphaCutoff(0.25);
+    material.alpha_mode = Checked::Valid(material::AlphaMode::Mask);
+    material.pbr_metallic_roughness.roughness_factor.0 = 0.5;
+    material.pbr_metallic_roughness.base_color_texture = Some(Info {
+        index: Index::new(0),
+        tex_coord: 1,
+        extensions: None,
+        extras: Default::default(),
+    });
+    assert_eq!(to_value(&material), serde_json::json!({
+        "alphaCutoff": 0.25,
+        "alphaMode": "MASK",
+        "pbrMetallicRoughness": {
+            "baseColorTexture": {"index": 0, "texCoord": 1},
+            "roughnessFactor": 0.5
+        }
+    }));
+
+    let sampler = Sampler {
+        wrap_t: Checked::Valid(texture::WrappingMode::ClampToEdge),
+        ..Default::default()
+    };
+    assert_eq!(to_value(&sampler), serde_json::json!({"wrapT": 33071}));
+}
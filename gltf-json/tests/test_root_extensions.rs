@@ -0,0 +1,70 @@
+use gltf_json::{deserialize, serialize, Root};
+
+const UNKNOWN: &str = r#"{
+    "asset": {"version": "2.0"},
+    "extensions": {
+        "VENDOR_scene_metadata": {"z": 1.50, "a": [1e3, "x"]},
+        "KHR_lights_punctual": {"lights": [{"type": "point"}]}
+    }
+}"#;
+
+#[test]
+fn test_unknown_root_extensions_are_preserved() {
+    let root: Root = deserialize::from_str(UNKNOWN).unwrap();
+    let extensions = root.extensions.as_ref().unwrap();
+    assert_eq!(extensions.others["VENDOR_scene_metadata"].get(), r#"{"z": 1.50, "a": [1e3, "x"]}"#);
+    let value = extensions.value("VENDOR_scene_metadata").unwrap();
+    assert_eq!(value["a"][1], "x");
+    assert!(extensions.value("VENDOR_missing").is_none());
+
+    // The raw JSON is written back byte for byte.
+    let json = serialize::to_string(&root).unwrap();
+    assert!(json.contains(r#""VENDOR_scene_metadata":{"z": 1.50, "a": [1e3, "x"]}"#), "{}", json);
+    let reparsed: Root = deserialize::from_str(&json).unwrap();
+    assert_eq!(serialize::to_string(&reparsed).unwrap(), json);
+}
+
+#[test]
+fn test_known_root_extensions_are_parsed() {
+    let root: Root = deserialize::from_str(UNKNOWN).unwrap();
+    let extensions = root.extensions.as_ref().unwrap();
+    #[cfg(feature = "KHR_lights_punctual")]
+    {
+        assert_eq!(extensions.khr_lights_punctual.as_ref().unwrap().lights.len(), 1);
+        assert!(!extensions.others.contains_key("KHR_lights_punctual"));
+    }
+    #[cfg(not(feature = "KHR_lights_punctual"))]
+    assert!(extensions.others.contains_key("KHR_lights_punctual"));
+    let lights = extensions.value("KHR_lights_punctual").unwrap();
+    assert_eq!(lights["lights"][0]["type"], "point");
+}
+
+#[cfg(feature = "KHR_xmp_json_ld")]
+#[test]
+fn test_xmp_packets() {
+    use gltf_json::validation::{Error, Validate};
+
+    let json = r#"{
+        "asset": {"version": "2.0", "extensions": {"KHR_xmp_json_ld": {"packet": 0}}},
+        "extensions": {
+            "KHR_xmp_json_ld": {
+                "packets": [{
+                    "@context": {"dc": "http://purl.org/dc/elements/1.1/"},
+                    "dc:creator": {"@list": ["Author"]}
+                }]
+            }
+        },
+        "nodes": [{"extensions": {"KHR_xmp_json_ld": {"packet": 1}}}]
+    }"#;
+    let root: Root = deserialize::from_str(json).unwrap();
+    let packets = &root.extensions.as_ref().unwrap().khr_xmp_json_ld.as_ref().unwrap().packets;
+    assert_eq!(packets.len(), 1);
+    assert_eq!(packets[0].0["dc:creator"]["@list"][0], "Author");
+    let node = root.nodes[0].extensions.as_ref().unwrap().khr_xmp_json_ld.as_ref().unwrap();
+    assert_eq!(node.packet.value(), 1);
+
+    let mut errors = Vec::new();
+    root.validate(&root, gltf_json::Path::new, &mut |path, error| errors.push((path(), error)));
+    let errors = errors.iter().map(|(path, error)| (path.as_str(), *error)).collect::<Vec<_>>();
+    assert_eq!(errors, vec![("nodes[0].extensions.KHR_xmp_json_ld.packet", Error::IndexOutOfBounds)]);
+}
@@ -0,0 +1,177 @@
+use super::DataType;
+
+/// Items that can be encoded with [`encode`].
+///
+/// [`encode`]: fn.encode.html
+pub trait Components: Copy {
+    /// The number of components of each item.
+    const COUNT: usize;
+
+    /// Returns the components of the item.
+    fn components(&self) -> &[f32];
+}
+
+impl Components for f32 {
+    const COUNT: usize = 1;
+    fn components(&self) -> &[f32] {
+        std::slice::from_ref(self)
+    }
+}
+
+macro_rules! impl_components {
+    ($($n:expr),*) => {
+        $(
+            impl Components for [f32; $n] {
+                const COUNT: usize = $n;
+                fn components(&self) -> &[f32] {
+                    &self[..]
+                }
+            }
+        )*
+    };
+}
+
+impl_components!(2, 3, 4, 9, 16);
+
+/// Accessor data produced by [`encode`].
+///
+/// [`encode`]: fn.encode.html
+#[derive(Clone, Debug, PartialEq)]
+pub struct Encoded {
+    /// The tightly packed elements in little endian byte order.
+    pub data: Vec<u8>,
+
+    /// The minimum value of each component, in the domain of the component
+    /// type as required for `accessor.min`.
+    pub min: json::Value,
+
+    /// The maximum value of each component, in the domain of the component
+    /// type as required for `accessor.max`.
+    pub max: json::Value,
+}
+
+/// Returns the factor normalized values are multiplied with before rounding,
+/// or `None` if the component type cannot be normalized.
+fn scale(component_type: DataType) -> Option<f32> {
+    match component_type {
+        DataType::I8 => Some(127.0),
+        DataType::U8 => Some(255.0),
+        DataType::I16 => Some(32767.0),
+        DataType::U16 => Some(65535.0),
+        DataType::U32 | DataType::F32 => None,
+    }
+}
+
+/// Returns the range of integer values of a component type.
+fn range(component_type: DataType) -> (f32, f32) {
+    match component_type {
+        DataType::I8 => (-128.0, 127.0),
+        DataType::U8 => (0.0, 255.0),
+        DataType::I16 => (-32768.0, 32767.0),
+        DataType::U16 => (0.0, 65535.0),
+        DataType::U32 => (0.0, 4_294_967_295.0),
+        DataType::F32 => (f32::MIN, f32::MAX),
+    }
+}
+
+/// Returns the largest difference between a value and its decoded encoding,
+/// for values within the range of the component type.
+///
+/// This is half of the quantization step, or zero for `F32`.
+pub fn max_error(component_type: DataType, normalized: bool) -> f32 {
+    match (component_type, normalized) {
+        (DataType::F32, _) => 0.0,
+        (_, true) => scale(component_type).map_or(0.0, |scale| 0.5 / scale),
+        (_, false) => 0.5,
+    }
+}
+
+/// Encodes items as accessor data of the given component type.
+///
+/// When `normalized` is `true`, values are clamped to `[0, 1]` for unsigned
+/// and to `[-1, 1]` for signed component types and quantized with the rounding
+/// of the glTF specification, for example `round(c * 32767.0)` for `I16`.
+/// Otherwise values are rounded to the nearest integer and clamped to the
+/// range of integer component types, and stored unchanged for `F32`.
+///
+/// Returns `None` if `items` is empty or if the component type cannot be
+/// normalized, which is the case for `U32` and `F32`.
+///
+/// ```
+/// use gltf::accessor::{encode, DataType};
+///
+/// let normals = [[0.0, 1.0, 0.0], [0.0, -1.0, 0.0]];
+/// let encoded = encode::encode(&normals, DataType::I8, true).unwrap();
+/// assert_eq!(encoded.data, vec![0, 127, 0, 0, 129, 0]);
+/// assert_eq!(encoded.min, gltf::json::Value::from(vec![0, -127, 0]));
+/// ```
+pub fn encode<T: Components>(
+    items: &[T],
+    component_type: DataType,
+    normalized: bool,
+) -> Option<Encoded> {
+    if items.is_empty() || (normalized && scale(component_type).is_none()) {
+        return None;
+    }
+    let mut data = Vec::with_capacity(items.len() * T::COUNT * component_type.size());
+    let mut min = vec![f32::INFINITY; T::COUNT];
+    let mut max = vec![f32::NEG_INFINITY; T::COUNT];
+    for item in items {
+        for (i, &x) in item.components().iter().enumerate() {
+            let value = if component_type == DataType::F32 {
+                x
+            } else if normalized {
+                let signed = matches!(component_type, DataType::I8 | DataType::I16);
+                let lower = if signed { -1.0 } else { 0.0 };
+                (x.max(lower).min(1.0) * scale(component_type).unwrap_or(1.0)).round()
+            } else {
+                let (lower, upper) = range(component_type);
+                x.round().max(lower).min(upper)
+            };
+            min[i] = min[i].min(value);
+            max[i] = max[i].max(value);
+            match component_type {
+                DataType::I8 => data.push(value as i8 as u8),
+                DataType::U8 => data.push(value as u8),
+                DataType::I16 => data.extend_from_slice(&(value as i16).to_le_bytes()),
+                DataType::U16 => data.extend_from_slice(&(value as u16).to_le_bytes()),
+                DataType::U32 => data.extend_from_slice(&(value as u32).to_le_bytes()),
+                DataType::F32 => data.extend_from_slice(&value.to_le_bytes()),
+            }
+        }
+    }
+    let to_value = |values: Vec<f32>| {
+        if component_type == DataType::F32 {
+            json::Value::from(values)
+        } else {
+            json::Value::from(values.into_iter().map(|x| x as i64).collect::<Vec<_>>())
+        }
+    };
+    Some(Encoded { data, min: to_value(min), max: to_value(max) })
+}
+
+/// Decodes tightly packed accessor data into its components, applying the
+/// normalization of the glTF specification when `normalized` is `true`.
+///
+/// Trailing bytes that do not form a whole component are ignored. Returns
+/// `None` if the component type cannot be normalized.
+pub fn decode(data: &[u8], component_type: DataType, normalized: bool) -> Option<Vec<f32>> {
+    let scale = match (normalized, scale(component_type)) {
+        (true, None) => return None,
+        (true, Some(scale)) => scale,
+        (false, _) => 1.0,
+    };
+    let values = data.chunks_exact(component_type.size()).map(|bytes| {
+        let value = match component_type {
+            DataType::I8 => f32::from(bytes[0] as i8),
+            DataType::U8 => f32::from(bytes[0]),
+            DataType::I16 => f32::from(i16::from_le_bytes([bytes[0], bytes[1]])),
+            DataType::U16 => f32::from(u16::from_le_bytes([bytes[0], bytes[1]])),
+            DataType::U32 => u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f32,
+            DataType::F32 => f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+        };
+        // The most negative signed value maps to -1.0 as well.
+        if normalized { (value / scale).max(-1.0) } else { value }
+    });
+    Some(values.collect())
+}
@@ -36,10 +36,10 @@
 //! # fn run() -> Result<(), Box<dyn std::error::Error>> {
 //! # use gltf::accessor::{DataType, Dimensions, Iter};
 //! let (gltf, buffers, _) = gltf::import("examples/Box.gltf")?;
-//! let get_buffer_data = |buffer: gltf::Buffer| buffers.get(buffer.index()).map(|x| &*x.0);
+//! let get_buffer_data = |buffer: gltf::Buffer| buffers.get(buffer.index()).map(|x| &**x);
 //! for accessor in gltf.accessors() {
 //!     match (accessor.data_type(), accessor.dimensions()) {
-//!         (DataType::F32, Dimensions::Vec3) => {
+//!         (Some(DataType::F32), Some(Dimensions::Vec3)) => {
 //!             let iter = Iter::<[f32; 3]>::new(accessor, get_buffer_data);
 //!             for item in iter {
 //!                 println!("{:?}", item);
@@ -64,12 +64,27 @@ pub use json::accessor::Type as Dimensions;
 #[cfg(feature = "utils")]
 pub mod util;
 
+/// Encoding of accessor data.
+pub mod encode;
+
 /// Contains data structures for sparse storage.
 pub mod sparse;
 
+/// Where and how accessors are used.
+pub(crate) mod usage;
+
+/// Statistics of accessor data.
+#[cfg(feature = "utils")]
+pub(crate) mod stats;
+
+pub use self::usage::Usage;
+
+#[cfg(feature = "utils")]
+pub use self::stats::{stats, Stats};
+
 #[cfg(feature = "utils")]
 #[doc(inline)]
-pub use self::util::{Item, Iter};
+pub use self::util::{Component, Item, Iter, ReadError, ReadItems, TypedItem};
 
 /// A typed view into a buffer view.
 #[derive(Clone, Debug)]
@@ -84,6 +99,8 @@ pub struct Accessor<'a> {
     json: &'a json::accessor::Accessor,
 }
 
+impl_identity!(Accessor, no_ord);
+
 impl<'a> Accessor<'a> {
     /// Constructs an `Accessor`.
     pub(crate) fn new(
@@ -104,15 +121,63 @@ impl<'a> Accessor<'a> {
     }
 
     /// Returns the size of each component that this accessor describes.
-    pub fn size(&self) -> usize {
-        self.data_type().size() * self.dimensions().multiplicity()
+    ///
+    /// Returns `None` if the data type or dimensions are invalid, which is
+    /// only possible for documents loaded without validation.
+    pub fn size(&self) -> Option<usize> {
+        Some(self.data_type()?.size() * self.dimensions()?.multiplicity())
+    }
+
+    /// Returns the stride in bytes between the elements of this accessor.
+    ///
+    /// This is the `byteStride` of the buffer view when one is authored, and
+    /// otherwise the size of one element including the column padding of
+    /// matrices, since the data is then tightly packed. Use
+    /// [`buffer::View::stride`] to distinguish the two cases.
+    ///
+    /// Returns `None` if there is no authored stride and the data type or
+    /// dimensions are invalid, which is only possible for documents loaded
+    /// without validation.
+    ///
+    /// [`buffer::View::stride`]: ../buffer/struct.View.html#method.stride
+    pub fn effective_stride(&self) -> Option<usize> {
+        self.view()
+            .and_then(|view| view.stride())
+            .or_else(|| Some(self.dimensions()?.element_size(self.data_type()?)))
+    }
+
+    /// Returns the number of bytes of its buffer view covered by the accessor,
+    /// `offset() + effective_stride() * (count() - 1) + element size`.
+    ///
+    /// Validation checks that this does not exceed the length of the buffer
+    /// view, and the readers read no further. Returns `None` if the accessor
+    /// has no buffer view.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// let gltf = gltf::Gltf::open("examples/Box.gltf")?;
+    /// for accessor in gltf.accessors() {
+    ///     let view = accessor.view().unwrap();
+    ///     assert!(accessor.required_view_length().unwrap() <= view.length());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    pub fn required_view_length(&self) -> Option<usize> {
+        let length = self.json.required_view_length(&self.document.0)?;
+        std::convert::TryFrom::try_from(length).ok()
     }
 
     /// Returns the buffer view this accessor reads from.
     ///
-    /// This may be `None` if the corresponding accessor is sparse.
+    /// This may be `None` if the corresponding accessor is sparse, or, for
+    /// documents loaded without validation, if the buffer view does not
+    /// exist.
     pub fn view(&self) -> Option<buffer::View<'a>> {
-        self.json.buffer_view.map(|view| self.document.views().nth(view.value()).unwrap())
+        self.json.buffer_view.and_then(|view| self.document.get(view))
     }
 
     /// Returns the offset relative to the start of the parent buffer view in bytes.
@@ -120,6 +185,20 @@ impl<'a> Accessor<'a> {
         self.json.byte_offset as usize
     }
 
+    /// Returns the offset of the first element relative to the start of the
+    /// parent buffer in bytes.
+    ///
+    /// This is the sum of the offsets of the buffer view and the accessor. The
+    /// accessor offset may exceed the stride, which is common when several
+    /// primitives share one interleaved buffer view. Element `i` begins at
+    /// `absolute_offset() + i * effective_stride()`.
+    ///
+    /// Returns `None` if the accessor has no buffer view, in which case its
+    /// elements are zero unless replaced by sparse values.
+    pub fn absolute_offset(&self) -> Option<usize> {
+        self.view().map(|view| view.offset() + self.offset())
+    }
+
     /// Returns the number of components within the buffer view - not to be confused
     /// with the number of bytes in the buffer view.
     pub fn count(&self) -> usize {
@@ -127,8 +206,11 @@ impl<'a> Accessor<'a> {
     }
 
     /// Returns the data type of components in the attribute.
-    pub fn data_type(&self) -> DataType {
-        self.json.component_type.unwrap().0
+    ///
+    /// Returns `None` if the component type is invalid, which is only
+    /// possible for documents loaded without validation.
+    pub fn data_type(&self) -> Option<DataType> {
+        self.json.component_type.valid().map(|x| x.0)
     }
 
     /// Optional application specific data.
@@ -137,8 +219,11 @@ impl<'a> Accessor<'a> {
     }
 
     /// Specifies if the attribute is a scalar, vector, or matrix.
-    pub fn dimensions(&self) -> Dimensions {
-        self.json.type_.unwrap()
+    ///
+    /// Returns `None` if the type is invalid, which is only possible for
+    /// documents loaded without validation.
+    pub fn dimensions(&self) -> Option<Dimensions> {
+        self.json.type_.valid()
     }
 
     /// Returns the minimum value of each component in this attribute.
@@ -154,7 +239,7 @@ impl<'a> Accessor<'a> {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     pub fn name(&self) -> Option<&'a str> {
-        self.json.name.as_ref().map(String::as_str)
+        self.json.name.as_deref()
     }
 
     /// Specifies whether integer data values should be normalized.
@@ -169,4 +254,48 @@ impl<'a> Accessor<'a> {
             sparse::Sparse::new(self.document, json)
         })
     }
+
+    /// Visits the items of the accessor as `T`, which must match its data
+    /// type and dimensions exactly.
+    ///
+    /// Items are never reinterpreted: reading `JOINTS_0` stored as `[u8; 4]`
+    /// as `[i8; 4]` or `[u16; 4]` is an error. Use [`ReadItems::cast`] for
+    /// lossless widenings and [`ReadItems::normalize`] to convert normalized
+    /// integers to floats.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let (document, buffers, _) = gltf::import("examples/Box.gltf")?;
+    /// let get = |buffer: gltf::Buffer| Some(&*buffers[buffer.index()]);
+    /// let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    /// let indices = primitive.indices().unwrap();
+    /// assert!(indices.read::<u32, _>(get).is_err());
+    /// let indices = indices.read::<u16, _>(get)?.cast::<u32>().collect::<Vec<_>>();
+    /// assert_eq!(indices.len(), 36);
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    ///
+    /// [`ReadItems::cast`]: util/struct.ReadItems.html#method.cast
+    /// [`ReadItems::normalize`]: util/struct.ReadItems.html#method.normalize
+    #[cfg(feature = "utils")]
+    pub fn read<'s, T, F>(&self, get_buffer_data: F) -> Result<util::ReadItems<'s, T>, util::ReadError>
+    where
+        T: TypedItem,
+        F: Clone + Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        let accessor = match (self.data_type(), self.dimensions()) {
+            (Some(data_type), Some(dimensions)) => (data_type, dimensions),
+            _ => return Err(util::ReadError::Unavailable),
+        };
+        let requested = (T::data_type(), T::dimensions());
+        if accessor != requested {
+            return Err(util::ReadError::TypeMismatch(util::TypeMismatch { accessor, requested }));
+        }
+        let iter = Iter::new(self.clone(), get_buffer_data).ok_or(util::ReadError::Unavailable)?;
+        Ok(util::ReadItems { iter, normalized: self.normalized() })
+    }
 }
@@ -35,8 +35,11 @@ impl<'a> Indices<'a> {
     }
 
     /// Returns the buffer view containing the sparse indices.
-    pub fn view(&self) -> buffer::View<'a> {
-        self.document.views().nth(self.json.buffer_view.value()).unwrap()
+    ///
+    /// Returns `None` if the buffer view does not exist, which is only
+    /// possible for documents loaded without validation.
+    pub fn view(&self) -> Option<buffer::View<'a>> {
+        self.document.get(self.json.buffer_view)
     }
 
     /// The offset relative to the start of the parent buffer view in bytes.
@@ -45,12 +48,16 @@ impl<'a> Indices<'a> {
     }
 
     /// The data type of each index.
-    pub fn index_type(&self) -> IndexType {
-        match self.json.component_type.unwrap().0 {
-            json::accessor::ComponentType::U8 => IndexType::U8,
-            json::accessor::ComponentType::U16 => IndexType::U16,
-            json::accessor::ComponentType::U32 => IndexType::U32,
-            _ => unreachable!(),
+    ///
+    /// Returns `None` if the component type is not an unsigned integer
+    /// type, which is only possible for documents loaded without
+    /// validation.
+    pub fn index_type(&self) -> Option<IndexType> {
+        match self.json.component_type.valid()?.0 {
+            json::accessor::ComponentType::U8 => Some(IndexType::U8),
+            json::accessor::ComponentType::U16 => Some(IndexType::U16),
+            json::accessor::ComponentType::U32 => Some(IndexType::U32),
+            _ => None,
         }
     }
 
@@ -128,8 +135,11 @@ impl<'a> Values<'a> {
     }
 
     /// Returns the buffer view containing the sparse values.
-    pub fn view(&self) -> buffer::View<'a> {
-        self.document.views().nth(self.json.buffer_view.value()).unwrap()
+    ///
+    /// Returns `None` if the buffer view does not exist, which is only
+    /// possible for documents loaded without validation.
+    pub fn view(&self) -> Option<buffer::View<'a>> {
+        self.document.get(self.json.buffer_view)
     }
 
     /// The offset relative to the start of the parent buffer view in bytes.
@@ -0,0 +1,194 @@
+use std::{mem, ops};
+
+use crate::accessor::util::{Item, Iter, ReadError};
+use crate::accessor::{Accessor, DataType, Dimensions};
+use crate::{buffer, Normalize};
+
+/// Per-component statistics of the items of an accessor, as returned by
+/// [`stats`].
+///
+/// [`stats`]: fn.stats.html
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Stats {
+    /// The number of items.
+    pub count: usize,
+
+    /// The smallest value of each component, or an empty vector if the
+    /// accessor has no items.
+    pub min: Vec<f32>,
+
+    /// The largest value of each component, or an empty vector if the
+    /// accessor has no items.
+    pub max: Vec<f32>,
+
+    /// The mean value of each component, or an empty vector if the accessor
+    /// has no items.
+    pub mean: Vec<f32>,
+
+    /// The number of items with at least one component outside the expected
+    /// range.
+    pub out_of_range: usize,
+}
+
+/// Computes the per-component minimum, maximum, and mean of the items of an
+/// accessor, and counts the items with a component outside `expected`.
+///
+/// The items are read one at a time, without collecting them. Components of
+/// normalized accessors are converted to floats as specified by glTF, and
+/// other integers are converted as they are, so the statistics of indices
+/// are those of the indices themselves.
+///
+/// Returns `ReadError::Unavailable` if the data is unavailable, and for
+/// matrices of byte or short components, whose columns are padded.
+///
+/// ```
+/// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let (document, buffers, _) = gltf::import("examples/Box.gltf")?;
+/// let get = |buffer: gltf::Buffer| Some(&*buffers[buffer.index()]);
+/// let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+/// let positions = primitive.get(&gltf::Semantic::Positions).unwrap();
+/// let stats = gltf::accessor::stats(&positions, get, -0.5..=0.5)?;
+/// assert_eq!(stats.count, 24);
+/// assert_eq!((stats.min, stats.max), (vec![-0.5; 3], vec![0.5; 3]));
+/// assert_eq!(stats.out_of_range, 0);
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #     run().expect("test failure");
+/// # }
+/// ```
+pub fn stats<'a, 's, F>(
+    accessor: &Accessor<'a>,
+    get_buffer_data: F,
+    expected: ops::RangeInclusive<f32>,
+) -> Result<Stats, ReadError>
+where
+    F: Clone + Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+{
+    scan(accessor, get_buffer_data, &|_| expected.clone())
+}
+
+/// Computes the statistics of an accessor, with the expected range of each
+/// component given by `expected`.
+pub(crate) fn scan<'a, 's, F>(
+    accessor: &Accessor<'a>,
+    get_buffer_data: F,
+    expected: &dyn Fn(usize) -> ops::RangeInclusive<f32>,
+) -> Result<Stats, ReadError>
+where
+    F: Clone + Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+{
+    match accessor.dimensions().ok_or(ReadError::Unavailable)? {
+        Dimensions::Scalar => scan_dimensions::<_, 1>(accessor, get_buffer_data, expected),
+        Dimensions::Vec2 => scan_dimensions::<_, 2>(accessor, get_buffer_data, expected),
+        Dimensions::Vec3 => scan_dimensions::<_, 3>(accessor, get_buffer_data, expected),
+        Dimensions::Vec4 | Dimensions::Mat2 => scan_dimensions::<_, 4>(accessor, get_buffer_data, expected),
+        Dimensions::Mat3 => scan_dimensions::<_, 9>(accessor, get_buffer_data, expected),
+        Dimensions::Mat4 => scan_dimensions::<_, 16>(accessor, get_buffer_data, expected),
+    }
+}
+
+fn scan_dimensions<'a, 's, F, const N: usize>(
+    accessor: &Accessor<'a>,
+    get_buffer_data: F,
+    expected: &dyn Fn(usize) -> ops::RangeInclusive<f32>,
+) -> Result<Stats, ReadError>
+where
+    F: Clone + Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+{
+    match accessor.data_type().ok_or(ReadError::Unavailable)? {
+        DataType::I8 => scan_items::<i8, F, N>(accessor, get_buffer_data, expected),
+        DataType::U8 => scan_items::<u8, F, N>(accessor, get_buffer_data, expected),
+        DataType::I16 => scan_items::<i16, F, N>(accessor, get_buffer_data, expected),
+        DataType::U16 => scan_items::<u16, F, N>(accessor, get_buffer_data, expected),
+        DataType::U32 => scan_items::<u32, F, N>(accessor, get_buffer_data, expected),
+        DataType::F32 => scan_items::<f32, F, N>(accessor, get_buffer_data, expected),
+    }
+}
+
+fn scan_items<'a, 's, T, F, const N: usize>(
+    accessor: &Accessor<'a>,
+    get_buffer_data: F,
+    expected: &dyn Fn(usize) -> ops::RangeInclusive<f32>,
+) -> Result<Stats, ReadError>
+where
+    T: Component,
+    F: Clone + Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+{
+    // Padded matrix columns cannot be read as consecutive components.
+    if accessor.size() != Some(mem::size_of::<Flat<T, N>>()) {
+        return Err(ReadError::Unavailable);
+    }
+    let items = Iter::<Flat<T, N>>::new(accessor.clone(), get_buffer_data).ok_or(ReadError::Unavailable)?;
+    let normalized = accessor.normalized();
+    let expected = (0..N).map(expected).collect::<Vec<_>>();
+    let mut stats = Stats::default();
+    let (mut min, mut max, mut sum) = ([f32::INFINITY; N], [f32::NEG_INFINITY; N], [0.0f64; N]);
+    for Flat(item) in items {
+        let mut in_range = true;
+        for (i, component) in item.iter().enumerate() {
+            let value = component.to_f32(normalized);
+            min[i] = min[i].min(value);
+            max[i] = max[i].max(value);
+            sum[i] += f64::from(value);
+            in_range &= expected[i].contains(&value);
+        }
+        stats.count += 1;
+        stats.out_of_range += !in_range as usize;
+    }
+    if stats.count > 0 {
+        stats.min = min.to_vec();
+        stats.max = max.to_vec();
+        stats.mean = sum.iter().map(|&x| (x / stats.count as f64) as f32).collect();
+    }
+    Ok(stats)
+}
+
+/// The components of an item, read consecutively.
+#[repr(transparent)]
+struct Flat<T, const N: usize>([T; N]);
+
+impl<T: Item + Copy, const N: usize> Item for Flat<T, N> {
+    fn from_slice(slice: &[u8]) -> Self {
+        Flat(std::array::from_fn(|i| T::from_slice(&slice[i * mem::size_of::<T>()..])))
+    }
+
+    fn zero() -> Self {
+        Flat([T::zero(); N])
+    }
+}
+
+/// Component types that convert to floats for statistics.
+trait Component: Item + Copy {
+    fn to_f32(self, normalized: bool) -> f32;
+}
+
+macro_rules! impl_component {
+    ($($ty:ty),*) => {
+        $(
+            impl Component for $ty {
+                fn to_f32(self, normalized: bool) -> f32 {
+                    if normalized {
+                        self.normalize()
+                    } else {
+                        self as f32
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_component!(i8, u8, i16, u16);
+
+impl Component for u32 {
+    fn to_f32(self, _: bool) -> f32 {
+        self as f32
+    }
+}
+
+impl Component for f32 {
+    fn to_f32(self, _: bool) -> f32 {
+        self
+    }
+}
@@ -0,0 +1,178 @@
+use crate::{animation, buffer, Accessor, Document, Primitive, Semantic, Skin};
+
+/// Describes where and how an accessor is used, as returned by
+/// [`Document::accessor_usages`].
+///
+/// [`Document::accessor_usages`]: ../struct.Document.html#method.accessor_usages
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum Usage<'a> {
+    /// A vertex attribute of a primitive.
+    Attribute {
+        /// The primitive that references the accessor.
+        primitive: Primitive<'a>,
+
+        /// The attribute semantic.
+        semantic: Semantic,
+    },
+
+    /// The index buffer of a primitive.
+    Indices {
+        /// The primitive that references the accessor.
+        primitive: Primitive<'a>,
+    },
+
+    /// A vertex attribute displacement of a morph target.
+    ///
+    /// The semantic is one of `Positions`, `Normals`, or `Tangents`.
+    MorphTarget {
+        /// The primitive that references the accessor.
+        primitive: Primitive<'a>,
+
+        /// The index of the morph target within the primitive.
+        target: usize,
+
+        /// The attribute semantic.
+        semantic: Semantic,
+    },
+
+    /// A per-instance attribute of a node, as defined by the
+    /// `EXT_mesh_gpu_instancing` extension.
+    #[cfg(feature = "EXT_mesh_gpu_instancing")]
+    InstanceAttribute {
+        /// The node that references the accessor.
+        node: crate::Node<'a>,
+
+        /// The attribute name, such as `TRANSLATION`.
+        name: String,
+    },
+
+    /// The inverse bind matrices of a skin.
+    InverseBindMatrices {
+        /// The skin that references the accessor.
+        skin: Skin<'a>,
+    },
+
+    /// The keyframe times of an animation sampler.
+    AnimationInput {
+        /// The sampler that references the accessor.
+        sampler: animation::Sampler<'a>,
+    },
+
+    /// The keyframe values of an animation sampler.
+    AnimationOutput {
+        /// The sampler that references the accessor.
+        sampler: animation::Sampler<'a>,
+    },
+
+    /// The buffer view containing the sparse indices of the accessor.
+    SparseIndices {
+        /// The buffer view containing the indices.
+        view: buffer::View<'a>,
+    },
+
+    /// The buffer view containing the sparse values of the accessor.
+    SparseValues {
+        /// The buffer view containing the values.
+        view: buffer::View<'a>,
+    },
+}
+
+impl<'a> Usage<'a> {
+    /// Returns the buffer view target required by this usage.
+    ///
+    /// Vertex attributes and morph targets require `ArrayBuffer` and indices
+    /// require `ElementArrayBuffer`. Other data must not have a target, except
+    /// for instance attributes, which may have either.
+    pub fn target(&self) -> Option<buffer::Target> {
+        match *self {
+            Usage::Attribute { .. } | Usage::MorphTarget { .. } => Some(buffer::Target::ArrayBuffer),
+            Usage::Indices { .. } => Some(buffer::Target::ElementArrayBuffer),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the buffer view read by this usage declares a target
+    /// other than [`target`], for example an `ArrayBuffer` used as indices.
+    ///
+    /// Buffer views without a target never conflict.
+    ///
+    /// [`target`]: #method.target
+    pub fn is_conflicting(&self, accessor: &Accessor<'a>) -> bool {
+        let view = match *self {
+            Usage::SparseIndices { ref view } | Usage::SparseValues { ref view } => {
+                Some(view.clone())
+            },
+            #[cfg(feature = "EXT_mesh_gpu_instancing")]
+            Usage::InstanceAttribute { .. } => return false,
+            _ => accessor.view(),
+        };
+        match view.and_then(|view| view.target()) {
+            Some(target) => Some(target) != self.target(),
+            None => false,
+        }
+    }
+}
+
+/// Collects every accessor usage of a document.
+pub(crate) fn collect(document: &Document) -> Vec<(Accessor<'_>, Usage<'_>)> {
+    let mut usages = Vec::new();
+    for mesh in document.meshes() {
+        for primitive in mesh.primitives() {
+            for (semantic, accessor) in primitive.attributes() {
+                let primitive = primitive.clone();
+                usages.push((accessor, Usage::Attribute { primitive, semantic }));
+            }
+            if let Some(accessor) = primitive.indices() {
+                let primitive = primitive.clone();
+                usages.push((accessor, Usage::Indices { primitive }));
+            }
+            for (target, morph_target) in primitive.morph_targets().enumerate() {
+                let attributes = [
+                    (Semantic::Positions, morph_target.positions()),
+                    (Semantic::Normals, morph_target.normals()),
+                    (Semantic::Tangents, morph_target.tangents()),
+                ];
+                for (semantic, accessor) in attributes.iter().cloned() {
+                    if let Some(accessor) = accessor {
+                        let primitive = primitive.clone();
+                        usages.push((accessor, Usage::MorphTarget { primitive, target, semantic }));
+                    }
+                }
+            }
+        }
+    }
+    #[cfg(feature = "EXT_mesh_gpu_instancing")]
+    for node in document.nodes() {
+        for (name, accessor) in node.instance_attributes() {
+            let name = name.to_string();
+            usages.push((accessor, Usage::InstanceAttribute { node: node.clone(), name }));
+        }
+    }
+    for skin in document.skins() {
+        if let Some(accessor) = skin.inverse_bind_matrices() {
+            usages.push((accessor, Usage::InverseBindMatrices { skin }));
+        }
+    }
+    for animation in document.animations() {
+        for sampler in animation.samplers() {
+            if let Some(input) = sampler.input() {
+                usages.push((input, Usage::AnimationInput { sampler: sampler.clone() }));
+            }
+            if let Some(output) = sampler.output() {
+                usages.push((output, Usage::AnimationOutput { sampler }));
+            }
+        }
+    }
+    for accessor in document.accessors() {
+        if let Some(sparse) = accessor.sparse() {
+            if let Some(view) = sparse.indices().view() {
+                usages.push((accessor.clone(), Usage::SparseIndices { view }));
+            }
+            if let Some(view) = sparse.values().view() {
+                usages.push((accessor, Usage::SparseValues { view }));
+            }
+        }
+    }
+    usages
+}
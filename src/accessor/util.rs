@@ -1,17 +1,30 @@
-use std::{iter, mem};
+use std::{error, fmt, iter, mem};
 use byteorder::{LE, ByteOrder};
 use std::marker::PhantomData;
 
-use crate::{accessor, buffer};
+use crate::{accessor, buffer, Normalize};
+use crate::accessor::{DataType, Dimensions};
 
 fn buffer_view_slice<'a, 's>(
     view: buffer::View<'a>,
     get_buffer_data: &dyn Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
 ) -> Option<&'s [u8]> {
     let start = view.offset();
-    let end = start + view.length();
-    get_buffer_data(view.buffer())
-        .map(|slice| &slice[start..end])
+    let end = start.checked_add(view.length())?;
+    get_buffer_data(view.buffer()?)?.get(start..end)
+}
+
+/// Returns the bytes of `count` items of `size` bytes, `stride` bytes apart,
+/// starting at `start`, or `None` if they overlap or overrun `slice`.
+///
+/// The end is computed with [`json::accessor::required_length`], as in the
+/// validation of accessors.
+fn item_slice(slice: &[u8], start: usize, stride: usize, count: usize, size: usize) -> Option<&[u8]> {
+    if stride < size {
+        return None;
+    }
+    let end = json::accessor::required_length(start as u64, stride as u64, count as u64, size as u64);
+    slice.get(start..std::convert::TryFrom::try_from(end).ok()?)
 }
 
 /// General iterator for an accessor.
@@ -101,23 +114,29 @@ pub struct SparseIter<'a, T: Item> {
     /// Sparse values iterator.
     values: ItemIter<'a, T>,
 
+    /// The number of elements of the accessor.
+    count: usize,
+
     /// Iterator counter.
     counter: u32,
 }
 
 impl<'a, T: Item> SparseIter<'a, T> {
     /// Constructor.
-    /// 
-    /// Here `base` is allowed to be `None` when the base buffer view is not explicitly specified.
+    ///
+    /// Here `base` is allowed to be `None` when the base buffer view is not explicitly specified,
+    /// in which case `count` zero values are substituted as the base values.
     pub fn new(
         base: Option<ItemIter<'a, T>>,
         indices: SparseIndicesIter<'a>,
         values: ItemIter<'a, T>,
+        count: usize,
     ) -> Self {
         SparseIter {
             base,
             indices: indices.peekable(),
             values: values,
+            count,
             counter: 0,
         }
     }
@@ -126,17 +145,15 @@ impl<'a, T: Item> SparseIter<'a, T> {
 impl<'a, T: Item> Iterator for SparseIter<'a, T> {
     type Item = T;
     fn next(&mut self) -> Option<Self::Item> {
-        let next_base_value = self.base.as_mut().map(|iter| iter.next()).unwrap_or(Some(T::zero()));
-        if next_base_value.is_none() {
+        if self.counter as usize >= self.count {
             return None;
         }
-
-        let mut next_value = next_base_value.unwrap();
+        let mut next_value = self.base.as_mut().map(|iter| iter.next()).unwrap_or(Some(T::zero()))?;
         let next_sparse_index = self.indices.peek().clone();
         if let Some(index) = next_sparse_index {
             if *index == self.counter {
                 self.indices.next(); // advance
-                next_value = self.values.next().unwrap();
+                next_value = self.values.next()?;
             }
         }
 
@@ -146,7 +163,7 @@ impl<'a, T: Item> Iterator for SparseIter<'a, T> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let hint = self.values.len() - self.counter as usize;
+        let hint = self.count.saturating_sub(self.counter as usize);
         (hint, Some(hint))
     }
 }
@@ -163,6 +180,24 @@ pub trait Item {
     fn zero() -> Self;
 }
 
+/// Represents the scalar component types of accessor items.
+pub trait Component: Item + Copy {
+    /// The data type of the component.
+    fn data_type() -> DataType;
+}
+
+/// Represents items whose data type and dimensions are known, which allows
+/// them to be checked against an [`Accessor`] at run time.
+///
+/// [`Accessor`]: struct.Accessor.html
+pub trait TypedItem: Item {
+    /// The data type of the item components.
+    fn data_type() -> DataType;
+
+    /// The dimensions of the item.
+    fn dimensions() -> Dimensions;
+}
+
 /// Visits the items in an [`Accessor`].
 ///
 /// [`Accessor`]: struct.Accessor.html
@@ -263,6 +298,59 @@ impl<T: Item + Copy> Item for [T; 4] {
     }
 }
 
+macro_rules! impl_component {
+    ($ty:ty, $data_type:ident) => {
+        impl Component for $ty {
+            fn data_type() -> DataType {
+                DataType::$data_type
+            }
+        }
+
+        impl TypedItem for $ty {
+            fn data_type() -> DataType {
+                DataType::$data_type
+            }
+            fn dimensions() -> Dimensions {
+                Dimensions::Scalar
+            }
+        }
+    };
+}
+
+impl_component!(i8, I8);
+impl_component!(i16, I16);
+impl_component!(u8, U8);
+impl_component!(u16, U16);
+impl_component!(u32, U32);
+impl_component!(f32, F32);
+
+impl<T: Component> TypedItem for [T; 2] {
+    fn data_type() -> DataType {
+        T::data_type()
+    }
+    fn dimensions() -> Dimensions {
+        Dimensions::Vec2
+    }
+}
+
+impl<T: Component> TypedItem for [T; 3] {
+    fn data_type() -> DataType {
+        T::data_type()
+    }
+    fn dimensions() -> Dimensions {
+        Dimensions::Vec3
+    }
+}
+
+impl<T: Component> TypedItem for [T; 4] {
+    fn data_type() -> DataType {
+        T::data_type()
+    }
+    fn dimensions() -> Dimensions {
+        Dimensions::Vec4
+    }
+}
+
 impl<'a, T: Item> ItemIter<'a, T> {
     /// Constructor.
     pub fn new(slice: &'a [u8], stride: usize) -> Self {
@@ -282,71 +370,50 @@ impl<'a, 's, T: Item> Iter<'s, T> {
     ) -> Option<Iter<'s, T>>
         where F: Clone + Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
     {
-        let is_sparse = accessor.sparse().is_some();
-        if is_sparse {
-            let sparse = accessor.sparse();
-            let indices = sparse.as_ref().unwrap().indices();
-            let values = sparse.as_ref().unwrap().values();
+        if let Some(sparse) = accessor.sparse() {
+            let indices = sparse.indices();
+            let values = sparse.values();
             let base_iter = {
                 // Using `if let` here instead of map to preserve the early return behavior.
                 if let Some(view) = accessor.view() {
                     let stride = view.stride().unwrap_or(mem::size_of::<T>());
-                    let start = accessor.offset();
-                    let end = start + stride * (accessor.count() - 1) + mem::size_of::<T>();
-                    let subslice = if let Some(slice) = buffer_view_slice(view, &get_buffer_data) {
-                        &slice[start..end]
-                    } else {
-                        return None
-                    };
+                    let slice = buffer_view_slice(view, &get_buffer_data)?;
+                    let subslice = item_slice(slice, accessor.offset(), stride, accessor.count(), mem::size_of::<T>())?;
                     Some(ItemIter::new(subslice, stride))
                 } else {
                     None
                 }
             };
-            let sparse_count = sparse.as_ref().unwrap().count() as usize;
+            let sparse_count = sparse.count() as usize;
             let index_iter = {
-                let view = indices.view();
-                let index_size = indices.index_type().size();
+                let view = indices.view()?;
+                let index_type = indices.index_type()?;
+                let index_size = index_type.size();
                 let stride = view.stride().unwrap_or(index_size);
-                let subslice = if let Some(slice) = buffer_view_slice(view, &get_buffer_data) {
-                    let start = indices.offset() as usize;
-                    let end = start + stride * (sparse_count - 1) + index_size;
-                    &slice[start..end]
-                } else {
-                    return None
-                };
-                match indices.index_type() {
+                let slice = buffer_view_slice(view, &get_buffer_data)?;
+                let subslice = item_slice(slice, indices.offset() as usize, stride, sparse_count, index_size)?;
+                match index_type {
                     accessor::sparse::IndexType::U8 => SparseIndicesIter::U8(ItemIter::new(subslice, stride)),
                     accessor::sparse::IndexType::U16 => SparseIndicesIter::U16(ItemIter::new(subslice, stride)),
                     accessor::sparse::IndexType::U32 => SparseIndicesIter::U32(ItemIter::new(subslice, stride)),
                 }
             };
             let value_iter = {
-                let view = values.view();
+                let view = values.view()?;
                 let stride = view.stride().unwrap_or(mem::size_of::<T>());
-                let subslice = if let Some(slice) = buffer_view_slice(view, &get_buffer_data) {
-                    let start = values.offset() as usize;
-                    let end = start + stride * (sparse_count - 1) + mem::size_of::<T>();
-                    &slice[start..end]
-                } else {
-                    return None
-                };
+                let slice = buffer_view_slice(view, &get_buffer_data)?;
+                let subslice = item_slice(slice, values.offset() as usize, stride, sparse_count, mem::size_of::<T>())?;
                 ItemIter::new(subslice, stride)
             };
-            Some(Iter::Sparse(SparseIter::new(base_iter, index_iter, value_iter)))
+            Some(Iter::Sparse(SparseIter::new(base_iter, index_iter, value_iter, accessor.count())))
         } else {
-            debug_assert_eq!(mem::size_of::<T>(), accessor.size());
-            debug_assert!(mem::size_of::<T>() > 0);
+            if Some(mem::size_of::<T>()) != accessor.size() {
+                return None;
+            }
             if let Some(view) = accessor.view() {
                 let stride = view.stride().unwrap_or(mem::size_of::<T>());
-                debug_assert!(stride >= mem::size_of::<T>(), "Mismatch in stride, expected at least {} stride but found {}", mem::size_of::<T>(), stride);
-                let start = accessor.offset();
-                let end = start + stride * (accessor.count() - 1) + mem::size_of::<T>();
-                let subslice = if let Some(slice) = buffer_view_slice(view, &get_buffer_data) {
-                    &slice[start..end]
-                } else {
-                    return None
-                };
+                let slice = buffer_view_slice(view, &get_buffer_data)?;
+                let subslice = item_slice(slice, accessor.offset(), stride, accessor.count(), mem::size_of::<T>())?;
                 Some(Iter::Standard(ItemIter { stride, data: subslice, _phantom: PhantomData }))
             } else {
                 None
@@ -411,3 +478,190 @@ impl<'a, T: Item> Iterator for ItemIter<'a, T> {
         (hint, Some(hint))
     }
 }
+
+/// The error returned when the item type requested from an accessor does not
+/// match its data type and dimensions.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TypeMismatch {
+    /// The data type and dimensions of the accessor.
+    pub accessor: (DataType, Dimensions),
+
+    /// The data type and dimensions of the requested item type.
+    pub requested: (DataType, Dimensions),
+}
+
+impl error::Error for TypeMismatch {}
+
+impl fmt::Display for TypeMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "accessor has {:?} {:?} items but {:?} {:?} items were requested",
+            self.accessor.0, self.accessor.1, self.requested.0, self.requested.1,
+        )
+    }
+}
+
+/// The error returned by [`Accessor::read`] and [`ReadItems::normalize`].
+///
+/// [`Accessor::read`]: struct.Accessor.html#method.read
+/// [`ReadItems::normalize`]: struct.ReadItems.html#method.normalize
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ReadError {
+    /// The requested item type does not match the accessor.
+    TypeMismatch(TypeMismatch),
+
+    /// Normalized values were requested from an accessor that is not
+    /// normalized, with the data type and dimensions of the accessor.
+    NotNormalized((DataType, Dimensions)),
+
+    /// The buffer data of the accessor is not available or too short.
+    Unavailable,
+}
+
+impl error::Error for ReadError {}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReadError::TypeMismatch(mismatch) => mismatch.fmt(f),
+            ReadError::NotNormalized((data_type, dimensions)) => write!(
+                f,
+                "accessor has {:?} {:?} items that are not normalized but normalized f32 items were requested",
+                data_type, dimensions,
+            ),
+            ReadError::Unavailable => write!(f, "accessor data is not available"),
+        }
+    }
+}
+
+/// Item types that items of type `S` widen to without loss, for
+/// [`ReadItems::cast`].
+///
+/// Implemented for `u8` to `u16` and `u32`, for `u16` to `u32`, for each of
+/// these to itself, and for arrays of these component-wise. Narrowing and
+/// changes of signedness do not compile:
+///
+/// ```compile_fail
+/// # use gltf::accessor::util::ReadItems;
+/// fn narrow(joints: ReadItems<[u16; 4]>) -> Vec<[u8; 4]> {
+///     joints.cast::<[u8; 4]>().collect()
+/// }
+/// ```
+///
+/// ```compile_fail
+/// # use gltf::accessor::util::ReadItems;
+/// fn reinterpret(joints: ReadItems<[u8; 4]>) -> Vec<[i8; 4]> {
+///     joints.cast::<[i8; 4]>().collect()
+/// }
+/// ```
+///
+/// [`ReadItems::cast`]: struct.ReadItems.html#method.cast
+pub trait Widen<S>: Sized {
+    /// Converts an item.
+    fn widen(item: S) -> Self;
+}
+
+macro_rules! impl_widen {
+    ($($from:ty => $to:ty),*) => {
+        $(
+            impl Widen<$from> for $to {
+                fn widen(item: $from) -> Self {
+                    <$to>::from(item)
+                }
+            }
+        )*
+    };
+}
+
+impl_widen!(u8 => u8, u8 => u16, u8 => u32, u16 => u16, u16 => u32, u32 => u32);
+
+impl<S, T: Widen<S>, const N: usize> Widen<[S; N]> for [T; N] {
+    fn widen(item: [S; N]) -> Self {
+        item.map(T::widen)
+    }
+}
+
+/// Item types that normalized integer items of type `S` convert to, for
+/// [`ReadItems::normalize`].
+///
+/// Implemented for `f32` from `i8`, `u8`, `i16`, and `u16`, and for arrays
+/// of these component-wise, following the glTF specification.
+///
+/// [`ReadItems::normalize`]: struct.ReadItems.html#method.normalize
+pub trait FromNormalized<S>: Sized {
+    /// Converts an item.
+    fn from_normalized(item: S) -> Self;
+}
+
+macro_rules! impl_from_normalized {
+    ($($from:ty),*) => {
+        $(
+            impl FromNormalized<$from> for f32 {
+                fn from_normalized(item: $from) -> Self {
+                    item.normalize()
+                }
+            }
+        )*
+    };
+}
+
+impl_from_normalized!(i8, u8, i16, u16);
+
+impl<S, T: FromNormalized<S>, const N: usize> FromNormalized<[S; N]> for [T; N] {
+    fn from_normalized(item: [S; N]) -> Self {
+        item.map(T::from_normalized)
+    }
+}
+
+/// The items of an accessor as returned by [`Accessor::read`], whose type
+/// matches the data type and dimensions of the accessor.
+///
+/// [`Accessor::read`]: struct.Accessor.html#method.read
+#[derive(Clone, Debug)]
+pub struct ReadItems<'a, T: Item> {
+    pub(crate) iter: Iter<'a, T>,
+    pub(crate) normalized: bool,
+}
+
+/// The items of an accessor converted by [`ReadItems::cast`] or
+/// [`ReadItems::normalize`].
+///
+/// [`ReadItems::cast`]: struct.ReadItems.html#method.cast
+/// [`ReadItems::normalize`]: struct.ReadItems.html#method.normalize
+pub type Converted<'a, T, U> = iter::Map<ReadItems<'a, T>, fn(T) -> U>;
+
+impl<'a, T: Item> ReadItems<'a, T> {
+    /// Widens the items to `U`, for example `u8` indices to `u32`.
+    pub fn cast<U: Widen<T>>(self) -> Converted<'a, T, U> {
+        self.map(U::widen)
+    }
+
+    /// Converts the items of a normalized accessor to floats, for example
+    /// `[u8; 4]` colors to `[f32; 4]`.
+    ///
+    /// Returns an error if the accessor is not normalized.
+    pub fn normalize<U: FromNormalized<T>>(self) -> Result<Converted<'a, T, U>, ReadError>
+    where
+        T: TypedItem,
+    {
+        if !self.normalized {
+            return Err(ReadError::NotNormalized((T::data_type(), T::dimensions())));
+        }
+        Ok(self.map(U::from_normalized))
+    }
+}
+
+impl<'a, T: Item> Iterator for ReadItems<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T: Item> ExactSizeIterator for ReadItems<'a, T> {}
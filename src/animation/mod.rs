@@ -1,13 +1,19 @@
 use crate::{accessor, scene, Document};
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
 
 #[cfg(feature = "utils")]
-use crate::Buffer;
+use crate::{buffer, Buffer};
 
 pub use json::animation::{Interpolation, Property};
 
 /// Iterators.
 pub mod iter;
 
+/// Resolution of `KHR_animation_pointer` JSON pointers.
+#[cfg(feature = "KHR_animation_pointer")]
+pub mod pointer;
+
 /// Utility functions.
 #[cfg(feature = "utils")]
 pub mod util;
@@ -16,6 +22,9 @@ pub mod util;
 #[doc(inline)]
 pub use self::util::Reader;
 
+#[cfg(feature = "utils")]
+pub use self::util::{blend, PreparedAnimation, SampledPose};
+
 /// A keyframe animation.
 #[derive(Clone, Debug)]
 pub struct Animation<'a> {
@@ -29,6 +38,17 @@ pub struct Animation<'a> {
     json: &'a json::animation::Animation,
 }
 
+impl_identity!(Animation);
+
+/// Maps node indices to the indices of the channels that target them, for
+/// every animation in a document.
+///
+/// The map is built on first use. It cannot become stale, since the JSON of a
+/// `Document` is not modified after loading; editing the JSON requires
+/// `Document::into_json` and `Document::from_json`, which builds a new map.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ChannelMap(OnceLock<Vec<BTreeMap<usize, Vec<usize>>>>);
+
 /// Targets an animation's sampler at a node's property.
 #[derive(Clone, Debug)]
 pub struct Channel<'a> {
@@ -49,6 +69,30 @@ pub struct Sampler<'a> {
     json: &'a json::animation::Sampler,
 }
 
+/// The property that an animation channel targets, as returned by
+/// [`Target::kind`].
+///
+/// [`Target::kind`]: struct.Target.html#method.kind
+#[cfg(feature = "KHR_animation_pointer")]
+#[derive(Clone, Debug)]
+pub enum TargetKind<'a> {
+    /// A TRS property or the morph target weights of a node.
+    Node {
+        /// The target node.
+        node: scene::Node<'a>,
+
+        /// The node property.
+        property: Property,
+    },
+
+    /// The JSON pointer of the `KHR_animation_pointer` extension.
+    ///
+    /// See [`pointer::Pointer::parse`] for resolving common pointers.
+    ///
+    /// [`pointer::Pointer::parse`]: pointer/enum.Pointer.html#method.parse
+    Pointer(&'a str),
+}
+
 /// The node and TRS property that an animation channel targets.
 #[derive(Clone, Debug)]
 pub struct Target<'a> {
@@ -77,6 +121,13 @@ impl<'a> Animation<'a> {
         self.index
     }
 
+    /// Returns the XMP metadata packet of this animation, as defined by the
+    /// `KHR_xmp_json_ld` extension.
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    pub fn xmp_packet(&self) -> Option<&'a json::extensions::root::khr_xmp_json_ld::Packet> {
+        self.document.xmp_packet(self.json.extensions.as_ref()?.khr_xmp_json_ld.as_ref()?)
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
@@ -92,10 +143,45 @@ impl<'a> Animation<'a> {
         }
     }
 
+    /// Returns an `Iterator` over the channels that target the given node.
+    ///
+    /// The channels of every animation are grouped by target node once, on
+    /// first use, so repeated lookups do not scan the channels.
+    ///
+    /// With the `KHR_animation_pointer` feature, this includes channels whose
+    /// pointer resolves to a property of the node, see
+    /// [`pointer::Pointer::parse`]. Their [`Target::node`] returns `None`, so
+    /// use [`Target::kind`] to find the targeted property instead.
+    ///
+    /// [`pointer::Pointer::parse`]: pointer/enum.Pointer.html#method.parse
+    /// [`Target::node`]: struct.Target.html#method.node
+    /// [`Target::kind`]: struct.Target.html#method.kind
+    pub fn channels_for_node(&self, node: &scene::Node) -> impl Iterator<Item = Channel<'a>> + 'a {
+        let anim = self.clone();
+        self.channel_map()
+            .get(&node.index())
+            .into_iter()
+            .flatten()
+            .map(move |&index| Channel::new(anim.clone(), &anim.json.channels[index]))
+    }
+
+    /// Returns an `Iterator` over the nodes targeted by the animation, in
+    /// ascending index order.
+    pub fn target_nodes(&self) -> impl Iterator<Item = scene::Node<'a>> + 'a {
+        let document = self.document;
+        self.channel_map()
+            .keys()
+            .filter_map(move |&index| document.get_usize(index))
+    }
+
+    fn channel_map(&self) -> &'a BTreeMap<usize, Vec<usize>> {
+        &self.document.1.get(&self.document.0)[self.index]
+    }
+
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     pub fn name(&self) -> Option<&'a str> {
-        self.json.name.as_ref().map(String::as_str)
+        self.json.name.as_deref()
     }
 
     /// Returns an `Iterator` over the animation samplers.
@@ -110,6 +196,40 @@ impl<'a> Animation<'a> {
     }
 }
 
+impl ChannelMap {
+    /// Returns the map of each animation, building them if necessary.
+    fn get(&self, root: &json::Root) -> &Vec<BTreeMap<usize, Vec<usize>>> {
+        self.0.get_or_init(|| {
+            root.animations
+                .iter()
+                .map(|animation| {
+                    let mut map = BTreeMap::new();
+                    for (index, channel) in animation.channels.iter().enumerate() {
+                        if let Some(node) = target_node(&channel.target) {
+                            map.entry(node).or_insert_with(Vec::new).push(index);
+                        }
+                    }
+                    map
+                })
+                .collect()
+        })
+    }
+}
+
+/// Returns the index of the node whose property is animated by a target.
+fn target_node(target: &json::animation::Target) -> Option<usize> {
+    if let Some(node) = target.node {
+        return Some(node.value());
+    }
+    #[cfg(feature = "KHR_animation_pointer")]
+    {
+        if let Some(pointer::Pointer::Node { node, .. }) = target.pointer().map(pointer::Pointer::parse) {
+            return Some(node);
+        }
+    }
+    None
+}
+
 impl<'a> Channel<'a> {
     /// Constructs a `Channel`.
     pub(crate) fn new(
@@ -129,8 +249,12 @@ impl<'a> Channel<'a> {
 
     /// Returns the sampler in this animation used to compute the value for the
     /// target.
-    pub fn sampler(&self) -> Sampler<'a> {
-        self.anim.samplers().nth(self.json.sampler.value()).unwrap()
+    ///
+    /// Returns `None` if the sampler does not exist, which is only possible
+    /// for documents loaded without validation.
+    pub fn sampler(&self) -> Option<Sampler<'a>> {
+        let json = self.anim.json.samplers.get(self.json.sampler.value())?;
+        Some(Sampler::new(self.anim.clone(), json))
     }
 
     /// Returns the node and property to target.
@@ -150,6 +274,26 @@ impl<'a> Channel<'a> {
         }
     }
 
+    /// Constructs an animation channel reader, fetching the data of every
+    /// buffer read by the sampler up front, as [`Primitive::try_reader`] does.
+    ///
+    /// [`Primitive::try_reader`]: ../mesh/struct.Primitive.html#method.try_reader
+    #[cfg(feature = "utils")]
+    pub fn try_reader<'s, F>(
+        &self,
+        get_buffer_data: F,
+    ) -> crate::Result<Reader<'a, 's, impl Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>>>
+    where
+        F: FnMut(Buffer<'a>) -> crate::Result<&'s [u8]>,
+    {
+        let accessors = self.sampler().into_iter().flat_map(|x| x.input().into_iter().chain(x.output()));
+        let get_buffer_data = buffer::prefetch(accessors, get_buffer_data)?;
+        Ok(Reader {
+            channel: self.clone(),
+            get_buffer_data,
+        })
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
@@ -178,15 +322,56 @@ impl<'a> Target<'a> {
         &self.json.extras
     }
 
-    /// Returns the target node.
-    pub fn node(&self) -> scene::Node<'a> {
-        self.anim.document.nodes().nth(self.json.node.value()).unwrap()
+    /// Returns the target node, or `None` if the channel targets the JSON
+    /// pointer of the `KHR_animation_pointer` extension instead.
+    pub fn node(&self) -> Option<scene::Node<'a>> {
+        self.json.node.and_then(|index| self.anim.document.get(index))
     }
 
     /// Returns the node's property to modify or the 'weights' of the morph
     /// targets it instantiates.
-    pub fn property(&self) -> Property {
-        self.json.path.unwrap()
+    ///
+    /// Returns `None` if the path is invalid, which is only possible for
+    /// documents loaded without validation.
+    pub fn property(&self) -> Option<Property> {
+        self.json.path.valid()
+    }
+
+    /// Returns either the target node and property or the JSON pointer of the
+    /// `KHR_animation_pointer` extension.
+    ///
+    /// Returns `None` if the node or pointer is missing or the path is
+    /// invalid, which is only possible for documents loaded without
+    /// validation.
+    ///
+    /// ```
+    /// use gltf::animation::pointer::{MaterialProperty, Pointer};
+    /// use gltf::animation::TargetKind;
+    ///
+    /// fn describe(document: &gltf::Document) {
+    ///     for channel in document.animations().flat_map(|x| x.channels()) {
+    ///         match channel.target().kind() {
+    ///             Some(TargetKind::Node { node, property }) => {
+    ///                 println!("{:?} of node {}", property, node.index());
+    ///             },
+    ///             Some(TargetKind::Pointer(pointer)) => match Pointer::parse(pointer) {
+    ///                 Pointer::Material { material, property: MaterialProperty::BaseColorFactor } => {
+    ///                     println!("base color of material {}", material);
+    ///                 },
+    ///                 _ => println!("{}", pointer),
+    ///             },
+    ///             None => {},
+    ///         }
+    ///     }
+    /// }
+    /// # let _ = describe;
+    /// ```
+    #[cfg(feature = "KHR_animation_pointer")]
+    pub fn kind(&self) -> Option<TargetKind<'a>> {
+        match self.json.path.valid()? {
+            Property::Pointer => self.json.pointer().map(TargetKind::Pointer),
+            property => self.node().map(|node| TargetKind::Node { node, property }),
+        }
     }
 }
 
@@ -213,17 +398,26 @@ impl<'a> Sampler<'a> {
     }
 
     /// Returns the accessor containing the keyframe input values (e.g. time).
-    pub fn input(&self) -> accessor::Accessor<'a> {
-        self.anim.document.accessors().nth(self.json.input.value()).unwrap()
+    ///
+    /// Returns `None` if the accessor does not exist, which is only possible
+    /// for documents loaded without validation.
+    pub fn input(&self) -> Option<accessor::Accessor<'a>> {
+        self.anim.document.get(self.json.input)
     }
 
     /// Returns the keyframe interpolation algorithm.
+    ///
+    /// An invalid interpolation, which is only possible for documents loaded
+    /// without validation, is read as the default `Linear`.
     pub fn interpolation(&self) -> Interpolation {
-        self.json.interpolation.unwrap()
+        self.json.interpolation.valid().unwrap_or_default()
     }
 
     /// Returns the accessor containing the keyframe output values.
-    pub fn output(&self) -> accessor::Accessor<'a> {
-        self.anim.document.accessors().nth(self.json.output.value()).unwrap()
+    ///
+    /// Returns `None` if the accessor does not exist, which is only possible
+    /// for documents loaded without validation.
+    pub fn output(&self) -> Option<accessor::Accessor<'a>> {
+        self.anim.document.get(self.json.output)
     }
 }
@@ -0,0 +1,122 @@
+use crate::animation::Property;
+
+/// An animatable material property.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum MaterialProperty {
+    /// `pbrMetallicRoughness/baseColorFactor`.
+    BaseColorFactor,
+
+    /// `pbrMetallicRoughness/metallicFactor`.
+    MetallicFactor,
+
+    /// `pbrMetallicRoughness/roughnessFactor`.
+    RoughnessFactor,
+
+    /// `emissiveFactor`.
+    EmissiveFactor,
+
+    /// `alphaCutoff`.
+    AlphaCutoff,
+
+    /// `normalTexture/scale`.
+    NormalTextureScale,
+
+    /// `occlusionTexture/strength`.
+    OcclusionTextureStrength,
+}
+
+/// A `KHR_animation_pointer` JSON pointer resolved to the property it targets.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Pointer<'a> {
+    /// A TRS property or the morph target weights of a node.
+    Node {
+        /// The index of the node.
+        node: usize,
+
+        /// The node property.
+        property: Property,
+    },
+
+    /// A factor of a material.
+    Material {
+        /// The index of the material.
+        material: usize,
+
+        /// The material property.
+        property: MaterialProperty,
+    },
+
+    /// A pointer the library does not recognise.
+    Unsupported(&'a str),
+}
+
+impl<'a> Pointer<'a> {
+    /// Resolves a JSON pointer such as
+    /// `/materials/0/pbrMetallicRoughness/baseColorFactor`.
+    ///
+    /// Pointers to properties other than those of `Property` and
+    /// `MaterialProperty` are returned as `Pointer::Unsupported`.
+    pub fn parse(pointer: &'a str) -> Self {
+        let mut segments = pointer.splitn(3, '/').skip(1);
+        let collection = segments.next();
+        let index = segments.next().and_then(|x| {
+            let mut split = x.splitn(2, '/');
+            split.next()?.parse::<usize>().ok().map(|index| (index, split.next()))
+        });
+        let resolved = match (collection, index) {
+            (Some("nodes"), Some((node, Some(property)))) => {
+                let property = match property {
+                    "translation" => Some(Property::Translation),
+                    "rotation" => Some(Property::Rotation),
+                    "scale" => Some(Property::Scale),
+                    "weights" => Some(Property::MorphTargetWeights),
+                    _ => None,
+                };
+                property.map(|property| Pointer::Node { node, property })
+            },
+            (Some("materials"), Some((material, Some(property)))) => {
+                use self::MaterialProperty::*;
+                let property = match property {
+                    "pbrMetallicRoughness/baseColorFactor" => Some(BaseColorFactor),
+                    "pbrMetallicRoughness/metallicFactor" => Some(MetallicFactor),
+                    "pbrMetallicRoughness/roughnessFactor" => Some(RoughnessFactor),
+                    "emissiveFactor" => Some(EmissiveFactor),
+                    "alphaCutoff" => Some(AlphaCutoff),
+                    "normalTexture/scale" => Some(NormalTextureScale),
+                    "occlusionTexture/strength" => Some(OcclusionTextureStrength),
+                    _ => None,
+                };
+                property.map(|property| Pointer::Material { material, property })
+            },
+            _ => None,
+        };
+        resolved.unwrap_or(Pointer::Unsupported(pointer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MaterialProperty, Pointer};
+    use crate::animation::Property;
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            Pointer::parse("/materials/2/pbrMetallicRoughness/baseColorFactor"),
+            Pointer::Material { material: 2, property: MaterialProperty::BaseColorFactor },
+        );
+        assert_eq!(
+            Pointer::parse("/nodes/10/weights"),
+            Pointer::Node { node: 10, property: Property::MorphTargetWeights },
+        );
+        for pointer in &[
+            "/materials/0/extensions/KHR_materials_emissive_strength/emissiveStrength",
+            "/materials/x/alphaCutoff",
+            "/nodes/1",
+            "/cameras/0/perspective/yfov",
+            "",
+        ] {
+            assert_eq!(Pointer::parse(pointer), Pointer::Unsupported(pointer));
+        }
+    }
+}
@@ -4,6 +4,12 @@ pub mod rotations;
 /// Casting iterator adapters for morph target weights.
 pub mod morph_target_weights;
 
+mod pose;
+mod resample;
+
+pub use self::pose::{blend, NodeTransform, PreparedAnimation, SampledPose};
+pub use self::resample::{resample, resample_animations, ChannelData, Report, Values};
+
 use crate::accessor;
 
 use crate::animation::Channel;
@@ -58,6 +64,23 @@ pub enum MorphTargetWeights<'a> {
     F32(accessor::Iter<'a, f32>),
 }
 
+/// Animation output sampler values of `KHR_animation_pointer` targets.
+///
+/// The values are not interpreted, since the type of the target property
+/// depends on the pointer.
+#[cfg(feature = "KHR_animation_pointer")]
+#[derive(Clone, Debug)]
+pub enum PointerOutputs<'a> {
+    /// Values of type `f32`.
+    Scalar(accessor::Iter<'a, f32>),
+    /// Values of type `[f32; 2]`.
+    Vec2(accessor::Iter<'a, [f32; 2]>),
+    /// Values of type `[f32; 3]`.
+    Vec3(accessor::Iter<'a, [f32; 3]>),
+    /// Values of type `[f32; 4]`.
+    Vec4(accessor::Iter<'a, [f32; 4]>),
+}
+
 /// Animation output sampler values.
 pub enum ReadOutputs<'a> {
     /// XYZ translations of type `[f32; 3]`.
@@ -71,6 +94,10 @@ pub enum ReadOutputs<'a> {
 
     /// Morph target animations.
     MorphTargetWeights(MorphTargetWeights<'a>),
+
+    /// Values of the property referenced by a `KHR_animation_pointer`.
+    #[cfg(feature = "KHR_animation_pointer")]
+    Pointer(PointerOutputs<'a>),
 }
 
 impl<'a> Rotations<'a> {
@@ -98,7 +125,7 @@ impl<'a> Rotations<'a> {
     }
 
     /// Reinterpret rotations as f32.  Lossy if underlying iterator yields i16
-    /// or u16.
+    /// or u16.  The rotations are renormalized to unit length.
     pub fn into_f32(self) -> rotations::CastingIter<'a, rotations::F32> {
         rotations::CastingIter::new(self)
     }
@@ -141,37 +168,52 @@ where F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
 {
     /// Visits the input samples of a channel.
     pub fn read_inputs(&self) -> Option<ReadInputs<'s>> {
-        accessor::Iter::new(self.channel.sampler().input(), self.get_buffer_data.clone())
+        accessor::Iter::new(self.channel.sampler()?.input()?, self.get_buffer_data.clone())
     }
 
     /// Visits the output samples of a channel.
+    ///
+    /// Returns `None` for `KHR_animation_pointer` targets unless the output
+    /// accessor contains `f32` scalars or vectors.
     pub fn read_outputs(&self) -> Option<ReadOutputs<'s>> {
         use accessor::{DataType, Iter};
         use crate::animation::Property;
-        let output = self.channel.sampler().output();
-        match self.channel.target().property() {
+        let output = self.channel.sampler()?.output()?;
+        match self.channel.target().property()? {
             Property::Translation => Iter::new(output, self.get_buffer_data.clone()).map(ReadOutputs::Translations),
             Property::Rotation => {
-                match output.data_type() {
+                match output.data_type()? {
                     DataType::I8 => Iter::new(output, self.get_buffer_data.clone()).map(|x| ReadOutputs::Rotations(Rotations::I8(x))),
                     DataType::U8 => Iter::new(output, self.get_buffer_data.clone()).map(|x| ReadOutputs::Rotations(Rotations::U8(x))),
                     DataType::I16 => Iter::new(output, self.get_buffer_data.clone()).map(|x| ReadOutputs::Rotations(Rotations::I16(x))),
                     DataType::U16 => Iter::new(output, self.get_buffer_data.clone()).map(|x| ReadOutputs::Rotations(Rotations::U16(x))),
                     DataType::F32 => Iter::new(output, self.get_buffer_data.clone()).map(|x| ReadOutputs::Rotations(Rotations::F32(x))),
-                    _ => unreachable!()
+                    _ => None,
                 }
             },
             Property::Scale => Iter::new(output, self.get_buffer_data.clone()).map(ReadOutputs::Scales),
             Property::MorphTargetWeights => {
-                match output.data_type() {
+                match output.data_type()? {
                     DataType::I8 => Iter::new(output, self.get_buffer_data.clone()).map(|x| ReadOutputs::MorphTargetWeights(MorphTargetWeights::I8(x))),
                     DataType::U8 => Iter::new(output, self.get_buffer_data.clone()).map(|x| ReadOutputs::MorphTargetWeights(MorphTargetWeights::U8(x))),
                     DataType::I16 => Iter::new(output, self.get_buffer_data.clone()).map(|x| ReadOutputs::MorphTargetWeights(MorphTargetWeights::I16(x))),
                     DataType::U16 => Iter::new(output, self.get_buffer_data.clone()).map(|x| ReadOutputs::MorphTargetWeights(MorphTargetWeights::U16(x))),
                     DataType::F32 => Iter::new(output, self.get_buffer_data.clone()).map(|x| ReadOutputs::MorphTargetWeights(MorphTargetWeights::F32(x))),
-                    _ => unreachable!()
+                    _ => None,
                 }
             },
+            #[cfg(feature = "KHR_animation_pointer")]
+            Property::Pointer => {
+                use accessor::Dimensions;
+                let outputs = match (output.data_type()?, output.dimensions()?) {
+                    (DataType::F32, Dimensions::Scalar) => Iter::new(output, self.get_buffer_data.clone()).map(PointerOutputs::Scalar),
+                    (DataType::F32, Dimensions::Vec2) => Iter::new(output, self.get_buffer_data.clone()).map(PointerOutputs::Vec2),
+                    (DataType::F32, Dimensions::Vec3) => Iter::new(output, self.get_buffer_data.clone()).map(PointerOutputs::Vec3),
+                    (DataType::F32, Dimensions::Vec4) => Iter::new(output, self.get_buffer_data.clone()).map(PointerOutputs::Vec4),
+                    _ => None,
+                };
+                outputs.map(ReadOutputs::Pointer)
+            },
         }
     }
 }
@@ -0,0 +1,233 @@
+use std::collections::BTreeMap;
+
+use crate::animation::{Animation, Property};
+use crate::scene::{Transform, TransformCache};
+use crate::Buffer;
+
+use super::resample::slerp;
+use super::ChannelData;
+
+/// The translation, rotation, and scale of a node.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NodeTransform {
+    /// `[x, y, z]` vector.
+    pub translation: [f32; 3],
+
+    /// `[x, y, z, w]` quaternion, where `w` is the scalar.
+    pub rotation: [f32; 4],
+
+    /// `[x, y, z]` vector.
+    pub scale: [f32; 3],
+}
+
+impl NodeTransform {
+    /// Interpolates between two transforms, using spherical linear
+    /// interpolation along the shortest path for the rotations.
+    pub fn lerp(&self, other: &NodeTransform, t: f32) -> NodeTransform {
+        let lerp3 = |a: [f32; 3], b: [f32; 3]| [0, 1, 2].map(|i| a[i] + (b[i] - a[i]) * t);
+        let rotation = slerp(&self.rotation, &other.rotation, t);
+        NodeTransform {
+            translation: lerp3(self.translation, other.translation),
+            rotation: [rotation[0], rotation[1], rotation[2], rotation[3]],
+            scale: lerp3(self.scale, other.scale),
+        }
+    }
+}
+
+/// The transforms of the nodes animated by an animation at one point in time,
+/// as returned by [`SampledPose::sample`].
+///
+/// Morph target weights and `KHR_animation_pointer` targets are not sampled.
+///
+/// [`SampledPose::sample`]: #method.sample
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SampledPose {
+    /// The transform of each animated node, by node index. Properties that
+    /// are not animated keep the value of the rest pose.
+    pub transforms: BTreeMap<usize, NodeTransform>,
+
+    /// The transform of each animated node as defined by the node itself, by
+    /// node index.
+    pub rest: BTreeMap<usize, NodeTransform>,
+}
+
+impl SampledPose {
+    /// Evaluates every translation, rotation, and scale channel of an
+    /// animation at `time` in seconds.
+    ///
+    /// Channels whose buffer data is unavailable are ignored.
+    pub fn sample<'a, 's, F>(animation: &Animation<'a>, time: f32, get_buffer_data: F) -> Self
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        let mut pose = SampledPose::default();
+        for channel in animation.channels() {
+            let target = channel.target();
+            let property = match target.property() {
+                Some(property) => property,
+                None => continue,
+            };
+            if ![Property::Translation, Property::Rotation, Property::Scale].contains(&property) {
+                continue;
+            }
+            let value = match ChannelData::from_reader(&channel.reader(get_buffer_data.clone())) {
+                Some(data) => data.sample(time),
+                None => continue,
+            };
+            let node = match target.node() {
+                Some(node) => node,
+                None => continue,
+            };
+            let rest = pose.rest.entry(node.index()).or_insert_with(|| {
+                let (translation, rotation, scale) = node.transform().decomposed();
+                NodeTransform { translation, rotation, scale }
+            });
+            let transform = pose.transforms.entry(node.index()).or_insert(*rest);
+            match (property, value.as_slice()) {
+                (Property::Translation, &[x, y, z]) => transform.translation = [x, y, z],
+                (Property::Rotation, &[x, y, z, w]) => transform.rotation = [x, y, z, w],
+                (Property::Scale, &[x, y, z]) => transform.scale = [x, y, z],
+                _ => {},
+            }
+        }
+        pose
+    }
+}
+
+/// The translation, rotation, and scale channels of an animation, read once
+/// for repeated sampling into a [`TransformCache`].
+///
+/// After the first call of [`apply`], sampling allocates no memory, so a
+/// playback loop that applies the animation, updates the cache, and reads
+/// world transforms runs without allocating.
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// use gltf::animation::PreparedAnimation;
+/// use gltf::scene::TransformCache;
+///
+/// let (document, buffers, _) = gltf::import("examples/Box.gltf")?;
+/// let scene = document.default_scene().unwrap();
+/// let mut cache = TransformCache::new(&scene);
+/// for animation in document.animations() {
+///     let mut prepared = PreparedAnimation::new(&animation, |x| Some(&buffers[x.index()]));
+///     for frame in 0..60 {
+///         prepared.apply(frame as f32 / 60.0, &mut cache);
+///         cache.update();
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #     run().expect("test failure");
+/// # }
+/// ```
+///
+/// [`TransformCache`]: ../../scene/struct.TransformCache.html
+/// [`apply`]: #method.apply
+#[derive(Clone, Debug)]
+pub struct PreparedAnimation {
+    /// The keyframes of each channel, with its target property and the
+    /// position of its node in `nodes`.
+    channels: Vec<(usize, Property, ChannelData)>,
+
+    /// The index of each animated node, with its rest transform.
+    nodes: Vec<(usize, NodeTransform)>,
+
+    /// The sampled transform of each animated node.
+    pose: Vec<NodeTransform>,
+
+    /// Storage for sampled values, reused between channels.
+    value: Vec<f32>,
+}
+
+impl PreparedAnimation {
+    /// Reads the translation, rotation, and scale channels of an animation.
+    ///
+    /// Channels whose buffer data is unavailable are ignored.
+    pub fn new<'a, 's, F>(animation: &Animation<'a>, get_buffer_data: F) -> Self
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        let mut channels = Vec::new();
+        let mut nodes: Vec<(usize, NodeTransform)> = Vec::new();
+        for channel in animation.channels() {
+            let target = channel.target();
+            let property = match target.property() {
+                Some(property) => property,
+                None => continue,
+            };
+            if ![Property::Translation, Property::Rotation, Property::Scale].contains(&property) {
+                continue;
+            }
+            let data = match ChannelData::from_reader(&channel.reader(get_buffer_data.clone())) {
+                Some(data) => data,
+                None => continue,
+            };
+            let node = match target.node() {
+                Some(node) => node,
+                None => continue,
+            };
+            let slot = match nodes.iter().position(|&(index, _)| index == node.index()) {
+                Some(slot) => slot,
+                None => {
+                    let (translation, rotation, scale) = node.transform().decomposed();
+                    nodes.push((node.index(), NodeTransform { translation, rotation, scale }));
+                    nodes.len() - 1
+                },
+            };
+            channels.push((slot, property, data));
+        }
+        let pose = nodes.iter().map(|&(_, rest)| rest).collect();
+        PreparedAnimation { channels, nodes, pose, value: Vec::new() }
+    }
+
+    /// Evaluates the animation at `time` in seconds and sets the local
+    /// transform of every animated node in `cache`.
+    ///
+    /// Properties that are not animated keep the value of the rest pose. Call
+    /// [`TransformCache::update`] afterwards to recompute world transforms.
+    ///
+    /// [`TransformCache::update`]: ../../scene/struct.TransformCache.html#method.update
+    pub fn apply(&mut self, time: f32, cache: &mut TransformCache) {
+        for (transform, &(_, rest)) in self.pose.iter_mut().zip(&self.nodes) {
+            *transform = rest;
+        }
+        for (slot, property, data) in &self.channels {
+            data.sample_into(time, &mut self.value);
+            let transform = &mut self.pose[*slot];
+            match (property, self.value.as_slice()) {
+                (Property::Translation, &[x, y, z]) => transform.translation = [x, y, z],
+                (Property::Rotation, &[x, y, z, w]) => transform.rotation = [x, y, z, w],
+                (Property::Scale, &[x, y, z]) => transform.scale = [x, y, z],
+                _ => {},
+            }
+        }
+        for (&(node, _), transform) in self.nodes.iter().zip(&self.pose) {
+            cache.set_local(node, Transform::Decomposed {
+                translation: transform.translation,
+                rotation: transform.rotation,
+                scale: transform.scale,
+            });
+        }
+    }
+}
+
+/// Crossfades between two sampled poses.
+///
+/// `t` ranges from `0.0`, returning the transforms of `a`, to `1.0`, returning
+/// the transforms of `b`. A node animated by only one of the poses is blended
+/// with its rest pose, so that it eases in or out of the animation.
+pub fn blend(a: &SampledPose, b: &SampledPose, t: f32) -> SampledPose {
+    let mut rest = a.rest.clone();
+    rest.extend(b.rest.iter().map(|(&node, &transform)| (node, transform)));
+    let transforms = rest
+        .iter()
+        .map(|(node, rest)| {
+            let from = a.transforms.get(node).unwrap_or(rest);
+            let to = b.transforms.get(node).unwrap_or(rest);
+            (*node, from.lerp(to, t))
+        })
+        .collect();
+    SampledPose { transforms, rest }
+}
@@ -0,0 +1,496 @@
+use std::collections::{HashMap, HashSet};
+
+use json::accessor::{ComponentType, GenericComponentType};
+use json::edit::Error;
+use json::validation::Checked;
+use json::{Index, Path, Root};
+
+use crate::animation::Interpolation;
+#[cfg(feature = "KHR_animation_pointer")]
+use crate::animation::Property;
+use crate::{Buffer, Document};
+
+use super::{ReadOutputs, Reader};
+
+/// The keyframes of an animation channel, as read by
+/// [`ChannelData::from_reader`].
+///
+/// [`ChannelData::from_reader`]: struct.ChannelData.html#method.from_reader
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChannelData {
+    /// The interpolation algorithm of the channel.
+    pub interpolation: Interpolation,
+
+    /// The keyframe times in seconds.
+    pub inputs: Vec<f32>,
+
+    /// The keyframe values.
+    pub values: Values,
+}
+
+/// The keyframe values of an animation channel.
+///
+/// For cubic spline interpolation, each keyframe has an in-tangent, a value,
+/// and an out-tangent.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Values {
+    /// XYZ translations.
+    Translations(Vec<[f32; 3]>),
+
+    /// XYZW rotation quaternions.
+    Rotations(Vec<[f32; 4]>),
+
+    /// XYZ scales.
+    Scales(Vec<[f32; 3]>),
+
+    /// Morph target weights, with one weight per morph target per keyframe.
+    MorphTargetWeights(Vec<f32>),
+}
+
+/// Summary of the changes made by [`resample_animations`].
+///
+/// [`resample_animations`]: fn.resample_animations.html
+#[derive(Clone, Debug, Default)]
+pub struct Report {
+    /// The number of samplers whose keyframes were reduced.
+    pub resampled: usize,
+
+    /// The total number of keyframes removed.
+    pub removed_keyframes: usize,
+
+    /// Samplers left untouched because they use cubic spline interpolation
+    /// or target a `KHR_animation_pointer`, for example `animations[0].samplers[1]`.
+    pub skipped: Vec<Path>,
+}
+
+impl ChannelData {
+    /// Reads the keyframes of an animation channel.
+    ///
+    /// Integer rotations and morph target weights are converted to `f32`.
+    /// Returns `None` if the buffer data of the channel is unavailable or the
+    /// channel targets a `KHR_animation_pointer`.
+    pub fn from_reader<'a, 's, F>(reader: &Reader<'a, 's, F>) -> Option<Self>
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        let sampler = reader.channel.sampler()?;
+        let input_count = sampler.input()?.count();
+        let output_count = sampler.output()?.count();
+        let inputs = reader.read_inputs()?.take(input_count).collect();
+        let values = match reader.read_outputs()? {
+            ReadOutputs::Translations(iter) => Values::Translations(iter.take(output_count).collect()),
+            ReadOutputs::Rotations(iter) => {
+                Values::Rotations(iter.into_f32().take(output_count).collect())
+            },
+            ReadOutputs::Scales(iter) => Values::Scales(iter.take(output_count).collect()),
+            ReadOutputs::MorphTargetWeights(iter) => {
+                Values::MorphTargetWeights(iter.into_f32().take(output_count).collect())
+            },
+            #[cfg(feature = "KHR_animation_pointer")]
+            ReadOutputs::Pointer(_) => return None,
+        };
+        Some(ChannelData {
+            interpolation: sampler.interpolation(),
+            inputs,
+            values,
+        })
+    }
+
+    /// Evaluates the channel at `time` in seconds and returns the components
+    /// of the value, for example the XYZW components of a rotation.
+    ///
+    /// Times before the first keyframe or after the last keyframe evaluate to
+    /// the value of that keyframe. Linear rotations use spherical linear
+    /// interpolation, and cubic spline rotations are normalized. Returns an
+    /// empty vector if the channel has no keyframes.
+    pub fn sample(&self, time: f32) -> Vec<f32> {
+        let mut value = Vec::new();
+        self.sample_into(time, &mut value);
+        value
+    }
+
+    /// Evaluates the channel at `time` in seconds like [`sample`], replacing
+    /// the contents of `value` with the components of the value.
+    ///
+    /// The capacity of `value` is reused, so sampling repeatedly into the same
+    /// vector does not allocate.
+    ///
+    /// [`sample`]: #method.sample
+    pub fn sample_into(&self, time: f32, value: &mut Vec<f32>) {
+        value.clear();
+        let components = self.components();
+        let count = self.inputs.len();
+        let (width, middle) = match self.interpolation {
+            Interpolation::CubicSpline => (3, 1),
+            _ => (1, 0),
+        };
+        let stride = components.len().checked_div(count * width).unwrap_or(0);
+        let part = |k: usize, part: usize| &components[(k * width + part) * stride..][..stride];
+        let k = match self.inputs.iter().rposition(|&x| x <= time) {
+            None if count == 0 => return,
+            None => return value.extend_from_slice(part(0, middle)),
+            Some(k) if k + 1 == count => return value.extend_from_slice(part(k, middle)),
+            Some(k) => k,
+        };
+        let delta = self.inputs[k + 1] - self.inputs[k];
+        let t = if delta > 0.0 { (time - self.inputs[k]) / delta } else { 0.0 };
+        match self.interpolation {
+            Interpolation::Step => value.extend_from_slice(part(k, 0)),
+            Interpolation::Linear => self.interpolate_into(part(k, 0), part(k + 1, 0), t, value),
+            Interpolation::CubicSpline => {
+                let (t2, t3) = (t * t, t * t * t);
+                let weights = [2.0 * t3 - 3.0 * t2 + 1.0, t3 - 2.0 * t2 + t, -2.0 * t3 + 3.0 * t2, t3 - t2];
+                let terms = [part(k, 1), part(k, 2), part(k + 1, 1), part(k + 1, 0)];
+                value.extend((0..stride).map(|i| {
+                    weights[0] * terms[0][i]
+                        + weights[1] * delta * terms[1][i]
+                        + weights[2] * terms[2][i]
+                        + weights[3] * delta * terms[3][i]
+                }));
+                if let Values::Rotations(_) = self.values {
+                    normalize_in_place(value);
+                }
+            },
+        }
+    }
+
+    /// Returns the number of `f32` components of each keyframe value.
+    fn stride(&self) -> usize {
+        match self.values {
+            Values::Translations(_) | Values::Scales(_) => 3,
+            Values::Rotations(_) => 4,
+            Values::MorphTargetWeights(ref weights) => {
+                weights.len().checked_div(self.inputs.len()).unwrap_or(0)
+            },
+        }
+    }
+
+    /// Returns the keyframe values as a flat array of components.
+    fn components(&self) -> &[f32] {
+        match self.values {
+            Values::Translations(ref x) | Values::Scales(ref x) => x.as_flattened(),
+            Values::Rotations(ref x) => x.as_flattened(),
+            Values::MorphTargetWeights(ref x) => x,
+        }
+    }
+
+    /// Returns the error of approximating `expected` by `actual`.
+    fn error(&self, expected: &[f32], actual: &[f32]) -> f32 {
+        match self.values {
+            Values::Translations(_) | Values::Scales(_) => distance(expected, actual),
+            Values::Rotations(_) => angle(expected, actual),
+            Values::MorphTargetWeights(_) => expected
+                .iter()
+                .zip(actual)
+                .map(|(a, b)| (a - b).abs())
+                .fold(0.0, f32::max),
+        }
+    }
+
+    /// Interpolates linearly between `a` and `b`.
+    fn interpolate(&self, a: &[f32], b: &[f32], t: f32) -> Vec<f32> {
+        let mut value = Vec::with_capacity(a.len());
+        self.interpolate_into(a, b, t, &mut value);
+        value
+    }
+
+    /// Interpolates linearly between `a` and `b`, appending the result to
+    /// `value`.
+    fn interpolate_into(&self, a: &[f32], b: &[f32], t: f32, value: &mut Vec<f32>) {
+        match self.values {
+            Values::Rotations(_) => slerp_into(a, b, t, value),
+            _ => value.extend(a.iter().zip(b).map(|(a, b)| a + (b - a) * t)),
+        }
+    }
+
+    /// Returns the keyframes at the given indices.
+    fn select(&self, keyframes: &[usize]) -> Self {
+        let inputs = keyframes.iter().map(|&k| self.inputs[k]).collect();
+        let values = match self.values {
+            Values::Translations(ref x) => Values::Translations(keyframes.iter().map(|&k| x[k]).collect()),
+            Values::Rotations(ref x) => Values::Rotations(keyframes.iter().map(|&k| x[k]).collect()),
+            Values::Scales(ref x) => Values::Scales(keyframes.iter().map(|&k| x[k]).collect()),
+            Values::MorphTargetWeights(ref x) => {
+                let n = self.stride();
+                let weights = keyframes.iter().flat_map(|&k| x[k * n..(k + 1) * n].iter().cloned());
+                Values::MorphTargetWeights(weights.collect())
+            },
+        };
+        ChannelData {
+            interpolation: self.interpolation,
+            inputs,
+            values,
+        }
+    }
+}
+
+fn distance(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(a, b)| (a - b) * (a - b)).sum::<f32>().sqrt()
+}
+
+pub(super) fn normalize(q: &[f32]) -> Vec<f32> {
+    let mut q = q.to_vec();
+    normalize_in_place(&mut q);
+    q
+}
+
+fn length(q: &[f32]) -> f32 {
+    q.iter().map(|x| x * x).sum::<f32>().sqrt()
+}
+
+fn normalize_in_place(q: &mut [f32]) {
+    let length = length(q);
+    if length > 0.0 {
+        q.iter_mut().for_each(|x| *x /= length);
+    }
+}
+
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+/// Returns the angle in radians between the orientations of two quaternions.
+fn angle(a: &[f32], b: &[f32]) -> f32 {
+    let d = dot(&normalize(a), &normalize(b)).abs().min(1.0);
+    2.0 * d.acos()
+}
+
+/// Spherical linear interpolation along the shortest path, as defined by the
+/// glTF specification.
+pub(super) fn slerp(a: &[f32], b: &[f32], t: f32) -> Vec<f32> {
+    let mut value = Vec::with_capacity(a.len());
+    slerp_into(a, b, t, &mut value);
+    value
+}
+
+/// Interpolates like [`slerp`], appending the result to `value`.
+///
+/// [`slerp`]: fn.slerp.html
+fn slerp_into(a: &[f32], b: &[f32], t: f32, value: &mut Vec<f32>) {
+    let scale = |q: &[f32]| match length(q) {
+        length if length > 0.0 => 1.0 / length,
+        _ => 1.0,
+    };
+    let (sa, sb) = (scale(a), scale(b));
+    let d = dot(a, b) * sa * sb;
+    let sign = if d < 0.0 { -1.0 } else { 1.0 };
+    let theta = d.abs().min(1.0).acos();
+    let (wa, wb) = if theta < 1.0e-6 {
+        (1.0 - t, t)
+    } else {
+        let s = theta.sin();
+        (((1.0 - t) * theta).sin() / s, (t * theta).sin() / s)
+    };
+    value.extend(a.iter().zip(b).map(|(a, b)| wa * sa * a + sign * wb * sb * b));
+}
+
+/// Removes keyframes which can be reconstructed from their neighbours within
+/// `tolerance`.
+///
+/// For linear interpolation, a keyframe is removed when interpolating between
+/// the remaining keyframes reproduces its value within `tolerance` at every
+/// removed timestamp. Rotations are compared by the angle in radians between
+/// their orientations, using spherical linear interpolation; translations and
+/// scales by Euclidean distance; and morph target weights by the largest
+/// absolute difference of any weight. For step interpolation, keyframes which
+/// repeat the previous value within `tolerance` are removed.
+///
+/// The first and last keyframes are always kept. Cubic spline keyframes are
+/// returned unchanged.
+pub fn resample(data: &ChannelData, tolerance: f32) -> ChannelData {
+    let count = data.inputs.len();
+    let stride = data.stride();
+    let components = data.components();
+    if count < 3 || components.len() != count * stride {
+        return data.clone();
+    }
+    let value = |k: usize| &components[k * stride..(k + 1) * stride];
+
+    let mut keyframes = vec![0];
+    match data.interpolation {
+        Interpolation::Linear => {
+            let mut anchor = 0;
+            let mut end = 2;
+            while end < count {
+                let (t0, t1) = (data.inputs[anchor], data.inputs[end]);
+                let reconstructible = t1 > t0 && (anchor + 1..end).all(|k| {
+                    let t = (data.inputs[k] - t0) / (t1 - t0);
+                    let approximation = data.interpolate(value(anchor), value(end), t);
+                    data.error(value(k), &approximation) <= tolerance
+                });
+                if reconstructible {
+                    end += 1;
+                } else {
+                    anchor = end - 1;
+                    keyframes.push(anchor);
+                    end = anchor + 2;
+                }
+            }
+        },
+        Interpolation::Step => {
+            for k in 1..count - 1 {
+                let previous = keyframes.last().copied().unwrap_or(0);
+                if data.error(value(previous), value(k)) > tolerance {
+                    keyframes.push(k);
+                }
+            }
+        },
+        Interpolation::CubicSpline => return data.clone(),
+    }
+    keyframes.push(count - 1);
+    data.select(&keyframes)
+}
+
+/// Appends `components` to a buffer as a new buffer view and returns the
+/// index of the view.
+fn push_view(root: &mut Root, buffers: &mut [Vec<u8>], buffer: usize, components: &[f32]) -> usize {
+    let data = &mut buffers[buffer];
+    let offset = (data.len() + 3) & !3;
+    data.resize(offset, 0);
+    for component in components {
+        data.extend_from_slice(&component.to_le_bytes());
+    }
+    root.buffers[buffer].byte_length = data.len() as u32;
+    root.buffer_views.push(json::buffer::View {
+        buffer: Index::new(buffer as u32),
+        byte_length: (components.len() * 4) as u32,
+        byte_offset: Some(offset as u32),
+        byte_stride: None,
+        #[cfg(feature = "names")]
+        name: None,
+        target: None,
+        extensions: None,
+        extras: Default::default(),
+    });
+    root.buffer_views.len() - 1
+}
+
+/// Replaces the data of an accessor with `components`, copying the accessor
+/// first if it is still referenced elsewhere, and returns its new index.
+fn write_accessor(
+    root: &mut Root,
+    buffers: &mut [Vec<u8>],
+    uses: &mut HashMap<usize, usize>,
+    index: usize,
+    components: &[f32],
+    count: usize,
+    bounds: Option<(f32, f32)>,
+) -> Result<usize, Error> {
+    let path = || Path::new().field("accessors").index(index);
+    let old = root.accessors.get(index).ok_or_else(|| Error::IndexOutOfBounds(path()))?;
+    let buffer = match old.buffer_view {
+        Some(view) => root
+            .buffer_views
+            .get(view.value())
+            .ok_or_else(|| Error::IndexOutOfBounds(path()))?
+            .buffer
+            .value(),
+        None => 0,
+    };
+    if buffer >= buffers.len() || buffer >= root.buffers.len() {
+        return Err(Error::OutOfRange(path()));
+    }
+    let mut accessor = old.clone();
+    accessor.buffer_view = Some(Index::new(push_view(root, buffers, buffer, components) as u32));
+    accessor.byte_offset = 0;
+    accessor.count = count as u32;
+    accessor.component_type = Checked::Valid(GenericComponentType(ComponentType::F32));
+    accessor.normalized = false;
+    accessor.sparse = None;
+    accessor.min = bounds.map(|(min, _)| json::Value::from(vec![min]));
+    accessor.max = bounds.map(|(_, max)| json::Value::from(vec![max]));
+
+    let remaining = uses.entry(index).or_insert(1);
+    if *remaining <= 1 {
+        root.accessors[index] = accessor;
+        Ok(index)
+    } else {
+        *remaining -= 1;
+        root.accessors.push(accessor);
+        Ok(root.accessors.len() - 1)
+    }
+}
+
+/// Reduces the keyframes of every animation sampler of a document with
+/// [`resample`].
+///
+/// `buffers` contains the data of every buffer in the document. The reduced
+/// keyframe times and values are appended as `f32` data to the buffers of the
+/// original accessors. Accessors referenced only by the resampled sampler are
+/// rewritten in place; shared accessors are copied. Samplers using cubic
+/// spline interpolation or targeting a `KHR_animation_pointer` are left
+/// untouched and listed in the report.
+///
+/// The original keyframe data is left in place; use [`Root::repack_buffers`]
+/// to remove it.
+///
+/// [`resample`]: fn.resample.html
+/// [`Root::repack_buffers`]: ../../json/struct.Root.html#method.repack_buffers
+pub fn resample_animations(
+    root: &mut Root,
+    buffers: &mut [Vec<u8>],
+    tolerance: f32,
+) -> Result<Report, Error> {
+    let document = Document::from_json_without_validation(root.clone());
+    let mut report = Report::default();
+    let mut resampled = Vec::new();
+    {
+        let get = |buffer: Buffer| buffers.get(buffer.index()).map(Vec::as_slice);
+        for animation in document.animations() {
+            let mut visited = HashSet::new();
+            let json = &root.animations[animation.index()];
+            for (channel, channel_json) in animation.channels().zip(&json.channels) {
+                let sampler = channel_json.sampler.value();
+                if !visited.insert(sampler) {
+                    continue;
+                }
+                let path = Path::new()
+                    .field("animations")
+                    .index(animation.index())
+                    .field("samplers")
+                    .index(sampler);
+                if channel.sampler().map(|x| x.interpolation()) == Some(Interpolation::CubicSpline) {
+                    report.skipped.push(path);
+                    continue;
+                }
+                #[cfg(feature = "KHR_animation_pointer")]
+                {
+                    if channel.target().property() == Some(Property::Pointer) {
+                        report.skipped.push(path);
+                        continue;
+                    }
+                }
+                let data = ChannelData::from_reader(&channel.reader(get))
+                    .ok_or_else(|| Error::OutOfRange(path.clone()))?;
+                let reduced = resample(&data, tolerance);
+                if reduced.inputs.len() < data.inputs.len() {
+                    report.removed_keyframes += data.inputs.len() - reduced.inputs.len();
+                    resampled.push((animation.index(), sampler, reduced));
+                }
+            }
+        }
+    }
+
+    let mut uses = HashMap::new();
+    for (accessor, _) in crate::accessor::usage::collect(&document) {
+        *uses.entry(accessor.index()).or_insert(0) += 1;
+    }
+    for (animation, sampler, data) in resampled {
+        let json = &root.animations[animation].samplers[sampler];
+        let (input, output) = (json.input.value(), json.output.value());
+        let count = data.inputs.len();
+        let bounds = (data.inputs[0], data.inputs[count - 1]);
+        let input = write_accessor(root, buffers, &mut uses, input, &data.inputs, count, Some(bounds))?;
+        let components = data.components();
+        let output_count = match data.values {
+            Values::MorphTargetWeights(ref x) => x.len(),
+            _ => count,
+        };
+        let output = write_accessor(root, buffers, &mut uses, output, components, output_count, None)?;
+        let json = &mut root.animations[animation].samplers[sampler];
+        json.input = Index::new(input as u32);
+        json.output = Index::new(output as u32);
+        report.resampled += 1;
+    }
+    Ok(report)
+}
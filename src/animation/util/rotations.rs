@@ -1,6 +1,7 @@
 use super::Rotations;
 use std::marker::PhantomData;
 use crate::Normalize;
+use crate::scene::renormalize;
 
 /// Casting iterator for `Rotations`.
 #[derive(Clone, Debug)]
@@ -23,6 +24,9 @@ pub struct I16;
 pub struct U16;
 
 /// Type which describes how to cast any weight into f32.
+///
+/// Rotations are renormalized after casting, see
+/// [`QUATERNION_EPSILON`](../../../scene/constant.QUATERNION_EPSILON.html).
 #[derive(Clone, Debug)]
 pub struct F32;
 
@@ -134,7 +138,7 @@ impl Cast for I8 {
     }
 }
 
-impl Cast for i8 {
+impl Cast for U8 {
     type Output = [u8; 4];
 
     fn cast_i8(x: [i8; 4]) -> Self::Output {
@@ -210,22 +214,22 @@ impl Cast for F32 {
     type Output = [f32; 4];
 
     fn cast_i8(x: [i8; 4]) -> Self::Output {
-        x.normalize()
+        renormalize(x.normalize())
     }
 
     fn cast_u8(x: [u8; 4]) -> Self::Output {
-        x.normalize()
+        renormalize(x.normalize())
     }
 
     fn cast_i16(x: [i16; 4]) -> Self::Output {
-        x.normalize()
+        renormalize(x.normalize())
     }
 
     fn cast_u16(x: [u16; 4]) -> Self::Output {
-        x.normalize()
+        renormalize(x.normalize())
     }
 
     fn cast_f32(x: [f32; 4]) -> Self::Output {
-        x.normalize()
+        renormalize(x.normalize())
     }
 }
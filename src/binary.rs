@@ -42,6 +42,10 @@ pub struct Glb<'a> {
     pub json: Cow<'a, [u8]>,
     /// The optional BIN section of the `.glb` file.
     pub bin: Option<Cow<'a, [u8]>>,
+    /// Chunks of types not defined by the glTF specification, in file order.
+    ///
+    /// These follow the BIN chunk when written.
+    pub extra_chunks: Vec<([u8; 4], Cow<'a, [u8]>)>,
 }
 
 /// The header section of a .glb file.
@@ -72,7 +76,7 @@ struct ChunkHeader {
     /// The length of the chunk data in byte excluding the header.
     length: u32,
     /// Chunk type.
-    ty: ChunkType,
+    ty: [u8; 4],
 }
 
 impl Header {
@@ -103,66 +107,104 @@ impl ChunkHeader {
         let length = reader.read_u32::<LittleEndian>().map_err(Io)?;
         let mut ty = [0; 4];
         reader.read_exact(&mut ty).map_err(Io)?;
-        let ty = match &ty {
-            b"JSON" => Ok(ChunkType::Json),
-            b"BIN\0" => Ok(ChunkType::Bin),
-            _ => Err(Error::UnknownChunkType(ty)),
-        }?;
         Ok(Self { length, ty })
     }
+
+    /// Returns the chunk type if it is one defined by the glTF specification.
+    fn kind(&self) -> Option<ChunkType> {
+        match &self.ty {
+            b"JSON" => Some(ChunkType::Json),
+            b"BIN\0" => Some(ChunkType::Bin),
+            _ => None,
+        }
+    }
 }
 
 fn align_to_multiple_of_four(n: &mut usize) {
     *n = (*n + 3) & !3;
 }
 
-fn split_binary_gltf<'a>(mut data: &'a [u8]) -> Result<(&'a [u8], Option<&'a [u8]>), Error> {
-    let (json, mut data) = ChunkHeader::from_reader(&mut data)
-        .and_then(|json_h| if let ChunkType::Json = json_h.ty {
-            Ok(json_h)
-        } else {
-            Err(Error::ChunkType(json_h.ty))
-        })
-        .and_then(|json_h| if json_h.length as usize <= data.len() {
-            Ok(json_h)
-        } else {
-            Err(Error::ChunkLength {
-                ty: json_h.ty,
-                length: json_h.length,
+/// Reads the next chunk header and splits its data off the front of `data`.
+fn read_chunk<'a>(data: &mut &'a [u8]) -> Result<(ChunkHeader, &'a [u8]), Error> {
+    let chunk_h = ChunkHeader::from_reader(&mut *data)?;
+    if chunk_h.length as usize > data.len() {
+        return Err(match chunk_h.kind() {
+            Some(ty) => Error::ChunkLength {
+                ty,
+                length: chunk_h.length,
                 length_read: data.len(),
-            })
-        })
-        // We have verified that json_h.length is no greater than that of
-        // data.len().
-        .map(|json_h| data.split_at(json_h.length as usize))?;
+            },
+            None => Error::Length {
+                length: chunk_h.length,
+                length_read: data.len(),
+            },
+        });
+    }
+    // We have verified that chunk_h.length is no greater than that of
+    // data.len().
+    let (chunk, rest) = data.split_at(chunk_h.length as usize);
+    *data = rest;
+    Ok((chunk_h, chunk))
+}
 
-    let bin = if data.len() > 0 {
-        ChunkHeader::from_reader(&mut data)
-            .and_then(|bin_h| if let ChunkType::Bin = bin_h.ty {
-                Ok(bin_h)
-            } else {
-                Err(Error::ChunkType(bin_h.ty))
-            })
-            .and_then(|bin_h| if bin_h.length as usize <= data.len() {
-                Ok(bin_h)
-            } else {
-                Err(Error::ChunkLength {
-                    ty: bin_h.ty,
-                    length: bin_h.length,
-                    length_read: data.len(),
-                })
-            })
-            // We have verified that bin_h.length is no greater than that
-            // of data.len().
-            .map(|bin_h| data.split_at(bin_h.length as usize))
-            .map(|(x, _)| Some(x))?
-    } else {
-        None
-    };
-    Ok((json, bin))
+/// The chunks of a binary glTF file following the header.
+struct Chunks<'a> {
+    json: &'a [u8],
+    bin: Option<&'a [u8]>,
+    extra: Vec<([u8; 4], &'a [u8])>,
+}
+
+fn split_binary_gltf(mut data: &[u8]) -> Result<Chunks<'_>, Error> {
+    let (json_h, json) = read_chunk(&mut data)?;
+    match json_h.kind() {
+        Some(ChunkType::Json) => {},
+        Some(ty) => return Err(Error::ChunkType(ty)),
+        None => return Err(Error::UnknownChunkType(json_h.ty)),
+    }
+
+    let mut chunks = Chunks { json, bin: None, extra: Vec::new() };
+    while !data.is_empty() {
+        let (chunk_h, chunk) = read_chunk(&mut data)?;
+        match chunk_h.kind() {
+            // The BIN chunk, if present, must immediately follow the JSON
+            // chunk.
+            Some(ChunkType::Bin) if chunks.bin.is_none() && chunks.extra.is_empty() => {
+                chunks.bin = Some(chunk);
+            },
+            Some(ty) => return Err(Error::ChunkType(ty)),
+            // Chunks of unknown types are preserved so that tooling may
+            // inspect them or write them back out.
+            None => chunks.extra.push((chunk_h.ty, chunk)),
+        }
+    }
+    Ok(chunks)
 }
 
 impl<'a> Glb<'a> {
+    /// Returns the total length of the `.glb` file, including padding.
+    fn length(&self) -> usize {
+        let mut length = mem::size_of::<Header>() + mem::size_of::<ChunkHeader>() + self.json.len();
+        align_to_multiple_of_four(&mut length);
+        let chunks = self.bin.iter().chain(self.extra_chunks.iter().map(|(_, data)| data));
+        for data in chunks {
+            length += mem::size_of::<ChunkHeader>() + data.len();
+            align_to_multiple_of_four(&mut length);
+        }
+        length
+    }
+
+    /// Returns an iterator over every chunk of the `.glb` file as
+    /// `(chunk_type, data)` pairs.
+    ///
+    /// The JSON chunk comes first, followed by the BIN chunk if present, and
+    /// then any chunks of unknown type.
+    pub fn chunks(&self) -> impl Iterator<Item = ([u8; 4], &[u8])> + '_ {
+        let json = Some((*b"JSON", &*self.json));
+        let bin = self.bin.as_ref().map(|bin| (*b"BIN\0", &**bin));
+        let extra = self.extra_chunks.iter().map(|(ty, data)| (*ty, &**data));
+        json.into_iter().chain(bin).chain(extra)
+    }
+
     /// Writes binary glTF to a writer.
     pub fn to_writer<W>(&self, mut writer: W) -> Result<(), crate::Error>
         where W: io::Write
@@ -171,44 +213,25 @@ impl<'a> Glb<'a> {
         {
             let magic = b"glTF";
             let version = 2;
-            let mut length = mem::size_of::<Header>() + mem::size_of::<ChunkHeader>() + self.json.len();
-            align_to_multiple_of_four(&mut length);
-            if let Some(bin) = self.bin.as_ref() {
-                length += mem::size_of::<ChunkHeader>() + bin.len();
-                align_to_multiple_of_four(&mut length);
-            }
+            let length = self.length();
 
             writer.write_all(&magic[..])?;
             writer.write_u32::<LittleEndian>(version)?;
             writer.write_u32::<LittleEndian>(length as u32)?;
         }
 
-        // Write JSON chunk header
-        {
-            let magic = b"JSON";
-            let mut length = self.json.len();
-            align_to_multiple_of_four(&mut length);
-            let padding = length - self.json.len();
-
-            writer.write_u32::<LittleEndian>(length as u32)?;
-            writer.write_all(&magic[..])?;
-            writer.write_all(&self.json)?;
-            for _ in 0..padding {
-                writer.write_u8(0x20)?;
-            }
-        }
-
-        if let Some(bin) = self.bin.as_ref() {
-            let magic = b"BIN\0";
-            let mut length = bin.len();
+        for (magic, data) in self.chunks() {
+            let mut length = data.len();
             align_to_multiple_of_four(&mut length);
-            let padding = length - bin.len();
+            let padding = length - data.len();
+            // The JSON chunk is padded with spaces, all others with zeros.
+            let pad = if &magic == b"JSON" { 0x20 } else { 0 };
 
             writer.write_u32::<LittleEndian>(length as u32)?;
             writer.write_all(&magic[..])?;
-            writer.write_all(&bin)?;
+            writer.write_all(data)?;
             for _ in 0..padding {
-                writer.write_u8(0)?;
+                writer.write_u8(pad)?;
             }
         }
 
@@ -217,14 +240,7 @@ impl<'a> Glb<'a> {
 
     /// Writes binary glTF to a byte vector.
     pub fn to_vec(&self) -> Result<Vec<u8>, crate::Error> {
-        let mut length = mem::size_of::<Header>() + mem::size_of::<ChunkHeader>() + self.json.len();
-        align_to_multiple_of_four(&mut length);
-        if let Some(bin) = self.bin.as_ref() {
-            length += mem::size_of::<ChunkHeader>() + bin.len();
-            align_to_multiple_of_four(&mut length);
-        }
-
-        let mut vec = Vec::with_capacity(length);
+        let mut vec = Vec::with_capacity(self.length());
         self.to_writer(&mut vec as &mut dyn io::Write)?;
         Ok(vec)
     }
@@ -234,10 +250,13 @@ impl<'a> Glb<'a> {
     /// * Mandatory GLB header.
     /// * Mandatory JSON chunk.
     /// * Optional BIN chunk.
+    /// * Any number of chunks of unknown type, see [`extra_chunks`].
+    ///
+    /// [`extra_chunks`]: #structfield.extra_chunks
     pub fn from_slice(mut data: &'a [u8]) -> Result<Self, crate::Error> {
         let header = Header::from_reader(&mut data)
             .and_then(|header| {
-                let contents_length = header.length as usize - Header::size_of();
+                let contents_length = (header.length as usize).saturating_sub(Header::size_of());
                 if contents_length <= data.len() {
                     Ok(header)
                 } else {
@@ -250,7 +269,12 @@ impl<'a> Glb<'a> {
             .map_err(crate::Error::Binary)?;
         match header.version {
             2 => split_binary_gltf(data)
-                .map(|(json, bin)| Glb { header, json: json.into(), bin: bin.map(Into::into) })
+                .map(|chunks| Glb {
+                    header,
+                    json: chunks.json.into(),
+                    bin: chunks.bin.map(Into::into),
+                    extra_chunks: chunks.extra.into_iter().map(|(ty, x)| (ty, x.into())).collect(),
+                })
                 .map_err(crate::Error::Binary),
             x => Err(crate::Error::Binary(Error::Version(x)))
         }
@@ -266,16 +290,21 @@ impl<'a> Glb<'a> {
         let header = Header::from_reader(&mut reader).map_err(crate::Error::Binary)?;
         match header.version {
             2 => {
-                let glb_len = header.length - Header::size_of() as u32;
+                let glb_len = header.length.saturating_sub(Header::size_of() as u32);
                 let mut buf = vec![0; glb_len as usize];
                 if let Err(e) = reader.read_exact(&mut buf).map_err(Error::Io) {
                     Err(crate::Error::Binary(e))
                 } else {
                     split_binary_gltf(&buf)
-                        .map(|(json, bin)| Glb {
+                        .map(|chunks| Glb {
                             header,
-                            json: json.to_vec().into(),
-                            bin: bin.map(<[u8]>::to_vec).map(Into::into),
+                            json: chunks.json.to_vec().into(),
+                            bin: chunks.bin.map(<[u8]>::to_vec).map(Into::into),
+                            extra_chunks: chunks
+                                .extra
+                                .into_iter()
+                                .map(|(ty, x)| (ty, x.to_vec().into()))
+                                .collect(),
                         })
                         .map_err(crate::Error::Binary)
                 }
@@ -285,6 +314,55 @@ impl<'a> Glb<'a> {
     }
 }
 
+/// Reads binary glTF from a stream, deserializing the JSON chunk directly from
+/// the stream rather than copying it into an intermediate buffer first.
+pub(crate) fn from_reader_streaming<R>(
+    mut reader: R,
+) -> Result<(json::Root, Option<Vec<u8>>), crate::Error>
+    where R: io::Read
+{
+    use self::Error::Io;
+    let header = Header::from_reader(&mut reader).map_err(crate::Error::Binary)?;
+    if header.version != 2 {
+        return Err(crate::Error::Binary(Error::Version(header.version)));
+    }
+
+    let json_h = ChunkHeader::from_reader(&mut reader).map_err(crate::Error::Binary)?;
+    match json_h.kind() {
+        Some(ChunkType::Json) => {},
+        Some(ty) => return Err(crate::Error::Binary(Error::ChunkType(ty))),
+        None => return Err(crate::Error::Binary(Error::UnknownChunkType(json_h.ty))),
+    }
+    let json = {
+        let mut chunk = io::Read::take(&mut reader, json_h.length as u64);
+        // Inspect the first bytes of the chunk for a byte order mark.
+        let mut prefix = Vec::with_capacity(4);
+        io::Read::read_to_end(&mut io::Read::take(&mut chunk, 4), &mut prefix)
+            .map_err(|e| crate::Error::Binary(Io(e)))?;
+        let start = crate::encoding::bom_length(&prefix)?;
+        crate::encoding::from_reader(io::Read::chain(&prefix[start..], &mut chunk), start, true)?
+    };
+
+    let mut length = Header::size_of() + mem::size_of::<ChunkHeader>() + json_h.length as usize;
+    align_to_multiple_of_four(&mut length);
+    let bin = if (header.length as usize) > length {
+        let bin_h = ChunkHeader::from_reader(&mut reader).map_err(crate::Error::Binary)?;
+        match bin_h.kind() {
+            Some(ChunkType::Bin) => {
+                let mut bin = vec![0; bin_h.length as usize];
+                reader.read_exact(&mut bin).map_err(|e| crate::Error::Binary(Io(e)))?;
+                Some(bin)
+            },
+            Some(ty) => return Err(crate::Error::Binary(Error::ChunkType(ty))),
+            // Chunks of unknown type are of no interest to the importer.
+            None => None,
+        }
+    } else {
+        None
+    };
+    Ok((json, bin))
+}
+
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use std::error::Error;
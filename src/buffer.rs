@@ -1,7 +1,12 @@
 #[cfg(feature = "import")]
 use std::ops;
+#[cfg(feature = "import")]
+use std::sync::Arc;
+
+use crate::{Document, Error, Result};
 
-use crate::Document;
+#[cfg(feature = "utils")]
+use crate::Accessor;
 
 pub use json::buffer::Target;
 
@@ -18,6 +23,8 @@ pub struct Buffer<'a> {
     json: &'a json::buffer::Buffer,
 }
 
+impl_identity!(Buffer);
+
 /// A view into a buffer generally representing a subset of the buffer.
 #[derive(Clone, Debug)]
 pub struct View<'a> {
@@ -29,11 +36,10 @@ pub struct View<'a> {
 
     /// The corresponding JSON struct.
     json: &'a json::buffer::View,
-
-    /// The parent `Buffer`.
-    parent: Buffer<'a>,
 }
 
+impl_identity!(View);
+
 /// Describes a buffer data source.
 #[derive(Clone, Debug)]
 pub enum Source<'a> {
@@ -45,15 +51,73 @@ pub enum Source<'a> {
 }
 
 /// Buffer data belonging to an imported glTF asset.
+///
+/// The data dereferences to a byte slice regardless of how it is stored, so
+/// readers may be given `|buffer| Some(&buffers[buffer.index()])`.
 #[cfg(feature = "import")]
 #[derive(Clone, Debug)]
-pub struct Data(pub Vec<u8>);
+pub enum Data {
+    /// Data owned by a vector.
+    Owned(Vec<u8>),
+
+    /// Data shared with other owners.
+    Shared(Arc<[u8]>),
+
+    /// Data backed by a read-only memory-mapped file.
+    #[cfg(all(feature = "mmap", unix))]
+    Mapped(Mapped),
+}
+
+#[cfg(all(feature = "mmap", unix))]
+pub use crate::mmap::Mapped;
 
 #[cfg(feature = "import")]
 impl ops::Deref for Data {
     type Target = [u8];
     fn deref(&self) -> &Self::Target {
-        self.0.as_slice()
+        match *self {
+            Data::Owned(ref data) => data.as_slice(),
+            Data::Shared(ref data) => data,
+            #[cfg(all(feature = "mmap", unix))]
+            Data::Mapped(ref data) => data,
+        }
+    }
+}
+
+#[cfg(feature = "import")]
+impl Data {
+    /// Checks that the data holds the `byteLength` bytes of `buffer`, as
+    /// [`Buffer::validate_data`] does.
+    ///
+    /// [`Buffer::validate_data`]: struct.Buffer.html#method.validate_data
+    pub fn validate_against(&self, buffer: &Buffer) -> Result<()> {
+        buffer.validate_data(self)
+    }
+
+    /// Returns the data as a mutable vector, copying shared and
+    /// memory-mapped data into an owned vector first.
+    pub fn to_mut(&mut self) -> &mut Vec<u8> {
+        if !matches!(*self, Data::Owned(_)) {
+            *self = Data::Owned(self.to_vec());
+        }
+        match *self {
+            Data::Owned(ref mut data) => data,
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[cfg(feature = "import")]
+impl From<Vec<u8>> for Data {
+    fn from(data: Vec<u8>) -> Self {
+        Data::Owned(data)
+    }
+}
+
+#[cfg(feature = "import")]
+impl From<Arc<[u8]>> for Data {
+    fn from(data: Arc<[u8]>) -> Self {
+        Data::Shared(data)
     }
 }
 
@@ -78,7 +142,7 @@ impl<'a> Buffer<'a> {
 
     /// Returns the buffer data source.
     pub fn source(&self) -> Source<'a> {
-        if let Some(uri) = self.json.uri.as_ref().map(String::as_str) {
+        if let Some(uri) = self.json.uri.as_deref() {
             Source::Uri(uri)
         } else {
             Source::Bin
@@ -90,10 +154,37 @@ impl<'a> Buffer<'a> {
         self.json.byte_length as usize
     }
 
+    /// Returns `true` if the buffer is an `EXT_meshopt_compression` fallback
+    /// buffer.
+    ///
+    /// The data of fallback buffers is not read on import when the `meshopt`
+    /// feature is enabled, and is zeroed except where buffer views are
+    /// decompressed into it.
+    #[cfg(feature = "EXT_meshopt_compression")]
+    pub fn is_meshopt_fallback(&self) -> bool {
+        let extension = self.json.extensions.as_ref().and_then(|x| x.ext_meshopt_compression.as_ref());
+        extension.is_some_and(|x| x.fallback)
+    }
+
+    /// Checks that `data` holds at least the `byteLength` bytes of the buffer.
+    ///
+    /// Longer data is accepted, since the `BIN` chunk of binary glTF is padded
+    /// with up to three bytes to a multiple of four.
+    pub fn validate_data(&self, data: &[u8]) -> Result<()> {
+        if data.len() < self.length() {
+            return Err(Error::BufferLength {
+                buffer: self.index,
+                expected: self.length(),
+                actual: data.len(),
+            });
+        }
+        Ok(())
+    }
+
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     pub fn name(&self) -> Option<&'a str> {
-        self.json.name.as_ref().map(String::as_str)
+        self.json.name.as_deref()
     }
 
     /// Optional application specific data.
@@ -109,12 +200,10 @@ impl<'a> View<'a> {
         index: usize,
         json: &'a json::buffer::View,
     ) -> Self {
-        let parent = document.buffers().nth(json.buffer.value()).unwrap();
         Self {
             document,
             index,
             json,
-            parent,
         }
     }
 
@@ -124,8 +213,11 @@ impl<'a> View<'a> {
     }
 
     /// Returns the parent `Buffer`.
-    pub fn buffer(&self) -> Buffer<'a> {
-        self.document.buffers().nth(self.json.buffer.value()).unwrap()
+    ///
+    /// Returns `None` if the buffer does not exist, which is only possible
+    /// for documents loaded without validation.
+    pub fn buffer(&self) -> Option<Buffer<'a>> {
+        self.document.get(self.json.buffer)
     }
 
     /// Returns the length of the buffer view in bytes.
@@ -140,6 +232,13 @@ impl<'a> View<'a> {
 
     /// Returns the stride in bytes between vertex attributes or other interleavable
     /// data. When `None`, data is assumed to be tightly packed.
+    ///
+    /// Only the authored `byteStride` is returned, so tightly packed data
+    /// that may be repacked freely is distinguished from data with an
+    /// explicit stride. See [`Accessor::effective_stride`] for the stride
+    /// that applies in either case.
+    ///
+    /// [`Accessor::effective_stride`]: ../accessor/struct.Accessor.html#method.effective_stride
     pub fn stride(&self) -> Option<usize> {
         self.json.byte_stride.and_then(|x| {
                 // Treat byte_stride == 0 same as not specifying stride.
@@ -155,12 +254,19 @@ impl<'a> View<'a> {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     pub fn name(&self) -> Option<&'a str> {
-        self.json.name.as_ref().map(String::as_str)
+        self.json.name.as_deref()
+    }
+
+    /// Returns the `EXT_meshopt_compression` data of the buffer view, if
+    /// present.
+    #[cfg(feature = "EXT_meshopt_compression")]
+    pub fn meshopt_compression(&self) -> Option<&'a json::extensions::buffer::ext_meshopt_compression::View> {
+        self.json.extensions.as_ref().and_then(|x| x.ext_meshopt_compression.as_ref())
     }
 
     /// Optional target the buffer should be bound to.
     pub fn target(&self) -> Option<Target> {
-        self.json.target.map(|target| target.unwrap())
+        self.json.target.and_then(|target| target.valid())
     }
 
     /// Optional application specific data.
@@ -168,3 +274,33 @@ impl<'a> View<'a> {
         &self.json.extras
     }
 }
+
+/// Fetches and validates the data of every buffer read by `accessors` for the
+/// `try_reader` methods, returning a lookup of the fetched data that can be
+/// given to a reader.
+#[cfg(feature = "utils")]
+pub(crate) fn prefetch<'a, 's, I, F>(
+    accessors: I,
+    mut get_buffer_data: F,
+) -> Result<impl Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>>
+where
+    I: IntoIterator<Item = Accessor<'a>>,
+    F: FnMut(Buffer<'a>) -> Result<&'s [u8]>,
+{
+    let mut fetched: Vec<Option<&'s [u8]>> = Vec::new();
+    for accessor in accessors {
+        let sparse = accessor.sparse().into_iter().flat_map(|x| x.indices().view().into_iter().chain(x.values().view()));
+        for buffer in accessor.view().into_iter().chain(sparse).filter_map(|view| view.buffer()) {
+            if fetched.get(buffer.index()).is_some_and(Option::is_some) {
+                continue;
+            }
+            let data = get_buffer_data(buffer.clone())?;
+            buffer.validate_data(data)?;
+            if fetched.len() <= buffer.index() {
+                fetched.resize(buffer.index() + 1, None);
+            }
+            fetched[buffer.index()] = Some(data);
+        }
+    }
+    Ok(move |buffer: Buffer<'a>| fetched.get(buffer.index()).copied().flatten())
+}
@@ -1,4 +1,4 @@
-use crate::Document;
+use crate::{Document, Node};
 
 /// A camera's projection.
 #[derive(Clone, Debug)]
@@ -24,6 +24,8 @@ pub struct Camera<'a> {
     json: &'a json::camera::Camera,
 }
 
+impl_identity!(Camera);
+
 ///  Values for an orthographic camera projection.
 #[derive(Clone, Debug)]
 pub struct Orthographic<'a> {
@@ -62,19 +64,36 @@ impl<'a> Camera<'a> {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     pub fn name(&self) -> Option<&'a str> {
-        self.json.name.as_ref().map(String::as_str)
+        self.json.name.as_deref()
+    }
+
+    /// Returns an `Iterator` over the nodes that reference this camera.
+    ///
+    /// A camera may be referenced by any number of nodes, each of which places
+    /// it in the scene with its own transform; see [`Node::view_matrix`].
+    ///
+    /// [`Node::view_matrix`]: ../scene/struct.Node.html#method.view_matrix
+    pub fn reference_nodes(&self) -> impl Iterator<Item = Node<'a>> + 'a {
+        let index = self.index;
+        self.document
+            .nodes()
+            .filter(move |node| node.camera().map(|camera| camera.index()) == Some(index))
     }
 
     /// Returns the camera's projection.
-    pub fn projection(&self) -> Projection {
-        match self.json.type_.unwrap() {
+    ///
+    /// Returns `None` if the `type` is invalid or the projection it names is
+    /// not defined, which is only possible for documents loaded without
+    /// validation.
+    pub fn projection(&self) -> Option<Projection<'a>> {
+        match self.json.type_.valid()? {
             json::camera::Type::Orthographic => {
-                let json = self.json.orthographic.as_ref().unwrap();
-                Projection::Orthographic(Orthographic::new(self.document, json))
+                let json = self.json.orthographic.as_ref()?;
+                Some(Projection::Orthographic(Orthographic::new(self.document, json)))
             },
             json::camera::Type::Perspective => {
-                let json = self.json.perspective.as_ref().unwrap();
-                Projection::Perspective(Perspective::new(self.document, json))
+                let json = self.json.perspective.as_ref()?;
+                Some(Projection::Perspective(Perspective::new(self.document, json)))
             },
         }
     }
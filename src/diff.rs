@@ -0,0 +1,715 @@
+//! Semantic comparison of two glTF documents.
+//!
+//! [`diff`] compares the resolved properties of accessors, animations,
+//! cameras, images, materials, meshes, nodes, samplers, scenes, skins, and
+//! textures, so a property that is omitted in one document and set to its
+//! default value in the other is not reported. Buffers and buffer views are
+//! not compared; accessor data is compared element by element instead, so
+//! changes to the layout of the data that preserve its values are not
+//! reported either. The names of accessors are compared, since they often
+//! tag the data streams, while other objects are compared regardless of
+//! their names.
+//!
+//! ```
+//! # fn run() -> Result<(), gltf::Error> {
+//! let (a, buffers_a, _) = gltf::import("examples/Box.gltf")?;
+//! let (b, buffers_b, _) = gltf::import("examples/Box.gltf")?;
+//! let diff = gltf::diff(&a, &b, &buffers_a, &buffers_b);
+//! assert!(diff.is_empty());
+//! # Ok(())
+//! # }
+//! # fn main() {
+//! #     let _ = run().expect("runtime error");
+//! # }
+//! ```
+//!
+//! [`diff`]: fn.diff.html
+
+use std::ops::Deref;
+
+use json::{Path, Value};
+
+use crate::accessor::{encode, Dimensions};
+#[cfg(feature = "KHR_animation_pointer")]
+use crate::animation::TargetKind;
+use crate::camera::Projection;
+use crate::image::{MimeType, Source};
+use crate::{buffer, texture, Accessor, Document};
+
+/// Options for [`diff_with`].
+///
+/// [`diff_with`]: fn.diff_with.html
+#[derive(Clone, Debug)]
+pub struct Options {
+    /// The largest difference between two numbers that are considered equal.
+    ///
+    /// The default is `1.0e-6`.
+    pub tolerance: f32,
+
+    /// Whether objects are matched by name rather than by index.
+    ///
+    /// Objects are matched with the first unmatched object of the same kind
+    /// and name in the other document. Objects without a name, or whose name
+    /// does not occur in the other document, are matched by index with an
+    /// object that is unmatched as well. The default is `false`.
+    #[cfg(feature = "names")]
+    pub match_by_name: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            tolerance: 1.0e-6,
+            #[cfg(feature = "names")]
+            match_by_name: false,
+        }
+    }
+}
+
+/// A difference between two documents.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Change {
+    /// The object exists only in the second document.
+    Added,
+
+    /// The object exists only in the first document.
+    Removed,
+
+    /// A resolved property differs.
+    Modified {
+        /// The value in the first document.
+        before: Value,
+
+        /// The value in the second document.
+        after: Value,
+    },
+
+    /// Elements of an accessor differ.
+    ///
+    /// Only the elements present in both accessors are compared; a change of
+    /// the element count is reported separately.
+    Data {
+        /// The index of the first element that differs.
+        first: usize,
+
+        /// The number of elements that differ.
+        count: usize,
+    },
+
+    /// The data of an accessor can be read from only one of the documents.
+    Unreadable,
+}
+
+/// The differences between two documents, as returned by [`diff`].
+///
+/// Each change is paired with the JSON path of the object or property in the
+/// first document, except for `Change::Added`, whose path refers to the
+/// second document. For example, a changed base color factor of the first
+/// material is reported as `materials[0].pbrMetallicRoughness.baseColorFactor`.
+///
+/// Enumerations are represented by the names of their variants, such as
+/// `"Triangles"` or `"ClampToEdge"`. References to other objects are
+/// represented by the index of the referenced object in the first document;
+/// references to objects that exist only in the second document are
+/// represented by their path, such as `"meshes[3]"`.
+///
+/// [`diff`]: fn.diff.html
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Diff {
+    /// The changes, grouped by the kind of object.
+    pub changes: Vec<(Path, Change)>,
+}
+
+impl Diff {
+    /// Returns `true` if the documents are equivalent.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+/// The kinds of object that are compared.
+#[derive(Clone, Copy, Debug)]
+enum Kind {
+    Accessors,
+    Animations,
+    Cameras,
+    Images,
+    Materials,
+    Meshes,
+    Nodes,
+    Samplers,
+    Scenes,
+    Skins,
+    Textures,
+}
+
+const KINDS: [Kind; 11] = [
+    Kind::Accessors,
+    Kind::Animations,
+    Kind::Cameras,
+    Kind::Images,
+    Kind::Materials,
+    Kind::Meshes,
+    Kind::Nodes,
+    Kind::Samplers,
+    Kind::Scenes,
+    Kind::Skins,
+    Kind::Textures,
+];
+
+impl Kind {
+    /// Returns the name of the root property holding objects of this kind.
+    fn name(self) -> &'static str {
+        match self {
+            Kind::Accessors => "accessors",
+            Kind::Animations => "animations",
+            Kind::Cameras => "cameras",
+            Kind::Images => "images",
+            Kind::Materials => "materials",
+            Kind::Meshes => "meshes",
+            Kind::Nodes => "nodes",
+            Kind::Samplers => "samplers",
+            Kind::Scenes => "scenes",
+            Kind::Skins => "skins",
+            Kind::Textures => "textures",
+        }
+    }
+
+    /// Returns the names of the objects of this kind.
+    #[cfg(feature = "names")]
+    fn object_names(self, document: &Document) -> Vec<Option<&str>> {
+        fn names<T>(objects: &[T], name: fn(&T) -> &Option<json::Str>) -> Vec<Option<&str>> {
+            objects.iter().map(|x| name(x).as_deref()).collect()
+        }
+        let root = &document.0;
+        match self {
+            Kind::Accessors => names(&root.accessors, |x| &x.name),
+            Kind::Animations => names(&root.animations, |x| &x.name),
+            Kind::Cameras => names(&root.cameras, |x| &x.name),
+            Kind::Images => names(&root.images, |x| &x.name),
+            Kind::Materials => names(&root.materials, |x| &x.name),
+            Kind::Meshes => names(&root.meshes, |x| &x.name),
+            Kind::Nodes => names(&root.nodes, |x| &x.name),
+            Kind::Samplers => names(&root.samplers, |x| &x.name),
+            Kind::Scenes => names(&root.scenes, |x| &x.name),
+            Kind::Skins => names(&root.skins, |x| &x.name),
+            Kind::Textures => names(&root.textures, |x| &x.name),
+        }
+    }
+
+    /// Returns the number of objects of this kind.
+    fn count(self, document: &Document) -> usize {
+        let root = &document.0;
+        match self {
+            Kind::Accessors => root.accessors.len(),
+            Kind::Animations => root.animations.len(),
+            Kind::Cameras => root.cameras.len(),
+            Kind::Images => root.images.len(),
+            Kind::Materials => root.materials.len(),
+            Kind::Meshes => root.meshes.len(),
+            Kind::Nodes => root.nodes.len(),
+            Kind::Samplers => root.samplers.len(),
+            Kind::Scenes => root.scenes.len(),
+            Kind::Skins => root.skins.len(),
+            Kind::Textures => root.textures.len(),
+        }
+    }
+
+    /// Returns the resolved properties of every object of this kind.
+    fn resolve(self, document: &Document, refs: &Refs) -> Vec<Value> {
+        match self {
+            Kind::Accessors => document.accessors().map(|x| accessor(&x)).collect(),
+            Kind::Animations => document.animations().map(|x| animation(&x, refs)).collect(),
+            Kind::Cameras => document.cameras().map(|x| camera(&x)).collect(),
+            Kind::Images => document.images().map(|x| image(&x)).collect(),
+            Kind::Materials => document.materials().map(|x| material(&x, refs)).collect(),
+            Kind::Meshes => document.meshes().map(|x| mesh(&x, refs)).collect(),
+            Kind::Nodes => document.nodes().map(|x| node(&x, refs)).collect(),
+            Kind::Samplers => document.samplers().map(|x| sampler(&x)).collect(),
+            Kind::Scenes => document.scenes().map(|x| scene(&x, refs)).collect(),
+            Kind::Skins => document.skins().map(|x| skin(&x, refs)).collect(),
+            Kind::Textures => document.textures().map(|x| texture(&x, refs)).collect(),
+        }
+    }
+}
+
+/// Translates references to objects into their representation in a [`Diff`].
+///
+/// [`Diff`]: struct.Diff.html
+struct Refs<'a> {
+    /// For every kind, the index of the matching object in the first document
+    /// of each object of the second document, or `None` to keep indices.
+    matching: Option<&'a [Vec<Option<usize>>]>,
+}
+
+impl<'a> Refs<'a> {
+    fn get(&self, kind: Kind, index: usize) -> Value {
+        match self.matching {
+            None => Value::from(index),
+            Some(matching) => match matching[kind as usize].get(index) {
+                Some(&Some(index)) => Value::from(index),
+                _ => Value::from(format!("{}[{}]", kind.name(), index)),
+            },
+        }
+    }
+
+    fn get_opt(&self, kind: Kind, index: Option<usize>) -> Value {
+        index.map_or(Value::Null, |index| self.get(kind, index))
+    }
+}
+
+fn object(fields: Vec<(&str, Value)>) -> Value {
+    fields.into_iter().collect()
+}
+
+fn variant<T: std::fmt::Debug>(value: T) -> Value {
+    Value::from(format!("{:?}", value))
+}
+
+fn accessor(accessor: &Accessor) -> Value {
+    object(vec![
+        ("componentType", accessor.data_type().map_or(Value::Null, variant)),
+        ("count", Value::from(accessor.count())),
+        ("max", accessor.max().unwrap_or(Value::Null)),
+        ("min", accessor.min().unwrap_or(Value::Null)),
+        #[cfg(feature = "names")]
+        ("name", Value::from(accessor.name())),
+        ("normalized", Value::from(accessor.normalized())),
+        ("type", accessor.dimensions().map_or(Value::Null, variant)),
+    ])
+}
+
+fn animation(animation: &crate::Animation, refs: &Refs) -> Value {
+    let channels = animation.channels().map(|channel| {
+        let sampler = channel.sampler().map_or(Value::Null, |sampler| object(vec![
+            ("input", refs.get_opt(Kind::Accessors, sampler.input().map(|x| x.index()))),
+            ("interpolation", variant(sampler.interpolation())),
+            ("output", refs.get_opt(Kind::Accessors, sampler.output().map(|x| x.index()))),
+        ]));
+        object(vec![
+            ("sampler", sampler),
+            ("target", target(&channel.target(), refs)),
+        ])
+    });
+    object(vec![("channels", channels.collect())])
+}
+
+fn target(target: &crate::animation::Target, refs: &Refs) -> Value {
+    #[cfg(feature = "KHR_animation_pointer")]
+    {
+        if let Some(TargetKind::Pointer(pointer)) = target.kind() {
+            return object(vec![("pointer", Value::from(pointer))]);
+        }
+    }
+    object(vec![
+        ("node", refs.get_opt(Kind::Nodes, target.node().map(|x| x.index()))),
+        ("path", target.property().map_or(Value::Null, variant)),
+    ])
+}
+
+fn camera(camera: &crate::Camera) -> Value {
+    match camera.projection() {
+        Some(Projection::Orthographic(projection)) => object(vec![
+            ("orthographic", object(vec![
+                ("xmag", Value::from(projection.xmag())),
+                ("ymag", Value::from(projection.ymag())),
+                ("zfar", Value::from(projection.zfar())),
+                ("znear", Value::from(projection.znear())),
+            ])),
+        ]),
+        Some(Projection::Perspective(projection)) => object(vec![
+            ("perspective", object(vec![
+                ("aspectRatio", Value::from(projection.aspect_ratio())),
+                ("yfov", Value::from(projection.yfov())),
+                ("zfar", Value::from(projection.zfar())),
+                ("znear", Value::from(projection.znear())),
+            ])),
+        ]),
+        None => Value::Null,
+    }
+}
+
+fn image(image: &crate::Image) -> Value {
+    match image.source() {
+        Some(Source::View { mime_type, .. }) => object(vec![
+            ("mimeType", Value::from(mime_type.as_str())),
+            ("uri", Value::Null),
+        ]),
+        Some(Source::Uri { uri, mime_type }) => object(vec![
+            ("mimeType", Value::from(mime_type.map(MimeType::as_str))),
+            ("uri", Value::from(uri)),
+        ]),
+        None => Value::Null,
+    }
+}
+
+fn texture_info(info: Option<(usize, u32)>, refs: &Refs, extra: Option<(&str, f32)>) -> Value {
+    match info {
+        None => Value::Null,
+        Some((texture, tex_coord)) => {
+            let mut fields = vec![
+                ("index", refs.get(Kind::Textures, texture)),
+                ("texCoord", Value::from(tex_coord)),
+            ];
+            if let Some((name, value)) = extra {
+                fields.push((name, Value::from(value)));
+            }
+            object(fields)
+        }
+    }
+}
+
+fn info(info: Option<texture::Info>) -> Option<(usize, u32)> {
+    info.map(|x| (x.texture().index(), x.tex_coord()))
+}
+
+fn material(material: &crate::Material, refs: &Refs) -> Value {
+    let pbr = material.pbr_metallic_roughness();
+    let normal = material.normal_texture();
+    let occlusion = material.occlusion_texture();
+    #[allow(unused_mut)]
+    let mut fields = vec![
+        ("alphaCutoff", Value::from(material.alpha_cutoff())),
+        ("alphaMode", variant(material.alpha_mode())),
+        ("doubleSided", Value::from(material.double_sided())),
+        ("emissiveFactor", Value::from(&material.emissive_factor()[..])),
+        ("emissiveTexture", texture_info(info(material.emissive_texture()), refs, None)),
+        ("normalTexture", texture_info(
+            normal.as_ref().map(|x| (x.texture().index(), x.tex_coord())),
+            refs,
+            normal.as_ref().map(|x| ("scale", x.scale())),
+        )),
+        ("occlusionTexture", texture_info(
+            occlusion.as_ref().map(|x| (x.texture().index(), x.tex_coord())),
+            refs,
+            occlusion.as_ref().map(|x| ("strength", x.strength())),
+        )),
+        ("pbrMetallicRoughness", object(vec![
+            ("baseColorFactor", Value::from(&pbr.base_color_factor()[..])),
+            ("baseColorTexture", texture_info(info(pbr.base_color_texture()), refs, None)),
+            ("metallicFactor", Value::from(pbr.metallic_factor())),
+            ("metallicRoughnessTexture", texture_info(info(pbr.metallic_roughness_texture()), refs, None)),
+            ("roughnessFactor", Value::from(pbr.roughness_factor())),
+        ])),
+    ];
+    #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+    fields.push(("pbrSpecularGlossiness", match material.pbr_specular_glossiness() {
+        None => Value::Null,
+        Some(pbr) => object(vec![
+            ("diffuseFactor", Value::from(&pbr.diffuse_factor()[..])),
+            ("diffuseTexture", texture_info(info(pbr.diffuse_texture()), refs, None)),
+            ("glossinessFactor", Value::from(pbr.glossiness_factor())),
+            ("specularFactor", Value::from(&pbr.specular_factor()[..])),
+            ("specularGlossinessTexture", texture_info(info(pbr.specular_glossiness_texture()), refs, None)),
+        ]),
+    }));
+    #[cfg(feature = "KHR_materials_unlit")]
+    fields.push(("unlit", Value::from(material.unlit())));
+    object(fields)
+}
+
+fn mesh(mesh: &crate::Mesh, refs: &Refs) -> Value {
+    let primitives = mesh.primitives().map(|primitive| {
+        let attributes = primitive.attributes()
+            .map(|(semantic, accessor)| (semantic.to_string(), refs.get(Kind::Accessors, accessor.index())))
+            .collect();
+        let targets = primitive.morph_targets().map(|target| {
+            let get = |accessor: Option<Accessor>| {
+                refs.get_opt(Kind::Accessors, accessor.map(|x| x.index()))
+            };
+            object(vec![
+                ("NORMAL", get(target.normals())),
+                ("POSITION", get(target.positions())),
+                ("TANGENT", get(target.tangents())),
+            ])
+        });
+        object(vec![
+            ("attributes", attributes),
+            ("indices", refs.get_opt(Kind::Accessors, primitive.indices().map(|x| x.index()))),
+            ("material", refs.get_opt(Kind::Materials, primitive.material().index())),
+            ("mode", variant(primitive.mode())),
+            ("targets", targets.collect()),
+        ])
+    });
+    object(vec![
+        ("primitives", primitives.collect()),
+        ("weights", Value::from(mesh.weights())),
+    ])
+}
+
+fn node(node: &crate::Node, refs: &Refs) -> Value {
+    let (translation, rotation, scale) = node.transform().decomposed();
+    object(vec![
+        ("camera", refs.get_opt(Kind::Cameras, node.camera().map(|x| x.index()))),
+        ("children", node.children().map(|x| refs.get(Kind::Nodes, x.index())).collect()),
+        ("mesh", refs.get_opt(Kind::Meshes, node.mesh().map(|x| x.index()))),
+        ("rotation", Value::from(&rotation[..])),
+        ("scale", Value::from(&scale[..])),
+        ("skin", refs.get_opt(Kind::Skins, node.skin().map(|x| x.index()))),
+        ("translation", Value::from(&translation[..])),
+        ("weights", Value::from(node.weights())),
+    ])
+}
+
+fn sampler(sampler: &texture::Sampler) -> Value {
+    object(vec![
+        ("magFilter", variant(sampler.mag_filter_or_default())),
+        ("minFilter", variant(sampler.min_filter_or_default())),
+        ("wrapS", variant(sampler.wrap_s())),
+        ("wrapT", variant(sampler.wrap_t())),
+    ])
+}
+
+fn scene(scene: &crate::Scene, refs: &Refs) -> Value {
+    object(vec![
+        ("nodes", scene.nodes().map(|x| refs.get(Kind::Nodes, x.index())).collect()),
+    ])
+}
+
+fn skin(skin: &crate::Skin, refs: &Refs) -> Value {
+    object(vec![
+        ("inverseBindMatrices", refs.get_opt(Kind::Accessors, skin.inverse_bind_matrices().map(|x| x.index()))),
+        ("joints", skin.joints().map(|x| refs.get(Kind::Nodes, x.index())).collect()),
+        ("skeleton", refs.get_opt(Kind::Nodes, skin.skeleton().map(|x| x.index()))),
+    ])
+}
+
+fn texture(texture: &crate::Texture, refs: &Refs) -> Value {
+    object(vec![
+        ("sampler", refs.get_opt(Kind::Samplers, texture.sampler().index())),
+        ("source", refs.get_opt(Kind::Images, texture.source().map(|x| x.index()))),
+    ])
+}
+
+/// Matches the objects of one kind, returning for every object of the second
+/// document the index of the matching object in the first document.
+fn match_objects(kind: Kind, a: &Document, b: &Document, options: &Options) -> Vec<Option<usize>> {
+    let (count_a, count_b) = (kind.count(a), kind.count(b));
+    let mut matching = vec![None; count_b];
+    let mut matched = vec![false; count_a];
+    #[cfg(feature = "names")]
+    {
+        if options.match_by_name {
+            let names_a = kind.object_names(a);
+            for (j, name) in kind.object_names(b).into_iter().enumerate() {
+                let found = name.and_then(|name| {
+                    (0..count_a).find(|&i| !matched[i] && names_a[i] == Some(name))
+                });
+                if let Some(i) = found {
+                    matched[i] = true;
+                    matching[j] = Some(i);
+                }
+            }
+        }
+    }
+    #[cfg(not(feature = "names"))]
+    let _ = options;
+    for j in 0..count_b.min(count_a) {
+        if matching[j].is_none() && !matched[j] {
+            matched[j] = true;
+            matching[j] = Some(j);
+        }
+    }
+    matching
+}
+
+/// Compares resolved values, recording the paths of differing leaves.
+fn compare(path: Path, a: &Value, b: &Value, tolerance: f64, changes: &mut Vec<(Path, Change)>) {
+    match (a, b) {
+        (Value::Number(x), Value::Number(y)) => {
+            if let (Some(x), Some(y)) = (x.as_f64(), y.as_f64()) {
+                if (x - y).abs() <= tolerance {
+                    return;
+                }
+            }
+        },
+        (Value::Object(x), Value::Object(y)) if x.len() == y.len() && x.keys().all(|key| y.contains_key(key)) => {
+            for (key, value) in x {
+                compare(path.field(key), value, &y[key], tolerance, changes);
+            }
+            return;
+        },
+        (Value::Array(x), Value::Array(y)) if x.len() == y.len() => {
+            for (i, (x, y)) in x.iter().zip(y).enumerate() {
+                compare(path.index(i), x, y, tolerance, changes);
+            }
+            return;
+        },
+        _ => {},
+    }
+    if a != b {
+        changes.push((path, Change::Modified { before: a.clone(), after: b.clone() }));
+    }
+}
+
+/// Returns the byte slice of a buffer view.
+pub(crate) fn view_data<'b, B>(view: buffer::View, buffers: &'b [B]) -> Option<&'b [u8]>
+    where B: Deref<Target = [u8]>
+{
+    let buffer = buffers.get(view.buffer()?.index())?;
+    buffer.get(view.offset()..view.offset() + view.length())
+}
+
+/// Reads the components of every element of an accessor, including the
+/// substitutions of sparse accessors, applying normalization.
+pub(crate) fn read_components<B>(accessor: &Accessor, buffers: &[B]) -> Option<Vec<f32>>
+    where B: Deref<Target = [u8]>
+{
+    let data_type = accessor.data_type()?;
+    let (columns, rows) = match accessor.dimensions()? {
+        Dimensions::Mat2 => (2, 2),
+        Dimensions::Mat3 => (3, 3),
+        Dimensions::Mat4 => (4, 4),
+        dimensions => (1, dimensions.multiplicity()),
+    };
+    // Matrix columns are aligned to four bytes.
+    let column_size = rows * data_type.size();
+    let column_stride = if columns > 1 { (column_size + 3) & !3 } else { column_size };
+    let element_size = column_stride * columns;
+    let packed = |data: &[u8], offset: usize, bytes: &mut Vec<u8>| -> Option<()> {
+        for column in 0..columns {
+            let start = offset + column * column_stride;
+            bytes.extend_from_slice(data.get(start..start + column_size)?);
+        }
+        Some(())
+    };
+
+    let count = accessor.count();
+    let mut bytes = Vec::with_capacity(count * columns * column_size);
+    match accessor.view() {
+        Some(view) => {
+            let data = view_data(view.clone(), buffers)?;
+            let stride = view.stride().unwrap_or(element_size);
+            for i in 0..count {
+                packed(data, accessor.offset() + i * stride, &mut bytes)?;
+            }
+        },
+        None => bytes.resize(count * columns * column_size, 0),
+    }
+    let mut components = encode::decode(&bytes, data_type, accessor.normalized())?;
+
+    if let Some(sparse) = accessor.sparse() {
+        let n = columns * rows;
+        let indices = sparse.indices();
+        let index_data = view_data(indices.view()?, buffers)?;
+        let index_size = indices.index_type()?.size();
+        let value_data = view_data(sparse.values().view()?, buffers)?;
+        let mut values = Vec::new();
+        for k in 0..sparse.count() as usize {
+            let start = indices.offset() as usize + k * index_size;
+            let index = index_data.get(start..start + index_size)?
+                .iter()
+                .rev()
+                .fold(0, |index, &byte| index << 8 | byte as usize);
+            values.clear();
+            packed(value_data, sparse.values().offset() as usize + k * element_size, &mut values)?;
+            let value = encode::decode(&values, data_type, accessor.normalized())?;
+            components.get_mut(index * n..index * n + n)?.copy_from_slice(&value);
+        }
+    }
+    Some(components)
+}
+
+/// Compares the data of two accessors element by element.
+fn compare_data<A, B>(
+    a: &Accessor,
+    b: &Accessor,
+    buffers_a: &[A],
+    buffers_b: &[B],
+    tolerance: f32,
+) -> Option<Change>
+    where A: Deref<Target = [u8]>, B: Deref<Target = [u8]>
+{
+    let n = a.dimensions()?.multiplicity();
+    if Some(n) != b.dimensions().map(|x| x.multiplicity()) {
+        return None;
+    }
+    match (read_components(a, buffers_a), read_components(b, buffers_b)) {
+        (Some(x), Some(y)) => {
+            let mut first = None;
+            let mut count = 0;
+            for (i, (x, y)) in x.chunks(n).zip(y.chunks(n)).enumerate() {
+                if x.iter().zip(y).any(|(x, y)| !((x - y).abs() <= tolerance || x == y)) {
+                    first = first.or(Some(i));
+                    count += 1;
+                }
+            }
+            first.map(|first| Change::Data { first, count })
+        },
+        (None, None) => None,
+        _ => Some(Change::Unreadable),
+    }
+}
+
+/// Compares two documents with the default [`Options`].
+///
+/// `buffers_a` and `buffers_b` hold the buffer data of the documents in the
+/// order of their buffers, as returned by [`import`]. The data of accessors
+/// that cannot be read from either document is not compared.
+///
+/// See the [module documentation] for what is compared.
+///
+/// [`Options`]: diff/struct.Options.html
+/// [`import`]: fn.import.html
+/// [module documentation]: diff/index.html
+pub fn diff<A, B>(a: &Document, b: &Document, buffers_a: &[A], buffers_b: &[B]) -> Diff
+    where A: Deref<Target = [u8]>, B: Deref<Target = [u8]>
+{
+    diff_with(a, b, buffers_a, buffers_b, &Options::default())
+}
+
+/// Compares two documents with the given options.
+///
+/// See [`diff`] for details.
+///
+/// [`diff`]: fn.diff.html
+pub fn diff_with<A, B>(
+    a: &Document,
+    b: &Document,
+    buffers_a: &[A],
+    buffers_b: &[B],
+    options: &Options,
+) -> Diff
+    where A: Deref<Target = [u8]>, B: Deref<Target = [u8]>
+{
+    let matching = KINDS.iter().map(|&kind| match_objects(kind, a, b, options)).collect::<Vec<_>>();
+    let refs_a = Refs { matching: None };
+    let refs_b = Refs { matching: Some(&matching) };
+    let accessors_a = a.accessors().collect::<Vec<_>>();
+    let accessors_b = b.accessors().collect::<Vec<_>>();
+    let mut changes = Vec::new();
+    for &kind in KINDS.iter() {
+        let values_a = kind.resolve(a, &refs_a);
+        let values_b = kind.resolve(b, &refs_b);
+        let path = Path::new().field(kind.name());
+        let mut matches = vec![None; values_a.len()];
+        for (j, i) in matching[kind as usize].iter().enumerate() {
+            if let Some(i) = *i {
+                matches[i] = Some(j);
+            }
+        }
+        for (i, j) in matches.into_iter().enumerate() {
+            let j = match j {
+                Some(j) => j,
+                None => {
+                    changes.push((path.index(i), Change::Removed));
+                    continue;
+                },
+            };
+            let tolerance = f64::from(options.tolerance);
+            compare(path.index(i), &values_a[i], &values_b[j], tolerance, &mut changes);
+            if let Kind::Accessors = kind {
+                let change = compare_data(&accessors_a[i], &accessors_b[j], buffers_a, buffers_b, options.tolerance);
+                if let Some(change) = change {
+                    changes.push((path.index(i), change));
+                }
+            }
+        }
+        for (j, i) in matching[kind as usize].iter().enumerate() {
+            if i.is_none() {
+                changes.push((path.index(j), Change::Added));
+            }
+        }
+    }
+    Diff { changes }
+}
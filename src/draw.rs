@@ -0,0 +1,153 @@
+//! Draw statistics grouped by material and by mesh.
+//!
+//! [`Document::report`] counts the primitives and triangles drawn for every
+//! material and mesh when each scene of a document is rendered once, for
+//! example to list the most expensive parts of an asset in a review tool.
+//!
+//! [`Document::report`]: ../struct.Document.html#method.report
+
+use std::collections::BTreeMap;
+
+use crate::material::AlphaMode;
+use crate::scene::Visibility;
+use crate::texture::{self, MaterialUse};
+use crate::{Buffer, Document};
+
+/// The use of a material by the primitives of a document, as listed by
+/// [`Report`].
+///
+/// [`Report`]: struct.Report.html
+#[derive(Clone, Debug, PartialEq)]
+pub struct MaterialUsage {
+    /// The index of the material, or `None` for the default material of
+    /// primitives without one.
+    pub material: Option<usize>,
+
+    /// The number of primitives drawn with the material, counting each
+    /// instance of their mesh.
+    pub primitives: usize,
+
+    /// The number of triangles drawn with the material, counting each
+    /// instance of their mesh.
+    pub triangles: usize,
+
+    /// The alpha mode of the material.
+    pub alpha_mode: AlphaMode,
+
+    /// The textures referenced by the material, in slot order.
+    pub textures: Vec<MaterialUse>,
+}
+
+/// The instances and triangles of a mesh, as listed by [`Report`].
+///
+/// [`Report`]: struct.Report.html
+#[derive(Clone, Debug, PartialEq)]
+pub struct MeshUsage {
+    /// The index of the mesh.
+    pub mesh: usize,
+
+    /// The number of nodes instantiating the mesh, counted once for every
+    /// scene that reaches the node.
+    pub instances: usize,
+
+    /// The number of triangles of one instance of the mesh.
+    pub triangles_per_instance: usize,
+
+    /// The number of triangles drawn for all instances of the mesh.
+    pub triangles: usize,
+}
+
+/// Draw statistics of a document, as returned by [`Document::report`].
+///
+/// Both lists are sorted by descending number of triangles, then by
+/// ascending index.
+///
+/// [`Document::report`]: ../struct.Document.html#method.report
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Report {
+    /// Every material of the document, and the default material if a drawn
+    /// primitive has no material.
+    pub by_material: Vec<MaterialUsage>,
+
+    /// Every mesh of the document.
+    pub by_mesh: Vec<MeshUsage>,
+}
+
+/// Counts the instances and triangles of every mesh and material.
+pub(crate) fn report<'a, 's, F>(document: &'a Document, get_buffer_data: Option<F>) -> Report
+where
+    F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+{
+    let mut instances = vec![0; document.meshes().len()];
+    for scene in document.scenes() {
+        for (node, _) in scene.walk(Visibility::default()) {
+            if let Some(mesh) = node.mesh() {
+                instances[mesh.index()] += 1;
+            }
+        }
+    }
+
+    let mut by_material = document
+        .materials()
+        .map(|material| MaterialUsage {
+            material: material.index(),
+            primitives: 0,
+            triangles: 0,
+            alpha_mode: material.alpha_mode(),
+            textures: texture::material_slots(&material)
+                .into_iter()
+                .map(|(slot, texture)| MaterialUse {
+                    material: material.index().unwrap_or_default(),
+                    slot,
+                    texture: texture.index(),
+                })
+                .collect(),
+        })
+        .collect::<Vec<_>>();
+    let mut default_material = MaterialUsage {
+        material: None,
+        primitives: 0,
+        triangles: 0,
+        alpha_mode: AlphaMode::Opaque,
+        textures: Vec::new(),
+    };
+    // Primitive triangle counts, keyed by mesh and primitive index.
+    let mut triangles = BTreeMap::new();
+    for mesh in document.meshes() {
+        for primitive in mesh.primitives() {
+            let count = primitive.triangle_count(get_buffer_data.clone());
+            triangles.insert((mesh.index(), primitive.index()), count);
+            let usage = match primitive.material_index() {
+                Some(index) => match by_material.get_mut(index) {
+                    Some(usage) => usage,
+                    None => continue,
+                },
+                None => &mut default_material,
+            };
+            usage.primitives += instances[mesh.index()];
+            usage.triangles += instances[mesh.index()] * count;
+        }
+    }
+    if default_material.primitives > 0 {
+        by_material.push(default_material);
+    }
+
+    let mut by_mesh = document
+        .meshes()
+        .map(|mesh| {
+            let per_instance = triangles.range((mesh.index(), 0)..(mesh.index() + 1, 0)).map(|x| x.1).sum();
+            MeshUsage {
+                mesh: mesh.index(),
+                instances: instances[mesh.index()],
+                triangles_per_instance: per_instance,
+                triangles: instances[mesh.index()] * per_instance,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // `None` sorts before every index, so the default material comes first
+    // among materials drawing as many triangles.
+    by_material.sort_by(|a, b| b.triangles.cmp(&a.triangles).then(a.material.cmp(&b.material)));
+    by_mesh.sort_by(|a, b| b.triangles.cmp(&a.triangles).then(a.mesh.cmp(&b.mesh)));
+    Report { by_material, by_mesh }
+}
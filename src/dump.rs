@@ -0,0 +1,364 @@
+//! Human-readable outlines of documents.
+//!
+//! [`Document::dump`] writes the scenes and node hierarchy of a document,
+//! followed by tables of its accessors, animations, textures, and images. The
+//! outline is intended for triage and its format may change between releases.
+//!
+//! ```text
+//! Scene 0 (default)
+//!   Node 0 matrix [1, 0, 0, 0, 0, 0, -1, 0, 0, 1, 0, 0, 0, 0, 0, 1]
+//!     Node 1
+//!       Mesh 0 "Mesh": 1 primitive, materials: "Red"
+//! Accessors
+//!   Accessor 0: Scalar of U16 x 36, min [0], max [23]
+//! ```
+//!
+//! [`Document::dump`]: ../struct.Document.html#method.dump
+
+use std::{fmt, io};
+use std::ops::Deref;
+
+use crate::diff::{read_components, view_data};
+use crate::image::{self, Source};
+use crate::scene::Transform;
+use crate::{Accessor, Document, Node, Scene};
+
+/// Options for [`Document::dump`].
+///
+/// [`Document::dump`]: ../struct.Document.html#method.dump
+#[derive(Clone, Debug, Default)]
+pub struct DumpOptions<'a> {
+    /// The number of levels of the node hierarchy to write, or `None` for
+    /// every level. The root nodes of a scene are on level 1. Deeper nodes are
+    /// summarized by their count.
+    pub max_depth: Option<usize>,
+
+    /// The data of every buffer, or `None` if buffer data is not consulted.
+    ///
+    /// With buffer data, the ranges of accessors without `min` and `max` are
+    /// computed from their elements, and the sizes of images stored in buffer
+    /// views are read from their headers.
+    pub buffers: Option<Vec<&'a [u8]>>,
+}
+
+impl<'a> DumpOptions<'a> {
+    /// Consults the given buffer data, for example the buffers returned by
+    /// [`import`].
+    ///
+    /// [`import`]: ../fn.import.html
+    pub fn with_buffers<B>(mut self, buffers: &'a [B]) -> Self
+    where
+        B: Deref<Target = [u8]>,
+    {
+        self.buffers = Some(buffers.iter().map(|x| &**x).collect());
+        self
+    }
+}
+
+/// Expands to ` "name"` if the item is named and the `names` feature is
+/// enabled, and to an empty string otherwise.
+macro_rules! name {
+    ($item:expr) => {{
+        #[cfg(feature = "names")]
+        let name = $item.name().map(|x| format!(" {:?}", x)).unwrap_or_default();
+        #[cfg(not(feature = "names"))]
+        let name = String::new();
+        name
+    }};
+}
+
+/// Formats a list of numbers as `[a, b, c]`.
+fn list<T: ToString>(values: &[T]) -> String {
+    let strings = values.iter().map(T::to_string).collect::<Vec<_>>();
+    format!("[{}]", strings.join(", "))
+}
+
+/// Formats the `min` or `max` of an accessor.
+fn bound(value: &json::Value) -> String {
+    match value.as_array() {
+        Some(values) => list(values),
+        None => value.to_string(),
+    }
+}
+
+/// Returns `s` if `count` is not one.
+fn plural(count: usize) -> &'static str {
+    if count == 1 { "" } else { "s" }
+}
+
+fn transform(node: &Node) -> String {
+    match node.transform() {
+        Transform::Matrix { matrix } => {
+            let values = matrix.iter().flat_map(|column| column.iter()).collect::<Vec<_>>();
+            format!(" matrix {}", list(&values))
+        },
+        Transform::Decomposed { translation, rotation, scale } => {
+            let mut s = String::new();
+            if translation != [0.0; 3] {
+                s += &format!(" translation {}", list(&translation));
+            }
+            if rotation != [0.0, 0.0, 0.0, 1.0] {
+                s += &format!(" rotation {}", list(&rotation));
+            }
+            if scale != [1.0; 3] {
+                s += &format!(" scale {}", list(&scale));
+            }
+            s
+        },
+    }
+}
+
+/// Returns the minimum and maximum of every component, computed from the
+/// buffer data.
+fn data_range(accessor: &Accessor, buffers: &[&[u8]]) -> Option<(Vec<f32>, Vec<f32>)> {
+    let components = read_components(accessor, buffers)?;
+    let n = accessor.dimensions()?.multiplicity();
+    let mut chunks = components.chunks(n);
+    let first = chunks.next()?;
+    let (mut min, mut max) = (first.to_vec(), first.to_vec());
+    for chunk in chunks {
+        for (i, &x) in chunk.iter().enumerate() {
+            min[i] = min[i].min(x);
+            max[i] = max[i].max(x);
+        }
+    }
+    Some((min, max))
+}
+
+/// Returns the largest keyframe time of an animation.
+fn duration(animation: &crate::Animation, buffers: Option<&[&[u8]]>) -> Option<f32> {
+    let mut duration = None::<f32>;
+    for sampler in animation.samplers() {
+        let input = match sampler.input() {
+            Some(input) => input,
+            None => continue,
+        };
+        let max = match input.max().as_ref().and_then(|x| x.get(0)).and_then(|x| x.as_f64()) {
+            Some(max) => Some(max as f32),
+            None => buffers
+                .and_then(|buffers| read_components(&input, buffers))
+                .and_then(|times| times.into_iter().fold(None, |max: Option<f32>, x| Some(max.map_or(x, |max| max.max(x))))),
+        };
+        if let Some(max) = max {
+            duration = Some(duration.map_or(max, |x| x.max(max)));
+        }
+    }
+    duration
+}
+
+/// Returns the image of a texture, without panicking when it has none.
+fn texture_image<'a>(texture: &crate::Texture<'a>) -> Option<image::Image<'a>> {
+    texture.source_with(crate::texture::DEFAULT_SOURCE_ORDER)
+}
+
+struct Dumper<'a, 'b, W> {
+    writer: W,
+    options: &'b DumpOptions<'a>,
+}
+
+impl<'a, 'b, W: io::Write> Dumper<'a, 'b, W> {
+    fn line(&mut self, depth: usize, text: &str) -> io::Result<()> {
+        writeln!(self.writer, "{:width$}{}", "", text, width = 2 * depth)
+    }
+
+    fn scene(&mut self, scene: &Scene, is_default: bool) -> io::Result<()> {
+        let default = if is_default { " (default)" } else { "" };
+        self.line(0, &format!("Scene {}{}{}", scene.index(), name!(scene), default))?;
+        let mut ancestors = Vec::new();
+        for node in scene.nodes() {
+            self.node(&node, 1, &mut ancestors)?;
+        }
+        Ok(())
+    }
+
+    fn node(&mut self, node: &Node, depth: usize, ancestors: &mut Vec<usize>) -> io::Result<()> {
+        if ancestors.contains(&node.index()) {
+            return self.line(depth, &format!("Node {} (cycle)", node.index()));
+        }
+        self.line(depth, &format!("Node {}{}{}", node.index(), name!(node), transform(node)))?;
+        if let Some(mesh) = node.mesh() {
+            let count = mesh.primitives().len();
+            let materials = mesh.primitives()
+                .map(|primitive| {
+                    let material = primitive.material();
+                    match material.index() {
+                        Some(index) => match name!(material) {
+                            name if name.is_empty() => format!("material {}", index),
+                            name => name[1..].to_string(),
+                        },
+                        None => String::from("default"),
+                    }
+                })
+                .collect::<Vec<_>>();
+            self.line(depth + 1, &format!(
+                "Mesh {}{}: {} primitive{}, materials: {}",
+                mesh.index(),
+                name!(mesh),
+                count,
+                plural(count),
+                materials.join(", "),
+            ))?;
+        }
+        if let Some(camera) = node.camera() {
+            self.line(depth + 1, &format!("Camera {}{}", camera.index(), name!(camera)))?;
+        }
+        if let Some(skin) = node.skin() {
+            let count = skin.joints().count();
+            self.line(depth + 1, &format!("Skin {}{}: {} joint{}", skin.index(), name!(skin), count, plural(count)))?;
+        }
+        ancestors.push(node.index());
+        let children = node.children().collect::<Vec<_>>();
+        if self.options.max_depth.is_some_and(|max| depth == max) && !children.is_empty() {
+            let count = children.len();
+            self.line(depth + 1, &format!("... {} child node{}", count, plural(count)))?;
+        } else {
+            for child in &children {
+                self.node(child, depth + 1, ancestors)?;
+            }
+        }
+        ancestors.pop();
+        Ok(())
+    }
+
+    fn accessor(&mut self, accessor: &Accessor) -> io::Result<()> {
+        let mut text = format!(
+            "Accessor {}{}: {} of {} x {}",
+            accessor.index(),
+            name!(accessor),
+            or_invalid(accessor.dimensions()),
+            or_invalid(accessor.data_type()),
+            accessor.count(),
+        );
+        if accessor.normalized() {
+            text += " normalized";
+        }
+        #[cfg(feature = "names")]
+        {
+            if let Some(view) = accessor.view().filter(|x| x.name().is_some()) {
+                text += &format!(", view {}{}", view.index(), name!(view));
+            }
+        }
+        if let Some(sparse) = accessor.sparse() {
+            text += &format!(", sparse {}", sparse.count());
+        }
+        match (accessor.min(), accessor.max()) {
+            (Some(min), Some(max)) => text += &format!(", min {}, max {}", bound(&min), bound(&max)),
+            _ => {
+                let range = self.options.buffers.as_ref().and_then(|x| data_range(accessor, x));
+                if let Some((min, max)) = range {
+                    text += &format!(", data min {}, max {}", list(&min), list(&max));
+                }
+            },
+        }
+        self.line(1, &text)
+    }
+
+    fn image(&mut self, image: &image::Image) -> io::Result<()> {
+        let mut text = format!("Image {}{}: ", image.index(), name!(image));
+        let mut data = None;
+        match image.source() {
+            Some(Source::View { view, mime_type }) => {
+                text += &format!("view {} ({})", view.index(), mime_type);
+                data = self.options.buffers.as_ref().and_then(|x| view_data(view, x));
+            },
+            Some(Source::Uri { uri, .. }) if uri.starts_with("data:") => text += "data URI",
+            Some(Source::Uri { uri, .. }) => text += &format!("{:?}", uri),
+            None => text += "no source",
+        }
+        if let Some((width, height)) = data.and_then(|x| image.dimensions(x)) {
+            text += &format!(", {}x{}", width, height);
+        }
+        self.line(1, &text)
+    }
+}
+
+impl Document {
+    /// Writes a human-readable outline of the document.
+    ///
+    /// The outline lists every scene with its node hierarchy, including the
+    /// transforms, meshes, cameras, and skins of the nodes, followed by
+    /// tables of the accessors, animations, textures, and images. See the
+    /// [`dump`] module for an example.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// use gltf::dump::DumpOptions;
+    ///
+    /// let (document, buffers, _) = gltf::import("examples/Box.gltf")?;
+    /// let options = DumpOptions { max_depth: Some(2), ..Default::default() };
+    /// let mut outline = Vec::new();
+    /// document.dump(&mut outline, &options.with_buffers(&buffers))?;
+    /// assert!(outline.starts_with(b"Scene 0"));
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    ///
+    /// [`dump`]: dump/index.html
+    pub fn dump<W: io::Write>(&self, writer: W, options: &DumpOptions) -> io::Result<()> {
+        let mut dumper = Dumper { writer, options };
+        let default = self.default_scene().map(|x| x.index());
+        for scene in self.scenes() {
+            dumper.scene(&scene, Some(scene.index()) == default)?;
+        }
+
+        if !self.0.accessors.is_empty() {
+            dumper.line(0, "Accessors")?;
+            for accessor in self.accessors() {
+                dumper.accessor(&accessor)?;
+            }
+        }
+
+        if !self.0.animations.is_empty() {
+            dumper.line(0, "Animations")?;
+            for animation in self.animations() {
+                let channels = animation.channels().count();
+                let samplers = animation.samplers().count();
+                let mut text = format!(
+                    "Animation {}{}: {} channel{}, {} sampler{}",
+                    animation.index(),
+                    name!(animation),
+                    channels,
+                    plural(channels),
+                    samplers,
+                    plural(samplers),
+                );
+                if let Some(duration) = duration(&animation, options.buffers.as_deref()) {
+                    text += &format!(", {}s", duration);
+                }
+                dumper.line(1, &text)?;
+            }
+        }
+
+        if !self.0.textures.is_empty() {
+            dumper.line(0, "Textures")?;
+            for texture in self.textures() {
+                let mut text = format!("Texture {}{}:", texture.index(), name!(texture));
+                if let Some(image) = texture_image(&texture) {
+                    text += &format!(" image {}", image.index());
+                }
+                match texture.sampler().index() {
+                    Some(index) => text += &format!(", sampler {}", index),
+                    None => text += ", default sampler",
+                }
+                dumper.line(1, &text)?;
+            }
+        }
+
+        if !self.0.images.is_empty() {
+            dumper.line(0, "Images")?;
+            for image in self.images() {
+                dumper.image(&image)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Formats a value read from a document loaded without validation, which may
+/// be invalid.
+fn or_invalid<T: fmt::Debug>(value: Option<T>) -> String {
+    value.map_or_else(|| "invalid".to_string(), |x| format!("{:?}", x))
+}
@@ -0,0 +1,119 @@
+use std::io;
+
+use crate::{Error, Result};
+
+/// The UTF-8 byte order mark.
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// Returns the name of the encoding of JSON text that is not UTF-8, detected
+/// either by its byte order mark or by the pattern of zero bytes in the first
+/// characters as described by RFC 4627.
+fn unsupported_encoding(data: &[u8]) -> Option<&'static str> {
+    match *data {
+        [0xFF, 0xFE, 0, 0, ..] | [_, 0, 0, 0, ..] => Some("UTF-32LE"),
+        [0, 0, 0xFE, 0xFF, ..] | [0, 0, 0, _, ..] => Some("UTF-32BE"),
+        [0xFF, 0xFE, ..] | [_, 0, ..] => Some("UTF-16LE"),
+        [0xFE, 0xFF, ..] | [0, _, ..] => Some("UTF-16BE"),
+        _ => None,
+    }
+}
+
+/// Returns the length of the UTF-8 byte order mark at the start of `data`,
+/// which is zero if there is none.
+///
+/// Only the first four bytes of the JSON text are inspected. Text encoded as
+/// UTF-16 or UTF-32 is rejected with `Error::UnsupportedEncoding`.
+pub(crate) fn bom_length(data: &[u8]) -> Result<usize> {
+    if data.starts_with(UTF8_BOM) {
+        Ok(UTF8_BOM.len())
+    } else if let Some(encoding) = unsupported_encoding(data) {
+        Err(Error::UnsupportedEncoding(encoding))
+    } else {
+        Ok(0)
+    }
+}
+
+/// Returns whether `byte` may follow the JSON text, which is the case for
+/// whitespace, and for nulls in the JSON chunk of binary glTF.
+fn is_padding(byte: u8, chunk: bool) -> bool {
+    matches!(byte, b' ' | b'\t' | b'\n' | b'\r') || (chunk && byte == 0)
+}
+
+/// Returns the JSON chunk of binary glTF without its trailing spaces, which
+/// pad the chunk to a multiple of four bytes, and the trailing nulls some
+/// exporters pad with instead.
+fn trim_padding(data: &[u8]) -> &[u8] {
+    let length = data.iter().rposition(|&x| x != b' ' && x != 0);
+    &data[..length.map_or(0, |x| x + 1)]
+}
+
+/// Deserializes the JSON text of `data` that starts at `start`, failing with
+/// `Error::TrailingContent` if anything other than padding follows it.
+fn parse(data: &[u8], start: usize, chunk: bool) -> Result<json::Root> {
+    let (root, length) = json::deserialize::from_slice_prefix(&data[start..])?;
+    let end = start + length;
+    match data[end..].iter().position(|&x| !is_padding(x, chunk)) {
+        Some(position) => Err(Error::TrailingContent { offset: end + position }),
+        None => Ok(root),
+    }
+}
+
+/// Deserializes JSON text, skipping a UTF-8 byte order mark.
+pub(crate) fn from_slice(data: &[u8]) -> Result<json::Root> {
+    let start = bom_length(data)?;
+    parse(data, start, false)
+}
+
+/// Deserializes the JSON chunk of binary glTF, skipping a UTF-8 byte order
+/// mark and trailing padding, and reporting the offset of invalid UTF-8
+/// within the chunk.
+pub(crate) fn from_chunk(data: &[u8]) -> Result<json::Root> {
+    let data = trim_padding(data);
+    let start = bom_length(data)?;
+    if let Err(error) = std::str::from_utf8(&data[start..]) {
+        return Err(Error::InvalidUtf8 { offset: start + error.valid_up_to() });
+    }
+    parse(data, start, true)
+}
+
+/// Returns the paths of the repeated object keys of JSON text or of the JSON
+/// chunk of binary glTF.
+pub(crate) fn duplicate_keys(data: &[u8]) -> Result<Vec<json::Path>> {
+    let start = bom_length(data)?;
+    Ok(json::deserialize::duplicate_keys(&data[start..])?)
+}
+
+/// Counts the bytes read from a reader.
+struct Counted<R> {
+    reader: R,
+    count: usize,
+}
+
+impl<R: io::Read> io::Read for Counted<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.reader.read(buf)?;
+        self.count += n;
+        Ok(n)
+    }
+}
+
+/// Deserializes JSON text from a reader positioned `start` bytes into the
+/// text, reading it to the end to check that only whitespace follows, or
+/// also nulls if the text is the JSON chunk of binary glTF.
+pub(crate) fn from_reader<R: io::Read>(reader: R, start: usize, chunk: bool) -> Result<json::Root> {
+    let mut reader = Counted { reader, count: start };
+    let root = json::deserialize::from_reader_prefix(&mut reader)?;
+    let mut buffer = [0; 256];
+    loop {
+        let offset = reader.count;
+        let n = match io::Read::read(&mut reader, &mut buffer) {
+            Ok(0) => return Ok(root),
+            Ok(n) => n,
+            Err(ref error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error.into()),
+        };
+        if let Some(position) = buffer[..n].iter().position(|&x| !is_padding(x, chunk)) {
+            return Err(Error::TrailingContent { offset: offset + position });
+        }
+    }
+}
@@ -0,0 +1,226 @@
+use crate::binary::{self, Glb};
+use crate::buffer;
+use crate::uri::Scheme;
+use std::borrow::Cow;
+use std::mem;
+
+use crate::image::MimeType;
+use crate::{Document, Error, Result};
+
+/// Returns the `byteLength` bytes of buffer data belonging to the given buffer.
+fn buffer_payload<'a>(
+    document: &Document,
+    buffers: &'a [buffer::Data],
+    index: usize,
+) -> Result<&'a [u8]> {
+    let expected = document.0.buffers[index].byte_length as usize;
+    match buffers.get(index) {
+        Some(data) if data.len() >= expected => Ok(&data[..expected]),
+        data => Err(Error::BufferLength {
+            buffer: index,
+            expected,
+            actual: data.map(|x| x.len()).unwrap_or(0),
+        }),
+    }
+}
+
+/// Returns the encoded bytes and MIME type of an image referenced by URI.
+fn image_payload<'a>(
+    image: &json::Image,
+    index: usize,
+    images: &'a [Vec<u8>],
+) -> Result<(Cow<'a, [u8]>, MimeType)> {
+    let uri = image.uri.as_deref().unwrap_or("");
+    let (payload, media_type): (Cow<[u8]>, Option<&str>) = match Scheme::parse(uri) {
+        Scheme::Data(media_type, base64) => {
+            let payload = base64::decode(&base64).map_err(Error::Base64)?;
+            (payload.into(), media_type)
+        },
+        Scheme::Unsupported => return Err(Error::UnsupportedScheme),
+        _ => {
+            let payload = images
+                .get(index)
+                .ok_or(Error::MissingImageData { image: index })?;
+            (payload.as_slice().into(), None)
+        },
+    };
+    let mime_type = match image.mime_type.clone() {
+        Some(mime_type) => mime_type,
+        None => match media_type {
+            Some(media_type) => MimeType::from(media_type),
+            None => match uri.rsplit('.').next() {
+                Some("png") => MimeType::Png,
+                Some("jpg") | Some("jpeg") => MimeType::Jpeg,
+                #[cfg(feature = "EXT_texture_webp")]
+                Some("webp") => MimeType::Webp,
+                #[cfg(feature = "MSFT_texture_dds")]
+                Some("dds") => MimeType::Dds,
+                _ => return Err(Error::UnsupportedImageEncoding),
+            },
+        },
+    };
+    match mime_type {
+        MimeType::Png | MimeType::Jpeg => Ok((payload, mime_type)),
+        #[cfg(feature = "EXT_texture_webp")]
+        MimeType::Webp => Ok((payload, mime_type)),
+        #[cfg(feature = "MSFT_texture_dds")]
+        MimeType::Dds => Ok((payload, mime_type)),
+        _ => Err(Error::UnsupportedImageEncoding),
+    }
+}
+
+pub(crate) fn data_uri(media_type: &str, payload: &[u8]) -> String {
+    format!("data:{};base64,{}", media_type, base64::encode(payload))
+}
+
+fn align_to_multiple_of_four(data: &mut Vec<u8>) {
+    let length = (data.len() + 3) & !3;
+    data.resize(length, 0);
+}
+
+impl Document {
+    /// Creates a self-contained copy of the glTF JSON where every buffer and
+    /// every image stored in a separate file is embedded as a base64 data URI.
+    ///
+    /// `buffers` contains the data of every buffer in the document, as
+    /// returned by [`import`]. Since the encoded image bytes are not retained
+    /// on import, `images` contains the encoded data of every image in the
+    /// document. Entries for images that are already stored in buffer views or
+    /// data URIs are ignored and may be empty.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// let (document, buffers, _) = gltf::import("examples/Box.gltf")?;
+    /// let embedded = document.to_embedded_json(&buffers, &[])?;
+    /// assert!(embedded.buffers[0].uri.as_ref().unwrap().starts_with("data:"));
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    ///
+    /// [`import`]: fn.import.html
+    pub fn to_embedded_json(
+        &self,
+        buffers: &[buffer::Data],
+        images: &[Vec<u8>],
+    ) -> Result<json::Root> {
+        let mut root = self.0.clone();
+        for (index, buffer) in root.buffers.iter_mut().enumerate() {
+            let payload = buffer_payload(self, buffers, index)?;
+            buffer.uri = Some(data_uri("application/octet-stream", payload).into());
+        }
+        for (index, image) in root.images.iter_mut().enumerate() {
+            match image.uri.as_ref().map(|uri| Scheme::parse(uri)) {
+                None | Some(Scheme::Data(..)) => continue,
+                Some(_) => {},
+            }
+            let uri = {
+                let (payload, mime_type) = image_payload(image, index, images)?;
+                data_uri(mime_type.as_str(), &payload)
+            };
+            image.uri = Some(uri.into());
+        }
+        Ok(root)
+    }
+
+    /// Creates binary glTF containing the document and its resources.
+    ///
+    /// The data of buffer 0 is moved into the `BIN` chunk. Every image
+    /// referenced by URI is appended to the `BIN` chunk at a 4-byte aligned
+    /// offset and rewritten to reference a new buffer view. Any remaining
+    /// buffers are embedded as base64 data URIs.
+    ///
+    /// `buffers` and `images` are interpreted as in [`to_embedded_json`].
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// let (document, buffers, _) = gltf::import("examples/Box.gltf")?;
+    /// let glb = document.to_glb(&buffers, &[])?;
+    /// let bytes = glb.to_vec()?;
+    /// # let _ = bytes;
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    ///
+    /// [`to_embedded_json`]: #method.to_embedded_json
+    pub fn to_glb(
+        &self,
+        buffers: &[buffer::Data],
+        images: &[Vec<u8>],
+    ) -> Result<Glb<'static>> {
+        let mut root = self.0.clone();
+        let mut bin = if root.buffers.is_empty() {
+            Vec::new()
+        } else {
+            buffer_payload(self, buffers, 0)?.to_vec()
+        };
+        for (index, buffer) in root.buffers.iter_mut().enumerate().skip(1) {
+            let payload = buffer_payload(self, buffers, index)?;
+            buffer.uri = Some(data_uri("application/octet-stream", payload).into());
+        }
+
+        for (index, image) in root.images.iter_mut().enumerate() {
+            if image.uri.is_none() {
+                continue;
+            }
+            let (payload, mime_type) = image_payload(image, index, images)?;
+            align_to_multiple_of_four(&mut bin);
+            let view = json::buffer::View {
+                buffer: json::Index::new(0),
+                byte_length: payload.len() as u32,
+                byte_offset: Some(bin.len() as u32),
+                byte_stride: None,
+                #[cfg(feature = "names")]
+                name: None,
+                target: None,
+                extensions: None,
+                extras: Default::default(),
+            };
+            bin.extend_from_slice(&payload);
+            image.buffer_view = Some(json::Index::new(root.buffer_views.len() as u32));
+            image.mime_type = Some(mime_type);
+            image.uri = None;
+            root.buffer_views.push(view);
+        }
+
+        if root.buffers.is_empty() && !bin.is_empty() {
+            root.buffers.push(json::Buffer {
+                byte_length: 0,
+                #[cfg(feature = "names")]
+                name: None,
+                uri: None,
+                extensions: None,
+                extras: Default::default(),
+            });
+        }
+        let bin = root.buffers.first_mut().map(|buffer| {
+            buffer.byte_length = bin.len() as u32;
+            buffer.uri = None;
+            bin
+        });
+
+        let json = root.to_vec()?;
+        let mut length = mem::size_of::<binary::Header>() + 8 + json.len();
+        length = (length + 3) & !3;
+        if let Some(bin) = bin.as_ref() {
+            length += 8 + bin.len();
+            length = (length + 3) & !3;
+        }
+        let header = binary::Header {
+            magic: *b"glTF",
+            version: 2,
+            length: length as u32,
+        };
+        Ok(Glb {
+            header,
+            json: json.into(),
+            bin: bin.map(Into::into),
+            extra_chunks: Vec::new(),
+        })
+    }
+}
@@ -0,0 +1,313 @@
+//! Conversion of embedded resources to external files.
+//!
+//! [`Gltf::externalize`] is the opposite of [`Document::to_embedded_json`]:
+//! every buffer and image is written to a separate file and referenced by a
+//! relative URI, and the buffer ranges that held images are removed.
+//!
+//! [`Gltf::externalize`]: ../struct.Gltf.html#method.externalize
+//! [`Document::to_embedded_json`]: ../struct.Document.html#method.to_embedded_json
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::fs;
+
+use json::repack::Layout;
+
+use crate::image::MimeType;
+use crate::uri::Scheme;
+use crate::{Error, Gltf, Result};
+
+/// Options for [`Gltf::externalize`].
+///
+/// [`Gltf::externalize`]: ../struct.Gltf.html#method.externalize
+#[derive(Clone, Debug)]
+pub struct ExternalizeOptions {
+    /// The directory against which the relative URIs of the document are
+    /// resolved, or `None` if the document only refers to data URIs and the
+    /// `BIN` chunk of binary glTF.
+    ///
+    /// Files referenced by the document are copied to the output directory.
+    pub base: Option<PathBuf>,
+
+    /// The layout of the accessor data in the written buffers, see
+    /// [`Root::repack_buffers`]. Defaults to `Layout::Separate`.
+    ///
+    /// [`Root::repack_buffers`]: ../json/struct.Root.html#method.repack_buffers
+    pub layout: Layout,
+}
+
+impl Default for ExternalizeOptions {
+    fn default() -> Self {
+        ExternalizeOptions {
+            base: None,
+            layout: Layout::Separate,
+        }
+    }
+}
+
+/// A file written by [`Gltf::externalize`].
+///
+/// [`Gltf::externalize`]: ../struct.Gltf.html#method.externalize
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExternalFile {
+    /// The URI of the file, relative to the output directory.
+    pub uri: String,
+
+    /// The path of the written file.
+    pub path: PathBuf,
+
+    /// The length of the file in bytes.
+    pub length: usize,
+
+    /// The buffers and images that refer to the file, such as `images[2]`.
+    ///
+    /// Several objects refer to the same file when their data is identical.
+    pub references: Vec<json::Path>,
+}
+
+/// The result of [`Gltf::externalize`].
+///
+/// [`Gltf::externalize`]: ../struct.Gltf.html#method.externalize
+#[derive(Clone, Debug)]
+pub struct Externalized {
+    /// The rewritten document, which refers to the written files.
+    pub root: json::Root,
+
+    /// The written files, in the order they were written: images first,
+    /// followed by buffers.
+    pub files: Vec<ExternalFile>,
+}
+
+/// Writes files to the output directory, deduplicating them by content.
+struct Writer<'a> {
+    dir: &'a Path,
+    files: Vec<ExternalFile>,
+    /// Maps the hash of the contents of every written file to its indices in
+    /// `files`.
+    hashes: HashMap<u64, Vec<usize>>,
+}
+
+impl<'a> Writer<'a> {
+    /// Writes `data` to a new file named `stem.extension`, or returns the URI
+    /// of an earlier file with the same contents.
+    fn write(&mut self, stem: &str, extension: &str, data: &[u8], reference: json::Path) -> Result<String> {
+        let mut hasher = DefaultHasher::new();
+        data.hash(&mut hasher);
+        let hash = hasher.finish();
+        for &index in self.hashes.get(&hash).into_iter().flatten() {
+            // The written file is compared to rule out hash collisions.
+            let file = &mut self.files[index];
+            if fs::read(&file.path).map_err(Error::Io)? == data {
+                file.references.push(reference);
+                return Ok(file.uri.clone());
+            }
+        }
+
+        let mut uri = format!("{}.{}", stem, extension);
+        let mut n = 1;
+        while self.files.iter().any(|file| file.uri == uri) {
+            uri = format!("{}_{}.{}", stem, n, extension);
+            n += 1;
+        }
+        let path = self.dir.join(&uri);
+        fs::write(&path, data).map_err(Error::Io)?;
+        self.hashes.entry(hash).or_default().push(self.files.len());
+        self.files.push(ExternalFile {
+            uri: uri.clone(),
+            path,
+            length: data.len(),
+            references: vec![reference],
+        });
+        Ok(uri)
+    }
+}
+
+/// The contents of a URI.
+struct Resource {
+    data: Vec<u8>,
+
+    /// The media type of a data URI.
+    media_type: Option<String>,
+
+    /// The path of a file.
+    path: Option<PathBuf>,
+}
+
+/// Reads a data URI, or a file relative to `base`.
+fn read(uri: &str, base: Option<&Path>) -> Result<Resource> {
+    let path = match Scheme::parse(uri) {
+        Scheme::Data(media_type, base64) => {
+            let data = base64::decode(&base64).map_err(Error::Base64)?;
+            return Ok(Resource { data, media_type: media_type.map(String::from), path: None });
+        },
+        Scheme::Unsupported => return Err(Error::UnsupportedScheme),
+        Scheme::File(path) => PathBuf::from(path),
+        Scheme::Relative => base.ok_or(Error::ExternalReferenceInSliceImport)?.join(uri),
+    };
+    let data = fs::read(&path).map_err(Error::Io)?;
+    Ok(Resource { data, media_type: None, path: Some(path) })
+}
+
+/// Replaces the characters of a name that are not safe in a file name.
+fn file_stem(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect()
+}
+
+/// Returns the file extension of an image MIME type.
+fn image_extension(mime_type: Option<&MimeType>) -> &'static str {
+    match mime_type {
+        Some(MimeType::Png) => "png",
+        Some(MimeType::Jpeg) => "jpg",
+        Some(MimeType::Webp) => "webp",
+        Some(MimeType::Ktx2) => "ktx2",
+        Some(MimeType::Dds) => "dds",
+        _ => "bin",
+    }
+}
+
+/// Converts an error of `Root::repack_buffers`, which the validation of a
+/// loaded document rules out.
+fn repack_error(error: json::edit::Error) -> Error {
+    use json::edit::Error as EditError;
+    use json::validation::Error as ValidationError;
+    let (path, error) = match error {
+        EditError::IndexOutOfBounds(path) => (path, ValidationError::IndexOutOfBounds),
+        EditError::OutOfRange(path) | EditError::Invalid(path) => (path, ValidationError::Invalid),
+        _ => (json::Path::new(), ValidationError::Invalid),
+    };
+    Error::Validation(vec![(path, error)])
+}
+
+impl Gltf {
+    /// Writes every buffer and image of the document to a separate file in
+    /// `dir` and returns the rewritten document together with a manifest of
+    /// the written files.
+    ///
+    /// Images stored in buffer views or data URIs, and image files referenced
+    /// by URI, are written to files named after the image. Unnamed images are
+    /// named after the file they are copied from, or after their index, such
+    /// as `image0.png`. Images with identical contents share one file. The buffer ranges of the images are then removed, along with
+    /// any other unused ranges, by [`Root::repack_buffers`], and the buffers
+    /// are written to files such as `buffer0.bin`. `dir` is created if it does
+    /// not exist. The returned document is not written, so that callers may
+    /// choose its file name and formatting.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// use gltf::externalize::ExternalizeOptions;
+    ///
+    /// let gltf = gltf::Gltf::open("examples/Box.glb")?;
+    /// let dir = std::env::temp_dir().join(format!("gltf-externalize-doc-{}", std::process::id()));
+    /// let externalized = gltf.externalize(&dir, &ExternalizeOptions::default())?;
+    /// assert_eq!(externalized.root.buffers[0].uri.as_deref(), Some("buffer0.bin"));
+    /// assert_eq!(externalized.files[0].references[0].as_str(), "buffers[0]");
+    /// # let _ = std::fs::remove_dir_all(dir);
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    ///
+    /// [`Root::repack_buffers`]: json/struct.Root.html#method.repack_buffers
+    pub fn externalize<P>(&self, dir: P, options: &ExternalizeOptions) -> Result<Externalized>
+    where
+        P: AsRef<Path>,
+    {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir).map_err(Error::Io)?;
+        let base = options.base.as_deref();
+        let mut buffers = Vec::with_capacity(self.document.0.buffers.len());
+        for (index, buffer) in self.document.0.buffers.iter().enumerate() {
+            let mut data = match (buffer.uri.as_deref(), self.blob.as_ref()) {
+                (Some(uri), _) => read(uri, base)?.data,
+                // Only the first buffer may refer to the `BIN` chunk.
+                (None, Some(blob)) if index == 0 => blob.clone(),
+                (None, _) => return Err(Error::MissingBlob { buffer: index }),
+            };
+            let expected = buffer.byte_length as usize;
+            if data.len() < expected {
+                return Err(Error::BufferLength { buffer: index, expected, actual: data.len() });
+            }
+            data.truncate(expected);
+            buffers.push(data);
+        }
+        let mut root = self.document.0.clone();
+        let mut writer = Writer { dir, files: Vec::new(), hashes: HashMap::new() };
+
+        for index in 0..root.images.len() {
+            let image = &root.images[index];
+            let mut mime_type = image.mime_type.clone();
+            let mut stem = None;
+            let data = match (image.buffer_view, image.uri.as_deref()) {
+                (Some(view), _) => {
+                    let out_of_bounds = |path| Error::Validation(vec![(path, json::validation::Error::IndexOutOfBounds)]);
+                    let view_path = json::Path::new().field("bufferViews").index(view.value());
+                    let view = root.buffer_views.get(view.value()).ok_or_else(|| {
+                        out_of_bounds(json::Path::new().field("images").index(index).field("bufferView"))
+                    })?;
+                    let buffer = view.buffer.value();
+                    let data = buffers.get(buffer).ok_or_else(|| out_of_bounds(view_path.field("buffer")))?;
+                    let start = view.byte_offset.unwrap_or(0) as usize;
+                    let end = start.saturating_add(view.byte_length as usize);
+                    data.get(start..end)
+                        .ok_or(Error::BufferLength { buffer, expected: end, actual: data.len() })?
+                        .to_vec()
+                },
+                (None, Some(uri)) => {
+                    let resource = read(uri, base)?;
+                    if let Some(path) = resource.path.as_ref() {
+                        stem = path.file_stem().map(|x| file_stem(&x.to_string_lossy()));
+                        if mime_type.is_none() {
+                            let extension = path.extension().map(|x| x.to_string_lossy().to_lowercase());
+                            mime_type = match extension.as_deref() {
+                                Some("jpg") => Some(MimeType::Jpeg),
+                                Some("dds") => Some(MimeType::Dds),
+                                Some(extension) => Some(MimeType::from(format!("image/{}", extension).as_str())),
+                                None => None,
+                            };
+                        }
+                    }
+                    mime_type = mime_type.or_else(|| resource.media_type.as_deref().map(MimeType::from));
+                    resource.data
+                },
+                (None, None) => continue,
+            };
+            #[cfg(feature = "names")]
+            {
+                if let Some(name) = image.name.as_deref() {
+                    stem = Some(file_stem(name));
+                }
+            }
+            let stem = stem.unwrap_or_else(|| format!("image{}", index));
+            let extension = image_extension(mime_type.as_ref());
+            let path = json::Path::new().field("images").index(index);
+            let uri = writer.write(&stem, extension, &data, path)?;
+            let image = &mut root.images[index];
+            image.uri = Some(uri.into());
+            image.buffer_view = None;
+        }
+
+        // Every buffer receives a URI, so the `BIN` chunk of binary glTF is
+        // removed by repacking if only images were stored in it.
+        for buffer in &mut root.buffers {
+            buffer.uri.get_or_insert_with(|| "".into());
+        }
+        root.repack_buffers(&mut buffers, options.layout).map_err(repack_error)?;
+
+        for (index, (buffer, data)) in root.buffers.iter_mut().zip(&buffers).enumerate() {
+            #[cfg(feature = "names")]
+            let stem = buffer.name.as_deref().map(file_stem);
+            #[cfg(not(feature = "names"))]
+            let stem = None;
+            let stem = stem.unwrap_or_else(|| format!("buffer{}", index));
+            let path = json::Path::new().field("buffers").index(index);
+            buffer.uri = Some(writer.write(&stem, "bin", data, path)?.into());
+        }
+        Ok(Externalized { root, files: writer.files })
+    }
+}
@@ -0,0 +1,311 @@
+use std::f32::consts::PI;
+
+use crate::accessor::encode;
+use crate::math::Matrix4;
+use crate::scene::Transform;
+use json::accessor::{ComponentType, GenericComponentType, Type};
+use json::buffer::Target;
+use json::edit::Error;
+use json::mesh::{Mode, Semantic};
+use json::validation::Checked::Valid;
+use json::{Index, Path, Root};
+
+/// The number of line segments used to approximate a circle.
+const SEGMENTS: usize = 16;
+
+/// The depth of the far plane drawn for perspective cameras with an infinite
+/// projection, relative to the near plane.
+const INFINITE_DEPTH: f32 = 1.0;
+
+/// Summary of the gizmos added by [`append_gizmos`].
+///
+/// [`append_gizmos`]: fn.append_gizmos.html
+#[derive(Clone, Debug, Default)]
+pub struct Report {
+    /// The scene containing the gizmo nodes, or `None` if the document has no
+    /// cameras or lights attached to nodes.
+    pub scene: Option<Index<json::Scene>>,
+
+    /// The number of camera gizmos added.
+    pub cameras: usize,
+
+    /// The number of light gizmos added.
+    pub lights: usize,
+}
+
+/// A list of line segments.
+#[derive(Default)]
+struct Lines(Vec<[f32; 3]>);
+
+impl Lines {
+    fn line(&mut self, a: [f32; 3], b: [f32; 3]) {
+        self.0.push(a);
+        self.0.push(b);
+    }
+
+    /// Adds a circle around `center` in the plane spanned by the axes `u` and
+    /// `v`.
+    fn circle(&mut self, center: [f32; 3], u: usize, v: usize, radius: f32) {
+        let point = |i: usize| {
+            let angle = 2.0 * PI * i as f32 / SEGMENTS as f32;
+            let mut p = center;
+            p[u] += radius * angle.cos();
+            p[v] += radius * angle.sin();
+            p
+        };
+        for i in 0..SEGMENTS {
+            self.line(point(i), point(i + 1));
+        }
+    }
+
+    /// Adds a frustum looking down the -Z axis, given the half extents of its
+    /// near and far planes.
+    fn frustum(&mut self, near: [f32; 3], far: [f32; 3]) {
+        let corners = |[x, y, z]: [f32; 3]| [[-x, -y, -z], [x, -y, -z], [x, y, -z], [-x, y, -z]];
+        let (near, far) = (corners(near), corners(far));
+        for i in 0..4 {
+            self.line(near[i], near[(i + 1) % 4]);
+            self.line(far[i], far[(i + 1) % 4]);
+            self.line(near[i], far[i]);
+        }
+    }
+}
+
+/// Returns the lines representing the frustum of a camera.
+fn camera_lines(camera: &json::Camera) -> Lines {
+    let mut lines = Lines::default();
+    if let Some(ref p) = camera.perspective {
+        let far = p.zfar.unwrap_or(p.znear + INFINITE_DEPTH);
+        let aspect_ratio = p.aspect_ratio.unwrap_or(1.0);
+        let half_extents = |z: f32| {
+            let y = z * (p.yfov / 2.0).tan();
+            [y * aspect_ratio, y, z]
+        };
+        lines.frustum(half_extents(p.znear), half_extents(far));
+    } else if let Some(ref o) = camera.orthographic {
+        lines.frustum([o.xmag, o.ymag, o.znear], [o.xmag, o.ymag, o.zfar]);
+    }
+    lines
+}
+
+/// Returns the lines representing a light: an arrow for directional lights, a
+/// sphere for point lights, and a cone for spot lights.
+#[cfg(feature = "KHR_lights_punctual")]
+fn light_lines(light: &json::extensions::scene::khr_lights_punctual::Light) -> Lines {
+    use json::extensions::scene::khr_lights_punctual::Type;
+
+    let mut lines = Lines::default();
+    let range = light.range.unwrap_or(1.0);
+    match light.type_ {
+        Valid(Type::Directional) => {
+            let tip = [0.0, 0.0, -1.0];
+            lines.line([0.0; 3], tip);
+            for &(x, y) in &[(0.1, 0.0), (-0.1, 0.0), (0.0, 0.1), (0.0, -0.1)] {
+                lines.line(tip, [x, y, -0.8]);
+            }
+        },
+        Valid(Type::Point) => {
+            lines.circle([0.0; 3], 0, 1, range);
+            lines.circle([0.0; 3], 0, 2, range);
+            lines.circle([0.0; 3], 1, 2, range);
+        },
+        Valid(Type::Spot) => {
+            let angle = light.spot.as_ref().map_or(PI / 4.0, |x| x.outer_cone_angle);
+            let radius = range * angle.tan();
+            lines.circle([0.0, 0.0, -range], 0, 1, radius);
+            for &(x, y) in &[(1.0, 0.0), (-1.0, 0.0), (0.0, 1.0), (0.0, -1.0)] {
+                lines.line([0.0; 3], [x * radius, y * radius, -range]);
+            }
+        },
+        _ => {},
+    }
+    lines
+}
+
+/// Returns the world transform of every node, or `None` for nodes that are
+/// part of a cycle.
+fn world_matrices(root: &Root) -> Vec<Option<Matrix4>> {
+    let mut parents = vec![None; root.nodes.len()];
+    for (index, node) in root.nodes.iter().enumerate() {
+        for child in node.children.iter().flatten() {
+            if let Some(parent) = parents.get_mut(child.value()) {
+                *parent = Some(index);
+            }
+        }
+    }
+    (0..root.nodes.len())
+        .map(|mut index| {
+            let mut world = Matrix4::from_nonuniform_scale(1.0, 1.0, 1.0);
+            // Walk up the hierarchy, bounded by the number of nodes in case
+            // the hierarchy contains a cycle.
+            for _ in 0..root.nodes.len() {
+                let local = Transform::from_json(&root.nodes[index]).matrix();
+                world = Matrix4::from_array(local) * world;
+                match parents[index] {
+                    Some(parent) => index = parent,
+                    None => return Some(world),
+                }
+            }
+            None
+        })
+        .collect()
+}
+
+/// Appends line-mesh gizmos representing each camera and each
+/// `KHR_lights_punctual` light of the document to a new scene named `debug`.
+///
+/// Every node with a camera or light is given a gizmo node with the same
+/// world transform in the new scene. The gizmos are drawn with the default
+/// material in the local space of the camera or light, which looks down the
+/// -Z axis:
+///
+/// * Cameras are drawn as their view frustum. Perspective cameras with an
+///   infinite projection are drawn up to one unit beyond the near plane.
+/// * Directional lights are drawn as a unit length arrow.
+/// * Point lights are drawn as a sphere with the radius of the light range.
+/// * Spot lights are drawn as a cone with the length of the light range and
+///   the outer cone angle.
+///
+/// Lights without a range are drawn with a range of one unit.
+///
+/// The vertex data is appended to buffer 0, whose data must be contained in
+/// `buffers`. When the document has no buffers, a buffer without a URI is
+/// added, which is only valid in binary glTF.
+pub fn append_gizmos(root: &mut Root, buffers: &mut Vec<Vec<u8>>) -> Result<Report, Error> {
+    let mut report = Report::default();
+    let matrices = world_matrices(root);
+    let mut gizmos = Vec::new();
+    for (index, node) in root.nodes.iter().enumerate() {
+        let path = || Path::new().field("nodes").index(index);
+        let matrix = || matrices[index].ok_or_else(|| Error::Invalid(path()));
+        if let Some(camera) = node.camera {
+            let camera = root
+                .cameras
+                .get(camera.value())
+                .ok_or_else(|| Error::IndexOutOfBounds(path().field("camera")))?;
+            let lines = camera_lines(camera);
+            if !lines.0.is_empty() {
+                gizmos.push(("camera gizmo", matrix()?, lines));
+                report.cameras += 1;
+            }
+        }
+        #[cfg(feature = "KHR_lights_punctual")]
+        {
+            let light = node
+                .extensions
+                .as_ref()
+                .and_then(|extensions| extensions.khr_lights_punctual.as_ref());
+            if let Some(light) = light {
+                let light = root.get(light.light).ok_or_else(|| {
+                    Error::IndexOutOfBounds(path().field("extensions").field("KHR_lights_punctual").field("light"))
+                })?;
+                let lines = light_lines(light);
+                if !lines.0.is_empty() {
+                    gizmos.push(("light gizmo", matrix()?, lines));
+                    report.lights += 1;
+                }
+            }
+        }
+    }
+    if gizmos.is_empty() {
+        return Ok(report);
+    }
+
+    if root.buffers.is_empty() {
+        root.buffers.push(json::Buffer {
+            byte_length: 0,
+            #[cfg(feature = "names")]
+            name: None,
+            uri: None,
+            extensions: None,
+            extras: Default::default(),
+        });
+        buffers.push(Vec::new());
+    }
+    let data = match buffers.get_mut(0) {
+        Some(data) if data.len() >= root.buffers[0].byte_length as usize => data,
+        _ => return Err(Error::OutOfRange(Path::new().field("buffers").index(0))),
+    };
+    data.truncate(root.buffers[0].byte_length as usize);
+
+    let mut nodes = Vec::with_capacity(gizmos.len());
+    for (_name, matrix, Lines(points)) in gizmos {
+        // Every element is 12 bytes long, so the views need no padding.
+        data.resize((data.len() + 3) & !3, 0);
+        let offset = data.len();
+        let encoded = encode::encode(&points, ComponentType::F32, false)
+            .ok_or_else(|| Error::Invalid(Path::new().field("accessors").index(root.accessors.len())))?;
+        data.extend_from_slice(&encoded.data);
+        root.buffer_views.push(json::buffer::View {
+            buffer: Index::new(0),
+            byte_length: (data.len() - offset) as u32,
+            byte_offset: Some(offset as u32),
+            byte_stride: None,
+            #[cfg(feature = "names")]
+            name: None,
+            target: Some(Valid(Target::ArrayBuffer)),
+            extensions: None,
+            extras: Default::default(),
+        });
+        root.accessors.push(json::Accessor {
+            buffer_view: Some(Index::new(root.buffer_views.len() as u32 - 1)),
+            byte_offset: 0,
+            count: points.len() as u32,
+            component_type: Valid(GenericComponentType(ComponentType::F32)),
+            extensions: None,
+            extras: Default::default(),
+            type_: Valid(Type::Vec3),
+            min: Some(encoded.min),
+            max: Some(encoded.max),
+            #[cfg(feature = "names")]
+            name: None,
+            normalized: false,
+            sparse: None,
+        });
+        let primitive = json::mesh::Primitive {
+            attributes: {
+                let mut map = json::mesh::Attributes::default();
+                map.insert(Valid(Semantic::Positions), Index::new(root.accessors.len() as u32 - 1));
+                map
+            },
+            extensions: None,
+            extras: Default::default(),
+            indices: None,
+            material: None,
+            mode: Valid(Mode::Lines),
+            targets: None,
+        };
+        root.meshes.push(json::Mesh {
+            extensions: None,
+            extras: Default::default(),
+            #[cfg(feature = "names")]
+            name: Some(_name.into()),
+            primitives: vec![primitive],
+            weights: None,
+        });
+        let m = matrix.as_array();
+        let node = json::Node {
+            mesh: Some(Index::new(root.meshes.len() as u32 - 1)),
+            matrix: Some([
+                m[0][0], m[0][1], m[0][2], m[0][3],
+                m[1][0], m[1][1], m[1][2], m[1][3],
+                m[2][0], m[2][1], m[2][2], m[2][3],
+                m[3][0], m[3][1], m[3][2], m[3][3],
+            ]),
+            #[cfg(feature = "names")]
+            name: Some(_name.into()),
+            ..Default::default()
+        };
+        nodes.push(root.push_node(node));
+    }
+    root.buffers[0].byte_length = data.len() as u32;
+
+    root.scenes.push(json::Scene {
+        #[cfg(feature = "names")]
+        name: Some("debug".into()),
+        nodes,
+        ..Default::default()
+    });
+    report.scene = Some(Index::new(root.scenes.len() as u32 - 1));
+    Ok(report)
+}
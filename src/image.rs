@@ -1,5 +1,7 @@
 use crate::{buffer, Document};
 
+pub use json::image::MimeType;
+
 #[cfg(feature = "import")]
 use image_crate::DynamicImage;
 
@@ -24,6 +26,11 @@ pub enum Format {
 
     /// Blue, green, red, alpha.
     B8G8R8A8,
+
+    /// Encoded DDS data of the `MSFT_texture_dds` extension, which is passed
+    /// through without decoding. The pixel data is the whole DDS file.
+    #[cfg(feature = "MSFT_texture_dds")]
+    Dds,
 }
 
 /// Describes an image data source.
@@ -35,7 +42,7 @@ pub enum Source<'a> {
         view: buffer::View<'a>,
 
         /// The image data MIME type.
-        mime_type: &'a str,
+        mime_type: &'a MimeType,
     },
 
     /// Image data is contained in an external data source.
@@ -44,7 +51,7 @@ pub enum Source<'a> {
         uri: &'a str,
 
         /// The image data MIME type, if provided.
-        mime_type: Option<&'a str>,
+        mime_type: Option<&'a MimeType>,
     },
 }
 
@@ -61,6 +68,8 @@ pub struct Image<'a> {
     json: &'a json::image::Image,
 }
 
+impl_identity!(Image);
+
 /// Image data belonging to an imported glTF asset.
 #[cfg(feature = "import")]
 #[derive(Clone, Debug)]
@@ -100,34 +109,198 @@ impl<'a> Image<'a> {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     pub fn name(&self) -> Option<&'a str> {
-        self.json.name.as_ref().map(String::as_str)
+        self.json.name.as_deref()
     }
 
     /// Returns the image data source.
-    pub fn source(&self) -> Source<'a> {
-        if let Some(index) = self.json.buffer_view.as_ref() {
-            let view = self.document
-                .views()
-                .nth(index.value())
-                .unwrap();
-            let mime_type = self.json.mime_type
-                .as_ref()
-                .map(|x| x.0.as_str())
-                .unwrap();
-            Source::View { view, mime_type }
+    ///
+    /// Returns `None` if the image defines neither a `uri` nor a `bufferView`
+    /// with a `mimeType`, or if the buffer view does not exist, which is only
+    /// possible for documents loaded without validation.
+    pub fn source(&self) -> Option<Source<'a>> {
+        if let Some(index) = self.json.buffer_view {
+            let view = self.document.get(index)?;
+            let mime_type = self.json.mime_type.as_ref()?;
+            Some(Source::View { view, mime_type })
         } else {
-            let uri = self.json.uri.as_ref().unwrap();
-            let mime_type = self.json.mime_type.as_ref().map(|x| x.0.as_str());
-            Source::Uri { uri, mime_type }
+            let uri = self.json.uri.as_ref()?;
+            let mime_type = self.json.mime_type.as_ref();
+            Some(Source::Uri { uri, mime_type })
         }
     }
 
+    /// Returns the width and height of this image in pixels, read from the
+    /// header of its encoded `data` without decoding the pixels.
+    ///
+    /// `data` is the content of the buffer view or file of the image. See
+    /// [`probe_dimensions`] for the supported formats.
+    ///
+    /// [`probe_dimensions`]: fn.probe_dimensions.html
+    pub fn dimensions(&self, data: &[u8]) -> Option<(u32, u32)> {
+        probe_dimensions(data)
+    }
+
+    /// Returns the XMP metadata packet of this image, as defined by the
+    /// `KHR_xmp_json_ld` extension.
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    pub fn xmp_packet(&self) -> Option<&'a json::extensions::root::khr_xmp_json_ld::Packet> {
+        self.document.xmp_packet(self.json.extensions.as_ref()?.khr_xmp_json_ld.as_ref()?)
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
     }
 }
 
+/// Returns the width and height in pixels of an encoded PNG, JPEG, WebP,
+/// KTX2, or DDS image, detected by its signature, without decoding the pixels.
+///
+/// Only the header is parsed, so the rest of the data may be truncated or
+/// invalid. Returns `None` if the format is not recognized or the header is
+/// incomplete. One-dimensional KTX2 textures are reported with a height of
+/// one.
+///
+/// ```
+/// let png = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR\0\0\x01\0\0\0\0\x40";
+/// assert_eq!(gltf::image::probe_dimensions(png), Some((256, 64)));
+/// assert_eq!(gltf::image::probe_dimensions(b"GIF89a"), None);
+/// ```
+pub fn probe_dimensions(data: &[u8]) -> Option<(u32, u32)> {
+    if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+        probe_png(data)
+    } else if data.starts_with(&[0xFF, 0xD8]) {
+        probe_jpeg(data)
+    } else if data.starts_with(b"RIFF") && data.get(8..12) == Some(b"WEBP") {
+        probe_webp(data)
+    } else if data.starts_with(b"\xABKTX 20\xBB\r\n\x1a\n") {
+        probe_ktx2(data)
+    } else if data.starts_with(b"DDS ") {
+        probe_dds(data)
+    } else {
+        None
+    }
+}
+
+fn u16_be(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 2)?;
+    Some(u32::from(bytes[0]) << 8 | u32::from(bytes[1]))
+}
+
+fn u32_be(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 4)?;
+    Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+fn u16_le(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 2)?;
+    Some(u32::from(bytes[0]) | u32::from(bytes[1]) << 8)
+}
+
+fn u24_le(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 3)?;
+    Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], 0]))
+}
+
+fn u32_le(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 4)?;
+    Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+/// Reads the `IHDR` chunk, which must be the first chunk of a PNG image.
+fn probe_png(data: &[u8]) -> Option<(u32, u32)> {
+    if data.get(12..16) != Some(b"IHDR") {
+        return None;
+    }
+    Some((u32_be(data, 16)?, u32_be(data, 20)?))
+}
+
+/// Scans the marker segments of a JPEG image up to the first start of frame.
+fn probe_jpeg(data: &[u8]) -> Option<(u32, u32)> {
+    let mut offset = 2;
+    loop {
+        // Markers may be preceded by any number of fill bytes.
+        if *data.get(offset)? != 0xFF {
+            return None;
+        }
+        while *data.get(offset)? == 0xFF {
+            offset += 1;
+        }
+        let marker = data[offset];
+        offset += 1;
+        match marker {
+            // Markers without a segment: TEM and RST0 to RST7.
+            0x01 | 0xD0..=0xD7 => continue,
+            // The end of the image or the start of the scan data precede
+            // any frame header.
+            0xD8..=0xDA => return None,
+            _ => {},
+        }
+        let length = u16_be(data, offset)? as usize;
+        match marker {
+            // SOF0 to SOF15, except DHT, JPG, and DAC.
+            0xC0..=0xCF if marker != 0xC4 && marker != 0xC8 && marker != 0xCC => {
+                let height = u16_be(data, offset + 3)?;
+                let width = u16_be(data, offset + 5)?;
+                // A height of zero is defined later by a DNL segment.
+                return if width == 0 || height == 0 { None } else { Some((width, height)) };
+            },
+            _ => offset += length,
+        }
+    }
+}
+
+/// Reads the first chunk of a WebP image, which describes either a simple
+/// lossy (`VP8 `) or lossless (`VP8L`) image or the canvas of an extended
+/// (`VP8X`) image.
+fn probe_webp(data: &[u8]) -> Option<(u32, u32)> {
+    match data.get(12..16)? {
+        b"VP8 " => {
+            // A key frame starts with a three byte tag and a start code.
+            if data.get(23..26) != Some(&[0x9D, 0x01, 0x2A]) {
+                return None;
+            }
+            // The upper two bits hold the scale, which is not applied.
+            let width = u16_le(data, 26)? & 0x3FFF;
+            let height = u16_le(data, 28)? & 0x3FFF;
+            Some((width, height))
+        },
+        b"VP8L" => {
+            if *data.get(20)? != 0x2F {
+                return None;
+            }
+            let bits = u32_le(data, 21)?;
+            Some(((bits & 0x3FFF) + 1, (bits >> 14 & 0x3FFF) + 1))
+        },
+        b"VP8X" => Some((u24_le(data, 24)? + 1, u24_le(data, 27)? + 1)),
+        _ => None,
+    }
+}
+
+/// Reads the pixel dimensions of the KTX2 header.
+fn probe_ktx2(data: &[u8]) -> Option<(u32, u32)> {
+    let width = u32_le(data, 20)?;
+    let height = u32_le(data, 24)?;
+    if width == 0 {
+        return None;
+    }
+    Some((width, height.max(1)))
+}
+
+/// Reads the pixel dimensions of the `DDS_HEADER` following the magic number.
+fn probe_dds(data: &[u8]) -> Option<(u32, u32)> {
+    // The header starts with its size, which is always 124 bytes.
+    if u32_le(data, 4)? != 124 {
+        return None;
+    }
+    let height = u32_le(data, 12)?;
+    let width = u32_le(data, 16)?;
+    if width == 0 || height == 0 {
+        return None;
+    }
+    Some((width, height))
+}
+
 #[cfg(feature = "import")]
 impl Data {
     /// Note: We don't implement `From<DynamicImage>` since we don't want
@@ -146,4 +319,9 @@ impl Data {
         let pixels = image.raw_pixels();
         Data { format, width, height, pixels }
     }
+
+    /// Constructs the data of an image that is not decoded, without pixels.
+    pub(crate) fn empty() -> Self {
+        Data { format: Format::R8G8B8A8, width: 0, height: 0, pixels: Vec::new() }
+    }
 }
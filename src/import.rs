@@ -1,58 +1,25 @@
 use base64;
 use crate::buffer;
-use crate::image;
+#[cfg(all(feature = "mmap", unix))]
+use crate::{binary, buffer::Mapped};
+use crate::image::{self, MimeType};
+use crate::texture;
+use crate::instrument::{Stage, Timer};
+use crate::progress::{Event, Progress};
 use std::{fs, io};
 
+use image_crate::ImageFormat;
 use image_crate::ImageFormat::{JPEG as Jpeg, PNG as Png};
+#[cfg(feature = "EXT_texture_webp")]
+use image_crate::ImageFormat::WEBP as WebP;
 use std::path::Path;
+use crate::uri::Scheme;
 use crate::{Document, Error, Gltf, Result};
 
 /// Return type of `import`.
-type Import = (Document, Vec<buffer::Data>, Vec<image::Data>);
-
-/// Represents the set of URI schemes the importer supports.
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
-enum Scheme<'a> {
-    /// `data:[<media type>];base64,<data>`.
-    Data(Option<&'a str>, &'a str),
-
-    /// `file:[//]<absolute file path>`.
-    ///
-    /// Note: The file scheme does not implement authority.
-    File(&'a str),
-
-    /// `../foo`, etc.
-    Relative,
-
-    /// Placeholder for an unsupported URI scheme identifier.
-    Unsupported,
-}
+pub(crate) type Import = (Document, Vec<buffer::Data>, Vec<image::Data>);
 
 impl<'a> Scheme<'a> {
-    fn parse<'s>(uri: &'s str) -> Scheme<'s> {
-        if uri.contains(":") {
-            if uri.starts_with("data:") {
-                let match0 = &uri["data:".len()..].split(";base64,").nth(0);
-                let match1 = &uri["data:".len()..].split(";base64,").nth(1);
-                if match1.is_some() {
-                    Scheme::Data(Some(match0.unwrap()), match1.unwrap())
-                } else if match0.is_some() {
-                    Scheme::Data(None, match0.unwrap())
-                } else {
-                    Scheme::Unsupported
-                }
-            } else if uri.starts_with("file://") {
-                Scheme::File(&uri["file://".len()..])
-            } else if uri.starts_with("file:") {
-                Scheme::File(&uri["file:".len()..])
-            } else {
-                Scheme::Unsupported
-            }
-        } else {
-            Scheme::Relative
-        }
-    }
-
     fn read(base: &Path, uri: &str) -> Result<Vec<u8>> {
         match Scheme::parse(uri) {
             Scheme::Data(_, base64) => base64::decode(&base64).map_err(Error::Base64),
@@ -75,107 +42,309 @@ where P: AsRef<Path>
     Ok(data)
 }
 
+
+/// Reads the data behind the URIs of a document.
+///
+/// This is the only part of import that differs between [`import`] and
+/// `import_async`, which fetches every external URI before the data is
+/// processed.
+pub(crate) trait Resolve {
+    /// Reads the data of a buffer URI.
+    fn buffer(&mut self, uri: &str) -> Result<buffer::Data>;
+
+    /// Reads the encoded data of an image URI.
+    fn image(&mut self, uri: &str) -> Result<Vec<u8>>;
+}
+
+/// Reads URIs relative to a directory of the file system.
+struct FileSystem<'a>(&'a Path);
+
+impl<'a> Resolve for FileSystem<'a> {
+    fn buffer(&mut self, uri: &str) -> Result<buffer::Data> {
+        Scheme::read(self.0, uri).map(buffer::Data::Owned)
+    }
+
+    fn image(&mut self, uri: &str) -> Result<Vec<u8>> {
+        Scheme::read(self.0, uri)
+    }
+}
+
+/// Reads URIs relative to a directory of the file system, memory-mapping
+/// local buffer files.
+///
+/// Only constructed by [`import_mapped`], whose caller guarantees that the
+/// mapped files are not modified.
+#[cfg(all(feature = "mmap", unix))]
+struct MappedFileSystem<'a>(&'a Path);
+
+#[cfg(all(feature = "mmap", unix))]
+impl<'a> Resolve for MappedFileSystem<'a> {
+    fn buffer(&mut self, uri: &str) -> Result<buffer::Data> {
+        let path = match Scheme::parse(uri) {
+            Scheme::File(path) => Some(Path::new(path).to_path_buf()),
+            Scheme::Relative => Some(self.0.join(uri)),
+            _ => None,
+        };
+        if let Some(path) = path {
+            // SAFETY: upheld by the caller of `import_mapped`.
+            if let Some(map) = unsafe { Mapped::open(&path) }.map_err(Error::Io)? {
+                return Ok(buffer::Data::Mapped(map));
+            }
+        }
+        Scheme::read(self.0, uri).map(buffer::Data::Owned)
+    }
+
+    fn image(&mut self, uri: &str) -> Result<Vec<u8>> {
+        Scheme::read(self.0, uri)
+    }
+}
+
+/// Pads buffer data with zeros to a multiple of four bytes.
+///
+/// Data that is not owned is copied only when padding is required.
+fn pad_buffer_data(data: buffer::Data) -> buffer::Data {
+    let mut data = match data {
+        buffer::Data::Owned(data) => data,
+        data if data.len() % 4 == 0 => return data,
+        data => data.to_vec(),
+    };
+    while data.len() % 4 != 0 {
+        data.push(0);
+    }
+    buffer::Data::Owned(data)
+}
+
 /// Import the buffer data referenced by a glTF document.
-pub fn import_buffer_data(
+pub(crate) fn import_buffer_data(
     document: &Document,
-    base: Option<&Path>,
-    mut blob: Option<Vec<u8>>,
+    mut resolve: Option<&mut dyn Resolve>,
+    mut blob: Option<buffer::Data>,
+    progress: &mut dyn Progress,
 ) -> Result<Vec<buffer::Data>> {
     let mut buffers = Vec::new();
+    let total_buffers = document.buffers().len();
     for buffer in document.buffers() {
-        let mut data = match buffer.source() {
-            buffer::Source::Uri(uri) if base.is_some() => Scheme::read(base.unwrap(), uri),
-            buffer::Source::Bin => blob.take().ok_or(Error::MissingBlob),
-            _ => Err(Error::ExternalReferenceInSliceImport)
-        }?;
-        if data.len() < buffer.length() {
-            return Err(
-                Error::BufferLength {
-                    buffer: buffer.index(),
-                    expected: buffer.length(),
-                    actual: data.len(),
-                }
-            );
-        }
-        while data.len() % 4 != 0 {
-            data.push(0);
-        }
-        buffers.push(buffer::Data(data));
+        let timer = Timer::start();
+        let (data, uri) = match (buffer.source(), resolve.as_mut()) {
+            #[cfg(feature = "meshopt")]
+            _ if buffer.is_meshopt_fallback() => (Ok(buffer::Data::Owned(vec![0; buffer.length()])), None),
+            (buffer::Source::Uri(uri), Some(resolve)) => (resolve.buffer(uri), Some(uri)),
+            (buffer::Source::Bin, _) if buffer.index() == 0 => {
+                (blob.take().ok_or(Error::MissingBlob { buffer: 0 }), None)
+            },
+            // Only the first buffer may refer to the `BIN` chunk.
+            (buffer::Source::Bin, _) => (Err(Error::MissingBlob { buffer: buffer.index() }), None),
+            _ => (Err(Error::ExternalReferenceInSliceImport), None),
+        };
+        let data = data?;
+        timer.finish(Stage::ReadBuffer, Some(buffer.index()), uri, Some(data.len()));
+        data.validate_against(&buffer)?;
+        let bytes = data.len();
+        buffers.push(pad_buffer_data(data));
+        progress.event(Event::BufferLoaded { index: buffer.index(), bytes, total_buffers });
     }
     Ok(buffers)
 }
 
+/// How the encoded data of an image is imported.
+#[derive(Clone, Copy)]
+enum Encoding {
+    /// Decoded to pixels in the given format.
+    Decode(ImageFormat),
+
+    /// Passed through as `image::Format::Dds`.
+    #[cfg(feature = "MSFT_texture_dds")]
+    Dds,
+}
+
+/// Returns the encoding of a supported MIME type.
+///
+/// `image/webp` is only supported with the `EXT_texture_webp` feature and
+/// `image/vnd-ms.dds` only with the `MSFT_texture_dds` feature.
+fn image_format(mime_type: &MimeType) -> Result<Encoding> {
+    match mime_type {
+        MimeType::Png => Ok(Encoding::Decode(Png)),
+        MimeType::Jpeg => Ok(Encoding::Decode(Jpeg)),
+        #[cfg(feature = "EXT_texture_webp")]
+        MimeType::Webp => Ok(Encoding::Decode(WebP)),
+        #[cfg(feature = "MSFT_texture_dds")]
+        MimeType::Dds => Ok(Encoding::Dds),
+        _ => Err(Error::UnsupportedImageEncoding),
+    }
+}
+
+/// Decodes the image with the given index, or passes DDS data through with
+/// the dimensions of its header.
+fn decode_image(index: usize, data: &[u8], encoding: Encoding) -> Result<image::Data> {
+    let format = match encoding {
+        Encoding::Decode(format) => format,
+        #[cfg(feature = "MSFT_texture_dds")]
+        Encoding::Dds => {
+            let (width, height) = image::probe_dimensions(data)
+                .filter(|_| data.starts_with(b"DDS "))
+                .ok_or(Error::UnsupportedImageEncoding)?;
+            let pixels = data.to_vec();
+            return Ok(image::Data { pixels, format: image::Format::Dds, width, height });
+        },
+    };
+    let timer = Timer::start();
+    let decoded_image = image_crate::load_from_memory_with_format(data, format)?;
+    timer.finish(Stage::DecodeImage, Some(index), None, Some(data.len()));
+    Ok(image::Data::new(decoded_image))
+}
+
 /// Import the image data referenced by a glTF document.
-pub fn import_image_data(
+///
+/// Images in an encoding this build cannot decode are skipped with empty
+/// data unless a texture uses them as its source, for example the WebP
+/// source of `EXT_texture_webp` without the `EXT_texture_webp` feature when
+/// the texture provides a PNG fallback.
+pub(crate) fn import_image_data(
     document: &Document,
-    base: Option<&Path>,
+    mut resolve: Option<&mut dyn Resolve>,
     buffer_data: &[buffer::Data],
+    progress: &mut dyn Progress,
 ) -> Result<Vec<image::Data>> {
     let mut images = Vec::new();
-    for image in document.images() {
-        match image.source() {
-            image::Source::Uri { uri, mime_type } if base.is_some() => {
-                match Scheme::parse(uri) {
-                    Scheme::Data(Some(annoying_case), base64) => {
-                        let format = match annoying_case.as_ref() {
-                            "image/png" => Png,
-                            "image/jpeg" => Jpeg,
-                            _ => return Err(Error::UnsupportedImageEncoding),
-                        };
-                        let encoded_image = base64::decode(&base64).map_err(Error::Base64)?;
-                        let decoded_image = image_crate::load_from_memory_with_format(&encoded_image, format)?;
-                        images.push(image::Data::new(decoded_image));
-                        continue;
-                    },
-                    Scheme::Unsupported => return Err(Error::UnsupportedScheme),
-                    _ => {},
-                }
-                let encoded_image = Scheme::read(base.unwrap(), uri)?;
-                let encoded_format =  match mime_type {
-                    Some("image/png") => Png,
-                    Some("image/jpeg") => Jpeg,
-                    Some(_) => return Err(Error::UnsupportedImageEncoding),
-                    None => match uri.rsplit(".").next() {
-                        Some("png") => Png,
-                        Some("jpg") | Some("jpeg") => Jpeg,
-                        _ => return Err(Error::UnsupportedImageEncoding),
-                    },
-                };
-                let decoded_image = image_crate::load_from_memory_with_format(&encoded_image, encoded_format)?;
-                images.push(image::Data::new(decoded_image));
-            },
-            image::Source::View { view, mime_type } => {
-                let parent_buffer_data = &buffer_data[view.buffer().index()].0;
-                let begin = view.offset();
-                let end = begin + view.length();
-                let encoded_image = &parent_buffer_data[begin..end];
-                let encoded_format = match mime_type {
-                    "image/png" => Png,
-                    "image/jpeg" => Jpeg,
-                    _ => return Err(Error::UnsupportedImageEncoding)
-                };
-                let decoded_image = image_crate::load_from_memory_with_format(encoded_image, encoded_format)?;
-                images.push(image::Data::new(decoded_image));
-            },
-            _ => return Err(Error::ExternalReferenceInSliceImport)
+    let total = document.images().len();
+    let mut used = vec![false; total];
+    for texture in document.textures() {
+        if let Some(image) = texture.source_with(texture::DEFAULT_SOURCE_ORDER) {
+            used[image.index()] = true;
         }
     }
+    for image in document.images() {
+        let data = match import_image(&image, resolve.as_mut().map(|x| &mut **x as _), buffer_data) {
+            Err(Error::UnsupportedImageEncoding) if !used[image.index()] => image::Data::empty(),
+            result => result?,
+        };
+        images.push(data);
+        progress.event(Event::ImageDecoded { index: image.index(), total });
+    }
 
     Ok(images)
 }
 
-fn import_impl(Gltf { document, blob }: Gltf, base: Option<&Path>) -> Result<Import> {
-    let buffer_data = import_buffer_data(&document, base, blob)?;
-    let image_data = import_image_data(&document, base, &buffer_data)?;
+/// Reads and decodes the data of an image.
+fn import_image(
+    image: &image::Image,
+    resolve: Option<&mut dyn Resolve>,
+    buffer_data: &[buffer::Data],
+) -> Result<image::Data> {
+    let source = image.source().ok_or_else(|| {
+        let path = json::Path::new().field("images").index(image.index());
+        Error::Validation(vec![(path, json::validation::Error::Missing)])
+    })?;
+    match (source, resolve) {
+        (image::Source::Uri { uri, mime_type }, Some(resolve)) => {
+            if let Scheme::Data(Some(annoying_case), base64) = Scheme::parse(uri) {
+                let format = image_format(&MimeType::from(annoying_case))?;
+                let timer = Timer::start();
+                let encoded_image = base64::decode(&base64).map_err(Error::Base64)?;
+                timer.finish(Stage::ReadImage, Some(image.index()), Some(uri), Some(encoded_image.len()));
+                return decode_image(image.index(), &encoded_image, format);
+            }
+            let timer = Timer::start();
+            let encoded_image = resolve.image(uri)?;
+            timer.finish(Stage::ReadImage, Some(image.index()), Some(uri), Some(encoded_image.len()));
+            let encoded_format =  match mime_type {
+                Some(mime_type) => image_format(mime_type)?,
+                None => match uri.rsplit(".").next() {
+                    Some("png") => Encoding::Decode(Png),
+                    Some("jpg") | Some("jpeg") => Encoding::Decode(Jpeg),
+                    #[cfg(feature = "EXT_texture_webp")]
+                    Some("webp") => Encoding::Decode(WebP),
+                    #[cfg(feature = "MSFT_texture_dds")]
+                    Some("dds") => Encoding::Dds,
+                    _ => return Err(Error::UnsupportedImageEncoding),
+                },
+            };
+            decode_image(image.index(), &encoded_image, encoded_format)
+        },
+        (image::Source::View { view, mime_type }, _) => {
+            let begin = view.offset();
+            let end = begin.checked_add(view.length());
+            let encoded_image = view.buffer()
+                .and_then(|buffer| buffer_data.get(buffer.index()))
+                .zip(end)
+                .and_then(|(data, end)| data.get(begin..end))
+                .ok_or_else(|| {
+                    let path = json::Path::new().field("images").index(image.index()).field("bufferView");
+                    Error::Validation(vec![(path, json::validation::Error::IndexOutOfBounds)])
+                })?;
+            let encoded_format = image_format(mime_type)?;
+            decode_image(image.index(), encoded_image, encoded_format)
+        },
+        _ => Err(Error::ExternalReferenceInSliceImport),
+    }
+}
+
+pub(crate) fn import_impl(
+    Gltf { document, blob }: Gltf,
+    resolve: Option<&mut dyn Resolve>,
+    progress: &mut dyn Progress,
+) -> Result<Import> {
+    let blob = blob.map(buffer::Data::Owned);
+    import_document(document, blob, resolve, progress)
+}
+
+fn import_document(
+    document: Document,
+    blob: Option<buffer::Data>,
+    mut resolve: Option<&mut dyn Resolve>,
+    progress: &mut dyn Progress,
+) -> Result<Import> {
+    let timer = Timer::start();
+    #[cfg_attr(not(feature = "meshopt"), allow(unused_mut))]
+    let mut buffer_data = import_buffer_data(&document, resolve.as_mut().map(|x| &mut **x as _), blob, progress)?;
+    #[cfg(feature = "meshopt")]
+    crate::meshopt::decode_views(&document, &mut buffer_data)?;
+    let image_data = import_image_data(&document, resolve, &buffer_data, progress)?;
+    let bytes = buffer_data.iter().map(|x| x.len()).sum();
+    timer.finish(Stage::Import, None, None, Some(bytes));
+    progress.event(Event::Finished { bytes });
     let import = (document, buffer_data, image_data);
     Ok(import)
 }
 
-fn import_path(path: &Path) -> Result<Import> {
+/// Imports glTF from a memory-mapped file, sharing the mapping with the
+/// `BIN` chunk of binary glTF.
+#[cfg(all(feature = "mmap", unix))]
+fn import_map(map: Mapped, base: &Path, progress: &mut dyn Progress) -> Result<Import> {
+    let timer = Timer::start();
+    let is_glb = map.starts_with(b"glTF");
+    let (json, blob) = if is_glb {
+        let glb = binary::Glb::from_slice(&map)?;
+        let json = crate::encoding::from_chunk(&glb.json)?;
+        let blob = glb.bin.map(|bin| {
+            let offset = bin.as_ptr() as usize - map.as_ptr() as usize;
+            buffer::Data::Mapped(map.slice(offset, bin.len()))
+        });
+        (json, blob)
+    } else {
+        (crate::encoding::from_slice(&map)?, None)
+    };
+    timer.finish(Stage::Parse, None, None, Some(map.len()));
+    progress.event(Event::JsonParsed { bytes: Some(map.len()) });
+    let container = if is_glb {
+        crate::Container::Glb { bin_chunk: blob.is_some() }
+    } else {
+        crate::Container::Json
+    };
+    let document = Document::load(json, container, true)?;
+    progress.event(Event::ValidationDone);
+    import_document(document, blob, Some(&mut MappedFileSystem(base)), progress)
+}
+
+fn import_path(path: &Path, progress: &mut dyn Progress) -> Result<Import> {
     let base = path.parent().unwrap_or(Path::new("./"));
     let file = fs::File::open(path).map_err(Error::Io)?;
+    let bytes = file.metadata().ok().map(|x| x.len() as usize);
     let reader = io::BufReader::new(file);
-    import_impl(Gltf::from_reader(reader)?, Some(base))
+    let gltf = Gltf::from_reader(reader)?;
+    progress.event(Event::JsonParsed { bytes });
+    progress.event(Event::ValidationDone);
+    import_impl(gltf, Some(&mut FileSystem(base)), progress)
 }
 
 /// Import some glTF 2.0 from the file system.
@@ -200,19 +369,86 @@ fn import_path(path: &Path) -> Result<Import> {
 /// such downloading from web URLs are not handled by this function. These
 /// scenarios are delegated to the user.
 ///
+/// Images in an encoding this build cannot decode are returned with empty
+/// data and zero dimensions if no texture uses them as its source, which
+/// happens when the source of an extension such as `EXT_texture_webp` is
+/// ignored because its feature is disabled. Other such images fail with
+/// [`Error::UnsupportedImageEncoding`].
+///
+/// To memory-map the file and external buffer files instead, see
+/// [`import_mapped`].
+///
 /// You can read glTF without loading resources by constructing the [`Gltf`]
 /// (standard glTF) or [`Glb`] (binary glTF) data structures explicitly.
 ///
 /// [`Gltf`]: struct.Gltf.html
 /// [`Glb`]: struct.Glb.html
+/// [`import_mapped`]: fn.import_mapped.html
+/// [`Error::UnsupportedImageEncoding`]: enum.Error.html#variant.UnsupportedImageEncoding
 pub fn import<P>(path: P) -> Result<Import>
     where P: AsRef<Path>
 {
-    import_path(path.as_ref())
+    import_path(path.as_ref(), &mut |_: Event| {})
 }
 
-pub fn import_slice_impl(slice: &[u8]) -> Result<Import> {
-    import_impl(Gltf::from_slice(slice)?, None)
+/// Import some glTF 2.0 from the file system, reporting each completed stage
+/// to `progress`.
+///
+/// See the [`progress`] module for an example.
+///
+/// [`progress`]: progress/index.html
+pub fn import_with_progress<P>(path: P, progress: &mut dyn Progress) -> Result<Import>
+    where P: AsRef<Path>
+{
+    import_path(path.as_ref(), progress)
+}
+
+/// Import some glTF 2.0 from the file system, memory-mapping the file and any
+/// external buffer files instead of reading them into memory.
+///
+/// The `BIN` chunk of binary glTF and external buffers are returned as
+/// [`buffer::Data::Mapped`] unless they needed to be padded. Empty files are
+/// read as usual.
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// // SAFETY: the example files are not modified while mapped.
+/// let (document, buffers, images) = unsafe { gltf::import_mapped("examples/Box.glb")? };
+/// assert!(matches!(buffers[0], gltf::buffer::Data::Mapped(_)));
+/// # let _ = (document, images);
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #     run().expect("test failure");
+/// # }
+/// ```
+///
+/// # Safety
+///
+/// The mapped files must not be modified or truncated, by this or any other
+/// process, while any of the returned buffer data is alive. Otherwise the
+/// byte slices handed out by the buffer data may change or fault, which is
+/// undefined behavior.
+///
+/// [`buffer::Data::Mapped`]: buffer/enum.Data.html#variant.Mapped
+#[cfg(all(feature = "mmap", unix))]
+pub unsafe fn import_mapped<P>(path: P) -> Result<Import>
+    where P: AsRef<Path>
+{
+    let path = path.as_ref();
+    let base = path.parent().unwrap_or(Path::new("./"));
+    let progress = &mut |_: Event| {};
+    match unsafe { Mapped::open(path) }.map_err(Error::Io)? {
+        Some(map) => import_map(map, base, progress),
+        None => import_path(path, progress),
+    }
+}
+
+pub fn import_slice_impl(slice: &[u8], progress: &mut dyn Progress) -> Result<Import> {
+    let gltf = Gltf::from_slice(slice)?;
+    progress.event(Event::JsonParsed { bytes: Some(slice.len()) });
+    progress.event(Event::ValidationDone);
+    import_impl(gltf, None, progress)
 }
 
 /// Import some glTF 2.0 from a slice
@@ -237,5 +473,13 @@ pub fn import_slice_impl(slice: &[u8]) -> Result<Import> {
 pub fn import_slice<S>(slice: S) -> Result<Import>
     where S: AsRef<[u8]>
 {
-    import_slice_impl(slice.as_ref())
+    import_slice_impl(slice.as_ref(), &mut |_: Event| {})
+}
+
+/// Import some glTF 2.0 from a slice, reporting each completed stage to
+/// `progress`.
+pub fn import_slice_with_progress<S>(slice: S, progress: &mut dyn Progress) -> Result<Import>
+    where S: AsRef<[u8]>
+{
+    import_slice_impl(slice.as_ref(), progress)
 }
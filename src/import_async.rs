@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+
+use crate::buffer;
+use crate::import::{import_impl, Import, Resolve};
+use crate::progress::Event;
+use crate::uri::Scheme;
+use crate::{Error, Gltf, Result};
+
+/// Reads the data behind the URIs of a glTF asset without blocking, for
+/// example from object storage.
+///
+/// ```
+/// use std::{fs, io, path::PathBuf};
+///
+/// /// Reads URIs relative to a directory.
+/// struct Directory(PathBuf);
+///
+/// impl gltf::AsyncSource for Directory {
+///     async fn read_uri(&self, uri: &str) -> io::Result<Vec<u8>> {
+///         fs::read(self.0.join(uri))
+///     }
+/// }
+///
+/// # fn run(source: Directory) -> impl std::future::Future<Output = gltf::Result<()>> {
+/// # async move {
+/// let (document, buffers, images) = gltf::import_async_with_source(&source, "Box.gltf").await?;
+/// # let _ = (document, buffers, images);
+/// # Ok(())
+/// # }
+/// # }
+/// # fn main() {
+/// #     let _ = run(Directory(PathBuf::from("examples")));
+/// # }
+/// ```
+pub trait AsyncSource {
+    /// Reads the data of a URI as written in the document, which is never a
+    /// `data:` URI.
+    ///
+    /// Relative URIs are relative to the URI of the document, which is
+    /// passed unchanged from [`import_async_with_source`].
+    ///
+    /// [`import_async_with_source`]: fn.import_async_with_source.html
+    fn read_uri(&self, uri: &str) -> impl Future<Output = io::Result<Vec<u8>>> + Send;
+}
+
+/// Reads URIs relative to a directory of the file system, on the blocking
+/// thread pool.
+struct FileSource(PathBuf);
+
+impl AsyncSource for FileSource {
+    fn read_uri(&self, uri: &str) -> impl Future<Output = io::Result<Vec<u8>>> + Send {
+        let path = match Scheme::parse(uri) {
+            Scheme::File(path) => Ok(PathBuf::from(path)),
+            Scheme::Relative => Ok(self.0.join(uri)),
+            _ => Err(io::Error::new(io::ErrorKind::Unsupported, "unsupported URI scheme")),
+        };
+        async move { run_blocking(move || fs::read(path?)).await }
+    }
+}
+
+/// URI data fetched from an `AsyncSource` ahead of import.
+struct Fetched(HashMap<String, Vec<u8>>);
+
+impl Fetched {
+    fn read(&mut self, uri: &str) -> Result<Vec<u8>> {
+        match Scheme::parse(uri) {
+            Scheme::Data(_, base64) => base64::decode(base64).map_err(Error::Base64),
+            // Fetched once per URI, but may be referred to several times.
+            _ => self.0.get(uri).cloned().ok_or(Error::UnsupportedScheme),
+        }
+    }
+}
+
+impl Resolve for Fetched {
+    fn buffer(&mut self, uri: &str) -> Result<buffer::Data> {
+        self.read(uri).map(buffer::Data::Owned)
+    }
+
+    fn image(&mut self, uri: &str) -> Result<Vec<u8>> {
+        self.read(uri)
+    }
+}
+
+/// Runs CPU-heavy or blocking work on the blocking thread pool of the Tokio
+/// runtime, so that the executor of the calling task is free to run other
+/// tasks meanwhile.
+///
+/// Panics of the closure are resumed in the awaiting task.
+async fn run_blocking<F, T>(f: F) -> T
+    where F: FnOnce() -> T + Send + 'static, T: Send + 'static
+{
+    match tokio::task::spawn_blocking(f).await {
+        Ok(output) => output,
+        Err(error) => match error.try_into_panic() {
+            Ok(panic) => std::panic::resume_unwind(panic),
+            // Only tasks of a runtime that is shutting down are cancelled.
+            Err(error) => std::panic::resume_unwind(Box::new(error)),
+        },
+    }
+}
+
+/// Import some glTF 2.0 from an [`AsyncSource`].
+///
+/// `uri` is the URI of the glTF or binary glTF file, passed to the source
+/// as is. The document and every external buffer and image it refers to are
+/// fetched from the source one after another, each URI once. Parsing,
+/// validation, and image decoding run on the blocking thread pool of the
+/// Tokio runtime, like the reads of [`import_async`].
+///
+/// # Panics
+///
+/// Panics if not awaited within a Tokio runtime.
+///
+/// The result is the same as that of [`import`] for the same files, and the
+/// same errors are reported.
+///
+/// [`AsyncSource`]: trait.AsyncSource.html
+/// [`import_async`]: fn.import_async.html
+/// [`import`]: fn.import.html
+pub async fn import_async_with_source<S>(source: &S, uri: &str) -> Result<Import>
+    where S: AsyncSource + ?Sized
+{
+    let data = source.read_uri(uri).await.map_err(Error::Io)?;
+    import_data(source, data).await
+}
+
+/// Imports the data of a glTF or binary glTF file, fetching the URIs it
+/// refers to from `source`.
+async fn import_data<S>(source: &S, data: Vec<u8>) -> Result<Import>
+    where S: AsyncSource + ?Sized
+{
+    let gltf = run_blocking(move || Gltf::from_slice(&data)).await?;
+
+    let mut fetched = HashMap::new();
+    for reference in gltf.external_references() {
+        if !fetched.contains_key(reference.uri) {
+            let data = source.read_uri(reference.uri).await.map_err(Error::Io)?;
+            fetched.insert(reference.uri.to_string(), data);
+        }
+    }
+
+    run_blocking(move || import_impl(gltf, Some(&mut Fetched(fetched)), &mut |_: Event| {})).await
+}
+
+/// Import some glTF 2.0 from the file system without blocking the calling
+/// task.
+///
+/// Files are read, and the data is processed, on the blocking thread pool of
+/// the Tokio runtime. See [`import_async_with_source`] for reading from other
+/// places.
+///
+/// # Panics
+///
+/// Panics if not awaited within a Tokio runtime.
+///
+/// [`import_async_with_source`]: fn.import_async_with_source.html
+pub async fn import_async<P>(path: P) -> Result<Import>
+    where P: AsRef<Path>
+{
+    let path = path.as_ref().to_path_buf();
+    let base = path.parent().unwrap_or(Path::new("./")).to_path_buf();
+    let data = run_blocking(move || fs::read(path)).await.map_err(Error::Io)?;
+    import_data(&FileSource(base), data).await
+}
@@ -6,7 +6,7 @@ use crate::buffer::{Buffer, View};
 use crate::camera::Camera;
 use crate::image::Image;
 use crate::material::Material;
-use crate::mesh::Mesh;
+use crate::mesh::{self, Mesh, Primitive};
 use crate::scene::{Node, Scene};
 use crate::skin::Skin;
 use crate::texture::{Sampler, Texture};
@@ -112,6 +112,19 @@ pub struct Meshes<'a> {
     pub(crate) document: &'a Document,
 }
 
+/// An `Iterator` that visits every primitive of every mesh in a glTF asset.
+#[derive(Clone, Debug)]
+pub struct Primitives<'a> {
+    /// Internal mesh iterator.
+    pub(crate) meshes: Meshes<'a>,
+
+    /// The primitives of the current mesh.
+    pub(crate) current: Option<mesh::iter::Primitives<'a>>,
+
+    /// The number of primitives not yet visited.
+    pub(crate) remaining: usize,
+}
+
 /// An `Iterator` that visits every node in a glTF asset.
 #[derive(Clone, Debug)]
 pub struct Nodes<'a> {
@@ -301,6 +314,24 @@ impl<'a> Iterator for Meshes<'a> {
     }
 }
 
+impl<'a> ExactSizeIterator for Primitives<'a> {}
+impl<'a> Iterator for Primitives<'a> {
+    type Item = Primitive<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(primitive) = self.current.as_mut().and_then(Iterator::next) {
+                self.remaining -= 1;
+                return Some(primitive);
+            }
+            self.current = Some(self.meshes.next()?.primitives());
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
 impl<'a> ExactSizeIterator for Nodes<'a> {}
 impl<'a> Iterator for Nodes<'a> {
     type Item = Node<'a>;
@@ -1,4 +1,4 @@
-use gltf_json::Extras;
+use json::Extras;
 use crate::Document;
 
 /// A light in the scene.
@@ -14,6 +14,8 @@ pub struct Light<'a> {
     json: &'a json::extensions::scene::khr_lights_punctual::Light,
 }
 
+impl_identity!(Light);
+
 impl<'a> Light<'a> {
     /// Constructs a `Light`.
     pub(crate) fn new(document: &'a Document, index: usize, json: &'a json::extensions::scene::khr_lights_punctual::Light) -> Self {
@@ -37,7 +39,7 @@ impl<'a> Light<'a> {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     pub fn name(&self) -> Option<&'a str> {
-        self.json.name.as_ref().map(String::as_str)
+        self.json.name.as_deref()
     }
 
     /// Optional application specific data.
@@ -58,17 +60,21 @@ impl<'a> Light<'a> {
     }
 
     /// Specifies the light subcategory.
-    pub fn kind(&self) -> Kind {
+    ///
+    /// Returns `None` if the `type` is invalid or a spot light does not define
+    /// `spot`, which is only possible for documents loaded without
+    /// validation.
+    pub fn kind(&self) -> Option<Kind> {
         use json::extensions::scene::khr_lights_punctual::Type;
-        match self.json.type_.unwrap() {
-            Type::Directional => Kind::Directional,
-            Type::Point => Kind::Point,
+        match self.json.type_.valid()? {
+            Type::Directional => Some(Kind::Directional),
+            Type::Point => Some(Kind::Point),
             Type::Spot => {
-                let args = self.json.spot.as_ref().unwrap();
-                Kind::Spot {
+                let args = self.json.spot.as_ref()?;
+                Some(Kind::Spot {
                     inner_cone_angle: args.inner_cone_angle,
                     outer_cone_angle: args.outer_cone_angle,
-                }
+                })
             },
         }
     }
@@ -1,4 +1,5 @@
 #![deny(missing_docs)]
+#![deny(clippy::unwrap_used, clippy::expect_used)]
 #![allow(unknown_lints)]
 
 //! glTF 2.0 loader
@@ -70,6 +71,20 @@
 //! You can read glTF without loading resources by constructing the [`Gltf`]
 //! (standard glTF) or [`Glb`] (binary glTF) data structures explicitly.
 //!
+//! # Iteration order
+//!
+//! Iteration never depends on hashing, so parsing the same document twice
+//! visits everything in the same order:
+//!
+//! * Objects of the document, such as nodes and meshes, and arrays like
+//!   `extensionsUsed` are visited in document order.
+//! * Primitive attributes are visited in the order described by
+//!   `Primitive::attributes`.
+//! * Extension names, such as those of `Document::extension_names` and
+//!   `Material::extension_names`, are sorted by name.
+//! * Extras are kept as raw JSON in document order, and the keys of objects
+//!   parsed into a `json::Value` are sorted.
+//!
 //! [glTF 2.0]: https://www.khronos.org/gltf
 //! [`Gltf`]: struct.Gltf.html
 //! [`Glb`]: struct.Glb.html
@@ -87,6 +102,52 @@ extern crate lazy_static;
 /// Contains (de)serializable data structures that match the glTF JSON text.
 pub extern crate gltf_json as json;
 
+/// Implements `PartialEq`, `Eq`, `Hash`, `PartialOrd`, and `Ord` for a wrapper
+/// with `document` and `index` fields.
+///
+/// Wrappers with inherent `min` and `max` methods, which `Ord` would shadow,
+/// are declared with `no_ord` and only implement equality and hashing.
+macro_rules! impl_identity {
+    ($wrapper:ident, no_ord) => {
+        /// Wrappers are equal when they refer to the same index of the same
+        /// document, compared by address. Wrappers of different documents are
+        /// never equal, even if the documents are identical.
+        impl<'a> PartialEq for $wrapper<'a> {
+            fn eq(&self, other: &Self) -> bool {
+                std::ptr::eq(self.document, other.document) && self.index == other.index
+            }
+        }
+
+        impl<'a> Eq for $wrapper<'a> {}
+
+        impl<'a> std::hash::Hash for $wrapper<'a> {
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                (self.document as *const crate::Document).hash(state);
+                self.index.hash(state);
+            }
+        }
+    };
+    ($wrapper:ident) => {
+        impl_identity!($wrapper, no_ord);
+
+        impl<'a> PartialOrd for $wrapper<'a> {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        /// Wrappers are ordered by index, so that the wrappers of one document
+        /// sort deterministically. Wrappers of different documents with the
+        /// same index are ordered by the address of their document.
+        impl<'a> Ord for $wrapper<'a> {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                let address = |x: &Self| x.document as *const crate::Document;
+                self.index.cmp(&other.index).then_with(|| address(self).cmp(&address(other)))
+            }
+        }
+    };
+}
+
 /// Accessors for reading vertex attributes from buffer views.
 pub mod accessor;
 
@@ -102,6 +163,23 @@ pub mod buffer;
 /// Cameras and their projections.
 pub mod camera;
 
+pub mod diff;
+
+pub mod draw;
+
+pub mod dump;
+
+/// The reference exporter.
+#[cfg(feature = "import")]
+mod export;
+
+#[cfg(feature = "import")]
+pub mod externalize;
+
+/// Line-mesh gizmos for debugging cameras and lights.
+#[cfg(feature = "debug-gizmos")]
+pub mod gizmos;
+
 /// Images that may be used by textures.
 pub mod image;
 
@@ -109,9 +187,20 @@ pub mod image;
 #[cfg(feature = "import")]
 mod import;
 
+/// Asynchronous import.
+#[cfg(feature = "async")]
+mod import_async;
+
+#[cfg(feature = "instrument")]
+pub mod instrument;
+#[cfg(not(feature = "instrument"))]
+mod instrument;
+
 /// Iterators for walking the glTF node hierarchy.
 pub mod iter;
 
+pub mod json_size;
+
 /// Support for the `KHR_lights_punctual` extension.
 #[cfg(feature = "KHR_lights_punctual")]
 pub mod khr_lights_punctual;
@@ -119,21 +208,53 @@ pub mod khr_lights_punctual;
 /// Material properties of primitives.
 pub mod material;
 
+pub mod prelude;
+
+/// Progress reports of import.
+#[cfg(feature = "import")]
+pub mod progress;
+
+/// Detection of the text encoding of JSON.
+mod encoding;
+
 /// For internal use.
 mod math;
 
+/// Decompression of `EXT_meshopt_compression` buffer views.
+#[cfg(feature = "meshopt")]
+mod meshopt;
+
+/// Memory-mapped buffer data.
+#[cfg(all(feature = "mmap", unix))]
+mod mmap;
+
 /// Meshes and their primitives.
 pub mod mesh;
 
+pub mod requirements;
+
 /// The glTF node heirarchy.
 pub mod scene;
 
+/// `serde::Serialize` implementations for the wrapper types.
+#[cfg(feature = "serialize")]
+mod serialize;
+
 /// Mesh skinning primitives.
 pub mod skin;
 
 /// Textures and their samplers.
 pub mod texture;
 
+/// URI classification.
+pub mod uri;
+
+/// Validation reports and the severity of validation errors.
+pub mod validation;
+
+/// Lookups of indices checked by validation.
+mod valid;
+
 #[doc(inline)]
 pub use self::animation::Animation;
 #[doc(inline)]
@@ -145,6 +266,8 @@ pub use self::buffer::Buffer;
 #[doc(inline)]
 pub use self::camera::Camera;
 #[doc(inline)]
+pub use self::diff::diff;
+#[doc(inline)]
 pub use self::image::Image;
 #[cfg(feature = "import")]
 #[doc(inline)]
@@ -152,6 +275,15 @@ pub use self::import::import;
 #[cfg(feature = "import")]
 #[doc(inline)]
 pub use self::import::import_slice;
+#[cfg(feature = "import")]
+#[doc(inline)]
+pub use self::import::{import_slice_with_progress, import_with_progress};
+#[cfg(all(feature = "mmap", unix))]
+#[doc(inline)]
+pub use self::import::import_mapped;
+#[cfg(feature = "async")]
+#[doc(inline)]
+pub use self::import_async::{import_async, import_async_with_source, AsyncSource};
 #[doc(inline)]
 pub use self::material::Material;
 #[doc(inline)]
@@ -164,7 +296,8 @@ pub use self::skin::Skin;
 pub use self::texture::Texture;
 
 use std::path::Path;
-use std::{fs, io, ops, result};
+use std::sync::Arc;
+use std::{fs, hash, io, ops, result};
 
 pub(crate) trait Normalize<T> {
     fn normalize(self) -> T;
@@ -173,6 +306,34 @@ pub(crate) trait Normalize<T> {
 /// Result type for convenience.
 pub type Result<T> = result::Result<T, Error>;
 
+/// Returns the names of the glTF 2.0 extensions enabled by cargo features,
+/// in alphabetical order.
+///
+/// Validation fails for documents that list other extensions in
+/// `extensionsRequired`, with `validation::Error::UnsupportedExtension`. The
+/// `extensions` feature enables every extension.
+///
+/// `EXT_meshopt_compression` is only supported with the `meshopt` feature,
+/// which decompresses buffer views on import. Its `EXT_meshopt_compression`
+/// feature only parses the extension.
+///
+/// ```
+/// let supported = gltf::supported_extensions();
+/// assert_eq!(supported.contains(&"KHR_lights_punctual"), cfg!(feature = "KHR_lights_punctual"));
+/// ```
+pub fn supported_extensions() -> &'static [&'static str] {
+    &SUPPORTED_EXTENSIONS
+}
+
+lazy_static! {
+    /// The enabled extensions, except for those that cannot be decoded.
+    static ref SUPPORTED_EXTENSIONS: Vec<&'static str> = json::extensions::ENABLED_EXTENSIONS
+        .iter()
+        .cloned()
+        .filter(|&name| cfg!(feature = "meshopt") || name != "EXT_meshopt_compression")
+        .collect();
+}
+
 /// Represents a runtime error.
 #[derive(Debug)]
 pub enum Error {
@@ -184,7 +345,6 @@ pub enum Error {
     Binary(binary::Error),
 
     /// Buffer length does not match expected length.
-    #[cfg(feature = "import")]
     BufferLength {
         /// The index of the offending buffer.
         buffer: usize,
@@ -199,6 +359,12 @@ pub enum Error {
     /// JSON deserialization error.
     Deserialize(json::Error),
 
+    /// The JSON chunk of binary glTF contains invalid UTF-8.
+    InvalidUtf8 {
+        /// The byte offset of the invalid data within the chunk.
+        offset: usize,
+    },
+
     /// Standard I/O error.
     Io(std::io::Error),
 
@@ -206,14 +372,37 @@ pub enum Error {
     #[cfg(feature = "import")]
     Image(image_crate::ImageError),
     
+    /// The `EXT_meshopt_compression` data of a buffer view is malformed.
+    #[cfg(feature = "meshopt")]
+    Meshopt {
+        /// The index of the offending buffer view.
+        view: usize,
+    },
+
     /// The `BIN` chunk of binary glTF is referenced but does not exist.
+    MissingBlob {
+        /// The index of the buffer referring to the chunk.
+        buffer: usize,
+    },
+
+    /// The encoded data of an image was not provided for export.
     #[cfg(feature = "import")]
-    MissingBlob,
+    MissingImageData {
+        /// The index of the offending image.
+        image: usize,
+    },
 
     /// An external file is referenced in a slice only import without path
     #[cfg(feature = "import")]
     ExternalReferenceInSliceImport,
 
+    /// The JSON text is followed by content other than whitespace, or, in the
+    /// JSON chunk of binary glTF, other than padding spaces and nulls.
+    TrailingContent {
+        /// The byte offset of the content within the text or chunk.
+        offset: usize,
+    },
+
     /// Unsupported image encoding.
     #[cfg(feature = "import")]
     UnsupportedImageEncoding,
@@ -222,6 +411,10 @@ pub enum Error {
     #[cfg(feature = "import")]
     UnsupportedScheme,
 
+    /// The JSON is not encoded as UTF-8, for example because it starts with a
+    /// UTF-16 byte order mark. Contains the name of the detected encoding.
+    UnsupportedEncoding(&'static str),
+
     /// glTF validation error.
     Validation(Vec<(json::Path, json::validation::Error)>),
 }
@@ -238,7 +431,80 @@ pub struct Gltf {
 
 /// glTF JSON wrapper.
 #[derive(Clone, Debug)]
-pub struct Document(json::Root);
+pub struct Document(
+    json::Root,
+    animation::ChannelMap,
+    Vec<validation::Entry>,
+    Option<json::identity::Identities>,
+);
+
+/// The file a document was loaded from, which determines the checks of its
+/// buffers, see [`Document::load`].
+///
+/// [`Document::load`]: struct.Document.html#method.load
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Container {
+    /// Standard glTF, whose buffers need a `uri`.
+    Json,
+
+    /// Binary glTF, whose first buffer may refer to the `BIN` chunk.
+    Glb {
+        /// Whether the file has a `BIN` chunk.
+        bin_chunk: bool,
+    },
+
+    /// Pre-deserialized JSON, whose buffers are not checked.
+    Unknown,
+}
+
+/// Options for loading glTF with [`Gltf::from_slice_with_options`].
+///
+/// The default options load glTF as [`Gltf::from_slice`] does.
+///
+/// [`Gltf::from_slice_with_options`]: struct.Gltf.html#method.from_slice_with_options
+/// [`Gltf::from_slice`]: struct.Gltf.html#method.from_slice
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ParseOptions {
+    /// Assign a stable ID to every object of the document, see
+    /// [`Document::identities`].
+    ///
+    /// [`Document::identities`]: struct.Document.html#method.identities
+    pub track_identity: bool,
+
+    /// Fail with `Error::Validation` if an object of the JSON repeats a key,
+    /// listing each repeated member as `validation::Error::DuplicateKey`.
+    ///
+    /// Otherwise repeated fields of glTF objects fail to deserialize with an
+    /// error that gives a line and column but no path, and the last of the
+    /// repeated keys of maps such as `attributes` is kept.
+    pub reject_duplicate_keys: bool,
+
+    /// Make equal names and URIs share one allocation, see
+    /// [`json::Root::intern_strings`].
+    ///
+    /// [`json::Root::intern_strings`]: json/struct.Root.html#method.intern_strings
+    pub intern_strings: bool,
+}
+
+impl ParseOptions {
+    /// Sets whether to assign a stable ID to every object of the document.
+    pub fn track_identity(mut self, track_identity: bool) -> Self {
+        self.track_identity = track_identity;
+        self
+    }
+
+    /// Sets whether to fail on repeated object keys.
+    pub fn reject_duplicate_keys(mut self, reject_duplicate_keys: bool) -> Self {
+        self.reject_duplicate_keys = reject_duplicate_keys;
+        self
+    }
+
+    /// Sets whether equal names and URIs share one allocation.
+    pub fn intern_strings(mut self, intern_strings: bool) -> Self {
+        self.intern_strings = intern_strings;
+        self
+    }
+}
 
 impl Gltf {
     /// Convenience function that loads glTF from the file system.
@@ -252,26 +518,67 @@ impl Gltf {
         Ok(gltf)
     }
 
+    /// Returns the `BIN` chunk of binary glTF, which holds the data of the
+    /// first buffer if its [`source`] is `Source::Bin`.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// use gltf::buffer::Source;
+    ///
+    /// let gltf = gltf::Gltf::open("examples/Box.glb")?;
+    /// let buffer = gltf.buffers().next().unwrap();
+    /// assert!(matches!(buffer.source(), Source::Bin));
+    /// assert_eq!(gltf.blob().map(|x| x.len() >= buffer.length()), Some(true));
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    ///
+    /// [`source`]: buffer/struct.Buffer.html#method.source
+    pub fn blob(&self) -> Option<&[u8]> {
+        self.blob.as_deref()
+    }
+
+    /// Takes the `BIN` chunk of binary glTF, leaving `None` in its place.
+    pub fn take_blob(&mut self) -> Option<Vec<u8>> {
+        self.blob.take()
+    }
+
     /// Loads glTF from a reader without performing validation checks.
-    pub fn from_reader_without_validation<R>(mut reader: R) -> Result<Self>
+    pub fn from_reader_without_validation<R>(reader: R) -> Result<Self>
+    where
+        R: io::Read + io::Seek
+    {
+        Self::load_reader(reader, false)
+    }
+
+    /// Loads glTF from a reader, see [`Document::load`].
+    ///
+    /// [`Document::load`]: struct.Document.html#method.load
+    fn load_reader<R>(mut reader: R, validate: bool) -> Result<Self>
     where
         R: io::Read + io::Seek
     {
+        let timer = instrument::Timer::start();
         let mut magic = [0u8; 4];
         reader.read_exact(&mut magic)?;
-        reader.seek(io::SeekFrom::Start(0))?;
         let (json, blob): (json::Root, Option<Vec<u8>>);
         if magic.starts_with(b"glTF") {
+            reader.seek(io::SeekFrom::Start(0))?;
             let mut glb = binary::Glb::from_reader(reader)?;
             // TODO: use `json::from_reader` instead of `json::from_slice`
-            json = json::deserialize::from_slice(&glb.json)?;
+            json = encoding::from_chunk(&glb.json)?;
             blob = glb.bin.take().map(|x| x.into_owned());
         } else {
-            json = json::deserialize::from_reader(reader)?;
+            let start = encoding::bom_length(&magic)?;
+            reader.seek(io::SeekFrom::Start(start as u64))?;
+            json = encoding::from_reader(reader, start, false)?;
             blob = None;
         };
-        let document = Document::from_json_without_validation(json);
-        Ok(Gltf { document, blob })
+        timer.finish(instrument::Stage::Parse, None, None, None);
+        Self::load(json, blob, magic.starts_with(b"glTF"), validate)
     }
 
     /// Loads glTF from a reader.
@@ -279,33 +586,216 @@ impl Gltf {
     where
         R: io::Read + io::Seek,
     {
-        let gltf = Self::from_reader_without_validation(reader)?;
-        let _ = gltf.document.validate()?;
-        Ok(gltf)
+        Self::load_reader(reader, true)
+    }
+
+    /// Loads glTF from a reader without performing validation checks or
+    /// buffering the entire document in memory.
+    ///
+    /// See [`from_reader_streaming`] for details.
+    ///
+    /// [`from_reader_streaming`]: #method.from_reader_streaming
+    pub fn from_reader_streaming_without_validation<R>(reader: R) -> Result<Self>
+    where
+        R: io::Read,
+    {
+        Self::load_reader_streaming(reader, false)
+    }
+
+    /// Loads glTF from a reader without buffering the entire document in
+    /// memory, see [`Document::load`].
+    ///
+    /// [`Document::load`]: struct.Document.html#method.load
+    fn load_reader_streaming<R>(mut reader: R, validate: bool) -> Result<Self>
+    where
+        R: io::Read,
+    {
+        use io::Read;
+        let timer = instrument::Timer::start();
+        let mut magic = [0u8; 4];
+        reader.read_exact(&mut magic)?;
+        let (json, blob): (json::Root, Option<Vec<u8>>);
+        if magic.starts_with(b"glTF") {
+            let reader = io::Cursor::new(magic).chain(reader);
+            let (root, bin) = binary::from_reader_streaming(reader)?;
+            json = root;
+            blob = bin;
+        } else {
+            let start = encoding::bom_length(&magic)?;
+            let reader = io::Cursor::new(&magic[start..]).chain(reader);
+            json = encoding::from_reader(reader, start, false)?;
+            blob = None;
+        };
+        timer.finish(instrument::Stage::Parse, None, None, None);
+        Self::load(json, blob, magic.starts_with(b"glTF"), validate)
+    }
+
+    /// Loads glTF from a reader without buffering the entire document in
+    /// memory.
+    ///
+    /// Unlike [`from_reader`], the reader is not required to implement `Seek`.
+    /// The JSON of both standard and binary glTF is deserialized directly
+    /// from the stream and only the `BIN` chunk of binary glTF is copied into
+    /// memory, which lowers peak memory usage for large documents.
+    ///
+    /// Reads are not buffered, therefore readers such as `File` should be
+    /// wrapped in a `BufReader`.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// let file = std::fs::File::open("examples/Box.glb")?;
+    /// let gltf = gltf::Gltf::from_reader_streaming(std::io::BufReader::new(file))?;
+    /// assert!(gltf.blob.is_some());
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    ///
+    /// [`from_reader`]: #method.from_reader
+    pub fn from_reader_streaming<R>(reader: R) -> Result<Self>
+    where
+        R: io::Read,
+    {
+        Self::load_reader_streaming(reader, true)
     }
 
     /// Loads glTF from a slice of bytes without performing validation
     /// checks.
     pub fn from_slice_without_validation(slice: &[u8]) -> Result<Self> {
+        Self::load_slice(slice, false)
+    }
+
+    /// Loads glTF from a slice of bytes, see [`Document::load`].
+    ///
+    /// [`Document::load`]: struct.Document.html#method.load
+    fn load_slice(slice: &[u8], validate: bool) -> Result<Self> {
+        let timer = instrument::Timer::start();
         let (json, blob): (json::Root, Option<Vec<u8>>);
         if slice.starts_with(b"glTF") {
             let mut glb = binary::Glb::from_slice(slice)?;
-            json = json::deserialize::from_slice(&glb.json)?;
+            json = encoding::from_chunk(&glb.json)?;
             blob = glb.bin.take().map(|x| x.into_owned());
         } else {
-            json = json::deserialize::from_slice(slice)?;
+            json = encoding::from_slice(slice)?;
             blob = None;
         };
-        let document = Document::from_json_without_validation(json);
-        Ok(Gltf { document, blob })
+        timer.finish(instrument::Stage::Parse, None, None, Some(slice.len()));
+        Self::load(json, blob, slice.starts_with(b"glTF"), validate)
     }
 
     /// Loads glTF from a slice of bytes.
     pub fn from_slice(slice: &[u8]) -> Result<Self> {
-        let gltf = Self::from_slice_without_validation(slice)?;
-        let _ = gltf.document.validate()?;
+        Self::load_slice(slice, true)
+    }
+
+    /// Loads glTF from a JSON value without performing validation checks.
+    pub fn from_value_without_validation(value: json::Value) -> Result<Self> {
+        Self::load_value(value, false)
+    }
+
+    /// Loads glTF from a JSON value, see [`Document::load`].
+    ///
+    /// [`Document::load`]: struct.Document.html#method.load
+    fn load_value(value: json::Value, validate: bool) -> Result<Self> {
+        let timer = instrument::Timer::start();
+        let json = <json::Root as std::convert::TryFrom<_>>::try_from(value)?;
+        timer.finish(instrument::Stage::Parse, None, None, None);
+        Self::load(json, None, false, validate)
+    }
+
+    /// Loads parsed glTF and the `BIN` chunk of binary glTF, see
+    /// [`Document::load`].
+    ///
+    /// [`Document::load`]: struct.Document.html#method.load
+    fn load(json: json::Root, blob: Option<Vec<u8>>, is_glb: bool, validate: bool) -> Result<Self> {
+        let container = if is_glb {
+            Container::Glb { bin_chunk: blob.is_some() }
+        } else {
+            Container::Json
+        };
+        let document = Document::load(json, container, validate)?;
+        Ok(Gltf { document, blob })
+    }
+
+    /// Loads glTF from a JSON value, for example one embedded in a larger
+    /// document, without writing it out and parsing it again.
+    ///
+    /// The document is validated like one loaded by [`from_slice`], with the
+    /// same report. Deserialization errors have no line and column, since a
+    /// value has no source text. Extras are written anew from the value, see
+    /// [`json::Root::try_from`].
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// let envelope: gltf::json::Value = gltf::json::deserialize::from_str(r#"{
+    ///     "id": 7,
+    ///     "asset": {"asset": {"version": "2.0"}, "nodes": [{}]}
+    /// }"#)?;
+    /// let gltf = gltf::Gltf::from_value(envelope["asset"].clone())?;
+    /// assert_eq!(gltf.nodes().len(), 1);
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    ///
+    /// [`from_slice`]: #method.from_slice
+    /// [`json::Root::try_from`]: json/struct.Root.html
+    pub fn from_value(value: json::Value) -> Result<Self> {
+        Self::load_value(value, true)
+    }
+
+    /// Loads glTF from a slice of bytes with the given options.
+    ///
+    /// ```
+    /// # use gltf::json::validation::Error;
+    /// let json = br#"{"asset": {"version": "2.0"}, "nodes": [{"name": "a", "name": "b"}]}"#;
+    /// let options = gltf::ParseOptions::default().reject_duplicate_keys(true);
+    /// match gltf::Gltf::from_slice_with_options(json, &options) {
+    ///     Err(gltf::Error::Validation(errors)) => {
+    ///         assert_eq!(errors[0].0.as_str(), "nodes[0].name");
+    ///         assert_eq!(errors[0].1, Error::DuplicateKey);
+    ///     },
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    pub fn from_slice_with_options(slice: &[u8], options: &ParseOptions) -> Result<Self> {
+        if options.reject_duplicate_keys {
+            let duplicates = if slice.starts_with(b"glTF") {
+                encoding::duplicate_keys(&binary::Glb::from_slice(slice)?.json)?
+            } else {
+                encoding::duplicate_keys(slice)?
+            };
+            if !duplicates.is_empty() {
+                let errors = duplicates.into_iter().map(|path| (path, json::validation::Error::DuplicateKey));
+                return Err(Error::Validation(errors.collect()));
+            }
+        }
+        let mut gltf = Self::from_slice(slice)?;
+        if options.intern_strings {
+            gltf.document.0.intern_strings();
+        }
+        if options.track_identity {
+            gltf.document.3 = Some(json::identity::Identities::new(&gltf.document.0));
+        }
         Ok(gltf)
     }
+
+    /// Returns `true` if the asset can be loaded without reading any external
+    /// files or URLs.
+    ///
+    /// This is the case when every buffer is either a `data:` URI or the `BIN`
+    /// chunk of binary glTF and every image is either a `data:` URI or stored
+    /// in a buffer view. Nothing is loaded; see [`external_references`] for the
+    /// URIs that prevent an asset from being self-contained.
+    ///
+    /// [`external_references`]: struct.Document.html#method.external_references
+    pub fn is_self_contained(&self) -> bool {
+        uri::is_self_contained(self)
+    }
 }
 
 impl ops::Deref for Gltf {
@@ -321,18 +811,146 @@ impl ops::DerefMut for Gltf {
     }
 }
 
+impl AsRef<Document> for Gltf {
+    fn as_ref(&self) -> &Document {
+        &self.document
+    }
+}
+
+/// A reference-counted handle to a [`Gltf`], returned by
+/// [`Gltf::into_shared`].
+///
+/// Cloning the handle does not copy the JSON or the binary payload, so each
+/// system or entity that needs the document can own a handle. Two handles are
+/// equal, and hash equally, if and only if they refer to the same document.
+///
+/// The wrapper types such as [`Mesh`] borrow the document they were obtained
+/// from, so a `Mesh<'a>` obtained through a handle cannot outlive the borrow
+/// of that handle. To refer to an object from a long-lived value, store a
+/// handle together with the index of the object and look the object up when
+/// it is needed:
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// struct PendingUpload {
+///     gltf: gltf::SharedGltf,
+///     mesh: usize,
+/// }
+///
+/// let gltf = gltf::Gltf::open("examples/Box.gltf")?.into_shared();
+/// let pending = PendingUpload { gltf: gltf.clone(), mesh: 0 };
+/// let mesh = pending.gltf.meshes().nth(pending.mesh).unwrap();
+/// assert_eq!(mesh.primitives().count(), 1);
+/// assert_eq!(pending.gltf, gltf);
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #     run().expect("test failure");
+/// # }
+/// ```
+///
+/// Code that processes a document within one scope can use a `Gltf` or
+/// `Document` directly and keep the borrowed wrappers.
+///
+/// [`Gltf`]: struct.Gltf.html
+/// [`Gltf::into_shared`]: struct.Gltf.html#method.into_shared
+/// [`Mesh`]: mesh/struct.Mesh.html
+#[derive(Clone, Debug)]
+pub struct SharedGltf(Arc<Gltf>);
+
+impl Gltf {
+    /// Moves the glTF into a reference-counted handle that can be cloned
+    /// without copying the document.
+    pub fn into_shared(self) -> SharedGltf {
+        SharedGltf(Arc::new(self))
+    }
+}
+
+impl SharedGltf {
+    /// Returns the glTF if this is its only handle, and the handle otherwise.
+    pub fn try_unwrap(self) -> result::Result<Gltf, Self> {
+        Arc::try_unwrap(self.0).map_err(SharedGltf)
+    }
+}
+
+impl From<Gltf> for SharedGltf {
+    fn from(gltf: Gltf) -> Self {
+        gltf.into_shared()
+    }
+}
+
+impl From<Arc<Gltf>> for SharedGltf {
+    fn from(gltf: Arc<Gltf>) -> Self {
+        SharedGltf(gltf)
+    }
+}
+
+impl From<SharedGltf> for Arc<Gltf> {
+    fn from(gltf: SharedGltf) -> Self {
+        gltf.0
+    }
+}
+
+impl ops::Deref for SharedGltf {
+    type Target = Gltf;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<Document> for SharedGltf {
+    fn as_ref(&self) -> &Document {
+        &self.0.document
+    }
+}
+
+impl PartialEq for SharedGltf {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for SharedGltf {}
+
+impl hash::Hash for SharedGltf {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.0).hash(state)
+    }
+}
+
+impl AsRef<Document> for Document {
+    fn as_ref(&self) -> &Document {
+        self
+    }
+}
+
 impl Document {
     /// Loads glTF from pre-deserialized JSON.
+    ///
+    /// The loaders that perform validation tolerate animations of nodes that
+    /// define their transform with a `matrix`, which the specification
+    /// forbids: the matrix of every such node is replaced with translation,
+    /// rotation, and scale, so the animation can apply. Each replacement is
+    /// listed in the [`validation_report`] as `Error::MatrixDecomposed`,
+    /// which is a warning and fails [`validate_strict`].
+    ///
+    /// NaN and infinite numbers in node transforms, material factors, the
+    /// aspect ratio and far plane of perspective cameras, and the bounds of
+    /// float accessors are replaced with safe defaults, such as the identity
+    /// scale, and listed as `Error::NonFiniteReplaced`, which is a warning as
+    /// well. Other non-finite numbers fail validation with
+    /// `Error::NonFinite`.
+    ///
+    /// [`validation_report`]: #method.validation_report
+    /// [`validate_strict`]: #method.validate_strict
     pub fn from_json(json: json::Root) -> Result<Self> {
-        let document = Self::from_json_without_validation(json);
-        let _ = document.validate()?;
-        Ok(document)
+        Self::load(json, Container::Unknown, true)
     }
 
     /// Loads glTF from pre-deserialized JSON without performing
     /// validation checks.
     pub fn from_json_without_validation(json: json::Root) -> Self {
-        Document(json)
+        Document(json, Default::default(), Vec::new(), None)
     }
 
     /// Unwraps the glTF document.
@@ -340,15 +958,288 @@ impl Document {
         self.0
     }
 
+    /// Unwraps the glTF document and its stable object IDs, if tracked.
+    pub fn into_json_with_identities(self) -> (json::Root, Option<json::identity::Identities>) {
+        (self.0, self.3)
+    }
+
+    /// Returns the stable object IDs of the document, if tracked.
+    ///
+    /// IDs are tracked for documents loaded with
+    /// `ParseOptions::track_identity` or given IDs with
+    /// [`set_identities`]. The editing methods of `json::Root` that remove
+    /// or reorder objects report a `json::identity::Remap`, with which the
+    /// IDs are carried over to the edited document:
+    ///
+    /// ```
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// use gltf::json::{self, identity::Kind};
+    /// use gltf::{Document, Gltf, ParseOptions};
+    ///
+    /// let json = br#"{
+    ///     "asset": {"version": "2.0"},
+    ///     "nodes": [{}, {}],
+    ///     "scenes": [{"nodes": [0]}, {"nodes": [1]}]
+    /// }"#;
+    /// let options = ParseOptions::default().track_identity(true);
+    /// let gltf = Gltf::from_slice_with_options(json, &options)?;
+    /// let id = gltf.identities().unwrap().get(Kind::Node, 1).unwrap();
+    ///
+    /// let (root, identities) = gltf.document.into_json_with_identities();
+    /// let mut identities = identities.unwrap();
+    /// let (extracted, report) = root.extract_scene(json::Index::new(1))?;
+    /// identities.apply(&report.remap, &extracted);
+    ///
+    /// let mut document = Document::from_json(extracted)?;
+    /// document.set_identities(Some(identities));
+    /// assert_eq!(document.identities().unwrap().find(id), Some((Kind::Node, 0)));
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    ///
+    /// [`set_identities`]: #method.set_identities
+    pub fn identities(&self) -> Option<&json::identity::Identities> {
+        self.3.as_ref()
+    }
+
+    /// Sets the stable object IDs of the document, see [`identities`].
+    ///
+    /// [`identities`]: #method.identities
+    pub fn set_identities(&mut self, identities: Option<json::identity::Identities>) {
+        self.3 = identities;
+    }
+
+    /// Loads parsed glTF. This is the pipeline shared by every loader.
+    ///
+    /// Checks the `BIN` chunk of binary glTF and the URIs of the buffers
+    /// against the `container` of the document. If `validate` is `true`,
+    /// then replaces non-finite numbers and decomposes the matrices of
+    /// animated nodes, as described for [`from_json`], and validates the
+    /// document.
+    ///
+    /// [`from_json`]: #method.from_json
+    pub(crate) fn load(json: json::Root, container: Container, validate: bool) -> Result<Self> {
+        let mut document = Self::from_json_without_validation(json);
+        match container {
+            Container::Json => document.check_buffer_uris(false),
+            Container::Glb { bin_chunk } => {
+                document.check_bin_chunk(bin_chunk)?;
+                document.check_buffer_uris(true);
+            },
+            Container::Unknown => {},
+        }
+        if validate {
+            document.replace_non_finite();
+            document.decompose_animated_matrices();
+            document.validate()?;
+        }
+        Ok(document)
+    }
+
+    /// Decomposes the matrices of animated nodes, see [`from_json`].
+    ///
+    /// [`from_json`]: #method.from_json
+    fn decompose_animated_matrices(&mut self) {
+        let decomposed = scene::decompose_animated_matrices(&mut self.0);
+        self.2.extend(decomposed.into_iter().map(|index| validation::Entry {
+            path: json::Path::new().field("nodes").index(index).field("matrix"),
+            error: validation::Error::MatrixDecomposed,
+        }));
+    }
+
+    /// Replaces non-finite numbers with safe defaults, see [`from_json`].
+    ///
+    /// [`from_json`]: #method.from_json
+    fn replace_non_finite(&mut self) {
+        let replaced = validation::replace_non_finite(&mut self.0);
+        self.2.extend(replaced.into_iter().map(|path| validation::Entry {
+            path,
+            error: validation::Error::NonFiniteReplaced,
+        }));
+    }
+
+    /// Checks that the first buffer of binary glTF refers to its `BIN` chunk
+    /// if, and only if, the chunk exists.
+    ///
+    /// A missing chunk is an error. An unused chunk is listed in the
+    /// [`validation_report`] as `Error::BinChunkUnused`, or as
+    /// `Error::BinChunkShadowed` if the first buffer defines a `uri`, in which
+    /// case the `uri` takes precedence.
+    ///
+    /// [`validation_report`]: #method.validation_report
+    fn check_bin_chunk(&mut self, has_bin_chunk: bool) -> Result<()> {
+        let buffers = json::Path::new().field("buffers");
+        let entry = match (self.0.buffers.first(), has_bin_chunk) {
+            (Some(buffer), false) if buffer.uri.is_none() && !is_fallback(buffer) => {
+                return Err(Error::MissingBlob { buffer: 0 })
+            },
+            (Some(buffer), true) if buffer.uri.is_some() => validation::Entry {
+                path: buffers.index(0).field("uri"),
+                error: validation::Error::BinChunkShadowed,
+            },
+            (None, true) => validation::Entry { path: buffers, error: validation::Error::BinChunkUnused },
+            _ => return Ok(()),
+        };
+        self.2.push(entry);
+        Ok(())
+    }
+
+    /// Lists every buffer without a `uri` in the [`validation_report`] as
+    /// `Error::BufferUriMissing`, except for the first buffer of binary glTF,
+    /// which refers to the `BIN` chunk, and `EXT_meshopt_compression`
+    /// fallback buffers.
+    ///
+    /// [`validation_report`]: #method.validation_report
+    fn check_buffer_uris(&mut self, binary: bool) {
+        let skip = if binary { 1 } else { 0 };
+        let missing = self.0.buffers.iter().enumerate().skip(skip).filter(|(_, x)| x.uri.is_none() && !is_fallback(x));
+        let entries = missing.map(|(index, _)| validation::Entry {
+            path: json::Path::new().field("buffers").index(index).field("uri"),
+            error: validation::Error::BufferUriMissing,
+        });
+        self.2.extend(entries);
+    }
+
     /// Perform validation checks on loaded glTF.
-    pub(crate) fn validate(&self) -> Result<()> {
+    ///
+    /// Errors with `Severity::Warning`, such as denormalized quaternions, are
+    /// ignored.
+    fn validate(&self) -> Result<()> {
+        let timer = instrument::Timer::start();
+        let result = self.validate_with(validation::Severity::Error);
+        timer.finish(instrument::Stage::Validate, None, None, None);
+        result
+    }
+
+    /// Perform validation checks on loaded glTF in strict mode.
+    ///
+    /// Unlike the validation performed when loading glTF, errors with
+    /// `Severity::Warning` cause validation to fail.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// let gltf = gltf::Gltf::open("examples/Box.gltf")?;
+    /// gltf.validate_strict()?;
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    pub fn validate_strict(&self) -> Result<()> {
+        self.validate_with(validation::Severity::Warning)
+    }
+
+    /// Perform validation checks, failing on errors whose severity is at
+    /// least `threshold`.
+    ///
+    /// `Severity::Error` is the validation performed when loading glTF and
+    /// `Severity::Warning` that of [`validate_strict`].
+    ///
+    /// [`validate_strict`]: #method.validate_strict
+    pub fn validate_with(&self, threshold: validation::Severity) -> Result<()> {
+        self.validation_report().into_result(threshold)
+    }
+
+    /// Returns every error found by the validation checks, including those
+    /// with `Severity::Warning` and `Severity::Info`.
+    pub fn validation_report(&self) -> validation::Report {
+        self.validation_report_with(&Default::default())
+    }
+
+    /// Returns every error found by the validation checks, separating the
+    /// errors suppressed by `options`.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// use gltf::validation::{Severity, ValidationOptions};
+    ///
+    /// let gltf = gltf::Gltf::open("examples/Box.gltf")?;
+    /// let options = ValidationOptions::default().suppress("DENORMALIZED_QUATERNION");
+    /// gltf.validation_report_with(&options).into_result(Severity::Warning)?;
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    pub fn validation_report_with(&self, options: &validation::ValidationOptions) -> validation::Report {
         use json::validation::Validate;
-        let mut errors = Vec::new();
+        let mut report = validation::Report::default();
         self.0.validate(
             &self.0,
             json::Path::new,
-            &mut |path, error| errors.push((path(), error)),
+            &mut |path, error| {
+                let entry = validation::Entry { path: path(), error };
+                if options.is_suppressed(&entry.path, &entry.error) {
+                    report.suppressed.push(entry);
+                } else {
+                    report.entries.push(entry);
+                }
+            },
         );
+        let required = self.0.extensions_required.iter().enumerate();
+        let unsupported = required
+            .filter(|(_, name)| !supported_extensions().contains(&name.as_str()))
+            .map(|(index, _)| validation::Entry {
+                path: json::Path::new().field("extensionsRequired").index(index),
+                error: validation::Error::UnsupportedExtension,
+            });
+        for entry in unsupported.chain(self.2.iter().cloned()) {
+            if options.is_suppressed(&entry.path, &entry.error) {
+                report.suppressed.push(entry);
+            } else {
+                report.entries.push(entry);
+            }
+        }
+        report
+    }
+
+    /// Perform validation checks that require buffer data.
+    ///
+    /// Checks that the values of every index accessor are less than the number
+    /// of vertices of their primitive.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// let (document, buffers, _) = gltf::import("examples/Box.gltf")?;
+    /// document.validate_buffer_data(|buffer| Some(&buffers[buffer.index()]))?;
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    #[cfg(feature = "utils")]
+    pub fn validate_buffer_data<'s, F>(&self, get_buffer_data: F) -> Result<()>
+    where
+        F: Clone + Fn(Buffer) -> Option<&'s [u8]>,
+    {
+        let mut errors = Vec::new();
+        for mesh in self.meshes() {
+            for primitive in mesh.primitives() {
+                let vertices = match primitive.get(&Semantic::Positions) {
+                    Some(accessor) => accessor.count(),
+                    None => continue,
+                };
+                let reader = primitive.reader(get_buffer_data.clone());
+                let out_of_bounds = reader
+                    .read_indices()
+                    .is_some_and(|iter| iter.into_u32().any(|x| x as usize >= vertices));
+                if out_of_bounds {
+                    let path = json::Path::new()
+                        .field("meshes")
+                        .index(mesh.index())
+                        .field("primitives")
+                        .index(primitive.index())
+                        .field("indices");
+                    errors.push((path, json::validation::Error::IndexOutOfBounds));
+                }
+            }
+        }
         if errors.is_empty() {
             Ok(())
         } else {
@@ -356,6 +1247,58 @@ impl Document {
         }
     }
 
+    /// Returns an `Iterator` that visits every use of every accessor of the
+    /// glTF asset.
+    ///
+    /// Accessors are visited once per reference, so an accessor shared by two
+    /// primitives is visited twice and an unused accessor is not visited. The
+    /// storage of sparse accessors is reported as `SparseIndices` and
+    /// `SparseValues` usages of the accessor.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// use gltf::accessor::Usage;
+    /// let gltf = gltf::Gltf::open("examples/Box.gltf")?;
+    /// for (accessor, usage) in gltf.accessor_usages() {
+    ///     if usage.is_conflicting(&accessor) {
+    ///         println!("accessor {} has the wrong buffer view target", accessor.index());
+    ///     }
+    ///     if let Usage::Attribute { semantic, .. } = usage {
+    ///         println!("accessor {} is used as {:?}", accessor.index(), semantic);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    pub fn accessor_usages(&self) -> impl Iterator<Item = (Accessor<'_>, accessor::Usage<'_>)> + '_ {
+        accessor::usage::collect(self).into_iter()
+    }
+
+    /// Returns every buffer and image URI which refers to data outside of the
+    /// glTF asset, together with the JSON path of the referencing property.
+    ///
+    /// `data:` URIs are not reported. Nothing is loaded.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// let gltf = gltf::Gltf::open("examples/Box.gltf")?;
+    /// let references = gltf.external_references().collect::<Vec<_>>();
+    /// assert_eq!(references[0].path.as_str(), "buffers[0].uri");
+    /// assert_eq!(references[0].uri, "Box0.bin");
+    /// assert!(!gltf.is_self_contained());
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    pub fn external_references(&self) -> impl Iterator<Item = uri::Reference<'_>> + '_ {
+        uri::external_references(self).into_iter()
+    }
+
     /// Returns an `Iterator` that visits the accessors of the glTF asset.
     pub fn accessors(&self) -> iter::Accessors {
         iter::Accessors {
@@ -389,11 +1332,17 @@ impl Document {
     }
 
     /// Returns the default scene, if provided.
+    ///
+    /// Returns `None` if the document does not declare a default scene, for
+    /// example because it is a library of meshes and materials without
+    /// scenes. Documents whose default scene does not exist fail validation;
+    /// `None` is also returned for them if they are loaded without
+    /// validation.
     pub fn default_scene(&self) -> Option<Scene> {
         self.0
             .scene
             .as_ref()
-            .map(|index| self.scenes().nth(index.value()).unwrap())
+            .and_then(|index| self.scenes().nth(index.value()))
     }
 
     /// Returns the extensions referenced in this .document file.
@@ -406,6 +1355,81 @@ impl Document {
         iter::ExtensionsRequired(self.0.extensions_required.iter())
     }
 
+    /// Returns the JSON of the named root extension.
+    ///
+    /// Root extensions not supported by this crate, or whose feature is
+    /// disabled, are kept as raw JSON and written back unchanged when the
+    /// document is serialized.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// let json = r#"{
+    ///     "asset": {"version": "2.0"},
+    ///     "extensions": {"VENDOR_metadata": {"studio": "Example"}}
+    /// }"#;
+    /// let gltf = gltf::Gltf::from_slice(json.as_bytes())?;
+    /// let value = gltf.extension_value("VENDOR_metadata").unwrap();
+    /// assert_eq!(value["studio"], "Example");
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    pub fn extension_value(&self, name: &str) -> Option<json::Value> {
+        self.0.extensions.as_ref().and_then(|extensions| extensions.value(name))
+    }
+
+    /// Returns the names of the root extensions of the document, sorted by
+    /// name.
+    ///
+    /// Unlike `extensions_used`, which lists the extensions used anywhere in
+    /// the document in document order, only extensions defined on the root
+    /// object are returned, including those whose feature is disabled.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// let json = r#"{
+    ///     "asset": {"version": "2.0"},
+    ///     "extensions": {"VENDOR_b": {}, "VENDOR_a": {}}
+    /// }"#;
+    /// let gltf = gltf::Gltf::from_slice(json.as_bytes())?;
+    /// assert_eq!(gltf.extension_names(), vec!["VENDOR_a", "VENDOR_b"]);
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    pub fn extension_names(&self) -> Vec<&str> {
+        self.0.extensions.as_ref().map_or(Vec::new(), |extensions| extensions.names())
+    }
+
+    /// Returns the XMP metadata packets of the asset, as defined by the
+    /// `KHR_xmp_json_ld` extension.
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    pub fn xmp_packets(&self) -> &[json::extensions::root::khr_xmp_json_ld::Packet] {
+        self.0.extensions
+            .as_ref()
+            .and_then(|extensions| extensions.khr_xmp_json_ld.as_ref())
+            .map_or(&[], |khr_xmp_json_ld| &khr_xmp_json_ld.packets)
+    }
+
+    /// Returns the XMP metadata packet of the asset itself.
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    pub fn asset_xmp_packet(&self) -> Option<&json::extensions::root::khr_xmp_json_ld::Packet> {
+        self.xmp_packet(self.0.asset.extensions.as_ref()?.khr_xmp_json_ld.as_ref()?)
+    }
+
+    /// Resolves a reference to an XMP metadata packet.
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    pub(crate) fn xmp_packet(
+        &self,
+        reference: &json::extensions::root::khr_xmp_json_ld::KhrXmpJsonLd,
+    ) -> Option<&json::extensions::root::khr_xmp_json_ld::Packet> {
+        self.xmp_packets().get(reference.packet.value())
+    }
+
     /// Returns an `Iterator` that visits the pre-loaded images of the glTF asset.
     pub fn images(&self) -> iter::Images {
         iter::Images {
@@ -448,6 +1472,31 @@ impl Document {
         }
     }
 
+    /// Returns an `Iterator` that visits every primitive of every mesh of the
+    /// glTF asset, in mesh order.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// let gltf = gltf::Gltf::open("examples/Box.gltf")?;
+    /// let primitives = gltf.primitives();
+    /// assert_eq!(primitives.len(), 1);
+    /// for primitive in primitives {
+    ///     println!("mesh {} primitive {}", primitive.mesh().index(), primitive.index());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    pub fn primitives(&self) -> iter::Primitives<'_> {
+        iter::Primitives {
+            meshes: self.meshes(),
+            current: None,
+            remaining: self.0.meshes.iter().map(|mesh| mesh.primitives.len()).sum(),
+        }
+    }
+
     /// Returns an `Iterator` that visits the nodes of the glTF asset.
     pub fn nodes(&self) -> iter::Nodes {
         iter::Nodes {
@@ -480,6 +1529,182 @@ impl Document {
         }
     }
 
+    /// Returns the images for which mipmaps must be generated, together with
+    /// the textures that sample them with a mipmapping minification filter.
+    ///
+    /// Images are visited in index order. See [`Sampler::requires_mipmaps`]
+    /// for how undefined filters are treated.
+    ///
+    /// [`Sampler::requires_mipmaps`]: texture/struct.Sampler.html#method.requires_mipmaps
+    pub fn textures_requiring_mipmaps(&self) -> impl Iterator<Item = texture::MipmapRequirement<'_>> + '_ {
+        texture::textures_requiring_mipmaps(self).into_iter()
+    }
+
+    /// Groups the textures of the document by their source image, listing
+    /// the distinct sampler configurations and material slots using each
+    /// image.
+    ///
+    /// Images sampled with both repeating and clamping wrapping modes are
+    /// flagged as unsafe for texture atlases.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// let gltf = gltf::Gltf::open("examples/Box.gltf")?;
+    /// let report = gltf.image_sharing_report();
+    /// assert_eq!(report.atlas_unsafe().count(), 0);
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    pub fn image_sharing_report(&self) -> texture::ImageSharingReport {
+        texture::image_sharing_report(self)
+    }
+
+    /// Lists the textures exceeding the limits of a target platform
+    /// `profile`, together with the material slots referencing them.
+    ///
+    /// The dimensions of each image are read from the header of its encoded
+    /// data, as returned by `get_image_data`, with
+    /// [`image::probe_dimensions`]. Non-power-of-two textures are only
+    /// reported for profiles such as `Profile::Gles2` when their sampler
+    /// requires mipmaps or repeats on either axis.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// use gltf::texture::Profile;
+    ///
+    /// let gltf = gltf::Gltf::open("examples/Box.gltf")?;
+    /// let report = gltf.texture_compatibility(Profile::Gles2, |_| None);
+    /// assert!(report.is_compatible());
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    ///
+    /// [`image::probe_dimensions`]: image/fn.probe_dimensions.html
+    pub fn texture_compatibility<'a, 's, F>(&'a self, profile: texture::Profile, get_image_data: F) -> texture::CompatibilityReport
+    where
+        F: Fn(Image<'a>) -> Option<&'s [u8]>,
+    {
+        texture::compatibility_report(self, profile, get_image_data)
+    }
+
+    /// Counts the primitives and triangles drawn for every material and the
+    /// instances and triangles of every mesh, when each scene is drawn once.
+    ///
+    /// Triangles are counted with [`Primitive::triangle_count`], which skips
+    /// degenerate triangles if buffer data is given.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// let gltf = gltf::Gltf::open("examples/Box.gltf")?;
+    /// let report = gltf.report(None::<fn(gltf::Buffer) -> Option<&'static [u8]>>);
+    /// assert_eq!(report.by_mesh[0].instances, 1);
+    /// assert_eq!(report.by_material[0].triangles, 12);
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    ///
+    /// [`Primitive::triangle_count`]: mesh/struct.Primitive.html#method.triangle_count
+    pub fn report<'a, 's, F>(&'a self, get_buffer_data: Option<F>) -> draw::Report
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        draw::report(self, get_buffer_data)
+    }
+
+    /// Counts the materials whose occlusion texture is packed into their
+    /// metallic-roughness texture, and lists those with both textures that
+    /// are not.
+    ///
+    /// See [`Material::occlusion_packed_in_metallic_roughness`].
+    ///
+    /// [`Material::occlusion_packed_in_metallic_roughness`]: material/struct.Material.html#method.occlusion_packed_in_metallic_roughness
+    pub fn packed_occlusion_stats(&self) -> material::PackedOcclusionStats {
+        material::packed_occlusion_stats(self)
+    }
+
+    /// Summarizes the capabilities needed to render the document, such as
+    /// skinning, morph targets, 32-bit indices, the number of texture
+    /// coordinate sets, and the required extensions.
+    ///
+    /// Only the JSON is consulted if `get_buffer_data` is `None`. Otherwise,
+    /// the sizes of images stored in buffer views are read from their
+    /// headers.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// let gltf = gltf::Gltf::open("examples/Box.gltf")?;
+    /// let requirements = gltf.requirements(None::<fn(gltf::Buffer) -> Option<&'static [u8]>>);
+    /// assert!(!requirements.skinning);
+    /// assert_eq!(requirements.max_tex_coord_sets, 0);
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    pub fn requirements<'a, 's, F>(&'a self, get_buffer_data: Option<F>) -> requirements::Requirements
+    where
+        F: Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        requirements::requirements(self, get_buffer_data)
+    }
+
+    /// Returns the groups of accessors that are declared identically, as
+    /// found by [`json::Root::duplicate_accessors`].
+    ///
+    /// [`json::Root::duplicate_accessors`]: json/struct.Root.html#method.duplicate_accessors
+    pub fn duplicate_accessors(&self) -> Vec<Vec<Accessor<'_>>> {
+        let groups = self.0.duplicate_accessors();
+        groups.iter().map(|group| group.iter().filter_map(|&x| self.get(x)).collect()).collect()
+    }
+
+    /// Returns the groups of accessors that are declared identically or hold
+    /// identical elements, as found by [`json::Root::duplicate_accessors_in`].
+    ///
+    /// Accessors whose data is not returned by `get_buffer_data` are only
+    /// compared by declaration.
+    ///
+    /// [`json::Root::duplicate_accessors_in`]: json/struct.Root.html#method.duplicate_accessors_in
+    pub fn duplicate_accessors_with<'a, 's, F>(&'a self, get_buffer_data: F) -> Vec<Vec<Accessor<'a>>>
+    where
+        F: Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        let buffers = self.buffers().map(|x| get_buffer_data(x).unwrap_or(&[])).collect::<Vec<_>>();
+        let groups = self.0.duplicate_accessors_in(&buffers);
+        groups.iter().map(|group| group.iter().filter_map(|&x| self.get(x)).collect()).collect()
+    }
+
+    /// Returns the groups of buffer views that are declared identically, as
+    /// found by [`json::Root::duplicate_views`].
+    ///
+    /// [`json::Root::duplicate_views`]: json/struct.Root.html#method.duplicate_views
+    pub fn duplicate_views(&self) -> Vec<Vec<buffer::View<'_>>> {
+        let groups = self.0.duplicate_views();
+        groups.iter().map(|group| group.iter().filter_map(|&x| self.get(x)).collect()).collect()
+    }
+
+    /// Returns the groups of buffer views that are declared identically or
+    /// hold identical bytes, as found by [`json::Root::duplicate_views_in`].
+    ///
+    /// [`json::Root::duplicate_views_in`]: json/struct.Root.html#method.duplicate_views_in
+    pub fn duplicate_views_with<'a, 's, F>(&'a self, get_buffer_data: F) -> Vec<Vec<buffer::View<'a>>>
+    where
+        F: Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        let buffers = self.buffers().map(|x| get_buffer_data(x).unwrap_or(&[])).collect::<Vec<_>>();
+        let groups = self.0.duplicate_views_in(&buffers);
+        groups.iter().map(|group| group.iter().filter_map(|&x| self.get(x)).collect()).collect()
+    }
+
     /// Returns an `Iterator` that visits the textures of the glTF asset.
     pub fn textures(&self) -> iter::Textures {
         iter::Textures {
@@ -496,6 +1721,54 @@ impl Document {
             document: self,
         }
     }
+
+    /// Returns the first accessor with the given name.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// let json = r#"{
+    ///     "asset": {"version": "2.0"},
+    ///     "buffers": [{"byteLength": 12, "uri": "data.bin"}],
+    ///     "bufferViews": [{"buffer": 0, "byteLength": 12}],
+    ///     "accessors": [
+    ///         {"bufferView": 0, "componentType": 5126, "count": 1, "type": "VEC3", "name": "POSITION_lod0"},
+    ///         {"bufferView": 0, "componentType": 5126, "count": 1, "type": "VEC3", "name": "POSITION_lod1"}
+    ///     ]
+    /// }"#;
+    /// let gltf = gltf::Gltf::from_slice(json.as_bytes())?;
+    /// assert_eq!(gltf.accessor_by_name("POSITION_lod1").map(|x| x.index()), Some(1));
+    /// assert!(gltf.accessor_by_name("POSITION_lod2").is_none());
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    #[cfg(feature = "names")]
+    pub fn accessor_by_name(&self, name: &str) -> Option<Accessor<'_>> {
+        self.accessors().find(|x| x.name() == Some(name))
+    }
+
+    /// Returns the first buffer view with the given name.
+    #[cfg(feature = "names")]
+    pub fn view_by_name(&self, name: &str) -> Option<buffer::View<'_>> {
+        self.views().find(|x| x.name() == Some(name))
+    }
+}
+
+/// Returns `true` if `buffer` is an `EXT_meshopt_compression` fallback
+/// buffer, whose data is never read, since the views of it are decompressed.
+#[cfg(feature = "meshopt")]
+fn is_fallback(buffer: &json::Buffer) -> bool {
+    let extension = buffer.extensions.as_ref().and_then(|x| x.ext_meshopt_compression.as_ref());
+    extension.is_some_and(|x| x.fallback)
+}
+
+/// Returns `false`, since fallback buffers are read unless
+/// `EXT_meshopt_compression` data is decompressed.
+#[cfg(not(feature = "meshopt"))]
+fn is_fallback(_: &json::Buffer) -> bool {
+    false
 }
 
 impl std::fmt::Display for Error {
@@ -504,7 +1777,6 @@ impl std::fmt::Display for Error {
             #[cfg(feature = "import")]
             Error::Base64(ref e) => e.fmt(f),
             Error::Binary(ref e) => e.fmt(f),
-            #[cfg(feature = "import")]
             Error::BufferLength { buffer, expected, actual } => {
                 write!(
                     f,
@@ -515,17 +1787,33 @@ impl std::fmt::Display for Error {
                 )
             },
             Error::Deserialize(ref e) => e.fmt(f),
+            Error::InvalidUtf8 { offset } => {
+                write!(f, "invalid UTF-8 in JSON chunk at byte offset {}", offset)
+            },
             Error::Io(ref e) => e.fmt(f),
             #[cfg(feature = "import")]
             Error::Image(ref e) => e.fmt(f),
+            #[cfg(feature = "meshopt")]
+            Error::Meshopt { view } => {
+                write!(f, "buffer view {}: malformed EXT_meshopt_compression data", view)
+            },
+            Error::MissingBlob { buffer } => {
+                write!(f, "buffer {}: missing BIN chunk of binary glTF", buffer)
+            },
             #[cfg(feature = "import")]
-            Error::MissingBlob => write!(f, "missing binary portion of binary glTF"),
+            Error::MissingImageData { image } => write!(f, "image {}: missing encoded image data", image),
             #[cfg(feature = "import")]
             Error::ExternalReferenceInSliceImport => write!(f, "external reference in slice only import"),
+            Error::TrailingContent { offset } => {
+                write!(f, "unexpected content after the JSON text at byte offset {}", offset)
+            },
             #[cfg(feature = "import")]
             Error::UnsupportedImageEncoding => write!(f, "unsupported image encoding"),
             #[cfg(feature = "import")]
             Error::UnsupportedScheme => write!(f, "unsupported URI scheme"),
+            Error::UnsupportedEncoding(encoding) => {
+                write!(f, "unsupported JSON encoding {}, expected UTF-8", encoding)
+            },
             Error::Validation(ref xs) => {
                 write!(f, "invalid glTF:")?;
                 for &(ref path, ref error) in xs {
@@ -543,20 +1831,25 @@ impl std::error::Error for Error {
             #[cfg(feature = "import")]
             Error::Base64(ref e) => e.description(),
             Error::Binary(ref e) => e.description(),
-            #[cfg(feature = "import")]
             Error::BufferLength { .. } => "buffer length does not match expected length",
             Error::Deserialize(ref e) => e.description(),
+            Error::InvalidUtf8 { .. } => "invalid UTF-8 in JSON chunk",
             Error::Io(ref e) => e.description(),
             #[cfg(feature = "import")]
             Error::Image(ref e) => e.description(),
+            #[cfg(feature = "meshopt")]
+            Error::Meshopt { .. } => "malformed EXT_meshopt_compression data",
+            Error::MissingBlob { .. } => "missing BIN section of binary glTF",
             #[cfg(feature = "import")]
-            Error::MissingBlob => "missing BIN section of binary glTF",
+            Error::MissingImageData { .. } => "missing encoded image data",
             #[cfg(feature = "import")]
             Error::ExternalReferenceInSliceImport => "external reference in slice only import",
+            Error::TrailingContent { .. } => "unexpected content after the JSON text",
             #[cfg(feature = "import")]
             Error::UnsupportedImageEncoding => "unsupported image encoding",
             #[cfg(feature = "import")]
             Error::UnsupportedScheme => "unsupported URI scheme",
+            Error::UnsupportedEncoding(_) => "unsupported JSON encoding",
             Error::Validation(_) => "invalid glTF",
         }
     }
@@ -626,11 +1919,11 @@ impl Normalize<i16> for u8 {
 }
 
 impl Normalize<u16> for u8 {
-    fn normalize(self) -> u16 { self.max(0) as u16 * 2 }
+    fn normalize(self) -> u16 { self as u16 * 0x101 }
 }
 
 impl Normalize<f32> for u8 {
-    fn normalize(self) -> f32 { (self as f32 * 32767.0_f32.recip()).max(-1.0) }
+    fn normalize(self) -> f32 { self as f32 / 255.0 }
 }
 
 impl Normalize<i8> for i16 {
@@ -658,7 +1951,7 @@ impl Normalize<i8> for u16 {
 }
 
 impl Normalize<u8> for u16 {
-    fn normalize(self) -> u8 { (self / 0x100) as u8 }
+    fn normalize(self) -> u8 { ((self as u32 + 0x80) / 0x101) as u8 }
 }
 
 impl Normalize<i16> for u16 {
@@ -670,7 +1963,7 @@ impl Normalize<u16> for u16 {
 }
 
 impl Normalize<f32> for u16 {
-    fn normalize(self) -> f32 { self as f32 * 65535.0_f32.recip() }
+    fn normalize(self) -> f32 { self as f32 / 65535.0 }
 }
 
 impl Normalize<i8> for f32 {
@@ -678,7 +1971,7 @@ impl Normalize<i8> for f32 {
 }
 
 impl Normalize<u8> for f32 {
-    fn normalize(self) -> u8 { (self.max(0.0) * 255.0) as u8 }
+    fn normalize(self) -> u8 { (self.clamp(0.0, 1.0) * 255.0).round() as u8 }
 }
 
 impl Normalize<i16> for f32 {
@@ -686,7 +1979,7 @@ impl Normalize<i16> for f32 {
 }
 
 impl Normalize<u16> for f32 {
-    fn normalize(self) -> u16 { (self.max(0.0) * 65535.0) as u16 }
+    fn normalize(self) -> u16 { (self.clamp(0.0, 1.0) * 65535.0).round() as u16 }
 }
 
 impl Normalize<f32> for f32 {
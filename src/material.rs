@@ -1,3 +1,7 @@
+use std::fmt;
+use std::ops;
+
+use crate::texture::UvTransform;
 use crate::{texture, Document};
 
 pub use json::material::AlphaMode;
@@ -7,6 +11,36 @@ lazy_static! {
 }
 
 /// The material appearance of a primitive.
+///
+/// Materials are usually read from a glTF file, but code that consumes them can
+/// be tested with a document built from JSON structs:
+///
+/// ```
+/// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// use gltf::json::{self, material::{NormalTexture, OcclusionTexture, StrengthFactor}, Index};
+///
+/// let mut root = json::Root::default();
+/// root.images.push(json::Image { uri: Some("normal.png".into()), ..Default::default() });
+/// root.textures.push(json::Texture { source: Some(Index::new(0)), ..Default::default() });
+/// root.materials.push(json::Material {
+///     normal_texture: Some(NormalTexture { scale: 0.5, ..NormalTexture::new(Index::new(0)) }),
+///     occlusion_texture: Some(OcclusionTexture {
+///         strength: StrengthFactor::new(0.25),
+///         ..OcclusionTexture::new(Index::new(0))
+///     }),
+///     ..Default::default()
+/// });
+///
+/// let document = gltf::Document::from_json(root)?;
+/// let material = document.materials().next().unwrap();
+/// assert_eq!(material.normal_texture().unwrap().scale(), 0.5);
+/// assert_eq!(material.occlusion_texture().unwrap().strength(), 0.25);
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #     run().expect("test failure");
+/// # }
+/// ```
 #[derive(Clone, Debug)]
 pub struct Material<'a> {
     /// The parent `Document` struct.
@@ -19,6 +53,358 @@ pub struct Material<'a> {
     json: &'a json::material::Material,
 }
 
+impl_identity!(Material);
+
+/// A copy of the resolved properties of a `Material` which does not borrow the
+/// document.
+///
+/// Texture references are stored as indices, so a `MaterialData` can be kept
+/// in long-lived caches such as `HashMap<usize, MaterialData>`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MaterialData {
+    /// The internal JSON index, or `None` for the default material.
+    pub index: Option<usize>,
+
+    /// Optional user-defined name for the material.
+    #[cfg(feature = "names")]
+    pub name: Option<String>,
+
+    /// The alpha rendering mode.
+    pub alpha_mode: AlphaMode,
+
+    /// The alpha cutoff value.
+    pub alpha_cutoff: f32,
+
+    /// Specifies whether the material is double-sided.
+    pub double_sided: bool,
+
+    /// The base color factor.
+    pub base_color_factor: [f32; 4],
+
+    /// The base color texture.
+    pub base_color_texture: Option<TextureData>,
+
+    /// The metalness factor.
+    pub metallic_factor: f32,
+
+    /// The roughness factor.
+    pub roughness_factor: f32,
+
+    /// The metallic-roughness texture.
+    pub metallic_roughness_texture: Option<TextureData>,
+
+    /// The normal map texture.
+    pub normal_texture: Option<TextureData>,
+
+    /// The scale applied to each normal vector of the normal texture.
+    pub normal_scale: f32,
+
+    /// The occlusion texture.
+    pub occlusion_texture: Option<TextureData>,
+
+    /// The strength of the occlusion texture.
+    pub occlusion_strength: f32,
+
+    /// The emissive texture.
+    pub emissive_texture: Option<TextureData>,
+
+    /// The emissive color factor.
+    pub emissive_factor: [f32; 3],
+
+    /// Specifies whether the material is unlit, see [`Material::unlit`].
+    ///
+    /// Always `false` unless the `KHR_materials_unlit` feature is enabled.
+    ///
+    /// [`Material::unlit`]: struct.Material.html#method.unlit
+    pub unlit: bool,
+}
+
+/// A texture reference of a `MaterialData`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct TextureData {
+    /// The index of the texture.
+    pub texture: usize,
+
+    /// The index of the image used by the texture.
+    pub image: usize,
+
+    /// The set index of the `TEXCOORD` attribute used by the texture.
+    pub tex_coord: u32,
+}
+
+/// The parameters of the normal map of a material, as returned by
+/// [`Material::normal_mapping`].
+///
+/// The sign of the bitangent is not a material parameter, but the `w`
+/// component of the `TANGENT` attribute of each vertex.
+///
+/// [`Material::normal_mapping`]: struct.Material.html#method.normal_mapping
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NormalMapping {
+    /// The index of the normal texture.
+    pub texture: usize,
+
+    /// The index of the image used by the texture.
+    pub image: usize,
+
+    /// The set index of the `TEXCOORD` attribute to sample the texture with,
+    /// after any `KHR_texture_transform` override.
+    pub tex_coord: u32,
+
+    /// The transform of the texture coordinates.
+    pub transform: UvTransform,
+
+    /// The scalar multiplier applied to the X and Y components of each normal
+    /// vector of the texture.
+    pub scale: f32,
+}
+
+/// The parameters of the occlusion map of a material, as returned by
+/// [`Material::occlusion_params`].
+///
+/// [`Material::occlusion_params`]: struct.Material.html#method.occlusion_params
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OcclusionParams {
+    /// The index of the occlusion texture.
+    pub texture: usize,
+
+    /// The index of the image used by the texture.
+    pub image: usize,
+
+    /// The set index of the `TEXCOORD` attribute to sample the texture with,
+    /// after any `KHR_texture_transform` override.
+    pub tex_coord: u32,
+
+    /// The transform of the texture coordinates.
+    pub transform: UvTransform,
+
+    /// The amount of occlusion applied.
+    pub strength: f32,
+}
+
+/// The parameters of the emissive map of a material, as returned by
+/// [`Material::emissive_params`].
+///
+/// [`Material::emissive_params`]: struct.Material.html#method.emissive_params
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EmissiveParams {
+    /// The index of the emissive texture.
+    pub texture: usize,
+
+    /// The index of the image used by the texture.
+    pub image: usize,
+
+    /// The set index of the `TEXCOORD` attribute to sample the texture with,
+    /// after any `KHR_texture_transform` override.
+    pub tex_coord: u32,
+
+    /// The transform of the texture coordinates.
+    pub transform: UvTransform,
+
+    /// The emissive color the texture is multiplied with.
+    pub factor: [f32; 3],
+}
+
+/// A set of shader features used by a material, as returned by
+/// [`Material::features`].
+///
+/// Implements `Hash` and `Eq` so that it may key a cache of compiled shaders
+/// or pipelines directly.
+///
+/// [`Material::features`]: struct.Material.html#method.features
+#[derive(Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct MaterialFeatures(u32);
+
+impl MaterialFeatures {
+    /// The material has a base color texture.
+    pub const BASE_COLOR_TEXTURE: Self = MaterialFeatures(1 << 0);
+
+    /// The material has a metallic-roughness texture.
+    pub const METALLIC_ROUGHNESS_TEXTURE: Self = MaterialFeatures(1 << 1);
+
+    /// The material has a normal texture.
+    pub const NORMAL_MAP: Self = MaterialFeatures(1 << 2);
+
+    /// The material has an occlusion texture.
+    pub const OCCLUSION: Self = MaterialFeatures(1 << 3);
+
+    /// The material has an emissive texture or a non-zero emissive factor.
+    pub const EMISSIVE: Self = MaterialFeatures(1 << 4);
+
+    /// The alpha mode is `Mask`.
+    pub const ALPHA_MASK: Self = MaterialFeatures(1 << 5);
+
+    /// The alpha mode is `Blend`.
+    pub const ALPHA_BLEND: Self = MaterialFeatures(1 << 6);
+
+    /// The material is double-sided.
+    pub const DOUBLE_SIDED: Self = MaterialFeatures(1 << 7);
+
+    /// A texture of the material reads the `TEXCOORD_1` attribute.
+    pub const TEXCOORD_1_USED: Self = MaterialFeatures(1 << 8);
+
+    /// The material uses the `KHR_materials_pbrSpecularGlossiness` extension.
+    #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+    pub const SPECULAR_GLOSSINESS: Self = MaterialFeatures(1 << 9);
+
+    /// The specular-glossiness model has a diffuse texture.
+    #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+    pub const DIFFUSE_TEXTURE: Self = MaterialFeatures(1 << 10);
+
+    /// The specular-glossiness model has a specular-glossiness texture.
+    #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+    pub const SPECULAR_GLOSSINESS_TEXTURE: Self = MaterialFeatures(1 << 11);
+
+    /// The material uses the `KHR_materials_unlit` extension.
+    #[cfg(feature = "KHR_materials_unlit")]
+    pub const UNLIT: Self = MaterialFeatures(1 << 12);
+
+    /// The name and value of every flag.
+    const NAMES: &'static [(&'static str, MaterialFeatures)] = &[
+        ("BASE_COLOR_TEXTURE", Self::BASE_COLOR_TEXTURE),
+        ("METALLIC_ROUGHNESS_TEXTURE", Self::METALLIC_ROUGHNESS_TEXTURE),
+        ("NORMAL_MAP", Self::NORMAL_MAP),
+        ("OCCLUSION", Self::OCCLUSION),
+        ("EMISSIVE", Self::EMISSIVE),
+        ("ALPHA_MASK", Self::ALPHA_MASK),
+        ("ALPHA_BLEND", Self::ALPHA_BLEND),
+        ("DOUBLE_SIDED", Self::DOUBLE_SIDED),
+        ("TEXCOORD_1_USED", Self::TEXCOORD_1_USED),
+        #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+        ("SPECULAR_GLOSSINESS", Self::SPECULAR_GLOSSINESS),
+        #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+        ("DIFFUSE_TEXTURE", Self::DIFFUSE_TEXTURE),
+        #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+        ("SPECULAR_GLOSSINESS_TEXTURE", Self::SPECULAR_GLOSSINESS_TEXTURE),
+        #[cfg(feature = "KHR_materials_unlit")]
+        ("UNLIT", Self::UNLIT),
+    ];
+
+    /// Returns an empty set of features.
+    pub fn empty() -> Self {
+        MaterialFeatures(0)
+    }
+
+    /// Returns the raw value of the flags.
+    pub fn bits(&self) -> u32 {
+        self.0
+    }
+
+    /// Returns `true` if no flags are set.
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` if every flag of `other` is set.
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Sets every flag of `other`.
+    pub fn insert(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+
+    /// Clears every flag of `other`.
+    pub fn remove(&mut self, other: Self) {
+        self.0 &= !other.0;
+    }
+
+    /// Sets or clears every flag of `other`.
+    pub fn set(&mut self, other: Self, value: bool) {
+        if value {
+            self.insert(other);
+        } else {
+            self.remove(other);
+        }
+    }
+}
+
+impl ops::BitOr for MaterialFeatures {
+    type Output = Self;
+    fn bitor(self, other: Self) -> Self {
+        MaterialFeatures(self.0 | other.0)
+    }
+}
+
+impl ops::BitOrAssign for MaterialFeatures {
+    fn bitor_assign(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+}
+
+impl ops::BitAnd for MaterialFeatures {
+    type Output = Self;
+    fn bitand(self, other: Self) -> Self {
+        MaterialFeatures(self.0 & other.0)
+    }
+}
+
+impl ops::BitAndAssign for MaterialFeatures {
+    fn bitand_assign(&mut self, other: Self) {
+        self.0 &= other.0;
+    }
+}
+
+impl ops::Sub for MaterialFeatures {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        MaterialFeatures(self.0 & !other.0)
+    }
+}
+
+impl fmt::Debug for MaterialFeatures {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut first = true;
+        for &(name, flag) in Self::NAMES {
+            if self.contains(flag) {
+                if !first {
+                    f.write_str(" | ")?;
+                }
+                f.write_str(name)?;
+                first = false;
+            }
+        }
+        if first {
+            f.write_str("(empty)")?;
+        }
+        Ok(())
+    }
+}
+
+/// How often the occlusion of the materials of a document is packed into
+/// their metallic-roughness texture, as returned by
+/// [`Document::packed_occlusion_stats`].
+///
+/// [`Document::packed_occlusion_stats`]: ../struct.Document.html#method.packed_occlusion_stats
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PackedOcclusionStats {
+    /// The number of materials of the document.
+    pub materials: usize,
+
+    /// The number of materials with an occlusion texture.
+    pub occlusion: usize,
+
+    /// The number of materials whose occlusion texture is packed into their
+    /// metallic-roughness texture.
+    pub packed: usize,
+
+    /// The indices of the materials with both an occlusion and a
+    /// metallic-roughness texture that are not packed together, in ascending
+    /// order.
+    pub unpacked: Vec<usize>,
+}
+
+impl TextureData {
+    fn new(texture: &texture::Texture, tex_coord: u32) -> Option<Self> {
+        Some(Self {
+            texture: texture.index(),
+            image: texture.source()?.index(),
+            tex_coord,
+        })
+    }
+}
+
 impl<'a> Material<'a> {
     /// Constructs a `Material`.
     pub(crate) fn new(
@@ -67,8 +453,11 @@ impl<'a> Material<'a> {
     ///   destination areas and the rendered output is combined with the background
     ///   using the normal painting operation (i.e. the Porter and Duff over
     ///   operator).
+    ///
+    /// An invalid mode, which is only possible for documents loaded without
+    /// validation, is read as the default `Opaque`.
     pub fn alpha_mode(&self) -> AlphaMode {
-        self.json.alpha_mode.unwrap()
+        self.json.alpha_mode.valid().unwrap_or_default()
     }
 
     /// Specifies whether the material is double-sided.
@@ -84,7 +473,48 @@ impl<'a> Material<'a> {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     pub fn name(&self) -> Option<&'a str> {
-        self.json.name.as_ref().map(String::as_str)
+        self.json.name.as_deref()
+    }
+
+    /// Returns the parameters of the material model to render the material
+    /// with: specular-glossiness if the `KHR_materials_pbrSpecularGlossiness`
+    /// extension is present, and metallic-roughness otherwise.
+    ///
+    /// The normal, occlusion, and emissive properties of the material apply
+    /// to both models and are returned by [`normal_texture`],
+    /// [`occlusion_texture`], [`emissive_texture`], and [`emissive_factor`].
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// use gltf::material::Pbr;
+    ///
+    /// let gltf = gltf::Gltf::open("examples/Box.gltf")?;
+    /// let material = gltf.materials().next().unwrap();
+    /// match material.pbr() {
+    ///     Pbr::MetallicRoughness(pbr) => println!("base color {:?}", pbr.base_color_factor()),
+    ///     #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+    ///     Pbr::SpecularGlossiness(pbr) => println!("diffuse {:?}", pbr.diffuse_factor()),
+    /// }
+    /// println!("normal map {:?}", material.normal_texture().map(|x| x.texture().index()));
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    ///
+    /// [`normal_texture`]: #method.normal_texture
+    /// [`occlusion_texture`]: #method.occlusion_texture
+    /// [`emissive_texture`]: #method.emissive_texture
+    /// [`emissive_factor`]: #method.emissive_factor
+    pub fn pbr(&self) -> Pbr<'a> {
+        #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+        {
+            if let Some(pbr) = self.pbr_specular_glossiness() {
+                return Pbr::SpecularGlossiness(pbr);
+            }
+        }
+        Pbr::MetallicRoughness(self.pbr_metallic_roughness())
     }
 
     /// Parameter values that define the metallic-roughness material model from
@@ -114,10 +544,14 @@ impl<'a> Material<'a> {
     ///
     /// The normal vectors use OpenGL conventions where +X is right, +Y is up, and
     /// +Z points toward the viewer.
+    ///
+    /// This applies to both material models returned by [`pbr`].
+    ///
+    /// [`pbr`]: #method.pbr
     pub fn normal_texture(&self) -> Option<NormalTexture<'a>> {
-        self.json.normal_texture.as_ref().map(|json| {
-            let texture = self.document.textures().nth(json.index.value()).unwrap();
-            NormalTexture::new(texture, json)
+        self.json.normal_texture.as_ref().and_then(|json| {
+            let texture = self.document.get(json.index)?;
+            Some(NormalTexture::new(texture, json))
         })
     }
 
@@ -129,13 +563,62 @@ impl<'a> Material<'a> {
     ///
     /// If other channels are present (GBA), they are ignored for occlusion
     /// calculations.
+    ///
+    /// This applies to both material models returned by [`pbr`].
+    ///
+    /// [`pbr`]: #method.pbr
     pub fn occlusion_texture(&self) -> Option<OcclusionTexture<'a>> {
-        self.json.occlusion_texture.as_ref().map(|json| {
-            let texture = self.document.textures().nth(json.index.value()).unwrap();
-            OcclusionTexture::new(texture, json)
+        self.json.occlusion_texture.as_ref().and_then(|json| {
+            let texture = self.document.get(json.index)?;
+            Some(OcclusionTexture::new(texture, json))
         })
     }
 
+    /// Returns `true` if the occlusion texture is packed into the R channel of
+    /// the metallic-roughness texture, so that renderers can bind one texture
+    /// for both.
+    ///
+    /// This is the case when both textures resolve to the same image, are
+    /// sampled with the same sampler settings, and use the same `TEXCOORD`
+    /// set and transform. Without the `KHR_texture_transform` feature, which
+    /// could transform the two differently, `false` is returned for documents
+    /// that use the extension.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// let gltf = gltf::Gltf::open("examples/Box.gltf")?;
+    /// let material = gltf.materials().next().unwrap();
+    /// assert!(!material.occlusion_packed_in_metallic_roughness());
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    pub fn occlusion_packed_in_metallic_roughness(&self) -> bool {
+        let pbr = self.pbr_metallic_roughness();
+        let (occlusion, metallic_roughness) = match (self.occlusion_texture(), pbr.metallic_roughness_texture()) {
+            (Some(occlusion), Some(metallic_roughness)) => (occlusion, metallic_roughness),
+            _ => return false,
+        };
+        if !cfg!(feature = "KHR_texture_transform")
+            && self.document.extensions_used().any(|x| x == "KHR_texture_transform")
+        {
+            return false;
+        }
+        let a_transform = occlusion.uv_transform();
+        let b_transform = metallic_roughness.uv_transform();
+        let (a, b) = (occlusion.texture(), metallic_roughness.texture());
+        let (a_sampler, b_sampler) = (a.sampler(), b.sampler());
+        occlusion.effective_tex_coord() == metallic_roughness.effective_tex_coord()
+            && a_transform == b_transform
+            && a.source().map(|x| x.index()) == b.source().map(|x| x.index())
+            && a_sampler.mag_filter() == b_sampler.mag_filter()
+            && a_sampler.min_filter() == b_sampler.min_filter()
+            && a_sampler.wrap_s() == b_sampler.wrap_s()
+            && a_sampler.wrap_t() == b_sampler.wrap_t()
+    }
+
     /// The emissive map texture.
     ///
     /// The emissive map controls the color and intensity of the light being
@@ -143,20 +626,91 @@ impl<'a> Material<'a> {
     ///
     /// This texture contains RGB components in sRGB color space. If a fourth
     /// component (A) is present, it is ignored.
+    ///
+    /// This applies to both material models returned by [`pbr`].
+    ///
+    /// [`pbr`]: #method.pbr
     pub fn emissive_texture(&self) -> Option<texture::Info<'a>> {
-        self.json.emissive_texture.as_ref().map(|json| {
-            let texture = self.document.textures().nth(json.index.value()).unwrap();
-            texture::Info::new(texture, json)
+        self.json.emissive_texture.as_ref().and_then(|json| {
+            let texture = self.document.get(json.index)?;
+            Some(texture::Info::new(texture, json))
         })
     }
 
     /// The emissive color of the material.
     ///
     /// The default value is `[0.0, 0.0, 0.0]`.
+    ///
+    /// This applies to both material models returned by [`pbr`].
+    ///
+    /// [`pbr`]: #method.pbr
     pub fn emissive_factor(&self) -> [f32; 3] {
         self.json.emissive_factor.0
     }
 
+    /// Returns the normal texture, its `TEXCOORD` set and transform, and
+    /// its scale, or `None` if the material has no normal texture.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// let gltf = gltf::Gltf::open("examples/Lantern.gltf")?;
+    /// let material = gltf.materials().next().unwrap();
+    /// let normal = material.normal_mapping().unwrap();
+    /// assert_eq!(normal.texture, material.normal_texture().unwrap().texture().index());
+    /// assert!(normal.transform.is_identity());
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    pub fn normal_mapping(&self) -> Option<NormalMapping> {
+        self.normal_texture().and_then(|normal| {
+            let transform = normal.uv_transform();
+            let texture = normal.texture();
+            Some(NormalMapping {
+                texture: texture.index(),
+                image: texture.source()?.index(),
+                tex_coord: normal.effective_tex_coord(),
+                transform,
+                scale: normal.scale(),
+            })
+        })
+    }
+
+    /// Returns the occlusion texture, its `TEXCOORD` set and transform, and
+    /// its strength, or `None` if the material has no occlusion texture.
+    pub fn occlusion_params(&self) -> Option<OcclusionParams> {
+        self.occlusion_texture().and_then(|occlusion| {
+            let transform = occlusion.uv_transform();
+            let texture = occlusion.texture();
+            Some(OcclusionParams {
+                texture: texture.index(),
+                image: texture.source()?.index(),
+                tex_coord: occlusion.effective_tex_coord(),
+                transform,
+                strength: occlusion.strength(),
+            })
+        })
+    }
+
+    /// Returns the emissive texture, its `TEXCOORD` set and transform, and
+    /// the emissive factor, or `None` if the material has no emissive
+    /// texture.
+    pub fn emissive_params(&self) -> Option<EmissiveParams> {
+        self.emissive_texture().and_then(|emissive| {
+            let transform = emissive.uv_transform();
+            let texture = emissive.texture();
+            Some(EmissiveParams {
+                texture: texture.index(),
+                image: texture.source()?.index(),
+                tex_coord: emissive.effective_tex_coord(),
+                transform,
+                factor: self.emissive_factor(),
+            })
+        })
+    }
+
     /// Specifies whether the material is unlit.
     /// 
     /// Returns `true` if the [`KHR_materials_unlit`] property was specified, in which 
@@ -170,12 +724,236 @@ impl<'a> Material<'a> {
             .map_or(false, |extensions| extensions.unlit.is_some())
     }
 
+    /// Returns the names of the extensions specified on this material, sorted
+    /// by name.
+    ///
+    /// Only extensions enabled by crate features are reported.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// let gltf = gltf::Gltf::open("examples/Box.gltf")?;
+    /// let material = gltf.materials().next().unwrap();
+    /// assert_eq!(material.extension_names().count(), 0);
+    /// assert!(!material.has_extension("KHR_materials_unlit"));
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    pub fn extension_names(&self) -> impl Iterator<Item = &'static str> + 'a {
+        let material = self.clone();
+        json::extensions::ENABLED_EXTENSIONS
+            .iter()
+            .cloned()
+            .filter(move |name| material.has_extension(name))
+    }
+
+    /// Returns `true` if the named extension is specified on this material.
+    ///
+    /// Always returns `false` for extensions not enabled by crate features.
+    pub fn has_extension(&self, name: &str) -> bool {
+        match (name, self.json.extensions.as_ref()) {
+            #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+            ("KHR_materials_pbrSpecularGlossiness", Some(extensions)) => {
+                extensions.pbr_specular_glossiness.is_some()
+            },
+            #[cfg(feature = "KHR_materials_unlit")]
+            ("KHR_materials_unlit", Some(extensions)) => extensions.unlit.is_some(),
+            #[cfg(feature = "KHR_xmp_json_ld")]
+            ("KHR_xmp_json_ld", Some(extensions)) => extensions.khr_xmp_json_ld.is_some(),
+            _ => false,
+        }
+    }
+
+    /// Returns the set of shader features used by this material.
+    ///
+    /// Only the material itself is inspected; textures are not resolved.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// use gltf::material::MaterialFeatures;
+    ///
+    /// let gltf = gltf::Gltf::open("examples/Box.gltf")?;
+    /// let material = gltf.materials().next().unwrap();
+    /// assert_eq!(material.features(), MaterialFeatures::empty());
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    pub fn features(&self) -> MaterialFeatures {
+        let json = self.json;
+        let pbr = &json.pbr_metallic_roughness;
+        let mut features = MaterialFeatures::empty();
+        let mut tex_coords = Vec::new();
+        if let Some(ref info) = pbr.base_color_texture {
+            features.insert(MaterialFeatures::BASE_COLOR_TEXTURE);
+            tex_coords.push(info.effective_tex_coord());
+        }
+        if let Some(ref info) = pbr.metallic_roughness_texture {
+            features.insert(MaterialFeatures::METALLIC_ROUGHNESS_TEXTURE);
+            tex_coords.push(info.effective_tex_coord());
+        }
+        if let Some(ref normal) = json.normal_texture {
+            features.insert(MaterialFeatures::NORMAL_MAP);
+            tex_coords.push(normal.effective_tex_coord());
+        }
+        if let Some(ref occlusion) = json.occlusion_texture {
+            features.insert(MaterialFeatures::OCCLUSION);
+            tex_coords.push(occlusion.effective_tex_coord());
+        }
+        if let Some(ref info) = json.emissive_texture {
+            features.insert(MaterialFeatures::EMISSIVE);
+            tex_coords.push(info.effective_tex_coord());
+        }
+        if json.emissive_factor.0 != [0.0; 3] {
+            features.insert(MaterialFeatures::EMISSIVE);
+        }
+        match self.alpha_mode() {
+            AlphaMode::Mask => features.insert(MaterialFeatures::ALPHA_MASK),
+            AlphaMode::Blend => features.insert(MaterialFeatures::ALPHA_BLEND),
+            AlphaMode::Opaque => {},
+        }
+        features.set(MaterialFeatures::DOUBLE_SIDED, json.double_sided);
+        #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+        {
+            let extension = json.extensions
+                .as_ref()
+                .and_then(|extensions| extensions.pbr_specular_glossiness.as_ref());
+            if let Some(extension) = extension {
+                features.insert(MaterialFeatures::SPECULAR_GLOSSINESS);
+                if let Some(ref info) = extension.diffuse_texture {
+                    features.insert(MaterialFeatures::DIFFUSE_TEXTURE);
+                    tex_coords.push(info.effective_tex_coord());
+                }
+                if let Some(ref info) = extension.specular_glossiness_texture {
+                    features.insert(MaterialFeatures::SPECULAR_GLOSSINESS_TEXTURE);
+                    tex_coords.push(info.effective_tex_coord());
+                }
+            }
+        }
+        #[cfg(feature = "KHR_materials_unlit")]
+        features.set(MaterialFeatures::UNLIT, self.unlit());
+        features.set(MaterialFeatures::TEXCOORD_1_USED, tex_coords.contains(&1));
+        features
+    }
+
+    /// Returns a key for sorting draw calls by material state.
+    ///
+    /// Draws sharing a key can be batched together. The key is stable across
+    /// runs and has the following bit layout, from most to least significant:
+    ///
+    /// | Bits    | Contents                                                  |
+    /// |---------|-----------------------------------------------------------|
+    /// | 62 - 63 | Alpha mode: `0` for opaque, `1` for mask, `2` for blend.  |
+    /// | 61      | Set if the material is double sided.                      |
+    /// | 58 - 60 | Reserved; populated by [`Primitive::sort_key`].           |
+    /// | 32 - 57 | The bits of [`features`].                                 |
+    /// | 0 - 31  | The base color texture index plus one, or `0` if none.    |
+    ///
+    /// Since the alpha mode occupies the highest bits, sorting by key in
+    /// ascending order draws opaque materials first, then masked, and blended
+    /// materials last.
+    ///
+    /// [`Primitive::sort_key`]: ../mesh/struct.Primitive.html#method.sort_key
+    /// [`features`]: #method.features
+    pub fn sort_key(&self) -> u64 {
+        let alpha_mode = match self.alpha_mode() {
+            AlphaMode::Opaque => 0,
+            AlphaMode::Mask => 1,
+            AlphaMode::Blend => 2,
+        };
+        let texture = self.json.pbr_metallic_roughness
+            .base_color_texture
+            .as_ref()
+            .map_or(0, |info| info.index.value() as u64 + 1);
+        (alpha_mode << 62)
+            | ((self.double_sided() as u64) << 61)
+            | ((u64::from(self.features().bits()) & 0x3ff_ffff) << 32)
+            | (texture & 0xffff_ffff)
+    }
+
+    /// Copies the resolved properties of the material into a `MaterialData`,
+    /// which does not borrow the document.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// use std::collections::HashMap;
+    ///
+    /// let mut cache = HashMap::new();
+    /// {
+    ///     let gltf = gltf::Gltf::open("examples/Box.gltf")?;
+    ///     for material in gltf.materials() {
+    ///         cache.insert(material.index().unwrap(), material.detach());
+    ///     }
+    /// }
+    /// assert_eq!(cache[&0].base_color_factor, [0.8, 0.0, 0.0, 1.0]);
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    pub fn detach(&self) -> MaterialData {
+        let pbr = self.pbr_metallic_roughness();
+        let info = |info: texture::Info| TextureData::new(&info.texture(), info.tex_coord());
+        let normal = self.normal_texture();
+        let occlusion = self.occlusion_texture();
+        MaterialData {
+            index: self.index,
+            #[cfg(feature = "names")]
+            name: self.name().map(String::from),
+            alpha_mode: self.alpha_mode(),
+            alpha_cutoff: self.alpha_cutoff(),
+            double_sided: self.double_sided(),
+            base_color_factor: pbr.base_color_factor(),
+            base_color_texture: pbr.base_color_texture().and_then(info),
+            metallic_factor: pbr.metallic_factor(),
+            roughness_factor: pbr.roughness_factor(),
+            metallic_roughness_texture: pbr.metallic_roughness_texture().and_then(info),
+            normal_texture: normal
+                .as_ref()
+                .and_then(|x| TextureData::new(&x.texture(), x.tex_coord())),
+            normal_scale: normal.as_ref().map_or(1.0, NormalTexture::scale),
+            occlusion_texture: occlusion
+                .as_ref()
+                .and_then(|x| TextureData::new(&x.texture(), x.tex_coord())),
+            occlusion_strength: occlusion.as_ref().map_or(1.0, OcclusionTexture::strength),
+            emissive_texture: self.emissive_texture().and_then(info),
+            emissive_factor: self.emissive_factor(),
+            unlit: self.has_extension("KHR_materials_unlit"),
+        }
+    }
+
+    /// Returns the XMP metadata packet of this material, as defined by the
+    /// `KHR_xmp_json_ld` extension.
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    pub fn xmp_packet(&self) -> Option<&'a json::extensions::root::khr_xmp_json_ld::Packet> {
+        self.document.xmp_packet(self.json.extensions.as_ref()?.khr_xmp_json_ld.as_ref()?)
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
     }
 }
 
+/// The parameters of the material model of a material, as returned by
+/// [`Material::pbr`].
+///
+/// [`Material::pbr`]: struct.Material.html#method.pbr
+pub enum Pbr<'a> {
+    /// The core metallic-roughness material model.
+    MetallicRoughness(PbrMetallicRoughness<'a>),
+
+    /// The specular-glossiness material model of the
+    /// `KHR_materials_pbrSpecularGlossiness` extension.
+    #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+    SpecularGlossiness(PbrSpecularGlossiness<'a>),
+}
+
 /// A set of parameter values that are used to define the metallic-roughness
 /// material model from Physically-Based Rendering (PBR) methodology.
 pub struct PbrMetallicRoughness<'a> {
@@ -208,9 +986,9 @@ impl<'a> PbrMetallicRoughness<'a> {
     /// Returns the base color texture. The texture contains RGB(A) components
     /// in sRGB color space.
     pub fn base_color_texture(&self) -> Option<texture::Info<'a>> {
-        self.json.base_color_texture.as_ref().map(|json| {
-            let texture = self.document.textures().nth(json.index.value()).unwrap();
-            texture::Info::new(texture, json)
+        self.json.base_color_texture.as_ref().and_then(|json| {
+            let texture = self.document.get(json.index)?;
+            Some(texture::Info::new(texture, json))
         })
     }
 
@@ -238,9 +1016,9 @@ impl<'a> PbrMetallicRoughness<'a> {
     /// These values are linear. If other channels are present (R or A),
     /// they are ignored for metallic-roughness calculations.
     pub fn metallic_roughness_texture(&self) -> Option<texture::Info<'a>> {
-        self.json.metallic_roughness_texture.as_ref().map(|json| {
-            let texture = self.document.textures().nth(json.index.value()).unwrap();
-            texture::Info::new(texture, json)
+        self.json.metallic_roughness_texture.as_ref().and_then(|json| {
+            let texture = self.document.get(json.index)?;
+            Some(texture::Info::new(texture, json))
         })
     }
 
@@ -283,9 +1061,9 @@ impl<'a> PbrSpecularGlossiness<'a> {
 
     /// Returns the base color texture.
     pub fn diffuse_texture(&self) -> Option<texture::Info<'a>> {
-        self.json.diffuse_texture.as_ref().map(|json| {
-            let texture = self.document.textures().nth(json.index.value()).unwrap();
-            texture::Info::new(texture, json)
+        self.json.diffuse_texture.as_ref().and_then(|json| {
+            let texture = self.document.get(json.index)?;
+            Some(texture::Info::new(texture, json))
         })
     }
 
@@ -313,9 +1091,9 @@ impl<'a> PbrSpecularGlossiness<'a> {
     /// components) and its glossiness (A component). The color values are in
     /// sRGB space.
     pub fn specular_glossiness_texture(&self) -> Option<texture::Info<'a>> {
-        self.json.specular_glossiness_texture.as_ref().map(|json| {
-            let texture = self.document.textures().nth(json.index.value()).unwrap();
-            texture::Info::new(texture, json)
+        self.json.specular_glossiness_texture.as_ref().and_then(|json| {
+            let texture = self.document.get(json.index)?;
+            Some(texture::Info::new(texture, json))
         })
     }
 
@@ -356,11 +1134,37 @@ impl<'a> NormalTexture<'a> {
         self.json.tex_coord
     }
 
+    /// Returns the set index of the `TEXCOORD` attribute the texture is
+    /// sampled with, which `KHR_texture_transform` may override, see
+    /// `texture::Info::effective_tex_coord`.
+    pub fn effective_tex_coord(&self) -> u32 {
+        self.json.effective_tex_coord()
+    }
+
     /// Returns the referenced texture.
     pub fn texture(&self) -> texture::Texture<'a> {
         self.texture.clone()
     }
 
+    /// Returns the `KHR_texture_transform` extension of the reference.
+    #[cfg(feature = "KHR_texture_transform")]
+    pub fn texture_transform(&self) -> Option<texture::TextureTransform<'a>> {
+        self.transform_json().map(texture::TextureTransform::new)
+    }
+
+    /// Returns the transform of the texture coordinates.
+    pub(crate) fn uv_transform(&self) -> UvTransform {
+        #[cfg(feature = "KHR_texture_transform")]
+        return UvTransform::from_json(self.transform_json());
+        #[cfg(not(feature = "KHR_texture_transform"))]
+        return UvTransform::IDENTITY;
+    }
+
+    #[cfg(feature = "KHR_texture_transform")]
+    fn transform_json(&self) -> Option<&'a json::extensions::texture::TextureTransform> {
+        self.json.extensions.as_ref().and_then(|x| x.texture_transform.as_ref())
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
@@ -398,10 +1202,36 @@ impl<'a> OcclusionTexture<'a> {
         self.json.tex_coord
     }
 
+    /// Returns the set index of the `TEXCOORD` attribute the texture is
+    /// sampled with, which `KHR_texture_transform` may override, see
+    /// `texture::Info::effective_tex_coord`.
+    pub fn effective_tex_coord(&self) -> u32 {
+        self.json.effective_tex_coord()
+    }
+
     /// Returns the referenced texture.
     pub fn texture(&self) -> texture::Texture<'a> {
         self.texture.clone()
     }
+
+    /// Returns the `KHR_texture_transform` extension of the reference.
+    #[cfg(feature = "KHR_texture_transform")]
+    pub fn texture_transform(&self) -> Option<texture::TextureTransform<'a>> {
+        self.transform_json().map(texture::TextureTransform::new)
+    }
+
+    /// Returns the transform of the texture coordinates.
+    pub(crate) fn uv_transform(&self) -> UvTransform {
+        #[cfg(feature = "KHR_texture_transform")]
+        return UvTransform::from_json(self.transform_json());
+        #[cfg(not(feature = "KHR_texture_transform"))]
+        return UvTransform::IDENTITY;
+    }
+
+    #[cfg(feature = "KHR_texture_transform")]
+    fn transform_json(&self) -> Option<&'a json::extensions::texture::TextureTransform> {
+        self.json.extensions.as_ref().and_then(|x| x.texture_transform.as_ref())
+    }
     
     /// Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
@@ -409,6 +1239,25 @@ impl<'a> OcclusionTexture<'a> {
     }
 }
 
+/// Counts the materials of a document whose occlusion is packed into their
+/// metallic-roughness texture.
+pub(crate) fn packed_occlusion_stats(document: &Document) -> PackedOcclusionStats {
+    let mut stats = PackedOcclusionStats::default();
+    for material in document.materials() {
+        stats.materials += 1;
+        if material.occlusion_texture().is_none() {
+            continue;
+        }
+        stats.occlusion += 1;
+        if material.occlusion_packed_in_metallic_roughness() {
+            stats.packed += 1;
+        } else if material.pbr_metallic_roughness().metallic_roughness_texture().is_some() {
+            stats.unpacked.extend(material.index());
+        }
+    }
+    stats
+}
+
 impl<'a> AsRef<texture::Texture<'a>> for NormalTexture<'a> {
     fn as_ref(&self) -> &texture::Texture<'a> {
         &self.texture
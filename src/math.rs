@@ -260,7 +260,6 @@ impl Matrix4 {
         }
     }
 
-    #[cfg(test)]
     pub fn from_array(m: [[f32; 4]; 4]) -> Matrix4 {
         Matrix4::new(
             m[0][0], m[0][1], m[0][2], m[0][3],
@@ -316,6 +315,53 @@ impl Matrix4 {
         )
     }
 
+    /// Compute the inverse of the matrix, or `None` if it is singular.
+    pub fn invert(&self) -> Option<Matrix4> {
+        // Laplace expansion by 2x2 sub-determinants. Since the inverse of the
+        // transpose is the transpose of the inverse, the column-major array
+        // may be treated as row-major throughout.
+        let a = self.as_array();
+        let s0 = a[0][0] * a[1][1] - a[1][0] * a[0][1];
+        let s1 = a[0][0] * a[1][2] - a[1][0] * a[0][2];
+        let s2 = a[0][0] * a[1][3] - a[1][0] * a[0][3];
+        let s3 = a[0][1] * a[1][2] - a[1][1] * a[0][2];
+        let s4 = a[0][1] * a[1][3] - a[1][1] * a[0][3];
+        let s5 = a[0][2] * a[1][3] - a[1][2] * a[0][3];
+        let c5 = a[2][2] * a[3][3] - a[3][2] * a[2][3];
+        let c4 = a[2][1] * a[3][3] - a[3][1] * a[2][3];
+        let c3 = a[2][1] * a[3][2] - a[3][1] * a[2][2];
+        let c2 = a[2][0] * a[3][3] - a[3][0] * a[2][3];
+        let c1 = a[2][0] * a[3][2] - a[3][0] * a[2][2];
+        let c0 = a[2][0] * a[3][1] - a[3][0] * a[2][1];
+
+        let det = s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0;
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+        let d = 1.0 / det;
+        Some(Matrix4::new(
+            (a[1][1] * c5 - a[1][2] * c4 + a[1][3] * c3) * d,
+            (-a[0][1] * c5 + a[0][2] * c4 - a[0][3] * c3) * d,
+            (a[3][1] * s5 - a[3][2] * s4 + a[3][3] * s3) * d,
+            (-a[2][1] * s5 + a[2][2] * s4 - a[2][3] * s3) * d,
+
+            (-a[1][0] * c5 + a[1][2] * c2 - a[1][3] * c1) * d,
+            (a[0][0] * c5 - a[0][2] * c2 + a[0][3] * c1) * d,
+            (-a[3][0] * s5 + a[3][2] * s2 - a[3][3] * s1) * d,
+            (a[2][0] * s5 - a[2][2] * s2 + a[2][3] * s1) * d,
+
+            (a[1][0] * c4 - a[1][1] * c2 + a[1][3] * c0) * d,
+            (-a[0][0] * c4 + a[0][1] * c2 - a[0][3] * c0) * d,
+            (a[3][0] * s4 - a[3][1] * s2 + a[3][3] * s0) * d,
+            (-a[2][0] * s4 + a[2][1] * s2 - a[2][3] * s0) * d,
+
+            (-a[1][0] * c3 + a[1][1] * c1 - a[1][2] * c0) * d,
+            (a[0][0] * c3 - a[0][1] * c1 + a[0][2] * c0) * d,
+            (-a[3][0] * s3 + a[3][1] * s1 - a[3][2] * s0) * d,
+            (a[2][0] * s3 - a[2][1] * s1 + a[2][2] * s0) * d,
+        ))
+    }
+
     pub fn as_array(&self) -> [[f32; 4]; 4] {
         [
             self.x.as_array(),
@@ -14,6 +14,9 @@ pub struct MorphTargets<'a> {
 }
 
 /// An `Iterator` that visits the attributes of a `Primitive`.
+///
+/// Attributes with an invalid semantic or a missing accessor, which are only
+/// possible for documents loaded without validation, are skipped.
 #[derive(Clone, Debug)]
 pub struct Attributes<'a> {
     /// The parent `Document` struct.
@@ -23,7 +26,7 @@ pub struct Attributes<'a> {
     pub(crate) prim: Primitive<'a>,
 
     /// The internal attribute iterator.
-    pub(crate) iter: collections::hash_map::Iter<
+    pub(crate) iter: collections::btree_map::Iter<
             'a,
         json::validation::Checked<json::mesh::Semantic>,
         json::Index<json::accessor::Accessor>,
@@ -40,21 +43,19 @@ pub struct Primitives<'a>  {
     pub(crate) iter: iter::Enumerate<slice::Iter<'a, json::mesh::Primitive>>,
 }
 
-impl<'a> ExactSizeIterator for Attributes<'a> {}
 impl<'a> Iterator for Attributes<'a> {
     type Item = Attribute<'a>;
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter
-            .next()
-            .map(|(key, index)| {
-                let semantic = key.as_ref().unwrap().clone();
-                let accessor = self.document.accessors().nth(index.value()).unwrap();
-                (semantic, accessor)
-            })
+        let document = self.document;
+        self.iter.find_map(|(key, index)| {
+            let semantic = key.as_ref().valid()?.clone();
+            let accessor = document.get(*index)?;
+            Some((semantic, accessor))
+        })
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.iter.size_hint()
+        (0, self.iter.size_hint().1)
     }
 }
 
@@ -79,13 +80,13 @@ impl<'a> Iterator for MorphTargets<'a> {
             .map(|json| {
                 let positions = json.positions
                     .as_ref()
-                    .map(|index| self.document.accessors().nth(index.value()).unwrap());
+                    .and_then(|index| self.document.get(*index));
                 let normals = json.normals
                     .as_ref()
-                    .map(|index| self.document.accessors().nth(index.value()).unwrap());
+                    .and_then(|index| self.document.get(*index));
                 let tangents = json.tangents
                     .as_ref()
-                    .map(|index| self.document.accessors().nth(index.value()).unwrap());
+                    .and_then(|index| self.document.get(*index));
                 MorphTarget {
                     positions,
                     normals,
@@ -51,6 +51,13 @@
 /// Iterators.
 pub mod iter;
 
+/// Index buffer optimization.
+pub mod optimize;
+
+/// Mesh simplification.
+#[cfg(feature = "simplify")]
+pub mod simplify;
+
 /// Utility functions.
 #[cfg(feature = "utils")]
 pub mod util;
@@ -58,10 +65,12 @@ pub mod util;
 use crate::{Accessor, Buffer, Document, Material};
 
 #[cfg(feature = "utils")]
-use crate::accessor;
+use crate::{accessor, buffer};
 
 pub use json::mesh::{Mode, Semantic};
-use json::validation::Checked;
+
+#[cfg(feature = "simplify")]
+pub use self::simplify::simplify;
 
 /// Vertex attribute data.
 pub type Attribute<'a> = (Semantic, Accessor<'a>);
@@ -79,6 +88,27 @@ pub struct Bounds<T> {
     pub max: T,
 }
 
+/// The largest index of a primitive, as returned by [`Primitive::max_index`].
+///
+/// [`Primitive::max_index`]: struct.Primitive.html#method.max_index
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum MaxIndex {
+    /// The `max` declared by the index accessor.
+    Declared(u32),
+
+    /// The largest index found by reading the index data.
+    Scanned(u32),
+}
+
+impl MaxIndex {
+    /// Returns the largest index, however it was found.
+    pub fn value(self) -> u32 {
+        match self {
+            MaxIndex::Declared(value) | MaxIndex::Scanned(value) => value,
+        }
+    }
+}
+
 /// A set of primitives to be rendered.
 #[derive(Clone, Debug)]
 pub struct Mesh<'a>  {
@@ -92,6 +122,8 @@ pub struct Mesh<'a>  {
     json: &'a json::mesh::Mesh,
 }
 
+impl_identity!(Mesh);
+
 /// A single morph target for a mesh primitive.
 #[derive(Clone, Debug)]
 pub struct MorphTarget<'a> {
@@ -118,6 +150,36 @@ pub struct Primitive<'a>  {
     json: &'a json::mesh::Primitive,
 }
 
+/// Primitives are equal when they are the same primitive of the same mesh, see
+/// the implementation for `Mesh`.
+impl<'a> PartialEq for Primitive<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.mesh == other.mesh && self.index == other.index
+    }
+}
+
+impl<'a> Eq for Primitive<'a> {}
+
+impl<'a> std::hash::Hash for Primitive<'a> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.mesh.hash(state);
+        self.index.hash(state);
+    }
+}
+
+impl<'a> PartialOrd for Primitive<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Primitives are ordered by mesh, then by their index within the mesh.
+impl<'a> Ord for Primitive<'a> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (&self.mesh, self.index).cmp(&(&other.mesh, other.index))
+    }
+}
+
 /// Mesh primitive reader.
 #[derive(Clone, Debug)]
 pub struct Reader<'a, 's, F>
@@ -147,6 +209,13 @@ impl<'a> Mesh<'a>  {
         self.index
     }
 
+    /// Returns the XMP metadata packet of this mesh, as defined by the
+    /// `KHR_xmp_json_ld` extension.
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    pub fn xmp_packet(&self) -> Option<&'a json::extensions::root::khr_xmp_json_ld::Packet> {
+        self.document.xmp_packet(self.json.extensions.as_ref()?.khr_xmp_json_ld.as_ref()?)
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
@@ -155,7 +224,7 @@ impl<'a> Mesh<'a>  {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     pub fn name(&self) -> Option<&'a str> {
-        self.json.name.as_ref().map(String::as_str)
+        self.json.name.as_deref()
     }
 
     /// Defines the geometry to be renderered with a material.
@@ -187,13 +256,14 @@ impl<'a> Primitive<'a> {
     }
 
     /// Returns the bounds of the `POSITION` vertex attribute.
-    pub fn bounding_box(&self) -> BoundingBox {
-        // NOTE: cannot panic if validated "minimally"
-        let pos_accessor_index = self.json.attributes.get(&Checked::Valid(Semantic::Positions)).unwrap();
-        let pos_accessor = self.mesh.document.accessors().nth(pos_accessor_index.value()).unwrap();
-        let min: [f32; 3] = json::deserialize::from_value(pos_accessor.min().unwrap()).unwrap();
-        let max: [f32; 3] = json::deserialize::from_value(pos_accessor.max().unwrap()).unwrap();
-        Bounds { min, max }
+    ///
+    /// Returns `None` if the attribute or its bounds are missing or
+    /// malformed, which validation rejects.
+    pub fn bounding_box(&self) -> Option<BoundingBox> {
+        let pos_accessor = self.get(&Semantic::Positions)?;
+        let min: [f32; 3] = json::deserialize::from_value(pos_accessor.min()?).ok()?;
+        let max: [f32; 3] = json::deserialize::from_value(pos_accessor.max()?).ok()?;
+        Some(Bounds { min, max })
     }
 
     /// Optional application specific data.
@@ -205,22 +275,190 @@ impl<'a> Primitive<'a> {
     pub fn get(&self, semantic: &Semantic) -> Option<Accessor<'a>> {
         self.json.attributes
             .get(&json::validation::Checked::Valid(semantic.clone()))
-            .map(|index| self.mesh.document.accessors().nth(index.value()).unwrap())
+            .and_then(|index| self.mesh.document.get(*index))
     }
 
-    /// Returns the internal JSON index.
+    /// Returns the internal JSON index of the primitive within its mesh.
     pub fn index(&self) -> usize {
         self.index
     }
 
+    /// Returns the mesh containing this primitive.
+    pub fn mesh(&self) -> Mesh<'a> {
+        self.mesh.clone()
+    }
+
     /// Returns the accessor containing the primitive indices, if provided.
     pub fn indices(&self) -> Option<Accessor<'a>> {
         self.json.indices
             .as_ref()
-            .map(|index| self.mesh.document.accessors().nth(index.value()).unwrap())
+            .and_then(|index| self.mesh.document.get(*index))
+    }
+
+    /// Returns `false` if the accessor of the indices cannot be used as
+    /// indices, see `json::accessor::Accessor::is_index_compatible`.
+    ///
+    /// Validation rejects such primitives with `Error::InvalidIndexAccessor`,
+    /// so this only happens for documents loaded without validation, whose
+    /// [`validation_report`] lists the error. The readers of such primitives
+    /// skip them, reading no data at all instead of misread indices.
+    ///
+    /// [`validation_report`]: ../struct.Document.html#method.validation_report
+    pub fn has_valid_indices(&self) -> bool {
+        let root = &self.mesh.document.0;
+        self.json.indices
+            .as_ref()
+            .and_then(|index| root.get(*index))
+            .map_or(true, |accessor| accessor.is_index_compatible(root))
+    }
+
+    /// Returns the number of vertices of the primitive.
+    ///
+    /// This is the count of the `POSITION` accessor, or of the attribute with
+    /// the first name in alphabetical order if there is no `POSITION`, since
+    /// every attribute of a valid primitive has the same count. Returns zero
+    /// if the primitive has no attributes.
+    pub fn vertex_count(&self) -> usize {
+        if let Some(positions) = self.get(&Semantic::Positions) {
+            return positions.count();
+        }
+        self.attributes()
+            .map(|(semantic, accessor)| (semantic.to_string(), accessor.count()))
+            .min()
+            .map_or(0, |(_, count)| count)
+    }
+
+    /// Returns the number of indices of the primitive, or `None` if the
+    /// primitive is not indexed.
+    pub fn index_count(&self) -> Option<usize> {
+        self.indices().map(|accessor| accessor.count())
+    }
+
+    /// Returns the largest index of the primitive, or `None` if the primitive
+    /// is not indexed.
+    ///
+    /// The `max` declared by the index accessor is used if present, without
+    /// reading any data. Otherwise the indices are read from the data returned
+    /// by `get_buffer_data`, which requires the `utils` feature. `None` is
+    /// also returned if the indices cannot be read or there are none.
+    ///
+    /// Validation checks that the declared `max` refers to an existing
+    /// vertex, and [`Document::validate_buffer_data`] checks every index.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// use gltf::mesh::MaxIndex;
+    ///
+    /// let (document, buffers, _) = gltf::import("examples/Box.gltf")?;
+    /// let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    /// let get_buffer_data = |buffer: gltf::Buffer| buffers.get(buffer.index()).map(|x| &**x);
+    /// assert_eq!(primitive.max_index(Some(get_buffer_data)), Some(MaxIndex::Declared(23)));
+    /// assert_eq!(primitive.vertex_count(), 24);
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    ///
+    /// [`Document::validate_buffer_data`]: ../struct.Document.html#method.validate_buffer_data
+    pub fn max_index<'s, F>(&self, get_buffer_data: Option<F>) -> Option<MaxIndex>
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        let accessor = self.indices()?;
+        let declared = accessor.max().and_then(|max| max.get(0).and_then(|x| x.as_f64()));
+        if let Some(max) = declared {
+            return Some(MaxIndex::Declared(max as u32));
+        }
+        #[cfg(feature = "utils")]
+        {
+            use accessor::DataType;
+            use self::util::ReadIndices;
+            let get_buffer_data = get_buffer_data?;
+            let indices = match accessor.data_type()? {
+                DataType::U8 => accessor::Iter::new(accessor, get_buffer_data).map(ReadIndices::U8),
+                DataType::U16 => accessor::Iter::new(accessor, get_buffer_data).map(ReadIndices::U16),
+                DataType::U32 => accessor::Iter::new(accessor, get_buffer_data).map(ReadIndices::U32),
+                _ => None,
+            };
+            indices?.into_u32().max().map(MaxIndex::Scanned)
+        }
+        #[cfg(not(feature = "utils"))]
+        {
+            let _ = get_buffer_data;
+            None
+        }
+    }
+
+    /// Returns the number of triangles drawn by the primitive, which is zero
+    /// for points and lines.
+    ///
+    /// Without buffer data the count follows from the number of indices, or
+    /// of vertices if the primitive is not indexed. With buffer data,
+    /// degenerate triangles with a repeated index, such as those joining the
+    /// strips of a `TriangleStrip`, are not counted.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let (document, buffers, _) = gltf::import("examples/Box.gltf")?;
+    /// let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    /// let get_buffer_data = |buffer: gltf::Buffer| buffers.get(buffer.index()).map(|x| &**x);
+    /// assert_eq!(primitive.triangle_count(Some(get_buffer_data)), 12);
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    pub fn triangle_count<'s, F>(&self, get_buffer_data: Option<F>) -> usize
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        let count = self.index_count().unwrap_or_else(|| self.vertex_count());
+        let declared = match self.mode() {
+            Mode::Triangles => count / 3,
+            Mode::TriangleStrip | Mode::TriangleFan => count.saturating_sub(2),
+            _ => return 0,
+        };
+        #[cfg(feature = "utils")]
+        {
+            use accessor::DataType;
+            use self::util::ReadIndices;
+            let (accessor, get_buffer_data) = match (self.indices(), get_buffer_data) {
+                (Some(accessor), Some(get_buffer_data)) if self.has_valid_indices() => (accessor, get_buffer_data),
+                _ => return declared,
+            };
+            let indices = match accessor.data_type() {
+                Some(DataType::U8) => accessor::Iter::new(accessor, get_buffer_data).map(ReadIndices::U8),
+                Some(DataType::U16) => accessor::Iter::new(accessor, get_buffer_data).map(ReadIndices::U16),
+                Some(DataType::U32) => accessor::Iter::new(accessor, get_buffer_data).map(ReadIndices::U32),
+                _ => None,
+            };
+            let indices = match indices {
+                Some(indices) => indices.into_u32().collect::<Vec<_>>(),
+                None => return declared,
+            };
+            let distinct = |a: u32, b: u32, c: u32| a != b && b != c && a != c;
+            match self.mode() {
+                Mode::Triangles => indices.chunks_exact(3).filter(|x| distinct(x[0], x[1], x[2])).count(),
+                Mode::TriangleStrip => indices.windows(3).filter(|x| distinct(x[0], x[1], x[2])).count(),
+                _ => indices.windows(2).skip(1).filter(|x| distinct(indices[0], x[0], x[1])).count(),
+            }
+        }
+        #[cfg(not(feature = "utils"))]
+        {
+            let _ = get_buffer_data;
+            declared
+        }
     }
 
     /// Returns an `Iterator` that visits the vertex attributes.
+    ///
+    /// The attributes are visited in the order of the specification:
+    /// `POSITION`, `NORMAL`, `TANGENT`, then the `TEXCOORD_n`, `COLOR_n`,
+    /// `JOINTS_n`, and `WEIGHTS_n` sets by ascending index, and finally
+    /// application-specific attributes sorted by name.
     pub fn attributes(&self) -> iter::Attributes<'a> {
         iter::Attributes {
             document: self.mesh.document,
@@ -229,17 +467,55 @@ impl<'a> Primitive<'a> {
         }
     }
 
-    /// Returns the material to apply to this primitive when rendering
+    /// Returns the material to apply to this primitive when rendering.
+    ///
+    /// Primitives without a material use the default material defined by the
+    /// specification, whose [`index`] is `None`.
+    ///
+    /// [`index`]: ../material/struct.Material.html#method.index
     pub fn material(&self) -> Material<'a> {
         self.json.material
             .as_ref()
-            .map(|index| self.mesh.document.materials().nth(index.value()).unwrap())
+            .and_then(|index| self.mesh.document.get(*index))
             .unwrap_or_else(|| Material::default(self.mesh.document))
     }
 
+    /// Returns the index of the material of this primitive, or `None` if it
+    /// uses the default material.
+    ///
+    /// Writers should use this function, since the default material is not
+    /// part of the document and must not be written.
+    pub fn material_index(&self) -> Option<usize> {
+        self.json.material.map(|index| index.value())
+    }
+
     /// The type of primitives to render.
+    ///
+    /// An invalid mode, which is only possible for documents loaded without
+    /// validation, is read as the default `Triangles`.
     pub fn mode(&self) -> Mode {
-        self.json.mode.unwrap()
+        self.json.mode.valid().unwrap_or_default()
+    }
+
+    /// Returns a key for sorting draw calls by pipeline state and material.
+    ///
+    /// This is the [`Material::sort_key`] of the primitive's material with
+    /// the primitive mode stored in bits 58 - 60, so primitives sharing a key
+    /// share both their material state and their topology.
+    ///
+    /// [`Material::sort_key`]: ../material/struct.Material.html#method.sort_key
+    pub fn sort_key(&self) -> u64 {
+        self.material().sort_key() | (u64::from(self.mode().as_gl_enum()) << 58)
+    }
+
+    /// Returns the number of joint sets, that is the number of consecutive
+    /// `JOINTS_n` and `WEIGHTS_n` attribute pairs starting from set 0.
+    pub fn joint_set_count(&self) -> u32 {
+        (0..)
+            .take_while(|&set| {
+                self.get(&Semantic::Joints(set)).is_some() && self.get(&Semantic::Weights(set)).is_some()
+            })
+            .count() as u32
     }
 
     /// Returns an `Iterator` that visits the morph targets of the primitive.
@@ -268,29 +544,155 @@ impl<'a> Primitive<'a> {
     {
         Reader { primitive: self, get_buffer_data }
     }
+
+    /// Constructs the primitive reader, fetching the data of every buffer read
+    /// by the primitive up front.
+    ///
+    /// Unlike [`reader`], the data is returned as a `Result`, and data shorter
+    /// than the `byteLength` of its buffer is reported as
+    /// `Error::BufferLength` instead of failing as an out-of-range read.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// let (gltf, buffers, _) = gltf::import("examples/Box.gltf")?;
+    /// let primitive = gltf.meshes().next().unwrap().primitives().next().unwrap();
+    /// let truncated = &buffers[0][..8];
+    /// let error = primitive.try_reader(|_| Ok(truncated)).err();
+    /// assert!(matches!(error, Some(gltf::Error::BufferLength { actual: 8, .. })));
+    /// let reader = primitive.try_reader(|buffer| Ok(&buffers[buffer.index()]))?;
+    /// assert_eq!(reader.read_positions().unwrap().len(), 24);
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    ///
+    /// [`reader`]: #method.reader
+    #[cfg(feature = "utils")]
+    pub fn try_reader<'s, F>(
+        &'a self,
+        get_buffer_data: F,
+    ) -> crate::Result<Reader<'a, 's, impl Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>>>
+    where
+        F: FnMut(Buffer<'a>) -> crate::Result<&'s [u8]>,
+    {
+        let targets = self.morph_targets().flat_map(|x| vec![x.positions(), x.normals(), x.tangents()]);
+        let accessors = self.indices().into_iter()
+            .chain(self.attributes().map(|(_, accessor)| accessor))
+            .chain(targets.flatten());
+        let get_buffer_data = buffer::prefetch(accessors, get_buffer_data)?;
+        Ok(Reader { primitive: self, get_buffer_data })
+    }
+
+    /// Counts the texture coordinates outside `[0, 1]` along an axis that a
+    /// texture of the primitive's material wraps with
+    /// `WrappingMode::ClampToEdge`, which samples the edge of the texture
+    /// instead of repeating it.
+    ///
+    /// Each `TEXCOORD` set sampled by a texture, after any
+    /// `KHR_texture_transform` override, is streamed once with
+    /// [`accessor::stats`], and an axis is checked if any texture sampling the
+    /// set clamps it. Coordinates are checked before any transform. Sets the
+    /// primitive lacks are skipped.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let (document, buffers, _) = gltf::import("examples/Box.gltf")?;
+    /// let get = |buffer: gltf::Buffer| Some(&*buffers[buffer.index()]);
+    /// let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    /// // The box is untextured, so no coordinate is counted.
+    /// assert_eq!(primitive.uv_out_of_unit_range_count(get)?, 0);
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    ///
+    /// [`accessor::stats`]: ../accessor/fn.stats.html
+    #[cfg(feature = "utils")]
+    pub fn uv_out_of_unit_range_count<'s, F>(&self, get_buffer_data: F) -> Result<usize, accessor::ReadError>
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        use crate::texture::WrappingMode::ClampToEdge;
+        let material = self.material();
+        let pbr = material.pbr_metallic_roughness();
+        let mut textures = vec![
+            pbr.base_color_texture().map(|x| (x.texture(), x.effective_tex_coord())),
+            pbr.metallic_roughness_texture().map(|x| (x.texture(), x.effective_tex_coord())),
+            material.normal_texture().map(|x| (x.texture(), x.effective_tex_coord())),
+            material.occlusion_texture().map(|x| (x.texture(), x.effective_tex_coord())),
+            material.emissive_texture().map(|x| (x.texture(), x.effective_tex_coord())),
+        ];
+        #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+        {
+            if let Some(extension) = material.pbr_specular_glossiness() {
+                textures.push(extension.diffuse_texture().map(|x| (x.texture(), x.effective_tex_coord())));
+                textures.push(extension.specular_glossiness_texture().map(|x| (x.texture(), x.effective_tex_coord())));
+            }
+        }
+
+        // Whether the U and V axes of each set are clamped.
+        let mut clamped = std::collections::BTreeMap::<u32, [bool; 2]>::new();
+        for (texture, set) in textures.into_iter().flatten() {
+            let sampler = texture.sampler();
+            let axes = clamped.entry(set).or_default();
+            axes[0] |= sampler.wrap_s() == ClampToEdge;
+            axes[1] |= sampler.wrap_t() == ClampToEdge;
+        }
+        let mut count = 0;
+        for (set, axes) in clamped {
+            let accessor = match self.get(&Semantic::TexCoords(set)) {
+                Some(accessor) if axes != [false, false] => accessor,
+                _ => continue,
+            };
+            let expected = |axis: usize| match axes.get(axis) {
+                Some(true) => 0.0..=1.0,
+                _ => f32::NEG_INFINITY..=f32::INFINITY,
+            };
+            count += accessor::stats::scan(&accessor, get_buffer_data.clone(), &expected)?.out_of_range;
+        }
+        Ok(count)
+    }
 }
 
 #[cfg(feature = "utils")]
 impl<'a, 's, F> Reader<'a, 's, F>
     where F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
 {
+    /// Returns the accessor with the given semantic, or `None` if the
+    /// primitive is skipped, see [`Primitive::has_valid_indices`].
+    ///
+    /// [`Primitive::has_valid_indices`]: struct.Primitive.html#method.has_valid_indices
+    fn get(&self, semantic: &Semantic) -> Option<Accessor<'a>> {
+        self.primitive.has_valid_indices().then(|| self.primitive.get(semantic))?
+    }
+
+    /// Returns the accessor of the indices, or `None` if the primitive is
+    /// skipped.
+    fn indices(&self) -> Option<Accessor<'a>> {
+        self.primitive.has_valid_indices().then(|| self.primitive.indices())?
+    }
+
     /// Visits the vertex positions of a primitive.
     pub fn read_positions(&self) -> Option<util::ReadPositions<'s>> {
-        self.primitive
+        self
             .get(&Semantic::Positions)
             .and_then(|accessor| accessor::Iter::new(accessor, self.get_buffer_data.clone()))
     }
 
     /// Visits the vertex normals of a primitive.
     pub fn read_normals(&self) -> Option<util::ReadNormals<'s>> {
-        self.primitive
+        self
             .get(&Semantic::Normals)
             .and_then(|accessor| accessor::Iter::new(accessor, self.get_buffer_data.clone()))
     }
 
     /// Visits the vertex tangents of a primitive.
     pub fn read_tangents(&self) -> Option<util::ReadTangents<'s>> {
-        self.primitive
+        self
             .get(&Semantic::Tangents)
             .and_then(|accessor| accessor::Iter::new(accessor, self.get_buffer_data.clone()))
     }
@@ -300,17 +702,17 @@ impl<'a, 's, F> Reader<'a, 's, F>
         use accessor::DataType::{U8, U16, F32};
         use accessor::Dimensions::{Vec3, Vec4};
         use self::util::ReadColors;
-        self.primitive
+        self
             .get(&Semantic::Colors(set))
             .and_then(|accessor| {
-                match (accessor.data_type(), accessor.dimensions()) {
+                match (accessor.data_type()?, accessor.dimensions()?) {
                     (U8, Vec3)  => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadColors::RgbU8),
                     (U16, Vec3) => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadColors::RgbU16),
                     (F32, Vec3) => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadColors::RgbF32),
                     (U8, Vec4)  => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadColors::RgbaU8),
                     (U16, Vec4) => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadColors::RgbaU16),
                     (F32, Vec4) => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadColors::RgbaF32),
-                    _ => unreachable!(),
+                    _ => None,
                 }
             })
     }
@@ -319,14 +721,14 @@ impl<'a, 's, F> Reader<'a, 's, F>
     pub fn read_indices(&self) -> Option<util::ReadIndices<'s>> {
         use accessor::DataType;
         use self::util::ReadIndices;
-        self.primitive
+        self
             .indices()
             .and_then(|accessor| {
-                match accessor.data_type() {
+                match accessor.data_type()? {
                     DataType::U8  => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadIndices::U8),
                     DataType::U16 => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadIndices::U16),
                     DataType::U32 => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadIndices::U32),
-                    _ => unreachable!(),
+                    _ => None,
                 }
             })
     }
@@ -335,13 +737,13 @@ impl<'a, 's, F> Reader<'a, 's, F>
     pub fn read_joints(&self, set: u32) -> Option<util::ReadJoints<'s>> {
         use accessor::DataType;
         use self::util::ReadJoints;
-        self.primitive
+        self
             .get(&Semantic::Joints(set))
             .and_then(|accessor| {
-                match accessor.data_type() {
+                match accessor.data_type()? {
                     DataType::U8  => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadJoints::U8),
                     DataType::U16 => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadJoints::U16),
-                    _ => unreachable!(),
+                    _ => None,
                 }
             })
     }
@@ -350,14 +752,14 @@ impl<'a, 's, F> Reader<'a, 's, F>
     pub fn read_tex_coords(&self, set: u32) -> Option<util::ReadTexCoords<'s>> {
         use accessor::DataType;
         use self::util::ReadTexCoords;
-        self.primitive
+        self
             .get(&Semantic::TexCoords(set))
             .and_then(|accessor| {
-                match accessor.data_type() {
+                match accessor.data_type()? {
                     DataType::U8  => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadTexCoords::U8),
                     DataType::U16 => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadTexCoords::U16),
                     DataType::F32 => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadTexCoords::F32),
-                    _ => unreachable!(),
+                    _ => None,
                 }
             })
     }
@@ -366,18 +768,175 @@ impl<'a, 's, F> Reader<'a, 's, F>
     pub fn read_weights(&self, set: u32) -> Option<util::ReadWeights<'s>>  {
         use self::accessor::DataType;
         use self::util::ReadWeights;
-        self.primitive
+        self
             .get(&Semantic::Weights(set))
             .and_then(|accessor| {
-                match accessor.data_type() {
+                match accessor.data_type()? {
                     DataType::U8  => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadWeights::U8),
                     DataType::U16 => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadWeights::U16),
                     DataType::F32 => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadWeights::F32),
-                    _ => unreachable!(),
+                    _ => None,
                 }
             })
     }
 
+    /// Merges the influences of every joint set into the four largest
+    /// influences of each vertex, since many renderers support no more than
+    /// four influences.
+    ///
+    /// The influences of each vertex are ordered by descending weight. Ties
+    /// are broken by set and then by component, so the result is
+    /// deterministic. The retained weights are renormalized to sum to one,
+    /// unless they are all zero.
+    ///
+    /// Returns `None` if the primitive has no joint sets, see
+    /// [`Primitive::joint_set_count`], or if their data is unavailable.
+    ///
+    /// [`Primitive::joint_set_count`]: struct.Primitive.html#method.joint_set_count
+    pub fn read_merged_influences(&self) -> Option<util::Influences> {
+        let sets = self.primitive.joint_set_count();
+        if sets == 0 {
+            return None;
+        }
+        let mut influences = Vec::<Vec<(u16, f32)>>::new();
+        for set in 0..sets {
+            let joints = self.read_joints(set)?.into_u16();
+            let weights = self.read_weights(set)?.into_f32();
+            influences.resize(joints.len().max(influences.len()), Vec::new());
+            for (vertex, (joints, weights)) in joints.zip(weights).enumerate() {
+                influences[vertex].extend(joints.iter().cloned().zip(weights.iter().cloned()));
+            }
+        }
+
+        let mut merged = util::Influences::default();
+        for mut vertex in influences {
+            // The sort is stable, preserving the order of equal weights.
+            vertex.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+            let (mut joints, mut weights) = ([0; 4], [0.0; 4]);
+            for (i, &(joint, weight)) in vertex.iter().take(4).enumerate() {
+                joints[i] = joint;
+                weights[i] = weight;
+            }
+            let sum = weights.iter().sum::<f32>();
+            if sum > 0.0 {
+                weights.iter_mut().for_each(|x| *x /= sum);
+            }
+            merged.joints.push(joints);
+            merged.weights.push(weights);
+        }
+        Some(merged)
+    }
+
+    /// Visits the values of an application-specific attribute, such as
+    /// `_BATCHID`, given its semantic name including the leading underscore.
+    ///
+    /// Returns `None` if the primitive has no such attribute and an error if
+    /// the data type or dimensions of `T` do not match its accessor. Values are
+    /// not converted, even if the accessor is normalized.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let (document, buffers, _) = gltf::import("tests/batch_id.gltf")?;
+    /// # let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    /// let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+    /// if let Some(batch_ids) = reader.read_custom::<u16>("_BATCHID") {
+    ///     for batch_id in batch_ids? {
+    ///         println!("{}", batch_id);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    pub fn read_custom<T>(&self, semantic: &str) -> Option<Result<accessor::Iter<'s, T>, util::TypeMismatch>>
+    where
+        T: accessor::TypedItem,
+    {
+        if !semantic.starts_with('_') {
+            return None;
+        }
+        let accessor = self.get(&Semantic::Extras(semantic[1..].to_string()))?;
+        let actual = (accessor.data_type()?, accessor.dimensions()?);
+        let requested = (T::data_type(), T::dimensions());
+        if actual != requested {
+            return Some(Err(util::TypeMismatch { accessor: actual, requested }));
+        }
+        accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(Ok)
+    }
+
+    /// Visits the vertices of the primitive as interleaved structs, reading
+    /// the attributes requested by `V` in lockstep.
+    ///
+    /// Missing attributes are filled in with the defaults of the
+    /// [`vertices`] module: normals point up, tangents along +X with `w = 1`,
+    /// texture co-ordinates are zero, and colors opaque white. Texture
+    /// co-ordinates and colors are read from set 0 and converted to floats.
+    ///
+    /// Returns an error if the primitive has no positions, if the data of a
+    /// requested attribute is unavailable, or if a requested attribute has a
+    /// different number of items than `POSITION`.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// use gltf::mesh::util::vertices::StandardVertex;
+    ///
+    /// let (document, buffers, _) = gltf::import("examples/Box.gltf")?;
+    /// let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    /// let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+    /// let vertices = reader.read_vertices::<StandardVertex>()?.collect::<Vec<_>>();
+    /// assert_eq!(StandardVertex::as_bytes(&vertices).len(), 32 * vertices.len());
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    ///
+    /// [`vertices`]: util/vertices/index.html
+    pub fn read_vertices<V>(&self) -> Result<util::vertices::ReadVertices<'s, V>, util::vertices::VertexError>
+    where
+        V: util::vertices::FromAttributes,
+    {
+        use self::util::vertices::{ReadVertices, VertexError};
+        let mask = V::ATTRIBUTES;
+        let expected = self.get(&Semantic::Positions).ok_or(VertexError::MissingPositions)?.count();
+        // Returns `true` if the attribute is requested and present.
+        let requested = |semantic: Semantic, wanted: bool| -> Result<bool, VertexError> {
+            match self.get(&semantic) {
+                Some(accessor) if wanted && accessor.count() != expected => {
+                    Err(VertexError::LengthMismatch { semantic, expected, actual: accessor.count() })
+                },
+                Some(_) => Ok(wanted),
+                None => Ok(false),
+            }
+        };
+
+        let positions = self.read_positions().ok_or(VertexError::Unavailable(Semantic::Positions))?;
+        let normals = if requested(Semantic::Normals, mask.normal)? {
+            Some(self.read_normals().ok_or(VertexError::Unavailable(Semantic::Normals))?)
+        } else {
+            None
+        };
+        let tangents = if requested(Semantic::Tangents, mask.tangent)? {
+            Some(self.read_tangents().ok_or(VertexError::Unavailable(Semantic::Tangents))?)
+        } else {
+            None
+        };
+        let tex_coords = if requested(Semantic::TexCoords(0), mask.tex_coord)? {
+            Some(self.read_tex_coords(0).ok_or(VertexError::Unavailable(Semantic::TexCoords(0)))?.into_f32())
+        } else {
+            None
+        };
+        let colors = if requested(Semantic::Colors(0), mask.color)? {
+            Some(self.read_colors(0).ok_or(VertexError::Unavailable(Semantic::Colors(0)))?.into_rgba_f32())
+        } else {
+            None
+        };
+        Ok(ReadVertices { positions, normals, tangents, tex_coords, colors, _vertex: std::marker::PhantomData })
+    }
+
     /// Visits the morph targets of the primitive.
     pub fn read_morph_targets(&self) -> util::ReadMorphTargets<'a, 's, F> {
         util::ReadMorphTargets {
@@ -0,0 +1,344 @@
+//! [`optimize_primitive`] reorders the triangles of an indexed triangle list
+//! for the post-transform vertex cache using Tom Forsyth's "Linear-Speed
+//! Vertex Cache Optimisation" algorithm. [`OptimizeIndices`] applies it to
+//! every indexed `Triangles` primitive of a document and narrows `u32`
+//! indices to `u16` where the vertex count allows.
+//!
+//! [`optimize_primitive`]: fn.optimize_primitive.html
+//! [`OptimizeIndices`]: trait.OptimizeIndices.html
+
+#[cfg(feature = "import")]
+use std::collections::{BTreeSet, HashSet};
+
+#[cfg(feature = "import")]
+use json::accessor::{ComponentType, GenericComponentType};
+#[cfg(feature = "import")]
+use json::edit::Error;
+#[cfg(feature = "import")]
+use json::validation::Checked;
+#[cfg(feature = "import")]
+use json::{Index, Path, Root};
+
+#[cfg(feature = "import")]
+use super::{Mode, Semantic};
+#[cfg(feature = "import")]
+use crate::buffer;
+
+/// The size of the simulated LRU vertex cache.
+const CACHE_SIZE: usize = 32;
+
+const CACHE_DECAY_POWER: f32 = 1.5;
+const LAST_TRIANGLE_SCORE: f32 = 0.75;
+const VALENCE_BOOST_SCALE: f32 = 2.0;
+const VALENCE_BOOST_POWER: f32 = 0.5;
+
+/// Summary of the changes made by [`OptimizeIndices::optimize_indices`].
+///
+/// [`OptimizeIndices::optimize_indices`]: trait.OptimizeIndices.html#tymethod.optimize_indices
+#[cfg(feature = "import")]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Report {
+    /// The number of index accessors whose triangles were reordered.
+    pub reordered: usize,
+
+    /// The number of index accessors narrowed from `u32` to `u16`.
+    pub narrowed: usize,
+
+    /// The buffers whose `data:` URI was re-encoded to hold the appended
+    /// indices, in ascending order.
+    pub reencoded: Vec<usize>,
+}
+
+/// Per-vertex state of the optimizer.
+#[derive(Clone, Default)]
+struct Vertex {
+    /// Triangles using this vertex that have not been emitted yet.
+    triangles: Vec<usize>,
+
+    /// Position in the LRU cache, if cached.
+    cache_position: Option<usize>,
+
+    score: f32,
+}
+
+fn vertex_score(vertex: &Vertex) -> f32 {
+    if vertex.triangles.is_empty() {
+        return -1.0;
+    }
+    let cache_score = match vertex.cache_position {
+        None => 0.0,
+        Some(position) if position < 3 => LAST_TRIANGLE_SCORE,
+        Some(position) => {
+            let scale = 1.0 / (CACHE_SIZE - 3) as f32;
+            (1.0 - (position - 3) as f32 * scale).powf(CACHE_DECAY_POWER)
+        },
+    };
+    let valence = (vertex.triangles.len() as f32).powf(-VALENCE_BOOST_POWER);
+    cache_score + VALENCE_BOOST_SCALE * valence
+}
+
+/// Reorders the triangles of an indexed triangle list to improve the hit rate
+/// of the post-transform vertex cache.
+///
+/// `positions` are the vertex positions of the primitive, which determine the
+/// number of vertices. The vertices of each triangle, and hence its winding, are
+/// preserved; only the order of the triangles changes. Trailing indices that do
+/// not form a complete triangle are left in place.
+///
+/// # Panics
+///
+/// Panics if an index is out of range of `positions`.
+pub fn optimize_primitive(positions: &[[f32; 3]], indices: &mut [u32]) {
+    reorder(positions.len(), indices)
+}
+
+fn reorder(vertex_count: usize, indices: &mut [u32]) {
+    let triangle_count = indices.len() / 3;
+    let mut vertices = vec![Vertex::default(); vertex_count];
+    for (triangle, corners) in indices.chunks(3).take(triangle_count).enumerate() {
+        for &index in corners {
+            vertices[index as usize].triangles.push(triangle);
+        }
+    }
+    for vertex in &mut vertices {
+        vertex.score = vertex_score(vertex);
+    }
+
+    let triangle_score = |vertices: &[Vertex], triangle: usize| {
+        indices[3 * triangle..3 * triangle + 3]
+            .iter()
+            .map(|&index| vertices[index as usize].score)
+            .sum::<f32>()
+    };
+
+    let mut emitted = vec![false; triangle_count];
+    let mut output = Vec::with_capacity(3 * triangle_count);
+    let mut cache: Vec<u32> = Vec::with_capacity(CACHE_SIZE + 3);
+    let mut cursor = 0;
+    for _ in 0..triangle_count {
+        // Prefer the best triangle that uses a cached vertex.
+        let mut best = None;
+        let mut best_score = -1.0;
+        for &index in &cache {
+            for &triangle in &vertices[index as usize].triangles {
+                let score = triangle_score(&vertices, triangle);
+                if score > best_score {
+                    best = Some(triangle);
+                    best_score = score;
+                }
+            }
+        }
+        let triangle = match best {
+            Some(triangle) => triangle,
+            None => {
+                while emitted[cursor] {
+                    cursor += 1;
+                }
+                cursor
+            },
+        };
+
+        emitted[triangle] = true;
+        let corners = [indices[3 * triangle], indices[3 * triangle + 1], indices[3 * triangle + 2]];
+        output.extend_from_slice(&corners);
+        for &index in &corners {
+            vertices[index as usize].triangles.retain(|&x| x != triangle);
+        }
+
+        // Move the corners to the front of the cache.
+        cache.retain(|x| !corners.contains(x));
+        for (position, &index) in corners.iter().enumerate() {
+            cache.insert(position, index);
+        }
+        for &index in cache.iter().skip(CACHE_SIZE) {
+            let vertex = &mut vertices[index as usize];
+            vertex.cache_position = None;
+            vertex.score = vertex_score(vertex);
+        }
+        cache.truncate(CACHE_SIZE);
+        for (position, &index) in cache.iter().enumerate() {
+            let vertex = &mut vertices[index as usize];
+            vertex.cache_position = Some(position);
+            vertex.score = vertex_score(vertex);
+        }
+    }
+
+    indices[..output.len()].copy_from_slice(&output);
+}
+
+/// Returns the average cache miss ratio of an indexed triangle list, which is
+/// the number of vertex cache misses per triangle, for a FIFO cache of the
+/// given size.
+///
+/// Values range from 3.0 in the worst case to about 0.5 for large regular
+/// meshes.
+pub fn acmr(indices: &[u32], cache_size: usize) -> f32 {
+    let triangle_count = indices.len() / 3;
+    if triangle_count == 0 {
+        return 0.0;
+    }
+    let mut cache = std::collections::VecDeque::with_capacity(cache_size + 1);
+    let mut misses = 0;
+    for &index in &indices[..3 * triangle_count] {
+        if !cache.contains(&index) {
+            misses += 1;
+            cache.push_back(index);
+            if cache.len() > cache_size {
+                cache.pop_front();
+            }
+        }
+    }
+    misses as f32 / triangle_count as f32
+}
+
+/// Index buffer optimization of a whole document.
+#[cfg(feature = "import")]
+pub trait OptimizeIndices {
+    /// Reorders and narrows the indices of every indexed `Triangles`
+    /// primitive of the document.
+    ///
+    /// `buffers` contains the data of every buffer in the document, as
+    /// returned by [`import`]. The new indices of each accessor are appended
+    /// to the buffer of its original buffer view and referenced by a new
+    /// buffer view, so that views shared with other data are not modified.
+    /// Shared and memory-mapped buffer data is copied before it is extended.
+    /// Buffers defined by a `data:` URI are re-encoded with the appended
+    /// indices and listed in the report; other buffers must be written out
+    /// by the caller. Indices are narrowed from `u32` to `u16` when every
+    /// index is below 65535, the maximum `u16` value being reserved. Sparse
+    /// index accessors and accessors shared with other primitive modes are
+    /// ignored.
+    ///
+    /// The original index data is left in place; use [`Root::repack_buffers`]
+    /// to remove it.
+    ///
+    /// [`import`]: ../../fn.import.html
+    /// [`Root::repack_buffers`]: ../../json/struct.Root.html#method.repack_buffers
+    fn optimize_indices(&mut self, buffers: &mut [buffer::Data]) -> Result<Report, Error>;
+}
+
+#[cfg(feature = "import")]
+impl OptimizeIndices for Root {
+    fn optimize_indices(&mut self, buffers: &mut [buffer::Data]) -> Result<Report, Error> {
+        let root = self;
+        let mut report = Report::default();
+        let mut extended = BTreeSet::new();
+        let mut skipped = HashSet::new();
+        let mut candidates = Vec::new();
+        for primitive in root.meshes.iter().flat_map(|x| x.primitives.iter()) {
+            let indices = match primitive.indices {
+                Some(indices) => indices.value(),
+                None => continue,
+            };
+            let positions = primitive
+                .attributes
+                .get(&Checked::Valid(Semantic::Positions))
+                .map(|x| x.value());
+            match (primitive.mode, positions) {
+                (Checked::Valid(Mode::Triangles), Some(positions)) => candidates.push((indices, positions)),
+                _ => {
+                    skipped.insert(indices);
+                },
+            }
+        }
+
+        let mut visited = HashSet::new();
+        for (index, positions) in candidates {
+            if skipped.contains(&index) || !visited.insert(index) {
+                continue;
+            }
+            let path = || Path::new().field("accessors").index(index);
+            let accessor = root.accessors.get(index).ok_or_else(|| Error::IndexOutOfBounds(path()))?;
+            if accessor.sparse.is_some() {
+                continue;
+            }
+            let vertex_count = root
+                .accessors
+                .get(positions)
+                .ok_or_else(|| Error::IndexOutOfBounds(Path::new().field("accessors").index(positions)))?
+                .count as usize;
+            let view_index = accessor.buffer_view.ok_or_else(|| Error::Invalid(path()))?;
+            let view = root
+                .buffer_views
+                .get(view_index.value())
+                .ok_or_else(|| Error::IndexOutOfBounds(path()))?;
+            let component_type = match accessor.component_type {
+                Checked::Valid(GenericComponentType(x)) => x,
+                Checked::Invalid => return Err(Error::Invalid(path())),
+            };
+            let size = component_type.size();
+            let stride = view.byte_stride.filter(|&x| x > 0).map_or(size, |x| x as usize);
+            let start = view.byte_offset.unwrap_or(0) as usize + accessor.byte_offset as usize;
+            let count = accessor.count as usize;
+            let buffer = view.buffer.value();
+            let data = buffers.get(buffer).ok_or_else(|| Error::OutOfRange(path()))?;
+            if count > 0 && start + (count - 1) * stride + size > data.len() {
+                return Err(Error::OutOfRange(path()));
+            }
+
+            let mut indices = Vec::with_capacity(count);
+            for i in 0..count {
+                let bytes = &data[start + i * stride..start + i * stride + size];
+                indices.push(match component_type {
+                    ComponentType::U8 => u32::from(bytes[0]),
+                    ComponentType::U16 => u32::from(u16::from_le_bytes([bytes[0], bytes[1]])),
+                    ComponentType::U32 => u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+                    _ => return Err(Error::Invalid(path())),
+                });
+            }
+            if indices.iter().any(|&x| x as usize >= vertex_count) {
+                return Err(Error::Invalid(path()));
+            }
+            reorder(vertex_count, &mut indices);
+            report.reordered += 1;
+
+            let narrow = component_type == ComponentType::U32 && indices.iter().all(|&x| x < 65535);
+            let component_type = if narrow {
+                report.narrowed += 1;
+                ComponentType::U16
+            } else {
+                component_type
+            };
+
+            let data = buffers[buffer].to_mut();
+            let offset = (data.len() + 3) & !3;
+            data.resize(offset, 0);
+            for &index in &indices {
+                match component_type {
+                    ComponentType::U8 => data.push(index as u8),
+                    ComponentType::U16 => data.extend_from_slice(&(index as u16).to_le_bytes()),
+                    _ => data.extend_from_slice(&index.to_le_bytes()),
+                }
+            }
+            let length = data.len() - offset;
+            let padded = (data.len() + 3) & !3;
+            data.resize(padded, 0);
+
+            let mut view = view.clone();
+            view.byte_offset = Some(offset as u32);
+            view.byte_length = length as u32;
+            view.byte_stride = None;
+            root.buffers[buffer].byte_length = padded as u32;
+            extended.insert(buffer);
+            root.buffer_views.push(view);
+
+            let accessor = &mut root.accessors[index];
+            accessor.buffer_view = Some(Index::new(root.buffer_views.len() as u32 - 1));
+            accessor.byte_offset = 0;
+            accessor.component_type = Checked::Valid(GenericComponentType(component_type));
+        }
+
+        for buffer in extended {
+            let uri = match root.buffers[buffer].uri.as_deref() {
+                Some(uri) if uri.starts_with("data:") => uri,
+                _ => continue,
+            };
+            let media_type = uri["data:".len()..].split([';', ',']).next().unwrap_or_default();
+            let media_type = if media_type.is_empty() { "application/octet-stream" } else { media_type };
+            let uri = crate::export::data_uri(media_type, &buffers[buffer]);
+            root.buffers[buffer].uri = Some(uri.into());
+            report.reencoded.push(buffer);
+        }
+        Ok(report)
+    }
+}
@@ -0,0 +1,467 @@
+//! [`simplify`] reduces the number of triangles of an indexed triangle list by
+//! collapsing edges in order of increasing quadric error, after Garland and
+//! Heckbert's "Surface Simplification Using Quadric Error Metrics".
+//! [`append_lods`] applies it to every `Triangles` primitive of a document and
+//! appends the results as additional meshes, one per level of detail.
+//!
+//! [`simplify`]: fn.simplify.html
+//! [`append_lods`]: fn.append_lods.html
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+use json::accessor::{ComponentType, GenericComponentType, Type};
+use json::buffer::Target;
+use json::edit::Error;
+use json::validation::Checked;
+use json::{Index, Path, Root};
+
+use crate::{Buffer, Document};
+
+use super::{Mode, Semantic};
+
+/// Summary of the changes made by [`append_lods`].
+///
+/// [`append_lods`]: fn.append_lods.html
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Report {
+    /// For every mesh of the document, the indices of the meshes appended for
+    /// it, one per ratio, in the order of the ratios.
+    pub lods: Vec<Vec<usize>>,
+
+    /// The number of primitives that were simplified, counted once per ratio.
+    pub simplified: usize,
+}
+
+/// A symmetric 4x4 matrix measuring the sum of squared distances to a set of
+/// planes, storing the upper triangle row by row.
+#[derive(Clone, Copy, Default)]
+struct Quadric([f64; 10]);
+
+impl Quadric {
+    /// The quadric of the plane `ax + by + cz + d = 0`, where `(a, b, c)` is a
+    /// unit normal.
+    fn plane([a, b, c]: [f64; 3], d: f64) -> Self {
+        Quadric([a * a, a * b, a * c, a * d, b * b, b * c, b * d, c * c, c * d, d * d])
+    }
+
+    fn add(&mut self, other: &Quadric) {
+        for (x, y) in self.0.iter_mut().zip(&other.0) {
+            *x += y;
+        }
+    }
+
+    fn error(&self, [x, y, z]: [f64; 3]) -> f64 {
+        let [a2, ab, ac, ad, b2, bc, bd, c2, cd, d2] = self.0;
+        a2 * x * x + b2 * y * y + c2 * z * z + d2
+            + 2.0 * (ab * x * y + ac * x * z + bc * y * z + ad * x + bd * y + cd * z)
+    }
+}
+
+fn sub(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]]
+}
+
+/// Collapsing the vertex `from` onto the vertex `to`.
+///
+/// Candidates are ordered by cost first. They are not updated in place but
+/// discarded when popped if the version of either vertex has changed since.
+#[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+struct Candidate {
+    /// The bits of the non-negative `f64` error, which sort like the error.
+    cost: u64,
+    from: u32,
+    to: u32,
+    from_version: u32,
+    to_version: u32,
+}
+
+struct Simplifier {
+    points: Vec<[f64; 3]>,
+    triangles: Vec<[u32; 3]>,
+    alive: Vec<bool>,
+    remaining: usize,
+
+    /// The triangles using each vertex, possibly including removed ones.
+    incident: Vec<Vec<usize>>,
+
+    quadrics: Vec<Quadric>,
+
+    /// Vertices on a border or a non-manifold edge, which are never moved.
+    locked: Vec<bool>,
+
+    version: Vec<u32>,
+    heap: BinaryHeap<Reverse<Candidate>>,
+}
+
+impl Simplifier {
+    fn new(positions: &[[f32; 3]], triangles: Vec<[u32; 3]>) -> Self {
+        let points = positions
+            .iter()
+            .map(|p| [f64::from(p[0]), f64::from(p[1]), f64::from(p[2])])
+            .collect::<Vec<_>>();
+        let mut quadrics = vec![Quadric::default(); points.len()];
+        let mut incident = vec![Vec::new(); points.len()];
+        let mut edges = HashMap::new();
+        for (triangle, &corners) in triangles.iter().enumerate() {
+            let [a, b, c] = corners.map(|x| points[x as usize]);
+            let normal = cross(sub(b, a), sub(c, a));
+            let length = dot(normal, normal).sqrt();
+            if length > 0.0 {
+                let normal = normal.map(|x| x / length);
+                let quadric = Quadric::plane(normal, -dot(normal, a));
+                for &vertex in &corners {
+                    quadrics[vertex as usize].add(&quadric);
+                }
+            }
+            for k in 0..3 {
+                let (u, v) = (corners[k], corners[(k + 1) % 3]);
+                incident[u as usize].push(triangle);
+                *edges.entry((u.min(v), u.max(v))).or_insert(0) += 1;
+            }
+        }
+        let mut locked = vec![false; points.len()];
+        for (&(u, v), &count) in &edges {
+            if count != 2 {
+                locked[u as usize] = true;
+                locked[v as usize] = true;
+            }
+        }
+
+        let mut simplifier = Simplifier {
+            version: vec![0; points.len()],
+            points,
+            alive: vec![true; triangles.len()],
+            remaining: triangles.len(),
+            triangles,
+            incident,
+            quadrics,
+            locked,
+            heap: BinaryHeap::new(),
+        };
+        for (u, v) in edges.into_keys() {
+            simplifier.push(u, v);
+            simplifier.push(v, u);
+        }
+        simplifier
+    }
+
+    fn push(&mut self, from: u32, to: u32) {
+        if self.locked[from as usize] {
+            return;
+        }
+        let mut quadric = self.quadrics[from as usize];
+        quadric.add(&self.quadrics[to as usize]);
+        let cost = quadric.error(self.points[to as usize]).max(0.0);
+        self.heap.push(Reverse(Candidate {
+            cost: cost.to_bits(),
+            from,
+            to,
+            from_version: self.version[from as usize],
+            to_version: self.version[to as usize],
+        }));
+    }
+
+    /// The vertices sharing a remaining triangle with `vertex`.
+    fn neighbors(&self, vertex: u32) -> Vec<u32> {
+        let mut neighbors = self.incident[vertex as usize]
+            .iter()
+            .filter(|&&x| self.alive[x])
+            .flat_map(|&x| self.triangles[x].iter().copied())
+            .filter(|&x| x != vertex)
+            .collect::<Vec<_>>();
+        neighbors.sort_unstable();
+        neighbors.dedup();
+        neighbors
+    }
+
+    /// Whether the collapse keeps the surface manifold and does not flip the
+    /// orientation of any remaining triangle.
+    fn is_valid(&self, candidate: &Candidate) -> bool {
+        let (from, to) = (candidate.from, candidate.to);
+        if candidate.from_version != self.version[from as usize] || candidate.to_version != self.version[to as usize] {
+            return false;
+        }
+        let triangles = self.incident[from as usize].iter().copied().filter(|&x| self.alive[x]);
+        let shared = triangles.clone().filter(|&x| self.triangles[x].contains(&to)).count();
+        if shared == 0 {
+            return false;
+        }
+
+        // The link condition: the only vertices adjacent to both ends are the
+        // opposite corners of the triangles removed by the collapse.
+        let neighbors = self.neighbors(to);
+        let common = self.neighbors(from).iter().filter(|x| neighbors.binary_search(x).is_ok()).count();
+        if common != shared {
+            return false;
+        }
+
+        let target = self.points[to as usize];
+        triangles.filter(|&x| !self.triangles[x].contains(&to)).all(|triangle| {
+            let corners = self.triangles[triangle].map(|x| self.points[x as usize]);
+            let moved = self.triangles[triangle].map(|x| if x == from { target } else { self.points[x as usize] });
+            let before = cross(sub(corners[1], corners[0]), sub(corners[2], corners[0]));
+            let after = cross(sub(moved[1], moved[0]), sub(moved[2], moved[0]));
+            dot(before, after) > 0.0
+        })
+    }
+
+    fn collapse(&mut self, from: u32, to: u32) {
+        let quadric = self.quadrics[from as usize];
+        self.quadrics[to as usize].add(&quadric);
+        for triangle in std::mem::take(&mut self.incident[from as usize]) {
+            if !self.alive[triangle] {
+                continue;
+            }
+            let corners = &mut self.triangles[triangle];
+            if corners.contains(&to) {
+                self.alive[triangle] = false;
+                self.remaining -= 1;
+            } else {
+                for corner in corners.iter_mut().filter(|x| **x == from) {
+                    *corner = to;
+                }
+                self.incident[to as usize].push(triangle);
+            }
+        }
+        let alive = &self.alive;
+        self.incident[to as usize].retain(|&x| alive[x]);
+        self.version[from as usize] += 1;
+        self.version[to as usize] += 1;
+        for neighbor in self.neighbors(to) {
+            self.push(neighbor, to);
+            self.push(to, neighbor);
+        }
+    }
+}
+
+/// Reduces the number of triangles of an indexed triangle list.
+///
+/// Edges are collapsed in order of increasing quadric error until at most
+/// `target_ratio` of the triangles remain, or until the next collapse would
+/// move the surface further than `error_limit`, in the units of `positions`.
+/// Vertices on the border of the mesh are never moved, so that holes, and the
+/// seams between vertices that share a position but not their other
+/// attributes, are preserved. Collapses that would make the surface
+/// non-manifold or flip a triangle are skipped.
+///
+/// No vertices are created or moved: the returned indices refer to a subset
+/// of the vertices of `positions`, in triangles of the original winding.
+/// Degenerate triangles and trailing indices that do not form a complete
+/// triangle are removed.
+///
+/// # Panics
+///
+/// Panics if an index is out of range of `positions`.
+pub fn simplify(positions: &[[f32; 3]], indices: &[u32], target_ratio: f32, error_limit: f32) -> Vec<u32> {
+    let triangles = indices
+        .chunks_exact(3)
+        .map(|x| [x[0], x[1], x[2]])
+        .filter(|x| x[0] != x[1] && x[1] != x[2] && x[2] != x[0])
+        .collect::<Vec<_>>();
+    let target = (triangles.len() as f32 * target_ratio.clamp(0.0, 1.0)) as usize;
+    let max_cost = f64::from(error_limit) * f64::from(error_limit);
+    let mut simplifier = Simplifier::new(positions, triangles);
+    while simplifier.remaining > target {
+        let candidate = match simplifier.heap.pop() {
+            Some(Reverse(candidate)) => candidate,
+            None => break,
+        };
+        if f64::from_bits(candidate.cost) > max_cost {
+            break;
+        }
+        if simplifier.is_valid(&candidate) {
+            simplifier.collapse(candidate.from, candidate.to);
+        }
+    }
+    simplifier
+        .triangles
+        .iter()
+        .zip(&simplifier.alive)
+        .filter(|(_, &alive)| alive)
+        .flat_map(|(x, _)| x.iter().copied())
+        .collect()
+}
+
+/// Maps every vertex to the first vertex with the same position and texture
+/// co-ordinates.
+fn weld(positions: &[[f32; 3]], tex_coords: Option<&[[f32; 2]]>) -> Vec<u32> {
+    let mut first = HashMap::new();
+    (0..positions.len())
+        .map(|i| {
+            let uv = tex_coords.map_or([0.0; 2], |x| x[i]);
+            let key = (positions[i].map(f32::to_bits), uv.map(f32::to_bits));
+            *first.entry(key).or_insert(i as u32)
+        })
+        .collect()
+}
+
+/// The simplified indices of the primitives of a mesh, one list per ratio,
+/// or `None` for primitives that are copied as they are.
+type Levels = Vec<Option<(usize, Vec<Vec<u32>>)>>;
+
+/// Appends a simplified copy of every mesh of a document for each of `ratios`.
+///
+/// `buffers` contains the data of every buffer in the document. The indices
+/// of every `Triangles` primitive are simplified with [`simplify`], after
+/// merging the vertices that share a position and `TEXCOORD_0`, so that the
+/// seams between texture charts are kept. Other primitives are copied as they
+/// are. The appended meshes share the vertex attributes and morph targets of
+/// the original and differ in their index accessors only, which are appended
+/// to the buffer of the `POSITION` accessor and use `u16` indices where the
+/// vertex count allows.
+///
+/// The nodes of the document are not modified; [`Report::lods`] lists the
+/// levels of detail of each mesh, for example to write `MSFT_lod` extensions.
+///
+/// [`simplify`]: fn.simplify.html
+/// [`Report::lods`]: struct.Report.html#structfield.lods
+pub fn append_lods(
+    root: &mut Root,
+    buffers: &mut [Vec<u8>],
+    ratios: &[f32],
+    error_limit: f32,
+) -> Result<Report, Error> {
+    let document = Document::from_json_without_validation(root.clone());
+    let mut meshes = Vec::new();
+    {
+        let get = |buffer: Buffer| buffers.get(buffer.index()).map(Vec::as_slice);
+        for mesh in document.meshes() {
+            let mut levels: Levels = Vec::new();
+            for primitive in mesh.primitives() {
+                let path = || {
+                    Path::new()
+                        .field("meshes")
+                        .index(mesh.index())
+                        .field("primitives")
+                        .index(primitive.index())
+                };
+                let accessor = match primitive.get(&Semantic::Positions) {
+                    Some(accessor) if primitive.mode() == Mode::Triangles => accessor,
+                    _ => {
+                        levels.push(None);
+                        continue;
+                    },
+                };
+                let buffer = accessor.view().and_then(|x| x.buffer()).map_or(0, |x| x.index());
+                let reader = primitive.reader(get);
+                let positions = reader
+                    .read_positions()
+                    .ok_or_else(|| Error::OutOfRange(path().field("attributes").key("POSITION")))?
+                    .collect::<Vec<_>>();
+                let tex_coords = reader
+                    .read_tex_coords(0)
+                    .map(|x| x.into_f32().collect::<Vec<_>>())
+                    .filter(|x| x.len() == positions.len());
+                let indices = match primitive.indices() {
+                    Some(_) => reader
+                        .read_indices()
+                        .ok_or_else(|| Error::OutOfRange(path().field("indices")))?
+                        .into_u32()
+                        .collect::<Vec<_>>(),
+                    None => (0..positions.len() as u32).collect(),
+                };
+                if indices.iter().any(|&x| x as usize >= positions.len()) {
+                    return Err(Error::Invalid(path().field("indices")));
+                }
+
+                let welded = weld(&positions, tex_coords.as_deref());
+                let indices = indices.iter().map(|&x| welded[x as usize]).collect::<Vec<_>>();
+                let lods = ratios
+                    .iter()
+                    .map(|&ratio| simplify(&positions, &indices, ratio, error_limit))
+                    .collect();
+                levels.push(Some((buffer, lods)));
+            }
+            meshes.push(levels);
+        }
+    }
+
+    let mut report = Report::default();
+    for (mesh, levels) in meshes.into_iter().enumerate() {
+        let mut lods = Vec::new();
+        for lod in 0..ratios.len() {
+            let mut copy = root.meshes[mesh].clone();
+            #[cfg(feature = "names")]
+            {
+                copy.name = copy.name.map(|x| format!("{} LOD{}", x, lod + 1).into());
+            }
+            for (primitive, level) in copy.primitives.iter_mut().zip(&levels) {
+                let (buffer, indices) = match level {
+                    // A primitive without triangles left keeps its indices,
+                    // since accessors may not be empty.
+                    Some((buffer, indices)) if !indices[lod].is_empty() => (*buffer, &indices[lod]),
+                    _ => continue,
+                };
+                if buffer >= buffers.len() || buffer >= root.buffers.len() {
+                    return Err(Error::OutOfRange(Path::new().field("buffers").index(buffer)));
+                }
+                let accessor = push_indices(root, &mut buffers[buffer], buffer, indices);
+                primitive.indices = Some(Index::new(accessor as u32));
+                report.simplified += 1;
+            }
+            root.meshes.push(copy);
+            lods.push(root.meshes.len() - 1);
+        }
+        report.lods.push(lods);
+    }
+    Ok(report)
+}
+
+/// Appends an index accessor and its buffer view and returns the index of the
+/// accessor.
+fn push_indices(root: &mut Root, data: &mut Vec<u8>, buffer: usize, indices: &[u32]) -> usize {
+    let narrow = indices.iter().all(|&x| x < 65535);
+    let offset = (data.len() + 3) & !3;
+    data.resize(offset, 0);
+    for &index in indices {
+        if narrow {
+            data.extend_from_slice(&(index as u16).to_le_bytes());
+        } else {
+            data.extend_from_slice(&index.to_le_bytes());
+        }
+    }
+    let length = data.len() - offset;
+    let padded = (data.len() + 3) & !3;
+    data.resize(padded, 0);
+    root.buffers[buffer].byte_length = padded as u32;
+    root.buffer_views.push(json::buffer::View {
+        buffer: Index::new(buffer as u32),
+        byte_length: length as u32,
+        byte_offset: Some(offset as u32),
+        byte_stride: None,
+        #[cfg(feature = "names")]
+        name: None,
+        target: Some(Checked::Valid(Target::ElementArrayBuffer)),
+        extensions: None,
+        extras: Default::default(),
+    });
+    root.accessors.push(json::Accessor {
+        buffer_view: Some(Index::new(root.buffer_views.len() as u32 - 1)),
+        byte_offset: 0,
+        count: indices.len() as u32,
+        component_type: Checked::Valid(GenericComponentType(if narrow {
+            ComponentType::U16
+        } else {
+            ComponentType::U32
+        })),
+        extensions: None,
+        extras: Default::default(),
+        type_: Checked::Valid(Type::Scalar),
+        min: None,
+        max: None,
+        #[cfg(feature = "names")]
+        name: None,
+        normalized: false,
+        sparse: None,
+    });
+    root.accessors.len() - 1
+}
@@ -13,11 +13,16 @@ pub mod tex_coords;
 /// Casting iterator adapters for node weights.
 pub mod weights;
 
+/// Interleaved vertex adapters.
+pub mod vertices;
+
 use crate::mesh;
 
 use crate::accessor::Iter;
 use crate::Buffer;
 
+pub use crate::accessor::util::TypeMismatch;
+
 /// XYZ vertex positions of type `[f32; 3]`.
 pub type ReadPositions<'a> = Iter<'a, [f32; 3]>;
 
@@ -38,6 +43,11 @@ pub type ReadNormalDisplacements<'a> = Iter<'a, [f32; 3]>;
 pub type ReadTangentDisplacements<'a> = Iter<'a, [f32; 3]>;
 
 /// Vertex colors.
+///
+/// The `into_*` adapters convert between component types as specified by
+/// glTF: integers are normalized by the maximum of their type, and floats are
+/// clamped to `[0, 1]` and rounded to the nearest integer. Missing alpha
+/// components are opaque.
 #[derive(Clone, Debug)]
 pub enum ReadColors<'a> {
     /// RGB vertex color of type `[u8; 3]>`.
@@ -100,6 +110,19 @@ pub enum ReadWeights<'a> {
     F32(Iter<'a, [f32; 4]>),
 }
 
+/// The four largest joint influences of each vertex, as returned by
+/// [`Reader::read_merged_influences`].
+///
+/// [`Reader::read_merged_influences`]: ../struct.Reader.html#method.read_merged_influences
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Influences {
+    /// The joint indices of each vertex, in the format of `JOINTS_0`.
+    pub joints: Vec<[u16; 4]>,
+
+    /// The weights of each vertex, in the format of `WEIGHTS_0`.
+    pub weights: Vec<[f32; 4]>,
+}
+
 /// Morph targets.
 #[derive(Clone, Debug)]
 pub struct ReadMorphTargets<'a, 's, F>
@@ -125,6 +148,9 @@ where
         Option<ReadTangentDisplacements<'s>>,
     );
     fn next(&mut self) -> Option<Self::Item> {
+        if !self.reader.primitive.has_valid_indices() {
+            return None;
+        }
         self.index += 1;
         self.reader.primitive
             .morph_targets()
@@ -144,6 +170,9 @@ where
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
+        if !self.reader.primitive.has_valid_indices() {
+            return (0, Some(0));
+        }
         self.reader.primitive.morph_targets().size_hint()
     }
 }
@@ -239,3 +268,4 @@ impl<'a> ReadWeights<'a> {
         self::weights::CastingIter::new(self)
     }
 }
+
@@ -0,0 +1,225 @@
+use std::marker::PhantomData;
+use std::{error, fmt, mem, slice};
+
+use crate::mesh::Semantic;
+
+use super::{colors, tex_coords, ReadNormals, ReadPositions, ReadTangents};
+
+/// The normal of vertices without a `NORMAL` attribute, pointing up.
+pub const DEFAULT_NORMAL: [f32; 3] = [0.0, 1.0, 0.0];
+
+/// The tangent of vertices without a `TANGENT` attribute, pointing along +X
+/// with a right-handed basis.
+pub const DEFAULT_TANGENT: [f32; 4] = [1.0, 0.0, 0.0, 1.0];
+
+/// The texture co-ordinates of vertices without a `TEXCOORD_0` attribute.
+pub const DEFAULT_TEX_COORD: [f32; 2] = [0.0, 0.0];
+
+/// The color of vertices without a `COLOR_0` attribute, opaque white.
+pub const DEFAULT_COLOR: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+
+/// The attributes of one vertex, as passed to [`FromAttributes`].
+///
+/// Attributes missing from the primitive, or not requested by
+/// [`FromAttributes::ATTRIBUTES`], hold the `DEFAULT_*` constants of this
+/// module.
+///
+/// [`FromAttributes`]: trait.FromAttributes.html
+/// [`FromAttributes::ATTRIBUTES`]: trait.FromAttributes.html#associatedconstant.ATTRIBUTES
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VertexAttributes {
+    /// The `POSITION` attribute.
+    pub position: [f32; 3],
+
+    /// The `NORMAL` attribute.
+    pub normal: [f32; 3],
+
+    /// The `TANGENT` attribute.
+    pub tangent: [f32; 4],
+
+    /// The `TEXCOORD_0` attribute, converted to floats.
+    pub tex_coord: [f32; 2],
+
+    /// The `COLOR_0` attribute, converted to RGBA floats.
+    pub color: [f32; 4],
+}
+
+/// The optional attributes read for a vertex type.
+///
+/// `POSITION` is always read.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct AttributeMask {
+    /// Read the `NORMAL` attribute.
+    pub normal: bool,
+
+    /// Read the `TANGENT` attribute.
+    pub tangent: bool,
+
+    /// Read the `TEXCOORD_0` attribute.
+    pub tex_coord: bool,
+
+    /// Read the `COLOR_0` attribute.
+    pub color: bool,
+}
+
+impl AttributeMask {
+    /// Reads every attribute.
+    pub const ALL: AttributeMask = AttributeMask { normal: true, tangent: true, tex_coord: true, color: true };
+}
+
+/// A vertex type built from the attributes of a primitive, as returned by
+/// [`Reader::read_vertices`].
+///
+/// ```
+/// use gltf::mesh::util::vertices::{AttributeMask, FromAttributes, VertexAttributes};
+///
+/// #[repr(C)]
+/// #[derive(Clone, Copy)]
+/// struct ColoredVertex {
+///     position: [f32; 3],
+///     color: [f32; 4],
+/// }
+///
+/// impl FromAttributes for ColoredVertex {
+///     const ATTRIBUTES: AttributeMask = AttributeMask {
+///         normal: false,
+///         tangent: false,
+///         tex_coord: false,
+///         color: true,
+///     };
+///
+///     fn from_attributes(attributes: &VertexAttributes) -> Self {
+///         ColoredVertex { position: attributes.position, color: attributes.color }
+///     }
+/// }
+/// ```
+///
+/// [`Reader::read_vertices`]: ../../struct.Reader.html#method.read_vertices
+pub trait FromAttributes: Sized {
+    /// The attributes used by `from_attributes`. The others are not read and
+    /// hold their defaults.
+    const ATTRIBUTES: AttributeMask;
+
+    /// Builds a vertex from its attributes.
+    fn from_attributes(attributes: &VertexAttributes) -> Self;
+}
+
+/// A vertex with a position, a normal, and texture co-ordinates.
+///
+/// The layout is `#[repr(C)]` without padding, 32 bytes per vertex, so a
+/// slice of vertices can be uploaded to the GPU as is, see [`as_bytes`].
+///
+/// [`as_bytes`]: #method.as_bytes
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct StandardVertex {
+    /// The `POSITION` attribute.
+    pub position: [f32; 3],
+
+    /// The `NORMAL` attribute, or [`DEFAULT_NORMAL`].
+    ///
+    /// [`DEFAULT_NORMAL`]: constant.DEFAULT_NORMAL.html
+    pub normal: [f32; 3],
+
+    /// The `TEXCOORD_0` attribute, or [`DEFAULT_TEX_COORD`].
+    ///
+    /// [`DEFAULT_TEX_COORD`]: constant.DEFAULT_TEX_COORD.html
+    pub tex_coord: [f32; 2],
+}
+
+impl StandardVertex {
+    /// Returns the bytes of a slice of vertices, in native byte order.
+    pub fn as_bytes(vertices: &[StandardVertex]) -> &[u8] {
+        // `StandardVertex` consists of `f32`s only and has no padding, so
+        // every byte is initialized.
+        unsafe { slice::from_raw_parts(vertices.as_ptr() as *const u8, mem::size_of_val(vertices)) }
+    }
+}
+
+impl FromAttributes for StandardVertex {
+    const ATTRIBUTES: AttributeMask = AttributeMask { normal: true, tangent: false, tex_coord: true, color: false };
+
+    fn from_attributes(attributes: &VertexAttributes) -> Self {
+        StandardVertex {
+            position: attributes.position,
+            normal: attributes.normal,
+            tex_coord: attributes.tex_coord,
+        }
+    }
+}
+
+/// The error returned by [`Reader::read_vertices`].
+///
+/// [`Reader::read_vertices`]: ../../struct.Reader.html#method.read_vertices
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VertexError {
+    /// The primitive has no `POSITION` attribute.
+    MissingPositions,
+
+    /// The data of an attribute is not available.
+    Unavailable(Semantic),
+
+    /// An attribute has a different number of items than `POSITION`.
+    LengthMismatch {
+        /// The semantic of the attribute.
+        semantic: Semantic,
+
+        /// The number of positions.
+        expected: usize,
+
+        /// The number of items of the attribute.
+        actual: usize,
+    },
+}
+
+impl error::Error for VertexError {}
+
+impl fmt::Display for VertexError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VertexError::MissingPositions => write!(f, "primitive has no POSITION attribute"),
+            VertexError::Unavailable(semantic) => write!(f, "data of attribute {:?} is not available", semantic),
+            VertexError::LengthMismatch { semantic, expected, actual } => write!(
+                f,
+                "attribute {:?} has {} items but POSITION has {}",
+                semantic, actual, expected,
+            ),
+        }
+    }
+}
+
+/// Interleaved vertices, visiting the attributes of a primitive in lockstep.
+#[derive(Clone, Debug)]
+pub struct ReadVertices<'a, V> {
+    pub(crate) positions: ReadPositions<'a>,
+    pub(crate) normals: Option<ReadNormals<'a>>,
+    pub(crate) tangents: Option<ReadTangents<'a>>,
+    pub(crate) tex_coords: Option<tex_coords::CastingIter<'a, tex_coords::F32>>,
+    pub(crate) colors: Option<colors::CastingIter<'a, colors::RgbaF32>>,
+    pub(crate) _vertex: PhantomData<V>,
+}
+
+/// Returns the next item of an optional attribute, or `default`.
+fn next_or<I: Iterator>(iter: &mut Option<I>, default: I::Item) -> I::Item {
+    iter.as_mut().and_then(Iterator::next).unwrap_or(default)
+}
+
+impl<'a, V: FromAttributes> ExactSizeIterator for ReadVertices<'a, V> {}
+impl<'a, V: FromAttributes> Iterator for ReadVertices<'a, V> {
+    type Item = V;
+    fn next(&mut self) -> Option<Self::Item> {
+        let position = self.positions.next()?;
+        let attributes = VertexAttributes {
+            position,
+            normal: next_or(&mut self.normals, DEFAULT_NORMAL),
+            tangent: next_or(&mut self.tangents, DEFAULT_TANGENT),
+            tex_coord: next_or(&mut self.tex_coords, DEFAULT_TEX_COORD),
+            color: next_or(&mut self.colors, DEFAULT_COLOR),
+        };
+        Some(V::from_attributes(&attributes))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.positions.size_hint()
+    }
+}
@@ -0,0 +1,450 @@
+use json::extensions::buffer::ext_meshopt_compression::{Filter, Mode, View as Compression};
+use json::validation::Checked;
+
+use crate::{buffer, Document, Error, Result};
+
+/// The header byte of attribute data, with the version in the low bits.
+const VERTEX_HEADER: u8 = 0xa0;
+
+/// The header byte of triangle index data.
+const INDEX_HEADER: u8 = 0xe0;
+
+/// The header byte of index sequence data.
+const SEQUENCE_HEADER: u8 = 0xd0;
+
+/// The number of bytes encoded together with one bit width.
+const BYTE_GROUP_SIZE: usize = 16;
+
+/// The number of bytes that must remain before a byte group is decoded.
+const BYTE_GROUP_DECODE_LIMIT: usize = 24;
+
+/// The upper bound of the size of an attribute block, in bytes.
+const VERTEX_BLOCK_SIZE_BYTES: usize = 8192;
+
+/// The upper bound of the number of attributes in a block.
+const VERTEX_BLOCK_MAX_SIZE: usize = 256;
+
+/// The lower bound of the size of the tail of attribute data.
+const TAIL_MAX_SIZE: usize = 32;
+
+/// The vertex FIFO references of triangles encoded with a table code.
+const CODE_AUX_TABLE_SIZE: usize = 16;
+
+fn unzigzag8(value: u8) -> u8 {
+    0u8.wrapping_sub(value & 1) ^ (value >> 1)
+}
+
+fn unzigzag32(value: u32) -> u32 {
+    0u32.wrapping_sub(value & 1) ^ (value >> 1)
+}
+
+/// Reads the byte at `position` and advances past it.
+fn next(data: &[u8], position: &mut usize) -> Option<u8> {
+    let byte = *data.get(*position)?;
+    *position += 1;
+    Some(byte)
+}
+
+/// Reads a variable length integer of up to five bytes, least significant
+/// group first.
+fn decode_vbyte(data: &[u8], position: &mut usize) -> Option<u32> {
+    let lead = next(data, position)?;
+    if lead < 128 {
+        return Some(u32::from(lead));
+    }
+    let mut result = u32::from(lead & 127);
+    let mut shift = 7;
+    for _ in 0..4 {
+        let group = next(data, position)?;
+        result |= u32::from(group & 127) << shift;
+        shift += 7;
+        if group < 128 {
+            break;
+        }
+    }
+    Some(result)
+}
+
+/// Decodes 16 bytes stored with 0, 2, 4, or 8 bits each, where the largest
+/// value of 2 and 4 bit widths escapes to a full byte following the group.
+fn decode_bytes_group(data: &[u8], position: usize, group: &mut [u8], bits_log2: u8) -> usize {
+    match bits_log2 {
+        0 => {
+            group.iter_mut().for_each(|x| *x = 0);
+            position
+        },
+        3 => {
+            group.copy_from_slice(&data[position..position + BYTE_GROUP_SIZE]);
+            position + BYTE_GROUP_SIZE
+        },
+        _ => {
+            let bits = 1 << bits_log2;
+            let per_byte = 8 / bits;
+            let escape = (1 << bits) - 1;
+            let mut escaped = position + BYTE_GROUP_SIZE / per_byte;
+            for (i, value) in group.iter_mut().enumerate() {
+                let shift = 8 - bits * (i % per_byte + 1);
+                let encoded = (data[position + i / per_byte] >> shift) & escape;
+                if encoded == escape {
+                    *value = data[escaped];
+                    escaped += 1;
+                } else {
+                    *value = encoded;
+                }
+            }
+            escaped
+        },
+    }
+}
+
+/// Decodes the byte groups of one byte of each attribute of a block.
+fn decode_bytes(data: &[u8], mut position: usize, buffer: &mut [u8]) -> Option<usize> {
+    let groups = buffer.len() / BYTE_GROUP_SIZE;
+    let header_size = groups.div_ceil(4);
+    let header = data.get(position..position + header_size)?;
+    position += header_size;
+    for (i, group) in buffer.chunks_mut(BYTE_GROUP_SIZE).enumerate() {
+        if data.len() - position < BYTE_GROUP_DECODE_LIMIT {
+            return None;
+        }
+        let bits_log2 = (header[i / 4] >> ((i % 4) * 2)) & 3;
+        position = decode_bytes_group(data, position, group, bits_log2);
+    }
+    Some(position)
+}
+
+/// Decodes `count` attributes of `stride` bytes, which are stored as the
+/// deltas of each byte to the same byte of the previous attribute.
+fn decode_attributes(count: usize, stride: usize, data: &[u8]) -> Option<Vec<u8>> {
+    if stride == 0 || stride > 256 || !stride.is_multiple_of(4) || data.len() < 1 + stride {
+        return None;
+    }
+    if data[0] & 0xf0 != VERTEX_HEADER || data[0] & 0x0f > 0 {
+        return None;
+    }
+
+    // The tail holds the attribute the deltas of the first one refer to.
+    let mut last = data[data.len() - stride..].to_vec();
+    let block_size = ((VERTEX_BLOCK_SIZE_BYTES / stride) & !(BYTE_GROUP_SIZE - 1)).min(VERTEX_BLOCK_MAX_SIZE);
+    let mut buffer = [0; VERTEX_BLOCK_MAX_SIZE];
+    let mut output = vec![0; count * stride];
+    let mut position = 1;
+    for block in output.chunks_mut(block_size * stride) {
+        let size = block.len() / stride;
+        let aligned = (size + BYTE_GROUP_SIZE - 1) & !(BYTE_GROUP_SIZE - 1);
+        for (k, previous) in last.iter().enumerate() {
+            position = decode_bytes(data, position, &mut buffer[..aligned])?;
+            let mut value = *previous;
+            for (i, delta) in buffer[..size].iter().enumerate() {
+                value = unzigzag8(*delta).wrapping_add(value);
+                block[i * stride + k] = value;
+            }
+        }
+        last.copy_from_slice(&block[(size - 1) * stride..]);
+    }
+
+    if data.len() - position != stride.max(TAIL_MAX_SIZE) {
+        return None;
+    }
+    Some(output)
+}
+
+/// Adds an edge to the FIFO of recently used edges.
+fn push_edge(edges: &mut [[u32; 2]; 16], offset: &mut usize, a: u32, b: u32) {
+    edges[*offset] = [a, b];
+    *offset = (*offset + 1) & 15;
+}
+
+/// Adds a vertex to the FIFO of recently used vertices, where it is
+/// overwritten by the next vertex unless `advance` is set.
+fn push_vertex(vertices: &mut [u32; 16], offset: &mut usize, vertex: u32, advance: bool) {
+    vertices[*offset] = vertex;
+    *offset = (*offset + advance as usize) & 15;
+}
+
+/// Decodes `count` triangle list indices, which are stored as references to
+/// recently used edges and vertices where possible.
+fn decode_triangles(count: usize, data: &[u8]) -> Option<Vec<u32>> {
+    if !count.is_multiple_of(3) || data.len() < 1 + count / 3 + CODE_AUX_TABLE_SIZE {
+        return None;
+    }
+    if data[0] & 0xf0 != INDEX_HEADER {
+        return None;
+    }
+    let version = data[0] & 0x0f;
+    if version > 1 {
+        return None;
+    }
+
+    let mut edges = [[u32::MAX; 2]; 16];
+    let mut vertices = [u32::MAX; 16];
+    let mut edge_offset = 0usize;
+    let mut vertex_offset = 0usize;
+
+    let mut next_vertex = 0u32;
+    let mut last = 0u32;
+    let fec_max = if version >= 1 { 13 } else { 15 };
+
+    let mut position = 1 + count / 3;
+    let safe_end = data.len() - CODE_AUX_TABLE_SIZE;
+    let table = &data[safe_end..];
+    let mut indices = Vec::with_capacity(count);
+
+    for triangle in 0..count / 3 {
+        if position > safe_end {
+            return None;
+        }
+        let code_triangle = data[1 + triangle];
+
+        if code_triangle < 0xf0 {
+            let fe = usize::from(code_triangle >> 4);
+            let [a, b] = edges[edge_offset.wrapping_sub(1 + fe) & 15];
+            let fec = code_triangle & 15;
+
+            let c = if fec < fec_max {
+                let c = if fec == 0 {
+                    next_vertex += 1;
+                    next_vertex - 1
+                } else {
+                    vertices[vertex_offset.wrapping_sub(1 + usize::from(fec)) & 15]
+                };
+                push_vertex(&mut vertices, &mut vertex_offset, c, fec == 0);
+                c
+            } else {
+                // 13 and 14 are the previous free index minus and plus one.
+                last = match fec {
+                    15 => last.wrapping_add(unzigzag32(decode_vbyte(data, &mut position)?)),
+                    13 => last.wrapping_sub(1),
+                    _ => last.wrapping_add(1),
+                };
+                push_vertex(&mut vertices, &mut vertex_offset, last, true);
+                last
+            };
+            indices.extend_from_slice(&[a, b, c]);
+            push_edge(&mut edges, &mut edge_offset, c, b);
+            push_edge(&mut edges, &mut edge_offset, a, c);
+        } else {
+            let (a, b, c, advance_b, advance_c) = if code_triangle < 0xfe {
+                let code_aux = table[usize::from(code_triangle & 15)];
+                let (feb, fec) = (usize::from(code_aux >> 4), usize::from(code_aux & 15));
+                let a = next_vertex;
+                next_vertex += 1;
+                let b = if feb == 0 {
+                    next_vertex += 1;
+                    next_vertex - 1
+                } else {
+                    vertices[vertex_offset.wrapping_sub(feb) & 15]
+                };
+                let c = if fec == 0 {
+                    next_vertex += 1;
+                    next_vertex - 1
+                } else {
+                    vertices[vertex_offset.wrapping_sub(fec) & 15]
+                };
+                (a, b, c, feb == 0, fec == 0)
+            } else {
+                let code_aux = next(data, &mut position)?;
+                let fea = if code_triangle == 0xfe { 0 } else { 15 };
+                let (feb, fec) = (usize::from(code_aux >> 4), usize::from(code_aux & 15));
+                if code_aux == 0 {
+                    next_vertex = 0;
+                }
+                let mut vertex = |fe: usize| match fe {
+                    0 => {
+                        next_vertex += 1;
+                        next_vertex - 1
+                    },
+                    15 => 0,
+                    _ => vertices[vertex_offset.wrapping_sub(fe) & 15],
+                };
+                let (mut a, mut b, mut c) = (vertex(fea), vertex(feb), vertex(fec));
+                for (fe, index) in [(fea, &mut a), (feb, &mut b), (fec, &mut c)] {
+                    if fe == 15 {
+                        last = last.wrapping_add(unzigzag32(decode_vbyte(data, &mut position)?));
+                        *index = last;
+                    }
+                }
+                (a, b, c, feb == 0 || feb == 15, fec == 0 || fec == 15)
+            };
+            indices.extend_from_slice(&[a, b, c]);
+            push_vertex(&mut vertices, &mut vertex_offset, a, true);
+            push_vertex(&mut vertices, &mut vertex_offset, b, advance_b);
+            push_vertex(&mut vertices, &mut vertex_offset, c, advance_c);
+            push_edge(&mut edges, &mut edge_offset, b, a);
+            push_edge(&mut edges, &mut edge_offset, c, b);
+            push_edge(&mut edges, &mut edge_offset, a, c);
+        }
+    }
+
+    if position != safe_end {
+        return None;
+    }
+    Some(indices)
+}
+
+/// Decodes `count` indices, which are stored as deltas to one of the two
+/// previous indices.
+fn decode_sequence(count: usize, data: &[u8]) -> Option<Vec<u32>> {
+    if data.len() < 1 + count + 4 || data[0] & 0xf0 != SEQUENCE_HEADER || data[0] & 0x0f > 1 {
+        return None;
+    }
+    let safe_end = data.len() - 4;
+    let mut position = 1;
+    let mut last = [0u32; 2];
+    let mut indices = Vec::with_capacity(count);
+    for _ in 0..count {
+        if position >= safe_end {
+            return None;
+        }
+        let value = decode_vbyte(data, &mut position)?;
+        let baseline = (value & 1) as usize;
+        last[baseline] = last[baseline].wrapping_add(unzigzag32(value >> 1));
+        indices.push(last[baseline]);
+    }
+    if position != safe_end {
+        return None;
+    }
+    Some(indices)
+}
+
+/// Reads a signed component of `size` bytes.
+fn component(element: &[u8], size: usize, index: usize) -> f32 {
+    match size {
+        1 => f32::from(element[index] as i8),
+        _ => f32::from(i16::from_le_bytes([element[2 * index], element[2 * index + 1]])),
+    }
+}
+
+/// Writes a signed component of `size` bytes, truncating the value.
+fn set_component(element: &mut [u8], size: usize, index: usize, value: i32) {
+    match size {
+        1 => element[index] = value as i8 as u8,
+        _ => element[2 * index..2 * index + 2].copy_from_slice(&(value as i16).to_le_bytes()),
+    }
+}
+
+/// Rounds to the nearest integer, away from zero at halfway.
+fn round(value: f32) -> i32 {
+    (value + if value >= 0.0 { 0.5 } else { -0.5 }) as i32
+}
+
+/// Reconstructs unit vectors from octahedral coordinates, keeping the fourth
+/// component.
+fn filter_octahedral(data: &mut [u8], stride: usize) {
+    let size = stride / 4;
+    let max = if size == 1 { 127.0 } else { 32767.0 };
+    for element in data.chunks_exact_mut(stride) {
+        let mut x = component(element, size, 0);
+        let mut y = component(element, size, 1);
+        let z = component(element, size, 2) - x.abs() - y.abs();
+
+        // Unfold the octahedron for negative Z.
+        let t = if z < 0.0 { z } else { 0.0 };
+        x += if x >= 0.0 { t } else { -t };
+        y += if y >= 0.0 { t } else { -t };
+
+        let scale = max / (x * x + y * y + z * z).sqrt();
+        set_component(element, size, 0, round(x * scale));
+        set_component(element, size, 1, round(y * scale));
+        set_component(element, size, 2, round(z * scale));
+    }
+}
+
+/// Reconstructs unit quaternions from their three smallest components.
+fn filter_quaternion(data: &mut [u8]) {
+    let scale = 1.0 / 2f32.sqrt();
+    for element in data.chunks_exact_mut(8) {
+        let stored = i16::from_le_bytes([element[6], element[7]]);
+        let component_scale = scale / (i32::from(stored) | 3) as f32;
+        let x = component(element, 2, 0) * component_scale;
+        let y = component(element, 2, 1) * component_scale;
+        let z = component(element, 2, 2) * component_scale;
+        let w = (1.0 - x * x - y * y - z * z).max(0.0).sqrt();
+
+        // The low bits store the index of the reconstructed component.
+        let largest = (stored & 3) as usize;
+        set_component(element, 2, (largest + 1) & 3, round(x * 32767.0));
+        set_component(element, 2, (largest + 2) & 3, round(y * 32767.0));
+        set_component(element, 2, (largest + 3) & 3, round(z * 32767.0));
+        set_component(element, 2, largest, (w * 32767.0 + 0.5) as i32);
+    }
+}
+
+/// Reconstructs floats from a 24 bit mantissa and an 8 bit exponent.
+fn filter_exponential(data: &mut [u8]) {
+    for word in data.chunks_exact_mut(4) {
+        let value = u32::from_le_bytes([word[0], word[1], word[2], word[3]]);
+        let mantissa = ((value << 8) as i32) >> 8;
+        let exponent = (value as i32) >> 24;
+        let power = f32::from_bits(((exponent + 127) as u32) << 23);
+        word.copy_from_slice(&(power * mantissa as f32).to_bits().to_le_bytes());
+    }
+}
+
+/// Decompresses the data of a buffer view of `length` bytes, returning `None`
+/// if the data is malformed.
+///
+/// The count and stride are checked against the length of the view before
+/// any output is allocated, since both are read from the document.
+pub(crate) fn decode(compression: &Compression, data: &[u8], length: usize) -> Option<Vec<u8>> {
+    let (mode, filter) = match (compression.mode, compression.filter) {
+        (Checked::Valid(mode), Checked::Valid(filter)) => (mode, filter),
+        _ => return None,
+    };
+    let count = compression.count as usize;
+    let stride = compression.byte_stride as usize;
+    if count.checked_mul(stride) != Some(length) {
+        return None;
+    }
+    let indices = match mode {
+        Mode::Attributes => {
+            let mut output = decode_attributes(count, stride, data)?;
+            match (filter, stride) {
+                (Filter::None, _) => {},
+                (Filter::Octahedral, 4) | (Filter::Octahedral, 8) => filter_octahedral(&mut output, stride),
+                (Filter::Quaternion, 8) => filter_quaternion(&mut output),
+                (Filter::Exponential, _) => filter_exponential(&mut output),
+                _ => return None,
+            }
+            return Some(output);
+        },
+        Mode::Triangles => decode_triangles(count, data)?,
+        Mode::Indices => decode_sequence(count, data)?,
+    };
+    match stride {
+        2 => Some(indices.iter().flat_map(|&x| (x as u16).to_le_bytes()).collect()),
+        4 => Some(indices.iter().flat_map(|&x| x.to_le_bytes()).collect()),
+        _ => None,
+    }
+}
+
+/// Decompresses every buffer view with the `EXT_meshopt_compression`
+/// extension into the data of its buffer.
+pub(crate) fn decode_views(document: &Document, buffers: &mut [buffer::Data]) -> Result<()> {
+    for view in document.views() {
+        let compression = match view.meshopt_compression() {
+            Some(compression) => compression,
+            None => continue,
+        };
+        let malformed = || Error::Meshopt { view: view.index() };
+        let begin = compression.byte_offset as usize;
+        let end = begin.checked_add(compression.byte_length as usize).ok_or_else(malformed)?;
+        let decoded = buffers
+            .get(compression.buffer.value())
+            .and_then(|data| data.get(begin..end))
+            .and_then(|source| decode(compression, source, view.length()))
+            .ok_or_else(malformed)?;
+
+        // The data of the view is usually a fallback buffer without a `uri`.
+        let target = view.buffer().and_then(|x| buffers.get_mut(x.index())).ok_or_else(malformed)?;
+        let mut data = match std::mem::replace(target, buffer::Data::Owned(Vec::new())) {
+            buffer::Data::Owned(data) => data,
+            data => data.to_vec(),
+        };
+        let begin = view.offset();
+        let result = data.get_mut(begin..begin + decoded.len()).map(|x| x.copy_from_slice(&decoded));
+        *target = buffer::Data::Owned(data);
+        result.ok_or_else(malformed)?;
+    }
+    Ok(())
+}
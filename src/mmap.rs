@@ -0,0 +1,95 @@
+use std::os::raw::c_void;
+use std::os::unix::io::AsRawFd;
+use std::path::Path;
+use std::sync::Arc;
+use std::{fmt, fs, io, ops, ptr};
+
+/// An owned read-only mapping of an entire file.
+struct Region {
+    ptr: *mut c_void,
+    len: usize,
+}
+
+// The mapping is private, read-only, and never written through.
+unsafe impl Send for Region {}
+unsafe impl Sync for Region {}
+
+impl Drop for Region {
+    fn drop(&mut self) {
+        unsafe {
+            libc::munmap(self.ptr, self.len);
+        }
+    }
+}
+
+/// A read-only memory-mapped region of a file.
+///
+/// Clones share the same mapping, which is released when the last clone is
+/// dropped. Mappings are only created by the unsafe [`import_mapped`], whose
+/// caller guarantees that the file is not modified or truncated while it is
+/// mapped.
+///
+/// [`import_mapped`]: ../fn.import_mapped.html
+#[derive(Clone)]
+pub struct Mapped {
+    region: Arc<Region>,
+    offset: usize,
+    len: usize,
+}
+
+impl Mapped {
+    /// Maps the file at the given path.
+    ///
+    /// Returns `None` for empty files, which cannot be mapped.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated while the mapping or any
+    /// slice of it is alive.
+    pub(crate) unsafe fn open(path: &Path) -> io::Result<Option<Self>> {
+        let file = fs::File::open(path)?;
+        let len = file.metadata()?.len() as usize;
+        if len == 0 {
+            return Ok(None);
+        }
+        let ptr = unsafe {
+            libc::mmap(ptr::null_mut(), len, libc::PROT_READ, libc::MAP_PRIVATE, file.as_raw_fd(), 0)
+        };
+        if ptr == libc::MAP_FAILED {
+            return Err(io::Error::last_os_error());
+        }
+        let region = Arc::new(Region { ptr, len });
+        Ok(Some(Mapped { region, offset: 0, len }))
+    }
+
+    /// Returns the sub-region `offset..offset + len` sharing this mapping.
+    ///
+    /// Panics if the range is out of bounds.
+    pub(crate) fn slice(&self, offset: usize, len: usize) -> Self {
+        assert!(offset + len <= self.len);
+        Mapped {
+            region: self.region.clone(),
+            offset: self.offset + offset,
+            len,
+        }
+    }
+}
+
+impl ops::Deref for Mapped {
+    type Target = [u8];
+    fn deref(&self) -> &Self::Target {
+        unsafe {
+            let base = self.region.ptr as *const u8;
+            std::slice::from_raw_parts(base.add(self.offset), self.len)
+        }
+    }
+}
+
+impl fmt::Debug for Mapped {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Mapped")
+            .field("offset", &self.offset)
+            .field("len", &self.len)
+            .finish()
+    }
+}
@@ -0,0 +1,61 @@
+//! Commonly used types, for glob importing.
+//!
+//! The prelude contains the wrapper types that documents are navigated with,
+//! the enumerations most often matched on, the readers of vertex, skin, and
+//! animation data, and the `Item` and `TypedItem` traits bounding the types
+//! read from accessors. Readers share the name `Reader` in their own modules
+//! and are re-exported here as `MeshReader`, `SkinReader`, and
+//! `AnimationReader`. `Sampler` refers to the texture sampler; the animation
+//! sampler remains available as `animation::Sampler`.
+//!
+//! The JSON data structures are deliberately excluded, since several of them
+//! share their names with the wrapper types. They remain available through
+//! `gltf::json`.
+//!
+//! ```
+//! use gltf::prelude::*;
+//!
+//! # fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! let gltf = Gltf::open("examples/Box.gltf")?;
+//! let scene: Scene = gltf.default_scene().unwrap();
+//! for node in scene.nodes() {
+//!     let primitives = node.mesh().iter().flat_map(Mesh::primitives).collect::<Vec<Primitive>>();
+//!     for primitive in primitives {
+//!         let material: Material = primitive.material();
+//!         let positions: Option<Accessor> = primitive.get(&Semantic::Positions);
+//!         assert_eq!(primitive.mode(), Mode::Triangles);
+//!         assert_eq!(material.alpha_mode(), AlphaMode::Opaque);
+//!         assert!(positions.is_some());
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! # fn main() {
+//! #    let _ = run().expect("runtime error");
+//! # }
+//! ```
+
+#[cfg(feature = "utils")]
+#[doc(no_inline)]
+pub use crate::accessor::{Item, TypedItem};
+#[doc(no_inline)]
+pub use crate::animation::Interpolation;
+#[cfg(feature = "utils")]
+#[doc(no_inline)]
+pub use crate::animation::Reader as AnimationReader;
+#[doc(no_inline)]
+pub use crate::material::AlphaMode;
+#[doc(no_inline)]
+pub use crate::mesh::Reader as MeshReader;
+#[doc(no_inline)]
+pub use crate::mesh::{Mode, Semantic};
+#[cfg(feature = "utils")]
+#[doc(no_inline)]
+pub use crate::skin::Reader as SkinReader;
+#[doc(no_inline)]
+pub use crate::texture::Sampler;
+#[doc(no_inline)]
+pub use crate::{
+    Accessor, Animation, Camera, Document, Gltf, Image, Material, Mesh, Node, Primitive, Scene,
+    Skin, Texture,
+};
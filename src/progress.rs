@@ -0,0 +1,91 @@
+//! [`import_with_progress`] and [`import_slice_with_progress`] report each
+//! stage of loading to a [`Progress`], for example to drive a progress bar
+//! while the buffers and images of a large asset are read:
+//!
+//! ```
+//! # fn run() -> Result<(), gltf::Error> {
+//! use gltf::progress::Event;
+//!
+//! let mut loaded = 0;
+//! let mut progress = |event: Event| {
+//!     if let Event::BufferLoaded { bytes, .. } = event {
+//!         loaded += bytes;
+//!     }
+//! };
+//! let (_, buffers, _) = gltf::import_with_progress("examples/Box.gltf", &mut progress)?;
+//! println!("read {} bytes into {} buffers", loaded, buffers.len());
+//! # Ok(())
+//! # }
+//! # fn main() {
+//! #     run().expect("test failure");
+//! # }
+//! ```
+//!
+//! Events are reported in the order listed by [`Event`], from the thread that
+//! called the import function. The `image_jpeg_rayon` feature parallelizes
+//! the decoding of a single JPEG image and does not change this.
+//!
+//! [`import_with_progress`]: ../fn.import_with_progress.html
+//! [`import_slice_with_progress`]: ../fn.import_slice_with_progress.html
+//! [`Progress`]: trait.Progress.html
+//! [`Event`]: enum.Event.html
+
+/// A stage of import that completed.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Event {
+    /// The JSON, or binary glTF, was deserialized from the given number of
+    /// bytes, if known.
+    JsonParsed {
+        /// The size of the file or slice.
+        bytes: Option<usize>,
+    },
+
+    /// The document was validated.
+    ///
+    /// Loaders that deserialize and validate in one step report this right
+    /// after `JsonParsed`.
+    ValidationDone,
+
+    /// The data of a buffer was read, in the order of the buffers.
+    BufferLoaded {
+        /// The index of the buffer.
+        index: usize,
+
+        /// The size of the buffer data, before padding.
+        bytes: usize,
+
+        /// The number of buffers of the document.
+        total_buffers: usize,
+    },
+
+    /// An image was decoded, in the order of the images.
+    ImageDecoded {
+        /// The index of the image.
+        index: usize,
+
+        /// The number of images of the document.
+        total: usize,
+    },
+
+    /// Every buffer and image was imported.
+    Finished {
+        /// The total size of the buffer data, after padding.
+        bytes: usize,
+    },
+}
+
+/// Receives the events of an import.
+///
+/// Implemented for closures taking an [`Event`].
+///
+/// [`Event`]: enum.Event.html
+pub trait Progress {
+    /// Called when a stage of import completes.
+    fn event(&mut self, event: Event);
+}
+
+impl<F: FnMut(Event)> Progress for F {
+    fn event(&mut self, event: Event) {
+        self(event)
+    }
+}
@@ -0,0 +1,159 @@
+//! Summaries of the capabilities needed to render a document.
+//!
+//! [`Document::requirements`] lets an application check an asset against its
+//! limits before loading any data, so it can refuse the asset or choose a
+//! fallback early.
+//!
+//! [`Document::requirements`]: ../struct.Document.html#method.requirements
+
+use std::fmt;
+
+use crate::accessor::DataType;
+use crate::image::{probe_dimensions, Source};
+use crate::{Buffer, Document, Semantic};
+
+/// The capabilities needed to render a document, as returned by
+/// [`Document::requirements`].
+///
+/// Every field except the image sizes is computed from the JSON alone.
+///
+/// [`Document::requirements`]: ../struct.Document.html#method.requirements
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Requirements {
+    /// The extensions listed in `extensionsRequired`.
+    pub extensions_required: Vec<String>,
+
+    /// The extensions listed in `extensionsUsed`.
+    pub extensions_used: Vec<String>,
+
+    /// `true` if any node has both a mesh and a skin.
+    pub skinning: bool,
+
+    /// The largest number of joints of a skin.
+    pub max_skin_joints: usize,
+
+    /// The largest number of `JOINTS_n` and `WEIGHTS_n` pairs of a primitive.
+    /// Each pair holds four influences per vertex.
+    pub max_joint_sets: u32,
+
+    /// `true` if any primitive has morph targets.
+    pub morph_targets: bool,
+
+    /// The largest number of morph targets of a primitive.
+    pub max_morph_targets: usize,
+
+    /// The number of texture coordinate sets needed by the primitive with
+    /// the highest `TEXCOORD_n` set, that is the highest `n` plus one.
+    pub max_tex_coord_sets: u32,
+
+    /// The number of vertex color sets needed by the primitive with the
+    /// highest `COLOR_n` set, that is the highest `n` plus one.
+    pub max_color_sets: u32,
+
+    /// `true` if any primitive has 32-bit unsigned indices.
+    pub u32_indices: bool,
+
+    /// `true` if the document has animations.
+    pub animations: bool,
+
+    /// The largest width or height of an image in pixels, or `None` if the
+    /// size of no image could be read.
+    pub max_image_size: Option<u32>,
+
+    /// The number of images whose size could not be read.
+    ///
+    /// Only images stored in buffer views are probed, and only when their
+    /// buffer data is available. Images referenced by URI are always
+    /// counted here.
+    pub unprobed_images: usize,
+}
+
+impl fmt::Display for Requirements {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let list = |names: &[String]| if names.is_empty() { String::from("none") } else { names.join(", ") };
+        let yes_no = |value: bool| if value { "yes" } else { "no" };
+        writeln!(f, "extensions required: {}", list(&self.extensions_required))?;
+        writeln!(f, "extensions used: {}", list(&self.extensions_used))?;
+        writeln!(
+            f,
+            "skinning: {} (max {} joints per skin, {} joint sets)",
+            yes_no(self.skinning),
+            self.max_skin_joints,
+            self.max_joint_sets,
+        )?;
+        writeln!(f, "morph targets: {} (max {})", yes_no(self.morph_targets), self.max_morph_targets)?;
+        writeln!(f, "texture coordinate sets: {}", self.max_tex_coord_sets)?;
+        writeln!(f, "color sets: {}", self.max_color_sets)?;
+        writeln!(f, "32-bit indices: {}", yes_no(self.u32_indices))?;
+        writeln!(f, "animations: {}", yes_no(self.animations))?;
+        match self.max_image_size {
+            Some(size) => write!(f, "max image size: {}", size)?,
+            None => write!(f, "max image size: unknown")?,
+        }
+        if self.unprobed_images > 0 {
+            write!(f, " ({} images not probed)", self.unprobed_images)?;
+        }
+        Ok(())
+    }
+}
+
+/// Summarizes the capabilities needed to render a document.
+pub(crate) fn requirements<'a, 's, F>(document: &'a Document, get_buffer_data: Option<F>) -> Requirements
+where
+    F: Fn(Buffer<'a>) -> Option<&'s [u8]>,
+{
+    let mut requirements = Requirements {
+        extensions_required: document.extensions_required().map(String::from).collect(),
+        extensions_used: document.extensions_used().map(String::from).collect(),
+        animations: !document.0.animations.is_empty(),
+        ..Default::default()
+    };
+
+    for node in document.nodes() {
+        if node.mesh().is_some() && node.skin().is_some() {
+            requirements.skinning = true;
+        }
+    }
+    for skin in document.skins() {
+        requirements.max_skin_joints = requirements.max_skin_joints.max(skin.joints().count());
+    }
+
+    for primitive in document.primitives() {
+        requirements.max_joint_sets = requirements.max_joint_sets.max(primitive.joint_set_count());
+        let targets = primitive.morph_targets().count();
+        requirements.morph_targets |= targets > 0;
+        requirements.max_morph_targets = requirements.max_morph_targets.max(targets);
+        for (semantic, _) in primitive.attributes() {
+            match semantic {
+                Semantic::TexCoords(set) => {
+                    requirements.max_tex_coord_sets = requirements.max_tex_coord_sets.max(set.saturating_add(1));
+                },
+                Semantic::Colors(set) => {
+                    requirements.max_color_sets = requirements.max_color_sets.max(set.saturating_add(1));
+                },
+                _ => {},
+            }
+        }
+        if primitive.indices().is_some_and(|x| x.data_type() == Some(DataType::U32)) {
+            requirements.u32_indices = true;
+        }
+    }
+
+    for image in document.images() {
+        let size = match (image.source(), get_buffer_data.as_ref()) {
+            (Some(Source::View { view, .. }), Some(get_buffer_data)) => view.buffer()
+                .and_then(|buffer| get_buffer_data(buffer))
+                .and_then(|data| data.get(view.offset()..view.offset() + view.length()))
+                .and_then(probe_dimensions),
+            _ => None,
+        };
+        match size {
+            Some((width, height)) => {
+                let size = width.max(height);
+                requirements.max_image_size = Some(requirements.max_image_size.map_or(size, |x| x.max(size)));
+            },
+            None => requirements.unprobed_images += 1,
+        }
+    }
+    requirements
+}
@@ -0,0 +1,166 @@
+use crate::math::Matrix4;
+use crate::mesh::{BoundingBox, Bounds, Primitive};
+use crate::scene::{Node, Visibility};
+use crate::{Document, Mesh};
+
+#[cfg(feature = "utils")]
+use crate::{accessor, Buffer};
+
+/// The bounding box of a scene or a node hierarchy.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WorldBounds {
+    /// The axis-aligned bounding box enclosing every primitive.
+    pub bounding_box: BoundingBox,
+
+    /// `true` if the bounding box may not enclose the rendered geometry.
+    ///
+    /// This is the case when a skinned or morphed primitive contributes, since
+    /// only its bind-pose bounds are known.
+    pub approximate: bool,
+}
+
+const IDENTITY: [[f32; 4]; 4] = [
+    [1.0, 0.0, 0.0, 0.0],
+    [0.0, 1.0, 0.0, 0.0],
+    [0.0, 0.0, 1.0, 0.0],
+    [0.0, 0.0, 0.0, 1.0],
+];
+
+/// Accumulates transformed points into a bounding box.
+struct Accumulator {
+    bounds: Option<BoundingBox>,
+    approximate: bool,
+}
+
+impl Accumulator {
+    fn add(&mut self, matrix: &[[f32; 4]; 4], p: [f32; 3]) {
+        let mut q = [0.0; 3];
+        for (i, q) in q.iter_mut().enumerate() {
+            *q = matrix[0][i] * p[0] + matrix[1][i] * p[1] + matrix[2][i] * p[2] + matrix[3][i];
+        }
+        match self.bounds {
+            Some(ref mut bounds) => {
+                for (i, &q) in q.iter().enumerate() {
+                    bounds.min[i] = bounds.min[i].min(q);
+                    bounds.max[i] = bounds.max[i].max(q);
+                }
+            },
+            None => self.bounds = Some(Bounds { min: q, max: q }),
+        }
+    }
+
+    /// Adds the primitives of a mesh, transformed by `matrix`.
+    fn add_mesh<'a, P, I>(&mut self, matrix: &[[f32; 4]; 4], mesh: &Mesh<'a>, skinned: bool, positions: &P)
+    where
+        P: Fn(&Primitive<'a>) -> Option<I>,
+        I: Iterator<Item = [f32; 3]>,
+    {
+        for primitive in mesh.primitives() {
+            // Skinned and morphed vertices are only known at runtime, so
+            // their bind-pose bounds are used instead.
+            let animated = skinned || primitive.morph_targets().len() > 0;
+            self.approximate |= animated;
+            match positions(&primitive) {
+                Some(iter) if !animated => {
+                    for p in iter {
+                        self.add(matrix, p);
+                    }
+                },
+                _ => self.add_corners(matrix, &primitive),
+            }
+        }
+    }
+
+    fn finish(self) -> Option<WorldBounds> {
+        let approximate = self.approximate;
+        self.bounds.map(|bounding_box| WorldBounds { bounding_box, approximate })
+    }
+
+    /// Adds all eight corners of the accessor bounds of a primitive.
+    fn add_corners(&mut self, matrix: &[[f32; 4]; 4], primitive: &Primitive) {
+        let Bounds { min, max } = match primitive.bounding_box() {
+            Some(bounds) => bounds,
+            None => return,
+        };
+        for corner in 0..8 {
+            let pick = |axis: usize| if corner & (1 << axis) == 0 { min[axis] } else { max[axis] };
+            self.add(matrix, [pick(0), pick(1), pick(2)]);
+        }
+    }
+}
+
+/// Computes the bounds of the node hierarchies rooted at `roots`, each paired
+/// with the transform of its parent, skipping the nodes hidden by
+/// `visibility` together with their descendants.
+///
+/// When `positions` returns the vertex positions of a primitive, the vertices
+/// are transformed individually; otherwise the corners of the accessor bounds
+/// are used.
+pub(crate) fn compute<'a, P, I>(
+    document: &'a Document,
+    roots: Vec<(usize, Matrix4)>,
+    visibility: Visibility,
+    positions: P,
+) -> Option<WorldBounds>
+where
+    P: Fn(&Primitive<'a>) -> Option<I>,
+    I: Iterator<Item = [f32; 3]>,
+{
+    let mut accumulator = Accumulator { bounds: None, approximate: false };
+    let mut stack = roots;
+    stack.reverse();
+    let mut visited = vec![false; document.0.nodes.len()];
+    while let Some((index, parent)) = stack.pop() {
+        // Guard against invalid node hierarchies and missing nodes.
+        let node: Node<'a> = match document.get_usize(index) {
+            Some(node) if !visited[index] => node,
+            _ => continue,
+        };
+        visited[index] = true;
+        if visibility.hides(&node) {
+            continue;
+        }
+        let world = parent * Matrix4::from_array(node.transform().matrix());
+        for child in node.json.children.iter().flatten().rev() {
+            stack.push((child.value(), world));
+        }
+        let mesh = match node.mesh() {
+            Some(mesh) => mesh,
+            None => continue,
+        };
+        // The transform of a skinned mesh node is ignored; in the bind pose
+        // the vertices are already in world space.
+        let skinned = node.skin().is_some();
+        let matrix = if skinned { IDENTITY } else { world.as_array() };
+        accumulator.add_mesh(&matrix, &mesh, skinned, &positions);
+    }
+    accumulator.finish()
+}
+
+/// Computes the bounds of every mesh of a document in its local space.
+pub(crate) fn compute_meshes<'a, P, I>(document: &'a Document, positions: P) -> Option<WorldBounds>
+where
+    P: Fn(&Primitive<'a>) -> Option<I>,
+    I: Iterator<Item = [f32; 3]>,
+{
+    let mut accumulator = Accumulator { bounds: None, approximate: false };
+    for mesh in document.meshes() {
+        accumulator.add_mesh(&IDENTITY, &mesh, false, &positions);
+    }
+    accumulator.finish()
+}
+
+/// Returns a closure reading the vertex positions of a primitive.
+#[cfg(feature = "utils")]
+pub(crate) fn reader<'a, 's, F>(
+    get_buffer_data: F,
+) -> impl Fn(&Primitive<'a>) -> Option<accessor::Iter<'s, [f32; 3]>>
+where
+    F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+{
+    move |primitive| {
+        primitive
+            .get(&crate::mesh::Semantic::Positions)
+            .and_then(|accessor| accessor::Iter::new(accessor, get_buffer_data.clone()))
+    }
+}
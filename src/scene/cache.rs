@@ -0,0 +1,139 @@
+use crate::math::*;
+
+use super::{Scene, Transform, Visibility};
+
+const IDENTITY: [[f32; 4]; 4] = [
+    [1.0, 0.0, 0.0, 0.0],
+    [0.0, 1.0, 0.0, 0.0],
+    [0.0, 0.0, 1.0, 0.0],
+    [0.0, 0.0, 0.0, 1.0],
+];
+
+/// The local and world transforms of the nodes of a scene, kept up to date
+/// as local transforms change, for example during animation playback.
+///
+/// Transforms are stored in flat arrays indexed by node. [`set_local`] marks
+/// a node as changed, and [`update`] recomputes the world transforms of the
+/// changed nodes and their descendants only. Neither allocates.
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// use gltf::scene::{Transform, TransformCache};
+///
+/// let gltf = gltf::Gltf::open("examples/Box.gltf")?;
+/// let scene = gltf.default_scene().unwrap();
+/// let mut cache = TransformCache::new(&scene);
+/// cache.set_local(0, Transform::Decomposed {
+///     translation: [0.0, 2.0, 0.0],
+///     rotation: [0.0, 0.0, 0.0, 1.0],
+///     scale: [1.0, 1.0, 1.0],
+/// });
+/// cache.update();
+/// assert_eq!(cache.world(1).unwrap()[3], [0.0, 2.0, 0.0, 1.0]);
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #     run().expect("test failure");
+/// # }
+/// ```
+///
+/// [`set_local`]: #method.set_local
+/// [`update`]: #method.update
+#[derive(Clone, Debug)]
+pub struct TransformCache {
+    /// The nodes of the scene, each after its parent.
+    order: Vec<usize>,
+
+    /// The parent of each node of the scene, by node index.
+    parents: Vec<Option<usize>>,
+
+    /// Marks the nodes of the scene, by node index.
+    members: Vec<bool>,
+
+    /// The local transform matrix of each node, by node index.
+    local: Vec<[[f32; 4]; 4]>,
+
+    /// The world transform matrix of each node, by node index.
+    world: Vec<[[f32; 4]; 4]>,
+
+    /// Marks the nodes whose local transform changed since the last update.
+    dirty: Vec<bool>,
+
+    /// Whether any node is marked as dirty.
+    pending: bool,
+}
+
+impl TransformCache {
+    /// Computes the local and world transforms of the nodes reachable from
+    /// the root nodes of a scene.
+    pub fn new(scene: &Scene) -> Self {
+        let count = scene.document.0.nodes.len();
+        let mut cache = TransformCache {
+            order: Vec::new(),
+            parents: vec![None; count],
+            members: vec![false; count],
+            local: vec![IDENTITY; count],
+            world: vec![IDENTITY; count],
+            dirty: vec![false; count],
+            pending: false,
+        };
+        for (node, world) in scene.walk(Visibility::default()) {
+            let index = node.index();
+            cache.order.push(index);
+            cache.members[index] = true;
+            cache.local[index] = node.transform().matrix();
+            cache.world[index] = world;
+            for child in node.children() {
+                cache.parents[child.index()] = Some(index);
+            }
+        }
+        cache
+    }
+
+    /// Replaces the local transform of a node and marks it for [`update`].
+    ///
+    /// Nodes outside of the scene are ignored.
+    ///
+    /// [`update`]: #method.update
+    pub fn set_local(&mut self, node_index: usize, transform: Transform) {
+        if self.members.get(node_index) == Some(&true) {
+            self.local[node_index] = transform.matrix();
+            self.dirty[node_index] = true;
+            self.pending = true;
+        }
+    }
+
+    /// Recomputes the world transforms of the nodes whose local transform
+    /// changed since the last update, together with their descendants.
+    pub fn update(&mut self) {
+        if !self.pending {
+            return;
+        }
+        for &index in &self.order {
+            let parent = self.parents[index];
+            if parent.is_some_and(|x| self.dirty[x]) {
+                self.dirty[index] = true;
+            }
+            if self.dirty[index] {
+                let parent = Matrix4::from_array(parent.map_or(IDENTITY, |x| self.world[x]));
+                self.world[index] = (parent * Matrix4::from_array(self.local[index])).as_array();
+            }
+        }
+        self.dirty.iter_mut().for_each(|x| *x = false);
+        self.pending = false;
+    }
+
+    /// Returns the local transform matrix of a node, or `None` if the node is
+    /// not part of the scene.
+    pub fn local(&self, node_index: usize) -> Option<[[f32; 4]; 4]> {
+        self.members.get(node_index).filter(|&&x| x).map(|_| self.local[node_index])
+    }
+
+    /// Returns the world transform matrix of a node as of the last
+    /// [`update`], or `None` if the node is not part of the scene.
+    ///
+    /// [`update`]: #method.update
+    pub fn world(&self, node_index: usize) -> Option<[[f32; 4]; 4]> {
+        self.members.get(node_index).filter(|&&x| x).map(|_| self.world[node_index])
+    }
+}
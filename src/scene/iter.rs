@@ -1,8 +1,14 @@
 use std::slice;
 
-use crate::{Document, Node};
+use crate::math::Matrix4;
+use crate::mesh::{self, Primitive};
+use crate::scene::Visibility;
+use crate::{Camera, Document, Node};
 
 /// An `Iterator` that visits the nodes in a scene.
+///
+/// Missing nodes, which are only possible for documents loaded without
+/// validation, are skipped.
 #[derive(Clone, Debug)]
 pub struct Nodes<'a> {
     /// The parent `Document` struct.
@@ -13,6 +19,9 @@ pub struct Nodes<'a> {
 }
 
 /// An `Iterator` that visits the children of a node.
+///
+/// Missing nodes, which are only possible for documents loaded without
+/// validation, are skipped.
 #[derive(Clone, Debug)]
 pub struct Children<'a> {
     /// The parent `Document` struct.
@@ -22,30 +31,220 @@ pub struct Children<'a> {
     pub(crate) iter: slice::Iter<'a, json::Index<json::scene::Node>>,
 }
 
-impl<'a> ExactSizeIterator for Nodes<'a> {}
+/// An `Iterator` that visits the primitives reachable from the root nodes of
+/// a scene, together with the world transform of the referencing node.
+///
+/// See [`Document::scene_primitives`] for the primitives of documents without
+/// a scene.
+///
+/// [`Document::scene_primitives`]: ../../struct.Document.html#method.scene_primitives
+#[derive(Clone, Debug)]
+pub struct Primitives<'a> {
+    /// The parent `Document` struct.
+    pub(crate) document: &'a Document,
+
+    /// Nodes yet to be visited, with the world transform of their parent.
+    pub(crate) stack: Vec<(usize, Matrix4)>,
+
+    /// Marks visited nodes, guarding against invalid node hierarchies.
+    pub(crate) visited: Vec<bool>,
+
+    /// The hidden nodes to skip together with their descendants.
+    pub(crate) visibility: Visibility,
+
+    /// The primitives of the current node and its world transform.
+    pub(crate) current: Option<(mesh::iter::Primitives<'a>, [[f32; 4]; 4])>,
+
+    /// Meshes visited with the identity transform once the stack is empty,
+    /// as requested by `SceneFallback::AllMeshes`.
+    pub(crate) meshes: Option<crate::iter::Meshes<'a>>,
+}
+
+impl<'a> Iterator for Primitives<'a> {
+    type Item = (Primitive<'a>, [[f32; 4]; 4]);
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((ref mut primitives, transform)) = self.current {
+                if let Some(primitive) = primitives.next() {
+                    return Some((primitive, transform));
+                }
+            }
+            self.current = None;
+            let (index, parent) = match self.stack.pop() {
+                Some(entry) => entry,
+                None => {
+                    let mesh = self.meshes.as_mut()?.next()?;
+                    let identity = Matrix4::from_nonuniform_scale(1.0, 1.0, 1.0);
+                    self.current = Some((mesh.primitives(), identity.as_array()));
+                    continue;
+                },
+            };
+            let node: Node<'a> = match self.document.get_usize(index) {
+                Some(node) if !self.visited[index] => node,
+                _ => continue,
+            };
+            self.visited[index] = true;
+            if self.visibility.hides(&node) {
+                continue;
+            }
+            let world = parent * Matrix4::from_array(node.transform().matrix());
+            for child in node.json.children.iter().flatten().rev() {
+                self.stack.push((child.value(), world));
+            }
+            if let Some(mesh) = node.mesh() {
+                self.current = Some((mesh.primitives(), world.as_array()));
+            }
+        }
+    }
+}
+
+/// An `Iterator` that visits the nodes reachable from the root nodes of a
+/// scene in depth-first order, together with their world transform.
+#[derive(Clone, Debug)]
+pub struct Walk<'a> {
+    /// The parent `Document` struct.
+    pub(crate) document: &'a Document,
+
+    /// Nodes yet to be visited, with the world transform of their parent.
+    pub(crate) stack: Vec<(usize, Matrix4)>,
+
+    /// Marks visited nodes, guarding against invalid node hierarchies.
+    pub(crate) visited: Vec<bool>,
+
+    /// The hidden nodes to skip together with their descendants.
+    pub(crate) visibility: Visibility,
+}
+
+impl<'a> Iterator for Walk<'a> {
+    type Item = (Node<'a>, [[f32; 4]; 4]);
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((index, parent)) = self.stack.pop() {
+            let node: Node<'a> = match self.document.get_usize(index) {
+                Some(node) if !self.visited[index] => node,
+                _ => continue,
+            };
+            self.visited[index] = true;
+            if self.visibility.hides(&node) {
+                continue;
+            }
+            let world = parent * Matrix4::from_array(node.transform().matrix());
+            for child in node.json.children.iter().flatten().rev() {
+                self.stack.push((child.value(), world));
+            }
+            return Some((node, world.as_array()));
+        }
+        None
+    }
+}
+
+/// An `Iterator` that visits the cameras of a scene together with the node
+/// referencing them and its world transform, as returned by
+/// [`Scene::cameras`].
+///
+/// [`Scene::cameras`]: ../struct.Scene.html#method.cameras
+#[derive(Clone, Debug)]
+pub struct Cameras<'a> {
+    /// The traversal of the scene.
+    pub(crate) walk: Walk<'a>,
+
+    /// Marks visited cameras when only the first reference to each camera is
+    /// visited.
+    pub(crate) seen: Option<Vec<bool>>,
+}
+
+impl<'a> Cameras<'a> {
+    /// Visits each camera only once, with the first node referencing it.
+    pub fn unique(mut self) -> Self {
+        self.seen = Some(vec![false; self.walk.document.0.cameras.len()]);
+        self
+    }
+}
+
+impl<'a> Iterator for Cameras<'a> {
+    type Item = (Camera<'a>, Node<'a>, [[f32; 4]; 4]);
+    fn next(&mut self) -> Option<Self::Item> {
+        for (node, transform) in self.walk.by_ref() {
+            let camera = match node.camera() {
+                Some(camera) => camera,
+                None => continue,
+            };
+            if let Some(seen) = self.seen.as_mut() {
+                if seen.get_mut(camera.index()).is_some_and(|x| std::mem::replace(x, true)) {
+                    continue;
+                }
+            }
+            return Some((camera, node, transform));
+        }
+        None
+    }
+}
+
+/// An `Iterator` that visits the lights of a scene together with the node
+/// referencing them and its world transform, as returned by
+/// [`Scene::lights`].
+///
+/// [`Scene::lights`]: ../struct.Scene.html#method.lights
+#[cfg(feature = "KHR_lights_punctual")]
+#[derive(Clone, Debug)]
+pub struct Lights<'a> {
+    /// The traversal of the scene.
+    pub(crate) walk: Walk<'a>,
+
+    /// Marks visited lights when only the first reference to each light is
+    /// visited.
+    pub(crate) seen: Option<Vec<bool>>,
+}
+
+#[cfg(feature = "KHR_lights_punctual")]
+impl<'a> Lights<'a> {
+    /// Visits each light only once, with the first node referencing it.
+    pub fn unique(mut self) -> Self {
+        let count = self.walk.document.lights().map_or(0, Iterator::count);
+        self.seen = Some(vec![false; count]);
+        self
+    }
+}
+
+#[cfg(feature = "KHR_lights_punctual")]
+impl<'a> Iterator for Lights<'a> {
+    type Item = (crate::khr_lights_punctual::Light<'a>, Node<'a>, [[f32; 4]; 4]);
+    fn next(&mut self) -> Option<Self::Item> {
+        for (node, transform) in self.walk.by_ref() {
+            let light = match node.light() {
+                Some(light) => light,
+                None => continue,
+            };
+            if let Some(seen) = self.seen.as_mut() {
+                if seen.get_mut(light.index()).is_some_and(|x| std::mem::replace(x, true)) {
+                    continue;
+                }
+            }
+            return Some((light, node, transform));
+        }
+        None
+    }
+}
+
 impl<'a> Iterator for Nodes<'a> {
     type Item = Node<'a>;
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter
-            .next()
-            .map(|index| self.document.nodes().nth(index.value()).unwrap())
+        let document = self.document;
+        self.iter.find_map(|index| document.get(*index))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.iter.size_hint()
+        (0, self.iter.size_hint().1)
     }
 }
 
-impl<'a> ExactSizeIterator for Children<'a> {}
 impl<'a> Iterator for Children<'a> {
     type Item = Node<'a>;
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter
-            .next()
-            .map(|index| self.document.nodes().nth(index.value()).unwrap())
+        let document = self.document;
+        self.iter.find_map(|index| document.get(*index))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.iter.size_hint()
+        (0, self.iter.size_hint().1)
     }
 }
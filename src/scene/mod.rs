@@ -1,9 +1,36 @@
 use crate::math::*;
+use crate::animation::{Animation, Channel};
 use crate::{Camera, Document, Mesh, Skin};
 
+#[cfg(feature = "utils")]
+use crate::Buffer;
+
 /// Iterators.
 pub mod iter;
 
+mod bounds;
+mod cache;
+
+pub use self::bounds::WorldBounds;
+pub use self::cache::TransformCache;
+
+/// Quaternions with a magnitude below this value are replaced with the identity
+/// rotation instead of being renormalized.
+pub const QUATERNION_EPSILON: f32 = 1.0e-6;
+
+/// Rescales a `[x, y, z, w]` quaternion to unit length.
+///
+/// Returns the identity rotation if the magnitude of the quaternion is below
+/// `QUATERNION_EPSILON`.
+pub(crate) fn renormalize(q: [f32; 4]) -> [f32; 4] {
+    let magnitude = (q[0] * q[0] + q[1] * q[1] + q[2] * q[2] + q[3] * q[3]).sqrt();
+    if magnitude < QUATERNION_EPSILON {
+        [0.0, 0.0, 0.0, 1.0]
+    } else {
+        [q[0] / magnitude, q[1] / magnitude, q[2] / magnitude, q[3] / magnitude]
+    }
+}
+
 /// The transform for a `Node`.
 #[derive(Clone, Debug)]
 pub enum Transform {
@@ -27,18 +54,54 @@ pub enum Transform {
 }
 
 impl Transform {
+    /// Returns the transform of a JSON node.
+    pub(crate) fn from_json(node: &json::scene::Node) -> Self {
+        if let Some(m) = node.matrix {
+            Transform::Matrix {
+                matrix: [
+                    [m[0], m[1], m[2], m[3]],
+                    [m[4], m[5], m[6], m[7]],
+                    [m[8], m[9], m[10], m[11]],
+                    [m[12], m[13], m[14], m[15]],
+                ],
+            }
+        } else {
+            Transform::Decomposed {
+                translation: node.translation
+                    .unwrap_or_else(|| [0.0, 0.0, 0.0]),
+                rotation: node.rotation
+                    .unwrap_or_else(json::scene::UnitQuaternion::default).0,
+                scale: node.scale
+                    .unwrap_or_else(|| [1.0, 1.0, 1.0]),
+            }
+        }
+    }
+
     /// Returns the matrix representation of this transform.
     ///
     /// If the transform is `Decomposed`, then the matrix is generated with the
-    /// equation `matrix = translation * rotation * scale`.
+    /// equation `matrix = translation * rotation * scale`. The rotation is
+    /// renormalized beforehand, see [`QUATERNION_EPSILON`].
+    ///
+    /// [`QUATERNION_EPSILON`]: constant.QUATERNION_EPSILON.html
     pub fn matrix(self) -> [[f32; 4]; 4] {
         match self {
             Transform::Matrix { matrix } => matrix,
             Transform::Decomposed { translation: t, rotation: r, scale: s } => {
+                let finite = t.iter().chain(&r).chain(&s).all(|x| x.is_finite());
+                let r = renormalize(r);
                 let t = Matrix4::from_translation(Vector3::new(t[0], t[1], t[2]));
                 let r = Matrix4::from_quaternion(Quaternion::new(r[3], r[0], r[1], r[2]));
                 let s = Matrix4::from_nonuniform_scale(s[0], s[1], s[2]);
-                (t * r * s).as_array()
+                let matrix = (t * r * s).as_array();
+                // Non-finite input is rejected by validation, so a non-finite
+                // result is a bug in the math above.
+                debug_assert!(
+                    !finite || matrix.iter().flatten().all(|x| x.is_finite()),
+                    "finite transform yields a non-finite matrix {:?}",
+                    matrix,
+                );
+                matrix
             },
         }
     }
@@ -46,7 +109,9 @@ impl Transform {
     /// Returns a decomposed representation of this transform.
     ///
     /// If the transform is `Matrix`, then the decomposition is extracted from the
-    /// matrix.
+    /// matrix. The rotation is always renormalized, see [`QUATERNION_EPSILON`].
+    ///
+    /// [`QUATERNION_EPSILON`]: constant.QUATERNION_EPSILON.html
     pub fn decomposed(self) -> ([f32; 3], [f32; 4], [f32; 3]) {
         match self {
             Transform::Matrix { matrix: m } => {
@@ -64,16 +129,164 @@ impl Transform {
                 i.y.multiply(1.0 / sy);
                 i.z.multiply(1.0 / sz);
                 let r = Quaternion::from_matrix(i);
-                let rotation = [r.v.x, r.v.y, r.v.z, r.s];
+                let rotation = renormalize([r.v.x, r.v.y, r.v.z, r.s]);
                 (translation, rotation, scale)
             },
             Transform::Decomposed { translation, rotation, scale } => {
-                (translation, rotation, scale)
+                (translation, renormalize(rotation), scale)
             },
         }
     }
 }
 
+/// Returns the inverse of a local transform, or `None` if it is singular.
+///
+/// Decomposed transforms are inverted as `scale⁻¹ * rotation⁻¹ * translation⁻¹`
+/// without a generic matrix inversion.
+fn inverse(transform: Transform) -> Option<Matrix4> {
+    match transform {
+        Transform::Matrix { matrix } => Matrix4::from_array(matrix).invert(),
+        Transform::Decomposed { translation: t, rotation: r, scale: s } => {
+            if s.contains(&0.0) {
+                return None;
+            }
+            let r = renormalize(r);
+            let t = Matrix4::from_translation(Vector3::new(-t[0], -t[1], -t[2]));
+            let r = Matrix4::from_quaternion(Quaternion::new(r[3], -r[0], -r[1], -r[2]));
+            let s = Matrix4::from_nonuniform_scale(1.0 / s[0], 1.0 / s[1], 1.0 / s[2]);
+            Some(s * r * t)
+        },
+    }
+}
+
+/// Replaces the `matrix` of every node whose translation, rotation, or scale
+/// is animated with its decomposition, since the specification forbids
+/// animating nodes defined by a matrix.
+///
+/// Returns the indices of the modified nodes in ascending order. Matrices
+/// without a finite decomposition are left unchanged.
+pub(crate) fn decompose_animated_matrices(root: &mut json::Root) -> Vec<usize> {
+    use json::animation::Property;
+    use json::validation::Checked;
+    let mut targets = root.animations
+        .iter()
+        .flat_map(|animation| animation.channels.iter())
+        .filter(|channel| matches!(
+            channel.target.path,
+            Checked::Valid(Property::Translation | Property::Rotation | Property::Scale)
+        ))
+        .filter_map(|channel| channel.target.node.map(|x| x.value()))
+        .collect::<Vec<_>>();
+    targets.sort_unstable();
+    targets.dedup();
+
+    let mut decomposed = Vec::new();
+    for index in targets {
+        let node = match root.nodes.get_mut(index) {
+            Some(node) if node.matrix.is_some() => node,
+            _ => continue,
+        };
+        if write_decomposed(node, Transform::from_json(node)) {
+            decomposed.push(index);
+        }
+    }
+    decomposed
+}
+
+/// Replaces the `matrix` or TRS properties of a node with the TRS properties
+/// of `transform`.
+///
+/// Returns `false`, leaving the node unchanged, if the decomposition of
+/// `transform` is not finite.
+fn write_decomposed(node: &mut json::scene::Node, transform: Transform) -> bool {
+    let (translation, rotation, scale) = transform.decomposed();
+    let finite = translation.iter()
+        .chain(rotation.iter())
+        .chain(scale.iter())
+        .all(|x| x.is_finite());
+    if finite {
+        node.matrix = None;
+        node.translation = Some(translation);
+        node.rotation = Some(json::scene::UnitQuaternion(rotation));
+        node.scale = Some(scale);
+    }
+    finite
+}
+
+/// Replaces the `matrix` or TRS properties of a node with the matrix of
+/// `transform`.
+fn write_matrix(node: &mut json::scene::Node, transform: Transform) {
+    let m = transform.matrix();
+    node.matrix = Some([
+        m[0][0], m[0][1], m[0][2], m[0][3],
+        m[1][0], m[1][1], m[1][2], m[1][3],
+        m[2][0], m[2][1], m[2][2], m[2][3],
+        m[3][0], m[3][1], m[3][2], m[3][3],
+    ]);
+    node.translation = None;
+    node.rotation = None;
+    node.scale = None;
+}
+
+/// Replaces the local transform of a node of `root`, keeping the
+/// representation of the node.
+///
+/// A node defined by a `matrix` receives the matrix of `transform`, and a
+/// node defined by TRS properties receives its decomposition, as by
+/// [`Transform::decomposed`]. Nodes targeted by an animation always receive
+/// TRS properties, since they cannot have a `matrix`. A matrix without a
+/// finite decomposition is written as is, or fails with
+/// `Error::AnimatedMatrix` for an animated node.
+///
+/// ```
+/// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// use gltf::json::{self, Index};
+/// use gltf::scene::{set_node_transform, Transform};
+///
+/// let mut root = json::Root::from_str(r#"{
+///     "asset": {"version": "2.0"},
+///     "nodes": [{"translation": [1.0, 0.0, 0.0]}]
+/// }"#)?;
+/// let mut matrix = [[0.0; 4]; 4];
+/// matrix[0][0] = 2.0;
+/// matrix[1][1] = 2.0;
+/// matrix[2][2] = 2.0;
+/// matrix[3] = [0.0, 3.0, 0.0, 1.0];
+/// set_node_transform(&mut root, Index::new(0), Transform::Matrix { matrix })?;
+/// assert!(root.nodes[0].matrix.is_none());
+/// assert_eq!(root.nodes[0].translation, Some([0.0, 3.0, 0.0]));
+/// assert_eq!(root.nodes[0].scale, Some([2.0, 2.0, 2.0]));
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #     run().expect("test failure");
+/// # }
+/// ```
+///
+/// [`Transform::decomposed`]: enum.Transform.html#method.decomposed
+pub fn set_node_transform(
+    root: &mut json::Root,
+    node: json::Index<json::scene::Node>,
+    transform: Transform,
+) -> Result<(), json::edit::Error> {
+    let animated = root.animations
+        .iter()
+        .flat_map(|animation| animation.channels.iter())
+        .any(|channel| channel.target.node.map(|x| x.value()) == Some(node.value()));
+    let json = root.nodes.get_mut(node.value()).ok_or_else(|| {
+        json::edit::Error::IndexOutOfBounds(json::Path::new().field("nodes").index(node.value()))
+    })?;
+    if json.matrix.is_some() && !animated {
+        write_matrix(json, transform);
+    } else if !write_decomposed(json, transform.clone()) {
+        if animated {
+            return Err(json::edit::Error::AnimatedMatrix);
+        }
+        write_matrix(json, transform);
+    }
+    Ok(())
+}
+
 /// A node in the node hierarchy.
 ///
 /// When a node contains a skin, all its meshes contain `JOINTS_0` and `WEIGHTS_0`
@@ -90,6 +303,8 @@ pub struct Node<'a> {
     json: &'a json::scene::Node,
 }
 
+impl_identity!(Node);
+
 /// The root nodes of a scene.
 #[derive(Clone, Debug)]
 pub struct Scene<'a> {
@@ -104,6 +319,8 @@ pub struct Scene<'a> {
     json: &'a json::scene::Scene,
 }
 
+impl_identity!(Scene);
+
 impl<'a> Node<'a> {
     /// Constructs a `Node`.
     pub(crate) fn new(
@@ -123,10 +340,58 @@ impl<'a> Node<'a> {
         self.index
     }
 
+    /// Returns an `Iterator` over the channels of every animation that target
+    /// this node, together with their animation.
+    ///
+    /// The channels are found through the index of
+    /// [`Animation::channels_for_node`], so the channels are not scanned.
+    ///
+    /// [`Animation::channels_for_node`]: ../animation/struct.Animation.html#method.channels_for_node
+    pub fn animations(&self) -> impl Iterator<Item = (Animation<'a>, Channel<'a>)> + 'a {
+        let node = self.clone();
+        self.document.animations().flat_map(move |animation| {
+            animation
+                .channels_for_node(&node)
+                .map(move |channel| (channel.animation(), channel))
+        })
+    }
+
+    /// Returns `true` if any animation targets this node.
+    pub fn is_animated(&self) -> bool {
+        self.animations().next().is_some()
+    }
+
+    /// Returns the bounding box of the primitives of this node and its
+    /// descendants, in the coordinate space of the node's parent.
+    ///
+    /// The eight corners of the `POSITION` accessor bounds of every primitive
+    /// are transformed, so the result may be larger than necessary when
+    /// rotations are involved. Returns `None` if no primitives are reachable.
+    pub fn bounding_box(&self) -> Option<WorldBounds> {
+        let identity = Matrix4::from_nonuniform_scale(1.0, 1.0, 1.0);
+        bounds::compute(self.document, vec![(self.index, identity)], Visibility::default(), no_positions)
+    }
+
+    /// Returns the bounding box of the primitives of this node and its
+    /// descendants, transforming each vertex individually.
+    ///
+    /// Primitives whose data is not returned by `get_buffer_data` fall back to
+    /// their accessor bounds, as with [`bounding_box`].
+    ///
+    /// [`bounding_box`]: #method.bounding_box
+    #[cfg(feature = "utils")]
+    pub fn bounding_box_with<'s, F>(&self, get_buffer_data: F) -> Option<WorldBounds>
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        let identity = Matrix4::from_nonuniform_scale(1.0, 1.0, 1.0);
+        bounds::compute(self.document, vec![(self.index, identity)], Visibility::default(), bounds::reader(get_buffer_data))
+    }
+
     /// Returns the camera referenced by this node.
     pub fn camera(&self) -> Option<Camera<'a>> {
-        self.json.camera.as_ref().map(|index| {
-            self.document.cameras().nth(index.value()).unwrap()
+        self.json.camera.as_ref().and_then(|index| {
+            self.document.get(*index)
         })
     }
 
@@ -138,66 +403,115 @@ impl<'a> Node<'a> {
         }
     }
 
+    /// Returns the XMP metadata packet of this node, as defined by the
+    /// `KHR_xmp_json_ld` extension.
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    pub fn xmp_packet(&self) -> Option<&'a json::extensions::root::khr_xmp_json_ld::Packet> {
+        self.document.xmp_packet(self.json.extensions.as_ref()?.khr_xmp_json_ld.as_ref()?)
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
     }
 
+    /// Returns `false` if the node and its descendants are hidden by the
+    /// `KHR_node_visibility` extension.
+    ///
+    /// Nodes without the extension are visible. The visibility of ancestors
+    /// is not taken into account; see [`Visibility`] for traversals that skip
+    /// hidden subtrees.
+    ///
+    /// [`Visibility`]: struct.Visibility.html
+    #[cfg(feature = "KHR_node_visibility")]
+    pub fn visible(&self) -> bool {
+        self.json.extensions
+            .as_ref()
+            .and_then(|extensions| extensions.khr_node_visibility.as_ref())
+            .is_none_or(|x| x.visible)
+    }
+
+    /// Returns the per-instance attributes of the mesh of this node, as
+    /// defined by the `EXT_mesh_gpu_instancing` extension, with their names,
+    /// such as `TRANSLATION`, in order of name.
+    ///
+    /// Attributes referring to missing accessors are skipped.
+    #[cfg(feature = "EXT_mesh_gpu_instancing")]
+    pub fn instance_attributes(&self) -> impl Iterator<Item = (&'a str, crate::Accessor<'a>)> + 'a {
+        let document = self.document;
+        self.json.extensions
+            .as_ref()
+            .and_then(|extensions| extensions.ext_mesh_gpu_instancing.as_ref())
+            .into_iter()
+            .flat_map(|x| x.attributes.iter())
+            .filter_map(move |(name, index)| Some((name.as_str(), document.get(*index)?)))
+    }
+
     /// Returns the light at this node as defined by the `KHR_lights_punctual` extension.
     #[cfg(feature = "KHR_lights_punctual")]
     pub fn light(&self) -> Option<crate::khr_lights_punctual::Light<'a>> {
-        if let Some(extensions) = self.json.extensions.as_ref() {
-            if let Some(khr_lights_punctual) = extensions.khr_lights_punctual.as_ref() {
-                let mut lights = self.document.lights().unwrap();
-                Some(lights.nth(khr_lights_punctual.light.value()).unwrap())
-            } else {
-                None
-            }
-        } else {
-            None
-        }
+        self.json.extensions
+            .as_ref()
+            .and_then(|extensions| extensions.khr_lights_punctual.as_ref())
+            .and_then(|khr_lights_punctual| self.document.get(khr_lights_punctual.light))
     }
 
     /// Returns the mesh referenced by this node.
     pub fn mesh(&self) -> Option<Mesh<'a>> {
-        self.json.mesh.as_ref().map(|index| {
-            self.document.meshes().nth(index.value()).unwrap()
+        self.json.mesh.as_ref().and_then(|index| {
+            self.document.get(*index)
         })
     }
 
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     pub fn name(&self) -> Option<&'a str> {
-        self.json.name.as_ref().map(String::as_str)
+        self.json.name.as_deref()
     }
 
     /// Returns the node's transform.
     pub fn transform(&self) -> Transform {
-        if let Some(m) = self.json.matrix {
-            Transform::Matrix {
-                matrix: [
-                    [m[0], m[1], m[2], m[3]],
-                    [m[4], m[5], m[6], m[7]],
-                    [m[8], m[9], m[10], m[11]],
-                    [m[12], m[13], m[14], m[15]],
-                ],
+        Transform::from_json(self.json)
+    }
+
+    /// Returns the inverse of the node's world transform in the given scene,
+    /// which is the view matrix of a camera attached to the node.
+    ///
+    /// Returns `None` if the node is not part of the scene or if the world
+    /// transform is singular.
+    pub fn view_matrix(&self, scene: &Scene) -> Option<[[f32; 4]; 4]> {
+        let nodes = &self.document.0.nodes;
+        let mut parents = vec![None; nodes.len()];
+        for (index, node) in nodes.iter().enumerate() {
+            for child in node.children.iter().flatten() {
+                if let Some(parent) = parents.get_mut(child.value()) {
+                    *parent = Some(index);
+                }
             }
-        } else {
-            Transform::Decomposed {
-                translation: self.json.translation
-                    .unwrap_or_else(|| [0.0, 0.0, 0.0]),
-                rotation: self.json.rotation
-                    .unwrap_or_else(json::scene::UnitQuaternion::default).0,
-                scale: self.json.scale
-                    .unwrap_or_else(|| [1.0, 1.0, 1.0]),
+        }
+
+        // Walk up the hierarchy, bounded by the number of nodes in case the
+        // hierarchy contains a cycle.
+        let mut view = Matrix4::from_nonuniform_scale(1.0, 1.0, 1.0);
+        let mut index = self.index;
+        for _ in 0..nodes.len() {
+            let node = Node::new(self.document, index, &nodes[index]);
+            view = view * inverse(node.transform())?;
+            match parents[index] {
+                Some(parent) => index = parent,
+                None => {
+                    let is_root = scene.json.nodes.iter().any(|x| x.value() == index);
+                    return if is_root { Some(view.as_array()) } else { None };
+                },
             }
         }
+        None
     }
 
     /// Returns the skin referenced by this node.
     pub fn skin(&self) -> Option<Skin<'a>> {
-        self.json.skin.as_ref().map(|index| {
-            self.document.skins().nth(index.value()).unwrap()
+        self.json.skin.as_ref().and_then(|index| {
+            self.document.get(*index)
         })
     }
 
@@ -226,6 +540,13 @@ impl<'a> Scene<'a> {
         self.index
     }
 
+    /// Returns the XMP metadata packet of this scene, as defined by the
+    /// `KHR_xmp_json_ld` extension.
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    pub fn xmp_packet(&self) -> Option<&'a json::extensions::root::khr_xmp_json_ld::Packet> {
+        self.document.xmp_packet(self.json.extensions.as_ref()?.khr_xmp_json_ld.as_ref()?)
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras{
         &self.json.extras
@@ -234,7 +555,195 @@ impl<'a> Scene<'a> {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     pub fn name(&self) -> Option<&'a str> {
-        self.json.name.as_ref().map(String::as_str)
+        self.json.name.as_deref()
+    }
+
+    /// Returns the world-space bounding box of the primitives of the scene.
+    ///
+    /// The eight corners of the `POSITION` accessor bounds of every primitive
+    /// are transformed by the world transform of the referencing node, so the
+    /// result may be larger than necessary when rotations are involved.
+    /// Returns `None` if the scene contains no primitives.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// let gltf = gltf::Gltf::open("examples/Box.gltf")?;
+    /// let scene = gltf.default_scene().unwrap();
+    /// let bounds = scene.bounding_box().unwrap();
+    /// assert!(!bounds.approximate);
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    pub fn bounding_box(&self) -> Option<WorldBounds> {
+        self.visible_bounding_box(Visibility::default())
+    }
+
+    /// Returns the world-space bounding box of the primitives of the scene,
+    /// as [`bounding_box`] does, without the nodes hidden by `visibility`
+    /// and their descendants.
+    ///
+    /// [`bounding_box`]: #method.bounding_box
+    pub fn visible_bounding_box(&self, visibility: Visibility) -> Option<WorldBounds> {
+        bounds::compute(self.document, self.roots(), visibility, no_positions)
+    }
+
+    /// Returns the world-space bounding box of the primitives of the scene,
+    /// transforming each vertex individually for a tighter fit.
+    ///
+    /// Primitives whose data is not returned by `get_buffer_data` fall back to
+    /// their accessor bounds, as with [`bounding_box`].
+    ///
+    /// [`bounding_box`]: #method.bounding_box
+    #[cfg(feature = "utils")]
+    pub fn bounding_box_with<'s, F>(&self, get_buffer_data: F) -> Option<WorldBounds>
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        self.visible_bounding_box_with(get_buffer_data, Visibility::default())
+    }
+
+    /// Returns the world-space bounding box of the primitives of the scene,
+    /// as [`bounding_box_with`] does, without the nodes hidden by
+    /// `visibility` and their descendants.
+    ///
+    /// [`bounding_box_with`]: #method.bounding_box_with
+    #[cfg(feature = "utils")]
+    pub fn visible_bounding_box_with<'s, F>(&self, get_buffer_data: F, visibility: Visibility) -> Option<WorldBounds>
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        bounds::compute(self.document, self.roots(), visibility, bounds::reader(get_buffer_data))
+    }
+
+    /// Returns the root nodes of the scene paired with the identity transform.
+    fn roots(&self) -> Vec<(usize, Matrix4)> {
+        let identity = Matrix4::from_nonuniform_scale(1.0, 1.0, 1.0);
+        self.json.nodes.iter().map(|x| (x.value(), identity)).collect()
+    }
+
+    /// Returns an `Iterator` that visits every primitive of every node
+    /// reachable from the root nodes of the scene, in depth-first order.
+    ///
+    /// Each primitive is paired with the world transform of the node that
+    /// references its mesh, so a mesh instantiated by several nodes is
+    /// visited once per node.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// let gltf = gltf::Gltf::open("examples/Box.gltf")?;
+    /// let scene = gltf.default_scene().unwrap();
+    /// for (primitive, transform) in scene.primitives() {
+    ///     println!("mesh {} at {:?}", primitive.mesh().index(), transform[3]);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    pub fn primitives(&self) -> iter::Primitives<'a> {
+        self.visible_primitives(Visibility::default())
+    }
+
+    /// Returns an `Iterator` that visits the primitives of the scene, as
+    /// [`primitives`] does, without the nodes hidden by `visibility` and
+    /// their descendants.
+    ///
+    /// [`primitives`]: #method.primitives
+    pub fn visible_primitives(&self, visibility: Visibility) -> iter::Primitives<'a> {
+        let mut stack = self.roots();
+        stack.reverse();
+        iter::Primitives {
+            document: self.document,
+            stack,
+            visited: vec![false; self.document.0.nodes.len()],
+            visibility,
+            current: None,
+            meshes: None,
+        }
+    }
+
+    /// Returns an `Iterator` that visits every node reachable from the root
+    /// nodes of the scene in depth-first order, paired with its world
+    /// transform.
+    ///
+    /// The nodes hidden by `visibility` are skipped together with their
+    /// descendants; `Visibility::default()` visits every node.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// use gltf::scene::Visibility;
+    ///
+    /// let gltf = gltf::Gltf::open("examples/Box.gltf")?;
+    /// let scene = gltf.default_scene().unwrap();
+    /// for (node, transform) in scene.walk(Visibility::default()) {
+    ///     println!("node {} at {:?}", node.index(), transform[3]);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    pub fn walk(&self, visibility: Visibility) -> iter::Walk<'a> {
+        let mut stack = self.roots();
+        stack.reverse();
+        iter::Walk {
+            document: self.document,
+            stack,
+            visited: vec![false; self.document.0.nodes.len()],
+            visibility,
+        }
+    }
+
+    /// Returns an `Iterator` that visits the cameras of the scene in the order
+    /// of [`walk`], together with the node referencing each camera and its
+    /// world transform.
+    ///
+    /// A camera referenced by several nodes is visited once per node, unless
+    /// [`Cameras::unique`] is called.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// let gltf = gltf::Gltf::open("examples/Box.gltf")?;
+    /// let scene = gltf.default_scene().unwrap();
+    /// for (camera, node, transform) in scene.cameras().unique() {
+    ///     println!("camera {} of node {} at {:?}", camera.index(), node.index(), transform[3]);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    ///
+    /// [`walk`]: #method.walk
+    /// [`Cameras::unique`]: iter/struct.Cameras.html#method.unique
+    pub fn cameras(&self) -> iter::Cameras<'a> {
+        iter::Cameras {
+            walk: self.walk(Visibility::default()),
+            seen: None,
+        }
+    }
+
+    /// Returns an `Iterator` that visits the lights of the scene in the order
+    /// of [`walk`], together with the node referencing each light and its
+    /// world transform.
+    ///
+    /// A light referenced by several nodes is visited once per node, unless
+    /// [`Lights::unique`] is called.
+    ///
+    /// [`walk`]: #method.walk
+    /// [`Lights::unique`]: iter/struct.Lights.html#method.unique
+    #[cfg(feature = "KHR_lights_punctual")]
+    pub fn lights(&self) -> iter::Lights<'a> {
+        iter::Lights {
+            walk: self.walk(Visibility::default()),
+            seen: None,
+        }
     }
 
     /// Returns an `Iterator` that visits each root node of the scene.
@@ -246,6 +755,238 @@ impl<'a> Scene<'a> {
     }
 }
 
+/// The hidden nodes skipped by the traversals of a scene, such as
+/// [`Scene::walk`].
+///
+/// A hidden node is skipped together with all of its descendants. The default
+/// skips nothing.
+///
+/// [`Scene::walk`]: struct.Scene.html#method.walk
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Visibility {
+    /// Skip the nodes hidden by the `KHR_node_visibility` extension.
+    ///
+    /// Has no effect unless the `KHR_node_visibility` feature is enabled.
+    pub extension: bool,
+
+    /// Skip the nodes whose extras contain `"visible": false`, the convention
+    /// of exporters that predate `KHR_node_visibility`.
+    ///
+    /// Has no effect unless the `extras` feature is enabled.
+    pub extras: bool,
+}
+
+impl Visibility {
+    /// Skips the nodes hidden by either the extension or their extras.
+    pub const ALL_HIDDEN: Visibility = Visibility { extension: true, extras: true };
+
+    /// Returns `true` if `node` is hidden.
+    ///
+    /// Only the node itself is checked; a node whose ancestor is hidden is
+    /// skipped by the traversals but may not be hidden itself.
+    #[allow(unused_variables)]
+    pub fn hides(&self, node: &Node) -> bool {
+        #[cfg(feature = "KHR_node_visibility")]
+        {
+            if self.extension && !node.visible() {
+                return true;
+            }
+        }
+        #[cfg(feature = "extras")]
+        {
+            if self.extras {
+                let extras = node.extras().as_ref()
+                    .and_then(|raw| json::deserialize::from_str::<json::Value>(raw.get()).ok());
+                if extras.is_some_and(|x| x.get("visible") == Some(&json::Value::Bool(false))) {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+}
+
+/// The nodes of a scene that instantiate one mesh, as returned by
+/// [`Document::mesh_instances`].
+///
+/// [`Document::mesh_instances`]: ../struct.Document.html#method.mesh_instances
+#[derive(Clone, Debug)]
+pub struct MeshInstances<'a> {
+    /// The instantiated mesh.
+    pub mesh: Mesh<'a>,
+
+    /// The nodes referencing the mesh in depth-first order, paired with their
+    /// world transform.
+    pub instances: Vec<(Node<'a>, [[f32; 4]; 4])>,
+
+    /// Whether any of the nodes is skinned, in which case its world transform
+    /// is ignored and the mesh is deformed by its joints instead.
+    pub skinned: bool,
+
+    /// Whether any of the nodes or their ancestors is targeted by an
+    /// animation, so that the world transforms or morph weights change.
+    pub animated: bool,
+}
+
+impl<'a> MeshInstances<'a> {
+    /// Returns `true` if the mesh can be drawn with one instanced draw call
+    /// per primitive, that is if no node is skinned or animated.
+    pub fn is_static(&self) -> bool {
+        !self.skinned && !self.animated
+    }
+}
+
+/// What the document-wide traversals of [`Document`] visit when the document
+/// has no default scene, for example because it is a library of meshes and
+/// materials.
+///
+/// [`Document`]: ../struct.Document.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SceneFallback {
+    /// Visit nothing.
+    Nothing,
+
+    /// Visit every mesh of the document once, with the identity transform.
+    AllMeshes,
+}
+
+impl Document {
+    /// Returns an `Iterator` that visits every primitive of the default scene
+    /// together with its world transform, as [`Scene::primitives`] does.
+    ///
+    /// Without a default scene, `fallback` decides whether nothing or every
+    /// mesh of the document is visited.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// use gltf::scene::SceneFallback;
+    ///
+    /// // A library of two meshes without nodes or scenes.
+    /// let gltf = gltf::Gltf::open("tests/library.gltf")?;
+    /// assert!(gltf.default_scene().is_none());
+    /// assert_eq!(gltf.scene_primitives(SceneFallback::Nothing).count(), 0);
+    /// assert_eq!(gltf.scene_primitives(SceneFallback::AllMeshes).count(), 2);
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    ///
+    /// [`Scene::primitives`]: scene/struct.Scene.html#method.primitives
+    pub fn scene_primitives(&self, fallback: SceneFallback) -> iter::Primitives<'_> {
+        match self.default_scene() {
+            Some(scene) => scene.primitives(),
+            None => iter::Primitives {
+                document: self,
+                stack: Vec::new(),
+                visited: Vec::new(),
+                visibility: Visibility::default(),
+                current: None,
+                meshes: match fallback {
+                    SceneFallback::Nothing => None,
+                    SceneFallback::AllMeshes => Some(self.meshes()),
+                },
+            },
+        }
+    }
+
+    /// Returns the meshes instantiated by the nodes of `scene`, in order of
+    /// their index, each with the nodes referencing it and their world
+    /// transforms.
+    ///
+    /// The scene is traversed once, as [`Scene::walk`] does, and animated
+    /// nodes are found through the index of [`Node::animations`]. Meshes not
+    /// referenced by the scene are omitted.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// let gltf = gltf::Gltf::open("examples/Box.gltf")?;
+    /// let scene = gltf.default_scene().unwrap();
+    /// for group in gltf.mesh_instances(&scene) {
+    ///     if group.instances.len() > 1 && group.is_static() {
+    ///         println!("mesh {} can be instanced", group.mesh.index());
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    ///
+    /// [`Scene::walk`]: scene/struct.Scene.html#method.walk
+    /// [`Node::animations`]: scene/struct.Node.html#method.animations
+    pub fn mesh_instances<'a>(&'a self, scene: &Scene<'a>) -> Vec<MeshInstances<'a>> {
+        let mut groups: Vec<Option<MeshInstances<'a>>> = vec![None; self.0.meshes.len()];
+        // Whether an ancestor of each node is animated, set before the node
+        // is visited since the traversal is depth-first.
+        let mut inherited = vec![false; self.0.nodes.len()];
+        for (node, transform) in scene.walk(Visibility::default()) {
+            let animated = inherited[node.index()] || node.is_animated();
+            for child in node.json.children.iter().flatten() {
+                if let Some(x) = inherited.get_mut(child.value()) {
+                    *x |= animated;
+                }
+            }
+            let mesh = match node.mesh() {
+                Some(mesh) => mesh,
+                None => continue,
+            };
+            let skinned = node.skin().is_some();
+            let group = groups[mesh.index()].get_or_insert_with(|| MeshInstances {
+                mesh,
+                instances: Vec::new(),
+                skinned: false,
+                animated: false,
+            });
+            group.skinned |= skinned;
+            group.animated |= animated;
+            group.instances.push((node, transform));
+        }
+        groups.into_iter().flatten().collect()
+    }
+
+    /// Returns the world-space bounding box of the default scene, as
+    /// [`Scene::bounding_box`] does.
+    ///
+    /// Without a default scene, `fallback` decides whether `None` or the
+    /// bounding box of every mesh in its local space is returned.
+    ///
+    /// [`Scene::bounding_box`]: scene/struct.Scene.html#method.bounding_box
+    pub fn scene_bounding_box(&self, fallback: SceneFallback) -> Option<WorldBounds> {
+        match (self.default_scene(), fallback) {
+            (Some(scene), _) => scene.bounding_box(),
+            (None, SceneFallback::Nothing) => None,
+            (None, SceneFallback::AllMeshes) => bounds::compute_meshes(self, no_positions),
+        }
+    }
+
+    /// Returns the world-space bounding box of the default scene with each
+    /// vertex transformed individually, as [`Scene::bounding_box_with`] does.
+    ///
+    /// Without a default scene, `fallback` decides whether `None` or the
+    /// bounding box of every mesh in its local space is returned.
+    ///
+    /// [`Scene::bounding_box_with`]: scene/struct.Scene.html#method.bounding_box_with
+    #[cfg(feature = "utils")]
+    pub fn scene_bounding_box_with<'a, 's, F>(&'a self, get_buffer_data: F, fallback: SceneFallback) -> Option<WorldBounds>
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        match (self.default_scene(), fallback) {
+            (Some(scene), _) => scene.bounding_box_with(get_buffer_data),
+            (None, SceneFallback::Nothing) => None,
+            (None, SceneFallback::AllMeshes) => bounds::compute_meshes(self, bounds::reader(get_buffer_data)),
+        }
+    }
+}
+
+/// Positions source for bounds computed from accessor bounds only.
+fn no_positions(_: &crate::mesh::Primitive) -> Option<std::iter::Empty<[f32; 3]>> {
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use crate::scene::Transform;
@@ -581,4 +1322,96 @@ mod tests {
         let scale = [10.0, 0.1, -0.1];
         test_decompose_scale(scale);
     }
+
+    /// Deterministic pseudo-random numbers in the range `[-1, 1)`.
+    fn random(state: &mut u32) -> f32 {
+        *state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+        (*state >> 8) as f32 / (1 << 23) as f32 - 1.0
+    }
+
+    fn magnitude(q: [f32; 4]) -> f32 {
+        (q[0] * q[0] + q[1] * q[1] + q[2] * q[2] + q[3] * q[3]).sqrt()
+    }
+
+    #[test]
+    fn renormalize_perturbed_rotations() {
+        let mut state = 1;
+        for _ in 0..1000 {
+            let axis = [random(&mut state), random(&mut state), random(&mut state)];
+            let rotation = rotate(axis[0], axis[1], axis[2], PI * random(&mut state));
+            let factor = 1.0 + 0.01 * random(&mut state);
+            let perturbed = [
+                rotation[0] * factor + 1.0e-4 * random(&mut state),
+                rotation[1] * factor + 1.0e-4 * random(&mut state),
+                rotation[2] * factor + 1.0e-4 * random(&mut state),
+                rotation[3] * factor + 1.0e-4 * random(&mut state),
+            ];
+            let transform = Transform::Decomposed {
+                translation: [0.0, 0.0, 0.0],
+                rotation: perturbed,
+                scale: [1.0, 1.0, 1.0],
+            };
+            let (_, output, _) = transform.clone().decomposed();
+            assert_relative_eq!(magnitude(output), 1.0, epsilon = 1.0e-6);
+            for i in 0..4 {
+                assert_relative_eq!(output[i], rotation[i], epsilon = 1.0e-3);
+            }
+            let matrix = Matrix4::from_array(transform.matrix());
+            let expected = Matrix4::from_quaternion(Quaternion::new(
+                rotation[3], rotation[0], rotation[1], rotation[2],
+            ));
+            assert_relative_eq!(matrix, expected, epsilon = 1.0e-3);
+        }
+    }
+
+    #[test]
+    fn renormalize_zero_rotation() {
+        let transform = Transform::Decomposed {
+            translation: [0.0, 0.0, 0.0],
+            rotation: [0.0, 0.0, 0.0, 0.0],
+            scale: [1.0, 1.0, 1.0],
+        };
+        let (_, rotation, _) = transform.decomposed();
+        assert_eq!(rotation, [0.0, 0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn view_matrix() {
+        let json = r#"{
+            "asset": {"version": "2.0"},
+            "cameras": [{"type": "orthographic", "orthographic": {
+                "xmag": 1.0, "ymag": 1.0, "zfar": 10.0, "znear": 0.1
+            }}],
+            "nodes": [
+                {"children": [1], "matrix": [
+                    0.0, 2.0, 0.0, 0.0,
+                    -2.0, 0.0, 0.0, 0.0,
+                    0.0, 0.0, 2.0, 0.0,
+                    1.0, 2.0, 3.0, 1.0
+                ]},
+                {"camera": 0, "translation": [0.0, 0.0, 5.0],
+                 "rotation": [0.0, 0.7071068, 0.0, 0.7071068], "scale": [1.0, 3.0, 1.0]},
+                {"camera": 0}
+            ],
+            "scenes": [{"nodes": [0]}]
+        }"#;
+        let gltf = crate::Gltf::from_slice(json.as_bytes()).unwrap();
+        let scene = gltf.scenes().next().unwrap();
+        let camera = gltf.cameras().next().unwrap();
+        let nodes = camera.reference_nodes().collect::<Vec<_>>();
+        assert_eq!(nodes.iter().map(|node| node.index()).collect::<Vec<_>>(), vec![1, 2]);
+
+        let parent = Matrix4::from_array(gltf.nodes().next().unwrap().transform().matrix());
+        let world = parent * Matrix4::from_array(nodes[0].transform().matrix());
+        let view = Matrix4::from_array(nodes[0].view_matrix(&scene).unwrap());
+        assert_relative_eq!(
+            view * world,
+            Matrix4::from_nonuniform_scale(1.0, 1.0, 1.0),
+            epsilon = 1.0e-5
+        );
+        assert_relative_eq!(view, world.invert().unwrap(), epsilon = 1.0e-5);
+
+        // The second node is not part of the scene.
+        assert!(nodes[1].view_matrix(&scene).is_none());
+    }
 }
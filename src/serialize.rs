@@ -0,0 +1,643 @@
+//! `serde::Serialize` implementations for the wrapper types.
+//!
+//! The wrappers serialize the values returned by their accessors rather than
+//! the raw JSON, so defaults are applied, the default material and sampler
+//! are resolved, and node transforms are decomposed. The format is:
+//!
+//! * Objects are maps whose keys are the names of the corresponding wrapper
+//!   functions, for example `alpha_cutoff` or `wrap_s`.
+//! * References to other top-level objects are serialized as their index.
+//!   The default material and the default sampler have a `null` index.
+//! * Enumerations are serialized as the name of their variant, for example
+//!   `"Opaque"` or `"ClampToEdge"`.
+//! * Primitive attributes are maps from the glTF attribute name, for example
+//!   `"TEXCOORD_0"`, to an accessor index, sorted by name.
+//! * Camera projections and image sources are maps with a `type` key.
+//!
+//! Names are only included if the `names` feature is enabled. Extras and
+//! extensions other than `KHR_materials_unlit` are not included.
+
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::{accessor, animation, buffer, camera, draw, image, material, mesh, requirements, scene, skin, texture, validation};
+use crate::Document;
+
+/// Serializes an enumeration as the name of its variant.
+struct Variant<T>(T);
+
+impl<T: fmt::Debug> Serialize for Variant<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{:?}", self.0))
+    }
+}
+
+/// Serializes every top-level object of a document.
+pub(crate) struct Snapshot<'a>(pub &'a Document);
+
+impl<'a> Serialize for Snapshot<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let document = self.0;
+        let mut s = serializer.serialize_struct("Document", 14)?;
+        s.serialize_field(
+            "default_scene",
+            &document.default_scene().map(|x| x.index()),
+        )?;
+        s.serialize_field("accessors", &document.accessors().collect::<Vec<_>>())?;
+        s.serialize_field("animations", &document.animations().collect::<Vec<_>>())?;
+        s.serialize_field("buffers", &document.buffers().collect::<Vec<_>>())?;
+        s.serialize_field("views", &document.views().collect::<Vec<_>>())?;
+        s.serialize_field("cameras", &document.cameras().collect::<Vec<_>>())?;
+        s.serialize_field("images", &document.images().collect::<Vec<_>>())?;
+        s.serialize_field("materials", &document.materials().collect::<Vec<_>>())?;
+        s.serialize_field("meshes", &document.meshes().collect::<Vec<_>>())?;
+        s.serialize_field("nodes", &document.nodes().collect::<Vec<_>>())?;
+        s.serialize_field("samplers", &document.samplers().collect::<Vec<_>>())?;
+        s.serialize_field("scenes", &document.scenes().collect::<Vec<_>>())?;
+        s.serialize_field("skins", &document.skins().collect::<Vec<_>>())?;
+        s.serialize_field("textures", &document.textures().collect::<Vec<_>>())?;
+        s.end()
+    }
+}
+
+impl<'a> Serialize for accessor::Accessor<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Accessor", 10)?;
+        s.serialize_field("index", &self.index())?;
+        #[cfg(feature = "names")]
+        s.serialize_field("name", &self.name())?;
+        s.serialize_field("view", &self.view().map(|x| x.index()))?;
+        s.serialize_field("offset", &self.offset())?;
+        s.serialize_field("count", &self.count())?;
+        s.serialize_field("data_type", &self.data_type().map(Variant))?;
+        s.serialize_field("dimensions", &self.dimensions().map(Variant))?;
+        s.serialize_field("normalized", &self.normalized())?;
+        s.serialize_field("min", &self.min())?;
+        s.serialize_field("max", &self.max())?;
+        s.serialize_field("sparse", &self.sparse().is_some())?;
+        s.end()
+    }
+}
+
+impl<'a> Serialize for animation::Animation<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Animation", 3)?;
+        s.serialize_field("index", &self.index())?;
+        #[cfg(feature = "names")]
+        s.serialize_field("name", &self.name())?;
+        s.serialize_field("channels", &self.channels().collect::<Vec<_>>())?;
+        s.end()
+    }
+}
+
+impl<'a> Serialize for animation::Channel<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Channel", 2)?;
+        s.serialize_field("sampler", &self.sampler())?;
+        s.serialize_field("target", &self.target())?;
+        s.end()
+    }
+}
+
+impl<'a> Serialize for animation::Sampler<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Sampler", 3)?;
+        s.serialize_field("input", &self.input().map(|x| x.index()))?;
+        s.serialize_field("output", &self.output().map(|x| x.index()))?;
+        s.serialize_field("interpolation", &Variant(self.interpolation()))?;
+        s.end()
+    }
+}
+
+impl<'a> Serialize for animation::Target<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Target", 2)?;
+        #[cfg(feature = "KHR_animation_pointer")]
+        {
+            if let Some(animation::TargetKind::Pointer(pointer)) = self.kind() {
+                s.serialize_field("pointer", pointer)?;
+                return s.end();
+            }
+        }
+        s.serialize_field("node", &self.node().map(|x| x.index()))?;
+        s.serialize_field("property", &self.property().map(Variant))?;
+        s.end()
+    }
+}
+
+impl<'a> Serialize for buffer::Buffer<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Buffer", 4)?;
+        s.serialize_field("index", &self.index())?;
+        #[cfg(feature = "names")]
+        s.serialize_field("name", &self.name())?;
+        s.serialize_field("length", &self.length())?;
+        match self.source() {
+            buffer::Source::Bin => s.serialize_field("uri", &None::<&str>)?,
+            buffer::Source::Uri(uri) => s.serialize_field("uri", uri)?,
+        }
+        s.end()
+    }
+}
+
+impl<'a> Serialize for buffer::View<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("View", 7)?;
+        s.serialize_field("index", &self.index())?;
+        #[cfg(feature = "names")]
+        s.serialize_field("name", &self.name())?;
+        s.serialize_field("buffer", &self.buffer().map(|x| x.index()))?;
+        s.serialize_field("offset", &self.offset())?;
+        s.serialize_field("length", &self.length())?;
+        s.serialize_field("stride", &self.stride())?;
+        s.serialize_field("target", &self.target().map(Variant))?;
+        s.end()
+    }
+}
+
+impl<'a> Serialize for camera::Camera<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Camera", 3)?;
+        s.serialize_field("index", &self.index())?;
+        #[cfg(feature = "names")]
+        s.serialize_field("name", &self.name())?;
+        s.serialize_field("projection", &self.projection())?;
+        s.end()
+    }
+}
+
+impl<'a> Serialize for camera::Projection<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Projection", 5)?;
+        match *self {
+            camera::Projection::Orthographic(ref x) => {
+                s.serialize_field("type", "orthographic")?;
+                s.serialize_field("xmag", &x.xmag())?;
+                s.serialize_field("ymag", &x.ymag())?;
+                s.serialize_field("zfar", &x.zfar())?;
+                s.serialize_field("znear", &x.znear())?;
+            },
+            camera::Projection::Perspective(ref x) => {
+                s.serialize_field("type", "perspective")?;
+                s.serialize_field("aspect_ratio", &x.aspect_ratio())?;
+                s.serialize_field("yfov", &x.yfov())?;
+                s.serialize_field("zfar", &x.zfar())?;
+                s.serialize_field("znear", &x.znear())?;
+            },
+        }
+        s.end()
+    }
+}
+
+impl Serialize for draw::MaterialUsage {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("MaterialUsage", 5)?;
+        s.serialize_field("material", &self.material)?;
+        s.serialize_field("primitives", &self.primitives)?;
+        s.serialize_field("triangles", &self.triangles)?;
+        s.serialize_field("alpha_mode", &Variant(self.alpha_mode))?;
+        s.serialize_field("textures", &self.textures)?;
+        s.end()
+    }
+}
+
+impl Serialize for draw::MeshUsage {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("MeshUsage", 4)?;
+        s.serialize_field("mesh", &self.mesh)?;
+        s.serialize_field("instances", &self.instances)?;
+        s.serialize_field("triangles_per_instance", &self.triangles_per_instance)?;
+        s.serialize_field("triangles", &self.triangles)?;
+        s.end()
+    }
+}
+
+impl Serialize for draw::Report {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Report", 2)?;
+        s.serialize_field("by_material", &self.by_material)?;
+        s.serialize_field("by_mesh", &self.by_mesh)?;
+        s.end()
+    }
+}
+
+impl<'a> Serialize for image::Image<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Image", 3)?;
+        s.serialize_field("index", &self.index())?;
+        #[cfg(feature = "names")]
+        s.serialize_field("name", &self.name())?;
+        s.serialize_field("source", &self.source())?;
+        s.end()
+    }
+}
+
+impl<'a> Serialize for image::Source<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Source", 3)?;
+        match *self {
+            image::Source::View { ref view, mime_type } => {
+                s.serialize_field("type", "view")?;
+                s.serialize_field("view", &view.index())?;
+                s.serialize_field("mime_type", mime_type)?;
+            },
+            image::Source::Uri { uri, mime_type } => {
+                s.serialize_field("type", "uri")?;
+                s.serialize_field("uri", uri)?;
+                s.serialize_field("mime_type", &mime_type)?;
+            },
+        }
+        s.end()
+    }
+}
+
+impl<'a> Serialize for material::Material<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Material", 11)?;
+        s.serialize_field("index", &self.index())?;
+        #[cfg(feature = "names")]
+        s.serialize_field("name", &self.name())?;
+        s.serialize_field("alpha_mode", &Variant(self.alpha_mode()))?;
+        s.serialize_field("alpha_cutoff", &self.alpha_cutoff())?;
+        s.serialize_field("double_sided", &self.double_sided())?;
+        s.serialize_field("pbr_metallic_roughness", &self.pbr_metallic_roughness())?;
+        s.serialize_field("normal_texture", &self.normal_texture())?;
+        s.serialize_field("occlusion_texture", &self.occlusion_texture())?;
+        s.serialize_field("emissive_texture", &self.emissive_texture())?;
+        s.serialize_field("emissive_factor", &self.emissive_factor())?;
+        #[cfg(feature = "KHR_materials_unlit")]
+        s.serialize_field("unlit", &self.unlit())?;
+        s.end()
+    }
+}
+
+impl<'a> Serialize for material::PbrMetallicRoughness<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("PbrMetallicRoughness", 5)?;
+        s.serialize_field("base_color_factor", &self.base_color_factor())?;
+        s.serialize_field("base_color_texture", &self.base_color_texture())?;
+        s.serialize_field("metallic_factor", &self.metallic_factor())?;
+        s.serialize_field("roughness_factor", &self.roughness_factor())?;
+        s.serialize_field("metallic_roughness_texture", &self.metallic_roughness_texture())?;
+        s.end()
+    }
+}
+
+impl<'a> Serialize for material::NormalTexture<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("NormalTexture", 3)?;
+        s.serialize_field("texture", &self.texture().index())?;
+        s.serialize_field("tex_coord", &self.tex_coord())?;
+        s.serialize_field("scale", &self.scale())?;
+        s.end()
+    }
+}
+
+impl<'a> Serialize for material::OcclusionTexture<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("OcclusionTexture", 3)?;
+        s.serialize_field("texture", &self.texture().index())?;
+        s.serialize_field("tex_coord", &self.tex_coord())?;
+        s.serialize_field("strength", &self.strength())?;
+        s.end()
+    }
+}
+
+impl Serialize for material::NormalMapping {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("NormalMapping", 5)?;
+        s.serialize_field("texture", &self.texture)?;
+        s.serialize_field("image", &self.image)?;
+        s.serialize_field("tex_coord", &self.tex_coord)?;
+        s.serialize_field("transform", &self.transform)?;
+        s.serialize_field("scale", &self.scale)?;
+        s.end()
+    }
+}
+
+impl Serialize for material::OcclusionParams {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("OcclusionParams", 5)?;
+        s.serialize_field("texture", &self.texture)?;
+        s.serialize_field("image", &self.image)?;
+        s.serialize_field("tex_coord", &self.tex_coord)?;
+        s.serialize_field("transform", &self.transform)?;
+        s.serialize_field("strength", &self.strength)?;
+        s.end()
+    }
+}
+
+impl Serialize for material::EmissiveParams {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("EmissiveParams", 5)?;
+        s.serialize_field("texture", &self.texture)?;
+        s.serialize_field("image", &self.image)?;
+        s.serialize_field("tex_coord", &self.tex_coord)?;
+        s.serialize_field("transform", &self.transform)?;
+        s.serialize_field("factor", &self.factor)?;
+        s.end()
+    }
+}
+
+impl<'a> Serialize for mesh::Mesh<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Mesh", 4)?;
+        s.serialize_field("index", &self.index())?;
+        #[cfg(feature = "names")]
+        s.serialize_field("name", &self.name())?;
+        s.serialize_field("primitives", &self.primitives().collect::<Vec<_>>())?;
+        s.serialize_field("weights", &self.weights())?;
+        s.end()
+    }
+}
+
+impl<'a> Serialize for mesh::Primitive<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let attributes = self
+            .attributes()
+            .map(|(semantic, accessor)| (semantic.to_string(), accessor.index()))
+            .collect::<BTreeMap<_, _>>();
+        let mut s = serializer.serialize_struct("Primitive", 6)?;
+        s.serialize_field("index", &self.index())?;
+        s.serialize_field("mode", &Variant(self.mode()))?;
+        s.serialize_field("material", &self.material_index())?;
+        s.serialize_field("attributes", &attributes)?;
+        s.serialize_field("indices", &self.indices().map(|x| x.index()))?;
+        s.serialize_field("morph_targets", &self.morph_targets().collect::<Vec<_>>())?;
+        s.end()
+    }
+}
+
+impl<'a> Serialize for mesh::MorphTarget<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("MorphTarget", 3)?;
+        s.serialize_field("positions", &self.positions().map(|x| x.index()))?;
+        s.serialize_field("normals", &self.normals().map(|x| x.index()))?;
+        s.serialize_field("tangents", &self.tangents().map(|x| x.index()))?;
+        s.end()
+    }
+}
+
+impl<'a> Serialize for scene::Node<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Node", 8)?;
+        s.serialize_field("index", &self.index())?;
+        #[cfg(feature = "names")]
+        s.serialize_field("name", &self.name())?;
+        s.serialize_field("camera", &self.camera().map(|x| x.index()))?;
+        s.serialize_field("children", &self.children().map(|x| x.index()).collect::<Vec<_>>())?;
+        s.serialize_field("mesh", &self.mesh().map(|x| x.index()))?;
+        s.serialize_field("skin", &self.skin().map(|x| x.index()))?;
+        s.serialize_field("transform", &self.transform())?;
+        s.serialize_field("weights", &self.weights())?;
+        s.end()
+    }
+}
+
+/// Transforms are always serialized in decomposed form.
+impl Serialize for scene::Transform {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (translation, rotation, scale) = self.clone().decomposed();
+        let mut s = serializer.serialize_struct("Transform", 3)?;
+        s.serialize_field("translation", &translation)?;
+        s.serialize_field("rotation", &rotation)?;
+        s.serialize_field("scale", &scale)?;
+        s.end()
+    }
+}
+
+impl<'a> Serialize for scene::Scene<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Scene", 3)?;
+        s.serialize_field("index", &self.index())?;
+        #[cfg(feature = "names")]
+        s.serialize_field("name", &self.name())?;
+        s.serialize_field("nodes", &self.nodes().map(|x| x.index()).collect::<Vec<_>>())?;
+        s.end()
+    }
+}
+
+impl<'a> Serialize for skin::Skin<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Skin", 5)?;
+        s.serialize_field("index", &self.index())?;
+        #[cfg(feature = "names")]
+        s.serialize_field("name", &self.name())?;
+        s.serialize_field(
+            "inverse_bind_matrices",
+            &self.inverse_bind_matrices().map(|x| x.index()),
+        )?;
+        s.serialize_field("joints", &self.joints().map(|x| x.index()).collect::<Vec<_>>())?;
+        s.serialize_field("skeleton", &self.skeleton().map(|x| x.index()))?;
+        s.end()
+    }
+}
+
+impl<'a> Serialize for texture::Info<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Info", 2)?;
+        s.serialize_field("texture", &self.texture().index())?;
+        s.serialize_field("tex_coord", &self.tex_coord())?;
+        s.end()
+    }
+}
+
+impl Serialize for texture::UvTransform {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("UvTransform", 3)?;
+        s.serialize_field("offset", &self.offset)?;
+        s.serialize_field("rotation", &self.rotation)?;
+        s.serialize_field("scale", &self.scale)?;
+        s.end()
+    }
+}
+
+impl<'a> Serialize for texture::Sampler<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Sampler", 6)?;
+        s.serialize_field("index", &self.index())?;
+        #[cfg(feature = "names")]
+        s.serialize_field("name", &self.name())?;
+        s.serialize_field("mag_filter", &self.mag_filter().map(Variant))?;
+        s.serialize_field("min_filter", &self.min_filter().map(Variant))?;
+        s.serialize_field("wrap_s", &Variant(self.wrap_s()))?;
+        s.serialize_field("wrap_t", &Variant(self.wrap_t()))?;
+        s.end()
+    }
+}
+
+impl<'a> Serialize for texture::Texture<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Texture", 4)?;
+        s.serialize_field("index", &self.index())?;
+        #[cfg(feature = "names")]
+        s.serialize_field("name", &self.name())?;
+        s.serialize_field("sampler", &self.sampler())?;
+        s.serialize_field("source", &self.source().map(|x| x.index()))?;
+        s.end()
+    }
+}
+
+impl Serialize for texture::SamplerConfig {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("SamplerConfig", 4)?;
+        s.serialize_field("mag_filter", &self.mag_filter.map(Variant))?;
+        s.serialize_field("min_filter", &self.min_filter.map(Variant))?;
+        s.serialize_field("wrap_s", &Variant(self.wrap_s))?;
+        s.serialize_field("wrap_t", &Variant(self.wrap_t))?;
+        s.end()
+    }
+}
+
+impl Serialize for texture::MaterialUse {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("MaterialUse", 3)?;
+        s.serialize_field("material", &self.material)?;
+        s.serialize_field("slot", &Variant(self.slot))?;
+        s.serialize_field("texture", &self.texture)?;
+        s.end()
+    }
+}
+
+impl Serialize for texture::ImageSharing {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("ImageSharing", 5)?;
+        s.serialize_field("image", &self.image)?;
+        s.serialize_field("textures", &self.textures)?;
+        s.serialize_field("samplers", &self.samplers)?;
+        s.serialize_field("materials", &self.materials)?;
+        s.serialize_field("atlas_unsafe", &self.atlas_unsafe)?;
+        s.end()
+    }
+}
+
+impl Serialize for texture::ImageSharingReport {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("ImageSharingReport", 1)?;
+        s.serialize_field("images", &self.images)?;
+        s.end()
+    }
+}
+
+impl Serialize for texture::Incompatibility {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Incompatibility", 6)?;
+        s.serialize_field("texture", &self.texture)?;
+        s.serialize_field("image", &self.image)?;
+        s.serialize_field("dimensions", &self.dimensions)?;
+        s.serialize_field("oversized", &self.oversized)?;
+        s.serialize_field("non_power_of_two", &self.non_power_of_two)?;
+        s.serialize_field("materials", &self.materials)?;
+        s.end()
+    }
+}
+
+impl Serialize for texture::CompatibilityReport {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("CompatibilityReport", 2)?;
+        s.serialize_field("incompatible", &self.incompatible)?;
+        s.serialize_field("unknown", &self.unknown)?;
+        s.end()
+    }
+}
+
+impl Serialize for requirements::Requirements {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Requirements", 13)?;
+        s.serialize_field("extensions_required", &self.extensions_required)?;
+        s.serialize_field("extensions_used", &self.extensions_used)?;
+        s.serialize_field("skinning", &self.skinning)?;
+        s.serialize_field("max_skin_joints", &self.max_skin_joints)?;
+        s.serialize_field("max_joint_sets", &self.max_joint_sets)?;
+        s.serialize_field("morph_targets", &self.morph_targets)?;
+        s.serialize_field("max_morph_targets", &self.max_morph_targets)?;
+        s.serialize_field("max_tex_coord_sets", &self.max_tex_coord_sets)?;
+        s.serialize_field("max_color_sets", &self.max_color_sets)?;
+        s.serialize_field("u32_indices", &self.u32_indices)?;
+        s.serialize_field("animations", &self.animations)?;
+        s.serialize_field("max_image_size", &self.max_image_size)?;
+        s.serialize_field("unprobed_images", &self.unprobed_images)?;
+        s.end()
+    }
+}
+
+impl Serialize for validation::Entry {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Entry", 4)?;
+        s.serialize_field("code", self.code())?;
+        s.serialize_field("severity", &Variant(self.severity()))?;
+        s.serialize_field("path", self.path.as_str())?;
+        s.serialize_field("message", &self.error.to_string())?;
+        s.end()
+    }
+}
+
+impl Serialize for validation::Report {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut counts = BTreeMap::new();
+        counts.insert("errors", self.count(validation::Severity::Error));
+        counts.insert("warnings", self.count(validation::Severity::Warning));
+        counts.insert("infos", self.count(validation::Severity::Info));
+        let mut s = serializer.serialize_struct("Report", 3)?;
+        s.serialize_field("counts", &counts)?;
+        s.serialize_field("entries", &self.entries)?;
+        s.serialize_field("suppressed", &self.suppressed)?;
+        s.end()
+    }
+}
+
+impl validation::Report {
+    /// Returns the report as JSON for consumption by other tools.
+    ///
+    /// The report is an object with three keys. `counts` maps `errors`,
+    /// `warnings`, and `infos` to the number of unsuppressed entries of each
+    /// severity. `entries` and `suppressed` are arrays of objects with the
+    /// `code`, `severity`, `path`, and `message` of each entry.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// let gltf = gltf::Gltf::open("examples/Box.gltf")?;
+    /// let report = gltf.validation_report().to_json();
+    /// assert_eq!(report["counts"]["errors"], 0);
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    pub fn to_json(&self) -> json::Value {
+        // Serialization only fails for maps with keys that are not strings.
+        json::serialize::to_value(self).unwrap_or_default()
+    }
+}
+
+impl Document {
+    /// Returns the resolved view of the document as JSON.
+    ///
+    /// Unlike [`into_json`], the snapshot contains the values returned by the
+    /// wrapper types after defaults are applied. The format is stable and
+    /// intended for debugging and golden-file tests. Every top-level array is
+    /// included, even if it is empty, and the keys of each object are named
+    /// after the corresponding wrapper functions.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// let gltf = gltf::Gltf::open("examples/Box.gltf")?;
+    /// let snapshot = gltf.resolved_snapshot();
+    /// let material = &snapshot["materials"][0];
+    /// assert_eq!(material["alpha_mode"], "Opaque");
+    /// assert_eq!(snapshot["nodes"][0]["transform"]["scale"][0], 1.0);
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    ///
+    /// [`into_json`]: #method.into_json
+    pub fn resolved_snapshot(&self) -> json::Value {
+        // Serialization only fails for maps with keys that are not strings.
+        json::serialize::to_value(Snapshot(self)).unwrap_or_default()
+    }
+}
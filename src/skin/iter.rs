@@ -3,6 +3,9 @@ use std::slice;
 use crate::{Document, Node};
 
 /// An `Iterator` that visits the joints of a `Skin`.
+///
+/// Missing nodes, which are only possible for documents loaded without
+/// validation, are skipped.
 #[derive(Clone, Debug)]
 pub struct Joints<'a> {
     /// The parent `Document` struct.
@@ -15,8 +18,7 @@ pub struct Joints<'a> {
 impl<'a> Iterator for Joints<'a>  {
     type Item = Node<'a>;
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter
-            .next()
-            .map(|index| self.document.nodes().nth(index.value()).unwrap())
+        let document = self.document;
+        self.iter.find_map(|index| document.get(*index))
     }
 }
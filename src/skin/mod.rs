@@ -1,7 +1,9 @@
 use crate::{Accessor, Document, Node};
 
 #[cfg(feature = "utils")]
-use crate::Buffer;
+use crate::math::Matrix4;
+#[cfg(feature = "utils")]
+use crate::{accessor, buffer, Buffer};
 
 /// Iterators.
 pub mod iter;
@@ -27,6 +29,8 @@ pub struct Skin<'a> {
     json: &'a json::skin::Skin,
 }
 
+impl_identity!(Skin);
+
 impl<'a> Skin<'a> {
     /// Constructs a `Skin`.
     pub(crate) fn new(
@@ -58,12 +62,7 @@ impl<'a> Skin<'a> {
     pub fn inverse_bind_matrices(&self) -> Option<Accessor<'a>> {
         self.json.inverse_bind_matrices
             .as_ref()
-            .map(|index| {
-                self.document
-                    .accessors()
-                    .nth(index.value())
-                    .unwrap()
-            })
+            .and_then(|&index| self.document.get(index))
     }
 
     /// Constructs a skin reader.
@@ -81,6 +80,92 @@ impl<'a> Skin<'a> {
         }
     }
 
+    /// Constructs a skin reader, fetching the data of the buffer read by the
+    /// skin up front, as [`Primitive::try_reader`] does.
+    ///
+    /// [`Primitive::try_reader`]: ../mesh/struct.Primitive.html#method.try_reader
+    #[cfg(feature = "utils")]
+    pub fn try_reader<'s, F>(
+        &'a self,
+        get_buffer_data: F,
+    ) -> crate::Result<Reader<'a, 's, impl Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>>>
+    where
+        F: FnMut(Buffer<'a>) -> crate::Result<&'s [u8]>,
+    {
+        let get_buffer_data = buffer::prefetch(self.inverse_bind_matrices(), get_buffer_data)?;
+        Ok(Reader {
+            skin: self.clone(),
+            get_buffer_data,
+        })
+    }
+
+    /// Returns the joint matrix of every joint of the skin, in the order of
+    /// [`joints`], for the skinned mesh instantiated by `node`.
+    ///
+    /// Each matrix is computed as `inverse(world(node)) * world(joint) *
+    /// inverseBindMatrix`, as defined by the specification, so the transform
+    /// of the node itself is cancelled and the skinned vertices are in world
+    /// space. `world` returns the world transform of a node by index, for
+    /// example with [`TransformCache::world`]. Missing inverse bind matrices
+    /// are the identity. The [`skeleton`] root only hints at the common root
+    /// of the joints and does not change the result.
+    ///
+    /// Returns `None` if `world` returns `None` for `node` or a joint, the
+    /// world transform of `node` is singular, or the inverse bind matrices
+    /// are unavailable or fewer than the joints.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// use gltf::scene::TransformCache;
+    ///
+    /// let (document, buffers, _) = gltf::import("examples/Box.gltf")?;
+    /// let scene = document.default_scene().unwrap();
+    /// let cache = TransformCache::new(&scene);
+    /// for node in document.nodes() {
+    ///     if let Some(skin) = node.skin() {
+    ///         let get_buffer_data = |x: gltf::Buffer| Some(&*buffers[x.index()]);
+    ///         let matrices = skin.joint_matrices(&node, |x| cache.world(x), get_buffer_data);
+    ///         println!("{:?}", matrices);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    ///
+    /// [`joints`]: #method.joints
+    /// [`skeleton`]: #method.skeleton
+    /// [`TransformCache::world`]: ../scene/struct.TransformCache.html#method.world
+    #[cfg(feature = "utils")]
+    pub fn joint_matrices<'s, W, F>(
+        &self,
+        node: &Node,
+        world: W,
+        get_buffer_data: F,
+    ) -> Option<Vec<[[f32; 4]; 4]>>
+    where
+        W: Fn(usize) -> Option<[[f32; 4]; 4]>,
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        let inverse = Matrix4::from_array(world(node.index())?).invert()?;
+        let mut inverse_bind_matrices = match self.inverse_bind_matrices() {
+            Some(accessor) => Some(accessor::Iter::<[[f32; 4]; 4]>::new(accessor, get_buffer_data)?),
+            None => None,
+        };
+        self.joints()
+            .map(|joint| {
+                let joint = Matrix4::from_array(world(joint.index())?);
+                let inverse_bind_matrix = match inverse_bind_matrices {
+                    Some(ref mut iter) => Matrix4::from_array(iter.next()?),
+                    None => Matrix4::from_nonuniform_scale(1.0, 1.0, 1.0),
+                };
+                Some((inverse * joint * inverse_bind_matrix).as_array())
+            })
+            .collect()
+    }
+
     /// Returns an `Iterator` that visits the skeleton nodes used as joints in
     /// this skin.
     pub fn joints(&self) -> iter::Joints<'a> {
@@ -93,14 +178,14 @@ impl<'a> Skin<'a> {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     pub fn name(&self) -> Option<&'a str> {
-        self.json.name.as_ref().map(String::as_str)
+        self.json.name.as_deref()
     }
 
     /// Returns the node used as the skeleton root. When `None`, joints
     /// transforms resolve to scene root.
     pub fn skeleton(&self) -> Option<Node<'a>> {
-        self.json.skeleton.as_ref().map(|index| {
-            self.document.nodes().nth(index.value()).unwrap()
+        self.json.skeleton.as_ref().and_then(|index| {
+            self.document.get(*index)
         })
     }
 }
@@ -16,6 +16,32 @@ pub struct Info<'a> {
     json: &'a json::texture::Info,
 }
 
+/// The `KHR_texture_transform` extension of a texture reference.
+#[cfg(feature = "KHR_texture_transform")]
+#[derive(Clone, Debug)]
+pub struct TextureTransform<'a> {
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::texture::TextureTransform,
+}
+
+/// A transform of texture coordinates, which are scaled, then rotated, then
+/// offset.
+///
+/// The transform of a texture reference without `KHR_texture_transform` is
+/// the identity.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UvTransform {
+    /// The offset of the UV coordinate origin as a factor of the texture
+    /// dimensions.
+    pub offset: [f32; 2],
+
+    /// The counter-clockwise rotation of the UV coordinates, in radians.
+    pub rotation: f32,
+
+    /// The scale factors of the UV coordinates.
+    pub scale: [f32; 2],
+}
+
 ///  Texture sampler properties for filtering and wrapping modes.
 #[derive(Clone, Debug)]
 pub struct Sampler<'a> {
@@ -30,6 +56,8 @@ pub struct Sampler<'a> {
     json: &'a json::texture::Sampler,
 }
 
+impl_identity!(Sampler);
+
 /// A texture and its sampler.
 #[derive(Clone, Debug)]
 pub struct Texture<'a> {
@@ -43,6 +71,433 @@ pub struct Texture<'a> {
     json: &'a json::texture::Texture,
 }
 
+impl_identity!(Texture);
+
+/// The textures sharing an image which require mipmaps, as returned by
+/// [`Document::textures_requiring_mipmaps`].
+///
+/// [`Document::textures_requiring_mipmaps`]: ../struct.Document.html#method.textures_requiring_mipmaps
+#[derive(Clone, Debug)]
+pub struct MipmapRequirement<'a> {
+    /// The image for which mipmaps must be generated.
+    pub image: image::Image<'a>,
+
+    /// The textures using `image` whose samplers require mipmaps.
+    pub textures: Vec<Texture<'a>>,
+}
+
+/// Groups the textures requiring mipmaps by their source image.
+pub(crate) fn textures_requiring_mipmaps(document: &Document) -> Vec<MipmapRequirement<'_>> {
+    let mut requirements: Vec<MipmapRequirement> = Vec::new();
+    for texture in document.textures() {
+        if !texture.sampler().requires_mipmaps() {
+            continue;
+        }
+        let image = match texture.source() {
+            Some(image) => image,
+            None => continue,
+        };
+        match requirements.iter_mut().find(|x| x.image.index() == image.index()) {
+            Some(requirement) => requirement.textures.push(texture),
+            None => requirements.push(MipmapRequirement { image, textures: vec![texture] }),
+        }
+    }
+    requirements.sort_by_key(|x| x.image.index());
+    requirements
+}
+
+/// The filtering and wrapping modes of a sampler, as listed by
+/// [`ImageSharing`].
+///
+/// [`ImageSharing`]: struct.ImageSharing.html
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SamplerConfig {
+    /// Magnification filter.
+    pub mag_filter: Option<MagFilter>,
+
+    /// Minification filter.
+    pub min_filter: Option<MinFilter>,
+
+    /// `s` wrapping mode.
+    pub wrap_s: WrappingMode,
+
+    /// `t` wrapping mode.
+    pub wrap_t: WrappingMode,
+}
+
+/// A source of the image of a texture, for choosing among the sources with
+/// [`Texture::source_with`].
+///
+/// [`Texture::source_with`]: struct.Texture.html#method.source_with
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SourceKind {
+    /// The core `source` of the texture.
+    Core,
+
+    /// The source of the `MSFT_texture_dds` extension.
+    #[cfg(feature = "MSFT_texture_dds")]
+    Dds,
+
+    /// The source of the `EXT_texture_webp` extension.
+    #[cfg(feature = "EXT_texture_webp")]
+    Webp,
+}
+
+/// The order in which [`Texture::source`] resolves the image of a texture:
+/// WebP, then DDS, then the core source, skipping extensions whose feature is
+/// disabled.
+///
+/// [`Texture::source`]: struct.Texture.html#method.source
+pub const DEFAULT_SOURCE_ORDER: &[SourceKind] = &[
+    #[cfg(feature = "EXT_texture_webp")]
+    SourceKind::Webp,
+    #[cfg(feature = "MSFT_texture_dds")]
+    SourceKind::Dds,
+    SourceKind::Core,
+];
+
+/// A texture slot of a material.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum MaterialSlot {
+    /// `pbrMetallicRoughness.baseColorTexture`.
+    BaseColor,
+
+    /// `pbrMetallicRoughness.metallicRoughnessTexture`.
+    MetallicRoughness,
+
+    /// `normalTexture`.
+    Normal,
+
+    /// `occlusionTexture`.
+    Occlusion,
+
+    /// `emissiveTexture`.
+    Emissive,
+
+    /// `KHR_materials_pbrSpecularGlossiness.diffuseTexture`.
+    #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+    Diffuse,
+
+    /// `KHR_materials_pbrSpecularGlossiness.specularGlossinessTexture`.
+    #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+    SpecularGlossiness,
+}
+
+/// A use of a texture by a material.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MaterialUse {
+    /// The index of the material.
+    pub material: usize,
+
+    /// The slot of the material referencing the texture.
+    pub slot: MaterialSlot,
+
+    /// The index of the texture.
+    pub texture: usize,
+}
+
+/// How the textures sharing one image sample it, as listed by
+/// [`ImageSharingReport`].
+///
+/// [`ImageSharingReport`]: struct.ImageSharingReport.html
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImageSharing {
+    /// The index of the image.
+    pub image: usize,
+
+    /// The indices of the textures using the image, in ascending order.
+    pub textures: Vec<usize>,
+
+    /// The distinct sampler configurations of the textures, in the order of
+    /// first use.
+    pub samplers: Vec<SamplerConfig>,
+
+    /// The material slots referencing the textures, ordered by material.
+    pub materials: Vec<MaterialUse>,
+
+    /// `true` if the image is sampled with both a repeating wrapping mode,
+    /// `Repeat` or `MirroredRepeat`, and `ClampToEdge`, on either axis.
+    ///
+    /// Such an image cannot be placed in a texture atlas without duplicating
+    /// it, since repeating textures must cover the whole atlas.
+    pub atlas_unsafe: bool,
+}
+
+/// The textures of a document grouped by their source image, as returned by
+/// [`Document::image_sharing_report`].
+///
+/// [`Document::image_sharing_report`]: ../struct.Document.html#method.image_sharing_report
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ImageSharingReport {
+    /// Every image used by at least one texture, in index order.
+    pub images: Vec<ImageSharing>,
+}
+
+impl ImageSharingReport {
+    /// Returns the images that cannot be placed in a texture atlas.
+    pub fn atlas_unsafe(&self) -> impl Iterator<Item = &ImageSharing> + '_ {
+        self.images.iter().filter(|x| x.atlas_unsafe)
+    }
+}
+
+/// The texture limits of a target platform, checked by
+/// [`Document::texture_compatibility`].
+///
+/// [`Document::texture_compatibility`]: ../struct.Document.html#method.texture_compatibility
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Profile {
+    /// OpenGL ES 2.0 and WebGL 1: at most 2048 pixels per side, and
+    /// non-power-of-two textures cannot be mipmapped or repeated.
+    Gles2,
+
+    /// OpenGL ES 3.0 and WebGL 2: at most 4096 pixels per side.
+    Gles3,
+
+    /// Desktop OpenGL, Direct3D, Vulkan, and Metal: at most 16384 pixels per
+    /// side.
+    Desktop,
+
+    /// Custom limits.
+    Custom {
+        /// The largest width and height in pixels.
+        max_dimension: u32,
+
+        /// `true` if non-power-of-two textures cannot be mipmapped or use a
+        /// repeating wrapping mode, as in OpenGL ES 2.0.
+        require_power_of_two: bool,
+    },
+}
+
+impl Profile {
+    /// Returns the largest width and height in pixels.
+    pub fn max_dimension(&self) -> u32 {
+        match *self {
+            Profile::Gles2 => 2048,
+            Profile::Gles3 => 4096,
+            Profile::Desktop => 16384,
+            Profile::Custom { max_dimension, .. } => max_dimension,
+        }
+    }
+
+    /// Returns `true` if non-power-of-two textures cannot be mipmapped or use
+    /// a repeating wrapping mode.
+    pub fn requires_power_of_two(&self) -> bool {
+        match *self {
+            Profile::Gles2 => true,
+            Profile::Gles3 | Profile::Desktop => false,
+            Profile::Custom { require_power_of_two, .. } => require_power_of_two,
+        }
+    }
+}
+
+/// A texture exceeding the limits of a [`Profile`], as listed by
+/// [`CompatibilityReport`].
+///
+/// [`Profile`]: enum.Profile.html
+/// [`CompatibilityReport`]: struct.CompatibilityReport.html
+#[derive(Clone, Debug, PartialEq)]
+pub struct Incompatibility {
+    /// The index of the texture.
+    pub texture: usize,
+
+    /// The index of the source image of the texture.
+    pub image: usize,
+
+    /// The width and height of the image in pixels.
+    pub dimensions: (u32, u32),
+
+    /// `true` if the width or height exceeds the largest dimension of the
+    /// profile.
+    pub oversized: bool,
+
+    /// `true` if the width or height is not a power of two while the sampler
+    /// of the texture requires mipmaps or repeats on either axis, and the
+    /// profile does not allow that.
+    pub non_power_of_two: bool,
+
+    /// The material slots referencing the texture, ordered by material.
+    pub materials: Vec<MaterialUse>,
+}
+
+/// The textures of a document exceeding the limits of a [`Profile`], as
+/// returned by [`Document::texture_compatibility`].
+///
+/// [`Profile`]: enum.Profile.html
+/// [`Document::texture_compatibility`]: ../struct.Document.html#method.texture_compatibility
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CompatibilityReport {
+    /// The incompatible textures, in index order.
+    pub incompatible: Vec<Incompatibility>,
+
+    /// The indices of the images whose dimensions could not be read, because
+    /// their data is unavailable or their format is not recognized.
+    pub unknown: Vec<usize>,
+}
+
+impl CompatibilityReport {
+    /// Returns `true` if every texture is within the limits of the profile.
+    ///
+    /// Images of unknown dimensions are not taken into account.
+    pub fn is_compatible(&self) -> bool {
+        self.incompatible.is_empty()
+    }
+}
+
+/// Returns the textures referenced by each slot of a material.
+pub(crate) fn material_slots<'a>(material: &crate::Material<'a>) -> Vec<(MaterialSlot, Texture<'a>)> {
+    let pbr = material.pbr_metallic_roughness();
+    let mut slots = Vec::new();
+    if let Some(info) = pbr.base_color_texture() {
+        slots.push((MaterialSlot::BaseColor, info.texture()));
+    }
+    if let Some(info) = pbr.metallic_roughness_texture() {
+        slots.push((MaterialSlot::MetallicRoughness, info.texture()));
+    }
+    if let Some(normal) = material.normal_texture() {
+        slots.push((MaterialSlot::Normal, normal.texture()));
+    }
+    if let Some(occlusion) = material.occlusion_texture() {
+        slots.push((MaterialSlot::Occlusion, occlusion.texture()));
+    }
+    if let Some(info) = material.emissive_texture() {
+        slots.push((MaterialSlot::Emissive, info.texture()));
+    }
+    #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+    {
+        if let Some(pbr) = material.pbr_specular_glossiness() {
+            if let Some(info) = pbr.diffuse_texture() {
+                slots.push((MaterialSlot::Diffuse, info.texture()));
+            }
+            if let Some(info) = pbr.specular_glossiness_texture() {
+                slots.push((MaterialSlot::SpecularGlossiness, info.texture()));
+            }
+        }
+    }
+    slots
+}
+
+/// Groups the textures of a document by their source image.
+pub(crate) fn image_sharing_report(document: &Document) -> ImageSharingReport {
+    let mut images: Vec<ImageSharing> = Vec::new();
+    for texture in document.textures() {
+        let sampler = texture.sampler();
+        let config = SamplerConfig {
+            mag_filter: sampler.mag_filter(),
+            min_filter: sampler.min_filter(),
+            wrap_s: sampler.wrap_s(),
+            wrap_t: sampler.wrap_t(),
+        };
+        let image = match texture.source() {
+            Some(image) => image.index(),
+            None => continue,
+        };
+        let sharing = match images.iter().position(|x| x.image == image) {
+            Some(position) => &mut images[position],
+            None => {
+                let position = images.len();
+                images.push(ImageSharing {
+                    image,
+                    textures: Vec::new(),
+                    samplers: Vec::new(),
+                    materials: Vec::new(),
+                    atlas_unsafe: false,
+                });
+                &mut images[position]
+            },
+        };
+        sharing.textures.push(texture.index());
+        if !sharing.samplers.contains(&config) {
+            sharing.samplers.push(config);
+        }
+    }
+
+    for (index, material) in document.materials().enumerate() {
+        for (slot, texture) in material_slots(&material) {
+            let image = match texture.source() {
+                Some(image) => image.index(),
+                None => continue,
+            };
+            if let Some(sharing) = images.iter_mut().find(|x| x.image == image) {
+                sharing.materials.push(MaterialUse {
+                    material: index,
+                    slot,
+                    texture: texture.index(),
+                });
+            }
+        }
+    }
+
+    for sharing in &mut images {
+        let modes = sharing.samplers.iter().flat_map(|x| vec![x.wrap_s, x.wrap_t]).collect::<Vec<_>>();
+        let repeats = modes.iter().any(|&x| x != WrappingMode::ClampToEdge);
+        let clamps = modes.contains(&WrappingMode::ClampToEdge);
+        sharing.atlas_unsafe = repeats && clamps;
+    }
+    images.sort_by_key(|x| x.image);
+    ImageSharingReport { images }
+}
+
+/// Checks the dimensions of the source image of every texture against the
+/// limits of `profile`.
+pub(crate) fn compatibility_report<'a, 's, F>(
+    document: &'a Document,
+    profile: Profile,
+    get_image_data: F,
+) -> CompatibilityReport
+where
+    F: Fn(image::Image<'a>) -> Option<&'s [u8]>,
+{
+    let mut dimensions = vec![None; document.images().len()];
+    let mut report = CompatibilityReport::default();
+    for image in document.images() {
+        let index = image.index();
+        dimensions[index] = get_image_data(image).and_then(image::probe_dimensions);
+        if dimensions[index].is_none() {
+            report.unknown.push(index);
+        }
+    }
+
+    for texture in document.textures() {
+        let image = match texture.source() {
+            Some(image) => image.index(),
+            None => continue,
+        };
+        let (width, height) = match dimensions[image] {
+            Some(dimensions) => dimensions,
+            None => continue,
+        };
+        let sampler = texture.sampler();
+        let repeats = sampler.wrap_s() != WrappingMode::ClampToEdge || sampler.wrap_t() != WrappingMode::ClampToEdge;
+        let oversized = width.max(height) > profile.max_dimension();
+        let non_power_of_two = profile.requires_power_of_two()
+            && !(width.is_power_of_two() && height.is_power_of_two())
+            && (sampler.requires_mipmaps() || repeats);
+        if oversized || non_power_of_two {
+            report.incompatible.push(Incompatibility {
+                texture: texture.index(),
+                image,
+                dimensions: (width, height),
+                oversized,
+                non_power_of_two,
+                materials: Vec::new(),
+            });
+        }
+    }
+
+    for (index, material) in document.materials().enumerate() {
+        for (slot, texture) in material_slots(&material) {
+            if let Some(incompatibility) = report.incompatible.iter_mut().find(|x| x.texture == texture.index()) {
+                incompatibility.materials.push(MaterialUse {
+                    material: index,
+                    slot,
+                    texture: texture.index(),
+                });
+            }
+        }
+    }
+    report
+}
+
 impl<'a> Sampler<'a> {
     /// Constructs a `Sampler`.
     pub(crate) fn new(
@@ -74,33 +529,73 @@ impl<'a> Sampler<'a> {
     }
 
     /// Magnification filter.
+    ///
+    /// An invalid filter, which is only possible for documents loaded
+    /// without validation, is read as undefined.
     pub fn mag_filter(&self) -> Option<MagFilter> {
-        self.json.mag_filter.map(|filter| filter.unwrap())
+        self.json.mag_filter.and_then(|filter| filter.valid())
     }
 
     /// Minification filter.
+    ///
+    /// An invalid filter, which is only possible for documents loaded
+    /// without validation, is read as undefined.
     pub fn min_filter(&self) -> Option<MinFilter> {
-        self.json.min_filter.map(|filter| filter.unwrap())
+        self.json.min_filter.and_then(|filter| filter.valid())
+    }
+
+    /// Magnification filter, or `Linear` when undefined.
+    ///
+    /// The specification leaves undefined filters to the implementation; this
+    /// is the customary choice of renderers.
+    pub fn mag_filter_or_default(&self) -> MagFilter {
+        self.mag_filter().unwrap_or(MagFilter::Linear)
+    }
+
+    /// Minification filter, or `LinearMipmapLinear` when undefined.
+    ///
+    /// The specification leaves undefined filters to the implementation; this
+    /// is the customary choice of renderers.
+    pub fn min_filter_or_default(&self) -> MinFilter {
+        self.min_filter().unwrap_or(MinFilter::LinearMipmapLinear)
+    }
+
+    /// Returns `true` if the minification filter samples from a mipmap chain,
+    /// in which case mipmaps must be generated for textures using this
+    /// sampler.
+    ///
+    /// Undefined filters are resolved with [`min_filter_or_default`] and
+    /// therefore require mipmaps.
+    ///
+    /// [`min_filter_or_default`]: #method.min_filter_or_default
+    pub fn requires_mipmaps(&self) -> bool {
+        self.min_filter_or_default().uses_mipmaps()
     }
 
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
-    pub fn name(&self) -> Option<&str> {
-        self.json.name.as_ref().map(String::as_str)
+    pub fn name(&self) -> Option<&'a str> {
+        self.json.name.as_deref()
     }
 
     /// `s` wrapping mode.
+    ///
+    /// An invalid mode, which is only possible for documents loaded without
+    /// validation, is read as the default `Repeat`.
     pub fn wrap_s(&self) -> WrappingMode {
-        self.json.wrap_s.unwrap()
+        self.json.wrap_s.valid().unwrap_or_default()
     }
 
     /// `t` wrapping mode.
+    ///
+    /// An invalid mode, which is only possible for documents loaded without
+    /// validation, is read as the default `Repeat`.
     pub fn wrap_t(&self) -> WrappingMode {
-        self.json.wrap_t.unwrap()
+        self.json.wrap_t.valid().unwrap_or_default()
     }
 
     /// Optional application specific data.
-    pub fn extras(&self) -> &json::Extras {
+    pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
     }
 }
@@ -126,25 +621,136 @@ impl<'a> Texture<'a> {
 
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
-    pub fn name(&self) -> Option<&str> {
-        self.json.name.as_ref().map(String::as_str)
+    pub fn name(&self) -> Option<&'a str> {
+        self.json.name.as_deref()
     }
 
     /// Returns the sampler used by this texture.
+    ///
+    /// Textures without a sampler use the default sampler, which repeats in
+    /// both directions and leaves filtering to the implementation. Its
+    /// [`index`] is `None`.
+    ///
+    /// [`index`]: struct.Sampler.html#method.index
     pub fn sampler(&self) -> Sampler<'a> {
+        self.sampler_authored()
+            .unwrap_or_else(|| Sampler::default(self.document))
+    }
+
+    /// Returns the sampler used by this texture, or `None` if it uses the
+    /// default sampler.
+    ///
+    /// Writers should use this function, since the default sampler is not part
+    /// of the document and must not be written.
+    pub fn sampler_authored(&self) -> Option<Sampler<'a>> {
         self.json.sampler
             .as_ref()
-            .map(|index| self.document.samplers().nth(index.value() as usize).unwrap())
-            .unwrap_or_else(|| Sampler::default(self.document))
+            .and_then(|index| self.document.get(*index))
     }
 
     /// Returns the image used by this texture.
-    pub fn source(&self) -> image::Image<'a> {
-        self.document.images().nth(self.json.source.value() as usize).unwrap()
+    ///
+    /// The image is resolved in the following order, as listed by
+    /// [`DEFAULT_SOURCE_ORDER`]:
+    ///
+    /// 1. The source of the `EXT_texture_webp` extension, if the
+    ///    `EXT_texture_webp` feature is enabled.
+    /// 2. The source of the `MSFT_texture_dds` extension, if the
+    ///    `MSFT_texture_dds` feature is enabled.
+    /// 3. The core `source` of the texture.
+    ///
+    /// Sources provided by other extensions, such as `KHR_texture_basisu`, are
+    /// ignored. Use [`source_with`] for a different preference.
+    ///
+    /// Validation guarantees that one of the above is present, so this only
+    /// returns `None` for documents loaded without validation.
+    ///
+    /// [`DEFAULT_SOURCE_ORDER`]: constant.DEFAULT_SOURCE_ORDER.html
+    /// [`source_with`]: #method.source_with
+    pub fn source(&self) -> Option<image::Image<'a>> {
+        self.source_with(DEFAULT_SOURCE_ORDER)
+    }
+
+    /// Returns the first source of this texture in the order of `preference`,
+    /// or `None` if the texture has none of them.
+    ///
+    /// ```
+    /// # #[cfg(feature = "MSFT_texture_dds")]
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// use gltf::texture::SourceKind;
+    ///
+    /// let json = r#"{
+    ///     "asset": {"version": "2.0"},
+    ///     "images": [{"uri": "fallback.png"}, {"uri": "preferred.dds"}],
+    ///     "textures": [{"source": 0, "extensions": {"MSFT_texture_dds": {"source": 1}}}]
+    /// }"#;
+    /// let gltf = gltf::Gltf::from_slice(json.as_bytes())?;
+    /// let texture = gltf.textures().next().unwrap();
+    /// assert_eq!(texture.source().unwrap().index(), 1);
+    /// let image = texture.source_with(&[SourceKind::Core, SourceKind::Dds]).unwrap();
+    /// assert_eq!(image.index(), 0);
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     #[cfg(feature = "MSFT_texture_dds")]
+    /// #     run().expect("test failure");
+    /// # }
+    /// ```
+    pub fn source_with(&self, preference: &[SourceKind]) -> Option<image::Image<'a>> {
+        preference.iter().find_map(|kind| match kind {
+            SourceKind::Core => self.core_source(),
+            #[cfg(feature = "MSFT_texture_dds")]
+            SourceKind::Dds => self.dds_source(),
+            #[cfg(feature = "EXT_texture_webp")]
+            SourceKind::Webp => self.webp_source(),
+        })
+    }
+
+    /// Returns the core `source` image of this texture, ignoring extensions.
+    ///
+    /// This is the fallback for clients that do not support the extension
+    /// that provides the image returned by [`source`].
+    ///
+    /// [`source`]: #method.source
+    pub fn core_source(&self) -> Option<image::Image<'a>> {
+        self.json.source
+            .and_then(|index| self.document.get(index))
+    }
+
+    /// Returns `true` if the wrap modes of the texture sampler require an
+    /// image with power-of-two dimensions on OpenGL ES 2.0 class hardware,
+    /// which only supports `ClampToEdge` for other dimensions.
+    ///
+    /// When the `(width, height)` of the image is given and both are powers of
+    /// two, this returns `false`. When the dimensions are unknown, this only
+    /// depends on the wrap modes.
+    pub fn wrap_requires_pot(&self, dimensions: Option<(u32, u32)>) -> bool {
+        let sampler = self.sampler();
+        let clamped = sampler.wrap_s() == WrappingMode::ClampToEdge
+            && sampler.wrap_t() == WrappingMode::ClampToEdge;
+        match dimensions {
+            _ if clamped => false,
+            Some((width, height)) => !width.is_power_of_two() || !height.is_power_of_two(),
+            None => true,
+        }
+    }
+
+    /// Returns the DDS image of the `MSFT_texture_dds` extension.
+    #[cfg(feature = "MSFT_texture_dds")]
+    pub fn dds_source(&self) -> Option<image::Image<'a>> {
+        self.json.dds_source()
+            .and_then(|index| self.document.get(index))
+    }
+
+    /// Returns the WebP image of the `EXT_texture_webp` extension.
+    #[cfg(feature = "EXT_texture_webp")]
+    pub fn webp_source(&self) -> Option<image::Image<'a>> {
+        self.json.webp_source()
+            .and_then(|index| self.document.get(index))
     }
 
     /// Optional application specific data.
-    pub fn extras(&self) -> &json::Extras {
+    pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
     }
 }
@@ -160,21 +766,136 @@ impl<'a> Info<'a> {
     }
 
     /// The set index of the texture's `TEXCOORD` attribute.
+    ///
+    /// Renderers should sample the texture with [`effective_tex_coord`]
+    /// instead, which `KHR_texture_transform` may override.
+    ///
+    /// [`effective_tex_coord`]: #method.effective_tex_coord
     pub fn tex_coord(&self) -> u32 {
         self.json.tex_coord
     }
 
+    /// The set index of the `TEXCOORD` attribute the texture is sampled
+    /// with, which is the `texCoord` of the `KHR_texture_transform`
+    /// extension if it overrides [`tex_coord`].
+    ///
+    /// [`tex_coord`]: #method.tex_coord
+    pub fn effective_tex_coord(&self) -> u32 {
+        self.json.effective_tex_coord()
+    }
+
     /// Returns the referenced `Texture`.
     pub fn texture(&self) -> Texture<'a> {
         self.texture.clone()
     }
 
+    /// Returns the `KHR_texture_transform` extension of the reference.
+    #[cfg(feature = "KHR_texture_transform")]
+    pub fn texture_transform(&self) -> Option<TextureTransform<'a>> {
+        self.transform_json().map(TextureTransform::new)
+    }
+
+    /// Returns the transform of the texture coordinates.
+    pub(crate) fn uv_transform(&self) -> UvTransform {
+        #[cfg(feature = "KHR_texture_transform")]
+        return UvTransform::from_json(self.transform_json());
+        #[cfg(not(feature = "KHR_texture_transform"))]
+        return UvTransform::IDENTITY;
+    }
+
+    #[cfg(feature = "KHR_texture_transform")]
+    fn transform_json(&self) -> Option<&'a json::extensions::texture::TextureTransform> {
+        self.json.extensions.as_ref().and_then(|x| x.texture_transform.as_ref())
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+#[cfg(feature = "KHR_texture_transform")]
+impl<'a> TextureTransform<'a> {
+    /// Constructs a `TextureTransform`.
+    pub(crate) fn new(json: &'a json::extensions::texture::TextureTransform) -> Self {
+        Self { json }
+    }
+
+    /// The offset of the UV coordinate origin as a factor of the texture
+    /// dimensions.
+    pub fn offset(&self) -> [f32; 2] {
+        self.json.offset
+    }
+
+    /// The counter-clockwise rotation of the UV coordinates around their
+    /// origin, in radians.
+    pub fn rotation(&self) -> f32 {
+        self.json.rotation
+    }
+
+    /// The scale factors of the UV coordinates.
+    pub fn scale(&self) -> [f32; 2] {
+        self.json.scale
+    }
+
+    /// The `TEXCOORD` set that overrides the one of the texture reference,
+    /// if any.
+    pub fn tex_coord(&self) -> Option<u32> {
+        self.json.tex_coord
+    }
+
     /// Optional application specific data.
-    pub fn extras(&self) -> &json::Extras {
+    pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
     }
 }
 
+impl UvTransform {
+    /// The transform that leaves coordinates unchanged.
+    pub const IDENTITY: Self = UvTransform {
+        offset: [0.0, 0.0],
+        rotation: 0.0,
+        scale: [1.0, 1.0],
+    };
+
+    /// Returns the transform of a texture reference with the given
+    /// extension.
+    #[cfg(feature = "KHR_texture_transform")]
+    pub(crate) fn from_json(json: Option<&json::extensions::texture::TextureTransform>) -> Self {
+        json.map_or(Self::IDENTITY, |json| UvTransform {
+            offset: json.offset,
+            rotation: json.rotation,
+            scale: json.scale,
+        })
+    }
+
+    /// Returns `true` if the transform leaves coordinates unchanged.
+    pub fn is_identity(&self) -> bool {
+        *self == Self::IDENTITY
+    }
+
+    /// Returns the column-major matrix that transforms homogeneous texture
+    /// coordinates, as in the `KHR_texture_transform` specification.
+    pub fn matrix(&self) -> [[f32; 3]; 3] {
+        let (sin, cos) = self.rotation.sin_cos();
+        let [sx, sy] = self.scale;
+        let [x, y] = self.offset;
+        [[cos * sx, -sin * sx, 0.0], [sin * sy, cos * sy, 0.0], [x, y, 1.0]]
+    }
+
+    /// Transforms texture coordinates.
+    pub fn apply(&self, uv: [f32; 2]) -> [f32; 2] {
+        let m = self.matrix();
+        [m[0][0] * uv[0] + m[1][0] * uv[1] + m[2][0], m[0][1] * uv[0] + m[1][1] * uv[1] + m[2][1]]
+    }
+}
+
+impl Default for UvTransform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
 impl<'a> AsRef<Texture<'a>> for Info<'a> {
     fn as_ref(&self) -> &Texture<'a> {
         &self.texture
@@ -0,0 +1,101 @@
+use crate::{buffer, image, Document, Gltf};
+
+/// Represents the set of URI schemes the importer supports.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Scheme<'a> {
+    /// `data:[<media type>];base64,<data>`.
+    ///
+    /// Contains the optional media type and the base64 encoded data.
+    Data(Option<&'a str>, &'a str),
+
+    /// `file:[//]<absolute file path>`.
+    ///
+    /// Note: The file scheme does not implement authority.
+    File(&'a str),
+
+    /// `../foo`, etc.
+    Relative,
+
+    /// Placeholder for an unsupported URI scheme identifier, such as `http:`.
+    Unsupported,
+}
+
+impl<'a> Scheme<'a> {
+    /// Classifies a URI by its scheme.
+    pub fn parse<'s>(uri: &'s str) -> Scheme<'s> {
+        if !uri.contains(':') {
+            Scheme::Relative
+        } else if let Some(data) = uri.strip_prefix("data:") {
+            let mut parts = data.split(";base64,");
+            match (parts.next(), parts.next()) {
+                (Some(media_type), Some(base64)) => Scheme::Data(Some(media_type), base64),
+                (Some(base64), None) => Scheme::Data(None, base64),
+                _ => Scheme::Unsupported,
+            }
+        } else if let Some(path) = uri.strip_prefix("file://") {
+            Scheme::File(path)
+        } else if let Some(path) = uri.strip_prefix("file:") {
+            Scheme::File(path)
+        } else {
+            Scheme::Unsupported
+        }
+    }
+
+    /// Returns `true` if loading the URI requires access to data outside of
+    /// the glTF asset, which is the case for every scheme except `data:`.
+    pub fn is_external(&self) -> bool {
+        !matches!(*self, Scheme::Data(..))
+    }
+}
+
+/// A reference to data outside of the glTF asset, as returned by
+/// [`Document::external_references`].
+///
+/// [`Document::external_references`]: ../struct.Document.html#method.external_references
+#[derive(Clone, Debug)]
+pub struct Reference<'a> {
+    /// The JSON path of the `uri` property, for example `buffers[0].uri`.
+    pub path: json::Path,
+
+    /// The external URI.
+    pub uri: &'a str,
+}
+
+impl<'a> Reference<'a> {
+    /// Classifies the URI by its scheme.
+    pub fn scheme(&self) -> Scheme<'a> {
+        Scheme::parse(self.uri)
+    }
+}
+
+/// Collects every external URI of a document.
+pub(crate) fn external_references(document: &Document) -> Vec<Reference<'_>> {
+    let mut references = Vec::new();
+    for buffer in document.buffers() {
+        if let buffer::Source::Uri(uri) = buffer.source() {
+            if Scheme::parse(uri).is_external() {
+                let path = json::Path::new().field("buffers").index(buffer.index()).field("uri");
+                references.push(Reference { path, uri });
+            }
+        }
+    }
+    for image in document.images() {
+        if let Some(image::Source::Uri { uri, .. }) = image.source() {
+            if Scheme::parse(uri).is_external() {
+                let path = json::Path::new().field("images").index(image.index()).field("uri");
+                references.push(Reference { path, uri });
+            }
+        }
+    }
+    references
+}
+
+/// Returns `true` if the asset can be loaded without reading any external
+/// data.
+pub(crate) fn is_self_contained(gltf: &Gltf) -> bool {
+    let bin = gltf.document.buffers().all(|buffer| match buffer.source() {
+        buffer::Source::Bin => buffer.index() == 0 && gltf.blob.is_some(),
+        buffer::Source::Uri(_) => true,
+    });
+    bin && external_references(&gltf.document).is_empty()
+}
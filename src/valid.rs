@@ -0,0 +1,60 @@
+use crate::{accessor, buffer, camera, image, material, mesh, scene, skin, texture};
+use crate::Document;
+
+/// A wrapper type that can be looked up by the index of its JSON struct.
+pub(crate) trait FromIndex<'a>: Sized {
+    /// The JSON struct the index refers to.
+    type Json;
+
+    /// Returns the item at `index`, or `None` if it is out of range.
+    fn from_index(document: &'a Document, index: usize) -> Option<Self>;
+}
+
+impl Document {
+    /// Returns the item an index refers to, or `None` if it is out of range.
+    ///
+    /// Validation checks that every index of the document is in range, but
+    /// documents loaded without validation may refer to missing items, so
+    /// the lookup is fallible.
+    pub(crate) fn get<'a, T: FromIndex<'a>>(&'a self, index: json::Index<T::Json>) -> Option<T> {
+        self.get_usize(index.value())
+    }
+
+    /// Returns the item at an index, or `None` if it is out of range.
+    pub(crate) fn get_usize<'a, T: FromIndex<'a>>(&'a self, index: usize) -> Option<T> {
+        T::from_index(self, index)
+    }
+}
+
+macro_rules! from_index {
+    ($wrapper:ty, $json:ty, $field:ident) => {
+        impl<'a> FromIndex<'a> for $wrapper {
+            type Json = $json;
+            fn from_index(document: &'a Document, index: usize) -> Option<Self> {
+                let json = document.0.$field.get(index)?;
+                Some(<$wrapper>::new(document, index, json))
+            }
+        }
+    };
+}
+
+from_index!(accessor::Accessor<'a>, json::Accessor, accessors);
+from_index!(buffer::Buffer<'a>, json::Buffer, buffers);
+from_index!(buffer::View<'a>, json::buffer::View, buffer_views);
+from_index!(camera::Camera<'a>, json::Camera, cameras);
+from_index!(image::Image<'a>, json::Image, images);
+from_index!(material::Material<'a>, json::Material, materials);
+from_index!(mesh::Mesh<'a>, json::Mesh, meshes);
+from_index!(scene::Node<'a>, json::Node, nodes);
+from_index!(texture::Sampler<'a>, json::texture::Sampler, samplers);
+from_index!(skin::Skin<'a>, json::Skin, skins);
+from_index!(texture::Texture<'a>, json::Texture, textures);
+
+#[cfg(feature = "KHR_lights_punctual")]
+impl<'a> FromIndex<'a> for crate::khr_lights_punctual::Light<'a> {
+    type Json = json::extensions::scene::khr_lights_punctual::Light;
+    fn from_index(document: &'a Document, index: usize) -> Option<Self> {
+        let lights = &document.0.extensions.as_ref()?.khr_lights_punctual.as_ref()?.lights;
+        Some(Self::new(document, index, lights.get(index)?))
+    }
+}
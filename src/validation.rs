@@ -0,0 +1,226 @@
+use crate::Result;
+
+pub use json::validation::{Error, Severity, ValidationOptions};
+
+/// An error found during validation, together with the JSON path of the data
+/// it refers to.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Entry {
+    /// The path of the invalid data.
+    pub path: json::Path,
+
+    /// The kind of error.
+    pub error: Error,
+}
+
+impl Entry {
+    /// Returns the severity of the error.
+    pub fn severity(&self) -> Severity {
+        self.error.severity()
+    }
+
+    /// Returns the stable code of the error, such as `"INDEX_OUT_OF_BOUNDS"`.
+    pub fn code(&self) -> &'static str {
+        self.error.code()
+    }
+}
+
+/// Every error found when validating a document, regardless of severity.
+///
+/// Errors suppressed by [`ValidationOptions`] are listed separately and are
+/// ignored by the other methods.
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// use gltf::validation::Severity;
+///
+/// let gltf = gltf::Gltf::open("examples/Box.gltf")?;
+/// let report = gltf.validation_report();
+/// for entry in report.at_least(Severity::Warning) {
+///     println!("{}: {} ({})", entry.path, entry.error, entry.code());
+/// }
+/// report.into_result(Severity::Warning)?;
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #     run().expect("test failure");
+/// # }
+/// ```
+///
+/// [`ValidationOptions`]: struct.ValidationOptions.html
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Report {
+    /// The errors in the order they were found.
+    pub entries: Vec<Entry>,
+
+    /// The suppressed errors in the order they were found.
+    pub suppressed: Vec<Entry>,
+}
+
+impl Report {
+    /// Returns an `Iterator` over the entries whose severity is at least
+    /// `threshold`.
+    pub fn at_least(&self, threshold: Severity) -> impl Iterator<Item = &Entry> + '_ {
+        self.entries.iter().filter(move |entry| entry.severity() >= threshold)
+    }
+
+    /// Returns the number of entries with the given severity.
+    pub fn count(&self, severity: Severity) -> usize {
+        self.entries.iter().filter(|entry| entry.severity() == severity).count()
+    }
+
+    /// Returns `true` if any entry has a severity of at least `threshold`.
+    pub fn fails(&self, threshold: Severity) -> bool {
+        self.at_least(threshold).next().is_some()
+    }
+
+    /// Returns `Error::Validation` with the entries whose severity is at
+    /// least `threshold`, if there are any.
+    ///
+    /// A threshold of `Severity::Error` corresponds to the validation
+    /// performed when loading glTF and `Severity::Warning` to
+    /// [`Document::validate_strict`].
+    ///
+    /// [`Document::validate_strict`]: ../struct.Document.html#method.validate_strict
+    pub fn into_result(self, threshold: Severity) -> Result<()> {
+        let errors = self.entries
+            .into_iter()
+            .filter(|entry| entry.severity() >= threshold)
+            .map(|entry| (entry.path, entry.error))
+            .collect::<Vec<_>>();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(crate::Error::Validation(errors))
+        }
+    }
+}
+
+/// Returns the replacement of a material factor in the range [0.0, 1.0]:
+/// infinities are clamped and NaN is replaced with the default.
+fn factor(value: f32, default: f32) -> f32 {
+    if value.is_nan() {
+        default
+    } else {
+        value.clamp(0.0, 1.0)
+    }
+}
+
+/// Replaces the NaN and infinite numbers of node transforms, material
+/// factors, camera parameters, and accessor bounds with safe defaults.
+///
+/// * Non-finite translation components and morph target weights become 0.0,
+///   scale components become 1.0, and a node whose rotation or matrix has a
+///   non-finite element receives the identity rotation or matrix.
+/// * Material factors are clamped to [0.0, 1.0], with NaN becoming the
+///   default of the factor. A non-finite alpha cutoff or normal scale is
+///   replaced with its default.
+/// * The aspect ratio and far plane of a perspective camera are removed, so
+///   the aspect ratio of the viewport and an infinite projection are used.
+/// * Bounds of float accessors that overflow `f32` become `f32::MAX` or
+///   `f32::MIN`.
+///
+/// Returns the paths of the replaced properties. The remaining camera
+/// parameters have no safe default and are left unchanged.
+pub(crate) fn replace_non_finite(root: &mut json::Root) -> Vec<json::Path> {
+    use json::Path;
+
+    fn finite(values: &[f32]) -> bool {
+        values.iter().all(|x| x.is_finite())
+    }
+
+    let mut replaced = Vec::new();
+    for (i, node) in root.nodes.iter_mut().enumerate() {
+        let path = || Path::new().field("nodes").index(i);
+        if let Some(translation) = node.translation.as_mut().filter(|x| !finite(&x[..])) {
+            translation.iter_mut().filter(|x| !x.is_finite()).for_each(|x| *x = 0.0);
+            replaced.push(path().field("translation"));
+        }
+        if let Some(rotation) = node.rotation.as_mut().filter(|x| !finite(&x.0)) {
+            *rotation = json::scene::UnitQuaternion::default();
+            replaced.push(path().field("rotation"));
+        }
+        if let Some(scale) = node.scale.as_mut().filter(|x| !finite(&x[..])) {
+            scale.iter_mut().filter(|x| !x.is_finite()).for_each(|x| *x = 1.0);
+            replaced.push(path().field("scale"));
+        }
+        if node.matrix.is_some_and(|x| !finite(&x)) {
+            node.matrix = None;
+            replaced.push(path().field("matrix"));
+        }
+        if let Some(weights) = node.weights.as_mut() {
+            for (j, weight) in weights.iter_mut().enumerate().filter(|(_, x)| !x.is_finite()) {
+                *weight = 0.0;
+                replaced.push(path().field("weights").index(j));
+            }
+        }
+    }
+
+    for (i, material) in root.materials.iter_mut().enumerate() {
+        let path = || Path::new().field("materials").index(i);
+        let pbr = &mut material.pbr_metallic_roughness;
+        if !finite(&pbr.base_color_factor.0) {
+            pbr.base_color_factor.0.iter_mut().for_each(|x| *x = factor(*x, 1.0));
+            replaced.push(path().field("pbrMetallicRoughness").field("baseColorFactor"));
+        }
+        for (name, value) in [("metallicFactor", &mut pbr.metallic_factor), ("roughnessFactor", &mut pbr.roughness_factor)] {
+            if !value.0.is_finite() {
+                value.0 = factor(value.0, 1.0);
+                replaced.push(path().field("pbrMetallicRoughness").field(name));
+            }
+        }
+        if !finite(&material.emissive_factor.0) {
+            material.emissive_factor.0.iter_mut().for_each(|x| *x = factor(*x, 0.0));
+            replaced.push(path().field("emissiveFactor"));
+        }
+        if !material.alpha_cutoff.0.is_finite() {
+            material.alpha_cutoff = Default::default();
+            replaced.push(path().field("alphaCutoff"));
+        }
+        if let Some(normal) = material.normal_texture.as_mut().filter(|x| !x.scale.is_finite()) {
+            normal.scale = 1.0;
+            replaced.push(path().field("normalTexture").field("scale"));
+        }
+        if let Some(occlusion) = material.occlusion_texture.as_mut().filter(|x| !x.strength.0.is_finite()) {
+            occlusion.strength.0 = factor(occlusion.strength.0, 1.0);
+            replaced.push(path().field("occlusionTexture").field("strength"));
+        }
+    }
+
+    for (i, camera) in root.cameras.iter_mut().enumerate() {
+        let path = || Path::new().field("cameras").index(i).field("perspective");
+        if let Some(perspective) = camera.perspective.as_mut() {
+            if perspective.aspect_ratio.is_some_and(|x| !x.is_finite()) {
+                perspective.aspect_ratio = None;
+                replaced.push(path().field("aspectRatio"));
+            }
+            if perspective.zfar.is_some_and(|x| !x.is_finite()) {
+                perspective.zfar = None;
+                replaced.push(path().field("zfar"));
+            }
+        }
+    }
+
+    for (i, accessor) in root.accessors.iter_mut().enumerate() {
+        use json::accessor::{ComponentType, GenericComponentType};
+        if !matches!(accessor.component_type, json::validation::Checked::Valid(GenericComponentType(ComponentType::F32))) {
+            continue;
+        }
+        for (name, bound) in [("min", &mut accessor.min), ("max", &mut accessor.max)] {
+            let values = match bound.as_mut().and_then(|x| x.as_array_mut()) {
+                Some(values) => values,
+                None => continue,
+            };
+            for (j, value) in values.iter_mut().enumerate() {
+                let x = match value.as_f64() {
+                    Some(x) if !(x as f32).is_finite() => x,
+                    _ => continue,
+                };
+                let clamped = if x > 0.0 { f32::MAX } else { f32::MIN };
+                *value = json::Value::from(clamped as f64);
+                replaced.push(Path::new().field("accessors").index(i).field(name).index(j));
+            }
+        }
+    }
+    replaced
+}
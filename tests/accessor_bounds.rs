@@ -0,0 +1,72 @@
+use gltf::json::validation::Error;
+
+/// Accessors of three `VEC3` float elements in a view with a stride of 24
+/// bytes, which fit exactly into 60 bytes since the padding after the last
+/// element is not required, and an accessor offset into the same view,
+/// followed by accessors overrunning their views by one byte and a sparse
+/// accessor whose values overrun theirs.
+const BOUNDS: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 72}],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 60, "byteStride": 24},
+        {"buffer": 0, "byteLength": 59, "byteStride": 24},
+        {"buffer": 0, "byteLength": 36},
+        {"buffer": 0, "byteLength": 4},
+        {"buffer": 0, "byteLength": 11}
+    ],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3"},
+        {"bufferView": 0, "byteOffset": 12, "componentType": 5126, "count": 2, "type": "VEC3"},
+        {"bufferView": 1, "componentType": 5126, "count": 3, "type": "VEC3"},
+        {"bufferView": 2, "byteOffset": 1, "componentType": 5126, "count": 3, "type": "VEC3"},
+        {"componentType": 5126, "count": 3, "type": "VEC3", "sparse": {
+            "count": 1,
+            "indices": {"bufferView": 3, "componentType": 5125},
+            "values": {"bufferView": 4, "componentType": 5126}
+        }}
+    ]
+}"#;
+
+fn data() -> Vec<u8> {
+    (0..18u8).flat_map(|x| f32::from(x).to_le_bytes().to_vec()).collect()
+}
+
+#[test]
+fn test_validation() {
+    let errors = match gltf::Gltf::from_slice(BOUNDS.as_bytes()).err() {
+        Some(gltf::Error::Validation(errors)) => errors,
+        _ => panic!("expected validation errors"),
+    };
+    let errors = errors.into_iter().map(|(path, error)| (path.to_string(), error)).collect::<Vec<_>>();
+    assert_eq!(
+        errors,
+        vec![
+            ("accessors[2]".to_string(), Error::AccessorTooLong { required: 60, available: 59 }),
+            ("accessors[3]".to_string(), Error::AccessorTooLong { required: 37, available: 36 }),
+            ("accessors[4].sparse.values".to_string(), Error::AccessorTooLong { required: 12, available: 11 }),
+        ],
+    );
+}
+
+#[test]
+fn test_required_view_length() {
+    let gltf = gltf::Gltf::from_slice_without_validation(BOUNDS.as_bytes()).unwrap();
+    let lengths = gltf.accessors().map(|x| x.required_view_length()).collect::<Vec<_>>();
+    assert_eq!(lengths, vec![Some(60), Some(48), Some(60), Some(37), None]);
+}
+
+#[cfg(feature = "utils")]
+#[test]
+fn test_reader_bounds() {
+    let gltf = gltf::Gltf::from_slice_without_validation(BOUNDS.as_bytes()).unwrap();
+    let data = data();
+    let read = |index: usize| {
+        let accessor = gltf.accessors().nth(index).unwrap();
+        gltf::accessor::Iter::<[f32; 3]>::new(accessor, |_| Some(&data)).map(|x| x.collect::<Vec<_>>())
+    };
+    assert_eq!(read(0), Some(vec![[0.0, 1.0, 2.0], [6.0, 7.0, 8.0], [12.0, 13.0, 14.0]]));
+    assert_eq!(read(1), Some(vec![[3.0, 4.0, 5.0], [9.0, 10.0, 11.0]]));
+    assert_eq!(read(2), None);
+    assert_eq!(read(3), None);
+}
@@ -0,0 +1,91 @@
+#![cfg(feature = "utils")]
+
+use gltf::accessor;
+
+/// A textured triangle sampled with the given wrapping modes, with a normalized
+/// byte accessor and an indices accessor.
+fn document(wrap_s: u32, wrap_t: u32) -> String {
+    format!(r#"{{
+        "asset": {{"version": "2.0"}},
+        "buffers": [{{"byteLength": 72}}],
+        "bufferViews": [{{"buffer": 0, "byteLength": 72}}],
+        "accessors": [
+            {{"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3"}},
+            {{"bufferView": 0, "byteOffset": 36, "componentType": 5126, "count": 3, "type": "VEC2"}},
+            {{"bufferView": 0, "byteOffset": 60, "componentType": 5121, "normalized": true, "count": 3, "type": "SCALAR"}},
+            {{"bufferView": 0, "byteOffset": 64, "componentType": 5123, "count": 3, "type": "SCALAR"}}
+        ],
+        "images": [{{"uri": "texture.png"}}],
+        "samplers": [{{"wrapS": {}, "wrapT": {}}}],
+        "textures": [{{"source": 0, "sampler": 0}}],
+        "materials": [{{"pbrMetallicRoughness": {{"baseColorTexture": {{"index": 0}}}}}}],
+        "meshes": [{{"primitives": [{{"attributes": {{"POSITION": 0, "TEXCOORD_0": 1}}, "indices": 3, "material": 0}}]}}]
+    }}"#, wrap_s, wrap_t)
+}
+
+fn data() -> Vec<u8> {
+    let mut data = Vec::new();
+    let floats = [
+        0.0f32, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 2.0, 0.0,
+        0.0, 0.0, 1.5, 0.5, 0.5, -0.25,
+    ];
+    for value in &floats {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+    data.extend_from_slice(&[0, 255, 51, 0]);
+    data.extend_from_slice(&[0, 0, 1, 0, 2, 0, 0, 0]);
+    data
+}
+
+const REPEAT: u32 = 10497;
+const CLAMP_TO_EDGE: u32 = 33071;
+
+#[test]
+fn test_stats() {
+    let data = data();
+    let gltf = gltf::Gltf::from_slice_without_validation(document(REPEAT, REPEAT).as_bytes()).unwrap();
+    let accessors = gltf.accessors().collect::<Vec<_>>();
+    let get = |_| Some(&data[..]);
+
+    let positions = accessor::stats(&accessors[0], get, 0.0..=1.0).unwrap();
+    assert_eq!(positions.count, 3);
+    assert_eq!(positions.min, vec![0.0, 0.0, 0.0]);
+    assert_eq!(positions.max, vec![1.0, 2.0, 0.0]);
+    assert_eq!(positions.mean, vec![1.0 / 3.0, 2.0 / 3.0, 0.0]);
+    assert_eq!(positions.out_of_range, 1);
+
+    let normalized = accessor::stats(&accessors[2], get, 0.0..=1.0).unwrap();
+    assert_eq!((normalized.min, normalized.max), (vec![0.0], vec![1.0]));
+    assert_eq!(normalized.mean, vec![0.4]);
+    assert_eq!(normalized.out_of_range, 0);
+
+    // Indices are not normalized, so their values are kept.
+    let indices = accessor::stats(&accessors[3], get, 0.0..=1.0).unwrap();
+    assert_eq!((indices.min, indices.max), (vec![0.0], vec![2.0]));
+    assert_eq!(indices.out_of_range, 1);
+}
+
+#[test]
+fn test_stats_unavailable() {
+    let gltf = gltf::Gltf::from_slice_without_validation(document(REPEAT, REPEAT).as_bytes()).unwrap();
+    let accessor = gltf.accessors().next().unwrap();
+    let result = accessor::stats(&accessor, |_| None, 0.0..=1.0);
+    assert!(matches!(result, Err(accessor::ReadError::Unavailable)));
+}
+
+#[test]
+fn test_uv_out_of_unit_range_count() {
+    let data = data();
+    let variants = [
+        (REPEAT, REPEAT, 0),
+        (CLAMP_TO_EDGE, REPEAT, 1),
+        (REPEAT, CLAMP_TO_EDGE, 1),
+        (CLAMP_TO_EDGE, CLAMP_TO_EDGE, 2),
+    ];
+    for &(wrap_s, wrap_t, expected) in &variants {
+        let gltf = gltf::Gltf::from_slice_without_validation(document(wrap_s, wrap_t).as_bytes()).unwrap();
+        let primitive = gltf.meshes().next().unwrap().primitives().next().unwrap();
+        let count = primitive.uv_out_of_unit_range_count(|_| Some(&data[..])).unwrap();
+        assert_eq!(count, expected, "wrapS {} wrapT {}", wrap_s, wrap_t);
+    }
+}
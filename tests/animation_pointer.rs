@@ -0,0 +1,152 @@
+#![cfg(feature = "KHR_animation_pointer")]
+
+use gltf::animation::pointer::{MaterialProperty, Pointer};
+use gltf::animation::util::{PointerOutputs, ReadOutputs};
+use gltf::animation::{Property, TargetKind};
+use gltf::json::validation::Error;
+
+const ANIMATION_POINTER: &str = r#"{
+    "asset": {"version": "2.0"},
+    "extensionsUsed": ["KHR_animation_pointer"],
+    "buffers": [{"byteLength": 40}],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 8},
+        {"buffer": 0, "byteOffset": 8, "byteLength": 32}
+    ],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 2, "type": "SCALAR"},
+        {"bufferView": 1, "componentType": 5126, "count": 2, "type": "VEC4"},
+        {"bufferView": 0, "componentType": 5126, "count": 2, "type": "SCALAR"}
+    ],
+    "materials": [{}],
+    "nodes": [{}],
+    "animations": [{
+        "samplers": [
+            {"input": 0, "output": 1},
+            {"input": 0, "output": 2}
+        ],
+        "channels": [
+            {"sampler": 0, "target": {
+                "path": "pointer",
+                "extensions": {"KHR_animation_pointer": {
+                    "pointer": "/materials/0/pbrMetallicRoughness/baseColorFactor"
+                }}
+            }},
+            {"sampler": 1, "target": {
+                "path": "pointer",
+                "extensions": {"KHR_animation_pointer": {
+                    "pointer": "/cameras/0/perspective/yfov"
+                }}
+            }},
+            {"sampler": 1, "target": {"node": 0, "path": "weights"}}
+        ]
+    }]
+}"#;
+
+#[test]
+fn test_pointer_targets() {
+    let gltf = gltf::Gltf::from_slice(ANIMATION_POINTER.as_bytes()).unwrap();
+    let animation = gltf.animations().next().unwrap();
+    let kinds = animation
+        .channels()
+        .map(|channel| match channel.target().kind().unwrap() {
+            TargetKind::Node { node, property } => (Some(node.index()), property, None),
+            TargetKind::Pointer(pointer) => (None, Property::Pointer, Some(Pointer::parse(pointer))),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        kinds,
+        vec![
+            (
+                None,
+                Property::Pointer,
+                Some(Pointer::Material { material: 0, property: MaterialProperty::BaseColorFactor }),
+            ),
+            (None, Property::Pointer, Some(Pointer::Unsupported("/cameras/0/perspective/yfov"))),
+            (Some(0), Property::MorphTargetWeights, None),
+        ]
+    );
+    assert_eq!(animation.target_nodes().count(), 1);
+    let nodes = animation.channels().map(|x| x.target().node().map(|x| x.index())).collect::<Vec<_>>();
+    assert_eq!(nodes, vec![None, None, Some(0)]);
+
+    let mut data = Vec::new();
+    for value in &[0.0f32, 1.0, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8] {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+    let mut channels = animation.channels();
+    let reader = channels.next().unwrap().reader(|_| Some(&data[..]));
+    match reader.read_outputs() {
+        Some(ReadOutputs::Pointer(PointerOutputs::Vec4(iter))) => {
+            assert_eq!(iter.collect::<Vec<_>>(), vec![[0.1, 0.2, 0.3, 0.4], [0.5, 0.6, 0.7, 0.8]]);
+        },
+        _ => panic!("expected vec4 outputs"),
+    }
+    let reader = channels.next().unwrap().reader(|_| Some(&data[..]));
+    match reader.read_outputs() {
+        Some(ReadOutputs::Pointer(PointerOutputs::Scalar(iter))) => {
+            assert_eq!(iter.collect::<Vec<_>>(), vec![0.0, 1.0]);
+        },
+        _ => panic!("expected scalar outputs"),
+    }
+}
+
+#[test]
+fn test_pointer_roundtrip() {
+    let gltf = gltf::Gltf::from_slice(ANIMATION_POINTER.as_bytes()).unwrap();
+    let json = gltf.document.into_json().to_string().unwrap();
+    assert!(json.contains(r#""path":"pointer""#));
+    assert!(json.contains(r#""pointer":"/cameras/0/perspective/yfov""#));
+    assert!(gltf::Gltf::from_slice(json.as_bytes()).is_ok());
+}
+
+#[test]
+fn test_pointer_validation() {
+    let json = ANIMATION_POINTER
+        .replace(r#""extensions": {"KHR_animation_pointer": {
+                    "pointer": "/cameras/0/perspective/yfov"
+                }}"#, r#""extensions": {}"#)
+        .replace(r#"{"node": 0, "path": "weights"}"#, r#"{"path": "weights"}"#);
+    match gltf::Gltf::from_slice(json.as_bytes()) {
+        Err(gltf::Error::Validation(errors)) => {
+            let errors = errors
+                .into_iter()
+                .map(|(path, error)| (path.0, error))
+                .collect::<Vec<_>>();
+            assert_eq!(
+                errors,
+                vec![
+                    (
+                        "animations[0].channels[1].target.extensions.KHR_animation_pointer".to_string(),
+                        Error::Missing,
+                    ),
+                    ("animations[0].channels[2].target.node".to_string(), Error::Missing),
+                ]
+            );
+        },
+        _ => panic!("expected validation errors"),
+    }
+}
+
+#[test]
+fn test_pointer_node_animations() {
+    let json = ANIMATION_POINTER
+        .replace(r#""nodes": [{}]"#, r#""nodes": [{}, {}, {}]"#)
+        .replace("/cameras/0/perspective/yfov", "/nodes/1/weights");
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    let nodes = gltf.nodes().collect::<Vec<_>>();
+    let channels = nodes[1]
+        .animations()
+        .map(|(_, channel)| match channel.target().kind() {
+            Some(TargetKind::Pointer(pointer)) => Pointer::parse(pointer),
+            _ => panic!("expected a pointer target"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(channels, vec![Pointer::Node { node: 1, property: Property::MorphTargetWeights }]);
+    assert!(nodes[0].is_animated());
+    assert!(!nodes[2].is_animated());
+
+    let animation = gltf.animations().next().unwrap();
+    let targets = animation.target_nodes().map(|x| x.index()).collect::<Vec<_>>();
+    assert_eq!(targets, vec![0, 1]);
+}
@@ -0,0 +1,120 @@
+#![cfg(feature = "utils")]
+
+use gltf::animation::util::{ChannelData, Values};
+use gltf::animation::{self, Interpolation, SampledPose};
+
+/// Two animations over one second: the first moves node 0 along X and turns
+/// node 1 by 90 degrees about Z, the second holds node 0 at `[0, 4, 0]`.
+const ANIMATIONS: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 88}],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 8},
+        {"buffer": 0, "byteOffset": 8, "byteLength": 24},
+        {"buffer": 0, "byteOffset": 32, "byteLength": 32},
+        {"buffer": 0, "byteOffset": 64, "byteLength": 24}
+    ],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 2, "type": "SCALAR", "min": [0], "max": [1]},
+        {"bufferView": 1, "componentType": 5126, "count": 2, "type": "VEC3"},
+        {"bufferView": 2, "componentType": 5126, "count": 2, "type": "VEC4"},
+        {"bufferView": 3, "componentType": 5126, "count": 2, "type": "VEC3"}
+    ],
+    "nodes": [{}, {"translation": [1, 0, 0]}],
+    "animations": [
+        {
+            "channels": [
+                {"sampler": 0, "target": {"node": 0, "path": "translation"}},
+                {"sampler": 1, "target": {"node": 1, "path": "rotation"}}
+            ],
+            "samplers": [{"input": 0, "output": 1}, {"input": 0, "output": 2}]
+        },
+        {
+            "channels": [{"sampler": 0, "target": {"node": 0, "path": "translation"}}],
+            "samplers": [{"input": 0, "output": 3}]
+        }
+    ]
+}"#;
+
+fn buffer_data() -> Vec<u8> {
+    let half = std::f32::consts::FRAC_1_SQRT_2;
+    let floats = [
+        0.0, 1.0, // inputs
+        0.0, 0.0, 0.0, 2.0, 0.0, 0.0, // translations
+        0.0, 0.0, 0.0, 1.0, 0.0, 0.0, half, half, // rotations
+        0.0, 4.0, 0.0, 0.0, 4.0, 0.0, // held translations
+    ];
+    floats.iter().flat_map(|x: &f32| x.to_le_bytes().to_vec()).collect()
+}
+
+fn assert_near(actual: &[f32], expected: &[f32]) {
+    assert_eq!(actual.len(), expected.len());
+    for (a, b) in actual.iter().zip(expected) {
+        assert!((a - b).abs() < 1.0e-5, "{:?} != {:?}", actual, expected);
+    }
+}
+
+#[test]
+fn test_sample_pose() {
+    let gltf = gltf::Gltf::from_slice(ANIMATIONS.as_bytes()).unwrap();
+    let data = buffer_data();
+    let animation = gltf.animations().next().unwrap();
+    let pose = SampledPose::sample(&animation, 0.5, |_| Some(&data));
+    assert_eq!(pose.transforms.keys().copied().collect::<Vec<_>>(), vec![0, 1]);
+    assert_near(&pose.transforms[&0].translation, &[1.0, 0.0, 0.0]);
+
+    // The translation of node 1 is not animated and keeps its rest value.
+    let sin = (std::f32::consts::PI / 8.0).sin();
+    let node = &pose.transforms[&1];
+    assert_near(&node.translation, &[1.0, 0.0, 0.0]);
+    assert_near(&node.rotation, &[0.0, 0.0, sin, (std::f32::consts::PI / 8.0).cos()]);
+    assert_near(&pose.rest[&1].rotation, &[0.0, 0.0, 0.0, 1.0]);
+
+    // Times outside of the keyframes are clamped.
+    let pose = SampledPose::sample(&animation, 2.0, |_| Some(&data));
+    assert_near(&pose.transforms[&0].translation, &[2.0, 0.0, 0.0]);
+
+    let pose = SampledPose::sample(&animation, 0.5, |_| None);
+    assert!(pose.transforms.is_empty());
+}
+
+#[test]
+fn test_blend() {
+    let gltf = gltf::Gltf::from_slice(ANIMATIONS.as_bytes()).unwrap();
+    let data = buffer_data();
+    let animations = gltf.animations().collect::<Vec<_>>();
+    let a = SampledPose::sample(&animations[0], 1.0, |_| Some(&data));
+    let b = SampledPose::sample(&animations[1], 1.0, |_| Some(&data));
+
+    assert_eq!(animation::blend(&a, &b, 0.0).transforms, a.transforms);
+    let blended = animation::blend(&a, &b, 0.5);
+    assert_near(&blended.transforms[&0].translation, &[1.0, 2.0, 0.0]);
+
+    // Node 1 is not animated by `b` and eases back to its rest pose.
+    let sin = (std::f32::consts::PI / 8.0).sin();
+    assert_near(&blended.transforms[&1].rotation, &[0.0, 0.0, sin, (std::f32::consts::PI / 8.0).cos()]);
+    let end = animation::blend(&a, &b, 1.0);
+    assert_near(&end.transforms[&1].rotation, &[0.0, 0.0, 0.0, 1.0]);
+    assert_near(&end.transforms[&0].translation, &[0.0, 4.0, 0.0]);
+}
+
+#[test]
+fn test_sample_interpolations() {
+    let step = ChannelData {
+        interpolation: Interpolation::Step,
+        inputs: vec![0.0, 1.0],
+        values: Values::Scales(vec![[1.0; 3], [2.0; 3]]),
+    };
+    assert_near(&step.sample(0.99), &[1.0; 3]);
+    assert_near(&step.sample(1.0), &[2.0; 3]);
+
+    // A cubic spline with zero tangents eases in and out.
+    let cubic = ChannelData {
+        interpolation: Interpolation::CubicSpline,
+        inputs: vec![0.0, 2.0],
+        values: Values::Translations(vec![[0.0; 3], [0.0; 3], [0.0; 3], [0.0; 3], [4.0; 3], [0.0; 3]]),
+    };
+    assert_near(&cubic.sample(1.0), &[2.0; 3]);
+    assert_near(&cubic.sample(0.5), &[0.625; 3]);
+    assert!(ChannelData { inputs: vec![], values: Values::Scales(vec![]), ..step }.sample(0.0).is_empty());
+}
@@ -0,0 +1,73 @@
+#![cfg(all(feature = "import", feature = "utils"))]
+
+use gltf::Error;
+
+/// A primitive whose positions span the whole 36 byte buffer, and a skin and
+/// an animation reading the same buffer.
+const PRIMITIVE: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 36}],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 36},
+        {"buffer": 0, "byteLength": 16}
+    ],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0]},
+        {"bufferView": 1, "componentType": 5126, "count": 1, "type": "SCALAR", "min": [0], "max": [0]},
+        {"bufferView": 1, "componentType": 5126, "count": 1, "type": "VEC3", "byteOffset": 4}
+    ],
+    "meshes": [{"primitives": [{"attributes": {"POSITION": 0}}]}],
+    "nodes": [{"mesh": 0}],
+    "animations": [{
+        "channels": [{"sampler": 0, "target": {"node": 0, "path": "translation"}}],
+        "samplers": [{"input": 1, "output": 2}]
+    }]
+}"#;
+
+fn truncated(error: Option<Error>) -> Option<(usize, usize, usize)> {
+    match error {
+        Some(Error::BufferLength { buffer, expected, actual }) => Some((buffer, expected, actual)),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_try_reader_reports_short_data() {
+    let gltf = gltf::Gltf::from_slice(PRIMITIVE.as_bytes()).unwrap();
+    let primitive = gltf.meshes().next().unwrap().primitives().next().unwrap();
+    let data = vec![0; 36];
+    assert_eq!(truncated(primitive.try_reader(|_| Ok(&data[..30])).err()), Some((0, 36, 30)));
+    let reader = primitive.try_reader(|_| Ok(&data)).unwrap();
+    assert_eq!(reader.read_positions().unwrap().count(), 3);
+
+    let channel = gltf.animations().next().unwrap().channels().next().unwrap();
+    assert_eq!(truncated(channel.try_reader(|_| Ok(&data[..4])).err()), Some((0, 36, 4)));
+    assert!(channel.try_reader(|_| Ok(&data)).unwrap().read_inputs().is_some());
+
+    // Errors of the provider are passed on.
+    let error = primitive.try_reader(|_| Err(Error::MissingBlob { buffer: 0 })).err();
+    assert!(matches!(error, Some(Error::MissingBlob { buffer: 0 })));
+}
+
+#[test]
+fn test_padding_is_accepted() {
+    let gltf = gltf::Gltf::from_slice(PRIMITIVE.as_bytes()).unwrap();
+    let buffer = gltf.buffers().next().unwrap();
+    let padded = gltf::buffer::Data::from(vec![0; 39]);
+    assert!(padded.validate_against(&buffer).is_ok());
+    let short = gltf::buffer::Data::from(vec![0; 35]);
+    assert_eq!(truncated(short.validate_against(&buffer).err()), Some((0, 36, 35)));
+}
+
+#[test]
+fn test_import_reports_short_data() {
+    let json = PRIMITIVE.replace(
+        r#"{"byteLength": 36}"#,
+        r#"{"byteLength": 36, "uri": "data:application/octet-stream;base64,AAAAAAAAAAA="}"#,
+    );
+    let path = std::env::temp_dir().join(format!("gltf_buffer_length_{}.gltf", std::process::id()));
+    std::fs::write(&path, json).unwrap();
+    let error = gltf::import(&path).err();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(truncated(error), Some((0, 36, 8)));
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "utils")]
+
+/// A triangle with normalized `u8` RGB colors in `COLOR_0` and float RGB
+/// colors in `COLOR_1`.
+const COLORS: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 84}],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 36},
+        {"buffer": 0, "byteOffset": 36, "byteLength": 12, "byteStride": 4},
+        {"buffer": 0, "byteOffset": 48, "byteLength": 36}
+    ],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0]},
+        {"bufferView": 1, "componentType": 5121, "normalized": true, "count": 3, "type": "VEC3"},
+        {"bufferView": 2, "componentType": 5126, "count": 3, "type": "VEC3"}
+    ],
+    "meshes": [{"primitives": [{"attributes": {"POSITION": 0, "COLOR_0": 1, "COLOR_1": 2}}]}]
+}"#;
+
+const BYTES: [[u8; 3]; 3] = [[255, 128, 1], [0, 64, 200], [127, 254, 3]];
+
+const FLOATS: [[f32; 3]; 3] = [[0.5, 0.2, 1.0], [0.002, -0.5, 2.0], [0.998, 0.0, 0.75]];
+
+fn buffer() -> Vec<u8> {
+    let mut data = vec![0; 36];
+    for color in &BYTES {
+        data.extend_from_slice(color);
+        data.push(0);
+    }
+    for x in FLOATS.iter().flatten() {
+        data.extend_from_slice(&x.to_le_bytes());
+    }
+    data
+}
+
+#[test]
+fn test_u8_rgb_colors() {
+    let gltf = gltf::Gltf::from_slice(COLORS.as_bytes()).unwrap();
+    let data = buffer();
+    let primitive = gltf.meshes().next().unwrap().primitives().next().unwrap();
+    let reader = primitive.reader(|_| Some(&data[..]));
+
+    match reader.read_colors(0).unwrap() {
+        gltf::mesh::util::ReadColors::RgbU8(_) => {},
+        colors => panic!("unexpected colors: {:?}", colors),
+    }
+    let rgba = reader.read_colors(0).unwrap().into_rgba_u8().collect::<Vec<_>>();
+    assert_eq!(rgba, vec![[255, 128, 1, 255], [0, 64, 200, 255], [127, 254, 3, 255]]);
+    let rgba = reader.read_colors(0).unwrap().into_rgba_f32().collect::<Vec<_>>();
+    assert_eq!(rgba[0], [1.0, 128.0 / 255.0, 1.0 / 255.0, 1.0]);
+    assert_eq!(rgba[1], [0.0, 64.0 / 255.0, 200.0 / 255.0, 1.0]);
+    let rgba = reader.read_colors(0).unwrap().into_rgba_u16().collect::<Vec<_>>();
+    assert_eq!(rgba[0], [65535, 32896, 257, 65535]);
+
+    // Converting to `u16` and back is lossless.
+    let rgb = reader.read_colors(0).unwrap().into_rgb_u16().collect::<Vec<_>>();
+    for (color, expected) in rgb.iter().zip(&BYTES) {
+        let bytes = color.iter().map(|&x| ((x as u32 + 0x80) / 0x101) as u8).collect::<Vec<_>>();
+        assert_eq!(&bytes[..], &expected[..]);
+    }
+}
+
+#[test]
+fn test_f32_rgb_colors() {
+    let gltf = gltf::Gltf::from_slice(COLORS.as_bytes()).unwrap();
+    let data = buffer();
+    let primitive = gltf.meshes().next().unwrap().primitives().next().unwrap();
+    let reader = primitive.reader(|_| Some(&data[..]));
+
+    // Components are rounded to the nearest value and clamped to [0, 1].
+    let rgba = reader.read_colors(1).unwrap().into_rgba_u8().collect::<Vec<_>>();
+    assert_eq!(rgba, vec![[128, 51, 255, 255], [1, 0, 255, 255], [254, 0, 191, 255]]);
+    let rgba = reader.read_colors(1).unwrap().into_rgba_u16().collect::<Vec<_>>();
+    assert_eq!(rgba[0], [32768, 13107, 65535, 65535]);
+    let rgba = reader.read_colors(1).unwrap().into_rgba_f32().collect::<Vec<_>>();
+    assert_eq!(rgba[2], [0.998, 0.0, 0.75, 1.0]);
+}
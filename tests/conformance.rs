@@ -0,0 +1,284 @@
+//! Conformance test.
+//!
+//! Import every variant of every sample model, validate it, and run the
+//! readers over every primitive, skin, and animation channel. The number of
+//! objects and items read is compared with the counts recorded in
+//! `tests/conformance_snapshot.txt`.
+//!
+//! The sample model test requires the `glTF-Sample-Models` submodule and is
+//! ignored by default. Run it with `cargo test --test conformance -- --ignored`.
+//! Assets without an entry in the snapshot fail. Set `GLTF_UPDATE_SNAPSHOT=1`
+//! to record new counts for every asset instead of comparing, and check in the
+//! updated snapshot.
+//!
+//! Validation failures are reported with the JSON path of each error.
+
+#![cfg(all(feature = "import", feature = "utils"))]
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::{env, fmt, fs, panic};
+
+const SAMPLE_MODELS_DIRECTORY_PATH: &str = "glTF-Sample-Models/2.0";
+const SNAPSHOT_PATH: &str = "tests/conformance_snapshot.txt";
+
+/// Sample model variants as pairs of directory name and file extension.
+const VARIANTS: &[(&str, &str)] = &[
+    ("glTF", "gltf"),
+    ("glTF-Binary", "glb"),
+    ("glTF-Embedded", "gltf"),
+    ("glTF-Draco", "gltf"),
+];
+
+/// Counts of the objects visited and the items read from an asset.
+#[derive(Default)]
+struct Counts {
+    nodes: usize,
+    meshes: usize,
+    primitives: usize,
+    vertices: usize,
+    indices: usize,
+    morph_targets: usize,
+    skins: usize,
+    joints: usize,
+    animations: usize,
+    channels: usize,
+    keyframes: usize,
+}
+
+/// The outcome of exercising an asset.
+enum Outcome {
+    /// The asset was read completely.
+    Read(Counts),
+
+    /// The asset requires an extension the crate does not support.
+    Unsupported(String),
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Outcome::Read(ref x) => write!(
+                f,
+                "nodes={} meshes={} primitives={} vertices={} indices={} morph_targets={} \
+                 skins={} joints={} animations={} channels={} keyframes={}",
+                x.nodes,
+                x.meshes,
+                x.primitives,
+                x.vertices,
+                x.indices,
+                x.morph_targets,
+                x.skins,
+                x.joints,
+                x.animations,
+                x.channels,
+                x.keyframes,
+            ),
+            Outcome::Unsupported(ref extension) => write!(f, "unsupported={}", extension),
+        }
+    }
+}
+
+/// Describes an error, listing the JSON path of each validation error on a
+/// line of its own.
+fn describe(err: gltf::Error) -> String {
+    match err {
+        gltf::Error::Validation(errors) => {
+            let lines = errors.iter().map(|(path, error)| format!("\n  {}: {} ({})", path, error, error.code()));
+            format!("validation failed:{}", lines.collect::<String>())
+        },
+        err => err.to_string(),
+    }
+}
+
+fn exercise(path: &Path) -> Result<Outcome, String> {
+    // Validation rejects unsupported required extensions, so they are looked
+    // up before loading.
+    let data = fs::read(path).map_err(|err| err.to_string())?;
+    let gltf = gltf::Gltf::from_slice_without_validation(&data).map_err(describe)?;
+    let unsupported = gltf
+        .extensions_required()
+        .find(|x| !gltf::supported_extensions().contains(x));
+    if let Some(extension) = unsupported {
+        return Ok(Outcome::Unsupported(extension.to_string()));
+    }
+
+    let (document, buffers, _) = gltf::import(path).map_err(describe)?;
+    let mut counts = Counts {
+        nodes: document.nodes().count(),
+        meshes: document.meshes().count(),
+        skins: document.skins().count(),
+        animations: document.animations().count(),
+        ..Default::default()
+    };
+
+    for mesh in document.meshes() {
+        for primitive in mesh.primitives() {
+            let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+            let path = format!("meshes[{}].primitives[{}]", mesh.index(), primitive.index());
+            counts.primitives += 1;
+            let positions = reader
+                .read_positions()
+                .ok_or_else(|| format!("{}: missing POSITION", path))?;
+            counts.vertices += positions.count();
+            if let Some(iter) = reader.read_indices() {
+                let vertices = primitive.get(&gltf::Semantic::Positions).unwrap().count();
+                for index in iter.into_u32() {
+                    if index as usize >= vertices {
+                        return Err(format!("{}.indices: index {} out of range", path, index));
+                    }
+                    counts.indices += 1;
+                }
+            }
+            let _ = reader.read_normals().map(Iterator::count);
+            let _ = reader.read_tangents().map(Iterator::count);
+            for set in 0..4 {
+                let _ = reader.read_colors(set).map(|x| x.into_rgba_f32().count());
+                let _ = reader.read_tex_coords(set).map(|x| x.into_f32().count());
+                let _ = reader.read_joints(set).map(|x| x.into_u16().count());
+                let _ = reader.read_weights(set).map(|x| x.into_f32().count());
+            }
+            for (positions, normals, tangents) in reader.read_morph_targets() {
+                let _ = positions.map(Iterator::count);
+                let _ = normals.map(Iterator::count);
+                let _ = tangents.map(Iterator::count);
+                counts.morph_targets += 1;
+            }
+        }
+    }
+
+    for skin in document.skins() {
+        let reader = skin.reader(|buffer| Some(&buffers[buffer.index()]));
+        let _ = reader.read_inverse_bind_matrices().map(Iterator::count);
+        counts.joints += skin.joints().count();
+    }
+
+    for animation in document.animations() {
+        for channel in animation.channels() {
+            let reader = channel.reader(|buffer| Some(&buffers[buffer.index()]));
+            let path = format!("animations[{}].channels[{}]", animation.index(), counts.channels);
+            counts.channels += 1;
+            counts.keyframes += reader
+                .read_inputs()
+                .ok_or_else(|| format!("{}: missing sampler input", path))?
+                .count();
+            let _ = match reader
+                .read_outputs()
+                .ok_or_else(|| format!("{}: missing sampler output", path))?
+            {
+                gltf::animation::util::ReadOutputs::Translations(iter) => iter.count(),
+                gltf::animation::util::ReadOutputs::Rotations(iter) => iter.into_f32().count(),
+                gltf::animation::util::ReadOutputs::Scales(iter) => iter.count(),
+                gltf::animation::util::ReadOutputs::MorphTargetWeights(iter) => {
+                    iter.into_f32().count()
+                },
+                #[cfg(feature = "KHR_animation_pointer")]
+                gltf::animation::util::ReadOutputs::Pointer(_) => 0,
+            };
+        }
+    }
+
+    Ok(Outcome::Read(counts))
+}
+
+fn load_snapshot() -> BTreeMap<String, String> {
+    fs::read_to_string(SNAPSHOT_PATH)
+        .unwrap_or_default()
+        .lines()
+        .filter(|line| !line.starts_with('#') && !line.trim().is_empty())
+        .filter_map(|line| {
+            let mut split = line.splitn(2, ": ");
+            Some((split.next()?.to_string(), split.next()?.to_string()))
+        })
+        .collect()
+}
+
+fn save_snapshot(snapshot: &BTreeMap<String, String>) {
+    let mut contents = String::from(
+        "# Expected conformance test counts.\n\
+         # Regenerate with `GLTF_UPDATE_SNAPSHOT=1 cargo test --test conformance -- --include-ignored`.\n",
+    );
+    for (name, counts) in snapshot {
+        contents.push_str(&format!("{}: {}\n", name, counts));
+    }
+    fs::write(SNAPSHOT_PATH, contents).expect("failed to write snapshot");
+}
+
+/// Exercises every asset and compares the results with the snapshot.
+///
+/// Assets without an entry in the snapshot fail, unless the snapshot is being
+/// updated.
+fn check(assets: Vec<(String, PathBuf)>) {
+    let update = env::var_os("GLTF_UPDATE_SNAPSHOT").is_some();
+    let mut snapshot = load_snapshot();
+    let mut failures = Vec::new();
+    for (name, path) in assets {
+        let result = panic::catch_unwind(|| exercise(&path))
+            .unwrap_or_else(|_| Err("panicked".to_string()));
+        match result {
+            Ok(outcome) => {
+                let actual = outcome.to_string();
+                if update {
+                    snapshot.insert(name, actual);
+                } else {
+                    match snapshot.get(&name) {
+                        Some(expected) if *expected == actual => println!("{}: ok", name),
+                        Some(expected) => failures.push(format!(
+                            "{}: expected {} but found {}",
+                            name, expected, actual
+                        )),
+                        None => failures.push(format!("{}: no snapshot entry", name)),
+                    }
+                }
+            },
+            Err(err) => failures.push(format!("{}: {}", name, err)),
+        }
+    }
+    if update {
+        save_snapshot(&snapshot);
+    }
+    assert!(failures.is_empty(), "conformance failures:\n{}", failures.join("\n"));
+}
+
+#[test]
+fn local_fixtures() {
+    let assets = [
+        "examples/Box.gltf",
+        "examples/Box.glb",
+        "tests/box_sparse.gltf",
+        "tests/box_sparse.glb",
+    ];
+    check(assets.iter().map(|x| (x.to_string(), PathBuf::from(x))).collect());
+}
+
+#[test]
+fn validation_failure_paths() {
+    let err = exercise(Path::new("tests/fuzz/corpus/animation_node_out_of_range.gltf")).err().unwrap();
+    assert!(err.starts_with("validation failed:\n  animations[0].channels[0].target.node: "), "{}", err);
+    assert!(err.ends_with("(INDEX_OUT_OF_BOUNDS)"), "{}", err);
+}
+
+#[test]
+#[ignore]
+fn sample_models() {
+    let mut assets = Vec::new();
+    let entries = fs::read_dir(SAMPLE_MODELS_DIRECTORY_PATH)
+        .expect("the glTF-Sample-Models submodule is required");
+    for entry in entries {
+        let entry_path = entry.expect("failed to read directory").path();
+        let file_name = match entry_path.file_name() {
+            Some(file_name) if entry_path.is_dir() => file_name.to_owned(),
+            _ => continue,
+        };
+        for &(variant, extension) in VARIANTS {
+            let mut path = entry_path.join(variant).join(&file_name);
+            path.set_extension(extension);
+            if path.exists() {
+                let name = format!("{}/{}", file_name.to_string_lossy(), variant);
+                assets.push((name, path));
+            }
+        }
+    }
+    assets.sort();
+    check(assets);
+}
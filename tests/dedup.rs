@@ -0,0 +1,99 @@
+/// Two primitives whose positions are byte-identical but stored in separate
+/// buffer views, a morph target redeclaring the first accessor, and an
+/// animation sampler whose input duplicates another accessor.
+const DUPLICATES: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 92}],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 36},
+        {"buffer": 0, "byteOffset": 36, "byteLength": 36},
+        {"buffer": 0, "byteOffset": 72, "byteLength": 4},
+        {"buffer": 0, "byteOffset": 76, "byteLength": 4},
+        {"buffer": 0, "byteOffset": 80, "byteLength": 12}
+    ],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0]},
+        {"bufferView": 1, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0]},
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0], "name": "copy"},
+        {"bufferView": 2, "componentType": 5126, "count": 1, "type": "SCALAR", "min": [0], "max": [0]},
+        {"bufferView": 3, "componentType": 5126, "count": 1, "type": "SCALAR", "min": [0], "max": [0]},
+        {"bufferView": 4, "componentType": 5126, "count": 1, "type": "VEC3"}
+    ],
+    "meshes": [{
+        "primitives": [
+            {"attributes": {"POSITION": 0}},
+            {"attributes": {"POSITION": 1}, "targets": [{"POSITION": 2}]}
+        ]
+    }],
+    "nodes": [{"mesh": 0}],
+    "animations": [{
+        "channels": [{"sampler": 0, "target": {"node": 0, "path": "translation"}}],
+        "samplers": [{"input": 4, "output": 5}]
+    }]
+}"#;
+
+fn buffer_data() -> Vec<u8> {
+    let positions = [0.0f32, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0];
+    let mut floats = positions.to_vec();
+    floats.extend_from_slice(&positions);
+    floats.extend_from_slice(&[0.0, 0.0, 2.0, 0.0, 0.0]);
+    floats.iter().flat_map(|x| x.to_le_bytes().to_vec()).collect()
+}
+
+fn indices<T>(groups: Vec<Vec<T>>, index: impl Fn(&T) -> usize) -> Vec<Vec<usize>> {
+    groups.iter().map(|group| group.iter().map(&index).collect()).collect()
+}
+
+#[test]
+fn test_duplicate_analysis() {
+    let gltf = gltf::Gltf::from_slice(DUPLICATES.as_bytes()).unwrap();
+    let data = buffer_data();
+    assert_eq!(indices(gltf.duplicate_accessors(), |x| x.index()), vec![vec![0, 2]]);
+    assert_eq!(
+        indices(gltf.duplicate_accessors_with(|_| Some(&data)), |x| x.index()),
+        vec![vec![0, 1, 2], vec![3, 4]],
+    );
+    assert!(gltf.duplicate_views().is_empty());
+    assert_eq!(
+        indices(gltf.duplicate_views_with(|_| Some(&data)), |x| x.index()),
+        vec![vec![0, 1], vec![2, 3]],
+    );
+
+    // Without buffer data, only identical declarations are found.
+    assert_eq!(indices(gltf.duplicate_accessors_with(|_| None), |x| x.index()), vec![vec![0, 2]]);
+}
+
+#[test]
+fn test_deduplicate_declarations() {
+    let mut root = gltf::json::Root::from_str(DUPLICATES).unwrap();
+    let report = root.deduplicate_accessors();
+    assert_eq!(report.removed_accessors, vec![2]);
+    assert!(report.removed_views.is_empty());
+    assert_eq!(report.bytes_saved, 0);
+    assert_eq!(root.accessors.len(), 5);
+    let target = root.meshes[0].primitives[1].targets.as_ref().unwrap()[0].positions;
+    assert_eq!(target.map(|x| x.value()), Some(0));
+    assert_eq!(root.animations[0].samplers[0].input.value(), 3);
+}
+
+#[test]
+fn test_deduplicate_contents() {
+    let mut root = gltf::json::Root::from_str(DUPLICATES).unwrap();
+    let data = buffer_data();
+    let report = root.deduplicate_accessors_in(&[&data[..]]);
+    assert_eq!(report.removed_accessors, vec![1, 2, 4]);
+    assert_eq!(report.removed_views, vec![1, 3]);
+    assert_eq!(report.bytes_saved, 40);
+    assert_eq!(root.accessors.len(), 3);
+    assert_eq!(root.buffer_views.len(), 3);
+
+    let gltf = gltf::Document::from_json(root).unwrap();
+    let positions = gltf.meshes().next().unwrap().primitives()
+        .map(|x| x.get(&gltf::Semantic::Positions).unwrap().index())
+        .collect::<Vec<_>>();
+    assert_eq!(positions, vec![0, 0]);
+    let sampler = gltf.animations().next().unwrap().samplers().next().unwrap();
+    assert_eq!((sampler.input().unwrap().index(), sampler.output().unwrap().index()), (1, 2));
+    assert_eq!(sampler.output().unwrap().view().unwrap().index(), 2);
+    assert!(gltf.duplicate_accessors_with(|_| Some(&data)).is_empty());
+}
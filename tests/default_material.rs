@@ -0,0 +1,34 @@
+const JSON: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 36, "uri": "data.bin"}],
+    "bufferViews": [{"buffer": 0, "byteLength": 36}],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0]}
+    ],
+    "materials": [{"doubleSided": true}],
+    "meshes": [{
+        "primitives": [
+            {"attributes": {"POSITION": 0}},
+            {"attributes": {"POSITION": 0}, "material": 0}
+        ]
+    }]
+}"#;
+
+#[test]
+fn test_primitive_without_material() {
+    let gltf = gltf::Gltf::from_slice(JSON.as_bytes()).unwrap();
+    let primitives = gltf.meshes().next().unwrap().primitives().collect::<Vec<_>>();
+
+    // The default material is returned, but is not authored.
+    let material = primitives[0].material();
+    assert_eq!(material.index(), None);
+    assert_eq!(primitives[0].material_index(), None);
+    assert_eq!(material.pbr_metallic_roughness().base_color_factor(), [1.0; 4]);
+    assert_eq!(material.alpha_mode(), gltf::material::AlphaMode::Opaque);
+    assert!(!material.double_sided());
+
+    let material = primitives[1].material();
+    assert_eq!(material.index(), Some(0));
+    assert_eq!(primitives[1].material_index(), Some(0));
+    assert!(material.double_sided());
+}
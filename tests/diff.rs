@@ -0,0 +1,159 @@
+use gltf::diff::{self, Change, Options};
+use gltf::json::Value;
+
+fn f32_bytes(values: &[f32]) -> Vec<u8> {
+    values.iter().flat_map(|x| x.to_le_bytes().to_vec()).collect()
+}
+
+const POSITIONS: [f32; 9] = [0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0];
+
+const TIGHTLY_PACKED: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 36}],
+    "bufferViews": [{"buffer": 0, "byteLength": 36}],
+    "accessors": [{
+        "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3",
+        "min": [0, 0, 0], "max": [1, 1, 0]
+    }],
+    "materials": [{"name": "red", "pbrMetallicRoughness": {"baseColorFactor": [1, 0, 0, 1]}}, {"name": "plain"}],
+    "meshes": [{"primitives": [{"attributes": {"POSITION": 0}, "material": 0}]}],
+    "nodes": [{"mesh": 0}],
+    "scenes": [{"nodes": [0]}]
+}"#;
+
+/// The document above with interleaved positions and explicit default values.
+const INTERLEAVED: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 76}],
+    "bufferViews": [{"buffer": 0, "byteOffset": 4, "byteLength": 72, "byteStride": 24}],
+    "accessors": [{
+        "bufferView": 0, "byteOffset": 12, "componentType": 5126, "count": 3, "type": "VEC3",
+        "min": [0, 0, 0], "max": [1, 1, 0]
+    }],
+    "materials": [
+        {
+            "name": "red",
+            "alphaMode": "OPAQUE",
+            "doubleSided": false,
+            "pbrMetallicRoughness": {"baseColorFactor": [1, 0, 0, 1], "metallicFactor": 1}
+        },
+        {"name": "plain", "emissiveFactor": [0, 0, 0]}
+    ],
+    "meshes": [{"primitives": [{"attributes": {"POSITION": 0}, "material": 0, "mode": 4}]}],
+    "nodes": [{"mesh": 0, "matrix": [1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1]}],
+    "scenes": [{"nodes": [0]}]
+}"#;
+
+fn interleaved_data(positions: &[f32]) -> Vec<u8> {
+    let mut data = vec![0xff; 4];
+    for position in positions.chunks(3) {
+        data.extend(f32_bytes(&[9.0, 9.0, 9.0]));
+        data.extend(f32_bytes(position));
+    }
+    data
+}
+
+fn parse(json: &str) -> gltf::Gltf {
+    gltf::Gltf::from_slice(json.as_bytes()).unwrap()
+}
+
+#[test]
+fn test_diff_ignores_layout_and_defaults() {
+    let a = parse(TIGHTLY_PACKED);
+    let b = parse(INTERLEAVED);
+    let diff = gltf::diff(&a, &b, &[f32_bytes(&POSITIONS)], &[interleaved_data(&POSITIONS)]);
+    assert_eq!(diff.changes, vec![]);
+    assert!(diff.is_empty());
+}
+
+#[test]
+fn test_diff_reports_changes() {
+    let a = parse(TIGHTLY_PACKED);
+    let b = parse(&INTERLEAVED
+        .replace("\"baseColorFactor\": [1, 0, 0, 1]", "\"baseColorFactor\": [1, 0.5, 0, 1]")
+        .replace("[1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1]", "[1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 2, 0, 0, 1]"));
+    let mut positions = POSITIONS;
+    positions[4] = 0.25;
+    positions[8] = 0.5;
+    let diff = gltf::diff(&a, &b, &[f32_bytes(&POSITIONS)], &[interleaved_data(&positions)]);
+    let changes = diff.changes.iter()
+        .map(|(path, change)| (path.as_str(), change.clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(changes, vec![
+        ("accessors[0]", Change::Data { first: 1, count: 2 }),
+        ("materials[0].pbrMetallicRoughness.baseColorFactor[1]", Change::Modified {
+            before: Value::from(0.0),
+            after: Value::from(0.5),
+        }),
+        ("nodes[0].translation[0]", Change::Modified {
+            before: Value::from(0.0),
+            after: Value::from(2.0),
+        }),
+    ]);
+
+    // Differences within the tolerance are ignored.
+    let options = Options { tolerance: 0.6, ..Default::default() };
+    let diff = diff::diff_with(&a, &b, &[f32_bytes(&POSITIONS)], &[interleaved_data(&positions)], &options);
+    assert_eq!(diff.changes.len(), 1);
+    assert_eq!(diff.changes[0].0.as_str(), "nodes[0].translation[0]");
+}
+
+#[test]
+fn test_diff_unreadable_data() {
+    let a = parse(TIGHTLY_PACKED);
+    let diff = gltf::diff(&a, &a, &[f32_bytes(&POSITIONS)], &[vec![0; 8]]);
+    assert_eq!(diff.changes.len(), 1);
+    assert_eq!(diff.changes[0].1, Change::Unreadable);
+
+    let buffers: &[Vec<u8>] = &[];
+    assert!(gltf::diff(&a, &a, buffers, buffers).is_empty());
+}
+
+#[test]
+fn test_diff_added_and_removed() {
+    let a = parse(TIGHTLY_PACKED);
+    let b = parse(&TIGHTLY_PACKED.replace(", {\"name\": \"plain\"}", ""));
+    let buffers = [f32_bytes(&POSITIONS)];
+    let diff = gltf::diff(&a, &b, &buffers, &buffers);
+    assert_eq!(diff.changes.len(), 1);
+    assert_eq!(diff.changes[0].0.as_str(), "materials[1]");
+    assert_eq!(diff.changes[0].1, Change::Removed);
+
+    let diff = gltf::diff(&b, &a, &buffers, &buffers);
+    assert_eq!(diff.changes[0].1, Change::Added);
+}
+
+#[cfg(feature = "names")]
+#[test]
+fn test_diff_match_by_name() {
+    let a = parse(TIGHTLY_PACKED);
+    // The materials are swapped, and the primitive references the moved material.
+    let b = parse(&TIGHTLY_PACKED
+        .replace(
+            "[{\"name\": \"red\", \"pbrMetallicRoughness\": {\"baseColorFactor\": [1, 0, 0, 1]}}, {\"name\": \"plain\"}]",
+            "[{\"name\": \"plain\"}, {\"name\": \"red\", \"pbrMetallicRoughness\": {\"baseColorFactor\": [1, 0, 0, 1]}}]",
+        )
+        .replace("\"material\": 0", "\"material\": 1"));
+    let buffers = [f32_bytes(&POSITIONS)];
+
+    let options = Options { match_by_name: true, ..Default::default() };
+    assert!(diff::diff_with(&a, &b, &buffers, &buffers, &options).is_empty());
+
+    // Matched by index, both materials and the reference differ.
+    let diff = gltf::diff(&a, &b, &buffers, &buffers);
+    let paths = diff.changes.iter().map(|(path, _)| path.as_str()).collect::<Vec<_>>();
+    assert!(paths.contains(&"materials[0].pbrMetallicRoughness.baseColorFactor[1]"));
+    assert!(paths.contains(&"meshes[0].primitives[0].material"));
+}
+
+#[cfg(feature = "names")]
+#[test]
+fn test_diff_accessor_names() {
+    let a = parse(TIGHTLY_PACKED);
+    let b = parse(&TIGHTLY_PACKED.replace("\"type\": \"VEC3\",", "\"type\": \"VEC3\", \"name\": \"POSITION_lod0\","));
+    let buffers = [f32_bytes(&POSITIONS)];
+    let diff = gltf::diff(&a, &b, &buffers, &buffers);
+    let changes = diff.changes.iter().map(|(path, change)| (path.as_str(), change)).collect::<Vec<_>>();
+    let renamed = Change::Modified { before: Value::Null, after: Value::from("POSITION_lod0") };
+    assert_eq!(changes, vec![("accessors[0].name", &renamed)]);
+}
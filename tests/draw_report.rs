@@ -0,0 +1,90 @@
+#![cfg(feature = "utils")]
+
+use gltf::draw::{MaterialUsage, MeshUsage};
+use gltf::material::AlphaMode;
+use gltf::texture::{MaterialSlot, MaterialUse};
+
+const JSON: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 68}],
+    "bufferViews": [{"buffer": 0, "byteLength": 18}, {"buffer": 0, "byteOffset": 20, "byteLength": 48}],
+    "accessors": [
+        {"bufferView": 1, "componentType": 5126, "count": 4, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0]},
+        {"bufferView": 0, "componentType": 5123, "count": 9, "type": "SCALAR"}
+    ],
+    "images": [{"uri": "texture.png"}],
+    "textures": [{"source": 0}],
+    "materials": [{"alphaMode": "BLEND", "pbrMetallicRoughness": {"baseColorTexture": {"index": 0}}}, {}],
+    "meshes": [
+        {"primitives": [{"attributes": {"POSITION": 0}, "indices": 1, "material": 0}]},
+        {"primitives": [
+            {"attributes": {"POSITION": 0}, "mode": 5, "material": 1},
+            {"attributes": {"POSITION": 0}, "mode": 0}
+        ]},
+        {"primitives": [{"attributes": {"POSITION": 0}, "indices": 1, "material": 1}]}
+    ],
+    "nodes": [{"mesh": 0}, {"mesh": 0, "children": [2]}, {"mesh": 1}],
+    "scenes": [{"nodes": [0, 1]}, {"nodes": [1]}]
+}"#;
+
+fn data() -> Vec<u8> {
+    let mut data = Vec::new();
+    // The second triangle is degenerate.
+    for index in &[0u16, 1, 2, 0, 0, 1, 1, 2, 3, 0] {
+        data.extend_from_slice(&index.to_le_bytes());
+    }
+    for value in &[0.0f32, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0] {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+    data
+}
+
+fn material(material: Option<usize>, primitives: usize, triangles: usize) -> MaterialUsage {
+    MaterialUsage { material, primitives, triangles, alpha_mode: AlphaMode::Opaque, textures: Vec::new() }
+}
+
+fn mesh(mesh: usize, instances: usize, triangles_per_instance: usize) -> MeshUsage {
+    MeshUsage { mesh, instances, triangles_per_instance, triangles: instances * triangles_per_instance }
+}
+
+#[test]
+fn test_report() {
+    let gltf = gltf::Gltf::from_slice_without_validation(JSON.as_bytes()).unwrap();
+    let report = gltf.report(None::<fn(gltf::Buffer) -> Option<&'static [u8]>>);
+    let textured = MaterialUsage {
+        alpha_mode: AlphaMode::Blend,
+        textures: vec![MaterialUse { material: 0, slot: MaterialSlot::BaseColor, texture: 0 }],
+        ..material(Some(0), 3, 9)
+    };
+    assert_eq!(report.by_material, vec![textured.clone(), material(Some(1), 2, 4), material(None, 2, 0)]);
+    assert_eq!(report.by_mesh, vec![mesh(0, 3, 3), mesh(1, 2, 2), mesh(2, 0, 3)]);
+
+    let data = data();
+    let report = gltf.report(Some(|_| Some(&data[..])));
+    let textured = MaterialUsage { triangles: 6, ..textured };
+    assert_eq!(report.by_material, vec![textured, material(Some(1), 2, 4), material(None, 2, 0)]);
+    assert_eq!(report.by_mesh, vec![mesh(0, 3, 2), mesh(1, 2, 2), mesh(2, 0, 2)]);
+}
+
+#[test]
+fn test_triangle_count() {
+    let gltf = gltf::Gltf::from_slice_without_validation(JSON.as_bytes()).unwrap();
+    let data = data();
+    let counts = gltf
+        .meshes()
+        .flat_map(|mesh| mesh.primitives())
+        .map(|primitive| primitive.triangle_count(Some(|_| Some(&data[..]))))
+        .collect::<Vec<_>>();
+    assert_eq!(counts, vec![2, 2, 0, 2]);
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn test_serialize() {
+    let gltf = gltf::Gltf::from_slice_without_validation(JSON.as_bytes()).unwrap();
+    let report = gltf.report(None::<fn(gltf::Buffer) -> Option<&'static [u8]>>);
+    let json = gltf::json::serialize::to_value(&report).unwrap();
+    assert_eq!(json["by_material"][0]["alpha_mode"], "Blend");
+    assert_eq!(json["by_material"][0]["textures"][0]["slot"], "BaseColor");
+    assert_eq!(json["by_mesh"][1]["triangles"], 4);
+}
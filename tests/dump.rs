@@ -0,0 +1,78 @@
+//! Pins the format of `Document::dump`.
+//!
+//! Set `GLTF_UPDATE_SNAPSHOT=1` to record a new snapshot instead of comparing.
+#![cfg(all(feature = "import", feature = "names"))]
+
+use std::{env, fs};
+
+use gltf::dump::DumpOptions;
+
+const SNAPSHOT_PATH: &str = "tests/box_dump.txt";
+
+fn dump(document: &gltf::Document, options: &DumpOptions) -> String {
+    let mut outline = Vec::new();
+    document.dump(&mut outline, options).unwrap();
+    String::from_utf8(outline).unwrap()
+}
+
+#[test]
+fn test_box_dump_snapshot() {
+    let (document, buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    let actual = dump(&document, &DumpOptions::default().with_buffers(&buffers));
+    if env::var_os("GLTF_UPDATE_SNAPSHOT").is_some() {
+        fs::write(SNAPSHOT_PATH, actual).unwrap();
+        return;
+    }
+    let expected = fs::read_to_string(SNAPSHOT_PATH).unwrap();
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_dump_max_depth() {
+    let gltf = gltf::Gltf::open("examples/Box.gltf").unwrap();
+    let options = DumpOptions { max_depth: Some(1), ..Default::default() };
+    let outline = dump(&gltf, &options);
+    let lines = outline.lines().take(3).collect::<Vec<_>>();
+    assert_eq!(lines, vec![
+        "Scene 0 (default)",
+        "  Node 0 matrix [1, 0, 0, 0, 0, 0, -1, 0, 0, 1, 0, 0, 0, 0, 0, 1]",
+        "    ... 1 child node",
+    ]);
+}
+
+#[test]
+fn test_dump_consults_buffers() {
+    let json = r#"{
+        "asset": {"version": "2.0"},
+        "buffers": [{"byteLength": 36}],
+        "bufferViews": [
+            {"buffer": 0, "byteLength": 24, "name": "uv"},
+            {"buffer": 0, "byteOffset": 24, "byteLength": 12}
+        ],
+        "accessors": [{"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC2", "name": "TEXCOORD_0"}],
+        "images": [{"name": "header", "bufferView": 1, "mimeType": "image/png"}],
+        "textures": [{"source": 0}]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    let mut data = Vec::new();
+    for x in &[1.0f32, -2.0, 3.0, 0.5, -1.0, 4.0] {
+        data.extend_from_slice(&x.to_le_bytes());
+    }
+    // The image is too short for its size to be probed.
+    data.extend_from_slice(&[0x89, b'P', b'N', b'G']);
+    data.extend_from_slice(&[0; 8]);
+
+    let without = dump(&gltf, &DumpOptions::default());
+    assert_eq!(without, "\
+Accessors
+  Accessor 0 \"TEXCOORD_0\": Vec2 of F32 x 3, view 0 \"uv\"
+Textures
+  Texture 0: image 0, default sampler
+Images
+  Image 0 \"header\": view 1 (image/png)
+");
+    let buffers = [data];
+    let with = dump(&gltf, &DumpOptions::default().with_buffers(&buffers));
+    assert!(with.contains("Accessor 0 \"TEXCOORD_0\": Vec2 of F32 x 3, view 0 \"uv\", data min [-1, -2], max [3, 4]\n"));
+    assert!(with.ends_with("Image 0 \"header\": view 1 (image/png)\n"));
+}
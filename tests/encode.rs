@@ -0,0 +1,102 @@
+use gltf::accessor::encode::{self, Encoded};
+use gltf::accessor::DataType;
+
+const COMPONENT_TYPES: &[DataType] = &[
+    DataType::I8,
+    DataType::U8,
+    DataType::I16,
+    DataType::U16,
+    DataType::U32,
+    DataType::F32,
+];
+
+/// Returns deterministic pseudo-random values in `[lower, upper)`.
+fn values(count: usize, lower: f32, upper: f32) -> Vec<[f32; 3]> {
+    let mut state = 0x9e37_79b9_u32;
+    let mut next = || {
+        state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        lower + (upper - lower) * (state >> 8) as f32 / (1 << 24) as f32
+    };
+    (0..count).map(|_| [next(), next(), next()]).collect()
+}
+
+/// Returns the range of values that can be encoded without clamping.
+fn domain(component_type: DataType, normalized: bool) -> (f32, f32) {
+    match (component_type, normalized) {
+        (DataType::I8, true) | (DataType::I16, true) => (-1.0, 1.0),
+        (_, true) => (0.0, 1.0),
+        (DataType::I8, false) => (-128.0, 127.0),
+        (DataType::U8, false) => (0.0, 255.0),
+        (DataType::I16, false) => (-32768.0, 32767.0),
+        (DataType::U16, false) => (0.0, 65535.0),
+        (DataType::U32, false) => (0.0, 1.0e6),
+        (DataType::F32, false) => (-1.0e6, 1.0e6),
+    }
+}
+
+/// Returns the per-component minimum and maximum of the raw encoded values.
+fn raw_bounds(encoded: &Encoded, component_type: DataType) -> (Vec<f64>, Vec<f64>) {
+    let raw = encode::decode(&encoded.data, component_type, false).unwrap();
+    let mut min = vec![f64::INFINITY; 3];
+    let mut max = vec![f64::NEG_INFINITY; 3];
+    for (i, &x) in raw.iter().enumerate() {
+        min[i % 3] = min[i % 3].min(f64::from(x));
+        max[i % 3] = max[i % 3].max(f64::from(x));
+    }
+    (min, max)
+}
+
+fn as_f64(value: &gltf::json::Value) -> Vec<f64> {
+    value.as_array().unwrap().iter().map(|x| x.as_f64().unwrap()).collect()
+}
+
+#[test]
+fn test_encode_roundtrip() {
+    for &component_type in COMPONENT_TYPES {
+        for &normalized in &[false, true] {
+            let items = values(1000, domain(component_type, normalized).0, domain(component_type, normalized).1);
+            let encoded = match encode::encode(&items, component_type, normalized) {
+                Some(encoded) => encoded,
+                None => {
+                    assert!(normalized);
+                    assert!(component_type == DataType::U32 || component_type == DataType::F32);
+                    continue;
+                },
+            };
+            assert_eq!(encoded.data.len(), items.len() * 3 * component_type.size());
+
+            let decoded = encode::decode(&encoded.data, component_type, normalized).unwrap();
+            let error = encode::max_error(component_type, normalized);
+            for (expected, actual) in items.iter().flat_map(|x| x.iter()).zip(&decoded) {
+                // Allow for the rounding error of the f32 arithmetic itself.
+                let tolerance = error + expected.abs() * 1.0e-6;
+                assert!(
+                    (expected - actual).abs() <= tolerance,
+                    "{:?} normalized: {}: {} decoded as {}",
+                    component_type, normalized, expected, actual,
+                );
+            }
+
+            let (min, max) = raw_bounds(&encoded, component_type);
+            assert_eq!(as_f64(&encoded.min), min);
+            assert_eq!(as_f64(&encoded.max), max);
+        }
+    }
+}
+
+#[test]
+fn test_encode_clamps() {
+    let items = [[-2.0, 0.5, 2.0]];
+    let encoded = encode::encode(&items, DataType::U8, true).unwrap();
+    assert_eq!(encoded.data, vec![0, 128, 255]);
+    let encoded = encode::encode(&items, DataType::I16, true).unwrap();
+    assert_eq!(encode::decode(&encoded.data, DataType::I16, true).unwrap(), vec![-1.0, 16384.0 / 32767.0, 1.0]);
+    let encoded = encode::encode(&[300.0f32, -1.0, 1.5], DataType::U8, false).unwrap();
+    assert_eq!(encoded.data, vec![255, 0, 2]);
+    assert_eq!(encoded.min, gltf::json::Value::from(vec![0]));
+    assert_eq!(encoded.max, gltf::json::Value::from(vec![255]));
+
+    // The most negative value decodes to -1.0 rather than below it.
+    assert_eq!(encode::decode(&[0x80], DataType::I8, true).unwrap(), vec![-1.0]);
+    assert!(encode::encode::<f32>(&[], DataType::F32, false).is_none());
+}
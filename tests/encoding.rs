@@ -0,0 +1,74 @@
+use std::borrow::Cow;
+use std::{fs, io};
+
+#[test]
+fn test_utf8_bom() {
+    let gltf = gltf::Gltf::open("tests/bom.gltf").unwrap();
+    assert_eq!(gltf.scenes().count(), 1);
+    #[cfg(feature = "names")]
+    assert_eq!(gltf.scenes().next().unwrap().name(), Some("Café"));
+
+    let bytes = fs::read("tests/bom.gltf").unwrap();
+    assert!(gltf::Gltf::from_slice(&bytes).is_ok());
+    assert!(gltf::Gltf::from_reader_streaming(io::Cursor::new(&bytes)).is_ok());
+}
+
+#[test]
+fn test_utf16() {
+    let bytes = fs::read("tests/utf16.gltf").unwrap();
+    let results = vec![
+        gltf::Gltf::open("tests/utf16.gltf"),
+        gltf::Gltf::from_slice(&bytes),
+        gltf::Gltf::from_reader_streaming(io::Cursor::new(&bytes)),
+    ];
+    for result in results {
+        let error = result.unwrap_err();
+        match error {
+            gltf::Error::UnsupportedEncoding("UTF-16LE") => {},
+            ref x => panic!("unexpected error: {:?}", x),
+        }
+        assert_eq!(error.to_string(), "unsupported JSON encoding UTF-16LE, expected UTF-8");
+    }
+
+    // Text without a byte order mark is detected by its zero bytes.
+    let error = gltf::Gltf::from_slice(b"\0{\0}").unwrap_err();
+    assert_eq!(error.to_string(), "unsupported JSON encoding UTF-16BE, expected UTF-8");
+}
+
+fn glb(json: &[u8]) -> Vec<u8> {
+    let glb = gltf::binary::Glb {
+        header: gltf::binary::Header { magic: *b"glTF", version: 2, length: 0 },
+        json: Cow::Borrowed(json),
+        bin: None,
+        extra_chunks: Vec::new(),
+    };
+    glb.to_vec().unwrap()
+}
+
+#[test]
+fn test_glb_invalid_utf8() {
+    let json = b"{\"asset\":{\"version\":\"2.0\"},\"scenes\":[{\"name\":\"\xff\"}]}";
+    let bytes = glb(json);
+    let error = gltf::Gltf::from_slice(&bytes).unwrap_err();
+    match error {
+        gltf::Error::InvalidUtf8 { offset: 46 } => {},
+        ref x => panic!("unexpected error: {:?}", x),
+    }
+    assert_eq!(error.to_string(), "invalid UTF-8 in JSON chunk at byte offset 46");
+    let error = gltf::Gltf::from_reader(io::Cursor::new(&bytes)).unwrap_err();
+    assert_eq!(error.to_string(), "invalid UTF-8 in JSON chunk at byte offset 46");
+}
+
+#[test]
+fn test_glb_bom() {
+    let bytes = glb(b"\xef\xbb\xbf{\"asset\":{\"version\":\"2.0\"}}");
+    assert!(gltf::Gltf::from_slice(&bytes).is_ok());
+    assert!(gltf::Gltf::from_reader(io::Cursor::new(&bytes)).is_ok());
+    assert!(gltf::Gltf::from_reader_streaming(io::Cursor::new(&bytes)).is_ok());
+
+    let bytes = glb(b"\xfe\xff\0{\0}");
+    match gltf::Gltf::from_reader_streaming(io::Cursor::new(&bytes)) {
+        Err(gltf::Error::UnsupportedEncoding("UTF-16BE")) => {},
+        x => panic!("unexpected result: {:?}", x.map(|_| ())),
+    }
+}
@@ -0,0 +1,75 @@
+use gltf::json::extensions::SUPPORTED_EXTENSIONS;
+
+/// Every extension, with whether its feature is enabled for this build.
+const FEATURES: &[(&str, bool)] = &[
+    ("EXT_mesh_gpu_instancing", cfg!(feature = "EXT_mesh_gpu_instancing")),
+    // Only supported when its data can be decoded.
+    ("EXT_meshopt_compression", cfg!(feature = "meshopt")),
+    ("EXT_texture_webp", cfg!(feature = "EXT_texture_webp")),
+    ("KHR_animation_pointer", cfg!(feature = "KHR_animation_pointer")),
+    ("KHR_lights_punctual", cfg!(feature = "KHR_lights_punctual")),
+    ("KHR_materials_pbrSpecularGlossiness", cfg!(feature = "KHR_materials_pbrSpecularGlossiness")),
+    ("KHR_materials_unlit", cfg!(feature = "KHR_materials_unlit")),
+    ("KHR_node_visibility", cfg!(feature = "KHR_node_visibility")),
+    ("KHR_texture_transform", cfg!(feature = "KHR_texture_transform")),
+    ("KHR_xmp_json_ld", cfg!(feature = "KHR_xmp_json_ld")),
+    ("MSFT_texture_dds", cfg!(feature = "MSFT_texture_dds")),
+];
+
+/// Returns the features listed by a `name = [...]` entry of a manifest.
+fn feature_list<'a>(manifest: &'a str, name: &str) -> Vec<&'a str> {
+    let start = manifest.find(&format!("\n{} = [", name)).expect("feature not found");
+    let list = &manifest[start..];
+    let list = &list[list.find('[').unwrap() + 1..list.find(']').unwrap()];
+    list.split(',').map(|x| x.trim().trim_matches('"')).filter(|x| !x.is_empty()).collect()
+}
+
+#[test]
+fn test_supported_extensions_match_features() {
+    let names = FEATURES.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+    assert_eq!(names, SUPPORTED_EXTENSIONS);
+    let enabled = FEATURES.iter().filter(|(_, enabled)| *enabled).map(|(name, _)| *name).collect::<Vec<_>>();
+    assert_eq!(enabled, gltf::supported_extensions());
+    if cfg!(all(feature = "extensions", feature = "meshopt")) {
+        assert_eq!(gltf::supported_extensions(), SUPPORTED_EXTENSIONS);
+    }
+}
+
+#[test]
+fn test_every_extension_has_a_feature() {
+    for manifest in [include_str!("../Cargo.toml"), include_str!("../gltf-json/Cargo.toml")] {
+        let umbrella = feature_list(manifest, "extensions");
+        for name in SUPPORTED_EXTENSIONS {
+            assert!(umbrella.contains(name), "{} is missing from the `extensions` feature", name);
+            let forwarded = feature_list(manifest, name);
+            assert!(forwarded.iter().all(|x| x.starts_with("gltf-json/") || x.starts_with("image?/")));
+        }
+    }
+}
+
+#[test]
+fn test_unsupported_required_extension() {
+    let json = r#"{
+        "asset": {"version": "2.0"},
+        "extensionsUsed": ["KHR_lights_punctual", "EXT_unknown"],
+        "extensionsRequired": ["KHR_lights_punctual", "EXT_unknown"]
+    }"#;
+    let error = gltf::Gltf::from_slice(json.as_bytes()).err();
+    let paths = match error {
+        Some(gltf::Error::Validation(errors)) => errors
+            .into_iter()
+            .inspect(|(_, error)| assert_eq!(*error, gltf::json::validation::Error::UnsupportedExtension))
+            .map(|(path, _)| path.to_string())
+            .collect::<Vec<_>>(),
+        _ => panic!("expected a validation error"),
+    };
+    let mut expected = vec!["extensionsRequired[1]"];
+    if !cfg!(feature = "KHR_lights_punctual") {
+        expected.insert(0, "extensionsRequired[0]");
+    }
+    assert_eq!(paths, expected);
+
+    // Listing an extension as used only is fine.
+    let json = r#"{"asset": {"version": "2.0"}, "extensionsUsed": ["EXT_unknown"]}"#;
+    assert!(gltf::Gltf::from_slice(json.as_bytes()).is_ok());
+}
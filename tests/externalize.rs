@@ -0,0 +1,119 @@
+#![cfg(feature = "import")]
+
+use std::path::{Path, PathBuf};
+
+use gltf::externalize::ExternalizeOptions;
+
+/// Returns an empty temporary directory for the named test.
+fn output_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("gltf-externalize-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+/// Returns the positions and the pixels of every image of an imported asset.
+fn contents(path: &Path) -> (Vec<[f32; 3]>, Vec<Vec<u8>>) {
+    let (document, buffers, images) = gltf::import(path).unwrap();
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+    let positions = reader.read_positions().unwrap().collect();
+    (positions, images.into_iter().map(|x| x.pixels).collect())
+}
+
+#[test]
+fn test_externalize_embedded_images() {
+    let dir = output_dir("embedded");
+    let gltf = gltf::Gltf::open("tests/embedded_images.gltf").unwrap();
+    let externalized = gltf.externalize(&dir, &ExternalizeOptions::default()).unwrap();
+
+    let manifest = externalized.files
+        .iter()
+        .map(|file| {
+            let references = file.references.iter().map(|x| x.as_str()).collect::<Vec<_>>();
+            (file.uri.as_str(), file.length, references)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        manifest,
+        vec![
+            // The first two images are identical.
+            ("albedo.png", 69, vec!["images[0]", "images[1]"]),
+            ("albedo_1.png", 69, vec!["images[2]"]),
+            // The range of the first image is removed from the buffer.
+            ("buffer0.bin", 36, vec!["buffers[0]"]),
+        ]
+    );
+    for file in &externalized.files {
+        assert_eq!(std::fs::metadata(&file.path).unwrap().len() as usize, file.length);
+    }
+    let root = &externalized.root;
+    assert_eq!(root.buffer_views.len(), 1);
+    assert!(root.images.iter().all(|x| x.buffer_view.is_none()));
+
+    let path = dir.join("externalized.gltf");
+    std::fs::write(&path, root.to_vec_pretty().unwrap()).unwrap();
+    assert_eq!(contents(&path), contents(Path::new("tests/embedded_images.gltf")));
+    let _ = std::fs::remove_dir_all(dir);
+}
+
+#[test]
+fn test_externalize_binary_gltf() {
+    let (document, buffers, _) = gltf::import("tests/embedded_images.gltf").unwrap();
+    let glb = document.to_glb(&buffers, &[]).unwrap().to_vec().unwrap();
+    let gltf = gltf::Gltf::from_slice(&glb).unwrap();
+
+    // The BIN chunk is written to a file like any other buffer.
+    let dir = output_dir("binary");
+    let externalized = gltf.externalize(&dir, &ExternalizeOptions::default()).unwrap();
+    let uris = externalized.files.iter().map(|x| x.uri.as_str()).collect::<Vec<_>>();
+    assert_eq!(uris, vec!["albedo.png", "albedo_1.png", "buffer0.bin"]);
+    assert_eq!(externalized.root.buffers[0].byte_length, 36);
+
+    // Copying the externalized files again leaves them unchanged.
+    let path = dir.join("externalized.gltf");
+    std::fs::write(&path, externalized.root.to_vec().unwrap()).unwrap();
+    let again = output_dir("again");
+    let options = ExternalizeOptions { base: Some(dir.clone()), ..Default::default() };
+    let copied = gltf::Gltf::open(&path).unwrap().externalize(&again, &options).unwrap();
+    assert_eq!(copied.files.iter().map(|x| x.uri.as_str()).collect::<Vec<_>>(), uris);
+    for (a, b) in externalized.files.iter().zip(&copied.files) {
+        assert_eq!(std::fs::read(&a.path).unwrap(), std::fs::read(&b.path).unwrap());
+    }
+
+    // Without a base directory, files cannot be read.
+    match gltf::Gltf::open(&path).unwrap().externalize(&again, &Default::default()) {
+        Err(gltf::Error::ExternalReferenceInSliceImport) => {},
+        x => panic!("unexpected result: {:?}", x.map(|_| ())),
+    }
+    let _ = std::fs::remove_dir_all(dir);
+    let _ = std::fs::remove_dir_all(again);
+}
+
+#[test]
+fn test_externalize_invalid_image_views() {
+    let dir = output_dir("invalid");
+    let json = |view: &str| {
+        format!(
+            r#"{{
+                "asset": {{"version": "2.0"}},
+                "buffers": [{{"byteLength": 4, "uri": "data:application/octet-stream;base64,AAAAAA=="}}],
+                "bufferViews": [{view}],
+                "images": [{{"bufferView": 0, "mimeType": "image/png"}}]
+            }}"#
+        )
+    };
+
+    let gltf = gltf::Gltf::from_slice_without_validation(json("").as_bytes()).unwrap();
+    match gltf.externalize(&dir, &ExternalizeOptions::default()) {
+        Err(gltf::Error::Validation(errors)) => assert_eq!(errors[0].0.as_str(), "images[0].bufferView"),
+        x => panic!("unexpected result: {:?}", x),
+    }
+
+    let view = r#"{"buffer": 0, "byteOffset": 2, "byteLength": 4}"#;
+    let gltf = gltf::Gltf::from_slice_without_validation(json(view).as_bytes()).unwrap();
+    match gltf.externalize(&dir, &ExternalizeOptions::default()) {
+        Err(gltf::Error::BufferLength { buffer: 0, expected: 6, actual: 4 }) => {},
+        x => panic!("unexpected result: {:?}", x),
+    }
+    let _ = std::fs::remove_dir_all(dir);
+}
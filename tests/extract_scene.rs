@@ -0,0 +1,167 @@
+use gltf::json::{self, repack::Layout, Index};
+use gltf::validation::Severity;
+
+/// Two scenes sharing one animation. The skinned mesh of the second scene uses
+/// a joint outside of its node hierarchy.
+const TWO_SCENES: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 136}, {"byteLength": 88}],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 36},
+        {"buffer": 0, "byteOffset": 36, "byteLength": 36},
+        {"buffer": 1, "byteLength": 64},
+        {"buffer": 0, "byteOffset": 72, "byteLength": 64},
+        {"buffer": 1, "byteOffset": 64, "byteLength": 24}
+    ],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0]},
+        {"bufferView": 1, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0]},
+        {"bufferView": 2, "componentType": 5126, "count": 1, "type": "MAT4"},
+        {"bufferView": 3, "componentType": 5126, "count": 2, "type": "SCALAR", "min": [0], "max": [1]},
+        {"bufferView": 3, "byteOffset": 8, "componentType": 5126, "count": 2, "type": "VEC3"},
+        {"bufferView": 3, "byteOffset": 32, "componentType": 5126, "count": 2, "type": "VEC4"},
+        {"bufferView": 4, "componentType": 5126, "count": 3, "type": "VEC2", "min": [0, 0], "max": [1, 1]}
+    ],
+    "images": [{"uri": "texture.png"}],
+    "samplers": [{}],
+    "textures": [{"sampler": 0, "source": 0}],
+    "materials": [
+        {"pbrMetallicRoughness": {"baseColorTexture": {"index": 0}}},
+        {}
+    ],
+    "meshes": [
+        {"primitives": [{"attributes": {"POSITION": 0}, "material": 1}]},
+        {"primitives": [{"attributes": {"POSITION": 1, "TEXCOORD_0": 6}, "material": 0}]}
+    ],
+    "cameras": [{"type": "perspective", "perspective": {"yfov": 1, "znear": 0.1}}],
+    "skins": [{"joints": [3], "inverseBindMatrices": 2}],
+    "nodes": [
+        {"mesh": 0, "children": [1]},
+        {"camera": 0},
+        {"mesh": 1, "skin": 0},
+        {},
+        {}
+    ],
+    "animations": [
+        {
+            "channels": [
+                {"sampler": 0, "target": {"node": 1, "path": "translation"}},
+                {"sampler": 1, "target": {"node": 3, "path": "rotation"}}
+            ],
+            "samplers": [{"input": 3, "output": 4}, {"input": 3, "output": 5}]
+        },
+        {
+            "channels": [{"sampler": 0, "target": {"node": 0, "path": "scale"}}],
+            "samplers": [{"input": 3, "output": 4}]
+        }
+    ],
+    "scenes": [{"nodes": [0]}, {"nodes": [2]}]
+}"#;
+
+fn buffer_data() -> Vec<Vec<u8>> {
+    let first = (0..136).map(|x| x as u8).collect();
+    let second = (0..88).map(|x| 200 - x as u8).collect();
+    vec![first, second]
+}
+
+fn assert_valid(root: json::Root) -> gltf::Document {
+    let document = gltf::Document::from_json(root).expect("invalid document");
+    let report = document.validation_report();
+    assert_eq!(report.at_least(Severity::Warning).count(), 0, "{:?}", report.entries);
+    document
+}
+
+#[test]
+fn test_extract_skinned_scene() {
+    let root = json::Root::from_str(TWO_SCENES).unwrap();
+    let (extracted, report) = root.extract_scene(Index::new(1)).unwrap();
+    assert_eq!(report.removed_animations, vec![1]);
+    let removed = report.removed_channels.iter().map(|x| x.as_str()).collect::<Vec<_>>();
+    assert_eq!(removed, vec!["animations[0].channels[0]"]);
+    assert_eq!(report.buffers, vec![0, 1]);
+
+    assert_eq!(extracted.scenes.len(), 1);
+    assert_eq!(extracted.scene.map(|x| x.value()), Some(0));
+    assert_eq!(extracted.nodes.len(), 2);
+    assert_eq!(extracted.nodes[0].mesh.map(|x| x.value()), Some(0));
+    assert_eq!(extracted.nodes[0].skin.map(|x| x.value()), Some(0));
+    assert_eq!(extracted.skins[0].joints[0].value(), 1);
+    assert_eq!(extracted.skins[0].inverse_bind_matrices.map(|x| x.value()), Some(1));
+    assert_eq!(extracted.materials.len(), 1);
+    assert_eq!(extracted.textures.len(), 1);
+    assert_eq!(extracted.images.len(), 1);
+    assert_eq!(extracted.cameras.len(), 0);
+    assert_eq!(extracted.accessors.len(), 5);
+    assert_eq!(extracted.buffer_views.len(), 4);
+
+    let animation = &extracted.animations[0];
+    assert_eq!(extracted.animations.len(), 1);
+    assert_eq!(animation.channels.len(), 1);
+    assert_eq!(animation.channels[0].sampler.value(), 0);
+    assert_eq!(animation.channels[0].target.node.map(|x| x.value()), Some(1));
+    assert_eq!(animation.samplers.len(), 1);
+    assert_eq!(animation.samplers[0].input.value(), 2);
+    assert_eq!(animation.samplers[0].output.value(), 3);
+
+    assert_valid(extracted);
+}
+
+#[test]
+fn test_extract_and_repack_scene() {
+    let root = json::Root::from_str(TWO_SCENES).unwrap();
+    let buffers = buffer_data();
+    let (mut extracted, report) = root.extract_scene(Index::new(0)).unwrap();
+    assert!(report.removed_channels.iter().map(|x| x.as_str()).eq(vec!["animations[0].channels[1]"]));
+    assert_eq!(report.removed_animations, Vec::<usize>::new());
+    assert_eq!(report.buffers, vec![0]);
+    assert_eq!(extracted.cameras.len(), 1);
+    assert_eq!(extracted.nodes[1].camera.map(|x| x.value()), Some(0));
+    assert_eq!(extracted.textures.len(), 0);
+    assert_eq!(extracted.animations.len(), 2);
+
+    // The view of the second mesh is removed from the retained buffer.
+    let mut data = report.buffers.iter().map(|&i| buffers[i].clone()).collect::<Vec<_>>();
+    let repacked = extracted.repack_buffers(&mut data, Layout::Separate).unwrap();
+    assert_eq!(repacked.bytes_before, 136);
+    assert_eq!(repacked.bytes_after, 68);
+    assert_eq!(&data[0][..36], &buffers[0][..36]);
+
+    let document = assert_valid(extracted);
+    assert_eq!(document.accessors().count(), 3);
+}
+
+#[test]
+fn test_extract_missing_scene() {
+    let root = json::Root::from_str(TWO_SCENES).unwrap();
+    match root.extract_scene(Index::new(2)) {
+        Err(json::edit::Error::IndexOutOfBounds(path)) => assert_eq!(path.as_str(), "scenes[2]"),
+        _ => panic!("expected an error"),
+    }
+}
+
+#[cfg(feature = "KHR_animation_pointer")]
+#[test]
+fn test_extract_pointer_channels() {
+    let pointer = |sampler: u32, pointer: &str| format!(
+        r#"{{"sampler": {}, "target": {{"path": "pointer", "extensions": {{"KHR_animation_pointer": {{"pointer": "{}"}}}}}}}}"#,
+        sampler, pointer,
+    );
+    let channels = [
+        pointer(0, "/materials/0/emissiveFactor"),
+        pointer(0, "/materials/1/emissiveFactor"),
+        pointer(0, "/nodes/1/translation"),
+    ];
+    let json = TWO_SCENES.replace(
+        r#"{"sampler": 0, "target": {"node": 0, "path": "scale"}}"#,
+        &channels.join(", "),
+    );
+    let root = json::Root::from_str(&json).unwrap();
+    let (extracted, report) = root.extract_scene(Index::new(0)).unwrap();
+    let removed = report.removed_channels.iter().map(|x| x.as_str()).collect::<Vec<_>>();
+    assert_eq!(removed, vec!["animations[0].channels[1]", "animations[1].channels[0]"]);
+    let pointers = extracted.animations[1].channels
+        .iter()
+        .map(|x| x.target.pointer().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(pointers, vec!["/materials/0/emissiveFactor", "/nodes/1/translation"]);
+}
@@ -0,0 +1,72 @@
+use std::convert::TryFrom;
+use std::fs;
+
+use gltf::json;
+
+/// A document with a denormalized rotation, a missing buffer URI, and an
+/// out of range node index.
+const INVALID: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 4}],
+    "nodes": [{"rotation": [0, 0, 0, 2]}],
+    "scenes": [{"nodes": [0, 3]}]
+}"#;
+
+fn value(json: &str) -> json::Value {
+    json::deserialize::from_str(json).unwrap()
+}
+
+fn paths(error: gltf::Error) -> Vec<String> {
+    match error {
+        gltf::Error::Validation(errors) => errors.iter().map(|(path, _)| path.to_string()).collect(),
+        error => panic!("expected a validation error, got {:?}", error),
+    }
+}
+
+#[test]
+fn test_same_report() {
+    let from_slice = gltf::Gltf::from_slice_without_validation(INVALID.as_bytes()).unwrap();
+    let from_value = gltf::Gltf::from_value_without_validation(value(INVALID)).unwrap();
+    let report = from_slice.validation_report();
+    assert_eq!(report.entries.len(), 3);
+    assert_eq!(from_value.validation_report(), report);
+
+    let from_slice = gltf::Gltf::from_slice(INVALID.as_bytes()).unwrap_err();
+    let from_value = gltf::Gltf::from_value(value(INVALID)).unwrap_err();
+    assert_eq!(paths(from_value), paths(from_slice));
+}
+
+#[test]
+fn test_same_document() {
+    let text = fs::read_to_string("examples/Lantern.gltf").unwrap();
+    let from_slice = gltf::Gltf::from_slice(text.as_bytes()).unwrap();
+    let from_value = gltf::Gltf::from_value(value(&text)).unwrap();
+    assert_eq!(from_value.validation_report(), from_slice.validation_report());
+    assert_eq!(
+        json::serialize::to_value(from_value.document.into_json()).unwrap(),
+        json::serialize::to_value(from_slice.document.into_json()).unwrap(),
+    );
+    assert!(from_value.blob.is_none());
+}
+
+#[test]
+fn test_deserialize_error() {
+    let error = json::Root::try_from(value(r#"{"asset": {"version": 2}}"#)).unwrap_err();
+    assert!(error.is_data());
+    match gltf::Gltf::from_value(value(r#"{"asset": {"version": 2}}"#)) {
+        Err(gltf::Error::Deserialize(_)) => {},
+        other => panic!("expected a deserialize error, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[cfg(feature = "extras")]
+#[test]
+fn test_extras_are_rewritten() {
+    let document = r#"{
+        "asset": {"version": "2.0"},
+        "nodes": [{"extras": {"b": [1, 2],  "a": "x"}}]
+    }"#;
+    let root = json::Root::try_from(value(document)).unwrap();
+    let extras = root.nodes[0].extras.as_ref().unwrap();
+    assert_eq!(extras.get(), r#"{"a":"x","b":[1,2]}"#);
+}
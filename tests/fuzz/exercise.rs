@@ -0,0 +1,150 @@
+//! The fuzzing harness shared by `fuzz/fuzz_targets/from_slice.rs` and
+//! `tests/fuzz_corpus.rs`.
+//!
+//! Loads arbitrary bytes with `Gltf::from_slice` and, if they validate, visits
+//! everything reachable through the wrapper API. Does the same for
+//! `Gltf::from_slice_without_validation`, whose documents may hold dangling
+//! indices and invalid enumerations. Nothing here may panic.
+
+use gltf::animation::util::ReadOutputs;
+use gltf::buffer::Source;
+use gltf::scene::Visibility;
+use gltf::{Buffer, Gltf};
+
+/// Exercises the wrapper API over `data`.
+pub fn exercise(data: &[u8]) {
+    #[cfg(feature = "import")]
+    let _ = gltf::import_slice(data);
+
+    if let Ok(gltf) = Gltf::from_slice(data) {
+        exercise_gltf(&gltf);
+    }
+    if let Ok(gltf) = Gltf::from_slice_without_validation(data) {
+        exercise_gltf(&gltf);
+    }
+}
+
+/// Visits everything reachable through the wrapper API of `gltf`.
+pub fn exercise_gltf(gltf: &Gltf) {
+    let blob = gltf.blob.as_deref();
+    let get_buffer_data = |buffer: Buffer| match buffer.source() {
+        Source::Bin => blob,
+        Source::Uri(_) => None,
+    };
+
+    for scene in gltf.scenes() {
+        let _ = scene.bounding_box();
+        let _ = scene.bounding_box_with(get_buffer_data);
+        let _ = scene.primitives().count();
+        for (node, _) in scene.walk(Visibility::ALL_HIDDEN) {
+            let _ = node.view_matrix(&scene);
+        }
+    }
+
+    for node in gltf.nodes() {
+        let _ = node.transform().decomposed();
+        let _ = (node.camera(), node.mesh(), node.skin(), node.children().count());
+        let _ = node.animations().count();
+        #[cfg(feature = "KHR_lights_punctual")]
+        let _ = node.light();
+    }
+
+    for mesh in gltf.meshes() {
+        for primitive in mesh.primitives() {
+            let _ = primitive.bounding_box();
+            let _ = (primitive.material().features(), primitive.vertex_count(), primitive.index_count());
+            let _ = primitive.max_index(Some(get_buffer_data));
+            let _ = primitive.attributes().count();
+            let _ = primitive.morph_targets().count();
+            exercise_reader(&primitive.reader(get_buffer_data));
+        }
+    }
+
+    for material in gltf.materials() {
+        let _ = (material.features(), material.sort_key(), material.detach());
+        let _ = material.extension_names().count();
+        let pbr = material.pbr_metallic_roughness();
+        let _ = (pbr.base_color_texture(), pbr.metallic_roughness_texture());
+        let _ = (material.normal_texture().map(|x| x.texture()), material.occlusion_texture().map(|x| x.texture()));
+        let _ = material.emissive_texture().map(|x| x.texture());
+        #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+        if let Some(sg) = material.pbr_specular_glossiness() {
+            let _ = (sg.diffuse_texture(), sg.specular_glossiness_texture());
+        }
+    }
+
+    for texture in gltf.textures() {
+        let _ = (texture.sampler().min_filter_or_default(), texture.source().map(|x| x.source()));
+        let _ = (texture.core_source(), texture.sampler_authored());
+    }
+
+    for animation in gltf.animations() {
+        for channel in animation.channels() {
+            let _ = (channel.target().node(), channel.target().property());
+            let reader = channel.reader(get_buffer_data);
+            if let Some(inputs) = reader.read_inputs() {
+                let _ = inputs.count();
+            }
+            if let Some(outputs) = reader.read_outputs() {
+                match outputs {
+                    ReadOutputs::Translations(x) => { let _ = x.count(); }
+                    ReadOutputs::Rotations(x) => { let _ = x.into_f32().count(); }
+                    ReadOutputs::Scales(x) => { let _ = x.count(); }
+                    ReadOutputs::MorphTargetWeights(x) => { let _ = x.into_f32().count(); }
+                    #[allow(unreachable_patterns)]
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    for skin in gltf.skins() {
+        let _ = (skin.skeleton(), skin.joints().count());
+        if let Some(matrices) = skin.reader(get_buffer_data).read_inverse_bind_matrices() {
+            let _ = matrices.count();
+        }
+    }
+
+    for camera in gltf.cameras() {
+        let _ = camera.projection();
+    }
+
+    for accessor in gltf.accessors() {
+        let _ = (accessor.size(), accessor.effective_stride(), accessor.absolute_offset());
+        let _ = (accessor.view(), accessor.min(), accessor.max());
+        if let Some(sparse) = accessor.sparse() {
+            let _ = (sparse.indices().view(), sparse.values().view());
+        }
+    }
+
+    let _ = gltf.requirements(Some(get_buffer_data));
+    let _ = gltf.image_sharing_report();
+    let _ = gltf.textures_requiring_mipmaps().count();
+    let _ = gltf.accessor_usages().count();
+    let _ = gltf.duplicate_accessors_with(get_buffer_data);
+    let _ = gltf.duplicate_views_with(get_buffer_data);
+    let _ = gltf.validate_buffer_data(get_buffer_data);
+}
+
+fn exercise_reader<'a, 's, F>(reader: &gltf::mesh::Reader<'a, 's, F>)
+where
+    F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+{
+    use gltf::mesh::util::vertices::StandardVertex;
+
+    let _ = reader.read_positions().map(Iterator::count);
+    let _ = reader.read_normals().map(Iterator::count);
+    let _ = reader.read_tangents().map(Iterator::count);
+    let _ = reader.read_indices().map(|x| x.into_u32().count());
+    for set in 0..2 {
+        let _ = reader.read_colors(set).map(|x| x.into_rgba_f32().count());
+        let _ = reader.read_joints(set).map(|x| x.into_u16().count());
+        let _ = reader.read_tex_coords(set).map(|x| x.into_f32().count());
+        let _ = reader.read_weights(set).map(|x| x.into_f32().count());
+    }
+    let _ = reader.read_merged_influences();
+    let _ = reader.read_vertices::<StandardVertex>().map(Iterator::count);
+    for (positions, normals, tangents) in reader.read_morph_targets() {
+        let _ = (positions.map(Iterator::count), normals.map(Iterator::count), tangents.map(Iterator::count));
+    }
+}
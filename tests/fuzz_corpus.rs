@@ -0,0 +1,165 @@
+#![cfg(feature = "utils")]
+
+//! Runs the fuzzing harness over the corpus of minimized crashers in
+//! `tests/fuzz/corpus`, and over a short deterministic mutation of the
+//! examples. Set `GLTF_FUZZ_ITERATIONS` for a longer run, and
+//! `GLTF_FUZZ_SEED` to explore other mutations.
+
+use gltf::json::Value;
+use std::{env, fs, panic};
+
+#[path = "fuzz/exercise.rs"]
+mod exercise;
+
+const SEEDS: &[&str] = &[
+    "examples/Box.gltf",
+    "examples/Box.glb",
+    "examples/Lantern.gltf",
+    "tests/box_sparse.glb",
+    "tests/embedded_images.gltf",
+    "tests/library.gltf",
+    "tests/matrix_animated.gltf",
+    "tests/minimal_accessor_min_max.gltf",
+    "tests/sparse_zero_base.gltf",
+];
+
+/// A xorshift generator, so that failures reproduce.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+}
+
+/// Replaces, inserts, or removes a few bytes, or overwrites a number with an
+/// interesting one.
+fn mutate(rng: &mut Rng, data: &mut Vec<u8>) {
+    const NUMBERS: &[&[u8]] = &[b"0", b"1", b"-1", b"3", b"4", b"255", b"65535", b"4294967295", b"1e9", b"5120", b"5125"];
+    for _ in 0..1 + rng.below(4) {
+        if data.is_empty() {
+            data.push(b'{');
+        }
+        let at = rng.below(data.len());
+        match rng.below(5) {
+            0 => data[at] = rng.next() as u8,
+            1 => data.insert(at, rng.next() as u8),
+            2 => { data.remove(at); }
+            _ => {
+                let end = data[at..].iter().position(|x| !x.is_ascii_digit()).map_or(data.len(), |x| at + x);
+                let number = NUMBERS[rng.below(NUMBERS.len())];
+                data.splice(at..end, number.iter().cloned());
+            }
+        }
+    }
+}
+
+/// Replaces, removes, or duplicates a random value of the JSON document.
+fn mutate_json(rng: &mut Rng, value: &mut Value) {
+    const NUMBERS: &[i64] = &[-1, 0, 1, 2, 3, 4, 7, 255, 256, 65535, 4294967295, 5120, 5121, 5123, 5125, 5126];
+    const STRINGS: &[&str] = &[
+        "SCALAR", "VEC2", "VEC3", "VEC4", "MAT4", "POSITION", "NORMAL", "TANGENT", "TEXCOORD_0",
+        "COLOR_0", "JOINTS_0", "WEIGHTS_0", "translation", "rotation", "scale", "weights", "pointer",
+        "STEP", "CUBICSPLINE", "orthographic", "perspective", "spot", "image/png",
+        "data:application/octet-stream;base64,AAAAAAAAAAA=",
+    ];
+    match value {
+        Value::Object(map) if !map.is_empty() && rng.below(4) > 0 => {
+            let key = map.keys().nth(rng.below(map.len())).cloned().unwrap();
+            match rng.below(8) {
+                0 => { map.remove(&key); }
+                _ => mutate_json(rng, map.get_mut(&key).unwrap()),
+            }
+        },
+        Value::Array(items) if !items.is_empty() && rng.below(4) > 0 => {
+            let at = rng.below(items.len());
+            match rng.below(8) {
+                0 => { items.remove(at); }
+                1 => { let item = items[at].clone(); items.push(item); }
+                _ => mutate_json(rng, &mut items[at]),
+            }
+        },
+        _ => *value = match rng.below(4) {
+            0 => Value::from(STRINGS[rng.below(STRINGS.len())]),
+            1 => Value::from(rng.next() as f64 / u64::MAX as f64 * 4.0 - 2.0),
+            2 => Value::Array(vec![Value::from(0); rng.below(5)]),
+            _ => Value::from(NUMBERS[rng.below(NUMBERS.len())]),
+        },
+    }
+}
+
+/// Mutates the JSON of a `.gltf` or `.glb` file, keeping it well-formed.
+fn mutate_structure(rng: &mut Rng, data: &[u8]) -> Option<Vec<u8>> {
+    match gltf::Glb::from_slice(data) {
+        Ok(mut glb) => {
+            let mut json: Value = gltf::json::deserialize::from_slice(&glb.json).ok()?;
+            for _ in 0..1 + rng.below(3) {
+                mutate_json(rng, &mut json);
+            }
+            glb.json = gltf::json::serialize::to_vec(&json).ok()?.into();
+            glb.to_vec().ok()
+        },
+        Err(_) => {
+            let mut json: Value = gltf::json::deserialize::from_slice(data).ok()?;
+            for _ in 0..1 + rng.below(3) {
+                mutate_json(rng, &mut json);
+            }
+            gltf::json::serialize::to_vec(&json).ok()
+        },
+    }
+}
+
+fn check(name: &str, data: &[u8]) {
+    if panic::catch_unwind(|| exercise::exercise(data)).is_err() {
+        let path = env::temp_dir().join(format!("gltf-crash-{}", name.replace('/', "-")));
+        let _ = fs::write(&path, data);
+        panic!("{} panicked, input written to {}", name, path.display());
+    }
+}
+
+fn corpus() -> Vec<String> {
+    let mut entries = fs::read_dir("tests/fuzz/corpus")
+        .unwrap()
+        .map(|x| x.unwrap().path().display().to_string())
+        .collect::<Vec<_>>();
+    entries.sort();
+    entries
+}
+
+#[test]
+fn test_corpus() {
+    let entries = corpus();
+    assert!(!entries.is_empty());
+    for path in entries {
+        check(&path, &fs::read(&path).unwrap());
+    }
+}
+
+#[test]
+fn test_mutations() {
+    let iterations = env::var("GLTF_FUZZ_ITERATIONS").ok().and_then(|x| x.parse().ok()).unwrap_or(500);
+    let seed = env::var("GLTF_FUZZ_SEED").ok().and_then(|x| x.parse().ok()).unwrap_or(0x2545_f491_4f6c_dd1d);
+    let mut rng = Rng(seed | 1);
+    let seeds = SEEDS.iter().map(|x| x.to_string()).chain(corpus());
+    for seed in seeds {
+        let seed_data = fs::read(&seed).unwrap();
+        for i in 0..iterations {
+            let data = match rng.below(2) {
+                0 => mutate_structure(&mut rng, &seed_data).unwrap(),
+                _ => {
+                    let mut data = seed_data.clone();
+                    mutate(&mut rng, &mut data);
+                    data
+                },
+            };
+            check(&format!("{}-{}", seed, i), &data);
+        }
+    }
+}
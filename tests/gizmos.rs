@@ -0,0 +1,92 @@
+#![cfg(feature = "debug-gizmos")]
+
+use gltf::gizmos;
+use gltf::mesh::Mode;
+
+const CAMERAS_AND_LIGHTS: &str = r#"{
+    "asset": {"version": "2.0"},
+    "extensionsUsed": ["KHR_lights_punctual"],
+    "extensions": {
+        "KHR_lights_punctual": {
+            "lights": [
+                {"type": "directional"},
+                {"type": "point", "range": 2},
+                {"type": "spot", "spot": {"outerConeAngle": 0.5}}
+            ]
+        }
+    },
+    "cameras": [
+        {"type": "perspective", "perspective": {"yfov": 1, "znear": 0.1}},
+        {"type": "orthographic", "orthographic": {"xmag": 1, "ymag": 1, "znear": 0, "zfar": 10}}
+    ],
+    "nodes": [
+        {"translation": [0, 5, 0], "children": [1, 2]},
+        {"camera": 0, "translation": [1, 0, 0]},
+        {"camera": 1, "extensions": {"KHR_lights_punctual": {"light": 0}}},
+        {"extensions": {"KHR_lights_punctual": {"light": 1}}},
+        {"extensions": {"KHR_lights_punctual": {"light": 2}}}
+    ],
+    "scenes": [{"nodes": [0, 3, 4]}]
+}"#;
+
+#[test]
+fn test_append_gizmos_roundtrip() {
+    let mut root = gltf::json::Root::from_str(CAMERAS_AND_LIGHTS).unwrap();
+    let mut buffers = Vec::new();
+    let report = gizmos::append_gizmos(&mut root, &mut buffers).unwrap();
+    assert_eq!(report.cameras, 2);
+    assert_eq!(report.lights, if cfg!(feature = "KHR_lights_punctual") { 3 } else { 0 });
+    let gizmo_count = report.cameras + report.lights;
+    assert_eq!(buffers.len(), 1);
+    assert_eq!(root.buffers[0].byte_length as usize, buffers[0].len());
+
+    // Write the document as binary glTF and load it back.
+    let json = root.to_vec().unwrap();
+    let glb = gltf::binary::Glb {
+        header: gltf::binary::Header { magic: *b"glTF", version: 2, length: 0 },
+        json: json.into(),
+        bin: Some(buffers[0].clone().into()),
+        extra_chunks: Vec::new(),
+    };
+    let gltf = gltf::Gltf::from_slice(&glb.to_vec().unwrap()).unwrap();
+    let blob = gltf.blob.as_ref().unwrap();
+
+    let scene = gltf.scenes().nth(report.scene.unwrap().value()).unwrap();
+    #[cfg(feature = "names")]
+    assert_eq!(scene.name(), Some("debug"));
+    let primitives = scene.primitives().collect::<Vec<_>>();
+    assert_eq!(primitives.len(), gizmo_count);
+    for (primitive, _) in &primitives {
+        assert_eq!(primitive.mode(), Mode::Lines);
+        let reader = primitive.reader(|_| Some(&blob[..]));
+        let positions = reader.read_positions().unwrap().collect::<Vec<_>>();
+        assert!(!positions.is_empty());
+        assert_eq!(positions.len() % 2, 0);
+        let bounds = primitive.bounding_box().unwrap();
+        for p in positions {
+            for (i, &x) in p.iter().enumerate() {
+                assert!(bounds.min[i] <= x && x <= bounds.max[i]);
+            }
+        }
+    }
+
+    // The gizmos share the world transforms of the cameras and lights.
+    let translations = primitives.iter().map(|(_, m)| m[3]).collect::<Vec<_>>();
+    assert_eq!(translations[0], [1.0, 5.0, 0.0, 1.0]);
+    assert_eq!(translations[1], [0.0, 5.0, 0.0, 1.0]);
+
+    // The orthographic frustum spans its clipping planes.
+    let bounds = primitives[1].0.bounding_box().unwrap();
+    assert_eq!(bounds.min, [-1.0, -1.0, -10.0]);
+    assert_eq!(bounds.max, [1.0, 1.0, 0.0]);
+}
+
+#[test]
+fn test_append_gizmos_without_cameras_or_lights() {
+    let mut root = gltf::json::Root::from_str(r#"{"asset": {"version": "2.0"}}"#).unwrap();
+    let mut buffers = Vec::new();
+    let report = gizmos::append_gizmos(&mut root, &mut buffers).unwrap();
+    assert!(report.scene.is_none());
+    assert!(root.scenes.is_empty());
+    assert!(buffers.is_empty());
+}
@@ -0,0 +1,131 @@
+use std::borrow::Cow;
+use std::{fs, io};
+
+use gltf::binary::Glb;
+
+const LICENSE: &[u8] = b"CC-BY 4.0";
+
+/// Returns `examples/Box.glb` with a `LICX` chunk appended after BIN.
+fn with_license_chunk() -> Vec<u8> {
+    let original = fs::read("examples/Box.glb").unwrap();
+    let mut glb = Glb::from_slice(&original).unwrap();
+    assert!(glb.extra_chunks.is_empty());
+    glb.extra_chunks.push((*b"LICX", Cow::Borrowed(LICENSE)));
+    let output = glb.to_vec().unwrap();
+
+    // The original bytes are untouched and followed by the padded chunk.
+    assert_eq!(&output[12..original.len()], &original[12..]);
+    let chunk = &output[original.len()..];
+    assert_eq!(chunk.len(), 8 + 12);
+    assert_eq!(&chunk[..8], &[12, 0, 0, 0, b'L', b'I', b'C', b'X']);
+    assert_eq!(&chunk[8..8 + LICENSE.len()], LICENSE);
+    assert_eq!(&chunk[8 + LICENSE.len()..], &[0, 0, 0]);
+    assert_eq!(&output[8..12], &(output.len() as u32).to_le_bytes());
+    output
+}
+
+#[test]
+fn test_glb_extra_chunk_roundtrip() {
+    let bytes = with_license_chunk();
+    let glb = Glb::from_slice(&bytes).unwrap();
+    let types = glb.chunks().map(|(ty, _)| ty).collect::<Vec<_>>();
+    assert_eq!(types, vec![*b"JSON", *b"BIN\0", *b"LICX"]);
+    let (_, license) = glb.chunks().last().unwrap();
+    assert_eq!(&license[..LICENSE.len()], LICENSE);
+    assert_eq!(glb.to_vec().unwrap(), bytes);
+
+    let glb = Glb::from_reader(io::Cursor::new(&bytes)).unwrap();
+    assert_eq!(glb.extra_chunks.len(), 1);
+    assert_eq!(glb.to_vec().unwrap(), bytes);
+}
+
+#[test]
+fn test_glb_extra_chunk_import() {
+    let bytes = with_license_chunk();
+    let gltf = gltf::Gltf::from_slice(&bytes).unwrap();
+    let streamed = gltf::Gltf::from_reader_streaming(io::Cursor::new(&bytes)).unwrap();
+    assert_eq!(gltf.blob, streamed.blob);
+    assert!(gltf.blob.is_some());
+}
+
+#[test]
+fn test_glb_misplaced_bin_chunk() {
+    let original = fs::read("examples/Box.glb").unwrap();
+    let mut glb = Glb::from_slice(&original).unwrap();
+    let bin = glb.bin.take().unwrap();
+    glb.extra_chunks.push((*b"LICX", Cow::Borrowed(LICENSE)));
+    glb.extra_chunks.push((*b"BIN\0", bin));
+    let bytes = glb.to_vec().unwrap();
+    match Glb::from_slice(&bytes) {
+        Err(gltf::Error::Binary(gltf::binary::Error::ChunkType(gltf::binary::ChunkType::Bin))) => {},
+        x => panic!("unexpected result: {:?}", x.map(|_| ())),
+    }
+}
+
+#[test]
+fn test_glb_missing_bin_chunk() {
+    let bytes = fs::read("tests/glb_missing_bin.glb").unwrap();
+    let results = vec![
+        gltf::Gltf::from_slice(&bytes),
+        gltf::Gltf::from_slice_without_validation(&bytes),
+        gltf::Gltf::from_reader(io::Cursor::new(&bytes)),
+        gltf::Gltf::from_reader_streaming(io::Cursor::new(&bytes)),
+    ];
+    for result in results {
+        match result {
+            Err(gltf::Error::MissingBlob { buffer: 0 }) => {},
+            result => panic!("expected a missing BIN chunk error: {:?}", result.map(|_| ())),
+        }
+    }
+}
+
+#[test]
+fn test_glb_unused_bin_chunk() {
+    let gltf = gltf::Gltf::open("tests/glb_unused_bin.glb").unwrap();
+    assert_eq!(gltf.blob.as_deref(), Some(&[9, 9, 9, 9][..]));
+    let report = gltf.validation_report();
+    let entries = report.entries.iter().map(|x| (x.path.as_str(), x.error)).collect::<Vec<_>>();
+    assert_eq!(entries, vec![("buffers", gltf::validation::Error::BinChunkUnused)]);
+    assert!(gltf.validate_strict().is_err());
+}
+
+#[cfg(feature = "import")]
+#[test]
+fn test_glb_shadowed_bin_chunk() {
+    let gltf = gltf::Gltf::open("tests/glb_shadowed_bin.glb").unwrap();
+    let report = gltf.validation_report();
+    let entries = report.entries.iter().map(|x| (x.path.as_str(), x.error)).collect::<Vec<_>>();
+    assert_eq!(entries, vec![("buffers[0].uri", gltf::validation::Error::BinChunkShadowed)]);
+    assert!(gltf.validate_strict().is_err());
+
+    // The URI takes precedence over the chunk.
+    let (_, buffers, _) = gltf::import("tests/glb_shadowed_bin.glb").unwrap();
+    assert_eq!(&buffers[0][..], &[1, 2, 3, 4]);
+}
+
+#[test]
+fn test_buffer_uri_missing() {
+    // A second buffer of binary glTF cannot refer to the BIN chunk.
+    let original = fs::read("examples/Box.glb").unwrap();
+    let mut glb = Glb::from_slice(&original).unwrap();
+    let mut root = gltf::json::Root::from_slice(&glb.json).unwrap();
+    root.buffers.push(root.buffers[0].clone());
+    glb.json = Cow::Owned(root.to_vec().unwrap());
+    let mut gltf = gltf::Gltf::from_slice(&glb.to_vec().unwrap()).unwrap();
+    let report = gltf.validation_report();
+    let entries = report.entries.iter().map(|x| (x.path.as_str(), x.error)).collect::<Vec<_>>();
+    assert_eq!(entries, vec![("buffers[1].uri", gltf::validation::Error::BufferUriMissing)]);
+    assert!(gltf.validate_strict().is_err());
+
+    assert!(matches!(gltf.buffers().next().unwrap().source(), gltf::buffer::Source::Bin));
+    assert_eq!(gltf.blob(), glb.bin.as_deref());
+    assert_eq!(gltf.take_blob().as_deref(), glb.bin.as_deref());
+    assert!(gltf.blob().is_none());
+
+    // Standard glTF has no BIN chunk for the first buffer to refer to.
+    let json = r#"{"asset": {"version": "2.0"}, "buffers": [{"byteLength": 4}]}"#;
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    let report = gltf.validation_report();
+    let entries = report.entries.iter().map(|x| (x.path.as_str(), x.error)).collect::<Vec<_>>();
+    assert_eq!(entries, vec![("buffers[0].uri", gltf::validation::Error::BufferUriMissing)]);
+}
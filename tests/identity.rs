@@ -0,0 +1,110 @@
+use gltf::json::identity::{Identities, Kind};
+use gltf::json::repack::Layout;
+use gltf::json::{Index, Root};
+
+/// Two scenes sharing a mesh, whose accessors 0 and 2 are identical, and an
+/// image stored in a buffer view.
+const SCENES: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 52}],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 36},
+        {"buffer": 0, "byteOffset": 36, "byteLength": 4},
+        {"buffer": 0, "byteOffset": 40, "byteLength": 12}
+    ],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [0, 0, 0]},
+        {"bufferView": 2, "componentType": 5126, "count": 3, "type": "SCALAR"},
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [0, 0, 0]}
+    ],
+    "images": [{"bufferView": 1, "mimeType": "image/png"}],
+    "meshes": [
+        {"primitives": [{"attributes": {"POSITION": 0}}]},
+        {"primitives": [{"attributes": {"POSITION": 2}}]}
+    ],
+    "nodes": [{"mesh": 0}, {"mesh": 1}, {"children": [1]}],
+    "scenes": [{"nodes": [0]}, {"nodes": [2]}]
+}"#;
+
+#[test]
+fn test_track_identity() {
+    let options = gltf::ParseOptions::default().track_identity(true);
+    let gltf = gltf::Gltf::from_slice_with_options(SCENES.as_bytes(), &options).unwrap();
+    let identities = gltf.identities().unwrap();
+    let ids = (0..3).map(|i| identities.get(Kind::Node, i).unwrap()).collect::<Vec<_>>();
+    assert_eq!(identities.find(ids[2]), Some((Kind::Node, 2)));
+    assert_eq!(identities.get(Kind::Node, 3), None);
+    assert_ne!(identities.get(Kind::Accessor, 0), identities.get(Kind::Mesh, 0));
+
+    assert!(gltf::Gltf::from_slice(SCENES.as_bytes()).unwrap().identities().is_none());
+}
+
+#[test]
+fn test_deduplicate() {
+    let mut root = Root::from_str(SCENES).unwrap();
+    let mut identities = Identities::new(&root);
+    let ids = (0..3).map(|i| identities.get(Kind::Accessor, i).unwrap()).collect::<Vec<_>>();
+    let mesh = identities.get(Kind::Mesh, 1).unwrap();
+
+    let report = root.deduplicate_accessors();
+    assert_eq!(report.removed_accessors, vec![2]);
+    let moved = identities.apply(&report.remap, &root);
+    assert_eq!(moved[&ids[0]], Some(0));
+    assert_eq!(moved[&ids[1]], Some(1));
+    // The duplicate maps to the accessor replacing it and is forgotten.
+    assert_eq!(moved[&ids[2]], Some(0));
+    assert_eq!(identities.find(ids[0]), Some((Kind::Accessor, 0)));
+    assert_eq!(identities.find(ids[2]), None);
+    assert_eq!(moved[&mesh], Some(1));
+}
+
+#[test]
+fn test_extract_scene() {
+    let root = Root::from_str(SCENES).unwrap();
+    let mut identities = Identities::new(&root);
+    let nodes = (0..3).map(|i| identities.get(Kind::Node, i).unwrap()).collect::<Vec<_>>();
+    let scene = identities.get(Kind::Scene, 1).unwrap();
+
+    let (extracted, report) = root.extract_scene(Index::new(1)).unwrap();
+    let moved = identities.apply(&report.remap, &extracted);
+    assert_eq!(moved[&nodes[0]], None);
+    assert_eq!(moved[&nodes[1]], Some(0));
+    assert_eq!(moved[&nodes[2]], Some(1));
+    assert_eq!(identities.find(scene), Some((Kind::Scene, 0)));
+    assert_eq!(identities.get(Kind::Node, 1), Some(nodes[2]));
+    assert_eq!(identities.find(nodes[0]), None);
+}
+
+#[test]
+fn test_repack_buffers() {
+    let mut root = Root::from_str(SCENES).unwrap();
+    let mut identities = Identities::new(&root);
+    let views = (0..3).map(|i| identities.get(Kind::BufferView, i).unwrap()).collect::<Vec<_>>();
+    let accessor = identities.get(Kind::Accessor, 1).unwrap();
+
+    let mut buffers = vec![vec![0; 52]];
+    let report = root.repack_buffers(&mut buffers, Layout::Separate).unwrap();
+    let moved = identities.apply(&report.remap, &root);
+    // The image view is copied and keeps its ID; the accessor data moved to
+    // new views, which receive new IDs.
+    let image_view = root.images[0].buffer_view.unwrap().value();
+    assert_eq!(moved[&views[1]], Some(image_view));
+    assert_eq!(moved[&views[0]], None);
+    assert_eq!(identities.get(Kind::BufferView, image_view), Some(views[1]));
+    for view in (0..root.buffer_views.len()).filter(|&x| x != image_view) {
+        assert!(!views.contains(&identities.get(Kind::BufferView, view).unwrap()));
+    }
+    assert_eq!(moved[&accessor], Some(1));
+}
+
+#[test]
+fn test_sync() {
+    let mut root = Root::from_str(SCENES).unwrap();
+    let mut identities = Identities::new(&root);
+    let last = identities.get(Kind::Node, 2).unwrap();
+    root.push_node(Default::default());
+    identities.sync(&root);
+    let new = identities.get(Kind::Node, 3).unwrap();
+    assert!(new > last);
+    assert_eq!(identities.get(Kind::Node, 2), Some(last));
+}
@@ -0,0 +1,137 @@
+#![cfg(feature = "async")]
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::io;
+use std::sync::Mutex;
+use std::task::{Context, Poll};
+
+const PNG: &str = "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAIAAACQd1PeAAAADElEQVR4nGP4z8AAAAMBAQDJ/pLvAAAAAElFTkSuQmCC";
+
+/// Binary glTF with a `BIN` chunk, an external buffer, and a data URI
+/// buffer, and with two images referring to the same external file.
+const JSON: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [
+        {"byteLength": 12},
+        {"byteLength": 6, "uri": "extra.bin"},
+        {"byteLength": 3, "uri": "data:application/octet-stream;base64,AQID"}
+    ],
+    "images": [{"uri": "image.png"}, {"uri": "textures/../image.png"}, {"uri": "image.png"}]
+}"#;
+
+/// Runs a future to completion on a Tokio runtime, whose blocking thread
+/// pool runs the parsing and decoding of the import.
+fn block_on<F: Future>(future: F) -> F::Output {
+    tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(future)
+}
+
+/// Returns `Pending` once before completing, like a read that waits on I/O.
+struct YieldOnce(bool);
+
+impl Future for YieldOnce {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            Poll::Ready(())
+        } else {
+            self.0 = true;
+            context.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+/// Files by URI, recording the URIs that were read.
+struct Memory {
+    files: HashMap<&'static str, Vec<u8>>,
+    reads: Mutex<Vec<String>>,
+}
+
+impl gltf::AsyncSource for Memory {
+    async fn read_uri(&self, uri: &str) -> io::Result<Vec<u8>> {
+        YieldOnce(false).await;
+        self.reads.lock().unwrap().push(uri.to_string());
+        let path = uri.replace("textures/../", "");
+        self.files.get(&*path).cloned().ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))
+    }
+}
+
+fn memory() -> Memory {
+    let glb = gltf::binary::Glb {
+        header: gltf::binary::Header { magic: *b"glTF", version: 2, length: 0 },
+        json: Cow::Borrowed(JSON.as_bytes()),
+        bin: Some(Cow::Owned((0..12).collect())),
+        extra_chunks: Vec::new(),
+    };
+    let mut files = HashMap::new();
+    files.insert("scene.glb", glb.to_vec().unwrap());
+    files.insert("extra.bin", vec![9; 6]);
+    files.insert("image.png", base64::decode(PNG).unwrap());
+    Memory { files, reads: Mutex::new(Vec::new()) }
+}
+
+#[test]
+fn test_source() {
+    let source = memory();
+    let (document, buffers, images) = block_on(gltf::import_async_with_source(&source, "scene.glb")).unwrap();
+    assert_eq!(document.buffers().len(), 3);
+    assert_eq!(&buffers[0][..], &(0..12).collect::<Vec<u8>>()[..]);
+    assert_eq!(&buffers[1][..], &[9, 9, 9, 9, 9, 9, 0, 0]);
+    assert_eq!(&buffers[2][..], &[1, 2, 3, 0]);
+    assert_eq!(images.len(), 3);
+    assert!(images.iter().all(|x| (x.width, x.height) == (1, 1)));
+
+    // Every URI is read once, in the order of the document.
+    let reads = source.reads.lock().unwrap().clone();
+    assert_eq!(reads, vec!["scene.glb", "extra.bin", "image.png", "textures/../image.png"]);
+}
+
+#[test]
+fn test_same_as_sync() {
+    // The same files, imported from the file system.
+    let source = memory();
+    let directory = std::env::temp_dir().join(format!("gltf-import-async-{}", std::process::id()));
+    std::fs::create_dir_all(directory.join("textures")).unwrap();
+    for (name, data) in &source.files {
+        std::fs::write(directory.join(name), data).unwrap();
+    }
+    let (_, expected_buffers, expected_images) = gltf::import(directory.join("scene.glb")).unwrap();
+    let (_, buffers, images) = block_on(gltf::import_async(directory.join("scene.glb"))).unwrap();
+    std::fs::remove_dir_all(&directory).unwrap();
+
+    let bytes = |buffers: &[gltf::buffer::Data]| buffers.iter().map(|x| x.to_vec()).collect::<Vec<_>>();
+    assert_eq!(bytes(&buffers), bytes(&expected_buffers));
+    let pixels = |images: &[gltf::image::Data]| images.iter().map(|x| x.pixels.clone()).collect::<Vec<_>>();
+    assert_eq!(pixels(&images), pixels(&expected_images));
+
+    let (_, buffers, _) = block_on(gltf::import_async("examples/Box.gltf")).unwrap();
+    let (_, expected_buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    assert_eq!(bytes(&buffers), bytes(&expected_buffers));
+}
+
+#[test]
+fn test_errors() {
+    let mut source = memory();
+    source.files.remove("image.png");
+    match block_on(gltf::import_async_with_source(&source, "scene.glb")) {
+        Err(gltf::Error::Io(error)) => assert_eq!(error.kind(), io::ErrorKind::NotFound),
+        x => panic!("unexpected result: {:?}", x.map(|_| ())),
+    }
+
+    // Buffers shorter than their declared length are rejected as by `import`.
+    source.files.insert("image.png", Vec::new());
+    source.files.insert("extra.bin", vec![0; 2]);
+    assert!(block_on(gltf::import_async_with_source(&source, "scene.glb")).is_err());
+}
+
+#[test]
+fn test_send() {
+    fn assert_send<T: Send>(_: T) {}
+    let source = memory();
+    assert_send(gltf::import_async_with_source(&source, "scene.glb"));
+    assert_send(gltf::import_async("examples/Box.gltf"));
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "import")]
+
 use std::{fs, path};
 use std::error::Error as StdError;
 
@@ -13,7 +15,7 @@ fn sanity_check(
         return Err(());
     }
     for (buf, data) in document.buffers().zip(buffer_data.iter()) {
-        if ((buf.length() + 3) & !3) != data.0.len() {
+        if ((buf.length() + 3) & !3) != data.len() {
             return Err(());
         }
     }
@@ -0,0 +1,103 @@
+use gltf::json::validation::Error;
+
+/// A triangle whose indices accessor is given by `indices`, and whose indices
+/// buffer view is given by `view`.
+fn document(indices: &str, view: &str) -> String {
+    format!(r#"{{
+        "asset": {{"version": "2.0"}},
+        "buffers": [{{"byteLength": 48, "uri": "data.bin"}}],
+        "bufferViews": [{{"buffer": 0, "byteLength": 36}}, {{"buffer": 0, "byteOffset": 36, "byteLength": 12{}}}],
+        "accessors": [
+            {{"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0]}},
+            {{"bufferView": 1, "count": 3, {}}}
+        ],
+        "meshes": [{{"primitives": [{{"attributes": {{"POSITION": 0}}, "indices": 1}}]}}]
+    }}"#, view, indices)
+}
+
+const VALID: &str = r#""componentType": 5123, "type": "SCALAR""#;
+
+fn errors(json: &str) -> Vec<(String, Error)> {
+    match gltf::Gltf::from_slice(json.as_bytes()) {
+        Ok(_) => Vec::new(),
+        Err(gltf::Error::Validation(errors)) => {
+            errors.into_iter().map(|(path, error)| (path.0, error)).collect()
+        },
+        Err(err) => panic!("unexpected error: {}", err),
+    }
+}
+
+#[test]
+fn test_valid() {
+    for indices in &[VALID, r#""componentType": 5121, "type": "SCALAR""#, r#""componentType": 5125, "type": "SCALAR""#] {
+        assert_eq!(errors(&document(indices, "")), vec![], "{}", indices);
+    }
+}
+
+#[test]
+fn test_violations() {
+    let variants = [
+        // Not `SCALAR`.
+        (r#""componentType": 5123, "type": "VEC2""#, ""),
+        // Floats.
+        (r#""componentType": 5126, "type": "SCALAR""#, ""),
+        // Signed.
+        (r#""componentType": 5122, "type": "SCALAR""#, ""),
+        // Normalized.
+        (r#""componentType": 5123, "normalized": true, "type": "SCALAR""#, ""),
+        // Strided.
+        (VALID, r#", "byteStride": 4"#),
+    ];
+    for &(indices, view) in &variants {
+        let errors = errors(&document(indices, view));
+        assert_eq!(errors, vec![("meshes[0].primitives[0].indices".to_string(), Error::InvalidIndexAccessor)], "{}", indices);
+        assert_eq!(errors[0].1.code(), "INVALID_INDEX_ACCESSOR");
+    }
+}
+
+#[test]
+fn test_sparse_indices() {
+    let json = r#"{
+        "asset": {"version": "2.0"},
+        "buffers": [{"byteLength": 16, "uri": "data.bin"}],
+        "bufferViews": [{"buffer": 0, "byteLength": 4, "byteStride": 4}, {"buffer": 0, "byteOffset": 4, "byteLength": 12}],
+        "accessors": [{
+            "componentType": 5126,
+            "count": 3,
+            "type": "VEC3",
+            "sparse": {"count": 1, "indices": {"bufferView": 0, "componentType": 5123}, "values": {"bufferView": 1}}
+        }]
+    }"#;
+    assert_eq!(
+        errors(json),
+        vec![("accessors[0].sparse.indices.bufferView".to_string(), Error::InvalidIndexAccessor)],
+    );
+}
+
+#[cfg(feature = "utils")]
+#[test]
+fn test_lenient_reader() {
+    let mut data = Vec::new();
+    for value in &[0.0f32, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0] {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+    data.extend_from_slice(&[0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 0, 0]);
+
+    let gltf = gltf::Gltf::from_slice_without_validation(document(VALID, "").as_bytes()).unwrap();
+    let primitive = gltf.meshes().next().unwrap().primitives().next().unwrap();
+    assert!(primitive.has_valid_indices());
+    let reader = primitive.reader(|_| Some(&data));
+    assert_eq!(reader.read_indices().unwrap().into_u32().collect::<Vec<_>>(), vec![0, 1, 2]);
+
+    let json = document(r#""componentType": 5123, "type": "VEC2""#, "");
+    let gltf = gltf::Gltf::from_slice_without_validation(json.as_bytes()).unwrap();
+    let primitive = gltf.meshes().next().unwrap().primitives().next().unwrap();
+    assert!(!primitive.has_valid_indices());
+    // The primitive is skipped rather than read with misread indices.
+    let reader = primitive.reader(|_| Some(&data));
+    assert!(reader.read_indices().is_none());
+    assert!(reader.read_positions().is_none());
+    assert_eq!(reader.read_morph_targets().count(), 0);
+    let codes = gltf.validation_report().entries.iter().map(|x| x.code()).collect::<Vec<_>>();
+    assert_eq!(codes, vec!["INVALID_INDEX_ACCESSOR"]);
+}
@@ -0,0 +1,84 @@
+#![cfg(feature = "utils")]
+
+/// Three vertices with eight influences in two joint sets. `JOINTS_0` stores
+/// bytes and `JOINTS_1` stores shorts.
+const TWO_SETS: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 168}],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 36},
+        {"buffer": 0, "byteOffset": 36, "byteLength": 12},
+        {"buffer": 0, "byteOffset": 48, "byteLength": 48},
+        {"buffer": 0, "byteOffset": 96, "byteLength": 24},
+        {"buffer": 0, "byteOffset": 120, "byteLength": 48}
+    ],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [0, 0, 0]},
+        {"bufferView": 1, "componentType": 5121, "count": 3, "type": "VEC4"},
+        {"bufferView": 2, "componentType": 5126, "count": 3, "type": "VEC4"},
+        {"bufferView": 3, "componentType": 5123, "count": 3, "type": "VEC4"},
+        {"bufferView": 4, "componentType": 5126, "count": 3, "type": "VEC4"}
+    ],
+    "meshes": [{
+        "primitives": [{
+            "attributes": {"POSITION": 0, "JOINTS_0": 1, "WEIGHTS_0": 2, "JOINTS_1": 3, "WEIGHTS_1": 4},
+            "mode": 0
+        }]
+    }]
+}"#;
+
+const JOINTS_0: [[u8; 4]; 3] = [[0, 1, 2, 3], [10, 11, 12, 13], [20, 21, 22, 23]];
+const WEIGHTS_0: [[f32; 4]; 3] = [[0.1, 0.3, 0.05, 0.1], [0.0; 4], [0.0; 4]];
+const JOINTS_1: [[u16; 4]; 3] = [[4, 5, 6, 7], [8, 9, 300, 301], [24, 25, 26, 27]];
+const WEIGHTS_1: [[f32; 4]; 3] = [[0.2, 0.1, 0.05, 0.1], [0.5, 0.5, 0.0, 0.0], [0.0; 4]];
+
+fn buffer() -> Vec<u8> {
+    let mut data = vec![0; 36];
+    data.extend(JOINTS_0.iter().flatten());
+    data.extend(WEIGHTS_0.iter().flatten().flat_map(|x| x.to_le_bytes()));
+    data.extend(JOINTS_1.iter().flatten().flat_map(|x| x.to_le_bytes()));
+    data.extend(WEIGHTS_1.iter().flatten().flat_map(|x| x.to_le_bytes()));
+    data
+}
+
+#[test]
+fn test_joint_set_count() {
+    let gltf = gltf::Gltf::from_slice(TWO_SETS.as_bytes()).unwrap();
+    let primitive = gltf.meshes().next().unwrap().primitives().next().unwrap();
+    assert_eq!(primitive.joint_set_count(), 2);
+
+    // A set without weights is not counted.
+    let json = TWO_SETS.replace(r#", "WEIGHTS_1": 4"#, "");
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    let primitive = gltf.meshes().next().unwrap().primitives().next().unwrap();
+    assert_eq!(primitive.joint_set_count(), 1);
+
+    let gltf = gltf::Gltf::open("examples/Box.gltf").unwrap();
+    let primitive = gltf.meshes().next().unwrap().primitives().next().unwrap();
+    assert_eq!(primitive.joint_set_count(), 0);
+}
+
+#[test]
+fn test_merged_influences() {
+    let gltf = gltf::Gltf::from_slice(TWO_SETS.as_bytes()).unwrap();
+    let data = buffer();
+    let primitive = gltf.meshes().next().unwrap().primitives().next().unwrap();
+    let reader = primitive.reader(|_| Some(&data[..]));
+    let joints = reader.read_joints(1).unwrap().into_u16().collect::<Vec<_>>();
+    assert_eq!(joints, JOINTS_1.to_vec());
+
+    let influences = reader.read_merged_influences().unwrap();
+    // The three influences of weight 0.1 are tied, and the first two in set
+    // and component order are retained.
+    let sum = 0.3f32 + 0.2 + 0.1 + 0.1;
+    assert_eq!(influences.joints[0], [1, 4, 0, 3]);
+    assert_eq!(influences.weights[0], [0.3 / sum, 0.2 / sum, 0.1 / sum, 0.1 / sum]);
+    assert_eq!(influences.joints[1], [8, 9, 10, 11]);
+    assert_eq!(influences.weights[1], [0.5, 0.5, 0.0, 0.0]);
+    assert_eq!(influences.joints[2], [20, 21, 22, 23]);
+    assert_eq!(influences.weights[2], [0.0; 4]);
+
+    let gltf = gltf::Gltf::open("examples/Box.gltf").unwrap();
+    let primitive = gltf.meshes().next().unwrap().primitives().next().unwrap();
+    assert!(primitive.reader(|_| None).read_merged_influences().is_none());
+}
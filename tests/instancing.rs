@@ -0,0 +1,71 @@
+/// Mesh 0 is instantiated by two static nodes, mesh 1 by a static node and the
+/// child of an animated node, and mesh 2 by a skinned node. Mesh 3 is only
+/// referenced outside the scene.
+const INSTANCES: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 44}],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 36},
+        {"buffer": 0, "byteOffset": 36, "byteLength": 4},
+        {"buffer": 0, "byteOffset": 40, "byteLength": 4}
+    ],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0]},
+        {"bufferView": 1, "componentType": 5126, "count": 1, "type": "SCALAR", "min": [0], "max": [0]},
+        {"bufferView": 2, "componentType": 5126, "count": 1, "type": "SCALAR"}
+    ],
+    "meshes": [
+        {"primitives": [{"attributes": {"POSITION": 0}}]},
+        {"primitives": [{"attributes": {"POSITION": 0}}]},
+        {"primitives": [{"attributes": {"POSITION": 0}}]},
+        {"primitives": [{"attributes": {"POSITION": 0}}]}
+    ],
+    "skins": [{"joints": [6]}],
+    "nodes": [
+        {"mesh": 0, "translation": [1, 0, 0]},
+        {"mesh": 0, "translation": [2, 0, 0]},
+        {"mesh": 1},
+        {"children": [4], "translation": [0, 5, 0]},
+        {"mesh": 1, "translation": [3, 0, 0]},
+        {"mesh": 2, "skin": 0, "children": [6]},
+        {},
+        {"mesh": 3}
+    ],
+    "animations": [{
+        "channels": [{"sampler": 0, "target": {"node": 3, "path": "rotation"}}],
+        "samplers": [{"input": 1, "output": 2}]
+    }],
+    "scene": 0,
+    "scenes": [{"nodes": [0, 1, 2, 3, 5]}]
+}"#;
+
+#[test]
+fn test_mesh_instances() {
+    let gltf = gltf::Gltf::from_slice(INSTANCES.as_bytes()).unwrap();
+    let scene = gltf.default_scene().unwrap();
+    let groups = gltf.mesh_instances(&scene);
+    let meshes = groups.iter().map(|x| x.mesh.index()).collect::<Vec<_>>();
+    assert_eq!(meshes, vec![0, 1, 2]);
+
+    let nodes = groups.iter()
+        .map(|group| group.instances.iter().map(|(node, _)| node.index()).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    assert_eq!(nodes, vec![vec![0, 1], vec![2, 4], vec![5]]);
+
+    assert!(groups[0].is_static());
+    assert_eq!(groups[0].instances[1].1[3], [2.0, 0.0, 0.0, 1.0]);
+
+    // Node 4 inherits the animation of its parent.
+    assert!(groups[1].animated && !groups[1].skinned);
+    assert_eq!(groups[1].instances[1].1[3], [3.0, 5.0, 0.0, 1.0]);
+
+    assert!(groups[2].skinned && !groups[2].animated);
+}
+
+#[test]
+fn test_mesh_instances_of_empty_scene() {
+    let json = r#"{"asset": {"version": "2.0"}, "scenes": [{"nodes": []}]}"#;
+    let empty = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    let scene = empty.scenes().next().unwrap();
+    assert!(empty.mesh_instances(&scene).is_empty());
+}
@@ -0,0 +1,74 @@
+#![cfg(all(feature = "instrument", feature = "import"))]
+
+use std::sync::{Arc, Mutex};
+
+use gltf::instrument::{self, Stage};
+
+/// Collects the messages of the `gltf` log records.
+struct Logger(Mutex<Vec<String>>);
+
+impl log::Log for Logger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.target() == "gltf"
+    }
+
+    fn log(&self, record: &log::Record) {
+        if self.enabled(record.metadata()) {
+            self.0.lock().unwrap().push(record.args().to_string());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: Logger = Logger(Mutex::new(Vec::new()));
+
+#[test]
+fn test_import_timeline() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let sink = events.clone();
+    instrument::set_listener(move |event| {
+        let uri = event.uri.map(String::from);
+        sink.lock().unwrap().push((event.stage, event.index, uri, event.bytes, event.to_string()));
+    });
+    let (_, buffers, images) = gltf::import("tests/instrument.gltf").unwrap();
+    instrument::clear_listener();
+    assert_eq!(buffers.len(), 1);
+    assert_eq!(images.len(), 1);
+
+    let events = events.lock().unwrap();
+    let stages = events.iter().map(|x| x.0).collect::<Vec<_>>();
+    assert_eq!(stages, vec![
+        Stage::Parse,
+        Stage::Validate,
+        Stage::ReadBuffer,
+        Stage::ReadImage,
+        Stage::DecodeImage,
+        Stage::Import,
+    ]);
+    assert_eq!(events[2].1, Some(0));
+    assert_eq!(events[2].2.as_deref(), Some("data:"));
+    assert_eq!(events[2].3, Some(12));
+    assert!(events[2].4.starts_with("read buffer 0 (data:): 12 bytes in "));
+    assert_eq!(events[3].3, Some(70));
+    assert_eq!(events[4].3, Some(70));
+    assert_eq!(events[5].3, Some(12));
+
+    // Nothing is reported without a listener.
+    gltf::import("tests/instrument.gltf").unwrap();
+    assert_eq!(events.len(), 6);
+}
+
+#[test]
+fn test_log_records() {
+    log::set_logger(&LOGGER).unwrap();
+    log::set_max_level(log::LevelFilter::Debug);
+    gltf::import("tests/instrument.gltf").unwrap();
+
+    // Records are logged without a listener, possibly along with those of
+    // the imports of other tests.
+    let records = LOGGER.0.lock().unwrap();
+    assert!(records.iter().any(|x| x.starts_with("parse: ")), "{:?}", records);
+    assert!(records.iter().any(|x| x.starts_with("read buffer 0 (data:): 12 bytes in ")), "{:?}", records);
+    assert!(records.iter().any(|x| x.starts_with("import: 12 bytes in ")), "{:?}", records);
+}
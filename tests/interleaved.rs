@@ -0,0 +1,95 @@
+#![cfg(feature = "utils")]
+
+/// Two primitives sharing one interleaved buffer view of positions, normals,
+/// and texture coordinates. The accessors of the second primitive begin past
+/// the first stride, so `offset % stride` selects the wrong vertex.
+const SHARED_INTERLEAVED: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 200}],
+    "bufferViews": [{"buffer": 0, "byteOffset": 8, "byteLength": 192, "byteStride": 32}],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 10, 20], "max": [2, 12, 22]},
+        {"bufferView": 0, "byteOffset": 12, "componentType": 5126, "count": 3, "type": "VEC3"},
+        {"bufferView": 0, "byteOffset": 24, "componentType": 5126, "count": 3, "type": "VEC2"},
+        {"bufferView": 0, "byteOffset": 96, "componentType": 5126, "count": 3, "type": "VEC3", "min": [3, 13, 23], "max": [5, 15, 25]},
+        {"bufferView": 0, "byteOffset": 108, "componentType": 5126, "count": 3, "type": "VEC3"},
+        {"bufferView": 0, "byteOffset": 120, "componentType": 5126, "count": 3, "type": "VEC2"}
+    ],
+    "meshes": [{
+        "primitives": [
+            {"attributes": {"POSITION": 0, "NORMAL": 1, "TEXCOORD_0": 2}},
+            {"attributes": {"POSITION": 3, "NORMAL": 4, "TEXCOORD_0": 5}}
+        ]
+    }]
+}"#;
+
+fn position(v: usize) -> [f32; 3] {
+    let v = v as f32;
+    [v, 10.0 + v, 20.0 + v]
+}
+
+fn normal(v: usize) -> [f32; 3] {
+    let v = v as f32;
+    [100.0 + v, 110.0 + v, 120.0 + v]
+}
+
+fn tex_coord(v: usize) -> [f32; 2] {
+    let v = v as f32;
+    [200.0 + v, 300.0 + v]
+}
+
+fn buffer_data() -> Vec<u8> {
+    let mut data = vec![0xff; 8];
+    for v in 0..6 {
+        let (p, n, t) = (position(v), normal(v), tex_coord(v));
+        let vertex = p.iter().chain(&n).chain(&t);
+        data.extend(vertex.flat_map(|x| x.to_le_bytes().to_vec()));
+    }
+    data
+}
+
+#[test]
+fn test_shared_interleaved_view() {
+    let gltf = gltf::Gltf::from_slice(SHARED_INTERLEAVED.as_bytes()).unwrap();
+    let data = buffer_data();
+    let mesh = gltf.meshes().next().unwrap();
+    for (primitive, first) in mesh.primitives().zip(vec![0, 3]) {
+        let reader = primitive.reader(|_| Some(&data));
+        let positions = reader.read_positions().unwrap().collect::<Vec<_>>();
+        assert_eq!(positions, (first..first + 3).map(position).collect::<Vec<_>>());
+        let normals = reader.read_normals().unwrap().collect::<Vec<_>>();
+        assert_eq!(normals, (first..first + 3).map(normal).collect::<Vec<_>>());
+        let tex_coords = reader.read_tex_coords(0).unwrap().into_f32().collect::<Vec<_>>();
+        assert_eq!(tex_coords, (first..first + 3).map(tex_coord).collect::<Vec<_>>());
+
+        // Random access agrees with sequential iteration.
+        assert_eq!(reader.read_normals().unwrap().nth(2), Some(normal(first + 2)));
+        assert_eq!(reader.read_tex_coords(0).unwrap().into_f32().last(), Some(tex_coord(first + 2)));
+    }
+}
+
+#[test]
+fn test_absolute_offset() {
+    let gltf = gltf::Gltf::from_slice(SHARED_INTERLEAVED.as_bytes()).unwrap();
+    let offsets = gltf.accessors().map(|x| x.absolute_offset()).collect::<Vec<_>>();
+    assert_eq!(offsets, vec![Some(8), Some(20), Some(32), Some(104), Some(116), Some(128)]);
+
+    let data = buffer_data();
+    let accessor = gltf.accessors().nth(4).unwrap();
+    let start = accessor.absolute_offset().unwrap() + 2 * accessor.effective_stride().unwrap();
+    let x = f32::from_le_bytes([data[start], data[start + 1], data[start + 2], data[start + 3]]);
+    assert_eq!(x, normal(5)[0]);
+
+    let json = r#"{
+        "asset": {"version": "2.0"},
+        "accessors": [{"componentType": 5126, "count": 1, "type": "SCALAR", "sparse": {
+            "count": 1,
+            "indices": {"bufferView": 0, "componentType": 5123},
+            "values": {"bufferView": 0}
+        }}],
+        "buffers": [{"byteLength": 4}],
+        "bufferViews": [{"buffer": 0, "byteLength": 4}]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    assert_eq!(gltf.accessors().next().unwrap().absolute_offset(), None);
+}
@@ -0,0 +1,60 @@
+const DOCUMENT: &str = r#"{
+    "asset": {"version": "2.0"},
+    "extensionsUsed": ["VENDOR_z", "KHR_materials_unlit", "VENDOR_a", "KHR_materials_pbrSpecularGlossiness"],
+    "extensions": {"VENDOR_z": {}, "VENDOR_m": {"b": 1, "a": 2}, "VENDOR_a": {}},
+    "buffers": [{"byteLength": 24, "uri": "data.bin"}],
+    "bufferViews": [{"buffer": 0, "byteLength": 24}],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 1, "type": "VEC3", "min": [0, 0, 0], "max": [0, 0, 0]},
+        {"bufferView": 0, "componentType": 5126, "count": 1, "type": "VEC2", "min": [0, 0], "max": [0, 0]},
+        {"bufferView": 0, "componentType": 5126, "count": 1, "type": "VEC4", "min": [0, 0, 0, 0], "max": [0, 0, 0, 0]}
+    ],
+    "materials": [{
+        "extensions": {"KHR_materials_unlit": {}, "KHR_materials_pbrSpecularGlossiness": {}}
+    }],
+    "meshes": [{"primitives": [{
+        "attributes": {"_ZED": 1, "TEXCOORD_1": 1, "COLOR_0": 2, "_ALPHA": 1, "TEXCOORD_0": 1, "NORMAL": 0, "POSITION": 0},
+        "material": 0
+    }]}]
+}"#;
+
+/// Records every iteration sequence whose order could depend on hashing.
+fn sequences(gltf: &gltf::Gltf) -> Vec<Vec<String>> {
+    let primitive = gltf.meshes().next().unwrap().primitives().next().unwrap();
+    vec![
+        primitive.attributes().map(|(semantic, _)| semantic.to_string()).collect(),
+        gltf.materials().next().unwrap().extension_names().map(String::from).collect(),
+        gltf.extension_names().into_iter().map(String::from).collect(),
+        gltf.extensions_used().map(String::from).collect(),
+        gltf.extension_value("VENDOR_m").unwrap().as_object().unwrap().keys().cloned().collect(),
+    ]
+}
+
+#[test]
+fn test_deterministic() {
+    let first = sequences(&gltf::Gltf::from_slice_without_validation(DOCUMENT.as_bytes()).unwrap());
+    let second = sequences(&gltf::Gltf::from_slice_without_validation(DOCUMENT.as_bytes()).unwrap());
+    assert_eq!(first, second);
+
+    assert_eq!(first[0], vec!["POSITION", "NORMAL", "TEXCOORD_0", "TEXCOORD_1", "COLOR_0", "_ALPHA", "_ZED"]);
+    let mut expected = Vec::new();
+    if cfg!(feature = "KHR_materials_pbrSpecularGlossiness") {
+        expected.push("KHR_materials_pbrSpecularGlossiness");
+    }
+    if cfg!(feature = "KHR_materials_unlit") {
+        expected.push("KHR_materials_unlit");
+    }
+    assert_eq!(first[1], expected);
+    assert_eq!(first[2], vec!["VENDOR_a", "VENDOR_m", "VENDOR_z"]);
+    assert_eq!(first[3], vec!["VENDOR_z", "KHR_materials_unlit", "VENDOR_a", "KHR_materials_pbrSpecularGlossiness"]);
+    assert_eq!(first[4], vec!["a", "b"]);
+}
+
+#[test]
+fn test_extension_lists_sorted() {
+    for list in &[gltf::json::extensions::ENABLED_EXTENSIONS, gltf::json::extensions::SUPPORTED_EXTENSIONS] {
+        let mut sorted = list.to_vec();
+        sorted.sort_unstable();
+        assert_eq!(&sorted, list);
+    }
+}
@@ -0,0 +1,122 @@
+#![cfg(feature = "utils")]
+
+use std::collections::HashMap;
+
+use gltf::animation::PreparedAnimation;
+use gltf::scene::TransformCache;
+
+/// A skinned mesh node translated by `[5, 0, 0]` next to a chain of two
+/// joints, below a root node translated by `[0, 0, 2]`. The first joint turns
+/// by 90 degrees about Z over one second.
+const RIG: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 168}],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 128},
+        {"buffer": 0, "byteOffset": 128, "byteLength": 8},
+        {"buffer": 0, "byteOffset": 136, "byteLength": 32}
+    ],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 2, "type": "MAT4"},
+        {"bufferView": 1, "componentType": 5126, "count": 2, "type": "SCALAR", "min": [0], "max": [1]},
+        {"bufferView": 2, "componentType": 5126, "count": 2, "type": "VEC4"}
+    ],
+    "scenes": [{"nodes": [0]}],
+    "nodes": [
+        {"children": [1, 2], "translation": [0, 0, 2]},
+        {"skin": 0, "translation": [5, 0, 0]},
+        {"children": [3], "translation": [0, 1, 0]},
+        {"translation": [0, 1, 0]}
+    ],
+    "skins": [{"joints": [2, 3], "inverseBindMatrices": 0}],
+    "animations": [{
+        "channels": [{"sampler": 0, "target": {"node": 2, "path": "rotation"}}],
+        "samplers": [{"input": 1, "output": 2}]
+    }]
+}"#;
+
+fn translation(x: f32, y: f32, z: f32) -> [f32; 16] {
+    [1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, x, y, z, 1.0]
+}
+
+fn buffer_data() -> Vec<u8> {
+    let half = std::f32::consts::FRAC_1_SQRT_2;
+    // The inverses of the world transforms of the joints in the rest pose.
+    let mut floats = translation(0.0, -1.0, -2.0).to_vec();
+    floats.extend_from_slice(&translation(0.0, -2.0, -2.0));
+    floats.extend_from_slice(&[0.0, 1.0]);
+    floats.extend_from_slice(&[0.0, 0.0, 0.0, 1.0, 0.0, 0.0, half, half]);
+    floats.iter().flat_map(|x| x.to_le_bytes().to_vec()).collect()
+}
+
+fn assert_near(actual: &[[[f32; 4]; 4]], expected: &[[[f32; 4]; 4]]) {
+    assert_eq!(actual.len(), expected.len());
+    for (a, b) in actual.iter().flatten().flatten().zip(expected.iter().flatten().flatten()) {
+        assert!((a - b).abs() < 1.0e-5, "{:?} != {:?}", actual, expected);
+    }
+}
+
+/// `translation(-5, 0, -2)`, undoing the world transform of the mesh node.
+const REST: [[f32; 4]; 4] = [
+    [1.0, 0.0, 0.0, 0.0],
+    [0.0, 1.0, 0.0, 0.0],
+    [0.0, 0.0, 1.0, 0.0],
+    [-5.0, 0.0, -2.0, 1.0],
+];
+
+/// `translation(-4, 1, -2) * rotation_z(90°)`, the turn of the first joint
+/// about its rest position followed by the undone mesh node transform.
+const TURNED: [[f32; 4]; 4] = [
+    [0.0, 1.0, 0.0, 0.0],
+    [-1.0, 0.0, 0.0, 0.0],
+    [0.0, 0.0, 1.0, 0.0],
+    [-4.0, 1.0, -2.0, 1.0],
+];
+
+#[test]
+fn test_joint_matrices() {
+    let gltf = gltf::Gltf::from_slice(RIG.as_bytes()).unwrap();
+    let data = buffer_data();
+    let get_buffer_data = |_| Some(&data[..]);
+    let scene = gltf.scenes().next().unwrap();
+    let node = gltf.nodes().nth(1).unwrap();
+    let skin = node.skin().unwrap();
+
+    let worlds = scene
+        .walk(Default::default())
+        .map(|(node, world)| (node.index(), world))
+        .collect::<HashMap<_, _>>();
+    let matrices = skin.joint_matrices(&node, |x| worlds.get(&x).copied(), get_buffer_data).unwrap();
+    assert_near(&matrices, &[REST, REST]);
+
+    let mut cache = TransformCache::new(&scene);
+    let animation = gltf.animations().next().unwrap();
+    PreparedAnimation::new(&animation, get_buffer_data).apply(1.0, &mut cache);
+    cache.update();
+    let matrices = skin.joint_matrices(&node, |x| cache.world(x), get_buffer_data).unwrap();
+    assert_near(&matrices, &[TURNED, TURNED]);
+
+    // Unknown world transforms and missing buffer data yield no matrices.
+    assert!(skin.joint_matrices(&node, |x| worlds.get(&x).copied().filter(|_| x != 3), get_buffer_data).is_none());
+    assert!(skin.joint_matrices(&node, |x| cache.world(x), |_| None).is_none());
+}
+
+#[test]
+fn test_skeleton_and_identity_bind_matrices() {
+    let json = RIG.replace(r#""inverseBindMatrices": 0"#, r#""skeleton": 2"#);
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    let scene = gltf.scenes().next().unwrap();
+    let node = gltf.nodes().nth(1).unwrap();
+    let skin = node.skin().unwrap();
+    assert_eq!(skin.skeleton().map(|x| x.index()), Some(2));
+
+    // Without inverse bind matrices, the joint matrices are relative to the
+    // mesh node.
+    let cache = TransformCache::new(&scene);
+    let matrices = skin.joint_matrices(&node, |x| cache.world(x), |_| None).unwrap();
+    let mut first = REST;
+    first[3] = [-5.0, 1.0, 0.0, 1.0];
+    let mut second = REST;
+    second[3] = [-5.0, 2.0, 0.0, 1.0];
+    assert_near(&matrices, &[first, second]);
+}
@@ -0,0 +1,61 @@
+use gltf::json::{self, material::{AlphaCutoff, EmissiveFactor, NormalTexture, OcclusionTexture, PbrBaseColorFactor, StrengthFactor}, texture, Index};
+
+#[test]
+fn test_defaults() {
+    let info = texture::Info::new(Index::new(2));
+    assert_eq!((info.index.value(), info.tex_coord), (2, 0));
+    assert!(!json::serialize::to_string(&info).unwrap().contains("texCoord"));
+
+    let normal = NormalTexture::new(Index::new(1));
+    assert_eq!(normal.scale, 1.0);
+    assert!(!json::serialize::to_string(&normal).unwrap().contains("scale"));
+
+    let occlusion = OcclusionTexture::new(Index::new(1));
+    assert_eq!(occlusion.strength.0, StrengthFactor::default().0);
+    assert!(!json::serialize::to_string(&occlusion).unwrap().contains("strength"));
+}
+
+#[test]
+fn test_in_range() {
+    assert_eq!(AlphaCutoff::new(2.0).0, 2.0);
+    assert_eq!(EmissiveFactor::new([0.0, 0.5, 1.0]).0, [0.0, 0.5, 1.0]);
+    assert_eq!(PbrBaseColorFactor::new([1.0; 4]).0, [1.0; 4]);
+    assert_eq!(StrengthFactor::new(0.0).0, 0.0);
+}
+
+#[test]
+#[should_panic(expected = "strength factor out of range")]
+fn test_strength_out_of_range() {
+    StrengthFactor::new(1.5);
+}
+
+#[test]
+#[should_panic(expected = "alpha cutoff out of range")]
+fn test_alpha_cutoff_out_of_range() {
+    AlphaCutoff::new(f32::NAN);
+}
+
+#[test]
+#[should_panic(expected = "base color factor out of range")]
+fn test_base_color_out_of_range() {
+    PbrBaseColorFactor::new([1.0, 1.0, 1.0, -0.1]);
+}
+
+#[test]
+fn test_wrapper() {
+    let mut root = json::Root::default();
+    root.images.push(json::Image { uri: Some("base.png".into()), ..Default::default() });
+    root.textures.push(json::Texture { source: Some(Index::new(0)), ..Default::default() });
+    let mut material = json::Material::default();
+    material.pbr_metallic_roughness.base_color_texture = Some(texture::Info { tex_coord: 1, ..texture::Info::new(Index::new(0)) });
+    material.pbr_metallic_roughness.base_color_factor = PbrBaseColorFactor::new([0.5, 0.5, 0.5, 1.0]);
+    root.materials.push(material);
+
+    let document = gltf::Document::from_json(root).unwrap();
+    let material = document.materials().next().unwrap();
+    let pbr = material.pbr_metallic_roughness();
+    assert_eq!(pbr.base_color_factor(), [0.5, 0.5, 0.5, 1.0]);
+    let info = pbr.base_color_texture().unwrap();
+    assert_eq!((info.texture().index(), info.tex_coord()), (0, 1));
+    assert!(material.normal_texture().is_none());
+}
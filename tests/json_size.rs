@@ -0,0 +1,69 @@
+const DOCUMENT: &str = r#"{
+    "asset": {"version": "2.0"},
+    "extensionsUsed": ["KHR_node_visibility"],
+    "meshes": [{"primitives": []}],
+    "nodes": [
+        {"mesh": 0, "name": "a\"bé"},
+        {"extensions": {"KHR_node_visibility": {"visible": false}}}
+    ]
+}"#;
+
+#[test]
+fn test_totals() {
+    let gltf = gltf::Gltf::open("examples/Lantern.gltf").unwrap();
+    let breakdown = gltf.json_size_breakdown().unwrap();
+    let json = gltf::json::serialize::to_vec(&gltf.document.clone().into_json()).unwrap();
+    assert_eq!(breakdown.bytes(), json.len());
+
+    // The properties are everything but the braces of the document.
+    let properties = breakdown.properties.iter().map(|x| x.bytes()).sum::<usize>();
+    assert_eq!(properties + 2, json.len());
+
+    let accessors = breakdown.property("accessors").unwrap();
+    assert!(accessors.fields["min"] > 0);
+    assert!(accessors.fields.values().sum::<usize>() < accessors.bytes());
+    assert!(!accessors.fields.contains_key("version"));
+}
+
+#[cfg(feature = "names")]
+#[test]
+fn test_escaped_names() {
+    let gltf = gltf::Gltf::from_slice(DOCUMENT.as_bytes()).unwrap();
+    let breakdown = gltf.json_size_breakdown().unwrap();
+    let nodes = breakdown.property("nodes").unwrap();
+
+    // The quote is escaped and the accented letter is written as UTF-8.
+    let name = r#","name":"a\"bé""#;
+    assert_eq!(name.len(), 16);
+    assert_eq!(nodes.categories.names, name.len());
+    assert_eq!(nodes.fields["name"], name.len());
+    assert_eq!(breakdown.categories.names, name.len());
+}
+
+#[cfg(feature = "KHR_node_visibility")]
+#[test]
+fn test_extensions() {
+    let gltf = gltf::Gltf::from_slice(DOCUMENT.as_bytes()).unwrap();
+    let breakdown = gltf.json_size_breakdown().unwrap();
+    let nodes = breakdown.property("nodes").unwrap();
+    let extensions = r#""extensions":{"KHR_node_visibility":{"visible":false}}"#;
+    assert_eq!(nodes.categories.extensions, extensions.len());
+    assert_eq!(breakdown.property("extensionsUsed").unwrap().categories.extensions, 0);
+}
+
+#[cfg(all(feature = "names", not(feature = "extras")))]
+#[test]
+fn test_lantern_snapshot() {
+    use std::{env, fs};
+
+    // Set `GLTF_UPDATE_SNAPSHOT=1` to record a new snapshot instead of comparing.
+    const SNAPSHOT_PATH: &str = "tests/lantern_json_size.txt";
+
+    let gltf = gltf::Gltf::open("examples/Lantern.gltf").unwrap();
+    let actual = gltf.json_size_breakdown().unwrap().to_string() + "\n";
+    if env::var_os("GLTF_UPDATE_SNAPSHOT").is_some() {
+        fs::write(SNAPSHOT_PATH, actual).unwrap();
+        return;
+    }
+    assert_eq!(actual, fs::read_to_string(SNAPSHOT_PATH).unwrap());
+}
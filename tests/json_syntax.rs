@@ -0,0 +1,118 @@
+use std::borrow::Cow;
+use std::{fs, io};
+
+fn glb(json: &[u8]) -> Vec<u8> {
+    let glb = gltf::binary::Glb {
+        header: gltf::binary::Header { magic: *b"glTF", version: 2, length: 0 },
+        json: Cow::Borrowed(json),
+        bin: None,
+        extra_chunks: Vec::new(),
+    };
+    glb.to_vec().unwrap()
+}
+
+/// Loads `bytes` with every loader.
+fn load_all(bytes: &[u8]) -> Vec<gltf::Result<gltf::Gltf>> {
+    vec![
+        gltf::Gltf::from_slice(bytes),
+        gltf::Gltf::from_reader(io::Cursor::new(bytes)),
+        gltf::Gltf::from_reader_streaming(io::Cursor::new(bytes)),
+    ]
+}
+
+#[test]
+fn test_null_padding() {
+    let bytes = fs::read("tests/null_padded.glb").unwrap();
+    for result in load_all(&bytes) {
+        let gltf = result.unwrap();
+        #[cfg(feature = "names")]
+        assert_eq!(gltf.nodes().next().unwrap().name(), Some("padded"));
+        #[cfg(not(feature = "names"))]
+        assert_eq!(gltf.nodes().count(), 1);
+    }
+}
+
+#[test]
+fn test_trailing_content() {
+    let bytes = fs::read("tests/trailing_content.gltf").unwrap();
+    let mut results = load_all(&bytes);
+    results.push(gltf::Gltf::open("tests/trailing_content.gltf"));
+    for result in results {
+        let error = result.unwrap_err();
+        match error {
+            gltf::Error::TrailingContent { offset: 69 } => {},
+            ref x => panic!("unexpected error: {:?}", x),
+        }
+        assert_eq!(error.to_string(), "unexpected content after the JSON text at byte offset 69");
+    }
+
+    // Trailing whitespace is not content, but nulls are outside of the JSON
+    // chunk of binary glTF, where they are padding like spaces.
+    let json = b"{\"asset\":{\"version\":\"2.0\"}} \r\n\t";
+    assert!(load_all(json).into_iter().all(|x| x.is_ok()));
+    let json = b"{\"asset\":{\"version\":\"2.0\"}}\0";
+    assert!(load_all(json).into_iter().all(|x| matches!(x, Err(gltf::Error::TrailingContent { offset: 27 }))));
+
+    let bytes = glb(b"{\"asset\":{\"version\":\"2.0\"}} \0 x");
+    for result in load_all(&bytes) {
+        match result {
+            Err(gltf::Error::TrailingContent { offset: 30 }) => {},
+            x => panic!("unexpected result: {:?}", x.map(|_| ())),
+        }
+    }
+}
+
+#[cfg(feature = "names")]
+#[test]
+fn test_duplicate_keys() {
+    use gltf::json::validation::Error;
+
+    let bytes = fs::read("tests/duplicate_keys.gltf").unwrap();
+    // By default repeated fields fail without a path.
+    match gltf::Gltf::from_slice(&bytes) {
+        Err(gltf::Error::Deserialize(error)) => assert!(error.to_string().starts_with("duplicate field `name`")),
+        x => panic!("unexpected result: {:?}", x.map(|_| ())),
+    }
+
+    let options = gltf::ParseOptions::default().reject_duplicate_keys(true);
+    let expected = vec![
+        ("meshes[0].primitives[0].attributes.POSITION".to_string(), Error::DuplicateKey),
+        ("nodes[0].name".to_string(), Error::DuplicateKey),
+        ("scene".to_string(), Error::DuplicateKey),
+    ];
+    for bytes in &[bytes.clone(), glb(&bytes)] {
+        match gltf::Gltf::from_slice_with_options(bytes, &options) {
+            Err(gltf::Error::Validation(errors)) => {
+                let errors = errors.into_iter().map(|(path, error)| (path.0, error)).collect::<Vec<_>>();
+                assert_eq!(errors, expected);
+                assert_eq!(errors[0].1.code(), "DUPLICATE_KEY");
+            },
+            x => panic!("unexpected result: {:?}", x.map(|_| ())),
+        }
+    }
+
+    let bytes = fs::read("tests/bom.gltf").unwrap();
+    assert!(gltf::Gltf::from_slice_with_options(&bytes, &options).is_ok());
+}
+
+#[test]
+fn test_intern_strings() {
+    let bytes = br#"{
+        "asset": {"version": "2.0"},
+        "buffers": [{"byteLength": 4, "uri": "tile.bin"}],
+        "images": [{"uri": "tile.png"}, {"uri": "tile.png"}, {"uri": "other.png"}]
+    }"#;
+    let uris = |root: &gltf::json::Root| root.images.iter().map(|x| x.uri.clone().unwrap()).collect::<Vec<_>>();
+
+    let root = gltf::Gltf::from_slice(bytes).unwrap().document.into_json();
+    let images = uris(&root);
+    assert!(!gltf::json::Str::ptr_eq(&images[0], &images[1]));
+
+    let options = gltf::ParseOptions::default().intern_strings(true);
+    let root = gltf::Gltf::from_slice_with_options(bytes, &options).unwrap().document.into_json();
+    let images = uris(&root);
+    assert_eq!(images, vec!["tile.png", "tile.png", "other.png"]);
+    assert!(gltf::json::Str::ptr_eq(&images[0], &images[1]));
+    assert!(!gltf::json::Str::ptr_eq(&images[0], &images[2]));
+    assert_eq!(root.buffers[0].uri.as_deref(), Some("tile.bin"));
+}
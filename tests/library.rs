@@ -0,0 +1,95 @@
+#![cfg(feature = "import")]
+
+use gltf::json::{self, Index};
+use gltf::scene::SceneFallback;
+use gltf::validation::Severity;
+use gltf::Buffer;
+
+/// Two meshes with materials, but no nodes or scenes.
+const LIBRARY: &str = "tests/library.gltf";
+
+#[test]
+fn test_library_traversal() {
+    let (document, buffers, _) = gltf::import(LIBRARY).unwrap();
+    let get_buffer_data = |buffer: Buffer| buffers.get(buffer.index()).map(|x| &**x);
+    assert!(document.default_scene().is_none());
+    assert_eq!(document.scenes().count(), 0);
+    assert_eq!(document.nodes().count(), 0);
+    assert_eq!(document.validation_report().at_least(Severity::Warning).count(), 0);
+
+    assert_eq!(document.scene_primitives(SceneFallback::Nothing).count(), 0);
+    let meshes = document
+        .scene_primitives(SceneFallback::AllMeshes)
+        .map(|(primitive, transform)| {
+            assert_eq!(transform[3], [0.0, 0.0, 0.0, 1.0]);
+            primitive.mesh().index()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(meshes, vec![0, 1]);
+
+    assert!(document.scene_bounding_box(SceneFallback::Nothing).is_none());
+    let bounds = document.scene_bounding_box(SceneFallback::AllMeshes).unwrap();
+    assert_eq!(bounds.bounding_box.min, [0.0, 0.0, -3.0]);
+    assert_eq!(bounds.bounding_box.max, [2.0, 1.0, 0.0]);
+    assert!(!bounds.approximate);
+    let tight = document.scene_bounding_box_with(get_buffer_data, SceneFallback::AllMeshes).unwrap();
+    assert_eq!(tight, bounds);
+    assert!(document.scene_bounding_box_with(get_buffer_data, SceneFallback::Nothing).is_none());
+}
+
+#[test]
+fn test_library_analysis() {
+    let (document, buffers, _) = gltf::import(LIBRARY).unwrap();
+    let get_buffer_data = |buffer: Buffer| buffers.get(buffer.index()).map(|x| &**x);
+    assert!(document.validate_buffer_data(get_buffer_data).is_ok());
+    assert_eq!(document.accessor_usages().count(), 3);
+    assert_eq!(document.external_references().count(), 0);
+    assert_eq!(document.textures_requiring_mipmaps().count(), 0);
+    assert_eq!(document.image_sharing_report().images.len(), 0);
+    assert!(gltf::diff(&document, &document, &buffers, &buffers).is_empty());
+
+    let requirements = document.requirements(Some(get_buffer_data));
+    assert!(!requirements.skinning);
+    assert_eq!(requirements.unprobed_images, 0);
+
+    let mut outline = Vec::new();
+    let options = gltf::dump::DumpOptions::default();
+    document.dump(&mut outline, &options.with_buffers(&buffers)).unwrap();
+    let outline = String::from_utf8(outline).unwrap();
+    assert!(outline.starts_with("Accessors\n"), "{}", outline);
+    assert!(!outline.contains("Scene"), "{}", outline);
+
+    let root = document.into_json();
+    match root.extract_scene(Index::new(0)) {
+        Err(json::edit::Error::IndexOutOfBounds(path)) => assert_eq!(path.as_str(), "scenes[0]"),
+        _ => panic!("expected an error"),
+    }
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn test_library_snapshot() {
+    let gltf = gltf::Gltf::open(LIBRARY).unwrap();
+    let snapshot = gltf.resolved_snapshot();
+    assert_eq!(snapshot["scene"], json::Value::Null);
+    assert_eq!(snapshot["scenes"], json::Value::Array(vec![]));
+    assert_eq!(snapshot["meshes"].as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn test_missing_default_scene() {
+    let json = r#"{"asset": {"version": "2.0"}, "scene": 0}"#;
+    match gltf::Gltf::from_slice(json.as_bytes()) {
+        Err(gltf::Error::Validation(errors)) => {
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors[0].0.as_str(), "scene");
+            assert_eq!(errors[0].1, json::validation::Error::IndexOutOfBounds);
+        },
+        _ => panic!("expected a validation error"),
+    }
+
+    let gltf = gltf::Gltf::from_slice_without_validation(json.as_bytes()).unwrap();
+    assert!(gltf.default_scene().is_none());
+    assert_eq!(gltf.scene_primitives(SceneFallback::AllMeshes).count(), 0);
+    assert!(gltf.scene_bounding_box(SceneFallback::AllMeshes).is_none());
+}
@@ -0,0 +1,68 @@
+//! Compile-pass tests which check that every value borrowed from a wrapper is
+//! bound to the lifetime of the `Document`, not to that of the wrapper.
+
+#![cfg(feature = "names")]
+
+use gltf::{camera, material, texture};
+
+/// Stores wrappers and borrowed data alongside each other.
+struct Assets<'a> {
+    materials: Vec<gltf::Material<'a>>,
+    pbr: Vec<material::PbrMetallicRoughness<'a>>,
+    normal_textures: Vec<material::NormalTexture<'a>>,
+    occlusion_textures: Vec<material::OcclusionTexture<'a>>,
+    textures: Vec<gltf::Texture<'a>>,
+    texture_infos: Vec<texture::Info<'a>>,
+    samplers: Vec<texture::Sampler<'a>>,
+    projections: Vec<camera::Projection<'a>>,
+    nodes: gltf::iter::Nodes<'a>,
+    names: Vec<&'a str>,
+    extras: Vec<&'a gltf::json::Extras>,
+}
+
+fn collect(document: &gltf::Document) -> Assets<'_> {
+    let mut assets = Assets {
+        materials: document.materials().collect(),
+        pbr: document.materials().map(|x| x.pbr_metallic_roughness()).collect(),
+        normal_textures: document.materials().filter_map(|x| x.normal_texture()).collect(),
+        occlusion_textures: document.materials().filter_map(|x| x.occlusion_texture()).collect(),
+        textures: document.textures().collect(),
+        texture_infos: document.materials().filter_map(|x| x.emissive_texture()).collect(),
+        samplers: document.textures().map(|x| x.sampler()).collect(),
+        projections: document.cameras().filter_map(|x| x.projection()).collect(),
+        nodes: document.nodes(),
+        names: Vec::new(),
+        extras: Vec::new(),
+    };
+    for texture in document.textures() {
+        assets.names.extend(texture.name());
+        assets.names.extend(texture.sampler().name());
+        assets.extras.push(texture.extras());
+        assets.extras.push(texture.sampler().extras());
+    }
+    for material in document.materials() {
+        assets.names.extend(material.name());
+        assets.extras.push(material.pbr_metallic_roughness().extras());
+        if let Some(info) = material.emissive_texture() {
+            assets.extras.push(info.extras());
+        }
+    }
+    assets
+}
+
+#[test]
+fn test_wrappers_outlive_parents() {
+    let gltf = gltf::Gltf::open("examples/Box.gltf").unwrap();
+    let assets = collect(&gltf);
+    assert_eq!(assets.materials.len(), 1);
+    assert_eq!(assets.pbr.len(), 1);
+    assert!(assets.normal_textures.is_empty());
+    assert!(assets.occlusion_textures.is_empty());
+    assert!(assets.textures.is_empty());
+    assert!(assets.texture_infos.is_empty());
+    assert!(assets.samplers.is_empty());
+    assert!(assets.projections.is_empty());
+    assert_eq!(assets.nodes.count(), 2);
+    assert_eq!(assets.names, vec!["Red"]);
+    assert_eq!(assets.extras.len(), 1);
+}
@@ -0,0 +1,37 @@
+use gltf::material::Pbr;
+
+#[test]
+fn test_unified_material_model() {
+    let gltf = gltf::Gltf::open("tests/specular_glossiness.gltf").unwrap();
+    let materials = gltf.materials().collect::<Vec<_>>();
+
+    // The normal, occlusion, and emissive slots apply to either model.
+    let sg = &materials[0];
+    assert_eq!(sg.normal_texture().map(|x| (x.texture().index(), x.scale())), Some((2, 0.5)));
+    assert_eq!(sg.occlusion_texture().map(|x| (x.texture().index(), x.strength())), Some((3, 0.25)));
+    assert_eq!(sg.emissive_texture().map(|x| x.texture().index()), Some(4));
+    assert_eq!(sg.emissive_factor(), [1.0, 0.5, 0.0]);
+    match sg.pbr() {
+        #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+        Pbr::SpecularGlossiness(pbr) => {
+            assert_eq!(pbr.diffuse_factor(), [1.0, 0.0, 0.0, 1.0]);
+            assert_eq!(pbr.diffuse_texture().map(|x| x.texture().index()), Some(0));
+            assert_eq!(pbr.glossiness_factor(), 0.75);
+        },
+        #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+        Pbr::MetallicRoughness(_) => panic!("expected specular-glossiness"),
+        // The extension is ignored without its feature.
+        #[cfg(not(feature = "KHR_materials_pbrSpecularGlossiness"))]
+        Pbr::MetallicRoughness(pbr) => {
+            assert_eq!(pbr.base_color_factor(), [0.5, 0.5, 0.5, 1.0]);
+        },
+    }
+
+    let mr = &materials[1];
+    assert_eq!(mr.normal_texture().map(|x| x.texture().index()), Some(2));
+    match mr.pbr() {
+        Pbr::MetallicRoughness(pbr) => assert_eq!(pbr.base_color_factor(), [0.0, 1.0, 0.0, 1.0]),
+        #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+        Pbr::SpecularGlossiness(_) => panic!("expected metallic-roughness"),
+    }
+}
@@ -0,0 +1,84 @@
+#![cfg(feature = "import")]
+
+use gltf::mesh::MaxIndex;
+use gltf::Buffer;
+
+/// A triangle with `u16` indices 0, 2, 1 and no declared `max`, followed by a
+/// non-indexed triangle.
+const TRIANGLES: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 44}],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 36},
+        {"buffer": 0, "byteOffset": 36, "byteLength": 6}
+    ],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0]},
+        {"bufferView": 1, "componentType": 5123, "count": 3, "type": "SCALAR"}
+    ],
+    "meshes": [{
+        "primitives": [
+            {"attributes": {"POSITION": 0}, "indices": 1},
+            {"attributes": {"POSITION": 0}}
+        ]
+    }]
+}"#;
+
+fn buffer_data() -> Vec<u8> {
+    let mut data = vec![0; 36];
+    data.extend_from_slice(&[0, 0, 2, 0, 1, 0, 0, 0]);
+    data
+}
+
+#[test]
+fn test_declared_max_index() {
+    let (document, buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    let get_buffer_data = |buffer: Buffer| buffers.get(buffer.index()).map(|x| &**x);
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    assert_eq!(primitive.max_index(Some(get_buffer_data)), Some(MaxIndex::Declared(23)));
+    assert_eq!(primitive.max_index(None::<fn(Buffer) -> Option<&'static [u8]>>), Some(MaxIndex::Declared(23)));
+    assert_eq!(primitive.vertex_count(), 24);
+    assert_eq!(primitive.index_count(), Some(36));
+}
+
+#[test]
+fn test_scanned_max_index() {
+    let gltf = gltf::Gltf::from_slice(TRIANGLES.as_bytes()).unwrap();
+    let data = buffer_data();
+    let get_buffer_data = |_: Buffer| Some(&data[..]);
+    let primitives = gltf.meshes().next().unwrap().primitives().collect::<Vec<_>>();
+    let max = primitives[0].max_index(Some(get_buffer_data));
+    assert_eq!(max, Some(MaxIndex::Scanned(2)));
+    assert_eq!(max.map(MaxIndex::value), Some(2));
+    assert_eq!(primitives[0].max_index(None::<fn(Buffer) -> Option<&'static [u8]>>), None);
+    assert_eq!(primitives[0].index_count(), Some(3));
+}
+
+#[test]
+fn test_non_indexed_primitive() {
+    let gltf = gltf::Gltf::from_slice(TRIANGLES.as_bytes()).unwrap();
+    let data = buffer_data();
+    let primitive = gltf.meshes().next().unwrap().primitives().nth(1).unwrap();
+    assert_eq!(primitive.vertex_count(), 3);
+    assert_eq!(primitive.index_count(), None);
+    assert_eq!(primitive.max_index(Some(|_: Buffer| Some(&data[..]))), None);
+
+    // Without `POSITION`, the count of another attribute is used.
+    let json = TRIANGLES.replace(r#"{"attributes": {"POSITION": 0}}"#, r#"{"attributes": {"_ID": 1}}"#);
+    let gltf = gltf::Gltf::from_slice_without_validation(json.as_bytes()).unwrap();
+    let primitive = gltf.meshes().next().unwrap().primitives().nth(1).unwrap();
+    assert_eq!(primitive.vertex_count(), 3);
+}
+
+#[test]
+fn test_declared_max_index_out_of_bounds() {
+    let json = TRIANGLES.replace(r#""type": "SCALAR"}"#, r#""type": "SCALAR", "min": [0], "max": [3]}"#);
+    match gltf::Gltf::from_slice(json.as_bytes()) {
+        Err(gltf::Error::Validation(errors)) => {
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors[0].0.as_str(), "meshes[0].primitives[0].indices");
+            assert_eq!(errors[0].1, gltf::json::validation::Error::IndexOutOfBounds);
+        },
+        _ => panic!("expected a validation error"),
+    }
+}
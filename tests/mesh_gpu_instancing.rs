@@ -0,0 +1,68 @@
+#![cfg(feature = "EXT_mesh_gpu_instancing")]
+
+use gltf::accessor::Usage;
+use gltf::json::{self, validation::Error, Index};
+
+/// A mesh drawn twice by node 0, with an unused accessor first so that
+/// extracting the scene renumbers the instance attributes.
+const INSTANCED: &str = r#"{
+    "asset": {"version": "2.0"},
+    "extensionsUsed": ["EXT_mesh_gpu_instancing"],
+    "buffers": [{"byteLength": 96}],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 4},
+        {"buffer": 0, "byteOffset": 4, "byteLength": 36},
+        {"buffer": 0, "byteOffset": 40, "byteLength": 24},
+        {"buffer": 0, "byteOffset": 64, "byteLength": 32}
+    ],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 1, "type": "SCALAR"},
+        {"bufferView": 1, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0]},
+        {"bufferView": 2, "componentType": 5126, "count": 2, "type": "VEC3"},
+        {"bufferView": 3, "componentType": 5126, "count": 2, "type": "VEC4"}
+    ],
+    "meshes": [{"primitives": [{"attributes": {"POSITION": 1}}]}],
+    "nodes": [{"mesh": 0, "extensions": {"EXT_mesh_gpu_instancing": {"attributes": {"TRANSLATION": 2, "ROTATION": 3}}}}],
+    "scene": 0,
+    "scenes": [{"nodes": [0]}]
+}"#;
+
+#[test]
+fn test_instance_attributes() {
+    let gltf = gltf::Gltf::from_slice(INSTANCED.as_bytes()).unwrap();
+    let node = gltf.nodes().next().unwrap();
+    let attributes = node.instance_attributes().map(|(name, x)| (name, x.index())).collect::<Vec<_>>();
+    assert_eq!(attributes, vec![("ROTATION", 3), ("TRANSLATION", 2)]);
+
+    let usages = gltf
+        .accessor_usages()
+        .filter_map(|(accessor, usage)| match usage {
+            Usage::InstanceAttribute { node, name } => Some((accessor.index(), node.index(), name)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(usages, vec![(3, 0, "ROTATION".to_string()), (2, 0, "TRANSLATION".to_string())]);
+}
+
+#[test]
+fn test_instance_attribute_types() {
+    let json = INSTANCED.replace(r#""ROTATION": 3"#, r#""ROTATION": 2"#);
+    match gltf::Gltf::from_slice(json.as_bytes()) {
+        Err(gltf::Error::Validation(errors)) => {
+            let (path, error) = &errors[0];
+            assert_eq!(*error, Error::Invalid);
+            assert!(path.as_str().ends_with(r#"attributes["ROTATION"]"#), "{}", path);
+        },
+        other => panic!("unexpected result: {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn test_extract_scene() {
+    let root = json::Root::from_str(INSTANCED).unwrap();
+    let (extracted, _) = root.extract_scene(Index::new(0)).unwrap();
+    assert_eq!(extracted.accessors.len(), 3);
+    let instancing = extracted.nodes[0].extensions.as_ref().unwrap().ext_mesh_gpu_instancing.as_ref().unwrap();
+    assert_eq!(instancing.attributes["TRANSLATION"].value(), 1);
+    assert_eq!(instancing.attributes["ROTATION"].value(), 2);
+}
@@ -0,0 +1,246 @@
+#![cfg(feature = "EXT_meshopt_compression")]
+
+use std::borrow::Cow;
+
+use gltf::binary::{Glb, Header};
+
+/// Three attributes of four bytes, with the byte groups of the first three
+/// bytes stored with 4, 8, and 2 bits, and the last byte constant.
+const ATTRIBUTES: &[u8] = &[
+    0xa0,
+    0x02, 0x24, 0x20, 0, 0, 0, 0, 0, 0,
+    0x03, 0x04, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0x01, 0xf4, 0, 0, 0, 0x06, 0x03,
+    0x00,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4,
+];
+
+/// Four triangles, referring to edges, recent vertices, and new vertices.
+const TRIANGLES: &[u8] = &[
+    0xe0, 0xf0, 0x10, 0xfe, 0xff, 0xf0, 0x0c, 0xff, 0x02, 0x02, 0x02, 0x00, 0x76, 0x87, 0x56, 0x67,
+    0x78, 0xa9, 0x86, 0x65, 0x89, 0x68, 0x98, 0x01, 0x69, 0x00, 0x00,
+];
+
+/// Five indices, stored as deltas to the two previous indices.
+const INDICES: &[u8] = &[0xd0, 0x14, 0x04, 0x02, 0xfc, 0x02, 0x1d, 0x00, 0x00, 0x00, 0x00];
+
+/// Encodes attributes with every byte group stored with 8 bits.
+fn encode_attributes(data: &[u8], stride: usize) -> Vec<u8> {
+    let count = data.len() / stride;
+    let groups = count.div_ceil(16);
+    let mut output = vec![0xa0];
+    for k in 0..stride {
+        output.extend(std::iter::repeat_n(0xff, groups.div_ceil(4)));
+        let mut deltas = vec![0; groups * 16];
+        let mut previous = 0u8;
+        for i in 0..count {
+            let delta = data[i * stride + k].wrapping_sub(previous) as i8;
+            deltas[i] = ((delta << 1) ^ (delta >> 7)) as u8;
+            previous = data[i * stride + k];
+        }
+        output.extend(deltas);
+    }
+    // The tail ends with the zero attribute the first deltas refer to.
+    output.extend(vec![0; stride.max(32)]);
+    output
+}
+
+/// Returns binary glTF with one view of `count` elements of `stride` bytes,
+/// decompressed from the `BIN` chunk into a fallback buffer.
+fn glb(data: &[u8], stride: usize, count: usize, mode: &str, filter: &str) -> Vec<u8> {
+    let json = format!(
+        r#"{{
+            "asset": {{"version": "2.0"}},
+            "extensionsUsed": ["EXT_meshopt_compression"],
+            "extensionsRequired": ["EXT_meshopt_compression"],
+            "buffers": [
+                {{"byteLength": {}}},
+                {{"byteLength": {}, "extensions": {{"EXT_meshopt_compression": {{"fallback": true}}}}}}
+            ],
+            "bufferViews": [{{
+                "buffer": 1,
+                "byteLength": {},
+                "extensions": {{"EXT_meshopt_compression": {{
+                    "buffer": 0,
+                    "byteLength": {},
+                    "byteStride": {},
+                    "count": {},
+                    "mode": "{}",
+                    "filter": "{}"
+                }}}}
+            }}],
+            "accessors": [{{"bufferView": 0, "componentType": 5123, "count": {}, "type": "SCALAR"}}]
+        }}"#,
+        data.len(),
+        stride * count,
+        stride * count,
+        data.len(),
+        stride,
+        count,
+        mode,
+        filter,
+        stride * count / 2,
+    );
+    let glb = Glb {
+        header: Header { magic: *b"glTF", version: 2, length: 0 },
+        json: Cow::Owned(json.into_bytes()),
+        bin: Some(Cow::Borrowed(data)),
+        extra_chunks: Vec::new(),
+    };
+    glb.to_vec().unwrap()
+}
+
+/// Imports a view, returning its decompressed data.
+#[cfg(feature = "meshopt")]
+fn decode(data: &[u8], stride: usize, count: usize, mode: &str, filter: &str) -> gltf::Result<Vec<u8>> {
+    let (_, buffers, _) = gltf::import_slice(glb(data, stride, count, mode, filter))?;
+    Ok(buffers[1][..stride * count].to_vec())
+}
+
+#[cfg(feature = "meshopt")]
+fn to_i16(data: &[u8]) -> Vec<i16> {
+    data.chunks(2).map(|x| i16::from_le_bytes([x[0], x[1]])).collect()
+}
+
+#[cfg(feature = "meshopt")]
+#[test]
+fn test_attributes() {
+    let decoded = decode(ATTRIBUTES, 4, 3, "ATTRIBUTES", "NONE").unwrap();
+    assert_eq!(decoded, vec![1, 2, 3, 4, 3, 2, 1, 4, 4, 2, 0, 4]);
+
+    let data = (0..200u32).flat_map(|x| (x * 7919).to_le_bytes()).collect::<Vec<_>>();
+    assert_eq!(decode(&encode_attributes(&data, 4), 4, 200, "ATTRIBUTES", "NONE").unwrap(), data);
+}
+
+#[cfg(all(feature = "meshopt", feature = "utils"))]
+#[test]
+fn test_triangles() {
+    let bytes = glb(TRIANGLES, 2, 12, "TRIANGLES", "NONE");
+    let (document, buffers, _) = gltf::import_slice(&bytes).unwrap();
+    let accessor = document.accessors().next().unwrap();
+    let indices = accessor.read::<u16, _>(|x| Some(&buffers[x.index()])).unwrap().collect::<Vec<_>>();
+    assert_eq!(indices, vec![0, 1, 2, 2, 1, 3, 4, 6, 5, 7, 8, 9]);
+}
+
+#[cfg(feature = "meshopt")]
+#[test]
+fn test_indices() {
+    let decoded = decode(INDICES, 4, 5, "INDICES", "NONE").unwrap();
+    let indices = decoded.chunks(4).map(|x| u32::from_le_bytes([x[0], x[1], x[2], x[3]])).collect::<Vec<_>>();
+    assert_eq!(indices, vec![5, 6, 5, 100, 7]);
+}
+
+#[cfg(feature = "meshopt")]
+#[test]
+fn test_octahedral_filter() {
+    let data = [64, 0, 127, 9, 100, 60, 127, 1];
+    let decoded = decode(&encode_attributes(&data, 4), 4, 2, "ATTRIBUTES", "OCTAHEDRAL").unwrap();
+    let decoded = decoded.iter().map(|&x| x as i8).collect::<Vec<_>>();
+    assert_eq!(decoded, vec![91, 0, 89, 9, 107, 43, -53, 1]);
+}
+
+#[cfg(feature = "meshopt")]
+#[test]
+fn test_quaternion_filter() {
+    // The low bits of the fourth component store the index of the largest.
+    let data = [0i16, 0, 32767, 32767, 32767, 0, 0, 32766];
+    let data = data.iter().flat_map(|x| x.to_le_bytes()).collect::<Vec<_>>();
+    let decoded = decode(&encode_attributes(&data, 8), 8, 2, "ATTRIBUTES", "QUATERNION").unwrap();
+    assert_eq!(to_i16(&decoded), vec![0, 0, 23170, 23170, 0, 0, 23170, 23170]);
+}
+
+#[cfg(feature = "meshopt")]
+#[test]
+fn test_exponential_filter() {
+    let data = [0xff00_0003u32, 0x0bff_ffff];
+    let data = data.iter().flat_map(|x| x.to_le_bytes()).collect::<Vec<_>>();
+    let decoded = decode(&encode_attributes(&data, 4), 4, 2, "ATTRIBUTES", "EXPONENTIAL").unwrap();
+    let floats = decoded.chunks(4).map(|x| f32::from_le_bytes([x[0], x[1], x[2], x[3]])).collect::<Vec<_>>();
+    assert_eq!(floats, vec![1.5, -2048.0]);
+}
+
+#[cfg(feature = "meshopt")]
+#[test]
+fn test_malformed() {
+    let truncated = &ATTRIBUTES[..ATTRIBUTES.len() - 1];
+    match decode(truncated, 4, 3, "ATTRIBUTES", "NONE") {
+        Err(gltf::Error::Meshopt { view: 0 }) => {},
+        other => panic!("expected a meshopt error, got {:?}", other.map(|_| ())),
+    }
+    match decode(&INDICES[..INDICES.len() - 1], 4, 5, "INDICES", "NONE") {
+        Err(gltf::Error::Meshopt { view: 0 }) => {},
+        other => panic!("expected a meshopt error, got {:?}", other.map(|_| ())),
+    }
+}
+
+/// Decodes a grid mesh and animation keyframes compressed with the reference
+/// encoder of meshoptimizer, with every mode and filter, and compares the
+/// result with the output of its scalar reference decoder. The fixtures are
+/// written by `tests/meshopt/generate.cpp`.
+#[cfg(feature = "meshopt")]
+#[test]
+fn test_reference_encoder() {
+    let expected = std::fs::read("tests/meshopt/grid.bin").unwrap();
+    let (document, buffers, _) = gltf::import("tests/meshopt/grid.glb").unwrap();
+    assert_eq!(document.views().count(), 6);
+    for view in document.views() {
+        let range = view.offset()..view.offset() + view.length();
+        assert_eq!(&buffers[1][range.clone()], &expected[range], "view {}", view.index());
+    }
+    assert_eq!(&buffers[1][..], &expected[..]);
+}
+
+#[cfg(feature = "meshopt")]
+#[test]
+fn test_count_mismatch() {
+    // The count is checked against the view length before any output is
+    // allocated, including counts whose size overflows.
+    for count in &[6, u32::MAX] {
+        let bytes = glb(INDICES, 4, 5, "INDICES", "NONE");
+        let json = String::from_utf8(Glb::from_slice(&bytes).unwrap().json.to_vec()).unwrap();
+        let json = json.replace(r#""count": 5,"#, &format!(r#""count": {},"#, count));
+        let glb = Glb {
+            header: Header { magic: *b"glTF", version: 2, length: 0 },
+            json: Cow::Owned(json.into_bytes()),
+            bin: Some(Cow::Borrowed(INDICES)),
+            extra_chunks: Vec::new(),
+        };
+        match gltf::import_slice(glb.to_vec().unwrap()) {
+            Err(gltf::Error::Meshopt { view: 0 }) => {},
+            other => panic!("expected a meshopt error, got {:?}", other.map(|_| ())),
+        }
+    }
+}
+
+#[test]
+fn test_extension_data() {
+    use gltf::json::extensions::buffer::ext_meshopt_compression::{Filter, Mode};
+    use gltf::json::validation::Checked;
+
+    let gltf = gltf::Gltf::from_slice_without_validation(&glb(INDICES, 4, 5, "INDICES", "NONE")).unwrap();
+    assert!(!gltf.buffers().next().unwrap().is_meshopt_fallback());
+    assert!(gltf.buffers().nth(1).unwrap().is_meshopt_fallback());
+    let compression = gltf.views().next().unwrap().meshopt_compression().unwrap();
+    assert_eq!(compression.buffer.value(), 0);
+    assert_eq!(compression.count, 5);
+    assert_eq!(compression.mode, Checked::Valid(Mode::Indices));
+    assert_eq!(compression.filter, Checked::Valid(Filter::None));
+
+    // Only attributes may be filtered.
+    let gltf = gltf::Gltf::from_slice_without_validation(&glb(INDICES, 4, 5, "INDICES", "OCTAHEDRAL")).unwrap();
+    let paths = gltf.validation_report().entries.iter().map(|x| x.path.to_string()).collect::<Vec<_>>();
+    assert!(paths.contains(&"bufferViews[0].extensions.EXT_meshopt_compression.byteStride".to_string()));
+}
+
+#[cfg(not(feature = "meshopt"))]
+#[test]
+fn test_unsupported_without_decoding() {
+    match gltf::Gltf::from_slice(&glb(INDICES, 4, 5, "INDICES", "NONE")) {
+        Err(gltf::Error::Validation(errors)) => {
+            let paths = errors.iter().map(|(path, _)| path.to_string()).collect::<Vec<_>>();
+            assert_eq!(paths, vec!["extensionsRequired[0]"]);
+        },
+        other => panic!("expected a validation error, got {:?}", other.map(|_| ())),
+    }
+}
@@ -0,0 +1,218 @@
+// Generates the EXT_meshopt_compression fixtures of `tests/meshopt.rs` with
+// the reference meshoptimizer encoder, using the same encoder calls as
+// gltfpack: attribute data is written with vertex codec version 0 and the
+// octahedral, quaternion, and exponential filters, triangle lists with the
+// index buffer codec, and other indices with the index sequence codec.
+//
+// Build against the sources of meshoptimizer 0.23. The SIMD filter decoders
+// use an approximate reciprocal square root, so the reference output is
+// produced with the scalar decoders:
+//
+//     c++ -O2 -DMESHOPTIMIZER_NO_SIMD -I meshoptimizer/src tests/meshopt/generate.cpp meshoptimizer/src/*.cpp -o generate
+//     ./generate tests/meshopt
+//
+// This writes `grid.glb` and `grid.bin`, the data of the fallback buffer as
+// decoded by the reference decoder.
+#include <math.h>
+#include <stdio.h>
+#include <string.h>
+
+#include <string>
+#include <vector>
+
+#include "meshoptimizer.h"
+
+struct View {
+    const char* mode;
+    const char* filter;
+    size_t stride;
+    size_t count;
+    std::vector<unsigned char> compressed;
+    std::vector<unsigned char> decoded;
+};
+
+static const size_t kSide = 17;
+static const size_t kVertices = kSide * kSide;
+
+static View attributes(const char* filter, size_t stride, const std::vector<unsigned char>& data) {
+    View view = {"ATTRIBUTES", filter, stride, data.size() / stride, {}, {}};
+    view.compressed.resize(meshopt_encodeVertexBufferBound(view.count, stride));
+    view.compressed.resize(meshopt_encodeVertexBuffer(&view.compressed[0], view.compressed.size(), &data[0], view.count, stride));
+    view.decoded.resize(data.size());
+    meshopt_decodeVertexBuffer(&view.decoded[0], view.count, stride, &view.compressed[0], view.compressed.size());
+    if (strcmp(filter, "OCTAHEDRAL") == 0)
+        meshopt_decodeFilterOct(&view.decoded[0], view.count, stride);
+    else if (strcmp(filter, "QUATERNION") == 0)
+        meshopt_decodeFilterQuat(&view.decoded[0], view.count, stride);
+    else if (strcmp(filter, "EXPONENTIAL") == 0)
+        meshopt_decodeFilterExp(&view.decoded[0], view.count, stride);
+    return view;
+}
+
+static View indices(bool triangles, size_t stride, const std::vector<unsigned int>& data) {
+    View view = {triangles ? "TRIANGLES" : "INDICES", "NONE", stride, data.size(), {}, {}};
+    if (triangles) {
+        view.compressed.resize(meshopt_encodeIndexBufferBound(data.size(), kVertices));
+        view.compressed.resize(meshopt_encodeIndexBuffer(&view.compressed[0], view.compressed.size(), &data[0], data.size()));
+        view.decoded.resize(data.size() * stride);
+        meshopt_decodeIndexBuffer(&view.decoded[0], data.size(), stride, &view.compressed[0], view.compressed.size());
+    } else {
+        view.compressed.resize(meshopt_encodeIndexSequenceBound(data.size(), kVertices));
+        view.compressed.resize(meshopt_encodeIndexSequence(&view.compressed[0], view.compressed.size(), &data[0], data.size()));
+        view.decoded.resize(data.size() * stride);
+        meshopt_decodeIndexSequence(&view.decoded[0], data.size(), stride, &view.compressed[0], view.compressed.size());
+    }
+    return view;
+}
+
+static void pad(std::vector<unsigned char>& data, unsigned char value) {
+    while (data.size() % 4)
+        data.push_back(value);
+}
+
+static void push_u32(std::vector<unsigned char>& data, unsigned int value) {
+    for (int i = 0; i < 4; ++i)
+        data.push_back((unsigned char)(value >> (8 * i)));
+}
+
+int main(int argc, char** argv) {
+    if (argc != 2) {
+        fprintf(stderr, "usage: %s <directory>\n", argv[0]);
+        return 1;
+    }
+    meshopt_encodeVertexVersion(0);
+    meshopt_encodeIndexVersion(1);
+
+    // A height field with quantized positions and octahedral normals.
+    std::vector<unsigned short> positions;
+    std::vector<float> normals;
+    for (size_t y = 0; y < kSide; ++y)
+        for (size_t x = 0; x < kSide; ++x) {
+            float fx = float(x) / (kSide - 1), fy = float(y) / (kSide - 1);
+            float h = 0.25f * sinf(fx * 6.0f) * cosf(fy * 4.0f);
+            positions.push_back((unsigned short)(fx * 65535.0f));
+            positions.push_back((unsigned short)((h + 0.5f) * 65535.0f));
+            positions.push_back((unsigned short)(fy * 65535.0f));
+            positions.push_back(0);
+            float nx = -1.5f * cosf(fx * 6.0f) * cosf(fy * 4.0f), nz = sinf(fx * 6.0f) * sinf(fy * 4.0f);
+            float length = sqrtf(nx * nx + 1.0f + nz * nz);
+            normals.push_back(nx / length);
+            normals.push_back(1.0f / length);
+            normals.push_back(nz / length);
+            normals.push_back(1.0f);
+        }
+
+    // Keyframes of a rotation about a tilted axis and of a translation.
+    std::vector<float> rotations, translations;
+    for (size_t i = 0; i < 64; ++i) {
+        float angle = float(i) * 0.1f;
+        float axis[3] = {0.48f, 0.64f, 0.6f};
+        for (int k = 0; k < 3; ++k)
+            rotations.push_back(axis[k] * sinf(angle / 2));
+        rotations.push_back(cosf(angle / 2));
+        translations.push_back(float(i) * 0.125f);
+        translations.push_back(100.0f * sinf(angle));
+        translations.push_back(-0.001f * float(i * i));
+    }
+
+    std::vector<unsigned int> triangles;
+    for (size_t y = 0; y + 1 < kSide; ++y)
+        for (size_t x = 0; x + 1 < kSide; ++x) {
+            unsigned int a = unsigned(y * kSide + x), b = a + 1, c = a + unsigned(kSide), d = c + 1;
+            unsigned int quad[6] = {a, c, b, b, c, d};
+            triangles.insert(triangles.end(), quad, quad + 6);
+        }
+    meshopt_optimizeVertexCache(&triangles[0], &triangles[0], triangles.size(), kVertices);
+
+    // The outline of the grid as a line strip.
+    std::vector<unsigned int> outline;
+    for (size_t i = 0; i < kSide; ++i)
+        outline.push_back(unsigned(i));
+    for (size_t i = 1; i < kSide; ++i)
+        outline.push_back(unsigned(i * kSide + kSide - 1));
+    for (size_t i = 1; i < kSide; ++i)
+        outline.push_back(unsigned(kVertices - 1 - i));
+    for (size_t i = kSide - 2; i > 0; --i)
+        outline.push_back(unsigned(i * kSide));
+    outline.push_back(0);
+
+    std::vector<unsigned char> bytes;
+    std::vector<View> views;
+
+    bytes.assign((unsigned char*)&positions[0], (unsigned char*)(&positions[0] + positions.size()));
+    views.push_back(attributes("NONE", 8, bytes));
+
+    bytes.resize(kVertices * 4);
+    meshopt_encodeFilterOct(&bytes[0], kVertices, 4, 8, &normals[0]);
+    views.push_back(attributes("OCTAHEDRAL", 4, bytes));
+
+    bytes.resize(64 * 8);
+    meshopt_encodeFilterQuat(&bytes[0], 64, 8, 12, &rotations[0]);
+    views.push_back(attributes("QUATERNION", 8, bytes));
+
+    bytes.resize(64 * 12);
+    meshopt_encodeFilterExp(&bytes[0], 64, 12, 15, &translations[0], meshopt_EncodeExpSeparate);
+    views.push_back(attributes("EXPONENTIAL", 12, bytes));
+
+    views.push_back(indices(true, 2, triangles));
+    views.push_back(indices(false, 4, outline));
+
+    std::vector<unsigned char> bin, decoded;
+    std::string json_views;
+    for (size_t i = 0; i < views.size(); ++i) {
+        const View& view = views[i];
+        char entry[512];
+        snprintf(entry, sizeof(entry),
+            "%s{\"buffer\":1,\"byteOffset\":%zu,\"byteLength\":%zu,%s\"extensions\":{\"EXT_meshopt_compression\":"
+            "{\"buffer\":0,\"byteOffset\":%zu,\"byteLength\":%zu,\"byteStride\":%zu,\"count\":%zu,\"mode\":\"%s\"%s%s%s}}}",
+            i ? "," : "", decoded.size(), view.decoded.size(),
+            strcmp(view.mode, "ATTRIBUTES") == 0 ? "\"byteStride\":" : "",
+            bin.size(), view.compressed.size(), view.stride, view.count, view.mode,
+            strcmp(view.filter, "NONE") ? ",\"filter\":\"" : "", strcmp(view.filter, "NONE") ? view.filter : "",
+            strcmp(view.filter, "NONE") ? "\"" : "");
+        std::string text = entry;
+        if (strcmp(view.mode, "ATTRIBUTES") == 0) {
+            // Insert the stride of the decompressed vertex view.
+            size_t at = text.find("\"byteStride\":\"extensions\"");
+            text.replace(at, strlen("\"byteStride\":"), "\"byteStride\":" + std::to_string(view.stride) + ",");
+        }
+        json_views += text;
+        bin.insert(bin.end(), view.compressed.begin(), view.compressed.end());
+        pad(bin, 0);
+        decoded.insert(decoded.end(), view.decoded.begin(), view.decoded.end());
+        pad(decoded, 0);
+    }
+
+    char header[512];
+    snprintf(header, sizeof(header),
+        "{\"asset\":{\"version\":\"2.0\",\"generator\":\"meshoptimizer 0.23\"},"
+        "\"extensionsUsed\":[\"EXT_meshopt_compression\"],"
+        "\"extensionsRequired\":[\"EXT_meshopt_compression\"],"
+        "\"buffers\":[{\"byteLength\":%zu},{\"byteLength\":%zu,\"extensions\":{\"EXT_meshopt_compression\":{\"fallback\":true}}}],"
+        "\"bufferViews\":[",
+        bin.size(), decoded.size());
+    std::vector<unsigned char> json;
+    std::string text = std::string(header) + json_views + "]}";
+    json.assign(text.begin(), text.end());
+    pad(json, ' ');
+
+    std::vector<unsigned char> glb;
+    push_u32(glb, 0x46546c67);
+    push_u32(glb, 2);
+    push_u32(glb, unsigned(12 + 8 + json.size() + 8 + bin.size()));
+    push_u32(glb, unsigned(json.size()));
+    push_u32(glb, 0x4e4f534a);
+    glb.insert(glb.end(), json.begin(), json.end());
+    push_u32(glb, unsigned(bin.size()));
+    push_u32(glb, 0x004e4942);
+    glb.insert(glb.end(), bin.begin(), bin.end());
+
+    std::string directory = argv[1];
+    FILE* file = fopen((directory + "/grid.glb").c_str(), "wb");
+    fwrite(&glb[0], 1, glb.size(), file);
+    fclose(file);
+    file = fopen((directory + "/grid.bin").c_str(), "wb");
+    fwrite(&decoded[0], 1, decoded.size(), file);
+    fclose(file);
+    return 0;
+}
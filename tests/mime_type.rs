@@ -0,0 +1,67 @@
+use gltf::image::{MimeType, Source};
+use gltf::validation::Error;
+
+const KTX2: &str = r#"{
+    "asset": {"version": "2.0"},
+    "images": [
+        {"uri": "texture.ktx2", "mimeType": "image/ktx2"},
+        {"uri": "texture.png", "mimeType": "image/png"}
+    ]
+}"#;
+
+#[test]
+fn test_ktx2_image() {
+    // `KHR_texture_basisu` is not implemented, but its MIME type is known.
+    let gltf = gltf::Gltf::from_slice(KTX2.as_bytes()).unwrap();
+    let image = gltf.images().next().unwrap();
+    match image.source().unwrap() {
+        Source::Uri { uri, mime_type } => {
+            assert_eq!(uri, "texture.ktx2");
+            assert_eq!(mime_type, Some(&MimeType::Ktx2));
+        },
+        Source::View { .. } => panic!("expected a uri"),
+    }
+
+    let report = gltf.validation_report();
+    let entries = report.entries.iter().map(|x| (x.path.as_str(), x.error)).collect::<Vec<_>>();
+    assert_eq!(entries, vec![("images[0].mimeType", Error::UndeclaredExtension)]);
+    assert!(gltf.validate_strict().is_err());
+
+    let json = KTX2.replace("\"images\"", "\"extensionsUsed\": [\"KHR_texture_basisu\"], \"images\"");
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    assert!(gltf.validation_report().entries.is_empty());
+
+    let root = gltf::json::Root::from_str(KTX2).unwrap();
+    let written = root.to_string().unwrap();
+    assert!(written.contains(r#""mimeType":"image/ktx2""#));
+    let reparsed = gltf::json::Root::from_str(&written).unwrap();
+    assert_eq!(reparsed.images[0].mime_type, Some(MimeType::Ktx2));
+    assert_eq!(reparsed.images[1].mime_type, Some(MimeType::Png));
+}
+
+#[test]
+fn test_unknown_mime_type() {
+    let json = KTX2.replace("image/ktx2", "image/x-custom");
+    let root = gltf::json::Root::from_str(&json).unwrap();
+    let mime_type = root.images[0].mime_type.clone().unwrap();
+    assert_eq!(mime_type, MimeType::Other("image/x-custom".to_string()));
+    assert_eq!(mime_type.extension(), None);
+    assert!(root.to_string().unwrap().contains(r#""mimeType":"image/x-custom""#));
+
+    // Types defined by unknown extensions are not reported.
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    assert!(gltf.validation_report().entries.is_empty());
+}
+
+#[test]
+fn test_display_and_from_str() {
+    for mime_type in &["image/jpeg", "image/png", "image/webp", "image/ktx2", "image/vnd-ms.dds", "text/plain"] {
+        let parsed = mime_type.parse::<MimeType>().unwrap();
+        assert_eq!(parsed.to_string(), *mime_type);
+        assert_eq!(parsed.as_str(), *mime_type);
+    }
+    assert_eq!("image/jpeg".parse::<MimeType>(), Ok(MimeType::Jpeg));
+    assert_eq!(MimeType::Webp.extension(), Some("EXT_texture_webp"));
+    assert_eq!(MimeType::Dds.extension(), Some("MSFT_texture_dds"));
+    assert_eq!(MimeType::Png.extension(), None);
+}
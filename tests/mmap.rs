@@ -0,0 +1,52 @@
+#![cfg(all(feature = "mmap", unix))]
+
+use std::fs;
+
+use gltf::buffer::Data;
+
+fn positions(document: &gltf::Document, buffers: &[Data]) -> Vec<[f32; 3]> {
+    document
+        .meshes()
+        .flat_map(|mesh| mesh.primitives())
+        .flat_map(|primitive| {
+            let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+            reader.read_positions().unwrap().collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+#[test]
+fn test_mapped_glb_matches_slice_import() {
+    let (document, mapped, _) = unsafe { gltf::import_mapped("examples/Box.glb") }.unwrap();
+    let bytes = fs::read("examples/Box.glb").unwrap();
+    let (_, owned, _) = gltf::import_slice(&bytes).unwrap();
+
+    match mapped[0] {
+        Data::Mapped(_) => {},
+        ref data => panic!("expected the BIN chunk to be mapped, found {:?}", data),
+    }
+    assert_eq!(&mapped[0][..], &owned[0][..]);
+    assert_eq!(positions(&document, &mapped), positions(&document, &owned));
+}
+
+#[test]
+fn test_mapped_external_buffer() {
+    let (document, mapped, _) = unsafe { gltf::import_mapped("examples/Box.gltf") }.unwrap();
+    let mut bytes = fs::read("examples/Box0.bin").unwrap();
+    while !bytes.len().is_multiple_of(4) {
+        bytes.push(0);
+    }
+    let owned = vec![Data::from(bytes)];
+
+    assert!(matches!(mapped[0], Data::Mapped(_)));
+    assert_eq!(&mapped[0][..], &owned[0][..]);
+    assert_eq!(positions(&document, &mapped), positions(&document, &owned));
+}
+
+#[test]
+fn test_import_reads_owned_data() {
+    let (_, buffers, _) = gltf::import("examples/Box.glb").unwrap();
+    assert!(matches!(buffers[0], Data::Owned(_)));
+    let (_, buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    assert!(matches!(buffers[0], Data::Owned(_)));
+}
@@ -0,0 +1,30 @@
+#![cfg(feature = "names")]
+
+const JSON: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 24, "uri": "data.bin"}],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 12, "name": "positions"},
+        {"buffer": 0, "byteOffset": 12, "byteLength": 12, "name": "positions"}
+    ],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 1, "type": "VEC3", "name": "POSITION_lod0"},
+        {"bufferView": 1, "componentType": 5126, "count": 1, "type": "VEC3", "name": "POSITION_lod1"},
+        {"bufferView": 1, "componentType": 5126, "count": 1, "type": "VEC3"}
+    ]
+}"#;
+
+#[test]
+fn test_accessor_and_view_names() {
+    let gltf = gltf::Gltf::from_slice(JSON.as_bytes()).unwrap();
+    let accessors = gltf.accessors().map(|x| x.name()).collect::<Vec<_>>();
+    assert_eq!(accessors, vec![Some("POSITION_lod0"), Some("POSITION_lod1"), None]);
+    assert_eq!(gltf.views().next().unwrap().name(), Some("positions"));
+
+    assert_eq!(gltf.accessor_by_name("POSITION_lod1").map(|x| x.index()), Some(1));
+    assert!(gltf.accessor_by_name("POSITION_lod2").is_none());
+
+    // The first of several views with the same name is returned.
+    assert_eq!(gltf.view_by_name("positions").map(|x| x.index()), Some(0));
+    assert!(gltf.view_by_name("normals").is_none());
+}
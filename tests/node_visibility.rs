@@ -0,0 +1,75 @@
+use gltf::scene::Visibility;
+
+// Node 1 is hidden by the extension, node 3 by its extras; nodes 2 and 4 are
+// their children. Every node instantiates the unit cube, translated along x.
+const HIDDEN: &str = r#"{
+    "asset": {"version": "2.0"},
+    "extensionsUsed": ["KHR_node_visibility"],
+    "buffers": [{"byteLength": 36, "uri": "data:application/octet-stream;base64,AAAAAAAAAAAAAAAAAACAPwAAAAAAAAAAAAAAAAAAgD8AAIA/"}],
+    "bufferViews": [{"buffer": 0, "byteLength": 36}],
+    "accessors": [{"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 1]}],
+    "meshes": [{"primitives": [{"attributes": {"POSITION": 0}}]}],
+    "nodes": [
+        {"mesh": 0, "children": [1, 3]},
+        {"mesh": 0, "translation": [10, 0, 0], "children": [2], "extensions": {"KHR_node_visibility": {"visible": false}}},
+        {"mesh": 0, "translation": [10, 0, 0]},
+        {"mesh": 0, "translation": [-10, 0, 0], "children": [4], "extras": {"visible": false}},
+        {"mesh": 0, "translation": [-10, 0, 0]}
+    ],
+    "scene": 0,
+    "scenes": [{"nodes": [0]}]
+}"#;
+
+fn indices(scene: &gltf::Scene, visibility: Visibility) -> Vec<usize> {
+    scene.walk(visibility).map(|(node, _)| node.index()).collect()
+}
+
+#[test]
+fn test_default_visits_everything() {
+    let gltf = gltf::Gltf::from_slice(HIDDEN.as_bytes()).unwrap();
+    let scene = gltf.default_scene().unwrap();
+    assert_eq!(indices(&scene, Visibility::default()), vec![0, 1, 2, 3, 4]);
+    assert_eq!(scene.primitives().count(), 5);
+    let bounds = scene.bounding_box().unwrap().bounding_box;
+    assert_eq!((bounds.min[0], bounds.max[0]), (-20.0, 21.0));
+
+    let (_, transform) = scene.walk(Visibility::default()).nth(2).unwrap();
+    assert_eq!(transform[3], [20.0, 0.0, 0.0, 1.0]);
+}
+
+#[cfg(feature = "KHR_node_visibility")]
+#[test]
+fn test_extension_hides_subtree() {
+    let gltf = gltf::Gltf::from_slice(HIDDEN.as_bytes()).unwrap();
+    let visible = gltf.nodes().map(|node| node.visible()).collect::<Vec<_>>();
+    assert_eq!(visible, vec![true, false, true, true, true]);
+
+    let scene = gltf.default_scene().unwrap();
+    let visibility = Visibility { extension: true, extras: false };
+    assert_eq!(indices(&scene, visibility), vec![0, 3, 4]);
+    assert_eq!(scene.visible_primitives(visibility).count(), 3);
+    let bounds = scene.visible_bounding_box(visibility).unwrap().bounding_box;
+    assert_eq!((bounds.min[0], bounds.max[0]), (-20.0, 1.0));
+
+    let json = gltf.document.into_json().to_string().unwrap();
+    assert!(json.contains(r#""KHR_node_visibility":{"visible":false}"#));
+}
+
+#[cfg(feature = "extras")]
+#[test]
+fn test_extras_hide_subtree() {
+    let gltf = gltf::Gltf::from_slice(HIDDEN.as_bytes()).unwrap();
+    let scene = gltf.default_scene().unwrap();
+    let visibility = Visibility { extension: false, extras: true };
+    assert_eq!(indices(&scene, visibility), vec![0, 1, 2]);
+    let bounds = scene.visible_bounding_box(visibility).unwrap().bounding_box;
+    assert_eq!((bounds.min[0], bounds.max[0]), (0.0, 21.0));
+}
+
+#[cfg(all(feature = "KHR_node_visibility", feature = "extras"))]
+#[test]
+fn test_all_hidden() {
+    let gltf = gltf::Gltf::from_slice(HIDDEN.as_bytes()).unwrap();
+    let scene = gltf.default_scene().unwrap();
+    assert_eq!(indices(&scene, Visibility::ALL_HIDDEN), vec![0]);
+}
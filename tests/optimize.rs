@@ -0,0 +1,109 @@
+#![cfg(all(feature = "import", feature = "utils"))]
+
+use std::collections::HashSet;
+
+use gltf::json::accessor::{ComponentType, GenericComponentType};
+use gltf::json::validation::Checked;
+use gltf::mesh::optimize::{self, OptimizeIndices};
+
+/// Returns the triangles of an index list, each rotated so that its smallest
+/// index comes first, which preserves winding.
+fn triangles(indices: &[u32]) -> HashSet<[u32; 3]> {
+    indices
+        .chunks(3)
+        .map(|x| {
+            let first = (0..3).min_by_key(|&i| x[i]).unwrap();
+            [x[first], x[(first + 1) % 3], x[(first + 2) % 3]]
+        })
+        .collect()
+}
+
+/// Returns an indexed triangle grid with its triangles in a shuffled order.
+fn shuffled_grid(size: u32) -> (Vec<[f32; 3]>, Vec<u32>) {
+    let mut positions = Vec::new();
+    for y in 0..=size {
+        for x in 0..=size {
+            positions.push([x as f32, y as f32, 0.0]);
+        }
+    }
+    let mut quads = Vec::new();
+    for y in 0..size {
+        for x in 0..size {
+            let i = y * (size + 1) + x;
+            quads.push([i, i + 1, i + size + 2]);
+            quads.push([i, i + size + 2, i + size + 1]);
+        }
+    }
+    let mut state = 0x2545_f491_u32;
+    for i in (1..quads.len()).rev() {
+        state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        quads.swap(i, (state >> 8) as usize % (i + 1));
+    }
+    (positions, quads.iter().flat_map(|x| x.iter().cloned()).collect())
+}
+
+#[test]
+fn test_optimize_primitive() {
+    let (positions, mut indices) = shuffled_grid(32);
+    let before = triangles(&indices);
+    let acmr_before = optimize::acmr(&indices, 16);
+
+    optimize::optimize_primitive(&positions, &mut indices);
+    let acmr_after = optimize::acmr(&indices, 16);
+
+    assert_eq!(before, triangles(&indices));
+    assert_eq!(before.len() * 3, indices.len());
+    assert!(acmr_before > 2.0, "acmr before: {}", acmr_before);
+    assert!(acmr_after < 1.0, "acmr after: {}", acmr_after);
+}
+
+/// Returns the indices of the first primitive of a document.
+fn read_indices(document: &gltf::Document, buffers: &[gltf::buffer::Data]) -> Vec<u32> {
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    let reader = primitive.reader(|x| buffers.get(x.index()).map(|x| &**x));
+    reader.read_indices().unwrap().into_u32().collect()
+}
+
+#[test]
+fn test_optimize_indices_narrows() {
+    let (document, mut buffers, _) = gltf::import("tests/box_sparse.gltf").unwrap();
+    let before = read_indices(&document, &buffers);
+
+    let mut root = document.into_json();
+    let view_count = root.buffer_views.len();
+    let report = root.optimize_indices(&mut buffers).unwrap();
+    assert_eq!(report, optimize::Report { reordered: 1, narrowed: 1, reencoded: vec![] });
+    assert_eq!(root.buffer_views.len(), view_count + 1);
+    assert_eq!(root.buffers[0].byte_length as usize, buffers[0].len());
+
+    let indices = root.meshes[0].primitives[0].indices.unwrap().value();
+    match root.accessors[indices].component_type {
+        Checked::Valid(GenericComponentType(x)) => assert_eq!(x, ComponentType::U16),
+        Checked::Invalid => panic!("invalid component type"),
+    }
+
+    let document = gltf::Document::from_json(root).unwrap();
+    let after = read_indices(&document, &buffers);
+    assert_eq!(before.len(), after.len());
+    assert_eq!(triangles(&before), triangles(&after));
+}
+
+#[test]
+fn test_optimize_indices_reencodes_data_uris() {
+    let (document, buffers, _) = gltf::import("tests/box_sparse.gltf").unwrap();
+    let before = read_indices(&document, &buffers);
+    let mut root = document.to_embedded_json(&buffers, &[]).unwrap();
+    let mut buffers = buffers.iter().map(|x| gltf::buffer::Data::from(std::sync::Arc::from(&**x))).collect::<Vec<_>>();
+
+    let report = root.optimize_indices(&mut buffers).unwrap();
+    assert_eq!(report.reencoded, vec![0]);
+    assert!(matches!(buffers[0], gltf::buffer::Data::Owned(_)));
+
+    // The document is self-contained after optimizing.
+    let path = std::env::temp_dir().join(format!("gltf_optimize_indices_{}.gltf", std::process::id()));
+    std::fs::write(&path, root.to_vec().unwrap()).unwrap();
+    let (document, imported, _) = gltf::import(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(&*imported[0], &*buffers[0]);
+    assert_eq!(triangles(&before), triangles(&read_indices(&document, &imported)));
+}
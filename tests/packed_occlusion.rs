@@ -0,0 +1,62 @@
+const DOCUMENT: &str = r#"{
+    "asset": {"version": "2.0"},
+    "images": [{"uri": "orm.png"}, {"uri": "occlusion.png"}],
+    "samplers": [{"wrapS": 33071}, {"wrapS": 33071}, {}],
+    "textures": [
+        {"source": 0, "sampler": 0},
+        {"source": 0, "sampler": 1},
+        {"source": 0, "sampler": 2},
+        {"source": 1, "sampler": 0}
+    ],
+    "materials": [
+        {"pbrMetallicRoughness": {"metallicRoughnessTexture": {"index": 0}}, "occlusionTexture": {"index": 0}},
+        {"pbrMetallicRoughness": {"metallicRoughnessTexture": {"index": 0}}, "occlusionTexture": {"index": 1}},
+        {"pbrMetallicRoughness": {"metallicRoughnessTexture": {"index": 0}}, "occlusionTexture": {"index": 2}},
+        {"pbrMetallicRoughness": {"metallicRoughnessTexture": {"index": 0}}, "occlusionTexture": {"index": 3}},
+        {"pbrMetallicRoughness": {"metallicRoughnessTexture": {"index": 0}}, "occlusionTexture": {"index": 0, "texCoord": 1}},
+        {"occlusionTexture": {"index": 0}},
+        {"pbrMetallicRoughness": {"metallicRoughnessTexture": {"index": 0}}}
+    ]
+}"#;
+
+#[test]
+fn test_packed() {
+    let gltf = gltf::Gltf::from_slice(DOCUMENT.as_bytes()).unwrap();
+    let packed = gltf.materials().map(|x| x.occlusion_packed_in_metallic_roughness()).collect::<Vec<_>>();
+    // Different textures with the same image and sampler settings are packed.
+    assert_eq!(packed, vec![true, true, false, false, false, false, false]);
+
+    let stats = gltf.packed_occlusion_stats();
+    assert_eq!(stats, gltf::material::PackedOcclusionStats {
+        materials: 7,
+        occlusion: 6,
+        packed: 2,
+        unpacked: vec![2, 3, 4],
+    });
+}
+
+#[cfg(not(feature = "KHR_texture_transform"))]
+#[test]
+fn test_texture_transform() {
+    let json = DOCUMENT.replacen('{', r#"{"extensionsUsed": ["KHR_texture_transform"],"#, 1);
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    assert!(gltf.materials().all(|x| !x.occlusion_packed_in_metallic_roughness()));
+    assert_eq!(gltf.packed_occlusion_stats().packed, 0);
+}
+
+#[cfg(feature = "KHR_texture_transform")]
+#[test]
+fn test_texture_transform() {
+    let transform = r#""extensions": {"KHR_texture_transform": {"offset": [0.5, 0]}}"#;
+    let json = DOCUMENT
+        .replacen('{', r#"{"extensionsUsed": ["KHR_texture_transform"],"#, 1)
+        .replacen(r#""occlusionTexture": {"index": 0}"#, &format!(r#""occlusionTexture": {{"index": 0, {}}}"#, transform), 1)
+        .replacen(r#""metallicRoughnessTexture": {"index": 0}}, "occlusionTexture": {"index": 1}"#, &format!(
+            r#""metallicRoughnessTexture": {{"index": 0, {0}}}}}, "occlusionTexture": {{"index": 1, {0}}}"#,
+            transform,
+        ), 1);
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    let packed = gltf.materials().map(|x| x.occlusion_packed_in_metallic_roughness()).collect::<Vec<_>>();
+    // Only references transformed the same way are packed.
+    assert_eq!(packed, vec![false, true, false, false, false, false, false]);
+}
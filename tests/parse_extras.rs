@@ -0,0 +1,44 @@
+use gltf::json::extras::ParseExtras;
+
+const JSON: &str = r#"{
+    "asset": {"version": "2.0"},
+    "nodes": [
+        {"name": "part", "extras": {"part": "A-113", "tolerances": [0.5, 0.25]}},
+        {"name": "plain"}
+    ]
+}"#;
+
+#[cfg(feature = "extras")]
+#[test]
+fn test_parse_on_demand() {
+    use std::collections::BTreeMap;
+
+    type Part = BTreeMap<String, gltf::json::Value>;
+
+    let gltf = gltf::Gltf::from_slice(JSON.as_bytes()).unwrap();
+    let nodes = gltf.nodes().collect::<Vec<_>>();
+    let part = nodes[0].extras().parse::<Part>().unwrap().unwrap();
+    assert_eq!(part.keys().collect::<Vec<_>>(), vec!["part", "tolerances"]);
+    assert_eq!(part["part"], "A-113");
+    assert_eq!(nodes[0].extras().as_value().unwrap()["tolerances"][1], 0.25);
+
+    // The extras are parsed once and the value is reused.
+    let value = nodes[0].extras().as_value().unwrap();
+    assert!(std::ptr::eq(value, nodes[0].extras().as_value().unwrap()));
+
+    // Extras of the wrong shape are reported rather than ignored.
+    assert!(nodes[0].extras().parse::<Vec<u32>>().unwrap().is_err());
+
+    assert!(nodes[1].extras().parse::<Part>().is_none());
+    assert!(nodes[1].extras().as_value().is_none());
+}
+
+#[cfg(not(feature = "extras"))]
+#[test]
+fn test_extras_discarded() {
+    let gltf = gltf::Gltf::from_slice(JSON.as_bytes()).unwrap();
+    for node in gltf.nodes() {
+        assert!(node.extras().as_value().is_none());
+        assert!(node.extras().parse::<u32>().is_none());
+    }
+}
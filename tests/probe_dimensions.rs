@@ -0,0 +1,63 @@
+use std::fs;
+
+use gltf::image::probe_dimensions;
+
+// The fixtures in `tests/probe` contain complete headers but no meaningful
+// pixel data.
+
+#[test]
+fn test_probe_progressive_jpeg() {
+    // The APP1 segment contains bytes resembling a baseline frame header,
+    // which must be skipped together with the segment.
+    let data = fs::read("tests/probe/progressive.jpg").unwrap();
+    assert_eq!(probe_dimensions(&data), Some((37, 19)));
+
+    // Truncated before the frame header.
+    assert_eq!(probe_dimensions(&data[..60]), None);
+}
+
+#[test]
+fn test_probe_extended_webp() {
+    let data = fs::read("tests/probe/extended.webp").unwrap();
+    assert_eq!(probe_dimensions(&data), Some((300, 200)));
+}
+
+#[test]
+fn test_probe_simple_webp() {
+    let mut lossy = b"RIFF\0\0\0\0WEBPVP8 \0\0\0\0".to_vec();
+    lossy.extend_from_slice(&[0x30, 0x01, 0x00, 0x9D, 0x01, 0x2A]);
+    lossy.extend_from_slice(&[0x40, 0x01, 0xF0, 0x80]);
+    assert_eq!(probe_dimensions(&lossy), Some((320, 240)));
+
+    let mut lossless = b"RIFF\0\0\0\0WEBPVP8L\0\0\0\0\x2F".to_vec();
+    lossless.extend_from_slice(&(15u32 | 31 << 14).to_le_bytes());
+    assert_eq!(probe_dimensions(&lossless), Some((16, 32)));
+}
+
+#[test]
+fn test_probe_png_and_ktx2() {
+    let mut png = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR".to_vec();
+    png.extend_from_slice(&640u32.to_be_bytes());
+    png.extend_from_slice(&480u32.to_be_bytes());
+    assert_eq!(probe_dimensions(&png), Some((640, 480)));
+    assert_eq!(probe_dimensions(&png[..20]), None);
+
+    let mut ktx2 = b"\xABKTX 20\xBB\r\n\x1a\n".to_vec();
+    for value in &[37u32, 1, 512, 0] {
+        ktx2.extend_from_slice(&value.to_le_bytes());
+    }
+    assert_eq!(probe_dimensions(&ktx2), Some((512, 1)));
+}
+
+#[test]
+fn test_image_dimensions() {
+    let json = r#"{
+        "asset": {"version": "2.0"},
+        "images": [{"uri": "progressive.jpg"}]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    let image = gltf.images().next().unwrap();
+    let data = fs::read("tests/probe/progressive.jpg").unwrap();
+    assert_eq!(image.dimensions(&data), Some((37, 19)));
+    assert_eq!(image.dimensions(b"not an image"), None);
+}
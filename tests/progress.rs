@@ -0,0 +1,33 @@
+#![cfg(feature = "import")]
+
+use gltf::progress::Event;
+
+#[test]
+fn test_events() {
+    let mut events = Vec::new();
+    let (_, buffers, images) = gltf::import_with_progress("tests/progress.gltf", &mut |x| events.push(x)).unwrap();
+    assert_eq!((buffers.len(), images.len()), (2, 2));
+
+    let bytes = std::fs::metadata("tests/progress.gltf").unwrap().len() as usize;
+    assert_eq!(events, vec![
+        Event::JsonParsed { bytes: Some(bytes) },
+        Event::ValidationDone,
+        Event::BufferLoaded { index: 0, bytes: 12, total_buffers: 2 },
+        Event::BufferLoaded { index: 1, bytes: 5, total_buffers: 2 },
+        Event::ImageDecoded { index: 0, total: 2 },
+        Event::ImageDecoded { index: 1, total: 2 },
+        Event::Finished { bytes: 20 },
+    ]);
+}
+
+#[test]
+fn test_slice_events() {
+    let bytes = std::fs::read("examples/Box.glb").unwrap();
+    let mut events = Vec::new();
+    gltf::import_slice_with_progress(&bytes, &mut |x| events.push(x)).unwrap();
+    assert_eq!(events[0], Event::JsonParsed { bytes: Some(bytes.len()) });
+    assert_eq!(events[1], Event::ValidationDone);
+    assert!(matches!(events[2], Event::BufferLoaded { index: 0, total_buffers: 1, .. }));
+    assert!(matches!(events[3], Event::Finished { .. }));
+    assert_eq!(events.len(), 4);
+}
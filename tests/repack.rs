@@ -0,0 +1,148 @@
+#![cfg(all(feature = "import", feature = "utils"))]
+
+use std::fs;
+
+use gltf::accessor::{DataType, Dimensions, Iter};
+use gltf::json::repack::Layout;
+
+/// Reads every component of the first `count` elements of an accessor.
+fn components<T, F>(iter: Iter<T>, count: usize, f: F) -> Vec<f64>
+where
+    T: gltf::accessor::Item,
+    F: Fn(T) -> Vec<f64>,
+{
+    let mut components = Vec::new();
+    for item in iter.take(count) {
+        components.extend(f(item));
+    }
+    components
+}
+
+/// Reads every component of every accessor as `f64`.
+fn read_accessors(document: &gltf::Document, buffers: &[Vec<u8>]) -> Vec<Vec<f64>> {
+    let get = |buffer: gltf::Buffer| buffers.get(buffer.index()).map(Vec::as_slice);
+    document
+        .accessors()
+        .map(|accessor| {
+            let count = accessor.count();
+            match (accessor.data_type().unwrap(), accessor.dimensions().unwrap()) {
+                (DataType::U16, Dimensions::Scalar) => {
+                    let iter = Iter::<u16>::new(accessor, get).unwrap();
+                    components(iter, count, |x| vec![f64::from(x)])
+                },
+                (DataType::U32, Dimensions::Scalar) => {
+                    let iter = Iter::<u32>::new(accessor, get).unwrap();
+                    components(iter, count, |x| vec![f64::from(x)])
+                },
+                (DataType::F32, Dimensions::Scalar) => {
+                    let iter = Iter::<f32>::new(accessor, get).unwrap();
+                    components(iter, count, |x| vec![f64::from(x)])
+                },
+                (DataType::F32, Dimensions::Vec3) => {
+                    let iter = Iter::<[f32; 3]>::new(accessor, get).unwrap();
+                    components(iter, count, |x| x.iter().cloned().map(f64::from).collect())
+                },
+                other => panic!("unexpected accessor type {:?}", other),
+            }
+        })
+        .collect()
+}
+
+fn check_repack(path: &str, layout: Layout) -> (gltf::json::Root, gltf::json::repack::Report) {
+    let (document, buffers, _) = gltf::import(path).unwrap();
+    let mut buffers = buffers.iter().map(|x| x.to_vec()).collect::<Vec<_>>();
+    let before = read_accessors(&document, &buffers);
+
+    let mut root = document.into_json();
+    let report = root.repack_buffers(&mut buffers, layout).unwrap();
+    let document = gltf::Document::from_json(root.clone()).unwrap();
+    let after = read_accessors(&document, &buffers);
+
+    assert_eq!(before, after);
+    for (buffer, data) in root.buffers.iter().zip(&buffers) {
+        assert_eq!(buffer.byte_length as usize, data.len());
+    }
+    (root, report)
+}
+
+#[test]
+fn test_repack_separate() {
+    let (root, report) = check_repack("examples/Box.gltf", Layout::Separate);
+    assert_eq!(root.buffer_views.len(), 3);
+    assert!(root.buffer_views.iter().all(|x| x.byte_stride.is_none()));
+    assert_eq!(report.bytes_after, 648);
+}
+
+#[test]
+fn test_repack_interleaved() {
+    let (root, report) = check_repack("examples/Box.gltf", Layout::InterleavedPerPrimitive);
+    assert_eq!(root.buffer_views.len(), 2);
+    assert_eq!(root.buffer_views[0].byte_stride, Some(24));
+    assert_eq!(report.bytes_after, 648);
+}
+
+#[test]
+fn test_repack_sparse_and_animation() {
+    for &layout in &[Layout::Separate, Layout::InterleavedPerPrimitive] {
+        let (root, report) = check_repack("tests/box_sparse.gltf", layout);
+        assert_eq!(report.copied_views.len(), 2);
+        let sparse = root.accessors[2].sparse.as_ref().unwrap();
+        let copied = report.copied_views.iter().map(|x| x.value()).collect::<Vec<_>>();
+        assert!(copied.contains(&sparse.indices.buffer_view.value()));
+        assert!(copied.contains(&sparse.values.buffer_view.value()));
+    }
+}
+
+#[test]
+fn test_repack_strips_unused_bytes() {
+    let json = fs::read_to_string("examples/Box.gltf").unwrap();
+    let mut root = gltf::json::Root::from_str(&json).unwrap();
+    let mut data = fs::read("examples/Box0.bin").unwrap();
+    data.extend_from_slice(&[0xff; 64]);
+    root.buffers[0].byte_length += 64;
+    root.buffer_views.push(gltf::json::buffer::View {
+        buffer: gltf::json::Index::new(0),
+        byte_length: 64,
+        byte_offset: Some(648),
+        byte_stride: None,
+        #[cfg(feature = "names")]
+        name: None,
+        target: None,
+        extensions: None,
+        extras: Default::default(),
+    });
+
+    let mut buffers = vec![data];
+    let report = root.repack_buffers(&mut buffers, Layout::Separate).unwrap();
+    assert_eq!(report.bytes_before, 712);
+    assert_eq!(report.bytes_after, 648);
+    assert_eq!(report.removed_views, 1);
+    assert_eq!(root.buffers[0].byte_length, 648);
+}
+
+#[test]
+fn test_repack_dangling_views() {
+    let json = r#"{
+        "asset": {"version": "2.0"},
+        "buffers": [{"byteLength": 8}],
+        "bufferViews": [{"buffer": 0, "byteLength": 8}],
+        "accessors": [{
+            "componentType": 5126, "count": 2, "type": "SCALAR",
+            "sparse": {"count": 1, "indices": {"bufferView": 0, "componentType": 5121}, "values": {"bufferView": 0}}
+        }],
+        "images": [{"bufferView": 0, "mimeType": "image/png"}]
+    }"#;
+    let cases = [
+        (r#""images": [{"bufferView": 3"#, "images[0].bufferView"),
+        (r#""indices": {"bufferView": 3"#, "accessors[0].sparse.indices.bufferView"),
+        (r#""values": {"bufferView": 3"#, "accessors[0].sparse.values.bufferView"),
+    ];
+    for (replacement, expected) in cases {
+        let original = replacement.replace('3', "0");
+        let mut root = gltf::json::Root::from_str(&json.replace(&original, replacement)).unwrap();
+        match root.repack_buffers(&mut vec![vec![0; 8]], Layout::Separate) {
+            Err(gltf::json::edit::Error::IndexOutOfBounds(path)) => assert_eq!(path.as_str(), expected),
+            x => panic!("unexpected result: {:?}", x.map(|x| x.removed_views)),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use gltf::Buffer;
+
+/// A skinned primitive with a morph target, 32-bit indices, and a PNG image
+/// stored in a buffer view.
+const SKINNED: &str = r#"{
+    "asset": {"version": "2.0"},
+    "extensionsUsed": ["KHR_materials_unlit"],
+    "buffers": [{"byteLength": 24}, {"byteLength": 144}],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 24},
+        {"buffer": 1, "byteLength": 36},
+        {"buffer": 1, "byteOffset": 36, "byteLength": 12},
+        {"buffer": 1, "byteOffset": 48, "byteLength": 24},
+        {"buffer": 1, "byteOffset": 72, "byteLength": 48},
+        {"buffer": 1, "byteOffset": 120, "byteLength": 24}
+    ],
+    "images": [{"bufferView": 0, "mimeType": "image/png"}, {"uri": "missing.png"}],
+    "accessors": [
+        {"bufferView": 1, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [0, 0, 0]},
+        {"bufferView": 2, "componentType": 5125, "count": 3, "type": "SCALAR"},
+        {"bufferView": 3, "componentType": 5123, "count": 3, "type": "VEC4"},
+        {"bufferView": 4, "componentType": 5126, "count": 3, "type": "VEC4"},
+        {"bufferView": 5, "componentType": 5126, "count": 3, "type": "VEC2"}
+    ],
+    "meshes": [{
+        "primitives": [{
+            "attributes": {"POSITION": 0, "JOINTS_0": 2, "WEIGHTS_0": 3, "TEXCOORD_1": 4},
+            "indices": 1,
+            "targets": [{"POSITION": 0}]
+        }]
+    }],
+    "skins": [{"joints": [1, 2]}],
+    "nodes": [{"mesh": 0, "skin": 0}, {"children": [2]}, {}]
+}"#;
+
+const PNG: &[u8] = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR\0\0\x01\0\0\0\0\x40";
+
+#[test]
+fn test_requirements_metadata_only() {
+    let gltf = gltf::Gltf::from_slice(SKINNED.as_bytes()).unwrap();
+    let requirements = gltf.requirements(None::<fn(Buffer) -> Option<&'static [u8]>>);
+    assert_eq!(requirements.extensions_used, vec!["KHR_materials_unlit"]);
+    assert!(requirements.extensions_required.is_empty());
+    assert!(requirements.skinning);
+    assert_eq!(requirements.max_skin_joints, 2);
+    assert_eq!(requirements.max_joint_sets, 1);
+    assert!(requirements.morph_targets);
+    assert_eq!(requirements.max_morph_targets, 1);
+    assert_eq!(requirements.max_tex_coord_sets, 2);
+    assert_eq!(requirements.max_color_sets, 0);
+    assert!(requirements.u32_indices);
+    assert!(!requirements.animations);
+    assert_eq!(requirements.max_image_size, None);
+    assert_eq!(requirements.unprobed_images, 2);
+}
+
+#[test]
+fn test_requirements_image_sizes() {
+    let gltf = gltf::Gltf::from_slice(SKINNED.as_bytes()).unwrap();
+    let requirements = gltf.requirements(Some(|_: Buffer| Some(PNG)));
+    assert_eq!(requirements.max_image_size, Some(256));
+    assert_eq!(requirements.unprobed_images, 1);
+    let summary = requirements.to_string();
+    assert!(summary.contains("skinning: yes (max 2 joints per skin, 1 joint sets)"), "{}", summary);
+    assert!(summary.ends_with("max image size: 256 (1 images not probed)"), "{}", summary);
+}
+
+#[test]
+fn test_requirements_box() {
+    let gltf = gltf::Gltf::open("examples/Box.gltf").unwrap();
+    let requirements = gltf.requirements(None::<fn(Buffer) -> Option<&'static [u8]>>);
+    assert_eq!(requirements, gltf::requirements::Requirements::default());
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn test_serialize_requirements() {
+    let gltf = gltf::Gltf::from_slice(SKINNED.as_bytes()).unwrap();
+    let requirements = gltf.requirements(Some(|_: Buffer| Some(PNG)));
+    let json = gltf::json::serialize::to_value(&requirements).unwrap();
+    assert_eq!(json["skinning"], true);
+    assert_eq!(json["max_image_size"], 256);
+    assert_eq!(json["extensions_required"], gltf::json::Value::Array(vec![]));
+}
@@ -0,0 +1,225 @@
+#![cfg(feature = "utils")]
+
+use gltf::animation::util::{self, ChannelData, Values};
+use gltf::animation::Interpolation;
+
+const TOLERANCE: f32 = 0.01;
+
+fn components(values: &Values) -> Vec<Vec<f32>> {
+    match *values {
+        Values::Translations(ref x) | Values::Scales(ref x) => x.iter().map(|x| x.to_vec()).collect(),
+        Values::Rotations(ref x) => x.iter().map(|x| x.to_vec()).collect(),
+        Values::MorphTargetWeights(ref x) => x.iter().map(|&x| vec![x]).collect(),
+    }
+}
+
+fn slerp(a: &[f32], b: &[f32], t: f32) -> Vec<f32> {
+    let d: f32 = a.iter().zip(b).map(|(a, b)| a * b).sum();
+    let (b, d) = if d < 0.0 {
+        (b.iter().map(|x| -x).collect::<Vec<_>>(), -d)
+    } else {
+        (b.to_vec(), d)
+    };
+    let theta = d.min(1.0).acos();
+    if theta < 1.0e-6 {
+        return a.iter().zip(&b).map(|(a, b)| a + (b - a) * t).collect();
+    }
+    let (wa, wb) = (((1.0 - t) * theta).sin() / theta.sin(), (t * theta).sin() / theta.sin());
+    a.iter().zip(&b).map(|(a, b)| wa * a + wb * b).collect()
+}
+
+/// Evaluates a linearly interpolated channel at time `t`.
+fn sample(data: &ChannelData, t: f32) -> Vec<f32> {
+    let values = components(&data.values);
+    let k = data.inputs.iter().rposition(|&x| x <= t).unwrap();
+    if k + 1 == data.inputs.len() {
+        return values[k].clone();
+    }
+    let s = (t - data.inputs[k]) / (data.inputs[k + 1] - data.inputs[k]);
+    match data.values {
+        Values::Rotations(_) => slerp(&values[k], &values[k + 1], s),
+        _ => values[k].iter().zip(&values[k + 1]).map(|(a, b)| a + (b - a) * s).collect(),
+    }
+}
+
+fn error(values: &Values, expected: &[f32], actual: &[f32]) -> f32 {
+    match *values {
+        Values::Rotations(_) => {
+            let d: f32 = expected.iter().zip(actual).map(|(a, b)| a * b).sum();
+            2.0 * d.abs().min(1.0).acos()
+        },
+        _ => expected.iter().zip(actual).map(|(a, b)| (a - b) * (a - b)).sum::<f32>().sqrt(),
+    }
+}
+
+/// Asserts that `resampled` reproduces `original` at every original timestamp.
+fn assert_within_tolerance(original: &ChannelData, resampled: &ChannelData) {
+    let values = components(&original.values);
+    for (&t, expected) in original.inputs.iter().zip(&values) {
+        let actual = sample(resampled, t);
+        let error = error(&original.values, expected, &actual);
+        assert!(error <= TOLERANCE + 1.0e-5, "error {} at {}", error, t);
+    }
+}
+
+fn times(count: usize) -> Vec<f32> {
+    (0..count).map(|i| i as f32 / 30.0).collect()
+}
+
+fn rotation(angle: f32) -> [f32; 4] {
+    [0.0, 0.0, (angle / 2.0).sin(), (angle / 2.0).cos()]
+}
+
+#[test]
+fn test_resample_translations() {
+    let inputs = times(121);
+    let translations = inputs.iter().map(|&t| [t.sin(), 2.0 * t, 0.0]).collect();
+    let data = ChannelData {
+        interpolation: Interpolation::Linear,
+        inputs,
+        values: Values::Translations(translations),
+    };
+    let resampled = util::resample(&data, TOLERANCE);
+    assert!(resampled.inputs.len() < data.inputs.len() / 4, "{}", resampled.inputs.len());
+    assert_eq!(resampled.inputs.first(), data.inputs.first());
+    assert_eq!(resampled.inputs.last(), data.inputs.last());
+    assert_within_tolerance(&data, &resampled);
+}
+
+#[test]
+fn test_resample_rotations() {
+    // Constant angular velocity is reproduced exactly by slerp.
+    let inputs = times(61);
+    let rotations = inputs.iter().map(|&t| rotation(t)).collect();
+    let data = ChannelData {
+        interpolation: Interpolation::Linear,
+        inputs: inputs.clone(),
+        values: Values::Rotations(rotations),
+    };
+    let resampled = util::resample(&data, TOLERANCE);
+    assert_eq!(resampled.inputs, vec![inputs[0], inputs[60]]);
+
+    // Accelerating rotation with a sign flip half-way.
+    let rotations = inputs
+        .iter()
+        .enumerate()
+        .map(|(i, &t)| {
+            let q = rotation(t * t * 3.0);
+            if i > 30 { [-q[0], -q[1], -q[2], -q[3]] } else { q }
+        })
+        .collect();
+    let data = ChannelData {
+        interpolation: Interpolation::Linear,
+        inputs,
+        values: Values::Rotations(rotations),
+    };
+    let resampled = util::resample(&data, TOLERANCE);
+    assert!(resampled.inputs.len() < data.inputs.len() / 2, "{}", resampled.inputs.len());
+    assert_within_tolerance(&data, &resampled);
+}
+
+#[test]
+fn test_resample_step_and_cubic_spline() {
+    let data = ChannelData {
+        interpolation: Interpolation::Step,
+        inputs: times(6),
+        values: Values::MorphTargetWeights(vec![0.0, 0.0, 0.001, 1.0, 1.0, 1.0]),
+    };
+    let resampled = util::resample(&data, TOLERANCE);
+    assert_eq!(resampled.values, Values::MorphTargetWeights(vec![0.0, 1.0, 1.0]));
+    assert_eq!(resampled.inputs, vec![data.inputs[0], data.inputs[3], data.inputs[5]]);
+
+    let data = ChannelData {
+        interpolation: Interpolation::CubicSpline,
+        inputs: times(3),
+        values: Values::Scales(vec![[1.0; 3]; 9]),
+    };
+    assert_eq!(util::resample(&data, TOLERANCE), data);
+}
+
+fn push_f32(data: &mut Vec<u8>, values: &[f32]) {
+    for value in values {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+}
+
+#[test]
+fn test_resample_animations() {
+    let inputs = times(61);
+    let translations = inputs.iter().flat_map(|&t| vec![t.sin(), 0.0, 0.0]).collect::<Vec<_>>();
+    let rotations = inputs.iter().flat_map(|&t| rotation(t).to_vec()).collect::<Vec<_>>();
+    let mut data = Vec::new();
+    push_f32(&mut data, &inputs);
+    push_f32(&mut data, &translations);
+    push_f32(&mut data, &rotations);
+    push_f32(&mut data, &[0.0, 1.0]);
+    push_f32(&mut data, &[0.0; 18]);
+    let json = format!(
+        r#"{{
+            "asset": {{"version": "2.0"}},
+            "buffers": [{{"byteLength": {}}}],
+            "bufferViews": [
+                {{"buffer": 0, "byteLength": 244}},
+                {{"buffer": 0, "byteOffset": 244, "byteLength": 732}},
+                {{"buffer": 0, "byteOffset": 976, "byteLength": 976}},
+                {{"buffer": 0, "byteOffset": 1952, "byteLength": 8}},
+                {{"buffer": 0, "byteOffset": 1960, "byteLength": 72}}
+            ],
+            "accessors": [
+                {{"bufferView": 0, "componentType": 5126, "count": 61, "type": "SCALAR", "min": [0], "max": [2]}},
+                {{"bufferView": 1, "componentType": 5126, "count": 61, "type": "VEC3"}},
+                {{"bufferView": 2, "componentType": 5126, "count": 61, "type": "VEC4"}},
+                {{"bufferView": 3, "componentType": 5126, "count": 2, "type": "SCALAR", "min": [0], "max": [1]}},
+                {{"bufferView": 4, "componentType": 5126, "count": 6, "type": "VEC3"}}
+            ],
+            "nodes": [{{}}],
+            "animations": [{{
+                "samplers": [
+                    {{"input": 0, "output": 1}},
+                    {{"input": 0, "output": 2}},
+                    {{"input": 3, "output": 4, "interpolation": "CUBICSPLINE"}}
+                ],
+                "channels": [
+                    {{"sampler": 0, "target": {{"node": 0, "path": "translation"}}}},
+                    {{"sampler": 1, "target": {{"node": 0, "path": "rotation"}}}},
+                    {{"sampler": 2, "target": {{"node": 0, "path": "scale"}}}}
+                ]
+            }}]
+        }}"#,
+        data.len(),
+    );
+    let document = gltf::Gltf::from_slice(json.as_bytes()).unwrap().document;
+    let mut buffers = vec![data];
+    let read = |document: &gltf::Document, buffers: &[Vec<u8>]| {
+        let get = |buffer: gltf::Buffer| buffers.get(buffer.index()).map(Vec::as_slice);
+        document
+            .animations()
+            .flat_map(|x| x.channels())
+            .map(|x| ChannelData::from_reader(&x.reader(get)).unwrap())
+            .collect::<Vec<_>>()
+    };
+    let before = read(&document, &buffers);
+
+    let mut root = document.into_json();
+    let report = util::resample_animations(&mut root, &mut buffers, TOLERANCE).unwrap();
+    assert_eq!(report.resampled, 2);
+    assert_eq!(report.skipped.len(), 1);
+    assert_eq!(report.skipped[0].as_str(), "animations[0].samplers[2]");
+    assert_eq!(root.buffers[0].byte_length as usize, buffers[0].len());
+
+    // The shared input accessor is copied for the first sampler and rewritten
+    // in place for the second.
+    let samplers = &root.animations[0].samplers;
+    assert_eq!(samplers[0].input.value(), 5);
+    assert_eq!(samplers[1].input.value(), 0);
+    assert_eq!(samplers[2].input.value(), 3);
+
+    let document = gltf::Document::from_json(root).unwrap();
+    let after = read(&document, &buffers);
+    assert_eq!(before.len(), after.len());
+    let removed = before.iter().zip(&after).map(|(a, b)| a.inputs.len() - b.inputs.len());
+    assert_eq!(report.removed_keyframes, removed.sum::<usize>());
+    assert_within_tolerance(&before[0], &after[0]);
+    assert_within_tolerance(&before[1], &after[1]);
+    assert_eq!(before[2], after[2]);
+}
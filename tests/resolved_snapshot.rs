@@ -0,0 +1,59 @@
+//! Pins the format of `Document::resolved_snapshot`.
+//!
+//! Set `GLTF_UPDATE_SNAPSHOT=1` to record a new snapshot instead of comparing.
+#![cfg(all(feature = "serialize", feature = "names"))]
+
+use std::{env, fs};
+
+const SNAPSHOT_PATH: &str = "tests/box_resolved_snapshot.json";
+
+#[test]
+fn test_box_resolved_snapshot() {
+    let gltf = gltf::Gltf::open("examples/Box.gltf").unwrap();
+    let mut actual = gltf.resolved_snapshot();
+
+    // Fields that depend on optional features are not part of the snapshot.
+    #[cfg(feature = "KHR_materials_unlit")]
+    for material in actual["materials"].as_array_mut().unwrap() {
+        material.as_object_mut().unwrap().remove("unlit");
+    }
+
+    if env::var_os("GLTF_UPDATE_SNAPSHOT").is_some() {
+        let json = gltf::json::serialize::to_string_pretty(&actual).unwrap();
+        fs::write(SNAPSHOT_PATH, json + "\n").unwrap();
+        return;
+    }
+    let expected = fs::read_to_string(SNAPSHOT_PATH).unwrap();
+    let expected: gltf::json::Value = gltf::json::deserialize::from_str(&expected).unwrap();
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_defaults_are_resolved() {
+    let json = r#"{
+        "asset": {"version": "2.0"},
+        "images": [{"uri": "a.png"}],
+        "textures": [{"source": 0}],
+        "materials": [{"pbrMetallicRoughness": {"baseColorTexture": {"index": 0}}}],
+        "nodes": [{"translation": [1, 2, 3]}]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    let snapshot = gltf.resolved_snapshot();
+
+    let material = &snapshot["materials"][0];
+    assert_eq!(material["alpha_cutoff"], 0.5);
+    assert_eq!(material["pbr_metallic_roughness"]["metallic_factor"], 1.0);
+    assert_eq!(material["pbr_metallic_roughness"]["base_color_texture"]["tex_coord"], 0);
+
+    let sampler = &snapshot["textures"][0]["sampler"];
+    assert!(sampler["index"].is_null());
+    assert_eq!(sampler["wrap_s"], "Repeat");
+
+    let transform = &snapshot["nodes"][0]["transform"];
+    assert_eq!(transform["translation"], array(&[1.0, 2.0, 3.0]));
+    assert_eq!(transform["rotation"], array(&[0.0, 0.0, 0.0, 1.0]));
+}
+
+fn array(values: &[f64]) -> gltf::json::Value {
+    values.iter().map(|&x| gltf::json::Value::from(x)).collect()
+}
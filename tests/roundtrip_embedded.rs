@@ -0,0 +1,95 @@
+#![cfg(feature = "import")]
+
+use std::error::Error as StdError;
+
+const PIXELS: [u8; 16] = [
+    255, 0, 0, 255,
+    0, 255, 0, 255,
+    0, 0, 255, 255,
+    255, 255, 255, 255,
+];
+
+type Asset = (gltf::Document, Vec<gltf::buffer::Data>, Vec<u8>);
+
+/// Loads `Box.gltf` and adds an image stored in an external file.
+fn box_with_image() -> Result<Asset, Box<dyn StdError>> {
+    let (document, buffers, _) = gltf::import("examples/Box.gltf")?;
+    let mut json = document.into_json();
+    json.images.push(gltf::json::deserialize::from_str(r#"{"uri": "checker.png"}"#)?);
+    let mut png = Vec::new();
+    image::png::PNGEncoder::new(&mut png).encode(&PIXELS, 2, 2, image::ColorType::RGBA(8))?;
+    Ok((gltf::Document::from_json(json)?, buffers, png))
+}
+
+#[test]
+fn embedded_json() -> Result<(), Box<dyn StdError>> {
+    let (document, buffers, png) = box_with_image()?;
+    let root = document.to_embedded_json(&buffers, std::slice::from_ref(&png))?;
+    let path = std::env::temp_dir().join(format!("gltf_roundtrip_embedded_{}.gltf", std::process::id()));
+    std::fs::write(&path, root.to_vec()?)?;
+    let imported = gltf::import(&path);
+    let _ = std::fs::remove_file(&path);
+    let (output, output_buffers, output_images) = imported?;
+
+    let length = buffers[0].len();
+    assert_eq!(&output_buffers[0][..length], &buffers[0][..]);
+    match output.images().next().unwrap().source().unwrap() {
+        gltf::image::Source::Uri { uri, .. } => {
+            let prefix = "data:image/png;base64,";
+            assert!(uri.starts_with(prefix));
+            assert_eq!(base64::decode(&uri[prefix.len()..])?, png);
+        },
+        _ => panic!("expected image URI"),
+    }
+    assert_eq!(output_images[0].pixels, &PIXELS[..]);
+    Ok(())
+}
+
+#[test]
+fn glb() -> Result<(), Box<dyn StdError>> {
+    let (document, buffers, png) = box_with_image()?;
+    let glb = document.to_glb(&buffers, std::slice::from_ref(&png))?;
+    let bytes = glb.to_vec()?;
+    assert_eq!(glb.header.length as usize, bytes.len());
+    let (output, output_buffers, output_images) = gltf::import_slice(&bytes)?;
+
+    let length = document.buffers().next().unwrap().length();
+    assert_eq!(&output_buffers[0][..length], &buffers[0][..length]);
+    match output.images().next().unwrap().source().unwrap() {
+        gltf::image::Source::View { view, mime_type } => {
+            assert_eq!(*mime_type, gltf::image::MimeType::Png);
+            assert_eq!(view.buffer().unwrap().index(), 0);
+            assert_eq!(view.offset() % 4, 0);
+            let begin = view.offset();
+            let end = begin + view.length();
+            assert_eq!(&output_buffers[0][begin..end], &png[..]);
+        },
+        _ => panic!("expected image buffer view"),
+    }
+    assert_eq!(output_images[0].pixels, &PIXELS[..]);
+    Ok(())
+}
+
+#[test]
+fn default_sampler_is_not_written() -> Result<(), Box<dyn StdError>> {
+    let (document, buffers, png) = box_with_image()?;
+    let mut json = document.into_json();
+    json.textures.push(gltf::json::deserialize::from_str(r#"{"source": 0}"#)?);
+    let document = gltf::Document::from_json(json)?;
+    let texture = document.textures().next().unwrap();
+    assert!(texture.sampler_authored().is_none());
+    assert_eq!(texture.sampler().index(), None);
+
+    let embedded = document.to_embedded_json(&buffers, std::slice::from_ref(&png))?;
+    let glb = document.to_glb(&buffers, std::slice::from_ref(&png))?;
+    let glb_json = std::str::from_utf8(&glb.json)?;
+    for text in &[embedded.to_string()?, glb_json.to_string()] {
+        assert!(!text.contains("sampler"), "{}", text);
+    }
+
+    let (output, _, _) = gltf::import_slice(&glb.to_vec()?)?;
+    let texture = output.textures().next().unwrap();
+    assert!(texture.sampler_authored().is_none());
+    assert_eq!(output.samplers().count(), 0);
+    Ok(())
+}
@@ -0,0 +1,51 @@
+const JSON: &str = r#"{
+    "asset": {"version": "2.0"},
+    "extensionsUsed": ["KHR_lights_punctual"],
+    "extensions": {"KHR_lights_punctual": {"lights": [{"type": "point"}, {"type": "directional"}]}},
+    "cameras": [
+        {"type": "perspective", "perspective": {"yfov": 1.0, "znear": 0.1}},
+        {"type": "perspective", "perspective": {"yfov": 0.5, "znear": 0.1}}
+    ],
+    "nodes": [
+        {"camera": 0, "translation": [1, 0, 0], "children": [1, 2]},
+        {"camera": 0, "translation": [0, 2, 0], "extensions": {"KHR_lights_punctual": {"light": 0}}},
+        {"camera": 1, "extensions": {"KHR_lights_punctual": {"light": 0}}},
+        {"camera": 1, "extensions": {"KHR_lights_punctual": {"light": 1}}}
+    ],
+    "scenes": [{"nodes": [0]}]
+}"#;
+
+#[test]
+fn test_scene_cameras() {
+    let gltf = gltf::Gltf::from_slice(JSON.as_bytes()).unwrap();
+    let scene = gltf.scenes().next().unwrap();
+
+    let cameras = scene
+        .cameras()
+        .map(|(camera, node, transform)| (camera.index(), node.index(), transform[3]))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        cameras,
+        vec![(0, 0, [1.0, 0.0, 0.0, 1.0]), (0, 1, [1.0, 2.0, 0.0, 1.0]), (1, 2, [1.0, 0.0, 0.0, 1.0])],
+    );
+
+    let unique = scene.cameras().unique().map(|(camera, node, _)| (camera.index(), node.index()));
+    assert_eq!(unique.collect::<Vec<_>>(), vec![(0, 0), (1, 2)]);
+}
+
+#[cfg(feature = "KHR_lights_punctual")]
+#[test]
+fn test_scene_lights() {
+    let gltf = gltf::Gltf::from_slice(JSON.as_bytes()).unwrap();
+    let scene = gltf.scenes().next().unwrap();
+
+    // The directional light is only referenced by a node outside the scene.
+    let lights = scene
+        .lights()
+        .map(|(light, node, transform)| (light.index(), node.index(), transform[3]))
+        .collect::<Vec<_>>();
+    assert_eq!(lights, vec![(0, 1, [1.0, 2.0, 0.0, 1.0]), (0, 2, [1.0, 0.0, 0.0, 1.0])]);
+
+    let unique = scene.lights().unique().map(|(light, node, _)| (light.index(), node.index()));
+    assert_eq!(unique.collect::<Vec<_>>(), vec![(0, 1)]);
+}
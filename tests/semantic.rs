@@ -0,0 +1,147 @@
+use gltf::json::mesh::Semantic;
+use gltf::json::validation::{Checked, Error, Severity};
+use gltf::json;
+
+/// A xorshift generator, so that the generated semantics are reproducible.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Returns a set index with a random number of digits.
+    fn set(&mut self) -> u32 {
+        match self.next() % 4 {
+            0 => (self.next() % 10) as u32,
+            1 => (self.next() % 1000) as u32,
+            2 => self.next() as u32,
+            _ => u32::MAX,
+        }
+    }
+
+    fn semantic(&mut self) -> Semantic {
+        match self.next() % 8 {
+            0 => Semantic::Positions,
+            1 => Semantic::Normals,
+            2 => Semantic::Tangents,
+            3 => Semantic::Colors(self.set()),
+            4 => Semantic::TexCoords(self.set()),
+            5 => Semantic::Joints(self.set()),
+            6 => Semantic::Weights(self.set()),
+            _ => {
+                let len = self.next() % 12;
+                let name = (0..len).map(|_| (b"ABC_XYZ019"[(self.next() % 10) as usize]) as char).collect();
+                Semantic::Extras(name)
+            },
+        }
+    }
+}
+
+#[test]
+fn test_round_trip() {
+    let mut rng = Rng(0x2545_F491_4F6C_DD1D);
+    for _ in 0..10_000 {
+        let semantic = rng.semantic();
+        let name = semantic.to_string();
+        assert_eq!(name.parse::<Semantic>(), Ok(semantic.clone()), "{}", name);
+
+        let json = json::serialize::to_string(&semantic).unwrap();
+        assert_eq!(json, format!("\"{}\"", name));
+        assert_eq!(json::deserialize::from_str::<Semantic>(&json).unwrap(), semantic);
+        assert_eq!(json::deserialize::from_str::<Checked<Semantic>>(&json).unwrap(), Checked::Valid(semantic));
+    }
+}
+
+#[test]
+fn test_canonical_names() {
+    assert_eq!(Semantic::TexCoords(0).to_string(), "TEXCOORD_0");
+    assert_eq!(Semantic::Colors(1).to_string(), "COLOR_1");
+    assert_eq!(Semantic::Joints(12).to_string(), "JOINTS_12");
+    assert_eq!(Semantic::Extras("CUSTOM".to_string()).to_string(), "_CUSTOM");
+    assert_eq!(Checked::<Semantic>::Invalid.to_string(), "<invalid semantic name>");
+}
+
+#[test]
+fn test_invalid_names() {
+    let invalid = [
+        "TEXCOORD_", "TEXCOORD_00", "TEXCOORD_01", "TEXCOORD_+1", "TEXCOORD_-1", "TEXCOORD_1a",
+        "TEXCOORD_4294967296", "TEXCOORD", "texcoord_0", "POSITION_0", "COLOR_ 1", "",
+    ];
+    for name in &invalid {
+        let error = name.parse::<Semantic>().unwrap_err();
+        assert_eq!(error.0, *name);
+        let json = format!("\"{}\"", name);
+        assert!(json::deserialize::from_str::<Semantic>(&json).is_err(), "{}", name);
+        assert_eq!(json::deserialize::from_str::<Checked<Semantic>>(&json).unwrap(), Checked::Invalid, "{}", name);
+    }
+}
+
+fn document(attribute: &str) -> String {
+    format!(r#"{{"asset":{{"version":"2.0"}},"buffers":[{{"byteLength":36,"uri":"data.bin"}}],"bufferViews":[{{"buffer":0,"byteLength":36}}],"accessors":[{{"bufferView":0,"componentType":5126,"count":3,"type":"VEC3","min":[0,0,0],"max":[1,1,0]}}],"meshes":[{{"primitives":[{{"attributes":{{"POSITION":0,"{}":0}}}}]}}]}}"#, attribute)
+}
+
+#[test]
+fn test_document_round_trip() {
+    for attribute in &["_CUSTOM", "_batch_id", "TEXCOORD_10", "COLOR_1"] {
+        let json = document(attribute);
+        let root = json::Root::from_str(&json).unwrap();
+        let attributes = format!(r#""attributes":{{"POSITION":0,"{}":0}}"#, attribute);
+        assert!(root.to_string().unwrap().contains(&attributes), "{}", attribute);
+
+        let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+        let primitive = gltf.meshes().next().unwrap().primitives().next().unwrap();
+        let names = primitive.attributes().map(|(semantic, _)| semantic.to_string()).collect::<Vec<_>>();
+        assert!(names.contains(&attribute.to_string()), "{:?}", names);
+    }
+}
+
+#[test]
+fn test_non_canonical_attribute() {
+    let json = document("TEXCOORD_00");
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    let primitive = gltf.meshes().next().unwrap().primitives().next().unwrap();
+    assert!(primitive.get(&Semantic::TexCoords(0)).is_some());
+    match gltf.validate_strict() {
+        Err(gltf::Error::Validation(errors)) => {
+            let path = errors.iter().find(|(_, error)| *error == Error::NonCanonicalSemantic).map(|(path, _)| path);
+            assert_eq!(path.map(|x| x.as_str()), Some(r#"meshes[0].primitives[0].attributes["TEXCOORD_00"]"#));
+        },
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    // The attribute is written back as it was read.
+    let root = json::Root::from_str(&json).unwrap();
+    assert!(root.to_string().unwrap().contains(r#""TEXCOORD_00":0"#));
+}
+
+#[test]
+fn test_invalid_attributes() {
+    let json = document(r#"texcoord_0":0,"COLOR_x":0,"TEXCOORD_1":0,"TEXCOORD_01"#);
+    let errors = match gltf::Gltf::from_slice(json.as_bytes()) {
+        Err(gltf::Error::Validation(errors)) => errors
+            .into_iter()
+            .filter(|(_, error)| error.severity() == Severity::Error)
+            .map(|(path, error)| (path.as_str().to_string(), error))
+            .collect::<Vec<_>>(),
+        other => panic!("unexpected result: {:?}", other.map(|_| ())),
+    };
+    let path = |name: &str| format!(r#"meshes[0].primitives[0].attributes["{}"]"#, name);
+    assert_eq!(errors, vec![
+        (path("COLOR_x"), Error::Invalid),
+        (path("TEXCOORD_01"), Error::Duplicate),
+        (path("texcoord_0"), Error::Invalid),
+    ]);
+
+    // Invalid attributes are kept, rather than collapsed into one.
+    let root = json::Root::from_str(&json).unwrap();
+    let attributes = &root.meshes[0].primitives[0].attributes;
+    assert_eq!(attributes.invalid.keys().collect::<Vec<_>>(), vec!["COLOR_x", "TEXCOORD_01", "texcoord_0"]);
+    assert!(attributes.contains_key(&Checked::Valid(Semantic::TexCoords(1))));
+    let gltf = gltf::Gltf::from_slice_without_validation(json.as_bytes()).unwrap();
+    let primitive = gltf.meshes().next().unwrap().primitives().next().unwrap();
+    assert_eq!(primitive.attributes().count(), 2);
+}
@@ -0,0 +1,72 @@
+use gltf::json::edit::Error;
+use gltf::json::{self, Index};
+use gltf::scene::{set_node_transform, Transform};
+
+/// A node defined by a matrix, one defined by TRS properties, and an animated
+/// node.
+const JSON: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 8}],
+    "bufferViews": [{"buffer": 0, "byteLength": 8}],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 1, "type": "SCALAR", "min": [0], "max": [0]},
+        {"bufferView": 0, "byteOffset": 4, "componentType": 5126, "count": 1, "type": "SCALAR"}
+    ],
+    "nodes": [
+        {"matrix": [1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1]},
+        {"translation": [1, 0, 0]},
+        {}
+    ],
+    "animations": [{
+        "channels": [{"sampler": 0, "target": {"node": 2, "path": "weights"}}],
+        "samplers": [{"input": 0, "output": 1}]
+    }]
+}"#;
+
+fn translated(translation: [f32; 3]) -> Transform {
+    Transform::Decomposed { translation, rotation: [0.0, 0.0, 0.0, 1.0], scale: [2.0; 3] }
+}
+
+#[test]
+fn test_keeps_representation() {
+    let mut root = json::Root::from_str(JSON).unwrap();
+
+    set_node_transform(&mut root, Index::new(0), translated([0.0, 3.0, 0.0])).unwrap();
+    let expected = [2.0, 0.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 3.0, 0.0, 1.0];
+    assert_eq!(root.nodes[0].matrix, Some(expected));
+    assert!(root.nodes[0].translation.is_none() && root.nodes[0].scale.is_none());
+
+    let matrix = Transform::Matrix {
+        matrix: [[2.0, 0.0, 0.0, 0.0], [0.0, 2.0, 0.0, 0.0], [0.0, 0.0, 2.0, 0.0], [0.0, 3.0, 0.0, 1.0]],
+    };
+    set_node_transform(&mut root, Index::new(1), matrix.clone()).unwrap();
+    assert!(root.nodes[1].matrix.is_none());
+    assert_eq!(root.nodes[1].translation, Some([0.0, 3.0, 0.0]));
+    assert_eq!(root.nodes[1].rotation.map(|x| x.0), Some([0.0, 0.0, 0.0, 1.0]));
+    assert_eq!(root.nodes[1].scale, Some([2.0; 3]));
+
+    // Animated nodes receive TRS properties even if given a matrix.
+    set_node_transform(&mut root, Index::new(2), matrix).unwrap();
+    assert!(root.nodes[2].matrix.is_none());
+    assert_eq!(root.nodes[2].scale, Some([2.0; 3]));
+    assert!(gltf::Document::from_json(root).is_ok());
+}
+
+#[test]
+fn test_singular_matrix() {
+    let mut root = json::Root::from_str(JSON).unwrap();
+    let singular = Transform::Matrix { matrix: [[0.0; 4]; 4] };
+
+    // A matrix without a finite decomposition is written as a matrix, except
+    // for animated nodes.
+    set_node_transform(&mut root, Index::new(1), singular.clone()).unwrap();
+    assert_eq!(root.nodes[1].matrix, Some([0.0; 16]));
+    assert!(root.nodes[1].translation.is_none());
+    assert_eq!(set_node_transform(&mut root, Index::new(2), singular), Err(Error::AnimatedMatrix));
+    assert!(root.nodes[2].matrix.is_none() && root.nodes[2].scale.is_none());
+
+    match set_node_transform(&mut root, Index::new(3), translated([0.0; 3])) {
+        Err(Error::IndexOutOfBounds(path)) => assert_eq!(path.as_str(), "nodes[3]"),
+        x => panic!("unexpected result: {:?}", x),
+    }
+}
@@ -0,0 +1,187 @@
+#![cfg(feature = "simplify")]
+
+use gltf::mesh::simplify::append_lods;
+
+const RINGS: u32 = 16;
+const SEGMENTS: u32 = 32;
+
+/// A unit UV sphere with a single vertex at each pole and a seam of
+/// duplicated vertices at `u = 0` and `u = 1`.
+fn sphere() -> (Vec<[f32; 3]>, Vec<[f32; 2]>, Vec<u32>) {
+    let mut positions = vec![[0.0, 1.0, 0.0]];
+    let mut tex_coords = vec![[0.5, 0.0]];
+    for ring in 1..RINGS {
+        let theta = std::f32::consts::PI * ring as f32 / RINGS as f32;
+        for segment in 0..=SEGMENTS {
+            let phi = 2.0 * std::f32::consts::PI * segment as f32 / SEGMENTS as f32;
+            positions.push([theta.sin() * phi.cos(), theta.cos(), theta.sin() * phi.sin()]);
+            tex_coords.push([segment as f32 / SEGMENTS as f32, ring as f32 / RINGS as f32]);
+        }
+    }
+    positions.push([0.0, -1.0, 0.0]);
+    tex_coords.push([0.5, 1.0]);
+
+    let vertex = |ring: u32, segment: u32| 1 + (ring - 1) * (SEGMENTS + 1) + segment;
+    let bottom = positions.len() as u32 - 1;
+    let mut indices = Vec::new();
+    for segment in 0..SEGMENTS {
+        indices.extend_from_slice(&[0, vertex(1, segment + 1), vertex(1, segment)]);
+        for ring in 1..RINGS - 1 {
+            let (a, b) = (vertex(ring, segment), vertex(ring, segment + 1));
+            let (c, d) = (vertex(ring + 1, segment), vertex(ring + 1, segment + 1));
+            indices.extend_from_slice(&[a, b, c, b, d, c]);
+        }
+        indices.extend_from_slice(&[bottom, vertex(RINGS - 1, segment), vertex(RINGS - 1, segment + 1)]);
+    }
+    (positions, tex_coords, indices)
+}
+
+fn triangle_count() -> usize {
+    (2 * SEGMENTS * (RINGS - 1)) as usize
+}
+
+/// The greatest distance from the unit sphere of the centroids and edge
+/// midpoints of the triangles.
+fn max_deviation(positions: &[[f32; 3]], indices: &[u32]) -> f32 {
+    let mut deviation = 0.0f32;
+    for triangle in indices.chunks(3) {
+        let [a, b, c] = [0, 1, 2].map(|k| positions[triangle[k] as usize]);
+        let samples = [
+            [0, 1, 2].map(|k| (a[k] + b[k] + c[k]) / 3.0),
+            [0, 1, 2].map(|k| (a[k] + b[k]) / 2.0),
+            [0, 1, 2].map(|k| (b[k] + c[k]) / 2.0),
+            [0, 1, 2].map(|k| (c[k] + a[k]) / 2.0),
+        ];
+        for sample in &samples {
+            let length = sample.iter().map(|x| x * x).sum::<f32>().sqrt();
+            deviation = deviation.max(1.0 - length);
+        }
+    }
+    deviation
+}
+
+#[test]
+fn test_simplify_sphere() {
+    let (positions, _, indices) = sphere();
+    assert_eq!(indices.len() / 3, triangle_count());
+    let original = max_deviation(&positions, &indices);
+
+    let simplified = gltf::mesh::simplify(&positions, &indices, 0.25, 1.0);
+    let count = simplified.len() / 3;
+    assert!(count <= triangle_count() / 4, "{} triangles", count);
+    assert!(count >= triangle_count() / 8, "{} triangles", count);
+    let deviation = max_deviation(&positions, &simplified);
+    assert!(deviation > original && deviation < 0.15, "deviation {}", deviation);
+
+    // The seam is on the border of the mesh and is kept.
+    for ring in 0..RINGS - 1 {
+        for &segment in &[0, SEGMENTS] {
+            assert!(simplified.contains(&(1 + ring * (SEGMENTS + 1) + segment)));
+        }
+    }
+}
+
+#[test]
+fn test_error_limit() {
+    let (positions, _, indices) = sphere();
+    let simplified = gltf::mesh::simplify(&positions, &indices, 0.25, 0.01);
+    assert!(simplified.len() / 3 > triangle_count() / 4);
+    assert!(max_deviation(&positions, &simplified) < 0.02);
+
+    // A curved surface cannot be simplified without error.
+    assert_eq!(gltf::mesh::simplify(&positions, &indices, 0.0, 0.0).len(), indices.len());
+}
+
+#[test]
+fn test_flat_grid() {
+    // A 5x5 vertex grid, whose interior vertices can be removed without error.
+    let positions = (0..25).map(|i| [(i % 5) as f32, (i / 5) as f32, 0.0]).collect::<Vec<_>>();
+    let mut indices = Vec::new();
+    for y in 0..4 {
+        for x in 0..4 {
+            let a = 5 * y + x;
+            indices.extend_from_slice(&[a, a + 1, a + 5, a + 1, a + 6, a + 5]);
+        }
+    }
+    let simplified = gltf::mesh::simplify(&positions, &indices, 0.0, 0.0);
+    assert!(simplified.len() < indices.len());
+    for &vertex in &simplified {
+        let [x, y, _] = positions[vertex as usize];
+        assert!(x == 0.0 || x == 4.0 || y == 0.0 || y == 4.0, "interior vertex {}", vertex);
+    }
+
+    // Every triangle keeps facing +Z.
+    for triangle in simplified.chunks(3) {
+        let [a, b, c] = [0, 1, 2].map(|k| positions[triangle[k] as usize]);
+        let z = (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0]);
+        assert!(z > 0.0);
+    }
+}
+
+#[test]
+fn test_append_lods() {
+    let (positions, tex_coords, indices) = sphere();
+    let mut data = Vec::new();
+    data.extend(positions.iter().flatten().flat_map(|x| x.to_le_bytes().to_vec()));
+    data.extend(tex_coords.iter().flatten().flat_map(|x| x.to_le_bytes().to_vec()));
+    data.extend(indices.iter().flat_map(|x| x.to_le_bytes().to_vec()));
+    let vertex_count = positions.len();
+    let json = format!(
+        r#"{{
+            "asset": {{"version": "2.0"}},
+            "buffers": [{{"byteLength": {}}}],
+            "bufferViews": [
+                {{"buffer": 0, "byteLength": {}}},
+                {{"buffer": 0, "byteOffset": {}, "byteLength": {}}},
+                {{"buffer": 0, "byteOffset": {}, "byteLength": {}}}
+            ],
+            "accessors": [
+                {{"bufferView": 0, "componentType": 5126, "count": {}, "type": "VEC3", "min": [-1, -1, -1], "max": [1, 1, 1]}},
+                {{"bufferView": 1, "componentType": 5126, "count": {}, "type": "VEC2"}},
+                {{"bufferView": 2, "componentType": 5125, "count": {}, "type": "SCALAR"}}
+            ],
+            "meshes": [{{
+                "name": "Sphere",
+                "primitives": [
+                    {{"attributes": {{"POSITION": 0, "TEXCOORD_0": 1}}, "indices": 2}},
+                    {{"attributes": {{"POSITION": 0}}, "mode": 0}}
+                ]
+            }}]
+        }}"#,
+        data.len(),
+        vertex_count * 12,
+        vertex_count * 12,
+        vertex_count * 8,
+        vertex_count * 20,
+        indices.len() * 4,
+        vertex_count,
+        vertex_count,
+        indices.len(),
+    );
+    let mut root = gltf::json::Root::from_str(&json).unwrap();
+    let mut buffers = vec![data];
+    let report = append_lods(&mut root, &mut buffers, &[0.5, 0.25], 1.0).unwrap();
+    assert_eq!(report.lods, vec![vec![1, 2]]);
+    assert_eq!(report.simplified, 2);
+
+    let document = gltf::Document::from_json(root).unwrap();
+    let meshes = document.meshes().collect::<Vec<_>>();
+    assert_eq!(meshes.len(), 3);
+    #[cfg(feature = "names")]
+    assert_eq!(meshes[2].name(), Some("Sphere LOD2"));
+    for (mesh, ratio) in meshes[1..].iter().zip(&[0.5, 0.25]) {
+        let primitives = mesh.primitives().collect::<Vec<_>>();
+        let indices = primitives[0].indices().unwrap();
+        assert_eq!(indices.data_type(), Some(gltf::accessor::DataType::U16));
+        assert!(indices.count() / 3 <= (triangle_count() as f32 * ratio) as usize);
+        assert_eq!(primitives[0].get(&gltf::Semantic::TexCoords(0)).unwrap().index(), 1);
+
+        // Points are not simplified.
+        assert_eq!(primitives[1].mode(), gltf::mesh::Mode::Points);
+        assert!(primitives[1].indices().is_none());
+
+        let reader = primitives[0].reader(|buffer| Some(&buffers[buffer.index()]));
+        let simplified = reader.read_indices().unwrap().into_u32().collect::<Vec<_>>();
+        assert!(max_deviation(&positions, &simplified) < 0.15);
+    }
+}
@@ -0,0 +1,96 @@
+#![cfg(feature = "utils")]
+
+use gltf::accessor::Iter;
+use gltf::json::{self, Index};
+
+/// A mesh with a morph target of `count` zero displacements, and a buffer
+/// holding the positions followed by the displacements.
+fn document(count: usize) -> (json::Root, Vec<Vec<u8>>) {
+    let length = 2 * count * 12;
+    let json = format!(r#"{{
+        "asset": {{"version": "2.0"}},
+        "buffers": [{{"byteLength": {}}}],
+        "bufferViews": [
+            {{"buffer": 0, "byteLength": {}, "target": 34962}},
+            {{"buffer": 0, "byteOffset": {}, "byteLength": {}, "target": 34962}}
+        ],
+        "accessors": [
+            {{"bufferView": 0, "componentType": 5126, "count": {}, "type": "VEC3", "min": [0, 0, 0], "max": [{}, 0, 0]}},
+            {{"bufferView": 1, "componentType": 5126, "count": {}, "type": "VEC3", "min": [0, 0, 0], "max": [0, 0, 0]}}
+        ],
+        "meshes": [{{"primitives": [{{"attributes": {{"POSITION": 0}}, "targets": [{{"POSITION": 1}}]}}]}}]
+    }}"#, length, count * 12, count * 12, count * 12, count, count - 1, count);
+    let mut data = Vec::new();
+    for i in 0..count {
+        for value in &[i as f32, 0.0, 0.0] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+    }
+    data.resize(length, 0);
+    (json::Root::from_str(&json).unwrap(), vec![data])
+}
+
+fn to_bytes(elements: &[[f32; 3]]) -> Vec<u8> {
+    elements.iter().flatten().flat_map(|x| x.to_le_bytes()).collect()
+}
+
+fn read(root: &json::Root, buffers: &[Vec<u8>], index: usize) -> Vec<[f32; 3]> {
+    let document = gltf::Document::from_json(root.clone()).unwrap();
+    let accessor = document.accessors().nth(index).unwrap();
+    Iter::<[f32; 3]>::new(accessor, |buffer: gltf::Buffer| buffers.get(buffer.index()).map(Vec::as_slice))
+        .unwrap()
+        .collect()
+}
+
+#[test]
+fn test_sparse() {
+    let (mut root, mut buffers) = document(300);
+    let mut modified = vec![[0.0f32; 3]; 300];
+    modified[4] = [0.0, 1.0, 0.0];
+    modified[299] = [0.0, 0.0, -2.0];
+
+    let sparse = root.set_accessor_data(Index::new(1), &mut buffers, &to_bytes(&modified), 0.5).unwrap();
+    assert!(sparse);
+    let accessor = &root.accessors[1];
+    assert_eq!(accessor.buffer_view.map(|x| x.value()), Some(1));
+    let storage = accessor.sparse.as_ref().unwrap();
+    assert_eq!(storage.count, 2);
+    assert_eq!(storage.indices.component_type.unwrap().0, json::accessor::ComponentType::U16);
+    assert_eq!(accessor.min, Some(json::Value::from(vec![0.0, 0.0, -2.0])));
+    assert_eq!(accessor.max, Some(json::Value::from(vec![0.0, 1.0, 0.0])));
+    assert_eq!(root.buffers[0].byte_length as usize, buffers[0].len());
+    assert_eq!(read(&root, &buffers, 1), modified);
+
+    // Writing the base data again drops the sparse storage.
+    let sparse = root.set_accessor_data(Index::new(1), &mut buffers, &to_bytes(&[[0.0; 3]; 300]), 0.5).unwrap();
+    assert!(sparse);
+    assert!(root.accessors[1].sparse.is_none());
+    assert_eq!(read(&root, &buffers, 1), vec![[0.0; 3]; 300]);
+}
+
+#[test]
+fn test_dense() {
+    let (mut root, mut buffers) = document(3);
+    let modified = [[1.0f32, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 3.0]];
+
+    let sparse = root.set_accessor_data(Index::new(1), &mut buffers, &to_bytes(&modified), 0.5).unwrap();
+    assert!(!sparse);
+    let accessor = &root.accessors[1];
+    assert!(accessor.sparse.is_none());
+    assert_eq!(accessor.buffer_view.map(|x| x.value()), Some(2));
+    assert_eq!(read(&root, &buffers, 1), modified.to_vec());
+    // The positions sharing the buffer are untouched.
+    assert_eq!(read(&root, &buffers, 0)[2], [2.0, 0.0, 0.0]);
+
+    root.repack_buffers(&mut buffers, json::repack::Layout::Separate).unwrap();
+    assert_eq!(read(&root, &buffers, 1), modified.to_vec());
+}
+
+#[test]
+fn test_invalid_length() {
+    let (mut root, mut buffers) = document(3);
+    let result = root.set_accessor_data(Index::new(1), &mut buffers, &[0; 12], 0.5);
+    assert_eq!(result, Err(json::edit::Error::Invalid(json::Path::new().field("accessors").index(1))));
+    let result = root.set_accessor_data(Index::new(2), &mut buffers, &[0; 12], 0.5);
+    assert!(matches!(result, Err(json::edit::Error::IndexOutOfBounds(_))));
+}
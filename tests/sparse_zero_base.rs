@@ -0,0 +1,31 @@
+#![cfg(all(feature = "import", feature = "utils"))]
+
+#[test]
+fn test_sparse_zero_base_morph_target() {
+    let (document, buffers, _) = gltf::import("tests/sparse_zero_base.gltf").unwrap();
+    let accessor = document.accessors().nth(1).unwrap();
+    assert!(accessor.view().is_none());
+    assert!(accessor.sparse().is_some());
+
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+    let (positions, normals, tangents) = reader.read_morph_targets().next().unwrap();
+    let positions = positions.unwrap();
+    assert_eq!(positions.len(), 3);
+    let positions = positions.collect::<Vec<_>>();
+    assert_eq!(positions, vec![[0.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 0.0]]);
+    assert!(normals.is_none());
+    assert!(tangents.is_none());
+}
+
+#[test]
+fn test_sparse_zero_base_scalar() {
+    let (document, buffers, _) = gltf::import("tests/sparse_zero_base.gltf").unwrap();
+    let accessor = document.accessors().nth(2).unwrap();
+    let get = |buffer: gltf::Buffer| Some(&*buffers[buffer.index()]);
+    let mut iter = gltf::accessor::Iter::<f32>::new(accessor, get).unwrap();
+    assert_eq!(iter.size_hint(), (4, Some(4)));
+    assert_eq!(iter.next(), Some(0.5));
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![0.0, 0.25, 0.0]);
+}
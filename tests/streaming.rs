@@ -0,0 +1,51 @@
+use std::io::Read;
+use std::{fs, io};
+
+/// A reader that yields at most one byte per call and cannot seek.
+struct Trickle<R>(R);
+
+impl<R: Read> Read for Trickle<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let len = buf.len().min(1);
+        self.0.read(&mut buf[..len])
+    }
+}
+
+fn assert_same(path: &str) {
+    let expected = gltf::Gltf::open(path).unwrap();
+    let file = fs::File::open(path).unwrap();
+    let actual = gltf::Gltf::from_reader_streaming(Trickle(io::BufReader::new(file))).unwrap();
+    let to_value = |gltf: &gltf::Gltf| {
+        gltf::json::serialize::to_value(gltf.document.clone().into_json()).unwrap()
+    };
+    assert_eq!(to_value(&expected), to_value(&actual));
+    assert_eq!(expected.blob, actual.blob);
+}
+
+#[test]
+fn test_streaming_gltf() {
+    assert_same("examples/Box.gltf");
+    assert_same("tests/box_sparse.gltf");
+}
+
+#[test]
+fn test_streaming_glb() {
+    assert_same("examples/Box.glb");
+    assert_same("tests/box_sparse.glb");
+}
+
+#[test]
+fn test_streaming_truncated_glb() {
+    let bytes = fs::read("examples/Box.glb").unwrap();
+    let truncated = &bytes[..bytes.len() - 4];
+    assert!(gltf::Gltf::from_reader_streaming(truncated).is_err());
+}
+
+#[cfg(feature = "extras")]
+#[test]
+fn test_streaming_extras() {
+    let json = r#"{"asset": {"version": "2.0"}, "nodes": [{"extras": {"a": [1, {"b": null}]}}]}"#;
+    let gltf = gltf::Gltf::from_reader_streaming(Trickle(json.as_bytes())).unwrap();
+    let node = gltf.nodes().next().unwrap();
+    assert_eq!(node.extras().as_ref().unwrap().get(), r#"{"a": [1, {"b": null}]}"#);
+}
@@ -0,0 +1,124 @@
+use gltf::json::strip::StripOptions;
+use gltf::json::Root;
+
+const DOCUMENT: &str = r#"{
+    "asset": {"version": "2.0", "extras": {"tool": "exporter"}},
+    "extensionsUsed": ["KHR_texture_transform", "EXT_authoring_notes"],
+    "extensions": {"EXT_authoring_notes": {"name": "kept as it is"}},
+    "buffers": [{"name": "buffer", "byteLength": 44, "uri": "data.bin", "extras": {"id": 1}}],
+    "bufferViews": [
+        {"name": "view", "buffer": 0, "byteLength": 36, "extras": {"a": 1}},
+        {"buffer": 0, "byteOffset": 36, "byteLength": 8, "extras": {"b": [2]}}
+    ],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0], "extras": {}},
+        {"bufferView": 1, "componentType": 5126, "count": 2, "type": "SCALAR", "min": [0], "max": [1]},
+        {"bufferView": 1, "componentType": 5126, "count": 2, "type": "SCALAR", "min": [0], "max": [1]}
+    ],
+    "images": [{"uri": "base.png", "name": "image", "extras": {}}],
+    "samplers": [{"name": "sampler", "extras": {"filter": "custom"}}],
+    "textures": [{"source": 0, "sampler": 0, "extras": {}}],
+    "materials": [{
+        "name": "material",
+        "pbrMetallicRoughness": {
+            "baseColorTexture": {
+                "index": 0,
+                "extensions": {"KHR_texture_transform": {"scale": [2, 2]}},
+                "extras": {"note": "tiled"}
+            },
+            "extras": {}
+        },
+        "normalTexture": {"index": 0, "extras": {}},
+        "extras": {}
+    }],
+    "meshes": [{
+        "name": "mesh",
+        "primitives": [{"attributes": {"POSITION": 0}, "material": 0, "extras": {"lod": 0}}],
+        "extras": {}
+    }],
+    "nodes": [{"name": "node", "mesh": 0, "extras": {}}],
+    "animations": [{
+        "name": "animation",
+        "channels": [{"sampler": 0, "target": {"node": 0, "path": "weights", "extras": {}}, "extras": {}}],
+        "samplers": [{"input": 1, "output": 2, "extras": {}}],
+        "extras": {}
+    }],
+    "scenes": [{"name": "scene", "nodes": [0], "extras": {}}],
+    "scene": 0,
+    "extras": {"project": "secret"}
+}"#;
+
+fn all() -> StripOptions {
+    StripOptions { names: true, extras: true, unknown_extensions: true, ..Default::default() }
+}
+
+#[test]
+fn test_strip_everything() {
+    let mut root = Root::from_str(DOCUMENT).unwrap();
+    let report = root.strip(&all()).unwrap();
+    #[cfg(feature = "KHR_texture_transform")]
+    assert_eq!(report.removed_extensions, vec!["EXT_authoring_notes"]);
+    #[cfg(not(feature = "KHR_texture_transform"))]
+    assert_eq!(report.removed_extensions, vec!["EXT_authoring_notes", "KHR_texture_transform"]);
+    assert!(report.bytes_saved() > 0);
+    assert_eq!(report.bytes_after, root.to_vec().unwrap().len());
+
+    let json = root.to_string().unwrap();
+    #[cfg(feature = "extras")]
+    assert_eq!(json.matches("\"extras\"").count(), 0, "{}", json);
+    #[cfg(feature = "names")]
+    assert_eq!(json.matches("\"name\"").count(), 0, "{}", json);
+    assert!(!json.contains("EXT_authoring_notes"));
+    assert!(gltf::Document::from_json(root).is_ok());
+}
+
+#[test]
+fn test_default_options() {
+    let mut root = Root::from_str(DOCUMENT).unwrap();
+    let value = root.to_value().unwrap();
+    let report = root.strip(&StripOptions::default()).unwrap();
+    assert_eq!(report.removed_extensions, Vec::<String>::new());
+    assert_eq!(root.to_value().unwrap(), value);
+}
+
+#[test]
+fn test_names_only() {
+    let mut root = Root::from_str(DOCUMENT).unwrap();
+    root.strip(&StripOptions { names: true, ..Default::default() }).unwrap();
+    // The contents of unknown extensions are not glTF objects.
+    assert_eq!(root.extensions.as_ref().unwrap().names(), vec!["EXT_authoring_notes"]);
+    assert!(root.to_string().unwrap().contains(r#"{"name":"kept as it is"}"#));
+    #[cfg(feature = "extras")]
+    assert!(root.samplers[0].extras.is_some());
+}
+
+#[cfg(feature = "KHR_texture_transform")]
+#[test]
+fn test_specific_extension() {
+    let mut root = Root::from_str(DOCUMENT).unwrap();
+    let options = StripOptions { specific_extensions: vec!["KHR_texture_transform".to_string()], ..Default::default() };
+    let report = root.strip(&options).unwrap();
+    assert_eq!(report.removed_extensions, vec!["KHR_texture_transform"]);
+    assert_eq!(root.extensions_used, vec!["EXT_authoring_notes"]);
+    let info = root.materials[0].pbr_metallic_roughness.base_color_texture.as_ref().unwrap();
+    assert!(info.extensions.is_none());
+}
+
+#[cfg(feature = "EXT_texture_webp")]
+#[test]
+fn test_stripping_keeps_document_valid() {
+    let json = r#"{
+        "asset": {"version": "2.0"},
+        "extensionsUsed": ["EXT_texture_webp"],
+        "images": [{"uri": "base.webp"}],
+        "textures": [{"extensions": {"EXT_texture_webp": {"source": 0}}}]
+    }"#;
+    let mut root = Root::from_str(json).unwrap();
+    let before = root.to_string().unwrap();
+    let options = StripOptions { specific_extensions: vec!["EXT_texture_webp".to_string()], ..Default::default() };
+    assert_eq!(
+        root.strip(&options),
+        Err(gltf::json::edit::Error::Invalid(gltf::json::Path::new().field("textures").index(0).field("source"))),
+    );
+    assert_eq!(root.to_string().unwrap(), before);
+}
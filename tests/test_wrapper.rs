@@ -13,8 +13,594 @@ fn test_accessor_bounds() {
     let gltf = gltf::Gltf::from_slice(&buffer).unwrap();
     let mesh = &gltf.meshes().nth(0).unwrap();
     let prim = mesh.primitives().nth(0).unwrap();
-    let bounds = prim.bounding_box();
+    let bounds = prim.bounding_box().unwrap();
     assert_eq!(bounds, Bounds { min: [-0.03, -0.04, -0.05], max: [1.0, 1.01, 0.02]});
 }
 
 
+
+const ANIMATED_NODES: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 4}],
+    "bufferViews": [{"buffer": 0, "byteLength": 4}],
+    "accessors": [{"bufferView": 0, "componentType": 5126, "count": 1, "type": "SCALAR"}],
+    "nodes": [{}, {}, {}],
+    "animations": [{
+        "samplers": [{"input": 0, "output": 0}],
+        "channels": [
+            {"sampler": 0, "target": {"node": 2, "path": "translation"}},
+            {"sampler": 0, "target": {"node": 0, "path": "rotation"}},
+            {"sampler": 0, "target": {"node": 2, "path": "scale"}}
+        ]
+    }]
+}"#;
+
+#[test]
+fn test_animation_channels_for_node() {
+    use gltf::animation::Property;
+
+    let gltf = gltf::Gltf::from_slice(ANIMATED_NODES.as_bytes()).unwrap();
+    let animation = gltf.animations().next().unwrap();
+    let targets = animation.target_nodes().map(|node| node.index()).collect::<Vec<_>>();
+    assert_eq!(targets, vec![0, 2]);
+
+    let nodes = gltf.nodes().collect::<Vec<_>>();
+    let properties = |node| {
+        animation
+            .channels_for_node(node)
+            .map(|channel| channel.target().property())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(properties(&nodes[0]), vec![Some(Property::Rotation)]);
+    assert_eq!(properties(&nodes[1]), vec![]);
+    assert_eq!(properties(&nodes[2]), vec![Some(Property::Translation), Some(Property::Scale)]);
+}
+
+#[test]
+fn test_node_animations() {
+    let gltf = gltf::Gltf::from_slice(ANIMATED_NODES.as_bytes()).unwrap();
+    let nodes = gltf.nodes().collect::<Vec<_>>();
+    let channels = nodes[2]
+        .animations()
+        .map(|(animation, channel)| (animation.index(), channel.target().property()))
+        .collect::<Vec<_>>();
+    use gltf::animation::Property;
+    assert_eq!(channels, vec![(0, Some(Property::Translation)), (0, Some(Property::Scale))]);
+    assert!(nodes[0].is_animated());
+    assert!(!nodes[1].is_animated());
+
+    // Editing the JSON requires a new document, which rebuilds the index.
+    let mut json = gltf.document.clone().into_json();
+    json.animations[0].channels.remove(1);
+    let document = gltf::Document::from_json(json).unwrap();
+    assert!(!document.nodes().next().unwrap().is_animated());
+    assert_eq!(document.nodes().nth(2).unwrap().animations().count(), 2);
+    assert!(nodes[0].is_animated());
+}
+
+#[cfg(feature = "import")]
+#[test]
+fn test_custom_attribute() {
+    let (document, buffers, _) = gltf::import("tests/batch_id.gltf").unwrap();
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    let semantic = gltf::Semantic::Extras("BATCHID".to_string());
+    assert!(primitive.attributes().any(|(x, _)| x == semantic));
+
+    let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+    let batch_ids = reader.read_custom::<u16>("_BATCHID").unwrap().unwrap();
+    assert_eq!(batch_ids.collect::<Vec<_>>(), vec![7, 7, 42]);
+
+    let error = reader.read_custom::<[f32; 2]>("_BATCHID").unwrap().unwrap_err();
+    assert_eq!(error.accessor, (gltf::accessor::DataType::U16, gltf::accessor::Dimensions::Scalar));
+    assert!(reader.read_custom::<u16>("_LIGHTMAP_UV").is_none());
+    assert!(reader.read_custom::<u16>("BATCHID").is_none());
+}
+
+#[test]
+fn test_material_detach() {
+    use gltf::material::{AlphaMode, TextureData};
+
+    let json = r#"{
+        "asset": {"version": "2.0"},
+        "images": [{"uri": "a.png"}, {"uri": "b.png"}],
+        "textures": [{"source": 1}, {"source": 0}],
+        "materials": [{
+            "alphaMode": "MASK",
+            "pbrMetallicRoughness": {"baseColorTexture": {"index": 1, "texCoord": 1}},
+            "normalTexture": {"index": 0, "scale": 0.5}
+        }]
+    }"#;
+    let data = {
+        let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+        let material = gltf.materials().next().unwrap();
+        material.detach()
+    };
+    assert_eq!(data.index, Some(0));
+    assert_eq!(data.alpha_mode, AlphaMode::Mask);
+    assert_eq!(data.base_color_texture, Some(TextureData { texture: 1, image: 0, tex_coord: 1 }));
+    assert_eq!(data.normal_texture, Some(TextureData { texture: 0, image: 1, tex_coord: 0 }));
+    assert_eq!(data.normal_scale, 0.5);
+    assert_eq!(data.occlusion_texture, None);
+    assert_eq!(data.occlusion_strength, 1.0);
+}
+
+#[test]
+fn test_material_features() {
+    use gltf::material::MaterialFeatures as F;
+
+    let json = r#"{
+        "asset": {"version": "2.0"},
+        "images": [{"uri": "a.png"}],
+        "textures": [{"source": 0}],
+        "materials": [
+            {},
+            {
+                "pbrMetallicRoughness": {
+                    "baseColorTexture": {"index": 0},
+                    "metallicRoughnessTexture": {"index": 0}
+                },
+                "normalTexture": {"index": 0},
+                "occlusionTexture": {"index": 0, "texCoord": 1}
+            },
+            {"alphaMode": "MASK", "doubleSided": true, "emissiveFactor": [1, 0, 0]},
+            {"alphaMode": "BLEND", "emissiveTexture": {"index": 0}}
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    let features = gltf.materials().map(|x| x.features()).collect::<Vec<_>>();
+    assert_eq!(features, vec![
+        F::empty(),
+        F::BASE_COLOR_TEXTURE | F::METALLIC_ROUGHNESS_TEXTURE | F::NORMAL_MAP
+            | F::OCCLUSION | F::TEXCOORD_1_USED,
+        F::ALPHA_MASK | F::DOUBLE_SIDED | F::EMISSIVE,
+        F::ALPHA_BLEND | F::EMISSIVE,
+    ]);
+    assert_eq!(format!("{:?}", features[2]), "EMISSIVE | ALPHA_MASK | DOUBLE_SIDED");
+    assert_eq!(format!("{:?}", features[0]), "(empty)");
+
+    let mut cache = std::collections::HashSet::new();
+    cache.extend(features.iter().cloned());
+    assert_eq!(cache.len(), 4);
+}
+
+#[cfg(feature = "KHR_materials_unlit")]
+#[test]
+fn test_material_features_unlit() {
+    use gltf::material::MaterialFeatures as F;
+
+    let json = r#"{
+        "asset": {"version": "2.0"},
+        "extensionsUsed": ["KHR_materials_unlit"],
+        "materials": [{"extensions": {"KHR_materials_unlit": {}}}]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    assert_eq!(gltf.materials().next().unwrap().features(), F::UNLIT);
+}
+
+#[test]
+fn test_sort_key_batches() {
+    let json = r#"{
+        "asset": {"version": "2.0"},
+        "buffers": [{"byteLength": 36}],
+        "bufferViews": [{"buffer": 0, "byteLength": 36}],
+        "accessors": [
+            {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 1]}
+        ],
+        "images": [{"uri": "a.png"}, {"uri": "b.png"}],
+        "textures": [{"source": 0}, {"source": 1}],
+        "materials": [
+            {"alphaMode": "BLEND"},
+            {"pbrMetallicRoughness": {"baseColorTexture": {"index": 1}}},
+            {"alphaMode": "MASK", "doubleSided": true},
+            {"pbrMetallicRoughness": {"baseColorTexture": {"index": 0}}},
+            {"alphaMode": "BLEND", "doubleSided": true, "emissiveFactor": [1, 1, 1]},
+            {"pbrMetallicRoughness": {"baseColorTexture": {"index": 1}}}
+        ],
+        "meshes": [{
+            "primitives": [
+                {"attributes": {"POSITION": 0}, "material": 0},
+                {"attributes": {"POSITION": 0}, "material": 1},
+                {"attributes": {"POSITION": 0}, "material": 2},
+                {"attributes": {"POSITION": 0}, "material": 3},
+                {"attributes": {"POSITION": 0}, "material": 4},
+                {"attributes": {"POSITION": 0}, "material": 5},
+                {"attributes": {"POSITION": 0}, "material": 1, "mode": 0},
+                {"attributes": {"POSITION": 0}}
+            ]
+        }]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    let materials = gltf.materials().collect::<Vec<_>>();
+    assert_eq!(materials[1].sort_key(), materials[5].sort_key());
+    assert_ne!(materials[1].sort_key(), materials[3].sort_key());
+
+    let mut primitives = gltf.primitives().collect::<Vec<_>>();
+    primitives.sort_by_key(|x| x.sort_key());
+    let alpha_modes = primitives.iter().map(|x| x.material().alpha_mode()).collect::<Vec<_>>();
+    let mut sorted = alpha_modes.clone();
+    sorted.sort_by_key(|&x| x as u32);
+    assert_eq!(alpha_modes, sorted);
+    assert_eq!(alpha_modes.first(), Some(&gltf::material::AlphaMode::Opaque));
+    assert_eq!(alpha_modes.last(), Some(&gltf::material::AlphaMode::Blend));
+
+    let mut batches = std::collections::HashMap::new();
+    for primitive in &primitives {
+        batches.entry(primitive.sort_key()).or_insert_with(Vec::new).push(primitive.clone());
+    }
+    // Materials 1 and 5 are batched together; the point primitive is not.
+    assert_eq!(batches.len(), primitives.len() - 1);
+    for batch in batches.values() {
+        let first = batch[0].material();
+        for primitive in batch {
+            assert_eq!(primitive.material().alpha_mode(), first.alpha_mode());
+            assert_eq!(primitive.material().double_sided(), first.double_sided());
+            assert_eq!(primitive.mode(), batch[0].mode());
+        }
+    }
+}
+
+#[cfg(feature = "utils")]
+#[test]
+fn test_scene_bounding_box() {
+    let json = r#"{
+        "asset": {"version": "2.0"},
+        "buffers": [{"byteLength": 36}],
+        "bufferViews": [{"buffer": 0, "byteLength": 36}],
+        "accessors": [
+            {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 1]}
+        ],
+        "meshes": [{"primitives": [{"attributes": {"POSITION": 0}}]}],
+        "skins": [{"joints": [2]}],
+        "nodes": [
+            {"translation": [10, 0, 0], "children": [1]},
+            {"mesh": 0, "rotation": [0, 0, 0.38268343, 0.9238795]},
+            {"translation": [-5, 0, 0]},
+            {"mesh": 0, "skin": 0, "translation": [100, 0, 0]}
+        ],
+        "scenes": [{"nodes": [0]}, {"nodes": [0, 2, 3]}, {"nodes": [2]}]
+    }"#;
+    let mut data = Vec::new();
+    for x in &[1.0f32, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0] {
+        data.extend_from_slice(&x.to_le_bytes());
+    }
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    let scenes = gltf.scenes().collect::<Vec<_>>();
+    let close = |a: [f32; 3], b: [f32; 3]| a.iter().zip(&b).all(|(a, b)| (a - b).abs() < 1.0e-5);
+    let s = std::f32::consts::FRAC_1_SQRT_2;
+
+    // All eight corners of the accessor bounds are rotated.
+    let bounds = scenes[0].bounding_box().unwrap();
+    assert!(!bounds.approximate);
+    assert!(close(bounds.bounding_box.min, [10.0 - s, 0.0, 0.0]));
+    assert!(close(bounds.bounding_box.max, [10.0 + s, 2.0 * s, 1.0]));
+
+    // Rotating the vertices themselves gives a tighter fit.
+    let bounds = scenes[0].bounding_box_with(|_| Some(&data[..])).unwrap();
+    assert!(!bounds.approximate);
+    assert!(close(bounds.bounding_box.min, [10.0 - s, 0.0, 0.0]));
+    assert!(close(bounds.bounding_box.max, [10.0 + s, s, 1.0]));
+
+    // Node bounds are in the coordinate space of the parent.
+    let node = gltf.nodes().nth(1).unwrap();
+    let bounds = node.bounding_box_with(|_| Some(&data[..])).unwrap();
+    assert!(close(bounds.bounding_box.min, [-s, 0.0, 0.0]));
+    assert!(close(bounds.bounding_box.max, [s, s, 1.0]));
+
+    // The skinned mesh contributes its untransformed bind-pose bounds.
+    let bounds = scenes[1].bounding_box_with(|_| Some(&data[..])).unwrap();
+    assert!(bounds.approximate);
+    assert!(close(bounds.bounding_box.min, [0.0, 0.0, 0.0]));
+    assert!(close(bounds.bounding_box.max, [10.0 + s, 1.0, 1.0]));
+
+    assert_eq!(scenes[2].bounding_box(), None);
+}
+
+const INSTANCED_MESHES: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 36}],
+    "bufferViews": [{"buffer": 0, "byteLength": 36}],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 1]}
+    ],
+    "meshes": [
+        {"primitives": [{"attributes": {"POSITION": 0}}, {"attributes": {"POSITION": 0}, "mode": 0}]},
+        {"primitives": []},
+        {"primitives": [{"attributes": {"POSITION": 0}}]}
+    ],
+    "nodes": [
+        {"translation": [1, 0, 0], "children": [1, 3]},
+        {"mesh": 0, "scale": [2, 2, 2]},
+        {"mesh": 2},
+        {"mesh": 2, "translation": [0, 5, 0]}
+    ],
+    "scenes": [{"nodes": [0]}, {"nodes": [2, 1]}]
+}"#;
+
+#[test]
+fn test_document_primitives() {
+    let gltf = gltf::Gltf::from_slice(INSTANCED_MESHES.as_bytes()).unwrap();
+    let mut primitives = gltf.primitives();
+    assert_eq!(primitives.len(), 3);
+    primitives.next();
+    assert_eq!(primitives.len(), 2);
+    let indices = gltf
+        .primitives()
+        .map(|x| (x.mesh().index(), x.index()))
+        .collect::<Vec<_>>();
+    assert_eq!(indices, vec![(0, 0), (0, 1), (2, 0)]);
+}
+
+#[test]
+fn test_scene_primitives() {
+    let gltf = gltf::Gltf::from_slice(INSTANCED_MESHES.as_bytes()).unwrap();
+    let scenes = gltf.scenes().collect::<Vec<_>>();
+
+    let visited = scenes[0]
+        .primitives()
+        .map(|(x, transform)| (x.mesh().index(), x.index(), transform))
+        .collect::<Vec<_>>();
+    let scaled = [[2.0, 0.0, 0.0, 0.0], [0.0, 2.0, 0.0, 0.0], [0.0, 0.0, 2.0, 0.0], [1.0, 0.0, 0.0, 1.0]];
+    let translated = [[1.0, 0.0, 0.0, 0.0], [0.0, 1.0, 0.0, 0.0], [0.0, 0.0, 1.0, 0.0], [1.0, 5.0, 0.0, 1.0]];
+    assert_eq!(visited, vec![(0, 0, scaled), (0, 1, scaled), (2, 0, translated)]);
+
+    let meshes = scenes[1]
+        .primitives()
+        .map(|(x, _)| x.mesh().index())
+        .collect::<Vec<_>>();
+    assert_eq!(meshes, vec![2, 0, 0]);
+}
+
+#[test]
+fn test_external_references() {
+    use gltf::uri::Scheme;
+
+    let glb = gltf::Gltf::open("examples/Box.glb").unwrap();
+    assert!(glb.is_self_contained());
+    assert_eq!(glb.external_references().count(), 0);
+
+    let json = r#"{
+        "asset": {"version": "2.0"},
+        "buffers": [{"byteLength": 4, "uri": "data:application/octet-stream;base64,AAAAAA=="}],
+        "bufferViews": [{"buffer": 0, "byteLength": 4}],
+        "images": [
+            {"bufferView": 0, "mimeType": "image/png"},
+            {"uri": "https://example.com/a.png"},
+            {"uri": "textures/b.png"}
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    assert!(!gltf.is_self_contained());
+    let references = gltf
+        .external_references()
+        .map(|x| (x.path.to_string(), x.uri, x.scheme()))
+        .collect::<Vec<_>>();
+    assert_eq!(references, vec![
+        ("images[1].uri".to_string(), "https://example.com/a.png", Scheme::Unsupported),
+        ("images[2].uri".to_string(), "textures/b.png", Scheme::Relative),
+    ]);
+
+    // A buffer without a URI refers to a `BIN` chunk which is absent.
+    let json = r#"{"asset": {"version": "2.0"}, "buffers": [{"byteLength": 4}]}"#;
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    assert_eq!(gltf.external_references().count(), 0);
+    assert!(!gltf.is_self_contained());
+}
+
+#[test]
+fn test_textures_requiring_mipmaps() {
+    use gltf::texture::{MagFilter, MinFilter};
+
+    let json = r#"{
+        "asset": {"version": "2.0"},
+        "images": [{"uri": "a.png"}, {"uri": "b.png"}, {"uri": "c.png"}],
+        "samplers": [
+            {"minFilter": 9729, "wrapS": 33071, "wrapT": 33071},
+            {"minFilter": 9984},
+            {"magFilter": 9728, "wrapT": 33071}
+        ],
+        "textures": [
+            {"source": 0, "sampler": 0},
+            {"source": 1, "sampler": 1},
+            {"source": 2},
+            {"source": 1, "sampler": 2},
+            {"source": 0, "sampler": 0}
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    let samplers = gltf.samplers().collect::<Vec<_>>();
+    assert!(!samplers[0].requires_mipmaps());
+    assert!(samplers[1].requires_mipmaps());
+    assert_eq!(samplers[2].min_filter_or_default(), MinFilter::LinearMipmapLinear);
+    assert_eq!(samplers[2].mag_filter_or_default(), MagFilter::Nearest);
+    assert!(samplers[2].requires_mipmaps());
+
+    let requirements = gltf
+        .textures_requiring_mipmaps()
+        .map(|x| (x.image.index(), x.textures.iter().map(|x| x.index()).collect::<Vec<_>>()))
+        .collect::<Vec<_>>();
+    assert_eq!(requirements, vec![(1, vec![1, 3]), (2, vec![2])]);
+
+    let textures = gltf.textures().collect::<Vec<_>>();
+    assert!(!textures[0].wrap_requires_pot(None));
+    assert!(!textures[0].wrap_requires_pot(Some((100, 3))));
+    assert!(textures[1].wrap_requires_pot(None));
+    assert!(textures[1].wrap_requires_pot(Some((256, 100))));
+    assert!(!textures[1].wrap_requires_pot(Some((256, 128))));
+    // Repeat on `s` alone is enough to require power-of-two dimensions.
+    assert!(textures[3].wrap_requires_pot(Some((100, 128))));
+}
+
+const SHARED_IMAGES: &str = r#"{
+    "asset": {"version": "2.0"},
+    "images": [{"uri": "a.png"}, {"uri": "b.png"}, {"uri": "c.png"}],
+    "samplers": [
+        {"wrapS": 33071, "wrapT": 33071},
+        {"magFilter": 9728},
+        {"wrapS": 33648, "wrapT": 33071}
+    ],
+    "textures": [
+        {"source": 0, "sampler": 0},
+        {"source": 0},
+        {"source": 1, "sampler": 1},
+        {"source": 1},
+        {"source": 2, "sampler": 2},
+        {"source": 0, "sampler": 0}
+    ],
+    "materials": [
+        {"pbrMetallicRoughness": {"baseColorTexture": {"index": 0}}, "normalTexture": {"index": 1}},
+        {"emissiveTexture": {"index": 2}, "occlusionTexture": {"index": 5}},
+        {"pbrMetallicRoughness": {"metallicRoughnessTexture": {"index": 3}}}
+    ]
+}"#;
+
+#[test]
+fn test_image_sharing_report() {
+    use gltf::texture::{MagFilter, MaterialSlot, WrappingMode};
+
+    let gltf = gltf::Gltf::from_slice(SHARED_IMAGES.as_bytes()).unwrap();
+    let report = gltf.image_sharing_report();
+    assert_eq!(report.images.len(), 3);
+
+    // Image 0 is clamped by textures 0 and 5 but repeated by texture 1.
+    let image = &report.images[0];
+    assert_eq!(image.textures, vec![0, 1, 5]);
+    assert_eq!(image.samplers.len(), 2);
+    assert_eq!(image.samplers[0].wrap_s, WrappingMode::ClampToEdge);
+    assert_eq!(image.samplers[1].wrap_s, WrappingMode::Repeat);
+    let slots = image.materials.iter().map(|x| (x.material, x.slot, x.texture)).collect::<Vec<_>>();
+    assert_eq!(slots, vec![
+        (0, MaterialSlot::BaseColor, 0),
+        (0, MaterialSlot::Normal, 1),
+        (1, MaterialSlot::Occlusion, 5),
+    ]);
+    assert!(image.atlas_unsafe);
+
+    // Image 1 uses two filter configurations that both repeat.
+    let image = &report.images[1];
+    assert_eq!(image.samplers.len(), 2);
+    assert_eq!(image.samplers[0].mag_filter, Some(MagFilter::Nearest));
+    assert!(!image.atlas_unsafe);
+
+    // Mirroring one axis and clamping the other conflicts as well.
+    let image = &report.images[2];
+    assert_eq!(image.textures, vec![4]);
+    assert!(image.materials.is_empty());
+    assert!(image.atlas_unsafe);
+
+    let unsafe_images = report.atlas_unsafe().map(|x| x.image).collect::<Vec<_>>();
+    assert_eq!(unsafe_images, vec![0, 2]);
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn test_image_sharing_report_serialize() {
+    let gltf = gltf::Gltf::from_slice(SHARED_IMAGES.as_bytes()).unwrap();
+    let value = gltf::json::serialize::to_value(gltf.image_sharing_report()).unwrap();
+    let image = &value["images"][0];
+    assert_eq!(image["atlas_unsafe"], true);
+    assert_eq!(image["samplers"][0]["wrap_s"], "ClampToEdge");
+    assert_eq!(image["samplers"][1]["mag_filter"], gltf::json::Value::Null);
+    assert_eq!(image["materials"][1]["slot"], "Normal");
+}
+
+const MORPHED_TRIANGLE: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 44}],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 36, "target": 34962},
+        {"buffer": 0, "byteOffset": 36, "byteLength": 6, "target": 34962}
+    ],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 1]},
+        {"bufferView": 1, "componentType": 5123, "count": 3, "type": "SCALAR"},
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3"}
+    ],
+    "meshes": [{
+        "primitives": [{"attributes": {"POSITION": 0}, "indices": 1, "targets": [{"POSITION": 2}]}]
+    }]
+}"#;
+
+#[test]
+fn test_accessor_usages() {
+    use gltf::accessor::Usage;
+    use gltf::Semantic;
+
+    let gltf = gltf::Gltf::from_slice(MORPHED_TRIANGLE.as_bytes()).unwrap();
+    let usages = gltf
+        .accessor_usages()
+        .map(|(accessor, usage)| {
+            let conflicting = usage.is_conflicting(&accessor);
+            let description = match usage {
+                Usage::Attribute { semantic, .. } => format!("attribute {:?}", semantic),
+                Usage::Indices { .. } => "indices".to_string(),
+                Usage::MorphTarget { target, semantic, .. } => {
+                    format!("target {} {:?}", target, semantic)
+                },
+                _ => panic!("unexpected usage"),
+            };
+            (accessor.index(), description, conflicting)
+        })
+        .collect::<Vec<_>>();
+    let expected = vec![
+        (0, format!("attribute {:?}", Semantic::Positions), false),
+        (1, "indices".to_string(), true),
+        (2, format!("target 0 {:?}", Semantic::Positions), false),
+    ];
+    assert_eq!(usages, expected);
+}
+
+#[test]
+fn test_effective_stride() {
+    let json = r#"{
+        "asset": {"version": "2.0"},
+        "buffers": [{"byteLength": 64}],
+        "bufferViews": [
+            {"buffer": 0, "byteLength": 32, "byteStride": 16},
+            {"buffer": 0, "byteOffset": 32, "byteLength": 32}
+        ],
+        "accessors": [
+            {"bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC3"},
+            {"bufferView": 1, "componentType": 5126, "count": 2, "type": "VEC3"},
+            {"bufferView": 1, "componentType": 5120, "count": 1, "type": "MAT3"}
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    let accessors = gltf.accessors().collect::<Vec<_>>();
+    assert_eq!(accessors[0].view().unwrap().stride(), Some(16));
+    assert_eq!(accessors[0].effective_stride(), Some(16));
+    assert_eq!(accessors[1].view().unwrap().stride(), None);
+    assert_eq!(accessors[1].effective_stride(), Some(12));
+    // Matrix columns of one byte components are padded to four bytes.
+    assert_eq!(accessors[2].effective_stride(), Some(12));
+}
+
+// The lazily built indices of a document do not affect the hash of a handle.
+#[allow(clippy::mutable_key_type)]
+#[test]
+fn test_shared_gltf() {
+    use std::collections::HashSet;
+
+    fn mesh_count<D: AsRef<gltf::Document>>(document: &D) -> usize {
+        document.as_ref().meshes().count()
+    }
+    fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+    let gltf = gltf::Gltf::open("examples/Box.gltf").unwrap();
+    assert_eq!(mesh_count(&gltf), 1);
+    let shared = gltf.into_shared();
+    let clone = shared.clone();
+    assert_send_sync(&shared);
+    assert_eq!(mesh_count(&shared), 1);
+    assert_eq!(mesh_count(&clone.document), 1);
+
+    // Handles compare by identity, not by content.
+    let other = gltf::Gltf::open("examples/Box.gltf").unwrap().into_shared();
+    assert_eq!(shared, clone);
+    assert_ne!(shared, other);
+    let set = vec![shared.clone(), clone.clone(), other].into_iter().collect::<HashSet<_>>();
+    assert_eq!(set.len(), 2);
+
+    let shared = shared.try_unwrap().unwrap_err();
+    drop((clone, set));
+    assert!(shared.try_unwrap().is_ok());
+}
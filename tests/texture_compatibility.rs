@@ -0,0 +1,91 @@
+use gltf::texture::{MaterialSlot, MaterialUse, Profile};
+
+const JSON: &str = r#"{
+    "asset": {"version": "2.0"},
+    "images": [{"uri": "large.png"}, {"uri": "npot.png"}, {"uri": "pot.png"}, {"uri": "missing.png"}],
+    "samplers": [{"magFilter": 9729, "minFilter": 9729, "wrapS": 33071, "wrapT": 33071}],
+    "textures": [
+        {"source": 0},
+        {"source": 1},
+        {"source": 1, "sampler": 0},
+        {"source": 2},
+        {"source": 3}
+    ],
+    "materials": [
+        {"pbrMetallicRoughness": {"baseColorTexture": {"index": 0}}, "normalTexture": {"index": 1}},
+        {"emissiveTexture": {"index": 0}, "occlusionTexture": {"index": 2}}
+    ]
+}"#;
+
+/// Returns the signature and header of a PNG image, which is all that is
+/// read to determine its dimensions.
+fn png(width: u32, height: u32) -> Vec<u8> {
+    let mut data = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR".to_vec();
+    data.extend_from_slice(&width.to_be_bytes());
+    data.extend_from_slice(&height.to_be_bytes());
+    data.extend_from_slice(&[8, 6, 0, 0, 0]);
+    data
+}
+
+fn images() -> Vec<Vec<u8>> {
+    vec![png(4097, 4097), png(300, 200), png(256, 256)]
+}
+
+#[test]
+fn test_gles2() {
+    let gltf = gltf::Gltf::from_slice(JSON.as_bytes()).unwrap();
+    let images = images();
+    let report = gltf.texture_compatibility(Profile::Gles2, |image| images.get(image.index()).map(Vec::as_slice));
+    assert!(!report.is_compatible());
+    assert_eq!(report.unknown, vec![3]);
+
+    let summary = report
+        .incompatible
+        .iter()
+        .map(|x| (x.texture, x.image, x.dimensions, x.oversized, x.non_power_of_two))
+        .collect::<Vec<_>>();
+    // The second use of the non-power-of-two image clamps without mipmaps.
+    assert_eq!(summary, vec![(0, 0, (4097, 4097), true, true), (1, 1, (300, 200), false, true)]);
+    assert_eq!(
+        report.incompatible[0].materials,
+        vec![
+            MaterialUse { material: 0, slot: MaterialSlot::BaseColor, texture: 0 },
+            MaterialUse { material: 1, slot: MaterialSlot::Emissive, texture: 0 },
+        ],
+    );
+    assert_eq!(
+        report.incompatible[1].materials,
+        vec![MaterialUse { material: 0, slot: MaterialSlot::Normal, texture: 1 }],
+    );
+}
+
+#[test]
+fn test_gles3_and_desktop() {
+    let gltf = gltf::Gltf::from_slice(JSON.as_bytes()).unwrap();
+    let images = images();
+    let get = |image: gltf::Image| images.get(image.index()).map(Vec::as_slice);
+
+    let report = gltf.texture_compatibility(Profile::Gles3, get);
+    let summary = report.incompatible.iter().map(|x| (x.texture, x.oversized, x.non_power_of_two)).collect::<Vec<_>>();
+    assert_eq!(summary, vec![(0, true, false)]);
+
+    assert!(gltf.texture_compatibility(Profile::Desktop, get).is_compatible());
+}
+
+#[test]
+fn test_custom() {
+    let gltf = gltf::Gltf::from_slice(JSON.as_bytes()).unwrap();
+    let images = images();
+    let get = |image: gltf::Image| images.get(image.index()).map(Vec::as_slice);
+
+    let profile = Profile::Custom { max_dimension: 256, require_power_of_two: false };
+    let report = gltf.texture_compatibility(profile, get);
+    let textures = report.incompatible.iter().map(|x| x.texture).collect::<Vec<_>>();
+    assert_eq!(textures, vec![0, 1, 2]);
+
+    let profile = Profile::Custom { max_dimension: 8192, require_power_of_two: true };
+    let report = gltf.texture_compatibility(profile, get);
+    let textures = report.incompatible.iter().map(|x| x.texture).collect::<Vec<_>>();
+    assert_eq!(textures, vec![0, 1]);
+    assert!(report.incompatible.iter().all(|x| !x.oversized));
+}
@@ -0,0 +1,87 @@
+#![cfg(feature = "MSFT_texture_dds")]
+
+use std::{env, fs};
+
+use gltf::texture::SourceKind;
+
+/// A 1x1 PNG image.
+const PNG: &str = "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAIAAACQd1PeAAAADElEQVR4nGP4z8AAAAMBAQDJ/pLvAAAAAElFTkSuQmCC";
+
+const PNG_AND_DDS: &str = r#"{
+    "asset": {"version": "2.0"},
+    "extensionsUsed": ["MSFT_texture_dds"],
+    "images": [
+        {"uri": "fallback.png"},
+        {"uri": "preferred.dds", "mimeType": "image/vnd-ms.dds"}
+    ],
+    "textures": [{
+        "source": 0,
+        "extensions": {"MSFT_texture_dds": {"source": 1}}
+    }]
+}"#;
+
+/// A DDS file with a 128-byte header for an image of the given size, followed
+/// by placeholder block data.
+fn dds(width: u32, height: u32) -> Vec<u8> {
+    let mut data = b"DDS ".to_vec();
+    data.extend(124u32.to_le_bytes());
+    data.extend(0x1007u32.to_le_bytes());
+    data.extend(height.to_le_bytes());
+    data.extend(width.to_le_bytes());
+    data.resize(128, 0);
+    data.extend([0xAB; 16]);
+    data
+}
+
+#[test]
+fn test_dds_source_is_preferred() {
+    let gltf = gltf::Gltf::from_slice(PNG_AND_DDS.as_bytes()).unwrap();
+    let texture = gltf.textures().next().unwrap();
+    assert_eq!(texture.source().unwrap().index(), 1);
+    assert_eq!(texture.dds_source().map(|x| x.index()), Some(1));
+    assert_eq!(texture.core_source().map(|x| x.index()), Some(0));
+}
+
+#[test]
+fn test_source_preference() {
+    let gltf = gltf::Gltf::from_slice(PNG_AND_DDS.as_bytes()).unwrap();
+    let texture = gltf.textures().next().unwrap();
+    let dds_first = texture.source_with(&[SourceKind::Dds, SourceKind::Core]);
+    assert_eq!(dds_first.map(|x| x.index()), Some(1));
+    let core_first = texture.source_with(&[SourceKind::Core, SourceKind::Dds]);
+    assert_eq!(core_first.map(|x| x.index()), Some(0));
+
+    // Sources the texture does not have are skipped.
+    let json = PNG_AND_DDS.replace(r#""source": 0,"#, "");
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    let texture = gltf.textures().next().unwrap();
+    assert_eq!(texture.source().unwrap().index(), 1);
+    let core_first = texture.source_with(&[SourceKind::Core, SourceKind::Dds]);
+    assert_eq!(core_first.map(|x| x.index()), Some(1));
+    assert!(texture.source_with(&[SourceKind::Core]).is_none());
+}
+
+#[test]
+fn test_probe_dds() {
+    assert_eq!(gltf::image::probe_dimensions(&dds(256, 64)), Some((256, 64)));
+    assert_eq!(gltf::image::probe_dimensions(&dds(256, 64)[..16]), None);
+    assert_eq!(gltf::image::probe_dimensions(&dds(0, 64)), None);
+}
+
+#[cfg(feature = "import")]
+#[test]
+fn test_dds_passthrough() {
+    let dir = env::temp_dir().join(format!("gltf_test_dds_passthrough_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let data = dds(8, 4);
+    fs::write(dir.join("preferred.dds"), &data).unwrap();
+    let json = PNG_AND_DDS.replace("fallback.png", &format!("data:image/png;base64,{}", PNG));
+    fs::write(dir.join("texture.gltf"), json).unwrap();
+    let (_, _, images) = gltf::import(dir.join("texture.gltf")).unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(images[0].format, gltf::image::Format::R8G8B8);
+    assert_eq!((images[1].width, images[1].height), (8, 4));
+    assert_eq!(images[1].format, gltf::image::Format::Dds);
+    assert_eq!(images[1].pixels, data);
+}
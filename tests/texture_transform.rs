@@ -0,0 +1,151 @@
+use gltf::texture::UvTransform;
+
+const DOCUMENT: &str = r#"{
+    "asset": {"version": "2.0"},
+    "extensionsUsed": ["KHR_texture_transform"],
+    "images": [{"uri": "normal.png"}, {"uri": "occlusion.png"}, {"uri": "emissive.png"}],
+    "textures": [{"source": 0}, {"source": 1}, {"source": 2}],
+    "materials": [
+        {
+            "normalTexture": {
+                "index": 0,
+                "scale": 0.5,
+                "extensions": {"KHR_texture_transform": {"offset": [0.5, 0], "scale": [2, 2], "texCoord": 1}}
+            },
+            "occlusionTexture": {"index": 1, "strength": 0.25, "texCoord": 1},
+            "emissiveTexture": {
+                "index": 2,
+                "extensions": {"KHR_texture_transform": {"rotation": 1.5707964}}
+            },
+            "emissiveFactor": [1, 0.5, 0]
+        },
+        {}
+    ]
+}"#;
+
+fn assert_near(a: [f32; 2], b: [f32; 2]) {
+    assert!((a[0] - b[0]).abs() < 1e-6 && (a[1] - b[1]).abs() < 1e-6, "{:?} != {:?}", a, b);
+}
+
+#[test]
+fn test_params() {
+    let gltf = gltf::Gltf::from_slice(DOCUMENT.as_bytes()).unwrap();
+    let materials = gltf.materials().collect::<Vec<_>>();
+
+    let normal = materials[0].normal_mapping().unwrap();
+    assert_eq!((normal.texture, normal.image, normal.scale), (0, 0, 0.5));
+    let occlusion = materials[0].occlusion_params().unwrap();
+    assert_eq!(occlusion, gltf::material::OcclusionParams {
+        texture: 1,
+        image: 1,
+        tex_coord: 1,
+        transform: UvTransform::IDENTITY,
+        strength: 0.25,
+    });
+    let emissive = materials[0].emissive_params().unwrap();
+    assert_eq!((emissive.texture, emissive.image, emissive.tex_coord), (2, 2, 0));
+    assert_eq!(emissive.factor, [1.0, 0.5, 0.0]);
+
+    #[cfg(feature = "KHR_texture_transform")]
+    {
+        // The transform overrides the `TEXCOORD` set of the reference.
+        assert_eq!(normal.tex_coord, 1);
+        assert_eq!(normal.transform, UvTransform { offset: [0.5, 0.0], rotation: 0.0, scale: [2.0, 2.0] });
+        assert_eq!(emissive.transform.rotation, 1.5707964);
+    }
+    // The extension is ignored without its feature.
+    #[cfg(not(feature = "KHR_texture_transform"))]
+    {
+        assert_eq!(normal.tex_coord, 0);
+        assert!(normal.transform.is_identity() && emissive.transform.is_identity());
+    }
+
+    assert!(materials[1].normal_mapping().is_none());
+    assert!(materials[1].occlusion_params().is_none());
+    assert!(materials[1].emissive_params().is_none());
+}
+
+#[test]
+fn test_uv_transform() {
+    let transform = UvTransform { offset: [0.5, 0.25], rotation: std::f32::consts::FRAC_PI_2, scale: [2.0, 4.0] };
+    // Scaled, then rotated counter-clockwise, then offset.
+    assert_near(transform.apply([1.0, 0.0]), [0.5, -1.75]);
+    assert_near(transform.apply([0.0, 1.0]), [4.5, 0.25]);
+
+    let matrix = transform.matrix();
+    let uv = [1.0, 1.0];
+    let by_matrix = [
+        matrix[0][0] * uv[0] + matrix[1][0] * uv[1] + matrix[2][0],
+        matrix[0][1] * uv[0] + matrix[1][1] * uv[1] + matrix[2][1],
+    ];
+    assert_near(by_matrix, transform.apply(uv));
+
+    assert!(UvTransform::default().is_identity());
+    assert_eq!(UvTransform::IDENTITY.apply([0.25, 0.75]), [0.25, 0.75]);
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn test_serialize() {
+    let gltf = gltf::Gltf::from_slice(DOCUMENT.as_bytes()).unwrap();
+    let occlusion = gltf.materials().next().unwrap().occlusion_params().unwrap();
+    assert_eq!(
+        gltf::json::serialize::to_string(&occlusion).unwrap(),
+        r#"{"texture":1,"image":1,"tex_coord":1,"transform":{"offset":[0.0,0.0],"rotation":0.0,"scale":[1.0,1.0]},"strength":0.25}"#,
+    );
+}
+
+/// A primitive with the given attributes whose base color texture uses set 0,
+/// overridden to set 1 by `KHR_texture_transform`.
+fn tex_coord_document(attributes: &str) -> String {
+    format!(r#"{{
+        "asset": {{"version": "2.0"}},
+        "extensionsUsed": ["KHR_texture_transform"],
+        "buffers": [{{"byteLength": 60, "uri": "data.bin"}}],
+        "bufferViews": [{{"buffer": 0, "byteLength": 36}}, {{"buffer": 0, "byteOffset": 36, "byteLength": 24}}],
+        "accessors": [
+            {{"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0]}},
+            {{"bufferView": 1, "componentType": 5126, "count": 3, "type": "VEC2", "min": [0, 0], "max": [1, 1]}}
+        ],
+        "images": [{{"uri": "texture.png"}}],
+        "textures": [{{"source": 0}}],
+        "materials": [{{"pbrMetallicRoughness": {{"baseColorTexture": {{
+            "index": 0,
+            "extensions": {{"KHR_texture_transform": {{"texCoord": 1}}}}
+        }}}}}}],
+        "meshes": [{{"primitives": [{{"attributes": {{{}}}, "material": 0}}]}}]
+    }}"#, attributes)
+}
+
+fn tex_coord_entries(attributes: &str) -> Vec<(String, gltf::json::validation::Error)> {
+    let gltf = gltf::Gltf::from_slice(tex_coord_document(attributes).as_bytes()).unwrap();
+    gltf.validation_report().entries.into_iter().map(|x| (x.path.0, x.error)).collect()
+}
+
+#[test]
+fn test_effective_tex_coord() {
+    use gltf::json::validation::Error;
+
+    let gltf = gltf::Gltf::from_slice(tex_coord_document(r#""POSITION": 0, "TEXCOORD_1": 1"#).as_bytes()).unwrap();
+    let info = gltf.materials().next().unwrap().pbr_metallic_roughness().base_color_texture().unwrap();
+    assert_eq!(info.tex_coord(), 0);
+    let present = tex_coord_entries(r#""POSITION": 0, "TEXCOORD_1": 1"#);
+    let absent = tex_coord_entries(r#""POSITION": 0, "TEXCOORD_0": 1"#);
+    let missing = |set: u32| vec![(format!(r#"meshes[0].primitives[0].attributes["TEXCOORD_{}"]"#, set), Error::TexCoordMissing)];
+
+    #[cfg(feature = "KHR_texture_transform")]
+    {
+        assert_eq!(info.effective_tex_coord(), 1);
+        assert_eq!(present, vec![]);
+        // The base set exists, but the override points to a set the
+        // primitive lacks.
+        assert_eq!(absent, missing(1));
+        assert_eq!(absent[0].1.code(), "TEXCOORD_MISSING");
+    }
+    #[cfg(not(feature = "KHR_texture_transform"))]
+    {
+        assert_eq!(info.effective_tex_coord(), 0);
+        assert_eq!(present, missing(0));
+        assert_eq!(absent, vec![]);
+    }
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "EXT_texture_webp")]
+
+use std::{env, fs};
+
+/// A 1x1 lossy WebP image.
+const WEBP: &str = "UklGRiIAAABXRUJQVlA4IBYAAAAwAQCdASoBAAEADsD+JaQAA3AAAAAA";
+
+const PNG_AND_WEBP: &str = r#"{
+    "asset": {"version": "2.0"},
+    "extensionsUsed": ["EXT_texture_webp"],
+    "images": [
+        {"uri": "fallback.png"},
+        {"uri": "preferred.webp", "mimeType": "image/webp"}
+    ],
+    "textures": [{
+        "source": 0,
+        "extensions": {"EXT_texture_webp": {"source": 1}}
+    }]
+}"#;
+
+#[test]
+fn test_webp_source_is_preferred() {
+    let gltf = gltf::Gltf::from_slice(PNG_AND_WEBP.as_bytes()).unwrap();
+    let texture = gltf.textures().next().unwrap();
+    assert_eq!(texture.source().unwrap().index(), 1);
+    assert_eq!(texture.webp_source().map(|x| x.index()), Some(1));
+    assert_eq!(texture.core_source().map(|x| x.index()), Some(0));
+}
+
+#[test]
+fn test_webp_only_texture() {
+    let json = PNG_AND_WEBP.replace(r#""source": 0,"#, "");
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    let texture = gltf.textures().next().unwrap();
+    assert_eq!(texture.source().unwrap().index(), 1);
+    assert!(texture.core_source().is_none());
+}
+
+#[test]
+fn test_webp_decoding() {
+    let json = format!(
+        r#"{{
+            "asset": {{"version": "2.0"}},
+            "images": [{{"uri": "data:image/webp;base64,{}"}}]
+        }}"#,
+        WEBP
+    );
+    let path = env::temp_dir().join(format!("gltf_test_webp_decoding_{}.gltf", std::process::id()));
+    fs::write(&path, json).unwrap();
+    let (_, _, images) = gltf::import(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!((images[0].width, images[0].height), (1, 1));
+}
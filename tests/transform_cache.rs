@@ -0,0 +1,135 @@
+#![cfg(feature = "utils")]
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use gltf::animation::PreparedAnimation;
+use gltf::scene::{Transform, TransformCache};
+
+/// Counts the allocations of the current thread.
+struct Counter;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for Counter {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|x| x.set(x.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counter = Counter;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(Cell::get)
+}
+
+/// A chain of two joints below a root node, where the first joint turns by
+/// 90 degrees about Z over one second. Node 3 is not part of the scene.
+const SKINNED: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 40}],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 8},
+        {"buffer": 0, "byteOffset": 8, "byteLength": 32}
+    ],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 2, "type": "SCALAR", "min": [0], "max": [1]},
+        {"bufferView": 1, "componentType": 5126, "count": 2, "type": "VEC4"}
+    ],
+    "scenes": [{"nodes": [0]}],
+    "nodes": [
+        {"children": [1], "translation": [0, 0, 5]},
+        {"children": [2]},
+        {"translation": [1, 0, 0]},
+        {}
+    ],
+    "skins": [{"joints": [1, 2]}],
+    "animations": [{
+        "channels": [{"sampler": 0, "target": {"node": 1, "path": "rotation"}}],
+        "samplers": [{"input": 0, "output": 1}]
+    }]
+}"#;
+
+fn buffer_data() -> Vec<u8> {
+    let half = std::f32::consts::FRAC_1_SQRT_2;
+    let floats = [0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, half, half];
+    floats.iter().flat_map(|x: &f32| x.to_le_bytes().to_vec()).collect()
+}
+
+fn assert_near(actual: &[f32], expected: &[f32]) {
+    for (a, b) in actual.iter().zip(expected) {
+        assert!((a - b).abs() < 1.0e-5, "{:?} != {:?}", actual, expected);
+    }
+}
+
+fn translation(translation: [f32; 3]) -> Transform {
+    Transform::Decomposed { translation, rotation: [0.0, 0.0, 0.0, 1.0], scale: [1.0, 1.0, 1.0] }
+}
+
+#[test]
+fn test_update() {
+    let gltf = gltf::Gltf::from_slice(SKINNED.as_bytes()).unwrap();
+    let scene = gltf.scenes().next().unwrap();
+    let mut cache = TransformCache::new(&scene);
+    for (node, world) in scene.walk(Default::default()) {
+        assert_eq!(cache.world(node.index()), Some(world));
+    }
+    assert_eq!(cache.world(3), None);
+    assert_eq!(cache.world(4), None);
+    assert_near(&cache.world(2).unwrap()[3], &[1.0, 0.0, 5.0, 1.0]);
+
+    // Changes are only visible after an update.
+    cache.set_local(1, translation([0.0, 2.0, 0.0]));
+    assert_near(&cache.world(2).unwrap()[3], &[1.0, 0.0, 5.0, 1.0]);
+    assert_near(&cache.local(1).unwrap()[3], &[0.0, 2.0, 0.0, 1.0]);
+    cache.update();
+    assert_near(&cache.world(1).unwrap()[3], &[0.0, 2.0, 5.0, 1.0]);
+    assert_near(&cache.world(2).unwrap()[3], &[1.0, 2.0, 5.0, 1.0]);
+    assert_near(&cache.world(0).unwrap()[3], &[0.0, 0.0, 5.0, 1.0]);
+
+    cache.set_local(2, translation([0.0, 0.0, 0.0]));
+    cache.set_local(3, translation([9.0, 9.0, 9.0]));
+    cache.update();
+    assert_near(&cache.world(2).unwrap()[3], &[0.0, 2.0, 5.0, 1.0]);
+    assert_eq!(cache.local(3), None);
+}
+
+#[test]
+fn test_playback_does_not_allocate() {
+    let gltf = gltf::Gltf::from_slice(SKINNED.as_bytes()).unwrap();
+    let data = buffer_data();
+    let scene = gltf.scenes().next().unwrap();
+    let skin = gltf.skins().next().unwrap();
+    let animation = gltf.animations().next().unwrap();
+    let mut cache = TransformCache::new(&scene);
+    let mut prepared = PreparedAnimation::new(&animation, |_| Some(&data));
+    let mut joints = [[[0.0; 4]; 4]; 2];
+
+    let mut frame = |time: f32, joints: &mut [[[f32; 4]; 4]; 2]| {
+        prepared.apply(time, &mut cache);
+        cache.update();
+        for (matrix, joint) in joints.iter_mut().zip(skin.joints()) {
+            *matrix = cache.world(joint.index()).unwrap();
+        }
+    };
+    frame(0.0, &mut joints);
+    let before = allocations();
+    for step in 0..=100 {
+        frame(step as f32 / 100.0, &mut joints);
+    }
+    assert_eq!(allocations(), before);
+
+    // At the end, the second joint is turned onto the Y axis.
+    assert_near(&joints[1][3], &[0.0, 1.0, 5.0, 1.0]);
+    frame(0.5, &mut joints);
+    let half = std::f32::consts::FRAC_1_SQRT_2;
+    assert_near(&joints[1][3], &[half, half, 5.0, 1.0]);
+}
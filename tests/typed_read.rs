@@ -0,0 +1,151 @@
+#![cfg(feature = "utils")]
+
+use gltf::accessor::{DataType, Dimensions, ReadError};
+
+/// Joints stored as unsigned bytes, normalized colors, `u16` indices,
+/// positions, and a rotation keyframe stored as normalized signed bytes.
+const JSON: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 44}],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 8},
+        {"buffer": 0, "byteOffset": 8, "byteLength": 8},
+        {"buffer": 0, "byteOffset": 16, "byteLength": 6},
+        {"buffer": 0, "byteOffset": 24, "byteLength": 12},
+        {"buffer": 0, "byteOffset": 36, "byteLength": 4},
+        {"buffer": 0, "byteOffset": 40, "byteLength": 4}
+    ],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5121, "count": 2, "type": "VEC4"},
+        {"bufferView": 1, "componentType": 5121, "count": 2, "type": "VEC4", "normalized": true},
+        {"bufferView": 2, "componentType": 5123, "count": 3, "type": "SCALAR"},
+        {"bufferView": 3, "componentType": 5126, "count": 1, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 1]},
+        {"bufferView": 4, "componentType": 5120, "count": 1, "type": "VEC4", "normalized": true},
+        {"bufferView": 5, "componentType": 5126, "count": 1, "type": "SCALAR", "min": [0], "max": [0]}
+    ],
+    "nodes": [{}],
+    "animations": [{
+        "channels": [{"sampler": 0, "target": {"node": 0, "path": "rotation"}}],
+        "samplers": [{"input": 5, "output": 4}]
+    }]
+}"#;
+
+fn buffer() -> Vec<u8> {
+    let mut data = vec![200, 1, 2, 3, 4, 5, 6, 7];
+    data.extend([255, 0, 51, 255, 0, 255, 0, 255]);
+    data.extend([1u16, 2, 300].iter().flat_map(|x| x.to_le_bytes()));
+    data.extend([0, 0]);
+    data.extend([1.0f32, 0.5, 0.0].iter().flat_map(|x| x.to_le_bytes()));
+    data.extend([0, 0, 127, 129]);
+    data.extend(0.0f32.to_le_bytes());
+    data
+}
+
+#[test]
+fn test_exact_read() {
+    let document = gltf::Document::from_json(gltf::json::Root::from_str(JSON).unwrap()).unwrap();
+    let buffer = buffer();
+    let get = |_| Some(buffer.as_slice());
+    let accessors = document.accessors().collect::<Vec<_>>();
+
+    let joints = accessors[0].read::<[u8; 4], _>(get).unwrap().collect::<Vec<_>>();
+    assert_eq!(joints, vec![[200, 1, 2, 3], [4, 5, 6, 7]]);
+    let indices = accessors[2].read::<u16, _>(get).unwrap();
+    assert_eq!(indices.len(), 3);
+    assert_eq!(indices.collect::<Vec<_>>(), vec![1, 2, 300]);
+    let positions = accessors[3].read::<[f32; 3], _>(get).unwrap().collect::<Vec<_>>();
+    assert_eq!(positions, vec![[1.0, 0.5, 0.0]]);
+}
+
+#[test]
+fn test_illegal_combinations() {
+    let document = gltf::Document::from_json(gltf::json::Root::from_str(JSON).unwrap()).unwrap();
+    let buffer = buffer();
+    let get = |_| Some(buffer.as_slice());
+    let accessors = document.accessors().collect::<Vec<_>>();
+    let joints = (DataType::U8, Dimensions::Vec4);
+
+    let mismatch = |result: Result<_, ReadError>| match result {
+        Err(ReadError::TypeMismatch(mismatch)) => (mismatch.accessor, mismatch.requested),
+        Err(error) => panic!("unexpected error {:?}", error),
+        Ok(_) => panic!("reinterpreted items"),
+    };
+
+    // Signedness.
+    let result = accessors[0].read::<[i8; 4], _>(get).map(|_| ());
+    assert_eq!(mismatch(result), (joints, (DataType::I8, Dimensions::Vec4)));
+    let result = accessors[4].read::<[u8; 4], _>(get).map(|_| ());
+    assert_eq!(mismatch(result), ((DataType::I8, Dimensions::Vec4), joints));
+    let result = accessors[2].read::<i16, _>(get).map(|_| ());
+    assert_eq!(mismatch(result), ((DataType::U16, Dimensions::Scalar), (DataType::I16, Dimensions::Scalar)));
+
+    // Width, including implicit widening.
+    let result = accessors[0].read::<[u16; 4], _>(get).map(|_| ());
+    assert_eq!(mismatch(result), (joints, (DataType::U16, Dimensions::Vec4)));
+    let result = accessors[2].read::<u32, _>(get).map(|_| ());
+    assert_eq!(mismatch(result), ((DataType::U16, Dimensions::Scalar), (DataType::U32, Dimensions::Scalar)));
+    let result = accessors[2].read::<u8, _>(get).map(|_| ());
+    assert_eq!(mismatch(result), ((DataType::U16, Dimensions::Scalar), (DataType::U8, Dimensions::Scalar)));
+
+    // Dimensions.
+    let result = accessors[0].read::<u8, _>(get).map(|_| ());
+    assert_eq!(mismatch(result), (joints, (DataType::U8, Dimensions::Scalar)));
+    let result = accessors[3].read::<[f32; 4], _>(get).map(|_| ());
+    assert_eq!(mismatch(result), ((DataType::F32, Dimensions::Vec3), (DataType::F32, Dimensions::Vec4)));
+
+    // Integers and floats.
+    let result = accessors[1].read::<[f32; 4], _>(get).map(|_| ());
+    assert_eq!(mismatch(result), (joints, (DataType::F32, Dimensions::Vec4)));
+    let result = accessors[3].read::<[u32; 3], _>(get).map(|_| ());
+    assert_eq!(mismatch(result), ((DataType::F32, Dimensions::Vec3), (DataType::U32, Dimensions::Vec3)));
+}
+
+#[test]
+fn test_error_messages() {
+    let document = gltf::Document::from_json(gltf::json::Root::from_str(JSON).unwrap()).unwrap();
+    let buffer = buffer();
+    let accessor = document.accessors().next().unwrap();
+
+    let error = accessor.read::<[i8; 4], _>(|_| Some(buffer.as_slice())).unwrap_err();
+    assert_eq!(error.to_string(), "accessor has U8 Vec4 items but I8 Vec4 items were requested");
+
+    let error = accessor.read::<[u8; 4], _>(|_| None).unwrap_err();
+    assert_eq!(error, ReadError::Unavailable);
+
+    let error = accessor.read::<[u8; 4], _>(|_| Some(buffer.as_slice())).unwrap().normalize::<[f32; 4]>();
+    let error = error.map(|_| ()).unwrap_err();
+    assert_eq!(error, ReadError::NotNormalized((DataType::U8, Dimensions::Vec4)));
+    assert!(error.to_string().contains("U8 Vec4"));
+}
+
+#[test]
+fn test_widening() {
+    let document = gltf::Document::from_json(gltf::json::Root::from_str(JSON).unwrap()).unwrap();
+    let buffer = buffer();
+    let get = |_| Some(buffer.as_slice());
+    let accessors = document.accessors().collect::<Vec<_>>();
+
+    let joints = accessors[0].read::<[u8; 4], _>(get).unwrap().cast::<[u16; 4]>().collect::<Vec<_>>();
+    assert_eq!(joints, vec![[200, 1, 2, 3], [4, 5, 6, 7]]);
+    let indices = accessors[2].read::<u16, _>(get).unwrap().cast::<u32>().collect::<Vec<_>>();
+    assert_eq!(indices, vec![1, 2, 300]);
+
+    let colors = accessors[1].read::<[u8; 4], _>(get).unwrap().normalize::<[f32; 4]>().unwrap();
+    assert_eq!(colors.collect::<Vec<_>>(), vec![[1.0, 0.0, 0.2, 1.0], [0.0, 1.0, 0.0, 1.0]]);
+    let rotations = accessors[4].read::<[i8; 4], _>(get).unwrap().normalize::<[f32; 4]>().unwrap();
+    assert_eq!(rotations.collect::<Vec<_>>(), vec![[0.0, 0.0, 1.0, -1.0]]);
+}
+
+#[test]
+fn test_rotations_into_u8() {
+    let document = gltf::Document::from_json(gltf::json::Root::from_str(JSON).unwrap()).unwrap();
+    let buffer = buffer();
+    let channel = document.animations().next().unwrap().channels().next().unwrap();
+    let reader = channel.reader(|_| Some(buffer.as_slice()));
+    match reader.read_outputs().unwrap() {
+        gltf::animation::util::ReadOutputs::Rotations(rotations) => {
+            assert_eq!(rotations.into_u8().collect::<Vec<_>>(), vec![[0, 0, 254, 0]]);
+        },
+        _ => panic!("expected rotations"),
+    }
+}
@@ -0,0 +1,87 @@
+//! Import of textures with a PNG core source and an extension source, with
+//! and without the feature that decodes the extension source.
+
+#![cfg(feature = "import")]
+
+use std::{env, fs};
+
+/// A 1x1 PNG image.
+const PNG: &str = "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAIAAACQd1PeAAAADElEQVR4nGP4z8AAAAMBAQDJ/pLvAAAAAElFTkSuQmCC";
+
+/// A 1x1 lossy WebP image.
+const WEBP: &str = "UklGRiIAAABXRUJQVlA4IBYAAAAwAQCdASoBAAEADsD+JaQAA3AAAAAA";
+
+/// A 2x2 DDS image with placeholder block data.
+const DDS: &str = "RERTIHwAAAAHEAAAAgAAAAIAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAACrq6urq6urq6urq6urq6ur";
+
+/// Imports JSON from a temporary file.
+fn import(name: &str, json: &str) -> gltf::Result<(gltf::Document, Vec<gltf::buffer::Data>, Vec<gltf::image::Data>)> {
+    let path = env::temp_dir().join(format!("gltf_test_unsupported_images_{}_{}.gltf", name, std::process::id()));
+    fs::write(&path, json).unwrap();
+    let result = gltf::import(&path);
+    fs::remove_file(&path).unwrap();
+    result
+}
+
+/// Returns a texture with a PNG core source and the extension source of the
+/// given data URI.
+fn fallback(extension: &str, uri: &str) -> String {
+    format!(
+        r#"{{
+            "asset": {{"version": "2.0"}},
+            "extensionsUsed": ["{}"],
+            "images": [{{"uri": "data:image/png;base64,{}"}}, {{"uri": "{}"}}],
+            "textures": [{{"source": 0, "extensions": {{"{}": {{"source": 1}}}}}}]
+        }}"#,
+        extension, PNG, uri, extension,
+    )
+}
+
+#[test]
+fn test_webp_with_png_fallback() {
+    let json = fallback("EXT_texture_webp", &format!("data:image/webp;base64,{}", WEBP));
+    let (document, _, images) = import("webp", &json).unwrap();
+    let source = document.textures().next().unwrap().source().unwrap().index();
+    assert_eq!((images[0].width, images[0].height), (1, 1));
+    if cfg!(feature = "EXT_texture_webp") {
+        assert_eq!(source, 1);
+        assert_eq!((images[1].width, images[1].height), (1, 1));
+    } else {
+        // The WebP image is not used, so it is skipped.
+        assert_eq!(source, 0);
+        assert_eq!((images[1].width, images[1].height), (0, 0));
+        assert!(images[1].pixels.is_empty());
+    }
+}
+
+#[test]
+fn test_dds_with_png_fallback() {
+    let json = fallback("MSFT_texture_dds", &format!("data:image/vnd-ms.dds;base64,{}", DDS));
+    let (document, _, images) = import("dds", &json).unwrap();
+    let source = document.textures().next().unwrap().source().unwrap().index();
+    assert_eq!((images[0].width, images[0].height), (1, 1));
+    if cfg!(feature = "MSFT_texture_dds") {
+        assert_eq!(source, 1);
+        assert_eq!((images[1].width, images[1].height), (2, 2));
+    } else {
+        assert_eq!(source, 0);
+        assert!(images[1].pixels.is_empty());
+    }
+}
+
+#[cfg(not(feature = "EXT_texture_webp"))]
+#[test]
+fn test_used_image_is_decoded() {
+    let json = format!(
+        r#"{{
+            "asset": {{"version": "2.0"}},
+            "images": [{{"uri": "data:image/webp;base64,{}"}}],
+            "textures": [{{"source": 0}}]
+        }}"#,
+        WEBP,
+    );
+    match import("used", &json) {
+        Err(gltf::Error::UnsupportedImageEncoding) => {},
+        other => panic!("expected an unsupported encoding, got {:?}", other.map(|_| ())),
+    }
+}
@@ -0,0 +1,496 @@
+use gltf::json::validation::Error;
+
+fn node_with_rotation(rotation: &str) -> String {
+    format!(
+        r#"{{"asset": {{"version": "2.0"}}, "nodes": [{{"rotation": {}}}]}}"#,
+        rotation
+    )
+}
+
+fn strict_errors(json: &str) -> Vec<(String, Error)> {
+    let gltf = gltf::Gltf::from_slice_without_validation(json.as_bytes()).expect("parsing failed");
+    match gltf.validate_strict() {
+        Ok(()) => Vec::new(),
+        Err(gltf::Error::Validation(errors)) => {
+            errors.into_iter().map(|(path, error)| (path.0, error)).collect()
+        },
+        Err(err) => panic!("unexpected error: {}", err),
+    }
+}
+
+#[test]
+fn test_denormalized_rotation() {
+    let json = node_with_rotation("[0.0, 0.0, 0.0, 1.0005]");
+    assert_eq!(strict_errors(&json), vec![]);
+
+    let json = node_with_rotation("[0.0, 0.0, 0.0, 1.01]");
+    let expected = vec![("nodes[0].rotation".to_string(), Error::Denormalized)];
+    assert_eq!(strict_errors(&json), expected);
+}
+
+#[test]
+fn test_zero_length_rotation() {
+    let json = node_with_rotation("[0.0, 0.0, 0.0, 0.0]");
+    let expected = vec![("nodes[0].rotation".to_string(), Error::ZeroLength)];
+    assert_eq!(strict_errors(&json), expected);
+
+    // Lenient loading accepts the rotation and decomposes it to identity.
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    let (_, rotation, _) = gltf.nodes().next().unwrap().transform().decomposed();
+    assert_eq!(rotation, [0.0, 0.0, 0.0, 1.0]);
+}
+
+const PRIMITIVE_COUNTS: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 48, "uri": "data.bin"}],
+    "bufferViews": [{"buffer": 0, "byteLength": 48}, {"buffer": 0, "byteLength": 8}],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 1]},
+        {"bufferView": 0, "componentType": 5126, "count": 4, "type": "VEC3"},
+        {"bufferView": 1, "componentType": 5123, "count": 4, "type": "SCALAR"}
+    ],
+    "meshes": [{
+        "primitives": [{
+            "attributes": {"POSITION": 0, "NORMAL": 1},
+            "indices": 2,
+            "targets": [{"POSITION": 1}]
+        }]
+    }]
+}"#;
+
+#[test]
+fn test_primitive_counts() {
+    let errors = match gltf::Gltf::from_slice(PRIMITIVE_COUNTS.as_bytes()) {
+        Err(gltf::Error::Validation(errors)) => errors,
+        _ => panic!("expected validation errors"),
+    };
+    let errors = errors
+        .into_iter()
+        .map(|(path, error)| (path.0, error))
+        .collect::<Vec<_>>();
+    let mismatch = Error::CountMismatch { expected: 3, actual: 4 };
+    assert_eq!(
+        errors,
+        vec![
+            (r#"meshes[0].primitives[0].attributes["NORMAL"]"#.to_string(), mismatch),
+            (r#"meshes[0].primitives[0].targets[0]["POSITION"]"#.to_string(), mismatch),
+            ("meshes[0].primitives[0].indices".to_string(), Error::Invalid),
+        ]
+    );
+}
+
+#[cfg(feature = "import")]
+#[test]
+fn test_index_values() {
+    let json = PRIMITIVE_COUNTS
+        .replace(r#""count": 4, "type": "VEC3""#, r#""count": 3, "type": "VEC3""#)
+        .replace(r#""count": 4, "type": "SCALAR""#, r#""count": 3, "type": "SCALAR""#);
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+
+    let data = [0, 0, 1, 0, 2, 0, 0, 0];
+    assert!(gltf.validate_buffer_data(|_| Some(&data[..])).is_ok());
+
+    let data = [0, 0, 1, 0, 3, 0, 0, 0];
+    match gltf.validate_buffer_data(|_| Some(&data[..])) {
+        Err(gltf::Error::Validation(errors)) => {
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors[0].0.as_str(), "meshes[0].primitives[0].indices");
+            assert_eq!(errors[0].1, Error::IndexOutOfBounds);
+        },
+        _ => panic!("expected validation errors"),
+    }
+}
+
+#[test]
+fn test_duplicate_channel_targets() {
+    let json = r#"{
+        "asset": {"version": "2.0"},
+        "buffers": [{"byteLength": 4, "uri": "data.bin"}],
+        "bufferViews": [{"buffer": 0, "byteLength": 4}],
+        "accessors": [{"bufferView": 0, "componentType": 5126, "count": 1, "type": "SCALAR"}],
+        "nodes": [{}],
+        "animations": [{
+            "samplers": [{"input": 0, "output": 0}],
+            "channels": [
+                {"sampler": 0, "target": {"node": 0, "path": "rotation"}},
+                {"sampler": 0, "target": {"node": 0, "path": "scale"}},
+                {"sampler": 0, "target": {"node": 0, "path": "rotation"}}
+            ]
+        }]
+    }"#;
+    match gltf::Gltf::from_slice(json.as_bytes()) {
+        Err(gltf::Error::Validation(errors)) => {
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors[0].0.as_str(), "animations[0].channels[2].target");
+            assert_eq!(errors[0].1, Error::Duplicate);
+        },
+        _ => panic!("expected validation errors"),
+    }
+}
+
+#[test]
+fn test_missing_texture_source() {
+    let json = r#"{
+        "asset": {"version": "2.0"},
+        "textures": [{}]
+    }"#;
+    match gltf::Gltf::from_slice(json.as_bytes()) {
+        Err(gltf::Error::Validation(errors)) => {
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors[0].0.as_str(), "textures[0].source");
+            assert_eq!(errors[0].1, Error::Missing);
+        },
+        _ => panic!("expected validation errors"),
+    }
+}
+
+fn load_errors(json: &str) -> Vec<(String, Error)> {
+    match gltf::Gltf::from_slice(json.as_bytes()) {
+        Err(gltf::Error::Validation(errors)) => {
+            errors.into_iter().map(|(path, error)| (path.0, error)).collect()
+        },
+        _ => panic!("expected validation errors"),
+    }
+}
+
+#[test]
+fn test_wrapper_invariants() {
+    let camera = r#"{
+        "asset": {"version": "2.0"},
+        "cameras": [{"type": "orthographic", "perspective": {"yfov": 1, "znear": 0.1}}]
+    }"#;
+    assert_eq!(load_errors(camera), vec![("cameras[0].orthographic".to_string(), Error::Missing)]);
+
+    let images = r#"{
+        "asset": {"version": "2.0"},
+        "buffers": [{"byteLength": 4, "uri": "data.bin"}],
+        "bufferViews": [{"buffer": 0, "byteLength": 4}],
+        "images": [{}, {"bufferView": 0}]
+    }"#;
+    assert_eq!(
+        load_errors(images),
+        vec![
+            ("images[0].uri".to_string(), Error::Missing),
+            ("images[1].mimeType".to_string(), Error::Missing),
+        ]
+    );
+
+    let channel = r#"{
+        "asset": {"version": "2.0"},
+        "buffers": [{"byteLength": 4, "uri": "data.bin"}],
+        "bufferViews": [{"buffer": 0, "byteLength": 4}],
+        "accessors": [{"bufferView": 0, "componentType": 5126, "count": 1, "type": "SCALAR", "min": [0], "max": [0]}],
+        "animations": [{
+            "channels": [
+                {"sampler": 0, "target": {"node": 1, "path": "scale"}},
+                {"sampler": 0, "target": {"node": 0, "path": "weight0s"}}
+            ],
+            "samplers": [{"input": 0, "output": 0}]
+        }],
+        "nodes": [{}]
+    }"#;
+    assert_eq!(
+        load_errors(channel),
+        vec![
+            ("animations[0].channels[0].target.node".to_string(), Error::IndexOutOfBounds),
+            ("animations[0].channels[1].target.path".to_string(), Error::Invalid),
+        ]
+    );
+}
+
+#[test]
+fn test_accessor_without_buffer_view() {
+    let json = r#"{
+        "asset": {"version": "2.0"},
+        "accessors": [
+            {"componentType": 5126, "count": 3, "type": "VEC3"},
+            {"componentType": 5126, "count": 3, "type": "VEC3", "byteOffset": 4, "sparse": {
+                "count": 1,
+                "indices": {"bufferView": 0, "componentType": 5123},
+                "values": {"bufferView": 0}
+            }}
+        ],
+        "buffers": [{"byteLength": 12, "uri": "data.bin"}],
+        "bufferViews": [{"buffer": 0, "byteLength": 12}]
+    }"#;
+    match gltf::Gltf::from_slice(json.as_bytes()) {
+        Err(gltf::Error::Validation(errors)) => {
+            let errors = errors
+                .into_iter()
+                .map(|(path, error)| (path.0, error))
+                .collect::<Vec<_>>();
+            assert_eq!(errors, vec![
+                ("accessors[0].bufferView".to_string(), Error::Missing),
+                ("accessors[1].byteOffset".to_string(), Error::Invalid),
+            ]);
+        },
+        _ => panic!("expected validation errors"),
+    }
+}
+
+const SHARED_VIEW: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 72, "uri": "data.bin"}],
+    "bufferViews": [{"buffer": 0, "byteLength": 72, "byteStride": 20}],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 1]},
+        {"bufferView": 0, "byteOffset": 12, "componentType": 5126, "count": 3, "type": "VEC2"}
+    ],
+    "meshes": [{"primitives": [{"attributes": {"POSITION": 0, "TEXCOORD_0": 1}}]}]
+}"#;
+
+fn lenient_errors(json: &str) -> Vec<(String, Error)> {
+    match gltf::Gltf::from_slice(json.as_bytes()) {
+        Ok(_) => Vec::new(),
+        Err(gltf::Error::Validation(errors)) => {
+            errors.into_iter().map(|(path, error)| (path.0, error)).collect()
+        },
+        Err(err) => panic!("unexpected error: {}", err),
+    }
+}
+
+#[test]
+fn test_byte_stride() {
+    assert_eq!(lenient_errors(SHARED_VIEW), vec![]);
+
+    // The stride is smaller than the position element.
+    let json = SHARED_VIEW.replace(r#""byteStride": 20"#, r#""byteStride": 8"#);
+    assert_eq!(lenient_errors(&json), vec![("accessors[0].bufferView".to_string(), Error::Invalid)]);
+
+    // Without a stride, the interleaved attributes cannot be told apart.
+    let json = SHARED_VIEW.replace(r#", "byteStride": 20"#, "");
+    assert_eq!(
+        lenient_errors(&json),
+        vec![
+            (r#"meshes[0].primitives[0].attributes["POSITION"]"#.to_string(), Error::Invalid),
+            (r#"meshes[0].primitives[0].attributes["TEXCOORD_0"]"#.to_string(), Error::Invalid),
+        ]
+    );
+}
+
+#[test]
+fn test_validation_report_severities() {
+    use gltf::validation::Severity;
+
+    // A denormalized rotation and a normal accessor without bounds.
+    let json = SHARED_VIEW
+        .replace(r#""TEXCOORD_0": 1"#, r#""TEXCOORD_0": 1, "NORMAL": 0"#)
+        .replace(r#""meshes""#, r#""nodes": [{"rotation": [0, 0, 0, 1.1]}], "meshes""#);
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    let report = gltf.validation_report();
+    let entries = report.entries
+        .iter()
+        .map(|entry| (entry.path.as_str(), entry.code(), entry.severity()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        entries,
+        vec![
+            (r#"meshes[0].primitives[0].attributes["TEXCOORD_0"]"#, "ACCESSOR_BOUNDS_MISSING", Severity::Info),
+            ("nodes[0].rotation", "DENORMALIZED_QUATERNION", Severity::Warning),
+        ]
+    );
+    assert_eq!(report.count(Severity::Info), 1);
+    assert_eq!(report.count(Severity::Error), 0);
+    assert!(report.fails(Severity::Info));
+    assert!(report.fails(Severity::Warning));
+    assert!(!report.fails(Severity::Error));
+    assert_eq!(report.at_least(Severity::Warning).count(), 1);
+
+    assert!(gltf.validate_with(Severity::Error).is_ok());
+    match gltf.validate_with(Severity::Warning) {
+        Err(gltf::Error::Validation(errors)) => assert_eq!(errors.len(), 1),
+        x => panic!("unexpected result: {:?}", x),
+    }
+    match gltf.validate_with(Severity::Info) {
+        Err(gltf::Error::Validation(errors)) => assert_eq!(errors.len(), 2),
+        x => panic!("unexpected result: {:?}", x),
+    }
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn test_validation_report_to_json() {
+    let json = SHARED_VIEW.replace(r#""byteStride": 20"#, r#""byteStride": 8"#);
+    let gltf = gltf::Gltf::from_slice_without_validation(json.as_bytes()).unwrap();
+    let report = gltf.validation_report().to_json();
+    assert_eq!(report["counts"]["errors"], 1);
+    assert_eq!(report["counts"]["warnings"], 0);
+    assert_eq!(report["counts"]["infos"], 1);
+    let entry = &report["entries"][0];
+    assert_eq!(entry["code"], "INVALID_VALUE");
+    assert_eq!(entry["severity"], "Error");
+    assert_eq!(entry["path"], "accessors[0].bufferView");
+    assert_eq!(entry["message"], "Invalid value");
+}
+
+#[test]
+fn test_validation_suppression() {
+    use gltf::validation::{Severity, ValidationOptions};
+
+    let json = SHARED_VIEW
+        .replace(r#""byteStride": 20"#, r#""byteStride": 8"#)
+        .replace(r#""meshes""#, r#""nodes": [{"rotation": [0, 0, 0, 1.1]}], "meshes""#);
+    let gltf = gltf::Gltf::from_slice_without_validation(json.as_bytes()).unwrap();
+    assert_eq!(gltf.validation_report().at_least(Severity::Warning).count(), 2);
+
+    let options = ValidationOptions::default()
+        .suppress("DENORMALIZED_QUATERNION")
+        .suppress_path_prefix("accessors[0]");
+    let report = gltf.validation_report_with(&options);
+    let suppressed = report.suppressed.iter().map(|x| x.path.as_str()).collect::<Vec<_>>();
+    assert_eq!(suppressed, vec!["accessors[0].bufferView", "nodes[0].rotation"]);
+    assert!(!report.fails(Severity::Warning));
+    assert!(report.into_result(Severity::Warning).is_ok());
+
+    // Prefixes only match whole path components.
+    let options = ValidationOptions::default().suppress_path_prefix("accessors[");
+    assert_eq!(gltf.validation_report_with(&options).suppressed.len(), 0);
+
+    // The options round-trip through configuration files.
+    let config = gltf::json::serialize::to_string(&options).unwrap();
+    assert_eq!(config, r#"{"codes":[],"pathPrefixes":["accessors["]}"#);
+    let parsed: ValidationOptions = gltf::json::deserialize::from_str(r#"{"codes": ["INVALID_VALUE"]}"#).unwrap();
+    assert_eq!(parsed, ValidationOptions::default().suppress("INVALID_VALUE"));
+}
+
+#[test]
+fn test_matrix_animated() {
+    use gltf::validation::Severity;
+
+    let json = std::fs::read("tests/matrix_animated.gltf").unwrap();
+    let gltf = gltf::Gltf::from_slice_without_validation(&json).unwrap();
+    let report = gltf.validation_report();
+    let errors = report.at_least(Severity::Error).collect::<Vec<_>>();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].path.as_str(), "animations[0].channels[0].target.node");
+    assert_eq!(errors[0].code(), "NODE_MATRIX_ANIMATED");
+    assert_eq!(errors[0].error.to_string(), "Animated node nodes[1] defines a matrix");
+
+    // Lenient loading decomposes the matrix and reports a warning instead.
+    let gltf = gltf::Gltf::from_slice(&json).unwrap();
+    let nodes = gltf.nodes().collect::<Vec<_>>();
+    assert!(matches!(nodes[0].transform(), gltf::scene::Transform::Matrix { .. }));
+    assert_eq!(nodes[1].transform().decomposed(), ([1.0, 2.0, 3.0], [0.0, 0.0, 0.0, 1.0], [2.0; 3]));
+    let report = gltf.validation_report();
+    assert_eq!(report.entries.len(), 1);
+    assert_eq!(report.entries[0].path.as_str(), "nodes[1].matrix");
+    assert_eq!(report.entries[0].code(), "NODE_MATRIX_DECOMPOSED");
+    assert_eq!(report.entries[0].severity(), Severity::Warning);
+    assert_eq!(strict_errors(std::str::from_utf8(&json).unwrap()).len(), 1);
+}
+
+#[test]
+fn test_color_formats() {
+    let json = |color: &str| format!(r#"{{
+        "asset": {{"version": "2.0"}},
+        "buffers": [{{"byteLength": 72, "uri": "data.bin"}}],
+        "bufferViews": [{{"buffer": 0, "byteLength": 36}}, {{"buffer": 0, "byteOffset": 36, "byteLength": 36}}],
+        "accessors": [
+            {{"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0]}},
+            {{"bufferView": 1, "count": 3, {}}}
+        ],
+        "meshes": [{{"primitives": [{{"attributes": {{"POSITION": 0, "COLOR_0": 1}}}}]}}]
+    }}"#, color);
+    let valid = [
+        r#""componentType": 5126, "type": "VEC3""#,
+        r#""componentType": 5121, "normalized": true, "type": "VEC4""#,
+        r#""componentType": 5123, "normalized": true, "type": "VEC3""#,
+    ];
+    for color in &valid {
+        assert_eq!(lenient_errors(&json(color)), vec![], "{}", color);
+    }
+    let invalid = [
+        r#""componentType": 5121, "type": "VEC4""#,
+        r#""componentType": 5122, "normalized": true, "type": "VEC3""#,
+        r#""componentType": 5126, "type": "VEC2""#,
+    ];
+    for color in &invalid {
+        assert_eq!(
+            lenient_errors(&json(color)),
+            vec![(r#"meshes[0].primitives[0].attributes["COLOR_0"]"#.to_string(), Error::Invalid)],
+            "{}",
+            color,
+        );
+    }
+}
+
+/// Numbers of `1e39` overflow to infinity in `f32`.
+const NON_FINITE: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 36, "uri": "data.bin"}],
+    "bufferViews": [{"buffer": 0, "byteLength": 36}],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, -1e39, 0], "max": [1e39, 1, 1]}
+    ],
+    "materials": [{"pbrMetallicRoughness": {"baseColorFactor": [1e39, 0.5, 0.5, 1], "roughnessFactor": -1e39}}],
+    "cameras": [{"type": "perspective", "perspective": {"yfov": 1, "znear": 0.1, "zfar": 1e39}}],
+    "nodes": [{"translation": [0, -1e39, 0], "scale": [1e39, 2, 1], "camera": 0}]
+}"#;
+
+#[test]
+fn test_non_finite_values() {
+    use gltf::validation::Severity;
+
+    let gltf = gltf::Gltf::from_slice_without_validation(NON_FINITE.as_bytes()).unwrap();
+    let report = gltf.validation_report();
+    let errors = report.entries
+        .iter()
+        .map(|entry| (entry.path.as_str(), entry.code()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        errors,
+        vec![
+            ("accessors[0].min[1]", "NON_FINITE_VALUE"),
+            ("accessors[0].max[0]", "NON_FINITE_VALUE"),
+            ("cameras[0].perspective.zfar", "NON_FINITE_VALUE"),
+            ("materials[0].pbrMetallicRoughness.baseColorFactor", "NON_FINITE_VALUE"),
+            ("materials[0].pbrMetallicRoughness.roughnessFactor", "NON_FINITE_VALUE"),
+            ("nodes[0].scale", "NON_FINITE_VALUE"),
+            ("nodes[0].translation", "NON_FINITE_VALUE"),
+        ]
+    );
+    assert!(report.entries.iter().all(|x| x.severity() == Severity::Error));
+
+    // Lenient loading replaces the values and reports warnings instead.
+    let gltf = gltf::Gltf::from_slice(NON_FINITE.as_bytes()).unwrap();
+    let node = gltf.nodes().next().unwrap();
+    assert_eq!(node.transform().decomposed(), ([0.0; 3], [0.0, 0.0, 0.0, 1.0], [1.0, 2.0, 1.0]));
+    let pbr = gltf.materials().next().unwrap().pbr_metallic_roughness();
+    assert_eq!(pbr.base_color_factor(), [1.0, 0.5, 0.5, 1.0]);
+    assert_eq!(pbr.roughness_factor(), 0.0);
+    let accessor = gltf.accessors().next().unwrap();
+    assert_eq!(accessor.min().unwrap()[1].as_f64(), Some(f32::MIN as f64));
+    assert_eq!(accessor.max().unwrap()[0].as_f64(), Some(f32::MAX as f64));
+    match gltf.cameras().next().unwrap().projection().unwrap() {
+        gltf::camera::Projection::Perspective(perspective) => assert_eq!(perspective.zfar(), None),
+        _ => panic!("expected a perspective camera"),
+    }
+    let report = gltf.validation_report();
+    assert_eq!(report.entries.len(), 7);
+    assert!(report.entries.iter().all(|x| x.code() == "NON_FINITE_VALUE_REPLACED"));
+    assert_eq!(strict_errors(NON_FINITE).len(), 7);
+}
+
+#[test]
+fn test_non_finite_without_default() {
+    let mut root = gltf::json::Root::from_str(NON_FINITE).unwrap();
+    root.nodes[0].rotation = Some(gltf::json::scene::UnitQuaternion([f32::NAN, 0.0, 0.0, 1.0]));
+    root.materials[0].emissive_factor.0 = [f32::NAN, 2.0, 0.5];
+    let document = gltf::Document::from_json(root.clone()).unwrap();
+    let node = document.nodes().next().unwrap();
+    assert_eq!(node.transform().decomposed().1, [0.0, 0.0, 0.0, 1.0]);
+    assert_eq!(document.materials().next().unwrap().emissive_factor(), [0.0, 1.0, 0.5]);
+
+    // The field of view cannot be replaced.
+    if let Some(perspective) = root.cameras[0].perspective.as_mut() {
+        perspective.yfov = f32::NAN;
+    }
+    match gltf::Document::from_json(root) {
+        Err(gltf::Error::Validation(errors)) => {
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors[0].0.as_str(), "cameras[0].perspective.yfov");
+            assert_eq!(errors[0].1, Error::NonFinite);
+        },
+        x => panic!("unexpected result: {:?}", x.map(|_| ())),
+    }
+}
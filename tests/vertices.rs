@@ -0,0 +1,126 @@
+#![cfg(feature = "utils")]
+
+use gltf::mesh::util::vertices::{
+    AttributeMask, FromAttributes, StandardVertex, VertexAttributes, VertexError, DEFAULT_NORMAL, DEFAULT_TEX_COORD,
+};
+use gltf::Semantic;
+
+/// Three vertices with positions, normals, texture co-ordinates, and 8-bit
+/// colors, followed by primitives lacking attributes or with a short normal
+/// accessor. The latter fails validation, so the document is loaded without.
+const VERTICES: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 108}],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 36},
+        {"buffer": 0, "byteOffset": 36, "byteLength": 36},
+        {"buffer": 0, "byteOffset": 72, "byteLength": 24},
+        {"buffer": 0, "byteOffset": 96, "byteLength": 12}
+    ],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [2, 2, 2]},
+        {"bufferView": 1, "componentType": 5126, "count": 3, "type": "VEC3"},
+        {"bufferView": 2, "componentType": 5126, "count": 3, "type": "VEC2"},
+        {"bufferView": 3, "componentType": 5121, "normalized": true, "count": 3, "type": "VEC4"},
+        {"bufferView": 1, "componentType": 5126, "count": 2, "type": "VEC3"}
+    ],
+    "meshes": [{
+        "primitives": [
+            {"attributes": {"POSITION": 0, "NORMAL": 1, "TEXCOORD_0": 2, "COLOR_0": 3}},
+            {"attributes": {"POSITION": 0}},
+            {"attributes": {"POSITION": 0, "NORMAL": 4}}
+        ]
+    }]
+}"#;
+
+fn buffer_data() -> Vec<u8> {
+    let mut data = Vec::new();
+    let mut floats = Vec::new();
+    for v in 0..3 {
+        floats.extend(vec![v as f32; 3]);
+    }
+    for _ in 0..3 {
+        floats.extend(vec![0.0, 0.0, 1.0]);
+    }
+    for v in 0..3 {
+        floats.extend(vec![0.5 * v as f32, 1.0]);
+    }
+    data.extend(floats.iter().flat_map(|x| x.to_le_bytes().to_vec()));
+    for _ in 0..3 {
+        data.extend(vec![255, 0, 0, 255]);
+    }
+    data
+}
+
+/// A vertex type reading tangents and colors but no normals.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct ColoredVertex {
+    position: [f32; 3],
+    color: [f32; 4],
+    tangent: [f32; 4],
+}
+
+impl FromAttributes for ColoredVertex {
+    const ATTRIBUTES: AttributeMask = AttributeMask { normal: false, tangent: true, tex_coord: false, color: true };
+
+    fn from_attributes(attributes: &VertexAttributes) -> Self {
+        ColoredVertex { position: attributes.position, color: attributes.color, tangent: attributes.tangent }
+    }
+}
+
+#[test]
+fn test_standard_vertices() {
+    let gltf = gltf::Gltf::from_slice_without_validation(VERTICES.as_bytes()).unwrap();
+    let data = buffer_data();
+    let primitive = gltf.meshes().next().unwrap().primitives().next().unwrap();
+    let reader = primitive.reader(|_| Some(&data));
+    let vertices = reader.read_vertices::<StandardVertex>().unwrap();
+    assert_eq!(vertices.len(), 3);
+    let vertices = vertices.collect::<Vec<_>>();
+    assert_eq!(vertices[2], StandardVertex { position: [2.0; 3], normal: [0.0, 0.0, 1.0], tex_coord: [1.0, 1.0] });
+
+    let bytes = StandardVertex::as_bytes(&vertices);
+    assert_eq!(bytes.len(), 96);
+    assert_eq!(bytes[32..36], 1.0f32.to_ne_bytes());
+
+    let vertices = reader.read_vertices::<ColoredVertex>().unwrap().collect::<Vec<_>>();
+    assert_eq!(vertices[1].color, [1.0, 0.0, 0.0, 1.0]);
+    assert_eq!(vertices[1].tangent, [1.0, 0.0, 0.0, 1.0]);
+}
+
+#[test]
+fn test_missing_attributes_use_defaults() {
+    let gltf = gltf::Gltf::from_slice_without_validation(VERTICES.as_bytes()).unwrap();
+    let data = buffer_data();
+    let primitive = gltf.meshes().next().unwrap().primitives().nth(1).unwrap();
+    let reader = primitive.reader(|_| Some(&data));
+    let vertices = reader.read_vertices::<StandardVertex>().unwrap().collect::<Vec<_>>();
+    assert_eq!(vertices.len(), 3);
+    assert!(vertices.iter().all(|x| x.normal == DEFAULT_NORMAL && x.tex_coord == DEFAULT_TEX_COORD));
+
+    let vertices = reader.read_vertices::<ColoredVertex>().unwrap().collect::<Vec<_>>();
+    assert_eq!(vertices[0].color, [1.0; 4]);
+}
+
+#[test]
+fn test_length_mismatch() {
+    let gltf = gltf::Gltf::from_slice_without_validation(VERTICES.as_bytes()).unwrap();
+    let data = buffer_data();
+    let primitive = gltf.meshes().next().unwrap().primitives().nth(2).unwrap();
+    let reader = primitive.reader(|_| Some(&data));
+    let expected = VertexError::LengthMismatch { semantic: Semantic::Normals, expected: 3, actual: 2 };
+    assert_eq!(reader.read_vertices::<StandardVertex>().err(), Some(expected));
+
+    // Attributes that are not requested are not checked.
+    assert_eq!(reader.read_vertices::<ColoredVertex>().unwrap().count(), 3);
+}
+
+#[test]
+fn test_unavailable_data() {
+    let gltf = gltf::Gltf::from_slice_without_validation(VERTICES.as_bytes()).unwrap();
+    let primitive = gltf.meshes().next().unwrap().primitives().next().unwrap();
+    let reader = primitive.reader(|_| None);
+    let error = reader.read_vertices::<StandardVertex>().err();
+    assert_eq!(error, Some(VertexError::Unavailable(Semantic::Positions)));
+}
@@ -0,0 +1,104 @@
+// The lazily built caches of a document are interior mutable, but wrappers
+// only hash its address and their index.
+#![allow(clippy::mutable_key_type)]
+
+use std::collections::{HashMap, HashSet};
+
+const DOCUMENT: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 36, "uri": "data.bin"}],
+    "bufferViews": [{"buffer": 0, "byteLength": 36}],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [0, 0, 0]}
+    ],
+    "images": [{"uri": "image.png"}],
+    "samplers": [{}],
+    "textures": [{"source": 0, "sampler": 0}, {"source": 0}],
+    "materials": [{}, {}],
+    "meshes": [{
+        "primitives": [
+            {"attributes": {"POSITION": 0}, "material": 1},
+            {"attributes": {"POSITION": 0}},
+            {"attributes": {"POSITION": 0}, "material": 0},
+            {"attributes": {"POSITION": 0}}
+        ]
+    }],
+    "nodes": [{"mesh": 0}, {"mesh": 0}, {}]
+}"#;
+
+fn load() -> gltf::Gltf {
+    gltf::Gltf::from_slice(DOCUMENT.as_bytes()).unwrap()
+}
+
+#[test]
+fn test_same_document() {
+    let gltf = load();
+    let first = gltf.nodes().next().unwrap();
+    assert_eq!(first, gltf.nodes().next().unwrap());
+    assert_ne!(first, gltf.nodes().nth(1).unwrap());
+    assert_eq!(first.mesh(), gltf.nodes().nth(1).unwrap().mesh());
+    assert_eq!(gltf.accessors().next(), gltf.meshes().next().unwrap().primitives().next().unwrap().get(&gltf::Semantic::Positions));
+
+    let nodes = gltf.nodes().chain(gltf.nodes()).collect::<HashSet<_>>();
+    assert_eq!(nodes.len(), 3);
+}
+
+#[test]
+fn test_different_documents() {
+    let (a, b) = (load(), load());
+    assert_ne!(a.nodes().next().unwrap(), b.nodes().next().unwrap());
+    assert_ne!(a.materials().next().unwrap(), b.materials().next().unwrap());
+    let nodes = a.nodes().chain(b.nodes()).collect::<HashSet<_>>();
+    assert_eq!(nodes.len(), 6);
+}
+
+#[test]
+fn test_default_material() {
+    let gltf = load();
+    let mesh = gltf.meshes().next().unwrap();
+    let materials = mesh.primitives().map(|x| x.material()).collect::<Vec<_>>();
+    assert_eq!(materials[1].index(), None);
+    assert_eq!(materials[1], materials[3]);
+    assert_ne!(materials[1], materials[2]);
+    assert_eq!(materials[2], gltf.materials().next().unwrap());
+
+    // The default materials of different documents differ too.
+    let other = load();
+    let other_default = other.meshes().next().unwrap().primitives().nth(1).unwrap().material();
+    assert_ne!(materials[1], other_default);
+
+    let mut pipelines = HashMap::new();
+    for material in &materials {
+        let next = pipelines.len();
+        pipelines.entry(material.clone()).or_insert(next);
+    }
+    assert_eq!(pipelines.len(), 3);
+    assert_eq!(pipelines[&materials[3]], 1);
+
+    // The default sampler compares like the default material.
+    let samplers = gltf.textures().map(|x| x.sampler()).collect::<Vec<_>>();
+    assert_eq!(samplers[1].index(), None);
+    assert_ne!(samplers[0], samplers[1]);
+    assert_eq!(samplers[1], gltf.textures().nth(1).unwrap().sampler());
+}
+
+#[test]
+fn test_ordering() {
+    let gltf = load();
+    let mut nodes = gltf.nodes().collect::<Vec<_>>();
+    nodes.reverse();
+    nodes.sort();
+    assert_eq!(nodes.iter().map(|x| x.index()).collect::<Vec<_>>(), vec![0, 1, 2]);
+
+    // The default material sorts first.
+    let mut materials = gltf.meshes().next().unwrap().primitives().map(|x| x.material()).collect::<Vec<_>>();
+    materials.sort();
+    let indices = materials.iter().map(|x| x.index()).collect::<Vec<_>>();
+    assert_eq!(indices, vec![None, None, Some(0), Some(1)]);
+
+    let mut primitives = gltf.meshes().next().unwrap().primitives().collect::<Vec<_>>();
+    primitives.reverse();
+    primitives.sort();
+    assert_eq!(primitives.iter().map(|x| x.index()).collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+    assert_eq!(primitives[0], gltf.meshes().next().unwrap().primitives().next().unwrap());
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "KHR_xmp_json_ld")]
+
+const XMP: &str = r#"{
+    "asset": {"version": "2.0", "extensions": {"KHR_xmp_json_ld": {"packet": 1}}},
+    "extensionsUsed": ["KHR_xmp_json_ld"],
+    "extensions": {
+        "KHR_xmp_json_ld": {
+            "packets": [
+                {"@context": {"dc": "http://purl.org/dc/elements/1.1/"}, "dc:title": "Node"},
+                {"@context": {"dc": "http://purl.org/dc/elements/1.1/"}, "dc:title": "Asset"}
+            ]
+        }
+    },
+    "materials": [{"extensions": {"KHR_xmp_json_ld": {"packet": 1}}}, {}],
+    "nodes": [{"extensions": {"KHR_xmp_json_ld": {"packet": 0}}}, {}],
+    "scenes": [{"nodes": [0, 1]}]
+}"#;
+
+#[test]
+fn test_xmp_packets() {
+    let gltf = gltf::Gltf::from_slice(XMP.as_bytes()).unwrap();
+    assert_eq!(gltf.xmp_packets().len(), 2);
+    assert_eq!(gltf.asset_xmp_packet().unwrap().0["dc:title"], "Asset");
+    let nodes = gltf.nodes().collect::<Vec<_>>();
+    assert_eq!(nodes[0].xmp_packet().unwrap().0["dc:title"], "Node");
+    assert!(nodes[1].xmp_packet().is_none());
+    assert!(gltf.scenes().next().unwrap().xmp_packet().is_none());
+
+    let materials = gltf.materials().collect::<Vec<_>>();
+    assert_eq!(materials[0].xmp_packet().unwrap().0["dc:title"], "Asset");
+    assert!(materials[0].has_extension("KHR_xmp_json_ld"));
+    assert_eq!(materials[0].extension_names().collect::<Vec<_>>(), vec!["KHR_xmp_json_ld"]);
+    assert!(!materials[1].has_extension("KHR_xmp_json_ld"));
+
+    let value = gltf.extension_value("KHR_xmp_json_ld").unwrap();
+    assert_eq!(value["packets"][1]["dc:title"], "Asset");
+}
+
+#[test]
+fn test_invalid_packet_reference() {
+    let json = XMP.replace(r#"{"packet": 0}"#, r#"{"packet": 2}"#);
+    match gltf::Gltf::from_slice(json.as_bytes()) {
+        Err(gltf::Error::Validation(errors)) => {
+            let paths = errors.iter().map(|(path, _)| path.as_str()).collect::<Vec<_>>();
+            assert_eq!(paths, vec!["nodes[0].extensions.KHR_xmp_json_ld.packet"]);
+        },
+        _ => panic!("expected a validation error"),
+    }
+}